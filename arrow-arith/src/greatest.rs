@@ -0,0 +1,434 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the [`greatest`] and [`least`] kernels, returning the element-wise
+//! maximum and minimum of two or more arrays, e.g. SQL `GREATEST(a, b, c)`
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_schema::{ArrowError, DataType};
+
+/// How [`greatest`] and [`least`] handle null values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NullPropagation {
+    /// The result is null if any of the values is null
+    #[default]
+    Propagate,
+    /// Null values are ignored, and the result is null only if all of the values are null
+    Skip,
+}
+
+/// Returns the element-wise maximum of `arrays`
+///
+/// Floating point values are compared using a total order, such that `NaN` is greater
+/// than all other values. Strings and binary values are compared lexicographically.
+///
+/// ```
+/// # use arrow_array::{Array, Int32Array};
+/// # use arrow_arith::greatest::{greatest, NullPropagation};
+/// let a = Int32Array::from(vec![Some(1), Some(5), None]);
+/// let b = Int32Array::from(vec![Some(3), Some(2), Some(4)]);
+///
+/// let result = greatest(&[&a, &b], NullPropagation::Propagate).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(vec![Some(3), Some(5), None]));
+///
+/// let result = greatest(&[&a, &b], NullPropagation::Skip).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(vec![3, 5, 4]));
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if `arrays` is empty, if the arrays have different data types or
+/// lengths, or if the data type is not supported
+pub fn greatest(
+    arrays: &[&dyn Array],
+    null_propagation: NullPropagation,
+) -> Result<ArrayRef, ArrowError> {
+    greatest_least("greatest", arrays, null_propagation, Ordering::Greater)
+}
+
+/// Returns the element-wise minimum of `arrays`
+///
+/// Floating point values are compared using a total order, such that `NaN` is greater
+/// than all other values. Strings and binary values are compared lexicographically.
+///
+/// ```
+/// # use arrow_array::{Array, StringArray};
+/// # use arrow_arith::greatest::{least, NullPropagation};
+/// let a = StringArray::from(vec![Some("b"), Some("apple"), None]);
+/// let b = StringArray::from(vec![Some("a"), Some("banana"), Some("c")]);
+///
+/// let result = least(&[&a, &b], NullPropagation::Propagate).unwrap();
+/// assert_eq!(result.as_ref(), &StringArray::from(vec![Some("a"), Some("apple"), None]));
+///
+/// let result = least(&[&a, &b], NullPropagation::Skip).unwrap();
+/// assert_eq!(result.as_ref(), &StringArray::from(vec!["a", "apple", "c"]));
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if `arrays` is empty, if the arrays have different data types or
+/// lengths, or if the data type is not supported
+pub fn least(
+    arrays: &[&dyn Array],
+    null_propagation: NullPropagation,
+) -> Result<ArrayRef, ArrowError> {
+    greatest_least("least", arrays, null_propagation, Ordering::Less)
+}
+
+fn greatest_least(
+    name: &str,
+    arrays: &[&dyn Array],
+    null_propagation: NullPropagation,
+    ordering: Ordering,
+) -> Result<ArrayRef, ArrowError> {
+    let Some(first) = arrays.first() else {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "{name} requires at least one array"
+        )));
+    };
+    for array in &arrays[1..] {
+        if array.data_type() != first.data_type() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "{name} requires arrays of the same data type, got {} and {}",
+                first.data_type(),
+                array.data_type()
+            )));
+        }
+        if array.len() != first.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "{name} requires arrays of the same length, got {} and {}",
+                first.len(),
+                array.len()
+            )));
+        }
+    }
+
+    macro_rules! primitive_helper {
+        ($t:ty) => {
+            Ok(primitive::<$t>(arrays, null_propagation, ordering))
+        };
+    }
+
+    let data_type = first.data_type();
+    downcast_primitive! {
+        data_type => (primitive_helper),
+        DataType::Boolean => {
+            let arrays = arrays.iter().map(|a| a.as_boolean().iter());
+            let values = combine(arrays, null_propagation, |b, a| b.cmp(a) == ordering);
+            Ok(Arc::new(BooleanArray::from(values)))
+        }
+        DataType::Utf8 => Ok(bytes::<Utf8Type>(arrays, null_propagation, ordering)),
+        DataType::LargeUtf8 => Ok(bytes::<LargeUtf8Type>(arrays, null_propagation, ordering)),
+        DataType::Binary => Ok(bytes::<BinaryType>(arrays, null_propagation, ordering)),
+        DataType::LargeBinary => Ok(bytes::<LargeBinaryType>(arrays, null_propagation, ordering)),
+        DataType::Utf8View => Ok(byte_view::<StringViewType>(arrays, null_propagation, ordering)),
+        DataType::BinaryView => Ok(byte_view::<BinaryViewType>(arrays, null_propagation, ordering)),
+        DataType::FixedSizeBinary(size) => {
+            let arrays = arrays.iter().map(|a| a.as_fixed_size_binary().iter());
+            let values = combine(arrays, null_propagation, |b, a| b.cmp(a) == ordering);
+            let array = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+                values.into_iter(),
+                *size,
+            )?;
+            Ok(Arc::new(array))
+        }
+        d => Err(ArrowError::InvalidArgumentError(format!(
+            "{name} does not support data type {d}"
+        ))),
+    }
+}
+
+/// Combines the values of each row of `arrays`, replacing the current value `a` with the
+/// value `b` of a later array if `pick(b, a)` is `true`
+fn combine<V, I>(
+    mut arrays: impl Iterator<Item = I>,
+    null_propagation: NullPropagation,
+    pick: impl Fn(&V, &V) -> bool,
+) -> Vec<Option<V>>
+where
+    I: Iterator<Item = Option<V>>,
+{
+    let mut result: Vec<_> = arrays.next().map(|a| a.collect()).unwrap_or_default();
+    for array in arrays {
+        for (current, value) in result.iter_mut().zip(array) {
+            match (current.as_ref(), value, null_propagation) {
+                (Some(a), Some(b), _) => {
+                    if pick(&b, a) {
+                        *current = Some(b);
+                    }
+                }
+                (None, Some(b), NullPropagation::Skip) => *current = Some(b),
+                (Some(_), None, NullPropagation::Propagate) => *current = None,
+                _ => {}
+            }
+        }
+    }
+    result
+}
+
+fn primitive<T: ArrowPrimitiveType>(
+    arrays: &[&dyn Array],
+    null_propagation: NullPropagation,
+    ordering: Ordering,
+) -> ArrayRef {
+    let data_type = arrays[0].data_type().clone();
+    let arrays = arrays.iter().map(|a| a.as_primitive::<T>().iter());
+    let values = combine(arrays, null_propagation, |b, a| b.compare(*a) == ordering);
+    Arc::new(PrimitiveArray::<T>::from_iter(values).with_data_type(data_type))
+}
+
+fn bytes<T: ByteArrayType>(
+    arrays: &[&dyn Array],
+    null_propagation: NullPropagation,
+    ordering: Ordering,
+) -> ArrayRef {
+    let arrays = arrays.iter().map(|a| a.as_bytes::<T>().iter());
+    let values = combine(arrays, null_propagation, |b, a| {
+        AsRef::<[u8]>::as_ref(*b).cmp(AsRef::<[u8]>::as_ref(*a)) == ordering
+    });
+    Arc::new(GenericByteArray::<T>::from_iter(values))
+}
+
+fn byte_view<T: ByteViewType>(
+    arrays: &[&dyn Array],
+    null_propagation: NullPropagation,
+    ordering: Ordering,
+) -> ArrayRef {
+    let arrays = arrays.iter().map(|a| a.as_byte_view::<T>().iter());
+    let values = combine(arrays, null_propagation, |b, a| {
+        AsRef::<[u8]>::as_ref(*b).cmp(AsRef::<[u8]>::as_ref(*a)) == ordering
+    });
+    Arc::new(GenericByteViewArray::<T>::from_iter(values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_buffer::i256;
+
+    fn check(
+        arrays: &[&dyn Array],
+        null_propagation: NullPropagation,
+        expected_greatest: &dyn Array,
+        expected_least: &dyn Array,
+    ) {
+        let result = greatest(arrays, null_propagation).unwrap();
+        assert_eq!(result.as_ref(), expected_greatest);
+        let result = least(arrays, null_propagation).unwrap();
+        assert_eq!(result.as_ref(), expected_least);
+    }
+
+    #[test]
+    fn test_primitive() {
+        let a = Int64Array::from(vec![Some(1), Some(-5), None, None, Some(7)]);
+        let b = Int64Array::from(vec![Some(3), None, Some(2), None, Some(7)]);
+        let c = Int64Array::from(vec![Some(2), Some(0), Some(-1), None, Some(-7)]);
+        let arrays: [&dyn Array; 3] = [&a, &b, &c];
+
+        check(
+            &arrays,
+            NullPropagation::Propagate,
+            &Int64Array::from(vec![Some(3), None, None, None, Some(7)]),
+            &Int64Array::from(vec![Some(1), None, None, None, Some(-7)]),
+        );
+        check(
+            &arrays,
+            NullPropagation::Skip,
+            &Int64Array::from(vec![Some(3), Some(0), Some(2), None, Some(7)]),
+            &Int64Array::from(vec![Some(1), Some(-5), Some(-1), None, Some(-7)]),
+        );
+
+        // A single array is returned unchanged
+        check(&[&a], NullPropagation::Propagate, &a, &a);
+    }
+
+    #[test]
+    fn test_float() {
+        let a = Float64Array::from(vec![1.0, f64::NAN, -0.0, f64::NEG_INFINITY]);
+        let b = Float64Array::from(vec![2.0, 1.0, 0.0, -1.0]);
+        let result = greatest(&[&a, &b], NullPropagation::default()).unwrap();
+        let result = result.as_primitive::<Float64Type>();
+        assert_eq!(result.value(0), 2.0);
+        assert!(result.value(1).is_nan());
+        assert!(result.value(2).is_sign_positive());
+        assert_eq!(result.value(3), -1.0);
+
+        let result = least(&[&a, &b], NullPropagation::default()).unwrap();
+        let result = result.as_primitive::<Float64Type>();
+        assert_eq!(result.values(), &[1.0, 1.0, -0.0, f64::NEG_INFINITY]);
+        assert!(result.value(2).is_sign_negative());
+    }
+
+    #[test]
+    fn test_decimal_and_temporal() {
+        let a = Decimal256Array::from(vec![i256::from(-1), i256::from(10)])
+            .with_precision_and_scale(40, 2)
+            .unwrap();
+        let b = Decimal256Array::from(vec![i256::from(1), i256::from(-10)])
+            .with_precision_and_scale(40, 2)
+            .unwrap();
+        let result = greatest(&[&a, &b], NullPropagation::default()).unwrap();
+        assert_eq!(result.data_type(), &DataType::Decimal256(40, 2));
+        let result = result.as_primitive::<Decimal256Type>();
+        assert_eq!(result.values(), &[i256::from(1), i256::from(10)]);
+
+        let a = TimestampMillisecondArray::from(vec![Some(1), None]).with_timezone("+01:00");
+        let b = TimestampMillisecondArray::from(vec![Some(0), Some(5)]).with_timezone("+01:00");
+        check(
+            &[&a, &b],
+            NullPropagation::Skip,
+            &TimestampMillisecondArray::from(vec![1, 5]).with_timezone("+01:00"),
+            &TimestampMillisecondArray::from(vec![0, 5]).with_timezone("+01:00"),
+        );
+
+        let a = Date32Array::from(vec![10, 20]);
+        let b = Date32Array::from(vec![15, 15]);
+        check(
+            &[&a, &b],
+            NullPropagation::Propagate,
+            &Date32Array::from(vec![15, 20]),
+            &Date32Array::from(vec![10, 15]),
+        );
+    }
+
+    #[test]
+    fn test_boolean() {
+        let a = BooleanArray::from(vec![Some(true), Some(false), None]);
+        let b = BooleanArray::from(vec![Some(false), Some(false), Some(true)]);
+        check(
+            &[&a, &b],
+            NullPropagation::Propagate,
+            &BooleanArray::from(vec![Some(true), Some(false), None]),
+            &BooleanArray::from(vec![Some(false), Some(false), None]),
+        );
+        check(
+            &[&a, &b],
+            NullPropagation::Skip,
+            &BooleanArray::from(vec![true, false, true]),
+            &BooleanArray::from(vec![false, false, true]),
+        );
+    }
+
+    #[test]
+    fn test_strings() {
+        let a = vec![
+            Some("apple"),
+            None,
+            Some("b"),
+            Some("a longer string value"),
+        ];
+        let b = vec![Some("banana"), Some("x"), None, Some("a longer string")];
+        let greatest = vec![
+            Some("banana"),
+            Some("x"),
+            Some("b"),
+            Some("a longer string value"),
+        ];
+        let least = vec![Some("apple"), Some("x"), Some("b"), Some("a longer string")];
+
+        check(
+            &[&StringArray::from(a.clone()), &StringArray::from(b.clone())],
+            NullPropagation::Skip,
+            &StringArray::from(greatest.clone()),
+            &StringArray::from(least.clone()),
+        );
+        check(
+            &[
+                &LargeStringArray::from(a.clone()),
+                &LargeStringArray::from(b.clone()),
+            ],
+            NullPropagation::Skip,
+            &LargeStringArray::from(greatest.clone()),
+            &LargeStringArray::from(least.clone()),
+        );
+        check(
+            &[
+                &StringViewArray::from(a.clone()),
+                &StringViewArray::from(b.clone()),
+            ],
+            NullPropagation::Skip,
+            &StringViewArray::from(greatest),
+            &StringViewArray::from(least),
+        );
+
+        let a: Vec<_> = a.iter().map(|s| s.map(str::as_bytes)).collect();
+        let b: Vec<_> = b.iter().map(|s| s.map(str::as_bytes)).collect();
+        check(
+            &[&BinaryArray::from(a.clone()), &BinaryArray::from(b.clone())],
+            NullPropagation::Propagate,
+            &BinaryArray::from(vec![Some(b"banana".as_slice()), None, None, a[3]]),
+            &BinaryArray::from(vec![Some(b"apple".as_slice()), None, None, b[3]]),
+        );
+        check(
+            &[
+                &BinaryViewArray::from(a.clone()),
+                &BinaryViewArray::from(b.clone()),
+            ],
+            NullPropagation::Propagate,
+            &BinaryViewArray::from(vec![Some(b"banana".as_slice()), None, None, a[3]]),
+            &BinaryViewArray::from(vec![Some(b"apple".as_slice()), None, None, b[3]]),
+        );
+    }
+
+    #[test]
+    fn test_fixed_size_binary() {
+        let a = FixedSizeBinaryArray::from(vec![Some(b"ab".as_slice()), None]);
+        let b = FixedSizeBinaryArray::from(vec![Some(b"ba".as_slice()), Some(b"cc".as_slice())]);
+        check(
+            &[&a, &b],
+            NullPropagation::Skip,
+            &FixedSizeBinaryArray::from(vec![b"ba".as_slice(), b"cc".as_slice()]),
+            &FixedSizeBinaryArray::from(vec![b"ab".as_slice(), b"cc".as_slice()]),
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = greatest(&[], NullPropagation::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: greatest requires at least one array"
+        );
+
+        let a = Int32Array::from(vec![1, 2]);
+        let b = Int64Array::from(vec![1, 2]);
+        let err = least(&[&a, &b], NullPropagation::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: least requires arrays of the same data type, got Int32 and Int64"
+        );
+
+        let b = Int32Array::from(vec![1]);
+        let err = greatest(&[&a, &b], NullPropagation::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: greatest requires arrays of the same length, got 2 and 1"
+        );
+
+        let a = NullArray::new(2);
+        let err = greatest(&[&a], NullPropagation::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: greatest does not support data type Null"
+        );
+    }
+}
@@ -21,11 +21,14 @@ use std::sync::Arc;
 
 use arrow_array::cast::AsArray;
 use cast::as_primitive_array;
-use chrono::{Datelike, TimeZone, Timelike, Utc};
+use chrono::{
+    Datelike, Days, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Timelike,
+    Utc,
+};
 
 use arrow_array::temporal_conversions::{
     MICROSECONDS, MICROSECONDS_IN_DAY, MILLISECONDS, MILLISECONDS_IN_DAY, NANOSECONDS,
-    NANOSECONDS_IN_DAY, SECONDS_IN_DAY, date32_to_datetime, date64_to_datetime,
+    NANOSECONDS_IN_DAY, SECONDS_IN_DAY, as_datetime, date32_to_datetime, date64_to_datetime,
     timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_s_to_datetime,
     timestamp_us_to_datetime,
 };
@@ -649,6 +652,221 @@ impl ExtractDatePartExt for PrimitiveArray<DurationNanosecondType> {
     }
 }
 
+/// Granularities to truncate date and timestamp arrays to.
+///
+/// See [`date_trunc`].
+///
+/// Marked as non-exhaustive as may expand to support more granularities
+/// in the future.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateTruncGranularity {
+    /// Midnight on the first day of the year
+    Year,
+    /// Midnight on the first day of the quarter
+    Quarter,
+    /// Midnight on the first day of the month
+    Month,
+    /// Midnight on the Monday of the ISO 8601 week
+    Week,
+    /// Midnight of the day
+    Day,
+    /// Start of the hour
+    Hour,
+    /// Start of the minute
+    Minute,
+    /// Start of the second
+    Second,
+}
+
+impl std::fmt::Display for DateTruncGranularity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl DateTruncGranularity {
+    /// Returns the width of this granularity in units of `unit`, if all buckets
+    /// have the same width when ignoring timezones
+    fn fixed_width(&self, unit: TimeUnit) -> Option<i64> {
+        let seconds = match self {
+            Self::Day => SECONDS_IN_DAY,
+            Self::Hour => 3_600,
+            Self::Minute => 60,
+            Self::Second => 1,
+            Self::Year | Self::Quarter | Self::Month | Self::Week => return None,
+        };
+        let per_second = match unit {
+            TimeUnit::Second => 1,
+            TimeUnit::Millisecond => MILLISECONDS,
+            TimeUnit::Microsecond => MICROSECONDS,
+            TimeUnit::Nanosecond => NANOSECONDS,
+        };
+        Some(seconds * per_second)
+    }
+
+    fn trunc_date(&self, d: NaiveDate) -> Option<NaiveDate> {
+        match self {
+            Self::Year => NaiveDate::from_ymd_opt(d.year(), 1, 1),
+            Self::Quarter => NaiveDate::from_ymd_opt(d.year(), (d.month0() / 3) * 3 + 1, 1),
+            Self::Month => NaiveDate::from_ymd_opt(d.year(), d.month(), 1),
+            Self::Week => d.checked_sub_days(Days::new(d.num_days_from_monday() as u64)),
+            Self::Day | Self::Hour | Self::Minute | Self::Second => Some(d),
+        }
+    }
+
+    fn trunc_datetime(&self, dt: NaiveDateTime) -> Option<NaiveDateTime> {
+        let time = match self {
+            Self::Hour => NaiveTime::from_hms_opt(dt.hour(), 0, 0)?,
+            Self::Minute => NaiveTime::from_hms_opt(dt.hour(), dt.minute(), 0)?,
+            // Leap seconds are represented as a nanosecond value of at least one second
+            Self::Second => NaiveTime::from_hms_opt(dt.hour(), dt.minute(), dt.second())?,
+            Self::Year | Self::Quarter | Self::Month | Self::Week | Self::Day => NaiveTime::MIN,
+        };
+        Some(self.trunc_date(dt.date())?.and_time(time))
+    }
+}
+
+/// Given an array, return a new array with each value truncated to the start of the
+/// bucket of the given [`DateTruncGranularity`] containing it, for example the first
+/// day of its month.
+///
+/// Currently only supports temporal types:
+///   - Date32/Date64
+///   - Timestamp
+///
+/// Returns an array of the same type as the input, including its timezone. Timestamps
+/// with a timezone are truncated in the local time of that timezone, so for example
+/// truncating to [`DateTruncGranularity::Day`] returns local midnight, even on days
+/// where a daylight saving time transition occurs. If the truncated local time is
+/// ambiguous, the latest instant that is not after the input is returned, and if it
+/// does not exist, the instant at which the transition occurs is returned. Values that
+/// cannot be truncated without overflowing are null.
+///
+/// Dictionary arrays are supported, with this function applied onto their values.
+///
+/// If array passed in is not of the above listed types (or is a dictionary array where the
+/// values array isn't of the above listed types), then this function will return an error.
+///
+/// # Examples
+///
+/// ```
+/// # use arrow_array::TimestampSecondArray;
+/// # use arrow_arith::temporal::{DateTruncGranularity, date_trunc};
+/// // 2021-06-15T13:45:30Z and 1969-12-31T23:59:59Z
+/// let input = TimestampSecondArray::from(vec![Some(1623764730), None, Some(-1)]);
+///
+/// let month = date_trunc(&input, DateTruncGranularity::Month).unwrap();
+/// // 2021-06-01T00:00:00Z and 1969-12-01T00:00:00Z
+/// let expected = TimestampSecondArray::from(vec![Some(1622505600), None, Some(-2678400)]);
+/// assert_eq!(month.as_ref(), &expected);
+///
+/// // Truncated in the local time of the timezone, 2021-06-15T00:00:00+10:00
+/// let input = input.with_timezone("+10:00");
+/// let day = date_trunc(&input, DateTruncGranularity::Day).unwrap();
+/// let expected = TimestampSecondArray::from(vec![Some(1623679200), None, Some(-36000)])
+///     .with_timezone("+10:00");
+/// assert_eq!(day.as_ref(), &expected);
+/// ```
+pub fn date_trunc(
+    array: &dyn Array,
+    granularity: DateTruncGranularity,
+) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        DataType::Date32 => Ok(Arc::new(date32_trunc(array.as_primitive(), granularity))),
+        DataType::Date64 => Ok(Arc::new(date64_trunc(array.as_primitive(), granularity))),
+        DataType::Timestamp(TimeUnit::Second, _) => {
+            let array = array.as_primitive::<TimestampSecondType>();
+            Ok(Arc::new(timestamp_trunc(array, granularity)?))
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            let array = array.as_primitive::<TimestampMillisecondType>();
+            Ok(Arc::new(timestamp_trunc(array, granularity)?))
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            let array = array.as_primitive::<TimestampMicrosecondType>();
+            Ok(Arc::new(timestamp_trunc(array, granularity)?))
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            let array = array.as_primitive::<TimestampNanosecondType>();
+            Ok(Arc::new(timestamp_trunc(array, granularity)?))
+        }
+        DataType::Dictionary(_, _) => {
+            let array = array.as_any_dictionary();
+            let values = date_trunc(array.values(), granularity)?;
+            Ok(array.with_values(values))
+        }
+        t => return_compute_error_with!(format!("{granularity} does not support"), t),
+    }
+}
+
+fn date32_trunc(
+    array: &PrimitiveArray<Date32Type>,
+    granularity: DateTruncGranularity,
+) -> PrimitiveArray<Date32Type> {
+    array.unary_opt(|d| {
+        let date = granularity.trunc_date(Date32Type::to_naive_date_opt(d)?)?;
+        Some(Date32Type::from_naive_date(date))
+    })
+}
+
+fn date64_trunc(
+    array: &PrimitiveArray<Date64Type>,
+    granularity: DateTruncGranularity,
+) -> PrimitiveArray<Date64Type> {
+    match granularity.fixed_width(TimeUnit::Millisecond) {
+        Some(width) => array.unary(|v| v - v.rem_euclid(width)),
+        None => array.unary_opt(|v| {
+            let dt = granularity.trunc_datetime(date64_to_datetime(v)?)?;
+            TimestampMillisecondType::from_naive_datetime(dt, None)
+        }),
+    }
+}
+
+fn timestamp_trunc<T: ArrowTimestampType>(
+    array: &PrimitiveArray<T>,
+    granularity: DateTruncGranularity,
+) -> Result<PrimitiveArray<T>, ArrowError> {
+    let truncated = match get_tz(array.data_type())? {
+        Some(tz) => array.unary_opt(|v| {
+            let utc = as_datetime::<T>(v)?;
+            let local = utc.checked_add_offset(tz.offset_from_utc_datetime(&utc).fix())?;
+            let truncated = local_to_utc(&tz, granularity.trunc_datetime(local)?, utc)?;
+            T::from_naive_datetime(truncated, None)
+        }),
+        None => match granularity.fixed_width(T::UNIT) {
+            // Euclidean remainder rounds values before the epoch down, not towards zero
+            Some(width) => array.unary(|v| v - v.rem_euclid(width)),
+            None => array.unary_opt(|v| {
+                let truncated = granularity.trunc_datetime(as_datetime::<T>(v)?)?;
+                T::from_naive_datetime(truncated, None)
+            }),
+        },
+    };
+    Ok(truncated.with_data_type(array.data_type().clone()))
+}
+
+/// Converts `local`, the truncation of the local time of the UTC instant `utc`,
+/// back to UTC
+fn local_to_utc(tz: &Tz, local: NaiveDateTime, utc: NaiveDateTime) -> Option<NaiveDateTime> {
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(dt) => Some(dt.naive_utc()),
+        // Within a repeated hour, truncation should not move to the earlier occurrence
+        // unless the input was within it
+        LocalResult::Ambiguous(earliest, latest) => match latest.naive_utc() <= utc {
+            true => Some(latest.naive_utc()),
+            false => Some(earliest.naive_utc()),
+        },
+        // The local time was skipped by a transition, which occurs at that local time
+        // in the offset in effect before it
+        LocalResult::None => {
+            let before = local.checked_sub_days(Days::new(1))?;
+            let offset = tz.offset_from_utc_datetime(&before).fix();
+            local.checked_sub_offset(offset)
+        }
+    }
+}
+
 macro_rules! return_compute_error_with {
     ($msg:expr, $param:expr) => {
         return { Err(ArrowError::ComputeError(format!("{}: {}", $msg, $param))) }
@@ -2040,4 +2258,201 @@ mod tests {
         assert_eq!(2015, actual.value(1));
         assert_eq!(2016, actual.value(2));
     }
+
+    /// Returns the nanoseconds since the epoch of an RFC 3339 timestamp
+    fn nanos(s: &str) -> i64 {
+        chrono::DateTime::parse_from_rfc3339(s)
+            .unwrap()
+            .timestamp_nanos_opt()
+            .unwrap()
+    }
+
+    fn check_timestamp_trunc<T: ArrowTimestampType>(
+        input: &str,
+        granularity: DateTruncGranularity,
+        expected: &str,
+        tz: Option<&str>,
+    ) {
+        let divisor = match T::UNIT {
+            TimeUnit::Second => 1_000_000_000,
+            TimeUnit::Millisecond => 1_000_000,
+            TimeUnit::Microsecond => 1_000,
+            TimeUnit::Nanosecond => 1,
+        };
+        let input = PrimitiveArray::<T>::from_iter([Some(nanos(input).div_euclid(divisor)), None])
+            .with_timezone_opt(tz);
+        let expected =
+            PrimitiveArray::<T>::from_iter([Some(nanos(expected).div_euclid(divisor)), None])
+                .with_timezone_opt(tz);
+        let actual = date_trunc(&input, granularity).unwrap();
+        assert_eq!(
+            actual.as_primitive::<T>(),
+            &expected,
+            "{granularity} of {:?}",
+            input.data_type()
+        );
+    }
+
+    fn check_trunc(
+        input: &str,
+        granularity: DateTruncGranularity,
+        expected: &str,
+        tz: Option<&str>,
+    ) {
+        check_timestamp_trunc::<TimestampSecondType>(input, granularity, expected, tz);
+        check_timestamp_trunc::<TimestampMillisecondType>(input, granularity, expected, tz);
+        check_timestamp_trunc::<TimestampMicrosecondType>(input, granularity, expected, tz);
+        check_timestamp_trunc::<TimestampNanosecondType>(input, granularity, expected, tz);
+    }
+
+    #[test]
+    fn test_date_trunc_timestamp() {
+        use DateTruncGranularity::*;
+        // A Thursday
+        let input = "2021-08-19T13:45:30.123456789Z";
+        check_trunc(input, Year, "2021-01-01T00:00:00Z", None);
+        check_trunc(input, Quarter, "2021-07-01T00:00:00Z", None);
+        check_trunc(input, Month, "2021-08-01T00:00:00Z", None);
+        check_trunc(input, Week, "2021-08-16T00:00:00Z", None);
+        check_trunc(input, Day, "2021-08-19T00:00:00Z", None);
+        check_trunc(input, Hour, "2021-08-19T13:00:00Z", None);
+        check_trunc(input, Minute, "2021-08-19T13:45:00Z", None);
+        check_trunc(input, Second, "2021-08-19T13:45:30Z", None);
+
+        // The first of each bucket is unchanged
+        check_trunc("2021-01-01T00:00:00Z", Year, "2021-01-01T00:00:00Z", None);
+        check_trunc("2021-08-16T00:00:00Z", Week, "2021-08-16T00:00:00Z", None);
+        // Weeks start on Monday, so Sunday is in the previous week
+        check_trunc("2021-08-22T23:59:59Z", Week, "2021-08-16T00:00:00Z", None);
+        check_trunc(
+            "2021-12-31T23:59:59Z",
+            Quarter,
+            "2021-10-01T00:00:00Z",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_date_trunc_timestamp_before_epoch() {
+        use DateTruncGranularity::*;
+        // Values before the epoch must round down, not towards zero
+        let input = "1969-12-31T23:59:59.999Z";
+        check_trunc(input, Year, "1969-01-01T00:00:00Z", None);
+        check_trunc(input, Quarter, "1969-10-01T00:00:00Z", None);
+        check_trunc(input, Month, "1969-12-01T00:00:00Z", None);
+        check_trunc(input, Week, "1969-12-29T00:00:00Z", None);
+        check_trunc(input, Day, "1969-12-31T00:00:00Z", None);
+        check_trunc(input, Hour, "1969-12-31T23:00:00Z", None);
+        check_trunc(input, Minute, "1969-12-31T23:59:00Z", None);
+        check_trunc(input, Second, "1969-12-31T23:59:59Z", None);
+
+        let input = "1900-03-15T06:07:08.5Z";
+        check_trunc(input, Week, "1900-03-12T00:00:00Z", None);
+        check_trunc(input, Day, "1900-03-15T00:00:00Z", None);
+        check_trunc(input, Second, "1900-03-15T06:07:08Z", None);
+    }
+
+    #[test]
+    fn test_date_trunc_timestamp_with_offset_timezone() {
+        use DateTruncGranularity::*;
+        // Truncated in local time, 2021-01-01T05:45:00+05:45
+        let tz = Some("+05:45");
+        let input = "2021-01-01T00:00:00Z";
+        check_trunc(input, Hour, "2021-01-01T05:00:00+05:45", tz);
+        check_trunc(input, Day, "2021-01-01T00:00:00+05:45", tz);
+        check_trunc(input, Minute, input, tz);
+
+        // 2020-12-31T19:00:00-10:00
+        let tz = Some("-10:00");
+        let input = "2021-01-01T05:00:00Z";
+        check_trunc(input, Year, "2020-01-01T00:00:00-10:00", tz);
+        check_trunc(input, Week, "2020-12-28T00:00:00-10:00", tz);
+        check_trunc(input, Day, "2020-12-31T00:00:00-10:00", tz);
+    }
+
+    #[test]
+    fn test_date_trunc_timestamp_overflow() {
+        let input = TimestampSecondArray::from(vec![i64::MAX, i64::MIN, 0]);
+        let actual = date_trunc(&input, DateTruncGranularity::Month).unwrap();
+        let expected = TimestampSecondArray::from(vec![None, None, Some(0)]);
+        assert_eq!(actual.as_primitive::<TimestampSecondType>(), &expected);
+    }
+
+    #[test]
+    fn test_date_trunc_date() {
+        use DateTruncGranularity::*;
+        let date = |s: &str| Date32Type::from_naive_date(s.parse().unwrap());
+        let input = Date32Array::from(vec![
+            Some(date("1969-12-31")),
+            None,
+            Some(date("2021-08-19")),
+        ]);
+
+        let cases = [
+            (Year, ["1969-01-01", "2021-01-01"]),
+            (Quarter, ["1969-10-01", "2021-07-01"]),
+            (Month, ["1969-12-01", "2021-08-01"]),
+            (Week, ["1969-12-29", "2021-08-16"]),
+            (Day, ["1969-12-31", "2021-08-19"]),
+            (Hour, ["1969-12-31", "2021-08-19"]),
+        ];
+        for (granularity, [a, b]) in cases {
+            let actual = date_trunc(&input, granularity).unwrap();
+            let expected = Date32Array::from(vec![Some(date(a)), None, Some(date(b))]);
+            assert_eq!(
+                actual.as_primitive::<Date32Type>(),
+                &expected,
+                "{granularity}"
+            );
+        }
+
+        let date = |s: &str| Date64Type::from_naive_date(s.parse().unwrap());
+        // One millisecond before the epoch
+        let input = Date64Array::from(vec![Some(-1), None, Some(date("2021-08-19"))]);
+        let cases = [
+            (Quarter, ["1969-10-01", "2021-07-01"]),
+            (Week, ["1969-12-29", "2021-08-16"]),
+            (Day, ["1969-12-31", "2021-08-19"]),
+        ];
+        for (granularity, [a, b]) in cases {
+            let actual = date_trunc(&input, granularity).unwrap();
+            let expected = Date64Array::from(vec![Some(date(a)), None, Some(date(b))]);
+            assert_eq!(
+                actual.as_primitive::<Date64Type>(),
+                &expected,
+                "{granularity}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_date_trunc_dictionary() {
+        let values =
+            TimestampSecondArray::from(vec![nanos("2021-08-19T13:45:30Z") / 1_000_000_000])
+                .with_timezone("+01:00");
+        let keys = Int8Array::from(vec![Some(0), None, Some(0)]);
+        let input = DictionaryArray::new(keys, Arc::new(values));
+
+        let actual = date_trunc(&input, DateTruncGranularity::Day).unwrap();
+        assert_eq!(actual.data_type(), input.data_type());
+        let actual = actual.as_dictionary::<Int8Type>();
+        let expected =
+            TimestampSecondArray::from(vec![nanos("2021-08-19T00:00:00+01:00") / 1_000_000_000])
+                .with_timezone("+01:00");
+        assert_eq!(
+            actual.values().as_primitive::<TimestampSecondType>(),
+            &expected
+        );
+        assert_eq!(actual.keys(), input.keys());
+    }
+
+    #[test]
+    fn test_date_trunc_unsupported() {
+        let input = Int32Array::from(vec![1]);
+        let err = date_trunc(&input, DateTruncGranularity::Day).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Day does not support: Int32"
+        );
+    }
 }
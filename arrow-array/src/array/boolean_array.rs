@@ -22,8 +22,9 @@ use crate::{Array, ArrayAccessor, ArrayRef, Scalar};
 use arrow_buffer::bit_chunk_iterator::UnalignedBitChunk;
 use arrow_buffer::{BooleanBuffer, Buffer, MutableBuffer, NullBuffer, bit_util};
 use arrow_data::{ArrayData, ArrayDataBuilder};
-use arrow_schema::DataType;
+use arrow_schema::{ArrowError, DataType};
 use std::any::Any;
+use std::ops::Range;
 use std::sync::Arc;
 
 /// An array of [boolean values](https://arrow.apache.org/docs/format/Columnar.html#fixed-size-primitive-layout)
@@ -260,6 +261,59 @@ impl BooleanArray {
         }
     }
 
+    /// Returns the contiguous, non-empty, ranges of non-null `true` values in ascending order
+    ///
+    /// This is the inverse of [`Self::from_set_ranges`], and can be used to convert a
+    /// filter mask into a list of selected row ranges
+    pub fn to_set_ranges(&self) -> Vec<Range<usize>> {
+        match self.nulls() {
+            Some(nulls) => (nulls.inner() & self.values()).set_ranges().collect(),
+            None => self.values().set_ranges().collect(),
+        }
+    }
+
+    /// Creates a [`BooleanArray`] of length `len` without nulls, that is `true` only for
+    /// indices within `ranges`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ranges` are not in ascending order, overlap, or extend beyond `len`
+    pub fn from_set_ranges<I: IntoIterator<Item = Range<usize>>>(ranges: I, len: usize) -> Self {
+        BooleanBuffer::from_set_ranges(ranges, len).into()
+    }
+
+    /// Creates a [`BooleanArray`] without nulls that is `true` wherever any of `masks`
+    /// contains a non-null `true` value, i.e. the logical OR of `masks` with nulls
+    /// treated as `false`
+    ///
+    /// This processes the input a `u64` word at a time into a single output allocation,
+    /// and so is more efficient than repeatedly applying the `or` kernel to many masks
+    ///
+    /// Returns an error if `masks` do not all have the same length
+    pub fn from_unioned(masks: &[&BooleanArray]) -> Result<Self, ArrowError> {
+        let len = masks.first().map(|m| m.len()).unwrap_or_default();
+        if let Some(m) = masks.iter().find(|m| m.len() != len) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot union boolean arrays of different lengths, got {len} and {}",
+                m.len()
+            )));
+        }
+
+        let mut words = vec![0_u64; len.div_ceil(64)];
+        for mask in masks {
+            let values = mask.values().bit_chunks().iter_padded();
+            match mask.nulls() {
+                Some(nulls) => {
+                    let nulls = nulls.inner().bit_chunks().iter_padded();
+                    let chunks = nulls.zip(values).map(|(n, v)| n & v);
+                    words.iter_mut().zip(chunks).for_each(|(w, c)| *w |= c);
+                }
+                None => words.iter_mut().zip(values).for_each(|(w, c)| *w |= c),
+            }
+        }
+        Ok(BooleanBuffer::new(Buffer::from_vec(words), 0, len).into())
+    }
+
     /// Returns the boolean value at index `i`.
     ///
     /// Note: This method does not check for nulls and the value is arbitrary
@@ -1062,4 +1116,49 @@ mod tests {
             assert_eq!(arr.has_false(), expected_has_false, "len={len}");
         }
     }
+
+    #[test]
+    fn test_set_ranges_round_trip() {
+        let mut rng = rng();
+        for len in [0, 1, 64, 100, 1000] {
+            let mask: BooleanArray = (0..len)
+                .map(|_| rng.random_bool(0.8).then(|| rng.random_bool(0.5)))
+                .collect();
+            let ranges = mask.to_set_ranges();
+            for (i, v) in mask.iter().enumerate() {
+                assert_eq!(ranges.iter().any(|r| r.contains(&i)), v == Some(true));
+            }
+
+            let round_trip = BooleanArray::from_set_ranges(ranges.clone(), len);
+            assert_eq!(round_trip.null_count(), 0);
+            assert_eq!(round_trip.true_count(), mask.true_count());
+            assert_eq!(round_trip.to_set_ranges(), ranges);
+        }
+
+        let mask = BooleanArray::from(vec![Some(true), None, Some(true), Some(true), Some(false)]);
+        assert_eq!(mask.to_set_ranges(), vec![0..1, 2..4]);
+        assert_eq!(mask.slice(1, 4).to_set_ranges(), vec![1..3]);
+    }
+
+    #[test]
+    fn test_from_unioned() {
+        let a = BooleanArray::from(vec![Some(true), None, Some(false), Some(false)]);
+        let b = BooleanArray::from(vec![None, Some(false), Some(false), Some(true)]);
+        let c = BooleanArray::from(vec![false, true, false, false]);
+
+        let result = BooleanArray::from_unioned(&[&a, &b, &c]).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![true, true, false, true]));
+
+        let result = BooleanArray::from_unioned(&[&a.slice(1, 3), &b.slice(1, 3)]).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![false, false, true]));
+
+        let result = BooleanArray::from_unioned(&[]).unwrap();
+        assert!(result.is_empty());
+
+        let err = BooleanArray::from_unioned(&[&a, &c.slice(0, 3)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot union boolean arrays of different lengths, got 4 and 3"
+        );
+    }
 }
@@ -0,0 +1,317 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Contains the `LazyArrayRef` type.
+
+use crate::{Array, ArrayRef};
+use arrow_buffer::NullBuffer;
+use arrow_data::ArrayData;
+use arrow_schema::{ArrowError, DataType};
+use std::any::Any;
+use std::sync::{Arc, OnceLock};
+
+/// A function that computes the contents of a [`LazyArrayRef`]
+pub type LazyArrayFn = Arc<dyn Fn() -> Result<ArrayRef, ArrowError> + Send + Sync>;
+
+/// An [`Array`] whose contents are computed on first access
+///
+/// This is useful for virtual or computed columns, where materializing the column
+/// may be expensive and may not be necessary, for example if a subsequent filter
+/// eliminates all rows.
+///
+/// The result of the first successful invocation of the function is memoized, and
+/// returned by all subsequent accesses, including those of any clones. All [`Array`] methods, including
+/// [`Array::as_any`], delegate to the materialized array, and so downcasting a
+/// [`LazyArrayRef`] yields the concrete type of the computed array.
+///
+/// # Panics
+///
+/// As the methods of [`Array`] are infallible, any [`Array`] method will panic if the
+/// function returns an error. Use [`LazyArrayRef::try_materialize`] to handle errors.
+///
+/// # Example
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{Array, ArrayRef, Int32Array, LazyArrayRef};
+/// let lazy = LazyArrayRef::new(|| Ok(Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef));
+/// assert!(!lazy.is_materialized());
+///
+/// // Accessing the array computes it
+/// assert_eq!(lazy.len(), 3);
+/// assert!(lazy.is_materialized());
+/// let ints = lazy.as_any().downcast_ref::<Int32Array>().unwrap();
+/// assert_eq!(ints.value(1), 2);
+/// ```
+#[derive(Clone)]
+pub struct LazyArrayRef {
+    f: LazyArrayFn,
+    array: Arc<OnceLock<ArrayRef>>,
+}
+
+impl LazyArrayRef {
+    /// Create a new [`LazyArrayRef`] that computes its contents by calling `f`
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn() -> Result<ArrayRef, ArrowError> + Send + Sync + 'static,
+    {
+        Self::new_from_fn(Arc::new(f))
+    }
+
+    /// Create a new [`LazyArrayRef`] from a shared [`LazyArrayFn`]
+    pub fn new_from_fn(f: LazyArrayFn) -> Self {
+        Self {
+            f,
+            array: Arc::new(OnceLock::new()),
+        }
+    }
+
+    /// Returns `true` if the array has been computed
+    pub fn is_materialized(&self) -> bool {
+        self.array.get().is_some()
+    }
+
+    /// Computes the array if it has not already been computed, returning a
+    /// reference to the result
+    ///
+    /// If the function returns an error, the error is returned and the array
+    /// remains unmaterialized, with the function invoked again on the next access
+    pub fn try_materialize(&self) -> Result<&ArrayRef, ArrowError> {
+        if let Some(array) = self.array.get() {
+            return Ok(array);
+        }
+        let array = (self.f)()?;
+        // If another thread raced us, keep the first value to be stored
+        Ok(self.array.get_or_init(|| array))
+    }
+
+    /// Computes the array if it has not already been computed, returning a
+    /// reference to the result
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function returns an error
+    pub fn materialize(&self) -> &ArrayRef {
+        match self.try_materialize() {
+            Ok(array) => array,
+            Err(e) => panic!("Failed to materialize LazyArrayRef: {e}"),
+        }
+    }
+
+    /// Computes the array if necessary and returns it, consuming `self`
+    pub fn into_inner(self) -> Result<ArrayRef, ArrowError> {
+        self.try_materialize().cloned()
+    }
+}
+
+impl std::fmt::Debug for LazyArrayRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.array.get() {
+            Some(array) => f.debug_tuple("LazyArrayRef").field(array).finish(),
+            None => write!(f, "LazyArrayRef(<unmaterialized>)"),
+        }
+    }
+}
+
+/// SAFETY: all methods delegate to the materialized [`ArrayRef`]
+unsafe impl Array for LazyArrayRef {
+    fn as_any(&self) -> &dyn Any {
+        self.materialize().as_any()
+    }
+
+    fn to_data(&self) -> ArrayData {
+        self.materialize().to_data()
+    }
+
+    fn into_data(self) -> ArrayData {
+        self.to_data()
+    }
+
+    fn data_type(&self) -> &DataType {
+        self.materialize().data_type()
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> ArrayRef {
+        self.materialize().slice(offset, length)
+    }
+
+    fn len(&self) -> usize {
+        self.materialize().len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.materialize().is_empty()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        if let Some(array) = Arc::get_mut(&mut self.array).and_then(OnceLock::get_mut) {
+            array.shrink_to_fit();
+        }
+    }
+
+    fn offset(&self) -> usize {
+        self.materialize().offset()
+    }
+
+    fn nulls(&self) -> Option<&NullBuffer> {
+        self.materialize().nulls()
+    }
+
+    fn logical_nulls(&self) -> Option<NullBuffer> {
+        self.materialize().logical_nulls()
+    }
+
+    fn is_null(&self, index: usize) -> bool {
+        self.materialize().is_null(index)
+    }
+
+    fn is_valid(&self, index: usize) -> bool {
+        self.materialize().is_valid(index)
+    }
+
+    fn null_count(&self) -> usize {
+        self.materialize().null_count()
+    }
+
+    fn logical_null_count(&self) -> usize {
+        self.materialize().logical_null_count()
+    }
+
+    fn is_nullable(&self) -> bool {
+        self.materialize().is_nullable()
+    }
+
+    fn get_buffer_memory_size(&self) -> usize {
+        self.materialize().get_buffer_memory_size()
+    }
+
+    fn get_array_memory_size(&self) -> usize {
+        self.materialize().get_array_memory_size()
+    }
+
+    #[cfg(feature = "pool")]
+    fn claim(&self, pool: &dyn arrow_buffer::MemoryPool) {
+        self.materialize().claim(pool)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cast::AsArray;
+    use crate::types::Int32Type;
+    use crate::{Int32Array, RecordBatch, StringArray};
+    use arrow_schema::{Field, Schema};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn counting(calls: Arc<AtomicUsize>) -> LazyArrayRef {
+        LazyArrayRef::new(move || {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Ok(Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])) as ArrayRef)
+        })
+    }
+
+    #[test]
+    fn test_lazy_not_computed_until_accessed() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let lazy = counting(calls.clone());
+        assert!(!lazy.is_materialized());
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        assert_eq!(format!("{lazy:?}"), "LazyArrayRef(<unmaterialized>)");
+
+        // Dropping without access never invokes the function
+        drop(lazy.clone());
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_lazy_memoized() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let lazy = counting(calls.clone());
+
+        assert_eq!(lazy.len(), 3);
+        assert!(lazy.is_materialized());
+        assert_eq!(lazy.null_count(), 1);
+        assert!(lazy.is_null(1));
+        assert!(lazy.is_valid(2));
+        assert_eq!(lazy.data_type(), &DataType::Int32);
+        assert_eq!(lazy.offset(), 0);
+        assert!(lazy.nulls().is_some());
+        assert!(lazy.get_array_memory_size() > 0);
+
+        // Clones share the memoized result
+        let cloned = lazy.clone();
+        assert!(cloned.is_materialized());
+        assert_eq!(cloned.len(), 3);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_lazy_delegates() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let lazy = counting(calls.clone());
+
+        // Downcasting yields the concrete computed type
+        let expected = Int32Array::from(vec![Some(1), None, Some(3)]);
+        assert_eq!(lazy.as_any().downcast_ref::<Int32Array>(), Some(&expected));
+        assert_eq!(lazy.materialize().as_primitive::<Int32Type>(), &expected);
+        assert_eq!(lazy.to_data(), expected.to_data());
+
+        let sliced = lazy.slice(1, 2);
+        assert_eq!(sliced.as_primitive::<Int32Type>(), &expected.slice(1, 2));
+
+        let data = lazy.clone().into_data();
+        assert_eq!(data, expected.to_data());
+        assert_eq!(format!("{lazy:?}"), format!("LazyArrayRef({expected:?})"));
+
+        // Can be used anywhere an ArrayRef is accepted
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let batch = RecordBatch::try_new(schema, vec![Arc::new(lazy) as ArrayRef]).unwrap();
+        assert_eq!(batch.num_rows(), 3);
+        assert_eq!(batch.column(0).as_primitive::<Int32Type>(), &expected);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_lazy_error() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let captured = calls.clone();
+        let lazy = LazyArrayRef::new(move || {
+            if captured.fetch_add(1, Ordering::SeqCst) == 0 {
+                return Err(ArrowError::ComputeError("not yet".to_string()));
+            }
+            Ok(Arc::new(StringArray::from(vec!["a"])) as ArrayRef)
+        });
+
+        let err = lazy.try_materialize().unwrap_err();
+        assert_eq!(err.to_string(), "Compute error: not yet");
+        assert!(!lazy.is_materialized());
+
+        // Errors are not memoized
+        assert_eq!(lazy.try_materialize().unwrap().len(), 1);
+        assert!(lazy.is_materialized());
+        assert_eq!(lazy.into_inner().unwrap().as_string::<i32>().value(0), "a");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to materialize LazyArrayRef: Compute error: failed")]
+    fn test_lazy_error_panics() {
+        let lazy = LazyArrayRef::new(|| Err(ArrowError::ComputeError("failed".to_string())));
+        lazy.len();
+    }
+}
@@ -76,6 +76,10 @@ mod list_view_array;
 
 pub use list_view_array::*;
 
+mod lazy_array;
+
+pub use lazy_array::*;
+
 use crate::iterator::ArrayIter;
 
 /// An array in the [Arrow Columnar Format](https://arrow.apache.org/docs/format/Columnar.html)
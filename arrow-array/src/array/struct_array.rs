@@ -20,6 +20,7 @@ use crate::{Array, ArrayRef, RecordBatch, make_array, new_null_array};
 use arrow_buffer::{BooleanBuffer, Buffer, NullBuffer};
 use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::{ArrowError, DataType, Field, FieldRef, Fields};
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::{any::Any, ops::Index};
 
@@ -327,6 +328,57 @@ impl StructArray {
             .map(|pos| self.column(pos))
     }
 
+    /// Combines the fields of this array and `other` into a new [`StructArray`]
+    ///
+    /// The fields of `other` follow those of `self`, and the child arrays are reused
+    /// without copying. A row of the returned array is null if it is null in either
+    /// `self` or `other`.
+    ///
+    /// # Errors
+    ///
+    /// Errors if
+    ///
+    /// * `self.len() != other.len()`
+    /// * The combined fields contain duplicate names
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{Array, ArrayRef, Int32Array, StringArray, StructArray};
+    /// # use arrow_buffer::NullBuffer;
+    /// # use arrow_schema::{DataType, Field};
+    /// let a = StructArray::from(vec![(
+    ///     Arc::new(Field::new("a", DataType::Int32, false)),
+    ///     Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+    /// )]);
+    /// let b = StructArray::new(
+    ///     vec![Field::new("b", DataType::Utf8, false)].into(),
+    ///     vec![Arc::new(StringArray::from(vec!["x", "y", "z"]))],
+    ///     Some(NullBuffer::from(vec![true, false, true])),
+    /// );
+    ///
+    /// let zipped = a.zip(&b).unwrap();
+    /// assert_eq!(zipped.column_names(), ["a", "b"]);
+    /// assert_eq!(zipped.logical_null_count(), 1);
+    /// ```
+    pub fn zip(&self, other: &StructArray) -> Result<Self, ArrowError> {
+        if self.len != other.len {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot zip StructArrays of different lengths, got {} and {}",
+                self.len, other.len
+            )));
+        }
+
+        let fields = zip_fields(self.fields(), other.fields())?;
+        let arrays = self.fields.iter().chain(&other.fields).cloned().collect();
+        let nulls = NullBuffer::union(self.nulls.as_ref(), other.nulls.as_ref());
+
+        // SAFETY: the child arrays of both inputs have the same length and match their
+        // fields, and `nulls` masks at least the nulls of both inputs
+        Ok(unsafe { Self::new_unchecked_with_length(fields, arrays, nulls, self.len) })
+    }
+
     /// Returns a zero-copy slice of this array with the indicated offset and length.
     pub fn slice(&self, offset: usize, len: usize) -> Self {
         assert!(
@@ -345,6 +397,20 @@ impl StructArray {
     }
 }
 
+/// Returns the concatenation of `a` and `b`, erroring if it contains duplicate names
+pub(crate) fn zip_fields(a: &Fields, b: &Fields) -> Result<Fields, ArrowError> {
+    let mut names = HashSet::with_capacity(a.len() + b.len());
+    for f in a.iter().chain(b.iter()) {
+        if !names.insert(f.name()) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot zip fields with duplicate name {:?}",
+                f.name()
+            )));
+        }
+    }
+    Ok(a.iter().chain(b.iter()).cloned().collect())
+}
+
 impl From<ArrayData> for StructArray {
     fn from(data: ArrayData) -> Self {
         let (data_type, len, nulls, offset, _buffers, child_data) = data.into_parts();
@@ -958,4 +1024,59 @@ mod tests {
 
         StructArray::try_new(fields, arrays, nulls).expect("should not error");
     }
+    #[test]
+    fn test_struct_array_zip() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3, 4]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c", "d"]));
+        let left = StructArray::new(
+            vec![Field::new("a", DataType::Int32, false)].into(),
+            vec![a.clone()],
+            Some(NullBuffer::from(vec![true, false, true, true])),
+        );
+        let right = StructArray::new(
+            vec![Field::new("b", DataType::Utf8, false)].into(),
+            vec![b.clone()],
+            Some(NullBuffer::from(vec![true, true, false, true])),
+        );
+
+        let zipped = left.zip(&right).unwrap();
+        assert_eq!(zipped.len(), 4);
+        assert_eq!(zipped.column_names(), ["a", "b"]);
+        assert!(Arc::ptr_eq(zipped.column(0), &a));
+        assert!(Arc::ptr_eq(zipped.column(1), &b));
+        let nulls: Vec<_> = zipped.nulls().unwrap().iter().collect();
+        assert_eq!(nulls, [true, false, false, true]);
+
+        // Nulls of one side only
+        let no_nulls = StructArray::new(
+            vec![Field::new("c", DataType::Utf8, false)].into(),
+            vec![b.clone()],
+            None,
+        );
+        let zipped = no_nulls.zip(&left).unwrap();
+        assert_eq!(zipped.column_names(), ["c", "a"]);
+        assert_eq!(zipped.nulls(), left.nulls());
+        assert_eq!(
+            no_nulls.zip(&no_nulls.slice(0, 4)).unwrap_err().to_string(),
+            "Invalid argument error: Cannot zip fields with duplicate name \"c\""
+        );
+
+        // Sliced inputs
+        let zipped = left.slice(1, 2).zip(&right.slice(2, 2)).unwrap();
+        let nulls: Vec<_> = zipped.nulls().unwrap().iter().collect();
+        assert_eq!(nulls, [false, true]);
+        assert_eq!(zipped.column(0).as_ref(), &a.slice(1, 2));
+        assert_eq!(zipped.column(1).as_ref(), &b.slice(2, 2));
+
+        // No fields
+        let empty = StructArray::new_empty_fields(4, None);
+        let zipped = empty.zip(&left).unwrap();
+        assert_eq!(zipped, left);
+
+        let err = left.zip(&right.slice(0, 3)).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: Cannot zip StructArrays of different lengths, got 4 and 3"
+        );
+    }
 }
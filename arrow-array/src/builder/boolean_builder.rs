@@ -229,6 +229,11 @@ impl ArrayBuilder for BooleanBuilder {
     fn finish_cloned(&self) -> ArrayRef {
         Arc::new(self.finish_cloned())
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.values_builder.shrink_to_fit();
+        self.null_buffer_builder.shrink_to_fit();
+    }
 }
 
 impl Extend<Option<bool>> for BooleanBuilder {
@@ -183,6 +183,11 @@ impl ArrayBuilder for FixedSizeBinaryBuilder {
     fn finish_cloned(&self) -> ArrayRef {
         Arc::new(self.finish_cloned())
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.values_builder.shrink_to_fit();
+        self.null_buffer_builder.shrink_to_fit();
+    }
 }
 
 #[cfg(test)]
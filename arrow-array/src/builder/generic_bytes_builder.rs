@@ -226,6 +226,16 @@ impl<T: ByteArrayType> GenericByteBuilder<T> {
         GenericByteArray::from(array_data)
     }
 
+    /// Returns the number of items this builder can hold without reallocating
+    pub fn capacity(&self) -> usize {
+        self.offsets_builder.capacity().saturating_sub(1)
+    }
+
+    /// Returns the number of bytes of data this builder can hold without reallocating
+    pub fn data_capacity(&self) -> usize {
+        self.value_builder.capacity()
+    }
+
     /// Returns the current values buffer as a slice
     pub fn values_slice(&self) -> &[u8] {
         self.value_builder.as_slice()
@@ -280,6 +290,12 @@ impl<T: ByteArrayType> ArrayBuilder for GenericByteBuilder<T> {
         Arc::new(self.finish_cloned())
     }
 
+    fn shrink_to_fit(&mut self) {
+        self.value_builder.shrink_to_fit();
+        self.offsets_builder.shrink_to_fit();
+        self.null_buffer_builder.shrink_to_fit();
+    }
+
     /// Returns the builder as a non-mutable `Any` reference.
     fn as_any(&self) -> &dyn Any {
         self
@@ -971,4 +987,24 @@ mod tests {
         assert_eq!("world", array.value(3));
         assert!(array.is_null(4));
     }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut builder = GenericStringBuilder::<i32>::with_capacity(1024, 4096);
+        builder.append_value("hello");
+        builder.append_null();
+        assert!(builder.capacity() >= 1024);
+        assert!(builder.data_capacity() >= 4096);
+
+        builder.shrink_to_fit();
+        assert_eq!(builder.capacity(), 2);
+        assert_eq!(builder.data_capacity(), 5);
+
+        builder.append_value("world");
+        let array = builder.finish();
+        assert_eq!(
+            array,
+            GenericStringArray::<i32>::from(vec![Some("hello"), None, Some("world")])
+        );
+    }
 }
@@ -561,6 +561,13 @@ impl<T: ByteViewType + ?Sized> ArrayBuilder for GenericByteViewBuilder<T> {
         Arc::new(self.finish_cloned())
     }
 
+    fn shrink_to_fit(&mut self) {
+        self.views_buffer.shrink_to_fit();
+        self.in_progress.shrink_to_fit();
+        self.completed.shrink_to_fit();
+        self.null_buffer_builder.shrink_to_fit();
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
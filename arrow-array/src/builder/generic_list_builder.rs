@@ -118,6 +118,11 @@ impl<OffsetSize: OffsetSizeTrait, T: ArrayBuilder> GenericListBuilder<OffsetSize
         }
     }
 
+    /// Returns the number of lists this builder can hold without reallocating
+    pub fn capacity(&self) -> usize {
+        self.offsets_builder.capacity().saturating_sub(1)
+    }
+
     /// Override the field passed to [`GenericListArray::new`]
     ///
     /// By default a nullable field is created with the name `item`
@@ -166,6 +171,12 @@ where
     fn finish_cloned(&self) -> ArrayRef {
         Arc::new(self.finish_cloned())
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.offsets_builder.shrink_to_fit();
+        self.values_builder.shrink_to_fit();
+        self.null_buffer_builder.shrink_to_fit();
+    }
 }
 
 impl<OffsetSize: OffsetSizeTrait, T: ArrayBuilder> GenericListBuilder<OffsetSize, T>
@@ -818,4 +829,22 @@ mod tests {
         builder.append_value([Some(1)]);
         builder.finish();
     }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut builder = ListBuilder::with_capacity(Int32Builder::with_capacity(1024), 1024);
+        builder.append_value([Some(1), Some(2)]);
+        builder.append_null();
+        assert!(builder.capacity() >= 1024);
+        assert!(builder.values().capacity() >= 1024);
+
+        builder.shrink_to_fit();
+        assert_eq!(builder.capacity(), 2);
+        assert_eq!(builder.values().capacity(), 2);
+
+        let array = builder.finish();
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.value(0).as_primitive::<Int32Type>().values(), &[1, 2]);
+        assert!(array.is_null(1));
+    }
 }
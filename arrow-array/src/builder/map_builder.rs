@@ -287,6 +287,13 @@ impl<K: ArrayBuilder, V: ArrayBuilder> ArrayBuilder for MapBuilder<K, V> {
         Arc::new(self.finish_cloned())
     }
 
+    fn shrink_to_fit(&mut self) {
+        self.offsets_builder.shrink_to_fit();
+        self.key_builder.shrink_to_fit();
+        self.value_builder.shrink_to_fit();
+        self.null_buffer_builder.shrink_to_fit();
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -261,6 +261,8 @@ mod primitive_run_builder;
 pub use primitive_run_builder::*;
 mod struct_builder;
 pub use struct_builder::*;
+mod record_batch_builder;
+pub use record_batch_builder::*;
 mod generic_bytes_dictionary_builder;
 pub use generic_bytes_dictionary_builder::*;
 mod generic_byte_run_builder;
@@ -341,6 +343,12 @@ pub trait ArrayBuilder: Any + Send + Sync {
     /// Builds the array without resetting the underlying builder.
     fn finish_cloned(&self) -> ArrayRef;
 
+    /// Shrinks the capacity of the builder's internal buffers as much as possible,
+    /// freeing memory reserved for values that were never appended.
+    ///
+    /// The default implementation does nothing.
+    fn shrink_to_fit(&mut self) {}
+
     /// Returns the builder as a non-mutable `Any` reference.
     ///
     /// This is most useful when one wants to call non-mutable APIs on a specific builder
@@ -376,6 +384,10 @@ impl ArrayBuilder for Box<dyn ArrayBuilder> {
         (**self).finish_cloned()
     }
 
+    fn shrink_to_fit(&mut self) {
+        (**self).shrink_to_fit()
+    }
+
     fn as_any(&self) -> &dyn Any {
         (**self).as_any()
     }
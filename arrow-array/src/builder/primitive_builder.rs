@@ -133,6 +133,11 @@ impl<T: ArrowPrimitiveType> ArrayBuilder for PrimitiveBuilder<T> {
     fn finish_cloned(&self) -> ArrayRef {
         Arc::new(self.finish_cloned())
     }
+
+    fn shrink_to_fit(&mut self) {
+        self.values_builder.shrink_to_fit();
+        self.null_buffer_builder.shrink_to_fit();
+    }
 }
 
 impl<T: ArrowPrimitiveType> Default for PrimitiveBuilder<T> {
@@ -722,4 +727,19 @@ mod tests {
         let mut builder = Decimal128Builder::new().with_data_type(DataType::Decimal128(2, 3));
         builder.append_array(&array)
     }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut builder = Int32Builder::with_capacity(1024);
+        builder.append_value(1);
+        builder.append_null();
+        builder.append_value(3);
+        assert!(builder.capacity() >= 1024);
+
+        builder.shrink_to_fit();
+        assert_eq!(builder.capacity(), 3);
+
+        let array = builder.finish();
+        assert_eq!(array, Int32Array::from(vec![Some(1), None, Some(3)]));
+    }
 }
@@ -0,0 +1,298 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::builder::*;
+use crate::{RecordBatch, RecordBatchOptions};
+use arrow_schema::{ArrowError, SchemaRef};
+
+/// Builder for [`RecordBatch`]
+///
+/// Holds an [`ArrayBuilder`] for each column of a [`Schema`], allowing a [`RecordBatch`]
+/// to be built one column at a time. The builder for each column can be obtained with
+/// [`RecordBatchBuilder::column_builder`], in the same way as for [`StructBuilder`].
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::builder::{Float64Builder, RecordBatchBuilder, StringBuilder};
+/// # use arrow_array::Float64Array;
+/// # use arrow_schema::{DataType, Field, Schema};
+/// let schema = Arc::new(Schema::new(vec![
+///     Field::new("city", DataType::Utf8, false),
+///     Field::new("temperature", DataType::Float64, true),
+/// ]));
+/// let mut builder = RecordBatchBuilder::from_schema(schema, 3);
+///
+/// let cities = builder.column_builder::<StringBuilder>(0).unwrap();
+/// cities.extend(["Paris", "Oslo", "Lima"].map(Some));
+///
+/// let temperatures = builder.column_builder::<Float64Builder>(1).unwrap();
+/// temperatures.extend([Some(18.5), None, Some(21.0)]);
+///
+/// let batch = builder.finish().unwrap();
+/// assert_eq!(batch.num_rows(), 3);
+/// assert_eq!(
+///     batch.column(1).as_ref(),
+///     &Float64Array::from(vec![Some(18.5), None, Some(21.0)])
+/// );
+/// ```
+///
+/// [`Schema`]: arrow_schema::Schema
+pub struct RecordBatchBuilder {
+    schema: SchemaRef,
+    column_builders: Vec<Box<dyn ArrayBuilder>>,
+}
+
+impl std::fmt::Debug for RecordBatchBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecordBatchBuilder")
+            .field("schema", &self.schema)
+            .field("num_columns", &self.num_columns())
+            .finish()
+    }
+}
+
+impl RecordBatchBuilder {
+    /// Creates a new `RecordBatchBuilder` from a [`SchemaRef`] and a builder for each column
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of builders does not match the number of fields in `schema`
+    pub fn new(schema: SchemaRef, column_builders: Vec<Box<dyn ArrayBuilder>>) -> Self {
+        assert_eq!(
+            schema.fields().len(),
+            column_builders.len(),
+            "Number of fields is not equal to the number of column_builders"
+        );
+        Self {
+            schema,
+            column_builders,
+        }
+    }
+
+    /// Creates a new `RecordBatchBuilder` from a [`SchemaRef`], with the builder for each
+    /// column pre-allocated to hold `row_capacity` rows
+    ///
+    /// See [`make_builder`] for the builder created for each column
+    pub fn from_schema(schema: SchemaRef, row_capacity: usize) -> Self {
+        let column_builders = schema
+            .fields()
+            .iter()
+            .map(|f| make_builder(f.data_type(), row_capacity))
+            .collect();
+        Self::new(schema, column_builders)
+    }
+
+    /// Returns a mutable reference to the builder for column `i`
+    ///
+    /// Result will be `None` if the input type `T` provided doesn't match the actual
+    /// column builder's type.
+    pub fn column_builder<T: ArrayBuilder>(&mut self, i: usize) -> Option<&mut T> {
+        self.column_builders[i].as_any_mut().downcast_mut::<T>()
+    }
+
+    /// Returns a reference to the column builders
+    pub fn column_builders(&self) -> &[Box<dyn ArrayBuilder>] {
+        &self.column_builders
+    }
+
+    /// Returns a mutable reference to the column builders
+    pub fn column_builders_mut(&mut self) -> &mut [Box<dyn ArrayBuilder>] {
+        &mut self.column_builders
+    }
+
+    /// Returns the schema of the [`RecordBatch`] this builder is building
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Returns the number of columns of the [`RecordBatch`] this builder is building
+    pub fn num_columns(&self) -> usize {
+        self.column_builders.len()
+    }
+
+    /// Builds the [`RecordBatch`] and resets this builder
+    ///
+    /// Returns an error, without resetting this builder, if the column builders have
+    /// different lengths. Also returns an error if the columns are otherwise invalid for
+    /// the schema, such as containing nulls in a non-nullable column, in which case the
+    /// builder is reset and its contents discarded.
+    pub fn finish(&mut self) -> Result<RecordBatch, ArrowError> {
+        let row_count = self.validate_lengths()?;
+        let columns = self.column_builders.iter_mut().map(|b| b.finish());
+        let options = RecordBatchOptions::new().with_row_count(Some(row_count));
+        RecordBatch::try_new_with_options(self.schema.clone(), columns.collect(), &options)
+    }
+
+    /// Builds the [`RecordBatch`] without resetting this builder
+    ///
+    /// Returns an error under the same conditions as [`Self::finish`]
+    pub fn finish_cloned(&self) -> Result<RecordBatch, ArrowError> {
+        let row_count = self.validate_lengths()?;
+        let columns = self.column_builders.iter().map(|b| b.finish_cloned());
+        let options = RecordBatchOptions::new().with_row_count(Some(row_count));
+        RecordBatch::try_new_with_options(self.schema.clone(), columns.collect(), &options)
+    }
+
+    /// Returns the number of rows of the column builders, or an error if they have
+    /// different lengths
+    fn validate_lengths(&self) -> Result<usize, ArrowError> {
+        let Some(first) = self.column_builders.first() else {
+            return Ok(0);
+        };
+        let len = first.len();
+        for (idx, builder) in self.column_builders.iter().enumerate().skip(1) {
+            if builder.len() != len {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "RecordBatchBuilder column builders are of unequal lengths: column 0 ({}) has {} rows, column {} ({}) has {} rows",
+                    self.schema.field(0).name(),
+                    len,
+                    idx,
+                    self.schema.field(idx).name(),
+                    builder.len()
+                )));
+            }
+        }
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cast::AsArray;
+    use crate::types::{Int32Type, TimestampMillisecondType};
+    use crate::{Int32Array, StringArray, TimestampMillisecondArray};
+    use arrow_schema::{DataType, Field, Fields, Schema, TimeUnit};
+    use std::sync::Arc;
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+            Field::new(
+                "c",
+                DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".into())),
+                true,
+            ),
+            Field::new_struct(
+                "d",
+                Fields::from(vec![Field::new("e", DataType::Int32, true)]),
+                true,
+            ),
+        ]))
+    }
+
+    fn append_rows(builder: &mut RecordBatchBuilder, n: i32) {
+        let a = builder.column_builder::<Int32Builder>(0).unwrap();
+        a.append_slice(&(0..n).collect::<Vec<_>>());
+
+        let b = builder.column_builder::<StringBuilder>(1).unwrap();
+        (0..n).for_each(|i| b.append_option((i % 2 == 0).then(|| i.to_string())));
+
+        let c = builder
+            .column_builder::<TimestampMillisecondBuilder>(2)
+            .unwrap();
+        c.append_slice(&(0..n as i64).collect::<Vec<_>>());
+
+        let d = builder.column_builder::<StructBuilder>(3).unwrap();
+        let e = d.field_builder::<Int32Builder>(0).unwrap();
+        (0..n).for_each(|i| e.append_value(i * 10));
+        d.append_non_nulls(n as usize);
+    }
+
+    #[test]
+    fn test_record_batch_builder() {
+        let mut builder = RecordBatchBuilder::from_schema(schema(), 4);
+        assert_eq!(builder.num_columns(), 4);
+        assert!(builder.column_builder::<StringBuilder>(0).is_none());
+
+        append_rows(&mut builder, 4);
+        let batch = builder.finish_cloned().unwrap();
+        assert_eq!(batch.num_rows(), 4);
+        assert_eq!(batch.schema(), schema());
+        assert_eq!(
+            batch.column(0).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![0, 1, 2, 3])
+        );
+        assert_eq!(
+            batch.column(1).as_string::<i32>(),
+            &StringArray::from(vec![Some("0"), None, Some("2"), None])
+        );
+        assert_eq!(
+            batch.column(2).as_primitive::<TimestampMillisecondType>(),
+            &TimestampMillisecondArray::from(vec![0, 1, 2, 3]).with_timezone("+01:00")
+        );
+        let d = batch.column(3).as_struct();
+        assert_eq!(
+            d.column(0).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![0, 10, 20, 30])
+        );
+
+        // The builder is reset by finish and can be reused
+        assert_eq!(builder.finish().unwrap(), batch);
+        assert_eq!(builder.column_builders()[0].len(), 0);
+        append_rows(&mut builder, 2);
+        assert_eq!(builder.finish().unwrap().num_rows(), 2);
+        assert_eq!(builder.finish().unwrap().num_rows(), 0);
+    }
+
+    #[test]
+    fn test_record_batch_builder_unequal_lengths() {
+        let mut builder = RecordBatchBuilder::from_schema(schema(), 4);
+        append_rows(&mut builder, 2);
+        builder
+            .column_builder::<StringBuilder>(1)
+            .unwrap()
+            .append_value("x");
+
+        let err = builder.finish().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: RecordBatchBuilder column builders are of unequal lengths: column 0 (a) has 2 rows, column 1 (b) has 3 rows"
+        );
+        // The builder is not reset on error
+        assert_eq!(builder.column_builders()[1].len(), 3);
+    }
+
+    #[test]
+    fn test_record_batch_builder_non_nullable() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let mut builder = RecordBatchBuilder::from_schema(schema, 4);
+        let a = builder.column_builder::<Int32Builder>(0).unwrap();
+        a.extend([Some(1), None]);
+
+        let err = builder.finish().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Column 'a' is declared as non-nullable but contains null values"
+        );
+    }
+
+    #[test]
+    fn test_record_batch_builder_empty_schema() {
+        let mut builder = RecordBatchBuilder::from_schema(Arc::new(Schema::empty()), 4);
+        let batch = builder.finish().unwrap();
+        assert_eq!(batch.num_columns(), 0);
+        assert_eq!(batch.num_rows(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of fields is not equal to the number of column_builders")]
+    fn test_record_batch_builder_mismatched_builders() {
+        RecordBatchBuilder::new(schema(), vec![Box::new(Int32Builder::new())]);
+    }
+}
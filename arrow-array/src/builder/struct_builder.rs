@@ -135,6 +135,13 @@ impl ArrayBuilder for StructBuilder {
         Arc::new(self.finish_cloned())
     }
 
+    fn shrink_to_fit(&mut self) {
+        self.field_builders
+            .iter_mut()
+            .for_each(|b| b.shrink_to_fit());
+        self.null_buffer_builder.shrink_to_fit();
+    }
+
     /// Returns the builder as a non-mutable `Any` reference.
     ///
     /// This is most useful when one wants to call non-mutable APIs on a specific builder
@@ -789,4 +796,22 @@ mod tests {
         assert_eq!(arr.len(), 1);
         assert_eq!(arr.null_count(), 0);
     }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let fields = vec![Field::new("a", DataType::Int32, true)];
+        let mut builder = StructBuilder::from_fields(fields, 1024);
+        builder
+            .field_builder::<Int32Builder>(0)
+            .unwrap()
+            .append_value(1);
+        builder.append(true);
+
+        builder.shrink_to_fit();
+        let a = builder.field_builder::<Int32Builder>(0).unwrap();
+        assert_eq!(a.capacity(), 1);
+
+        let arr = builder.finish();
+        assert_eq!(arr.len(), 1);
+    }
 }
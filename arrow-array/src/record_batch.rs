@@ -18,6 +18,7 @@
 //! A two-dimensional batch of column-oriented data with a defined
 //! [schema](arrow_schema::Schema).
 
+use crate::array::zip_fields;
 use crate::cast::AsArray;
 use crate::{Array, ArrayRef, StructArray, new_empty_array};
 use arrow_schema::{ArrowError, DataType, Field, FieldRef, Schema, SchemaBuilder, SchemaRef};
@@ -479,6 +480,55 @@ impl RecordBatch {
         }
     }
 
+    /// Combines the columns of this batch and `other` into a new [`RecordBatch`]
+    ///
+    /// The columns of `other` follow those of `self`, and are reused without copying.
+    /// The schema metadata of both batches is merged, preferring the values of `self`
+    /// for keys present in both.
+    ///
+    /// See also [`StructArray::zip`]
+    ///
+    /// # Errors
+    ///
+    /// Errors if
+    ///
+    /// * `self.num_rows() != other.num_rows()`
+    /// * The combined columns contain duplicate names
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+    /// let a = RecordBatch::try_from_iter([
+    ///     ("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+    /// ]).unwrap();
+    /// let b = RecordBatch::try_from_iter([
+    ///     ("b", Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef),
+    /// ]).unwrap();
+    ///
+    /// let zipped = a.zip(&b).unwrap();
+    /// assert_eq!(zipped.num_columns(), 2);
+    /// assert_eq!(zipped.schema().field(1).name(), "b");
+    /// ```
+    pub fn zip(&self, other: &RecordBatch) -> Result<RecordBatch, ArrowError> {
+        if self.row_count != other.row_count {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot zip RecordBatches with different numbers of rows, got {} and {}",
+                self.row_count, other.row_count
+            )));
+        }
+
+        let fields = zip_fields(self.schema.fields(), other.schema.fields())?;
+        let mut metadata = other.schema.metadata().clone();
+        metadata.extend(self.schema.metadata().clone());
+        let schema = Arc::new(Schema::new_with_metadata(fields, metadata));
+        let columns = self.columns.iter().chain(&other.columns).cloned().collect();
+
+        // SAFETY: the columns of both batches have `row_count` rows and match their fields
+        Ok(unsafe { RecordBatch::new_unchecked(schema, columns, self.row_count) })
+    }
+
     /// Normalize a semi-structured [`RecordBatch`] into a flat table.
     ///
     /// Nested [`Field`]s will generate names separated by `separator`, up to a depth of `max_level`
@@ -821,6 +871,19 @@ impl RecordBatch {
             .map(|array| array.get_array_memory_size())
             .sum()
     }
+
+    /// Shrinks the capacity of the buffers of every column as much as possible,
+    /// freeing unused memory.
+    ///
+    /// See [`Array::shrink_to_fit`] for more details. Columns whose [`ArrayRef`] is
+    /// shared with other owners are left untouched.
+    pub fn shrink_to_fit(&mut self) {
+        for column in &mut self.columns {
+            if let Some(array) = Arc::get_mut(column) {
+                array.shrink_to_fit();
+            }
+        }
+    }
 }
 
 /// Options that control the behaviour used when creating a [`RecordBatch`].
@@ -970,6 +1033,8 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::builder::Int32Builder;
+    use crate::types::Int32Type;
     use crate::{
         BooleanArray, Int8Array, Int32Array, Int64Array, ListArray, StringArray, StringViewArray,
     };
@@ -1771,4 +1836,70 @@ mod tests {
             "bar"
         );
     }
+    #[test]
+    fn test_zip() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        let metadata = |entries: &[(&str, &str)]| {
+            entries
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<HashMap<_, _>>()
+        };
+        let left_schema = Schema::new(vec![Field::new("a", DataType::Int32, false)])
+            .with_metadata(metadata(&[("k", "left")]));
+        let left = RecordBatch::try_new(Arc::new(left_schema), vec![a.clone()]).unwrap();
+        let right_schema = Schema::new(vec![Field::new("b", DataType::Utf8, false)])
+            .with_metadata(metadata(&[("k", "right"), ("r", "right")]));
+        let right = RecordBatch::try_new(Arc::new(right_schema), vec![b.clone()]).unwrap();
+
+        let zipped = left.zip(&right).unwrap();
+        assert_eq!(zipped.num_rows(), 3);
+        assert_eq!(zipped.schema().field(0), left.schema().field(0));
+        assert_eq!(zipped.schema().field(1), right.schema().field(0));
+        assert!(Arc::ptr_eq(zipped.column(0), &a));
+        assert!(Arc::ptr_eq(zipped.column(1), &b));
+        assert_eq!(zipped.schema().metadata()["k"], "left");
+        assert_eq!(zipped.schema().metadata()["r"], "right");
+
+        // No columns
+        let empty = RecordBatch::try_new_with_options(
+            Arc::new(Schema::empty()),
+            vec![],
+            &RecordBatchOptions::new().with_row_count(Some(3)),
+        )
+        .unwrap();
+        let zipped = empty.zip(&empty).unwrap();
+        assert_eq!(zipped.num_columns(), 0);
+        assert_eq!(zipped.num_rows(), 3);
+
+        let err = left.zip(&left).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: Cannot zip fields with duplicate name \"a\""
+        );
+
+        let err = left.zip(&right.slice(0, 2)).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: Cannot zip RecordBatches with different numbers of rows, got 3 and 2"
+        );
+    }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut builder = Int32Builder::with_capacity(1024);
+        builder.append_slice(&[1, 2, 3]);
+        let a: ArrayRef = Arc::new(builder.finish());
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let mut batch = RecordBatch::try_new(schema, vec![a]).unwrap();
+        let before = batch.get_array_memory_size();
+
+        batch.shrink_to_fit();
+        assert!(batch.get_array_memory_size() < before);
+        assert_eq!(
+            batch.column(0).as_primitive::<Int32Type>().values(),
+            &[1, 2, 3]
+        );
+    }
 }
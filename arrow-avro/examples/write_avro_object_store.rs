@@ -0,0 +1,88 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! # Stream Avro into object storage with the async writer
+//!
+//! This example writes Arrow `RecordBatch`es as an **Avro Object Container File**
+//! to an [`object_store`] location using `arrow_avro::writer::AsyncAvroWriter`.
+//!
+//! ## What this example does
+//! - Wraps an `object_store::buffered::BufWriter`, which implements
+//!   `tokio::io::AsyncWrite` and switches to a multipart upload once enough data
+//!   has been buffered.
+//! - Writes several batches with Zstandard block compression, encoding and
+//!   compressing each batch on tokio's blocking thread pool.
+//! - Reads the object back to verify the number of rows written.
+//!
+//! An in-memory store is used so the example runs without credentials; any other
+//! `ObjectStore`, such as Amazon S3, can be substituted.
+
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringArray};
+use arrow_avro::compression::CompressionCodec;
+use arrow_avro::reader::ReaderBuilder;
+use arrow_avro::writer::WriterBuilder;
+use arrow_avro::writer::format::AvroOcfFormat;
+use arrow_schema::{DataType, Field, Schema};
+use object_store::buffered::BufWriter;
+use object_store::memory::InMemory;
+use object_store::path::Path;
+use object_store::{ObjectStore, ObjectStoreExt};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("name", DataType::Utf8, true),
+    ]);
+
+    let store: Arc<dyn ObjectStore> = Arc::new(InMemory::new());
+    let path = Path::from("events/part-0.avro");
+
+    // `BufWriter` uploads in parts as data arrives, so memory use stays bounded
+    // by the part size rather than the size of the object
+    let sink = BufWriter::new(Arc::clone(&store), path.clone());
+    let mut writer = WriterBuilder::new(schema.clone())
+        .with_compression(Some(CompressionCodec::ZStandard))
+        .build_async::<_, AvroOcfFormat>(sink)?
+        .with_spawn_blocking(true);
+
+    let schema = Arc::new(schema);
+    for chunk in 0..10_i64 {
+        let ids = Int64Array::from_iter_values(chunk * 1000..(chunk + 1) * 1000);
+        let names =
+            StringArray::from_iter_values(ids.values().iter().map(|id| format!("event-{id}")));
+        let batch = RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![Arc::new(ids) as ArrayRef, Arc::new(names) as ArrayRef],
+        )?;
+        // Awaiting the write applies back-pressure from the upload
+        writer.write(&batch).await?;
+    }
+    // Completes the multipart upload
+    writer.finish().await?;
+
+    let bytes = store.get(&path).await?.bytes().await?;
+    let reader = ReaderBuilder::new().build(Cursor::new(bytes))?;
+    let mut rows = 0;
+    for batch in reader {
+        rows += batch?.num_rows();
+    }
+    println!("Wrote {rows} rows to {path}");
+    Ok(())
+}
@@ -19,7 +19,8 @@
 
 use crate::schema::{
     AVRO_ENUM_SYMBOLS_METADATA_KEY, AVRO_FIELD_DEFAULT_METADATA_KEY, AVRO_NAME_METADATA_KEY,
-    AVRO_NAMESPACE_METADATA_KEY, Array, Attributes, ComplexType, Enum, Fixed, Map, Nullability,
+    AVRO_NAMESPACE_METADATA_KEY, AVRO_NON_RECORD_ROOT_FIELD_NAME, AVRO_ROOT_RECORD_DEFAULT_NAME,
+    Array, Attributes, ComplexType, Enum, Field as AvroFieldSchema, Fixed, Map, Nullability,
     PrimitiveType, Record, Schema, Type, TypeName, make_full_name,
 };
 use arrow_schema::{
@@ -30,6 +31,7 @@ use arrow_schema::{
 use arrow_schema::{DECIMAL32_MAX_PRECISION, DECIMAL64_MAX_PRECISION};
 use indexmap::IndexMap;
 use serde_json::Value;
+use std::borrow::Cow;
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 use std::fmt;
@@ -124,6 +126,8 @@ pub(crate) enum Promotion {
     StringToBytes,
     /// Promotes `bytes` to a `string`.
     BytesToString,
+    /// Promotes `bytes` to a `string`, replacing invalid UTF-8 with `U+FFFD`.
+    BytesToStringLossy,
 }
 
 impl Display for Promotion {
@@ -138,6 +142,7 @@ impl Display for Promotion {
             Self::FloatToDouble => write!(formatter, "Float->Double"),
             Self::StringToBytes => write!(formatter, "String->Bytes"),
             Self::BytesToString => write!(formatter, "Bytes->String"),
+            Self::BytesToStringLossy => write!(formatter, "Bytes->String (lossy)"),
         }
     }
 }
@@ -316,7 +321,7 @@ impl AvroDataType {
         // Handle JSON nulls per-spec: allowed only for `null` type or unions with null FIRST
         if default_json.is_null() {
             return match self.codec() {
-                Codec::Null => Ok(AvroLiteral::Null),
+                Codec::Null | Codec::RecursionLimit(_) => Ok(AvroLiteral::Null),
                 Codec::Union(encodings, _, _) if !encodings.is_empty()
                     && matches!(encodings[0].codec(), Codec::Null) =>
                     {
@@ -330,7 +335,7 @@ impl AvroDataType {
             };
         }
         let lit = match self.codec() {
-            Codec::Null => {
+            Codec::Null | Codec::RecursionLimit(_) => {
                 return Err(ArrowError::SchemaError(
                     "Default for `null` type must be JSON null".to_string(),
                 ));
@@ -558,7 +563,7 @@ impl AvroDataType {
 
     fn store_default(&mut self, default_json: &Value) -> Result<(), ArrowError> {
         let json_text = serde_json::to_string(default_json).map_err(|e| {
-            ArrowError::ParseError(format!("Failed to serialize default to JSON: {e}"))
+            ArrowError::SchemaError(format!("Failed to serialize default to JSON: {e}"))
         })?;
         self.metadata
             .insert(AVRO_FIELD_DEFAULT_METADATA_KEY.to_string(), json_text);
@@ -628,7 +633,7 @@ impl<'a> TryFrom<&Schema<'a>> for AvroField {
                     name: r.name.to_string(),
                 })
             }
-            _ => Err(ArrowError::ParseError(format!(
+            _ => Err(ArrowError::SchemaError(format!(
                 "Expected record got {schema:?}"
             ))),
         }
@@ -643,6 +648,10 @@ pub(crate) struct AvroFieldBuilder<'a> {
     use_utf8view: bool,
     strict_mode: bool,
     tz: Tz,
+    root_field_name: &'a str,
+    field_types: Option<&'a IndexMap<String, DataType>>,
+    replace_invalid_utf8: bool,
+    max_recursion_depth: usize,
 }
 
 impl<'a> AvroFieldBuilder<'a> {
@@ -654,6 +663,10 @@ impl<'a> AvroFieldBuilder<'a> {
             use_utf8view: false,
             strict_mode: false,
             tz: Tz::default(),
+            root_field_name: AVRO_NON_RECORD_ROOT_FIELD_NAME,
+            field_types: None,
+            replace_invalid_utf8: false,
+            max_recursion_depth: DEFAULT_MAX_RECURSION_DEPTH,
         }
     }
 
@@ -685,19 +698,66 @@ impl<'a> AvroFieldBuilder<'a> {
         self
     }
 
+    /// Sets the name of the single field used when the root schema is not a record.
+    pub(crate) fn with_root_field_name(mut self, root_field_name: &'a str) -> Self {
+        self.root_field_name = root_field_name;
+        self
+    }
+
+    /// Sets the Arrow [`DataType`] to read the named fields of the root record as,
+    /// overriding the type derived from their Avro type.
+    pub(crate) fn with_field_types(mut self, field_types: &'a IndexMap<String, DataType>) -> Self {
+        self.field_types = Some(field_types);
+        self
+    }
+
+    /// Replace invalid UTF-8 with `U+FFFD` when reading `bytes` as a string,
+    /// rather than returning an error.
+    pub(crate) fn with_replace_invalid_utf8(mut self, replace_invalid_utf8: bool) -> Self {
+        self.replace_invalid_utf8 = replace_invalid_utf8;
+        self
+    }
+
+    /// Sets the number of times a recursive record may be nested within itself.
+    ///
+    /// References to a recursive record nested more deeply than this map to
+    /// [`Codec::RecursionLimit`].
+    pub(crate) fn with_max_recursion_depth(mut self, max_recursion_depth: usize) -> Self {
+        self.max_recursion_depth = max_recursion_depth;
+        self
+    }
+
     /// Build an [`AvroField`] from the builder
+    ///
+    /// A root schema that is not a record (e.g. an array, map, union or primitive) is
+    /// wrapped in a synthetic record with a single field named `root_field_name`. A
+    /// record with one field has the same binary encoding as the field's value, so
+    /// the wrapped schema decodes and encodes exactly like the bare one.
     pub(crate) fn build(self) -> Result<AvroField, ArrowError> {
-        match self.writer_schema {
+        let writer_schema = wrap_non_record_root(self.writer_schema, self.root_field_name);
+        let reader_schema = self
+            .reader_schema
+            .map(|s| wrap_non_record_root(s, self.root_field_name));
+        match writer_schema.as_ref() {
             Schema::Complex(ComplexType::Record(r)) => {
-                let mut resolver = Maker::new(self.use_utf8view, self.strict_mode, self.tz);
-                let data_type =
-                    resolver.make_data_type(self.writer_schema, self.reader_schema, None)?;
+                let mut resolver = Maker::new(self.use_utf8view, self.strict_mode, self.tz)
+                    .with_replace_invalid_utf8(self.replace_invalid_utf8)
+                    .with_max_recursion_depth(self.max_recursion_depth);
+                let mut data_type = resolver.make_data_type(
+                    writer_schema.as_ref(),
+                    reader_schema.as_deref(),
+                    None,
+                )?;
+                if let Some(field_types) = self.field_types {
+                    data_type =
+                        with_field_types(data_type, field_types, self.replace_invalid_utf8)?;
+                }
                 Ok(AvroField {
                     name: r.name.to_string(),
                     data_type,
                 })
             }
-            _ => Err(ArrowError::ParseError(format!(
+            _ => Err(ArrowError::SchemaError(format!(
                 "Expected a Record schema to build an AvroField, but got {:?}",
                 self.writer_schema
             ))),
@@ -705,6 +765,154 @@ impl<'a> AvroFieldBuilder<'a> {
     }
 }
 
+/// Returns the [`Promotion`] used to read `bytes` as a `string`
+fn bytes_to_string_promotion(replace_invalid_utf8: bool) -> Promotion {
+    match replace_invalid_utf8 {
+        true => Promotion::BytesToStringLossy,
+        false => Promotion::BytesToString,
+    }
+}
+
+/// Returns the record `root` with the fields named in `field_types` read as the
+/// corresponding Arrow [`DataType`].
+///
+/// Only `bytes` and `string` fields may be overridden, and only to the Arrow types that
+/// they can be read as, i.e. `Binary` or `Utf8`, and additionally `Utf8View` for `string`.
+fn with_field_types(
+    mut root: AvroDataType,
+    field_types: &IndexMap<String, DataType>,
+    replace_invalid_utf8: bool,
+) -> Result<AvroDataType, ArrowError> {
+    let Codec::Struct(fields) = &root.codec else {
+        return Err(ArrowError::SchemaError(
+            "Field types can only be overridden for a record".to_string(),
+        ));
+    };
+    // Fields only present in the reader schema are populated with their default value,
+    // which is not converted, and so their type cannot be overridden
+    let from_writer: Option<HashSet<usize>> = match &root.resolution {
+        Some(ResolutionInfo::Record(r)) => Some(
+            r.writer_fields
+                .iter()
+                .filter_map(|f| match f {
+                    ResolvedField::ToReader(idx) => Some(*idx),
+                    ResolvedField::Skip(_) => None,
+                })
+                .collect(),
+        ),
+        _ => None,
+    };
+    let mut fields = fields.to_vec();
+    for (name, target) in field_types {
+        let (idx, field) = fields
+            .iter_mut()
+            .enumerate()
+            .find(|(_, f)| f.name() == name)
+            .ok_or_else(|| {
+                ArrowError::SchemaError(format!(
+                    "Cannot read field \"{name}\" as {target}: field not found in Avro schema"
+                ))
+            })?;
+        if from_writer.as_ref().is_some_and(|f| !f.contains(&idx)) {
+            return Err(ArrowError::SchemaError(format!(
+                "Cannot read field \"{name}\" as {target}: field not found in writer schema"
+            )));
+        }
+        let dt = &mut field.data_type;
+        // The promotion applied to the value, and for a nullable field, the index of
+        // the non-null branch of the reader union
+        let (promotion, reader_idx) = match (&dt.resolution, dt.nullability) {
+            (None, nullability) => (None, nullability.map(|n| n.non_null_index())),
+            (Some(ResolutionInfo::Promotion(p)), None) => (Some(*p), None),
+            (Some(ResolutionInfo::Union(u)), Some(n)) if u.writer_is_union && u.reader_is_union => {
+                match u.writer_to_reader.get(n.non_null_index()) {
+                    Some(Some((idx, ResolutionInfo::Promotion(p)))) => (Some(*p), Some(*idx)),
+                    _ => {
+                        return Err(ArrowError::SchemaError(format!(
+                            "Cannot read field \"{name}\" as {target}: unsupported schema resolution"
+                        )));
+                    }
+                }
+            }
+            _ => {
+                return Err(ArrowError::SchemaError(format!(
+                    "Cannot read field \"{name}\" as {target}: unsupported schema resolution"
+                )));
+            }
+        };
+        let is_bytes = match (&dt.codec, promotion) {
+            (Codec::Binary, None | Some(Promotion::Direct))
+            | (
+                Codec::Utf8 | Codec::Utf8View,
+                Some(Promotion::BytesToString | Promotion::BytesToStringLossy),
+            ) => true,
+            (Codec::Utf8 | Codec::Utf8View, None | Some(Promotion::Direct))
+            | (Codec::Binary, Some(Promotion::StringToBytes)) => false,
+            (codec, _) => {
+                return Err(ArrowError::SchemaError(format!(
+                    "Cannot read field \"{name}\" as {target}: only bytes and string fields can be overridden, found {}",
+                    codec.data_type()
+                )));
+            }
+        };
+        let (codec, promotion) = match (target, is_bytes) {
+            (DataType::Binary, true) => (Codec::Binary, Promotion::Direct),
+            (DataType::Binary, false) => (Codec::Binary, Promotion::StringToBytes),
+            (DataType::Utf8, false) => (Codec::Utf8, Promotion::Direct),
+            (DataType::Utf8View, false) => (Codec::Utf8View, Promotion::Direct),
+            (DataType::Utf8, true) => {
+                (Codec::Utf8, bytes_to_string_promotion(replace_invalid_utf8))
+            }
+            _ => {
+                return Err(ArrowError::SchemaError(format!(
+                    "Cannot read field \"{name}\" of Avro type {} as {target}",
+                    if is_bytes { "bytes" } else { "string" }
+                )));
+            }
+        };
+        dt.codec = codec;
+        dt.resolution = match (promotion, dt.nullability, reader_idx) {
+            (Promotion::Direct, _, _) => None,
+            (promotion, Some(nullability), Some(reader_idx)) => {
+                // Nullable values are resolved via the branches of the union, as for
+                // a writer and reader schema that are both nullable unions
+                let mut writer_to_reader = vec![None, None];
+                writer_to_reader[nullability.non_null_index()] =
+                    Some((reader_idx, ResolutionInfo::Promotion(promotion)));
+                Some(ResolutionInfo::Union(ResolvedUnion {
+                    writer_to_reader: Arc::from(writer_to_reader),
+                    writer_is_union: true,
+                    reader_is_union: true,
+                }))
+            }
+            (promotion, _, _) => Some(ResolutionInfo::Promotion(promotion)),
+        };
+    }
+    root.codec = Codec::Struct(fields.into());
+    Ok(root)
+}
+
+/// Wraps a non-record root `schema` in a record with a single field named `field_name`.
+fn wrap_non_record_root<'a>(schema: &'a Schema<'a>, field_name: &'a str) -> Cow<'a, Schema<'a>> {
+    match schema {
+        Schema::Complex(ComplexType::Record(_)) => Cow::Borrowed(schema),
+        _ => Cow::Owned(Schema::Complex(ComplexType::Record(Record {
+            name: AVRO_ROOT_RECORD_DEFAULT_NAME,
+            namespace: None,
+            doc: None,
+            aliases: vec![],
+            fields: vec![AvroFieldSchema {
+                name: field_name,
+                doc: None,
+                r#type: schema.clone(),
+                default: None,
+                aliases: vec![],
+            }],
+            attributes: Attributes::default(),
+        }))),
+    }
+}
+
 /// Timezone representation for timestamps.
 ///
 /// Avro only distinguishes between UTC and local time (no timezone), but Arrow supports
@@ -808,6 +1016,11 @@ pub(crate) enum Codec {
     Interval,
     /// Represents Avro union type, maps to Arrow's Union data type
     Union(Arc<[AvroDataType]>, UnionFields, UnionMode),
+    /// A reference to the named recursive type beyond the maximum recursion depth,
+    /// maps to Arrow's Null data type
+    ///
+    /// Only null values can be decoded at this position; any other value is an error.
+    RecursionLimit(Arc<str>),
     /// Represents Avro custom logical type to map to Arrow Duration(TimeUnit::Nanosecond)
     #[cfg(feature = "avro_custom_types")]
     DurationNanos,
@@ -895,6 +1108,7 @@ impl Codec {
                 tz.as_ref().map(|tz| tz.as_str().into()),
             ),
             Self::Interval => DataType::Interval(IntervalUnit::MonthDayNano),
+            Self::RecursionLimit(_) => DataType::Null,
             Self::Fixed(size) => DataType::FixedSizeBinary(*size),
             Self::Decimal(precision, scale, _size) => {
                 let p = *precision as u8;
@@ -1064,7 +1278,7 @@ fn parse_decimal_attributes(
         .get("precision")
         .and_then(|v| v.as_u64())
         .or(if precision_required { None } else { Some(10) })
-        .ok_or_else(|| ArrowError::ParseError("Decimal requires precision".to_string()))?
+        .ok_or_else(|| ArrowError::SchemaError("Decimal requires precision".to_string()))?
         as usize;
     let scale = attributes
         .additional
@@ -1078,29 +1292,29 @@ fn parse_decimal_attributes(
         .map(|s| s as usize)
         .or(fallback_size);
     if precision == 0 {
-        return Err(ArrowError::ParseError(
+        return Err(ArrowError::SchemaError(
             "Decimal requires precision > 0".to_string(),
         ));
     }
     if scale > precision {
-        return Err(ArrowError::ParseError(format!(
+        return Err(ArrowError::SchemaError(format!(
             "Decimal has invalid scale > precision: scale={scale}, precision={precision}"
         )));
     }
     if precision > DECIMAL256_MAX_PRECISION as usize {
-        return Err(ArrowError::ParseError(format!(
+        return Err(ArrowError::SchemaError(format!(
             "Decimal precision {precision} exceeds maximum supported by Arrow ({})",
             DECIMAL256_MAX_PRECISION
         )));
     }
     if let Some(sz) = size {
         let max_p = max_precision_for_fixed_bytes(sz).ok_or_else(|| {
-            ArrowError::ParseError(format!(
+            ArrowError::SchemaError(format!(
                 "Invalid fixed size for decimal: {sz}, must be between 1 and 32 bytes"
             ))
         })?;
         if precision > max_p {
-            return Err(ArrowError::ParseError(format!(
+            return Err(ArrowError::SchemaError(format!(
                 "Decimal precision {precision} exceeds capacity of fixed size {sz} bytes (max {max_p})"
             )));
         }
@@ -1142,7 +1356,7 @@ enum UnionFieldKind {
 impl From<&Codec> for UnionFieldKind {
     fn from(c: &Codec) -> Self {
         match c {
-            Codec::Null => Self::Null,
+            Codec::Null | Codec::RecursionLimit(_) => Self::Null,
             Codec::Boolean => Self::Boolean,
             Codec::Int32 => Self::Int,
             Codec::Int64 => Self::Long,
@@ -1238,7 +1452,7 @@ impl<'a> Resolver<'a> {
             .unwrap_or_else(|| (namespace.unwrap_or(""), name));
         self.map
             .get(&(namespace, name))
-            .ok_or_else(|| ArrowError::ParseError(format!("Failed to resolve {namespace}.{name}")))
+            .ok_or_else(|| ArrowError::SchemaError(format!("Failed to resolve {namespace}.{name}")))
             .cloned()
     }
 }
@@ -1287,7 +1501,7 @@ fn ensure_names_match(
     ) {
         Ok(())
     } else {
-        Err(ArrowError::ParseError(format!(
+        Err(ArrowError::SchemaError(format!(
             "{data_type} name mismatch writer={writer_name}, reader={reader_name}"
         )))
     }
@@ -1375,6 +1589,15 @@ fn union_first_duplicate<'a>(
     None
 }
 
+/// The default number of times a recursive record may be nested within itself
+pub(crate) const DEFAULT_MAX_RECURSION_DEPTH: usize = 8;
+
+/// The maximum number of schema nodes created by unrolling recursive records
+///
+/// A record with `r` recursive fields unrolls to `r^depth` copies of itself, so the
+/// nesting depth alone does not bound the size of the resulting schema
+const MAX_RECURSIVE_EXPANSION: usize = 100_000;
+
 /// Resolves Avro type names to [`AvroDataType`]
 ///
 /// See <https://avro.apache.org/docs/1.11.1/specification/#names>
@@ -1383,6 +1606,13 @@ struct Maker<'a> {
     use_utf8view: bool,
     strict_mode: bool,
     tz: Tz,
+    replace_invalid_utf8: bool,
+    max_recursion_depth: usize,
+    /// The records currently being parsed, keyed by `(namespace, name)`, along with the
+    /// number of times each is nested within itself
+    in_progress: HashMap<(&'a str, &'a str), (Record<'a>, usize)>,
+    /// The number of schema nodes created by unrolling recursive records
+    expanded: usize,
 }
 
 impl<'a> Maker<'a> {
@@ -1392,7 +1622,63 @@ impl<'a> Maker<'a> {
             use_utf8view,
             strict_mode,
             tz,
+            replace_invalid_utf8: false,
+            max_recursion_depth: DEFAULT_MAX_RECURSION_DEPTH,
+            in_progress: Default::default(),
+            expanded: 0,
+        }
+    }
+
+    /// Replace invalid UTF-8 with `U+FFFD` when promoting `bytes` to `string`
+    fn with_replace_invalid_utf8(mut self, replace_invalid_utf8: bool) -> Self {
+        self.replace_invalid_utf8 = replace_invalid_utf8;
+        self
+    }
+
+    /// Sets the number of times a recursive record may be nested within itself
+    fn with_max_recursion_depth(mut self, max_recursion_depth: usize) -> Self {
+        self.max_recursion_depth = max_recursion_depth;
+        self
+    }
+
+    /// Resolves a reference to the named type `name`
+    ///
+    /// A reference to a record that is still being parsed is a recursive reference, and is
+    /// unrolled by parsing the record again, nested within itself. Once the record is nested
+    /// more than `max_recursion_depth` times, the reference resolves to
+    /// [`Codec::RecursionLimit`] instead.
+    ///
+    /// Returns an error if unrolling would create more than [`MAX_RECURSIVE_EXPANSION`]
+    /// schema nodes.
+    fn resolve_ref(
+        &mut self,
+        name: &str,
+        namespace: Option<&'a str>,
+    ) -> Result<AvroDataType, ArrowError> {
+        let (ns, n) = name
+            .rsplit_once('.')
+            .unwrap_or_else(|| (namespace.unwrap_or(""), name));
+        let Some((record, depth)) = self.in_progress.get(&(ns, n)) else {
+            return self.resolver.resolve(name, namespace);
+        };
+        if *depth > self.max_recursion_depth {
+            let (full_name, _) = make_full_name(n, Some(ns).filter(|ns| !ns.is_empty()), None);
+            return Ok(AvroDataType::new(
+                Codec::RecursionLimit(full_name.into()),
+                Default::default(),
+                None,
+            ));
+        }
+        self.expanded += record.fields.len() + 1;
+        if self.expanded > MAX_RECURSIVE_EXPANSION {
+            let (full_name, _) = make_full_name(n, Some(ns).filter(|ns| !ns.is_empty()), None);
+            return Err(ArrowError::SchemaError(format!(
+                "Unrolling recursive type {full_name} creates more than \
+                 {MAX_RECURSIVE_EXPANSION} schema nodes, reduce the maximum recursion depth"
+            )));
         }
+        let record = Schema::Complex(ComplexType::Record(record.clone()));
+        self.parse_type(&record, namespace)
     }
 
     #[cfg(feature = "avro_custom_types")]
@@ -1440,7 +1726,7 @@ impl<'a> Maker<'a> {
                 Default::default(),
                 None,
             )),
-            Schema::TypeName(TypeName::Ref(name)) => self.resolver.resolve(name, namespace),
+            Schema::TypeName(TypeName::Ref(name)) => self.resolve_ref(name, namespace),
             Schema::Union(f) => {
                 let null = f
                     .iter()
@@ -1496,6 +1782,11 @@ impl<'a> Maker<'a> {
             Schema::Complex(c) => match c {
                 ComplexType::Record(r) => {
                     let namespace = r.namespace.or(namespace);
+                    let key = (namespace.unwrap_or(""), r.name);
+                    self.in_progress
+                        .entry(key)
+                        .or_insert_with(|| (r.clone(), 0))
+                        .1 += 1;
                     let mut metadata = r.attributes.field_metadata();
                     let fields = r
                         .fields
@@ -1506,7 +1797,20 @@ impl<'a> Maker<'a> {
                                 data_type: self.parse_type(&field.r#type, namespace)?,
                             })
                         })
-                        .collect::<Result<_, ArrowError>>()?;
+                        .collect::<Result<_, ArrowError>>();
+                    // Only the outermost parse of a recursive record is registered, as the
+                    // nested ones are truncated at the maximum recursion depth
+                    let outermost = match self.in_progress.get_mut(&key) {
+                        Some((_, 1)) | None => {
+                            self.in_progress.remove(&key);
+                            true
+                        }
+                        Some((_, depth)) => {
+                            *depth -= 1;
+                            false
+                        }
+                    };
+                    let fields = fields?;
                     metadata.insert(AVRO_NAME_METADATA_KEY.to_string(), r.name.to_string());
                     if let Some(ns) = namespace {
                         metadata.insert(AVRO_NAMESPACE_METADATA_KEY.to_string(), ns.to_string());
@@ -1517,7 +1821,9 @@ impl<'a> Maker<'a> {
                         metadata,
                         resolution: None,
                     };
-                    self.resolver.register(r.name, namespace, field.clone());
+                    if outermost {
+                        self.resolver.register(r.name, namespace, field.clone());
+                    }
                     Ok(field)
                 }
                 ComplexType::Array(a) => {
@@ -1531,7 +1837,7 @@ impl<'a> Maker<'a> {
                 }
                 ComplexType::Fixed(f) => {
                     let size = f.size.try_into().map_err(|e| {
-                        ArrowError::ParseError(format!("Overflow converting size to i32: {e}"))
+                        ArrowError::SchemaError(format!("Overflow converting size to i32: {e}"))
                     })?;
                     let namespace = f.namespace.or(namespace);
                     let mut metadata = f.attributes.field_metadata();
@@ -1552,7 +1858,7 @@ impl<'a> Maker<'a> {
                         }
                         Some("duration") => {
                             if size != 12 {
-                                return Err(ArrowError::ParseError(format!(
+                                return Err(ArrowError::SchemaError(format!(
                                     "Invalid fixed size for Duration: {size}, must be 12"
                                 )));
                             };
@@ -1617,7 +1923,7 @@ impl<'a> Maker<'a> {
                         .collect::<Arc<[String]>>();
                     let mut metadata = e.attributes.field_metadata();
                     let symbols_json = serde_json::to_string(&e.symbols).map_err(|e| {
-                        ArrowError::ParseError(format!("Failed to serialize enum symbols: {e}"))
+                        ArrowError::SchemaError(format!("Failed to serialize enum symbols: {e}"))
                     })?;
                     metadata.insert(AVRO_ENUM_SYMBOLS_METADATA_KEY.to_string(), symbols_json);
                     metadata.insert(AVRO_NAME_METADATA_KEY.to_string(), e.name.to_string());
@@ -1697,12 +2003,12 @@ impl<'a> Maker<'a> {
                             .get("arrow.runEndIndexBits")
                             .and_then(|v| v.as_u64())
                             .and_then(|n| u8::try_from(n).ok())
-                            .ok_or_else(|| ArrowError::ParseError(
+                            .ok_or_else(|| ArrowError::SchemaError(
                                 "arrow.run-end-encoded requires 'arrow.runEndIndexBits' (one of 16, 32, or 64)"
                                     .to_string(),
                             ))?;
                         if bits_u8 != 16 && bits_u8 != 32 && bits_u8 != 64 {
-                            return Err(ArrowError::ParseError(format!(
+                            return Err(ArrowError::SchemaError(format!(
                                 "Invalid 'arrow.runEndIndexBits' value {bits_u8}; must be 16, 32, or 64"
                             )));
                         }
@@ -2083,9 +2389,11 @@ impl<'a> Maker<'a> {
             (PrimitiveType::Long, PrimitiveType::Double) => Promotion::LongToDouble,
             (PrimitiveType::Float, PrimitiveType::Double) => Promotion::FloatToDouble,
             (PrimitiveType::String, PrimitiveType::Bytes) => Promotion::StringToBytes,
-            (PrimitiveType::Bytes, PrimitiveType::String) => Promotion::BytesToString,
+            (PrimitiveType::Bytes, PrimitiveType::String) => {
+                bytes_to_string_promotion(self.replace_invalid_utf8)
+            }
             _ => {
-                return Err(ArrowError::ParseError(format!(
+                return Err(ArrowError::SchemaError(format!(
                     "Illegal promotion {write_primitive:?} to {read_primitive:?}"
                 )));
             }
@@ -2368,6 +2676,7 @@ impl<'a> Maker<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::errors::{AvroError, ErrorKind};
     use crate::schema::{
         AVRO_ROOT_RECORD_DEFAULT_NAME, Array, Attributes, ComplexType, Field as AvroFieldSchema,
         Fixed, PrimitiveType, Record, Schema, Type, TypeName,
@@ -2719,13 +3028,21 @@ mod tests {
         let result = maker.make_data_type(&writer_schema, Some(&reader_schema), None);
         assert!(result.is_err());
         match result {
-            Err(ArrowError::ParseError(msg)) => {
+            Err(ArrowError::SchemaError(msg)) => {
                 assert!(msg.contains("Illegal promotion"));
             }
-            _ => panic!("Expected ParseError for illegal promotion Double -> Float"),
+            _ => panic!("Expected SchemaError for illegal promotion Double -> Float"),
         }
     }
 
+    #[test]
+    fn test_invalid_decimal_is_schema_error() {
+        let err = parse_decimal_attributes(&Attributes::default(), Some(8), true).unwrap_err();
+        assert!(matches!(err, ArrowError::SchemaError(_)));
+        assert_eq!(err.kind(), ErrorKind::Schema);
+        assert_eq!(AvroError::from(err).kind(), ErrorKind::Schema);
+    }
+
     #[test]
     fn test_promotion_within_nullable_union_keeps_writer_null_ordering() {
         let writer = Schema::Union(vec![
@@ -3920,4 +4237,95 @@ mod tests {
             .make_data_type(&writer_schema, Some(&reader_schema), None)
             .expect("fixed alias resolution should succeed");
     }
+
+    #[test]
+    fn test_recursive_record() {
+        let schema_str = r#"
+        {
+          "type": "record",
+          "name": "Root",
+          "namespace": "ns",
+          "fields": [
+            {
+              "name": "tree",
+              "type": {
+                "type": "record",
+                "name": "Tree",
+                "fields": [
+                  { "name": "value", "type": "int" },
+                  { "name": "children", "type": { "type": "array", "items": "Tree" } }
+                ]
+              }
+            },
+            { "name": "other", "type": "ns.Tree" }
+          ]
+        }
+        "#;
+        let schema: Schema = serde_json::from_str(schema_str).unwrap();
+        let mut maker = Maker::new(false, false, Tz::default()).with_max_recursion_depth(1);
+        let data_type = maker.make_data_type(&schema, None, None).unwrap();
+        let Codec::Struct(fields) = data_type.codec() else {
+            panic!("expected struct, got {:?}", data_type.codec());
+        };
+        // A later reference to the recursive record resolves to its unrolled definition
+        assert_eq!(fields[0].data_type(), fields[1].data_type());
+
+        // Tree { value, children: [Tree { value, children: [<limit>] }] }
+        let Codec::Struct(tree) = fields[0].data_type().codec() else {
+            panic!("expected struct, got {:?}", fields[0].data_type().codec());
+        };
+        let Codec::List(item) = tree[1].data_type().codec() else {
+            panic!("expected list, got {:?}", tree[1].data_type().codec());
+        };
+        let Codec::Struct(nested) = item.codec() else {
+            panic!("expected struct, got {:?}", item.codec());
+        };
+        let Codec::List(item) = nested[1].data_type().codec() else {
+            panic!("expected list, got {:?}", nested[1].data_type().codec());
+        };
+        assert_eq!(item.codec(), &Codec::RecursionLimit("ns.Tree".into()));
+        assert_eq!(item.codec().data_type(), DataType::Null);
+
+        let mut maker = Maker::new(false, false, Tz::default()).with_max_recursion_depth(0);
+        let data_type = maker.make_data_type(&schema, None, None).unwrap();
+        let Codec::Struct(fields) = data_type.codec() else {
+            unreachable!()
+        };
+        let Codec::Struct(tree) = fields[0].data_type().codec() else {
+            unreachable!()
+        };
+        let Codec::List(item) = tree[1].data_type().codec() else {
+            panic!("expected list, got {:?}", tree[1].data_type().codec());
+        };
+        assert_eq!(item.codec(), &Codec::RecursionLimit("ns.Tree".into()));
+    }
+
+    #[test]
+    fn test_recursive_record_expansion_limit() {
+        // Each level of a binary tree doubles the number of nested records
+        let schema_str = r#"
+        {
+          "type": "record",
+          "name": "Node",
+          "fields": [
+            { "name": "value", "type": "int" },
+            { "name": "left", "type": ["null", "Node"] },
+            { "name": "right", "type": ["null", "Node"] }
+          ]
+        }
+        "#;
+        let schema: Schema = serde_json::from_str(schema_str).unwrap();
+        let mut maker = Maker::new(false, false, Tz::default());
+        let data_type = maker.make_data_type(&schema, None, None).unwrap();
+        assert!(matches!(data_type.codec(), Codec::Struct(_)));
+
+        let mut maker = Maker::new(false, false, Tz::default()).with_max_recursion_depth(32);
+        let err = maker.make_data_type(&schema, None, None).unwrap_err();
+        assert!(matches!(err, ArrowError::SchemaError(_)));
+        assert!(
+            err.to_string()
+                .contains("Unrolling recursive type Node creates more than 100000 schema nodes"),
+            "{err}"
+        );
+    }
 }
@@ -58,7 +58,7 @@ impl CompressionCodec {
                 Ok(out)
             }
             #[cfg(not(feature = "deflate"))]
-            CompressionCodec::Deflate => Err(AvroError::ParseError(
+            CompressionCodec::Deflate => Err(AvroError::NYI(
                 "Deflate codec requires deflate feature".to_string(),
             )),
             #[cfg(feature = "snappy")]
@@ -80,7 +80,7 @@ impl CompressionCodec {
                 Ok(decoded)
             }
             #[cfg(not(feature = "snappy"))]
-            CompressionCodec::Snappy => Err(AvroError::ParseError(
+            CompressionCodec::Snappy => Err(AvroError::NYI(
                 "Snappy codec requires snappy feature".to_string(),
             )),
 
@@ -94,7 +94,7 @@ impl CompressionCodec {
                 Ok(out)
             }
             #[cfg(not(feature = "zstd"))]
-            CompressionCodec::ZStandard => Err(AvroError::ParseError(
+            CompressionCodec::ZStandard => Err(AvroError::NYI(
                 "ZStandard codec requires zstd feature".to_string(),
             )),
             #[cfg(feature = "bzip2")]
@@ -107,7 +107,7 @@ impl CompressionCodec {
                 Ok(out)
             }
             #[cfg(not(feature = "bzip2"))]
-            CompressionCodec::Bzip2 => Err(AvroError::ParseError(
+            CompressionCodec::Bzip2 => Err(AvroError::NYI(
                 "Bzip2 codec requires bzip2 feature".to_string(),
             )),
             #[cfg(feature = "xz")]
@@ -120,9 +120,7 @@ impl CompressionCodec {
                 Ok(out)
             }
             #[cfg(not(feature = "xz"))]
-            CompressionCodec::Xz => Err(AvroError::ParseError(
-                "XZ codec requires xz feature".to_string(),
-            )),
+            CompressionCodec::Xz => Err(AvroError::NYI("XZ codec requires xz feature".to_string())),
         }
     }
 
@@ -138,7 +136,7 @@ impl CompressionCodec {
                 Ok(compressed)
             }
             #[cfg(not(feature = "deflate"))]
-            CompressionCodec::Deflate => Err(ArrowError::ParseError(
+            CompressionCodec::Deflate => Err(ArrowError::NotYetImplemented(
                 "Deflate codec requires deflate feature".to_string(),
             )),
 
@@ -155,7 +153,7 @@ impl CompressionCodec {
                 Ok(compressed)
             }
             #[cfg(not(feature = "snappy"))]
-            CompressionCodec::Snappy => Err(ArrowError::ParseError(
+            CompressionCodec::Snappy => Err(ArrowError::NotYetImplemented(
                 "Snappy codec requires snappy feature".to_string(),
             )),
 
@@ -170,7 +168,7 @@ impl CompressionCodec {
                 Ok(compressed)
             }
             #[cfg(not(feature = "zstd"))]
-            CompressionCodec::ZStandard => Err(ArrowError::ParseError(
+            CompressionCodec::ZStandard => Err(ArrowError::NotYetImplemented(
                 "ZStandard codec requires zstd feature".to_string(),
             )),
 
@@ -183,7 +181,7 @@ impl CompressionCodec {
                 Ok(compressed)
             }
             #[cfg(not(feature = "bzip2"))]
-            CompressionCodec::Bzip2 => Err(ArrowError::ParseError(
+            CompressionCodec::Bzip2 => Err(ArrowError::NotYetImplemented(
                 "Bzip2 codec requires bzip2 feature".to_string(),
             )),
             #[cfg(feature = "xz")]
@@ -194,7 +192,7 @@ impl CompressionCodec {
                 Ok(compressed)
             }
             #[cfg(not(feature = "xz"))]
-            CompressionCodec::Xz => Err(ArrowError::ParseError(
+            CompressionCodec::Xz => Err(ArrowError::NotYetImplemented(
                 "XZ codec requires xz feature".to_string(),
             )),
         }
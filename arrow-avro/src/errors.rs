@@ -18,6 +18,7 @@
 //! Common Avro errors and macros.
 
 use arrow_schema::ArrowError;
+pub use arrow_schema::ErrorKind;
 use core::num::TryFromIntError;
 use std::error::Error;
 use std::string::FromUtf8Error;
@@ -60,6 +61,147 @@ pub enum AvroError {
     /// Returned when a function needs more data to complete properly.
     /// The `Range<u64>` indicates the range of bytes that are needed.
     NeedMoreDataRange(std::ops::Range<u64>),
+    /// An error annotated with the location in the data at which it occurred.
+    ///
+    /// Displays as the underlying error, see [`AvroError::context`]
+    Context(Box<AvroError>, Box<ErrorContext>),
+}
+
+/// The location in Avro data at which an error occurred, see [`AvroError::context`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ErrorContext {
+    /// The path of the record field being decoded, outermost first
+    pub field: Vec<String>,
+    /// The index of the object container file block being read
+    pub block: Option<usize>,
+    /// The byte offset in the file of the block being read
+    pub offset: Option<u64>,
+}
+
+impl ErrorContext {
+    /// Creates an empty [`ErrorContext`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the record field being decoded
+    pub fn with_field(mut self, name: impl Into<String>) -> Self {
+        self.field = vec![name.into()];
+        self
+    }
+
+    /// Sets the index of the object container file block being read
+    pub fn with_block(mut self, block: usize) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the byte offset in the file of the block being read
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Adds the location from `outer`, which encloses `self`
+    ///
+    /// The field path of `outer` is prepended to that of `self`, any other fields not
+    /// set in `self` are taken from `outer`
+    fn merge(&mut self, outer: ErrorContext) {
+        let mut field = outer.field;
+        field.append(&mut self.field);
+        self.field = field;
+        self.block = self.block.or(outer.block);
+        self.offset = self.offset.or(outer.offset);
+    }
+}
+
+impl std::fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut sep = "";
+        if !self.field.is_empty() {
+            write!(f, "field {}", self.field.join("."))?;
+            sep = ", ";
+        }
+        if let Some(block) = self.block {
+            write!(f, "{sep}block {block}")?;
+            sep = ", ";
+        }
+        if let Some(offset) = self.offset {
+            write!(f, "{sep}offset {offset}")?;
+        }
+        Ok(())
+    }
+}
+
+impl AvroError {
+    /// Returns the [`ErrorKind`] of this error
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            AvroError::NYI(_) => ErrorKind::Unsupported,
+            AvroError::EOF(_) | AvroError::ParseError(_) => ErrorKind::Corrupt,
+            AvroError::SchemaError(_) => ErrorKind::Schema,
+            AvroError::IoError(_, _) => ErrorKind::Io,
+            AvroError::ArrowError(e) => error_kind(e),
+            AvroError::External(e) => external_kind(e.as_ref()),
+            AvroError::Context(e, _) => e.kind(),
+            AvroError::General(_)
+            | AvroError::IndexOutOfBound(_, _)
+            | AvroError::InvalidArgument(_)
+            | AvroError::NeedMoreData(_)
+            | AvroError::NeedMoreDataRange(_) => ErrorKind::Other,
+        }
+    }
+
+    /// Returns the location in the data at which this error occurred, if known
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            AvroError::Context(_, context) => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Annotates this error with the location in the data at which it occurred
+    ///
+    /// If this error already has a context, `context` is treated as enclosing it, see
+    /// [`ErrorContext`]. [`AvroError::NeedMoreData`] and [`AvroError::NeedMoreDataRange`]
+    /// are returned unchanged, as callers are expected to match on them.
+    pub fn with_context(self, context: ErrorContext) -> Self {
+        match self {
+            AvroError::Context(e, mut existing) => {
+                existing.merge(context);
+                AvroError::Context(e, existing)
+            }
+            e @ (AvroError::NeedMoreData(_) | AvroError::NeedMoreDataRange(_)) => e,
+            e => AvroError::Context(Box::new(e), Box::new(context)),
+        }
+    }
+}
+
+/// Returns the [`ErrorKind`] of an [`ArrowError`] returned by this crate
+///
+/// [`AvroError`]s are converted to an [`ArrowError::ExternalError`] wrapping the
+/// [`AvroError`], which can be recovered with [`Error::source`] to access its
+/// [`AvroError::context`]. Unlike [`ArrowError::kind`], this categorizes such errors
+/// by the kind of the wrapped [`AvroError`].
+pub fn error_kind(e: &ArrowError) -> ErrorKind {
+    match e {
+        ArrowError::ExternalError(e) => external_kind(e.as_ref()),
+        e => e.kind(),
+    }
+}
+
+fn external_kind(e: &(dyn Error + Send + Sync + 'static)) -> ErrorKind {
+    if e.is::<io::Error>() {
+        return ErrorKind::Io;
+    }
+    if let Some(e) = e.downcast_ref::<AvroError>() {
+        return e.kind();
+    }
+    if let Some(e) = e.downcast_ref::<ArrowError>() {
+        return error_kind(e);
+    }
+    ErrorKind::External
 }
 
 impl std::fmt::Display for AvroError {
@@ -85,6 +227,8 @@ impl std::fmt::Display for AvroError {
             AvroError::NeedMoreDataRange(range) => {
                 write!(fmt, "NeedMoreDataRange: {}..{}", range.start, range.end)
             }
+            // The context is not included so that messages are unchanged by it
+            AvroError::Context(e, _) => write!(fmt, "{e}"),
         }
     }
 }
@@ -95,6 +239,7 @@ impl Error for AvroError {
             AvroError::External(e) => Some(e.as_ref()),
             AvroError::ArrowError(e) => Some(e.as_ref()),
             AvroError::IoError(_, e) => Some(e),
+            AvroError::Context(e, _) => e.source(),
             _ => None,
         }
     }
@@ -124,6 +269,13 @@ impl From<FromUtf8Error> for AvroError {
     }
 }
 
+#[cfg(feature = "object_store")]
+impl From<object_store::Error> for AvroError {
+    fn from(e: object_store::Error) -> AvroError {
+        AvroError::External(Box::new(e))
+    }
+}
+
 impl From<ArrowError> for AvroError {
     fn from(e: ArrowError) -> Self {
         AvroError::ArrowError(Box::new(e))
@@ -142,7 +294,71 @@ impl From<AvroError> for ArrowError {
             AvroError::External(inner) => ArrowError::from_external_error(inner),
             AvroError::IoError(msg, err) => ArrowError::IoError(msg, err),
             AvroError::ArrowError(inner) => *inner,
-            other => ArrowError::AvroError(other.to_string()),
+            // Preserve the error so that its kind and context remain available
+            other => ArrowError::ExternalError(Box::new(other)),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind() {
+        assert_eq!(AvroError::NYI("x".into()).kind(), ErrorKind::Unsupported);
+        assert_eq!(AvroError::EOF("x".into()).kind(), ErrorKind::Corrupt);
+        assert_eq!(AvroError::ParseError("x".into()).kind(), ErrorKind::Corrupt);
+        assert_eq!(AvroError::SchemaError("x".into()).kind(), ErrorKind::Schema);
+        assert_eq!(AvroError::General("x".into()).kind(), ErrorKind::Other);
+        assert_eq!(
+            AvroError::InvalidArgument("x".into()).kind(),
+            ErrorKind::Other
+        );
+
+        let io = || io::Error::new(io::ErrorKind::UnexpectedEof, "eof");
+        assert_eq!(AvroError::from(io()).kind(), ErrorKind::Io);
+        assert_eq!(AvroError::IoError("x".into(), io()).kind(), ErrorKind::Io);
+        let utf8 = String::from_utf8(vec![0xff]).unwrap_err();
+        assert_eq!(AvroError::from(utf8).kind(), ErrorKind::External);
+
+        let arrow = ArrowError::SchemaError("x".into());
+        assert_eq!(AvroError::from(arrow).kind(), ErrorKind::Schema);
+
+        // Conversions to ArrowError preserve the kind
+        let arrow = ArrowError::from(AvroError::IoError("x".into(), io()));
+        assert_eq!(error_kind(&arrow), ErrorKind::Io);
+        let arrow = ArrowError::from(AvroError::NYI("x".into()));
+        assert_eq!(error_kind(&arrow), ErrorKind::Unsupported);
+        assert_eq!(arrow.kind(), ErrorKind::External);
+        let arrow = ArrowError::from(AvroError::SchemaError("x".into()));
+        assert_eq!(error_kind(&arrow), ErrorKind::Schema);
+        assert_eq!(AvroError::from(arrow).kind(), ErrorKind::Schema);
+    }
+
+    #[test]
+    fn test_context() {
+        let err = AvroError::ParseError("bad varint".into())
+            .with_context(ErrorContext::new().with_field("b"))
+            .with_context(ErrorContext::new().with_field("a"))
+            .with_context(ErrorContext::new().with_block(2).with_offset(40));
+
+        assert_eq!(err.kind(), ErrorKind::Corrupt);
+        let context = err.context().unwrap();
+        assert_eq!(context.field, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(context.block, Some(2));
+        assert_eq!(context.offset, Some(40));
+        assert_eq!(context.to_string(), "field a.b, block 2, offset 40");
+        assert_eq!(err.to_string(), "Parser error: bad varint");
+
+        // The typed error is preserved by conversion to ArrowError
+        let arrow = ArrowError::from(err);
+        assert_eq!(error_kind(&arrow), ErrorKind::Corrupt);
+        let source = arrow.source().unwrap().downcast_ref::<AvroError>().unwrap();
+        assert_eq!(source.context().unwrap().block, Some(2));
+
+        let err = AvroError::NeedMoreData(4).with_context(ErrorContext::new().with_block(1));
+        assert!(matches!(err, AvroError::NeedMoreData(4)));
+        assert!(err.context().is_none());
+    }
+}
@@ -133,6 +133,10 @@
 //! is enabled, [`AvroObjectReader`] provides integration with object storage services
 //! such as S3 via the [object_store] crate.
 //!
+//! [`AsyncAvroReader`], created with [`ReaderBuilder::build_async`], reads an Avro file
+//! sequentially from any `tokio::io::AsyncRead`, for sources that are not seekable such as
+//! network streams.
+//!
 //! ```ignore
 //! use std::sync::Arc;
 //! use arrow_avro::reader::{AsyncAvroFileReader, AvroObjectReader};
@@ -163,15 +167,19 @@
 //! ### Modules
 //!
 //! - [`reader`]: read Avro (OCF, SOE, Confluent) into Arrow `RecordBatch`es.
-//!   - With the `async` feature: [`AsyncAvroFileReader`] for async streaming reads.
+//!   - With the `async` feature: [`AsyncAvroFileReader`] and [`AsyncAvroReader`] for async streaming reads.
 //!   - With the `object_store` feature: [`AvroObjectReader`] for reading from cloud storage.
 //! - [`writer`]: write Arrow `RecordBatch`es as Avro (OCF, SOE, Confluent, Apicurio).
+//!   - With the `async` feature: [`AsyncWriter`] for writing to async sinks.
 //! - [`schema`]: Avro schema parsing / fingerprints / registries.
 //! - [`compression`]: codecs used for **OCF block compression** (i.e., Deflate, Snappy, Zstandard, BZip2, and XZ).
 //! - [`codec`]: internal Avro-Arrow type conversion and row decode/encode plans.
 //!
 //! [`AsyncAvroFileReader`]: reader::AsyncAvroFileReader
+//! [`AsyncAvroReader`]: reader::AsyncAvroReader
+//! [`ReaderBuilder::build_async`]: reader::ReaderBuilder::build_async
 //! [`AvroObjectReader`]: reader::AvroObjectReader
+//! [`AsyncWriter`]: writer::AsyncWriter
 //!
 //! ### Features
 //!
@@ -183,7 +191,8 @@
 //! - `xz` — enable XZ/LZMA block compression.
 //!
 //! **Async & Object Store (opt‑in)**
-//! - `async` — enable async APIs for reading Avro (`AsyncAvroFileReader`, `AsyncFileReader` trait).
+//! - `async` — enable async APIs for reading Avro (`AsyncAvroFileReader`, `AsyncAvroReader`, `AsyncFileReader` trait)
+//!   and writing Avro (`AsyncWriter`).
 //! - `object_store` — enable integration with the [`object_store`] crate for reading Avro
 //!   from cloud storage (S3, GCS, Azure Blob, etc.) via `AvroObjectReader`. Implies `async`.
 //!
@@ -21,7 +21,7 @@ use crate::reader::async_reader::ReaderState;
 use crate::reader::header::{Header, HeaderDecoder, HeaderInfo};
 use crate::reader::record::RecordDecoder;
 use crate::reader::{AsyncAvroFileReader, AsyncFileReader, Decoder};
-use crate::schema::{AvroSchema, FingerprintAlgorithm};
+use crate::schema::{AVRO_NON_RECORD_ROOT_FIELD_NAME, AvroSchema, FingerprintAlgorithm};
 use indexmap::IndexMap;
 use std::ops::Range;
 
@@ -39,6 +39,7 @@ pub struct ReaderBuilder<R> {
     utf8_view: bool,
     strict_mode: bool,
     tz: Tz,
+    root_field_name: String,
 }
 
 impl<R> ReaderBuilder<R> {
@@ -54,6 +55,7 @@ impl<R> ReaderBuilder<R> {
             utf8_view: false,
             strict_mode: false,
             tz: Default::default(),
+            root_field_name: AVRO_NON_RECORD_ROOT_FIELD_NAME.to_string(),
         }
     }
 
@@ -117,6 +119,16 @@ impl<R> ReaderBuilder<R> {
         self.tz = tz;
         self
     }
+
+    /// Sets the name of the single column produced when the file's schema is not a record.
+    ///
+    /// The default is `"value"`.
+    pub fn with_root_field_name(self, root_field_name: impl Into<String>) -> Self {
+        Self {
+            root_field_name: root_field_name.into(),
+            ..self
+        }
+    }
 }
 
 /// Reads the Avro file header (magic, metadata, sync marker) asynchronously from `reader`.
@@ -158,9 +170,10 @@ where
             .get_bytes(range_to_fetch.clone())
             .await
             .map_err(|err| {
-                AvroError::General(format!(
-                    "Error fetching Avro header from file reader: {err}"
-                ))
+                AvroError::IoError(
+                    "Error fetching Avro header from file reader".to_string(),
+                    std::io::Error::other(err),
+                )
             })?;
         if current_data.is_empty() {
             return Err(AvroError::EOF(
@@ -246,10 +259,11 @@ impl<R: AsyncFileReader> ReaderBuilder<R> {
                 .with_utf8view(self.utf8_view)
                 .with_strict_mode(self.strict_mode)
                 .with_tz(self.tz)
+                .with_root_field_name(&self.root_field_name)
                 .build()
         }?;
 
-        let record_decoder = RecordDecoder::try_new_with_options(root.data_type())?;
+        let record_decoder = RecordDecoder::try_new_with_options(root.data_type(), false)?;
         let decoder = Decoder::from_parts(
             self.batch_size,
             record_decoder,
@@ -35,9 +35,11 @@ use std::task::{Context, Poll};
 
 mod async_file_reader;
 mod builder;
+mod stream;
 
 pub use async_file_reader::AsyncFileReader;
 pub use builder::{ReaderBuilder, read_header_info};
+pub use stream::AsyncAvroReader;
 
 #[cfg(feature = "object_store")]
 mod store;
@@ -319,7 +321,7 @@ impl<R: AsyncFileReader + Unpin + 'static> AsyncAvroFileReader<R> {
                                     Err(e) => return self.finish_with_error(e),
                                 };
                                 if consumed == 0 {
-                                    return self.finish_with_error(AvroError::General(
+                                    return self.finish_with_error(AvroError::ParseError(
                                         "BlockDecoder failed to consume byte during VLQ header parsing"
                                             .into(),
                                     ));
@@ -335,7 +337,7 @@ impl<R: AsyncFileReader + Unpin + 'static> AsyncAvroFileReader<R> {
                                 Err(e) => return self.finish_with_error(e),
                             };
                             if consumed != data_to_use.len() {
-                                return self.finish_with_error(AvroError::General(
+                                return self.finish_with_error(AvroError::ParseError(
                                     "BlockDecoder failed to consume all bytes after VLQ header parsing"
                                         .into(),
                                 ));
@@ -408,7 +410,7 @@ impl<R: AsyncFileReader + Unpin + 'static> AsyncAvroFileReader<R> {
 
                     // data should always be consumed unless Finished, if it wasn't, something went wrong
                     if !data.is_empty() {
-                        return self.finish_with_error(AvroError::General(
+                        return self.finish_with_error(AvroError::ParseError(
                             "BlockDecoder failed to make progress decoding Avro block".into(),
                         ));
                     }
@@ -1604,7 +1606,7 @@ mod tests {
         let err = read_async_file(&file, 1024, None, None, Some(vec![100]))
             .await
             .unwrap_err();
-        assert!(matches!(err, ArrowError::AvroError(_)));
+        assert!(matches!(err, ArrowError::InvalidArgumentError(_)));
         assert!(err.to_string().contains("out of bounds"));
     }
 
@@ -1615,7 +1617,7 @@ mod tests {
         let err = read_async_file(&file, 1024, None, None, Some(vec![0, 0]))
             .await
             .unwrap_err();
-        assert!(matches!(err, ArrowError::AvroError(_)));
+        assert!(matches!(err, ArrowError::InvalidArgumentError(_)));
         assert!(err.to_string().contains("Duplicate projection index"));
     }
 
@@ -0,0 +1,653 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Asynchronous Avro OCF reader for forward-only [`AsyncRead`] sources
+
+use crate::compression::CompressionCodec;
+use crate::errors::AvroError;
+use crate::reader::header::{Header, HeaderDecoder};
+use crate::reader::vlq::VLQDecoder;
+use crate::reader::{Decoder, block_context, block_error};
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, SchemaRef};
+use futures::future::BoxFuture;
+use futures::{FutureExt, Stream};
+use std::io::ErrorKind;
+use std::mem;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, BufReader};
+
+/// Reads and decodes the OCF header from `reader`, returning it along with its length
+async fn read_header_async<R: AsyncRead + Unpin>(
+    reader: &mut BufReader<R>,
+) -> Result<(Header, u64), AvroError> {
+    let mut decoder = HeaderDecoder::default();
+    let mut position = 0;
+    loop {
+        let buf = reader.fill_buf().await?;
+        if buf.is_empty() {
+            break;
+        }
+        let read = buf.len();
+        let decoded = decoder.decode(buf)?;
+        reader.consume(decoded);
+        position += decoded as u64;
+        if decoded != read {
+            break;
+        }
+    }
+    decoder
+        .flush()
+        .map(|header| (header, position))
+        .ok_or_else(|| AvroError::EOF("Unexpected EOF while reading Avro header".to_string()))
+}
+
+/// An asynchronous Avro **Object Container File** reader for any [`AsyncRead`]
+///
+/// Unlike [`AsyncAvroFileReader`](super::AsyncAvroFileReader), which fetches byte ranges
+/// from a seekable source of known size, this reads the file sequentially, making it
+/// suitable for sources such as network streams or the body of an object store `GET`
+/// request. Each block is read in full, decompressed, and then decoded into
+/// `RecordBatch` values bounded by the configured batch size.
+///
+/// Created with [`ReaderBuilder::build_async`](crate::reader::ReaderBuilder::build_async),
+/// which reads the header before returning. The errors returned for invalid blocks, and
+/// the effect of [`ReaderBuilder::with_partial_on_error`](crate::reader::ReaderBuilder::with_partial_on_error),
+/// are the same as for the synchronous [`Reader`](crate::reader::Reader).
+///
+/// # Example
+///
+/// ```
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # use std::io::Cursor;
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use arrow_schema::{DataType, Field, Schema};
+/// use arrow_avro::reader::ReaderBuilder;
+/// use arrow_avro::writer::AvroWriter;
+/// use futures::TryStreamExt;
+///
+/// let schema = Schema::new(vec![Field::new("id", DataType::Int32, false)]);
+/// let batch = RecordBatch::try_new(
+///     Arc::new(schema.clone()),
+///     vec![Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef],
+/// )?;
+/// let mut writer = AvroWriter::new(Vec::new(), schema)?;
+/// writer.write(&batch)?;
+/// writer.finish()?;
+/// let bytes = writer.into_inner();
+///
+/// // Any `tokio::io::AsyncRead` may be used, for example a `tokio::fs::File`
+/// let reader = ReaderBuilder::new()
+///     .build_async(Cursor::new(bytes))
+///     .await?;
+/// let batches: Vec<RecordBatch> = reader.try_collect().await?;
+/// assert_eq!(batches, vec![batch]);
+/// # Ok(())
+/// # }
+/// ```
+pub struct AsyncAvroReader<R> {
+    header: Header,
+    schema: SchemaRef,
+    state: StreamState<R>,
+}
+
+impl<R> std::fmt::Debug for AsyncAvroReader<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncAvroReader")
+            .field("header", &self.header)
+            .field("schema", &self.schema)
+            .finish_non_exhaustive()
+    }
+}
+
+type ReadFuture<R> =
+    BoxFuture<'static, (Box<BlockReader<R>>, Result<Option<RecordBatch>, AvroError>)>;
+
+enum StreamState<R> {
+    /// Waiting for the next call to `poll_next`
+    Idle(Box<BlockReader<R>>),
+    /// Reading the next batch
+    Reading(ReadFuture<R>),
+    /// Reached the end of the input
+    Finished,
+}
+
+impl<R: AsyncRead + Unpin> AsyncAvroReader<R> {
+    pub(crate) async fn try_new(
+        reader: R,
+        decoder_for: impl FnOnce(&Header) -> Result<Decoder, AvroError>,
+        partial_on_error: bool,
+    ) -> Result<Self, AvroError> {
+        let mut reader = BufReader::new(reader);
+        let (header, header_len) = read_header_async(&mut reader).await?;
+        let decoder = decoder_for(&header)?;
+        let schema = decoder.schema();
+        let block_reader = BlockReader {
+            reader,
+            decoder,
+            sync: header.sync(),
+            codec: header.compression()?,
+            block_data: Vec::new(),
+            block_count: 0,
+            block_cursor: 0,
+            block_index: 0,
+            offset: header_len,
+            current_block: (0, header_len),
+            partial_on_error,
+            pending_error: None,
+            failed: false,
+            finished: false,
+        };
+        Ok(Self {
+            header,
+            schema,
+            state: StreamState::Idle(Box::new(block_reader)),
+        })
+    }
+}
+
+impl<R> AsyncAvroReader<R> {
+    /// Returns the Arrow schema discovered from the Avro file header (or derived via
+    /// the optional reader schema).
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Returns a reference to the parsed Avro container‑file header (magic, metadata, codec, sync).
+    pub fn avro_header(&self) -> &Header {
+        &self.header
+    }
+}
+
+// To maintain compatibility with the expected stream results in the ecosystem, this returns ArrowError.
+impl<R: AsyncRead + Unpin + Send + 'static> Stream for AsyncAvroReader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match mem::replace(&mut self.state, StreamState::Finished) {
+                StreamState::Idle(mut reader) => {
+                    let future = async move {
+                        let result = reader.read().await;
+                        (reader, result)
+                    };
+                    self.state = StreamState::Reading(future.boxed());
+                }
+                StreamState::Reading(mut future) => {
+                    return match future.poll_unpin(cx) {
+                        Poll::Pending => {
+                            self.state = StreamState::Reading(future);
+                            Poll::Pending
+                        }
+                        Poll::Ready((reader, result)) => match result {
+                            Ok(Some(batch)) => {
+                                self.state = StreamState::Idle(reader);
+                                Poll::Ready(Some(Ok(batch)))
+                            }
+                            Ok(None) => Poll::Ready(None),
+                            Err(e) => {
+                                // The reader may still have a pending error to return
+                                self.state = StreamState::Idle(reader);
+                                Poll::Ready(Some(Err(e.into())))
+                            }
+                        },
+                    };
+                }
+                StreamState::Finished => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+/// The state of an [`AsyncAvroReader`] moved into the future reading each batch
+struct BlockReader<R> {
+    reader: BufReader<R>,
+    decoder: Decoder,
+    sync: [u8; 16],
+    codec: Option<CompressionCodec>,
+    block_data: Vec<u8>,
+    block_count: usize,
+    block_cursor: usize,
+    /// The index of the next block to read from `reader`
+    block_index: usize,
+    /// The number of bytes consumed from `reader`
+    offset: u64,
+    /// The index and byte offset of the block in `block_data`
+    current_block: (usize, u64),
+    partial_on_error: bool,
+    /// The error to return after the rows decoded before it, see `partial_on_error`
+    pending_error: Option<AvroError>,
+    failed: bool,
+    finished: bool,
+}
+
+impl<R: AsyncRead + Unpin> BlockReader<R> {
+    /// Reads the next `RecordBatch`, or `Ok(None)` on EOF, see [`Reader`](crate::reader::Reader)
+    async fn read(&mut self) -> Result<Option<RecordBatch>, AvroError> {
+        if self.failed {
+            return self.pending_error.take().map_or(Ok(None), Err);
+        }
+        while !self.finished && !self.decoder.batch_is_full() {
+            if self.block_count == 0 {
+                if self.block_cursor != self.block_data.len() {
+                    let (index, offset) = self.current_block;
+                    let err = block_error(
+                        index,
+                        offset,
+                        format!(
+                            "{} bytes remain after decoding the declared number of records",
+                            self.block_data.len() - self.block_cursor
+                        ),
+                    );
+                    return self.fail_block(err);
+                }
+                match self.read_block().await {
+                    Ok(true) => continue,
+                    Ok(false) => {
+                        self.finished = true;
+                        break;
+                    }
+                    Err(err) => return self.fail_block(err),
+                }
+            }
+            // Decode as many rows as will fit in the current batch
+            let (consumed, records_decoded) = match self
+                .decoder
+                .decode_block(&self.block_data[self.block_cursor..], self.block_count)
+            {
+                Ok(decoded) => decoded,
+                Err(e) => {
+                    // The rows decoded so far may be incomplete, so are never returned
+                    self.failed = true;
+                    let (index, offset) = self.current_block;
+                    return Err(e.with_context(block_context(index, offset)));
+                }
+            };
+            self.block_cursor += consumed;
+            self.block_count -= records_decoded;
+        }
+        self.decoder.flush_block()
+    }
+
+    /// Reads the next block into `block_data`, returning `false` at the end of the input
+    async fn read_block(&mut self) -> Result<bool, AvroError> {
+        let (index, offset) = (self.block_index, self.offset);
+        let err = |detail: String| block_error(index, offset, detail);
+
+        let Some(count) = self.read_long().await? else {
+            return Ok(false);
+        };
+        let size = self.read_long().await?.ok_or_else(|| {
+            err(format!(
+                "file truncated at byte offset {} within the block header",
+                self.offset
+            ))
+        })?;
+        let count = usize::try_from(count).map_err(|_| err(format!("invalid count {count}")))?;
+        let size = usize::try_from(size).map_err(|_| err(format!("invalid size {size}")))?;
+
+        // The size is read from the file, so the buffer only grows with the data read
+        let mut data = Vec::new();
+        (&mut self.reader)
+            .take(size as u64)
+            .read_to_end(&mut data)
+            .await?;
+        self.offset += data.len() as u64;
+        if data.len() != size {
+            return Err(err(format!(
+                "file truncated at byte offset {} with {} bytes of block data remaining",
+                self.offset,
+                size - data.len()
+            )));
+        }
+        let mut sync = [0; 16];
+        self.read_exact(&mut sync, index, offset, "sync marker")
+            .await?;
+        if sync != self.sync {
+            return Err(err(format!(
+                "sync marker at byte offset {} does not match the file header, \
+                 the declared block size may be incorrect",
+                self.offset - 16
+            )));
+        }
+
+        self.block_data = match &self.codec {
+            Some(codec) => codec
+                .decompress(&data)
+                .map_err(|e| e.with_context(block_context(index, offset)))?,
+            None => data,
+        };
+        self.block_count = count;
+        self.block_cursor = 0;
+        self.current_block = (index, offset);
+        self.block_index += 1;
+        Ok(true)
+    }
+
+    /// Reads a zig-zag encoded long, returning `None` if the input ends before its first byte
+    async fn read_long(&mut self) -> Result<Option<i64>, AvroError> {
+        let mut decoder = VLQDecoder::default();
+        let mut first = true;
+        loop {
+            let buf = self.reader.fill_buf().await?;
+            if buf.is_empty() {
+                return match first {
+                    true => Ok(None),
+                    false => Err(block_error(
+                        self.block_index,
+                        self.offset,
+                        format!(
+                            "file truncated at byte offset {} within the block header",
+                            self.offset
+                        ),
+                    )),
+                };
+            }
+            first = false;
+            let mut remaining = buf;
+            let value = decoder.long(&mut remaining);
+            let consumed = buf.len() - remaining.len();
+            self.reader.consume(consumed);
+            self.offset += consumed as u64;
+            if value.is_some() {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// Fills `buf` from the input, returning an error if the input ends first
+    async fn read_exact(
+        &mut self,
+        buf: &mut [u8],
+        index: usize,
+        offset: u64,
+        what: &str,
+    ) -> Result<(), AvroError> {
+        match self.reader.read_exact(buf).await {
+            Ok(_) => {
+                self.offset += buf.len() as u64;
+                Ok(())
+            }
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => Err(block_error(
+                index,
+                offset,
+                format!(
+                    "file truncated after byte offset {} while reading {} bytes of {what}",
+                    self.offset,
+                    buf.len()
+                ),
+            )),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Stops reading after an error in the framing of a block, see [`Reader`](crate::reader::Reader)
+    fn fail_block(&mut self, err: AvroError) -> Result<Option<RecordBatch>, AvroError> {
+        self.failed = true;
+        if self.partial_on_error {
+            if let Some(batch) = self.decoder.flush_block()? {
+                self.pending_error = Some(err);
+                return Ok(Some(batch));
+            }
+        }
+        Err(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::reader::ReaderBuilder;
+    use crate::writer::AvroWriter;
+    use crate::writer::WriterBuilder;
+    use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+    use futures::{StreamExt, TryStreamExt};
+    use std::fs::File;
+    use std::io::{BufReader, Cursor};
+    use std::pin::Pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll};
+    use tokio::io::{AsyncRead, ReadBuf};
+
+    /// An [`AsyncRead`] returning at most `chunk` bytes per read, and pending every other poll
+    struct Trickle {
+        data: Vec<u8>,
+        position: usize,
+        chunk: usize,
+        pending: bool,
+    }
+
+    impl Trickle {
+        fn new(data: Vec<u8>, chunk: usize) -> Self {
+            Self {
+                data,
+                position: 0,
+                chunk,
+                pending: false,
+            }
+        }
+    }
+
+    impl AsyncRead for Trickle {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<std::io::Result<()>> {
+            self.pending = !self.pending;
+            if self.pending {
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+            let end = self
+                .data
+                .len()
+                .min(self.position + self.chunk.min(buf.remaining()));
+            buf.put_slice(&self.data[self.position..end]);
+            self.position = end;
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    fn test_batches() -> Vec<RecordBatch> {
+        (0..5)
+            .map(|i| {
+                let ids = Int64Array::from_iter_values(i * 10..i * 10 + 10);
+                let names: StringArray = (i * 10..i * 10 + 10)
+                    .map(|v| (v % 3 != 0).then(|| format!("name_{v}")))
+                    .collect();
+                RecordBatch::try_from_iter(vec![
+                    ("id", Arc::new(ids) as ArrayRef),
+                    ("name", Arc::new(names) as ArrayRef),
+                ])
+                .unwrap()
+            })
+            .collect()
+    }
+
+    fn write_ocf(batches: &[RecordBatch]) -> Vec<u8> {
+        let schema = batches[0].schema().as_ref().clone();
+        let mut writer = AvroWriter::new(Vec::new(), schema).unwrap();
+        for batch in batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        writer.into_inner()
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_matches_sync() {
+        let file = "test/data/comprehensive_e2e.avro";
+        let sync: Vec<_> = ReaderBuilder::new()
+            .with_batch_size(7)
+            .build(BufReader::new(File::open(file).unwrap()))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        let reader = tokio::fs::File::open(file).await.unwrap();
+        let reader = ReaderBuilder::new()
+            .with_batch_size(7)
+            .build_async(reader)
+            .await
+            .unwrap();
+        assert_eq!(reader.schema(), sync[0].schema());
+        let batches: Vec<_> = reader.try_collect().await.unwrap();
+        assert_eq!(batches, sync);
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_batches_span_blocks() {
+        let batches = test_batches();
+        let data = write_ocf(&batches);
+        let expected = arrow::compute::concat_batches(&batches[0].schema(), &batches).unwrap();
+
+        for (batch_size, chunk) in [(7, 1), (10, 3), (1024, 4096)] {
+            let reader = ReaderBuilder::new()
+                .with_batch_size(batch_size)
+                .build_async(Trickle::new(data.clone(), chunk))
+                .await
+                .unwrap();
+            let out: Vec<_> = reader.try_collect().await.unwrap();
+            assert!(out.iter().all(|b| b.num_rows() <= batch_size));
+            let out = arrow::compute::concat_batches(&expected.schema(), &out).unwrap();
+            assert_eq!(out, expected);
+        }
+    }
+
+    #[cfg(feature = "snappy")]
+    #[tokio::test]
+    async fn test_async_reader_compressed() {
+        let batches = test_batches();
+        let schema = batches[0].schema().as_ref().clone();
+        let mut writer = WriterBuilder::new(schema)
+            .with_compression(Some(crate::compression::CompressionCodec::Snappy))
+            .build::<_, crate::writer::format::AvroOcfFormat>(Vec::new())
+            .unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        let data = writer.into_inner();
+
+        let reader = ReaderBuilder::new()
+            .with_batch_size(10)
+            .build_async(Trickle::new(data, 5))
+            .await
+            .unwrap();
+        let out: Vec<_> = reader.try_collect().await.unwrap();
+        assert_eq!(out, batches);
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_truncated() {
+        let batches = test_batches();
+        let data = write_ocf(&batches);
+        let truncated = data[..data.len() - 20].to_vec();
+
+        let mut reader = ReaderBuilder::new()
+            .with_batch_size(10)
+            .build_async(Cursor::new(truncated))
+            .await
+            .unwrap();
+        for expected in &batches[..4] {
+            assert_eq!(&reader.next().await.unwrap().unwrap(), expected);
+        }
+        let err = reader.next().await.unwrap().unwrap_err().to_string();
+        assert!(err.contains("Invalid Avro block 4"), "{err}");
+        assert!(err.contains("file truncated"), "{err}");
+        assert!(reader.next().await.is_none());
+
+        // The header itself is truncated
+        let err = ReaderBuilder::new()
+            .build_async(Cursor::new(data[..10].to_vec()))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Unexpected EOF"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_oversized_block() {
+        let data = write_ocf(&test_batches());
+        let h = crate::reader::header::read_header_info(Cursor::new(&data))
+            .unwrap()
+            .header_len() as usize;
+        // A block of one record declaring a size of 2^60 bytes, followed by 4 bytes
+        let mut data = data[..h].to_vec();
+        data.push(2);
+        let mut size = 1_u64 << 61;
+        while size >= 0x80 {
+            data.push(size as u8 | 0x80);
+            size >>= 7;
+        }
+        data.push(size as u8);
+        data.extend_from_slice(&[0; 4]);
+
+        let mut reader = ReaderBuilder::new()
+            .build_async(Cursor::new(data))
+            .await
+            .unwrap();
+        let err = reader.next().await.unwrap().unwrap_err().to_string();
+        assert!(err.contains("Invalid Avro block 0"), "{err}");
+        assert!(
+            err.contains(&format!(
+                "with {} bytes of block data remaining",
+                (1_u64 << 60) - 4
+            )),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_bad_sync_partial() {
+        let batches = test_batches();
+        let mut data = write_ocf(&batches);
+        let len = data.len();
+        data[len - 1] ^= 0xFF;
+
+        let reader = ReaderBuilder::new()
+            .with_batch_size(100)
+            .with_partial_on_error(true)
+            .build_async(Cursor::new(data))
+            .await
+            .unwrap();
+        let results: Vec<_> = reader.collect().await;
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap().num_rows(), 40);
+        let err = results[1].as_ref().unwrap_err().to_string();
+        assert!(err.contains("does not match the file header"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_empty_file() {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64, false)]);
+        let mut writer = AvroWriter::new(Vec::new(), schema.clone()).unwrap();
+        writer.finish().unwrap();
+        let data = writer.into_inner();
+
+        let reader = ReaderBuilder::new()
+            .build_async(Cursor::new(data))
+            .await
+            .unwrap();
+        assert_eq!(reader.schema().fields(), schema.fields());
+        let out: Vec<_> = reader.try_collect().await.unwrap();
+        assert!(out.is_empty());
+    }
+}
@@ -112,7 +112,7 @@ impl BlockDecoder {
                 }
                 BlockDecoderState::Sync => {
                     let to_decode = buf.len().min(self.bytes_remaining);
-                    let write = &mut self.in_progress.sync[16 - to_decode..];
+                    let write = &mut self.in_progress.sync[16 - self.bytes_remaining..];
                     write[..to_decode].copy_from_slice(&buf[..to_decode]);
                     self.bytes_remaining -= to_decode;
                     buf = &buf[to_decode..];
@@ -138,7 +138,6 @@ impl BlockDecoder {
     }
 }
 
-#[cfg(feature = "async")]
 impl BlockDecoder {
     pub(crate) fn state(&self) -> &BlockDecoderState {
         &self.state
@@ -0,0 +1,118 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Row filtering for the Avro [`Reader`](super::Reader)
+
+use crate::errors::AvroError;
+use crate::reader::record::{RecordDecoder, RecordSkipper};
+use arrow_array::{Array, BooleanArray, RecordBatch};
+use arrow_buffer::BooleanBuffer;
+use arrow_schema::ArrowError;
+use std::fmt::{Debug, Formatter};
+
+/// A predicate selecting the rows of an Avro file to decode, see
+/// [`ReaderBuilder::with_predicate`](super::ReaderBuilder::with_predicate)
+///
+/// The predicate is evaluated on a [`RecordBatch`] of the top-level fields in
+/// `projection` for the rows of each block, and returns a [`BooleanArray`] of the
+/// same length. Rows where it returns `false` or null are skipped without decoding
+/// the remaining fields.
+pub struct AvroPredicate {
+    projection: Vec<usize>,
+    f: Box<dyn FnMut(RecordBatch) -> Result<BooleanArray, ArrowError> + Send>,
+}
+
+impl AvroPredicate {
+    /// Create a new [`AvroPredicate`] evaluating `f` on the top-level fields at the
+    /// indices in `projection`
+    ///
+    /// As for [`ReaderBuilder::with_projection`](super::ReaderBuilder::with_projection),
+    /// the indices refer to the fields of the reader schema if one is provided, otherwise
+    /// to those of the writer schema. The fields are passed to `f` in this order.
+    pub fn new<F>(projection: Vec<usize>, f: F) -> Self
+    where
+        F: FnMut(RecordBatch) -> Result<BooleanArray, ArrowError> + Send + 'static,
+    {
+        Self {
+            projection,
+            f: Box::new(f),
+        }
+    }
+
+    /// Returns the indices of the top-level fields the predicate is evaluated on
+    pub fn projection(&self) -> &[usize] {
+        &self.projection
+    }
+}
+
+impl Debug for AvroPredicate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AvroPredicate")
+            .field("projection", &self.projection)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Evaluates an [`AvroPredicate`] on the rows of each block
+#[derive(Debug)]
+pub(crate) struct BlockFilter {
+    predicate: AvroPredicate,
+    /// Decodes the fields in the predicate's projection
+    decoder: RecordDecoder,
+    /// Skips the rows not selected by the predicate
+    skipper: RecordSkipper,
+}
+
+impl BlockFilter {
+    pub(crate) fn new(
+        predicate: AvroPredicate,
+        decoder: RecordDecoder,
+        skipper: RecordSkipper,
+    ) -> Self {
+        Self {
+            predicate,
+            decoder,
+            skipper,
+        }
+    }
+
+    pub(crate) fn skipper(&self) -> &RecordSkipper {
+        &self.skipper
+    }
+
+    /// Evaluates the predicate on the `count` rows of the block `data`, returning the
+    /// selected rows
+    pub(crate) fn evaluate(
+        &mut self,
+        data: &[u8],
+        count: usize,
+    ) -> Result<BooleanBuffer, AvroError> {
+        self.decoder.decode(data, count)?;
+        let batch = self.decoder.flush()?;
+        let selection = (self.predicate.f)(batch)?;
+        if selection.len() != count {
+            return Err(AvroError::InvalidArgument(format!(
+                "Predicate returned {} values for a block of {count} rows",
+                selection.len()
+            )));
+        }
+        Ok(match selection.nulls() {
+            Some(nulls) => selection.values() & nulls.inner(),
+            None => selection.values().clone(),
+        })
+    }
+}
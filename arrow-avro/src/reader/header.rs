@@ -112,7 +112,7 @@ impl Header {
             Some(b"zstandard") => Ok(Some(CompressionCodec::ZStandard)),
             Some(b"bzip2") => Ok(Some(CompressionCodec::Bzip2)),
             Some(b"xz") => Ok(Some(CompressionCodec::Xz)),
-            Some(v) => Err(AvroError::ParseError(format!(
+            Some(v) => Err(AvroError::NYI(format!(
                 "Unrecognized compression codec \'{}\'",
                 String::from_utf8_lossy(v)
             ))),
@@ -124,7 +124,7 @@ impl Header {
         self.get(SCHEMA_METADATA_KEY)
             .map(|x| {
                 serde_json::from_slice(x).map_err(|e| {
-                    AvroError::ParseError(format!("Failed to parse Avro schema JSON: {e}"))
+                    AvroError::SchemaError(format!("Failed to parse Avro schema JSON: {e}"))
                 })
             })
             .transpose()
@@ -159,11 +159,11 @@ impl HeaderInfo {
     /// Returns the writer schema for this file.
     pub fn writer_schema(&self) -> Result<AvroSchema, AvroError> {
         let raw = self.0.header.get(SCHEMA_METADATA_KEY).ok_or_else(|| {
-            AvroError::ParseError("No Avro schema present in file header".to_string())
+            AvroError::SchemaError("No Avro schema present in file header".to_string())
         })?;
         let json_string = str::from_utf8(raw)
             .map_err(|e| {
-                AvroError::ParseError(format!("Invalid UTF-8 in Avro schema header: {e}"))
+                AvroError::SchemaError(format!("Invalid UTF-8 in Avro schema header: {e}"))
             })?
             .to_string();
         Ok(AvroSchema::new(json_string))
@@ -314,7 +314,7 @@ impl HeaderDecoder {
                 }
                 HeaderDecoderState::Sync => {
                     let to_decode = buf.len().min(self.bytes_remaining);
-                    let write = &mut self.sync_marker[16 - to_decode..];
+                    let write = &mut self.sync_marker[16 - self.bytes_remaining..];
                     write[..to_decode].copy_from_slice(&buf[..to_decode]);
                     self.bytes_remaining -= to_decode;
                     buf = &buf[to_decode..];
@@ -478,23 +478,26 @@
 //!   descriptive error. Populate the store up front to avoid this.
 //!
 //! ---
-use crate::codec::{AvroFieldBuilder, Tz};
-use crate::errors::AvroError;
+use crate::codec::{AvroFieldBuilder, DEFAULT_MAX_RECURSION_DEPTH, Tz};
+use crate::errors::{AvroError, ErrorContext};
 use crate::reader::header::read_header;
 use crate::schema::{
-    AvroSchema, CONFLUENT_MAGIC, Fingerprint, FingerprintAlgorithm, SCHEMA_METADATA_KEY,
-    SINGLE_OBJECT_MAGIC, Schema, SchemaStore,
+    AVRO_NON_RECORD_ROOT_FIELD_NAME, AvroSchema, CONFLUENT_MAGIC, Fingerprint,
+    FingerprintAlgorithm, SCHEMA_METADATA_KEY, SINGLE_OBJECT_MAGIC, Schema, SchemaStore,
 };
 use arrow_array::{RecordBatch, RecordBatchReader};
-use arrow_schema::{ArrowError, SchemaRef};
-use block::BlockDecoder;
+use arrow_buffer::BooleanBuffer;
+use arrow_schema::{ArrowError, DataType, SchemaRef};
+use block::{BlockDecoder, BlockDecoderState};
+use filter::BlockFilter;
 use header::Header;
 use indexmap::IndexMap;
-use record::RecordDecoder;
+use record::{RecordDecoder, RecordSkipper};
 use std::io::BufRead;
 
 mod block;
 mod cursor;
+mod filter;
 mod header;
 mod record;
 mod vlq;
@@ -502,12 +505,13 @@ mod vlq;
 #[cfg(feature = "async")]
 pub mod async_reader;
 
+pub use filter::AvroPredicate;
 pub use header::{HeaderInfo, read_header_info};
 
 #[cfg(feature = "object_store")]
 pub use async_reader::AvroObjectReader;
 #[cfg(feature = "async")]
-pub use async_reader::{AsyncAvroFileReader, AsyncFileReader};
+pub use async_reader::{AsyncAvroFileReader, AsyncAvroReader, AsyncFileReader};
 
 fn is_incomplete_data(err: &AvroError) -> bool {
     matches!(
@@ -812,7 +816,7 @@ impl Decoder {
         // If the fingerprint indicates a schema change, prepare to switch decoders.
         if self.active_fingerprint != Some(new_fingerprint) {
             let Some(new_decoder) = self.cache.shift_remove(&new_fingerprint) else {
-                return Err(AvroError::ParseError(format!(
+                return Err(AvroError::SchemaError(format!(
                     "Unknown fingerprint: {new_fingerprint:?}"
                 )));
             };
@@ -895,6 +899,33 @@ impl Decoder {
         Ok((consumed, to_decode))
     }
 
+    // Decode the rows of `data` (an OCF block payload) in `selection` that fit in the
+    // remaining capacity, skipping the rows not selected with `skipper`.
+    //
+    // Returns the number of bytes consumed from `data` along with the number of records
+    // decoded or skipped.
+    fn decode_block_filtered(
+        &mut self,
+        data: &[u8],
+        selection: &BooleanBuffer,
+        skipper: &RecordSkipper,
+    ) -> Result<(usize, usize), AvroError> {
+        let mut consumed = 0;
+        let mut row = 0;
+        for (start, end) in selection.set_slices() {
+            consumed += skipper.skip(&data[consumed..], start - row)?;
+            let to_decode = std::cmp::min(end - start, self.remaining_capacity);
+            consumed += self.active_decoder.decode(&data[consumed..], to_decode)?;
+            self.remaining_capacity -= to_decode;
+            row = start + to_decode;
+            if row < end {
+                return Ok((consumed, row));
+            }
+        }
+        consumed += skipper.skip(&data[consumed..], selection.len() - row)?;
+        Ok((consumed, selection.len()))
+    }
+
     // Produce a `RecordBatch` if at least one row is fully decoded, returning
     // `Ok(None)` if no new rows are available.
     fn flush_block(&mut self) -> Result<Option<RecordBatch>, AvroError> {
@@ -902,6 +933,24 @@ impl Decoder {
     }
 }
 
+/// Returns the reader schema for the file with `header`, or its writer schema if there
+/// is no `reader_schema`
+fn file_reader_schema(
+    header: &Header,
+    reader_schema: Option<&AvroSchema>,
+) -> Result<AvroSchema, AvroError> {
+    if let Some(reader_schema) = reader_schema {
+        return Ok(reader_schema.clone());
+    }
+    let raw = header.get(SCHEMA_METADATA_KEY).ok_or_else(|| {
+        AvroError::SchemaError("No Avro schema present in file header".to_string())
+    })?;
+    let json_string = std::str::from_utf8(raw)
+        .map_err(|e| AvroError::SchemaError(format!("Invalid UTF-8 in Avro schema header: {e}")))?
+        .to_string();
+    Ok(AvroSchema::new(json_string))
+}
+
 /// A builder that configures and constructs Avro readers and decoders.
 ///
 /// `ReaderBuilder` is the primary entry point for this module. It supports:
@@ -933,6 +982,10 @@ impl Decoder {
 ///   Confluent framing. Maps fingerprints to Avro schemas. See `Self::with_writer_schema_store`.
 /// * **`active_fingerprint`**: Optional starting fingerprint for streaming decode when the
 ///   first frame omits one (rare). See `Self::with_active_fingerprint`.
+/// * **`partial_on_error`**: Yield the rows decoded before a corrupt OCF block, before
+///   returning the error (default: `false`). See `Self::with_partial_on_error`.
+/// * **`predicate`**: Optional [`AvroPredicate`] selecting the rows of an OCF file to decode
+///   (default: `None`). See `Self::with_predicate`.
 ///
 /// ### Examples
 ///
@@ -974,6 +1027,13 @@ pub struct ReaderBuilder {
     projection: Option<Vec<usize>>,
     writer_schema_store: Option<SchemaStore>,
     active_fingerprint: Option<Fingerprint>,
+    partial_on_error: bool,
+    root_field_name: String,
+    field_types: IndexMap<String, DataType>,
+    replace_invalid_utf8: bool,
+    max_recursion_depth: usize,
+    skip_utf8_validation: bool,
+    predicate: Option<AvroPredicate>,
 }
 
 impl Default for ReaderBuilder {
@@ -987,6 +1047,13 @@ impl Default for ReaderBuilder {
             projection: None,
             writer_schema_store: None,
             active_fingerprint: None,
+            partial_on_error: false,
+            root_field_name: AVRO_NON_RECORD_ROOT_FIELD_NAME.to_string(),
+            field_types: IndexMap::new(),
+            replace_invalid_utf8: false,
+            max_recursion_depth: DEFAULT_MAX_RECURSION_DEPTH,
+            skip_utf8_validation: false,
+            predicate: None,
         }
     }
 }
@@ -1002,6 +1069,13 @@ impl ReaderBuilder {
     /// * `projection = None`
     /// * `writer_schema_store = None`
     /// * `active_fingerprint = None`
+    /// * `partial_on_error = false`
+    /// * `root_field_name = "value"`
+    /// * `field_types = {}`
+    /// * `replace_invalid_utf8 = false`
+    /// * `max_recursion_depth = 8`
+    /// * `skip_utf8_validation = false`
+    /// * `predicate = None`
     pub fn new() -> Self {
         Self::default()
     }
@@ -1019,8 +1093,12 @@ impl ReaderBuilder {
             .with_utf8view(self.utf8_view)
             .with_strict_mode(self.strict_mode)
             .with_tz(self.tz)
+            .with_root_field_name(&self.root_field_name)
+            .with_field_types(&self.field_types)
+            .with_replace_invalid_utf8(self.replace_invalid_utf8)
+            .with_max_recursion_depth(self.max_recursion_depth)
             .build()?;
-        RecordDecoder::try_new_with_options(root.data_type())
+        RecordDecoder::try_new_with_options(root.data_type(), self.skip_utf8_validation)
     }
 
     fn make_record_decoder_from_schemas(
@@ -1032,6 +1110,26 @@ impl ReaderBuilder {
         self.make_record_decoder(writer_schema, reader_schema_raw.as_ref())
     }
 
+    /// Builds the [`BlockFilter`] evaluating `predicate` on the rows of the file with `header`
+    fn make_filter(
+        &self,
+        predicate: AvroPredicate,
+        header: &Header,
+    ) -> Result<BlockFilter, AvroError> {
+        let writer_schema = header.schema()?.ok_or_else(|| {
+            AvroError::SchemaError("No Avro schema present in file header".into())
+        })?;
+        let reader_schema = file_reader_schema(header, self.reader_schema.as_ref())?
+            .project(predicate.projection())?;
+        let decoder =
+            self.make_record_decoder_from_schemas(&writer_schema, Some(&reader_schema))?;
+        let writer = AvroFieldBuilder::new(&writer_schema)
+            .with_max_recursion_depth(self.max_recursion_depth)
+            .build()?;
+        let skipper = RecordSkipper::try_new(writer.data_type())?;
+        Ok(BlockFilter::new(predicate, decoder, skipper))
+    }
+
     fn make_decoder(
         &self,
         header: Option<&Header>,
@@ -1039,31 +1137,12 @@ impl ReaderBuilder {
     ) -> Result<Decoder, AvroError> {
         if let Some(hdr) = header {
             let writer_schema = hdr.schema()?.ok_or_else(|| {
-                AvroError::ParseError("No Avro schema present in file header".into())
+                AvroError::SchemaError("No Avro schema present in file header".into())
             })?;
             let projected_reader_schema = self
                 .projection
                 .as_deref()
-                .map(|projection| {
-                    let base_schema = if let Some(reader_schema) = reader_schema {
-                        reader_schema.clone()
-                    } else {
-                        let raw = hdr.get(SCHEMA_METADATA_KEY).ok_or_else(|| {
-                            AvroError::ParseError(
-                                "No Avro schema present in file header".to_string(),
-                            )
-                        })?;
-                        let json_string = std::str::from_utf8(raw)
-                            .map_err(|e| {
-                                AvroError::ParseError(format!(
-                                    "Invalid UTF-8 in Avro schema header: {e}"
-                                ))
-                            })?
-                            .to_string();
-                        AvroSchema::new(json_string)
-                    };
-                    base_schema.project(projection)
-                })
+                .map(|projection| file_reader_schema(hdr, reader_schema)?.project(projection))
                 .transpose()?;
             let effective_reader_schema = projected_reader_schema.as_ref().or(reader_schema);
             let record_decoder =
@@ -1077,11 +1156,11 @@ impl ReaderBuilder {
             ));
         }
         let store = self.writer_schema_store.as_ref().ok_or_else(|| {
-            AvroError::ParseError("Writer schema store required for raw Avro".into())
+            AvroError::InvalidArgument("Writer schema store required for raw Avro".into())
         })?;
         let fingerprints = store.fingerprints();
         if fingerprints.is_empty() {
-            return Err(AvroError::ParseError(
+            return Err(AvroError::InvalidArgument(
                 "Writer schema store must contain at least one schema".into(),
             ));
         }
@@ -1089,7 +1168,7 @@ impl ReaderBuilder {
             .active_fingerprint
             .or_else(|| fingerprints.first().copied())
             .ok_or_else(|| {
-                AvroError::ParseError("Could not determine initial schema fingerprint".into())
+                AvroError::SchemaError("Could not determine initial schema fingerprint".into())
             })?;
         let projection = self.projection.as_deref();
         let projected_reader_schema = match (projection, reader_schema) {
@@ -1102,7 +1181,7 @@ impl ReaderBuilder {
             let avro_schema = match store.lookup(&fingerprint) {
                 Some(schema) => schema,
                 None => {
-                    return Err(AvroError::General(format!(
+                    return Err(AvroError::SchemaError(format!(
                         "Fingerprint {fingerprint:?} not found in schema store",
                     )));
                 }
@@ -1132,7 +1211,7 @@ impl ReaderBuilder {
             }
         }
         let active_decoder = active_decoder.ok_or_else(|| {
-            AvroError::General(format!(
+            AvroError::SchemaError(format!(
                 "Initial fingerprint {start_fingerprint:?} not found in schema store"
             ))
         })?;
@@ -1281,26 +1360,211 @@ impl ReaderBuilder {
         self
     }
 
+    /// Controls how a `Reader` handles a corrupt or truncated OCF block.
+    ///
+    /// The `Reader` validates the framing of each block: a block must be complete, be
+    /// followed by the sync marker from the file header, and contain exactly the number of
+    /// records it declares. Violations are reported as errors identifying the index of the
+    /// block and its byte offset within the file.
+    ///
+    /// When `false` (the default), such an error is returned immediately, discarding any
+    /// rows of the current batch decoded from preceding blocks. When `true`, those rows are
+    /// first returned as a final, possibly short, batch, followed by the error.
+    ///
+    /// This has no effect on errors decoding the records themselves, or on `Decoder`.
+    pub fn with_partial_on_error(mut self, partial_on_error: bool) -> Self {
+        self.partial_on_error = partial_on_error;
+        self
+    }
+
+    /// Sets the name of the column used for schemas whose root is not a record.
+    ///
+    /// Avro permits any schema at the root, e.g. `{"type":"array","items":"long"}` or
+    /// `["null","string"]`. Such a schema is read as a `RecordBatch` with a single column
+    /// of this name, holding one row per Avro datum. This applies to both the writer
+    /// and reader schemas.
+    ///
+    /// The default is `"value"`.
+    pub fn with_root_field_name(mut self, root_field_name: impl Into<String>) -> Self {
+        self.root_field_name = root_field_name.into();
+        self
+    }
+
+    /// Reads the field `name` of the root record as the Arrow `data_type`, rather than
+    /// the type derived from its Avro type.
+    ///
+    /// This allows an Avro `bytes` field to be read as `Utf8`, and an Avro `string` field
+    /// to be read as `Binary`, `Utf8` or `Utf8View`, without casting the decoded columns.
+    /// When reading `bytes` as `Utf8`, each value is validated to be UTF-8, see
+    /// [`Self::with_replace_invalid_utf8`].
+    ///
+    /// Only `bytes` and `string` fields of the root record present in the writer schema
+    /// can be overridden. Any other override results in an error naming the field when
+    /// the `Reader` or `Decoder` is built.
+    ///
+    /// ```
+    /// # use arrow_avro::reader::ReaderBuilder;
+    /// # use arrow_schema::DataType;
+    /// let builder = ReaderBuilder::new().with_field_type("payload", DataType::Utf8);
+    /// ```
+    pub fn with_field_type(mut self, name: impl Into<String>, data_type: DataType) -> Self {
+        self.field_types.insert(name.into(), data_type);
+        self
+    }
+
+    /// Controls how invalid UTF-8 is handled when reading Avro `bytes` as a string.
+    ///
+    /// This applies to `bytes` read as a string, either via [`Self::with_field_type`] or
+    /// a reader schema promoting `bytes` to `string`.
+    ///
+    /// When `false` (the default), invalid UTF-8 results in an error. When `true`, each
+    /// invalid sequence is replaced with `U+FFFD`, the Unicode replacement character.
+    pub fn with_replace_invalid_utf8(mut self, replace_invalid_utf8: bool) -> Self {
+        self.replace_invalid_utf8 = replace_invalid_utf8;
+        self
+    }
+
+    /// Sets the number of times a recursive record may be nested within itself.
+    ///
+    /// Avro records may refer to themselves, for example a linked list whose `next`
+    /// field is a nullable reference to the list's own record. Arrow types cannot be
+    /// recursive, so each such reference is unrolled into a nested `Struct` (or `List`,
+    /// `Map` or `Union` of `Struct`) up to this depth.
+    ///
+    /// Beyond the maximum depth the self-reference is read as a `Null` column. Null values
+    /// there are read as usual, while any other value results in an error.
+    ///
+    /// As a record with several recursive fields grows exponentially with the depth,
+    /// building the reader fails with a schema error if unrolling would create more
+    /// than 100,000 schema nodes.
+    ///
+    /// The default is `8`.
+    pub fn with_max_recursion_depth(mut self, max_recursion_depth: usize) -> Self {
+        self.max_recursion_depth = max_recursion_depth;
+        self
+    }
+
+    /// Specifies if UTF-8 validation of Avro `string` values should be skipped (defaults to `false`)
+    ///
+    /// Validating that each string is valid UTF-8 can be a significant part of the cost of
+    /// decoding string-heavy data. See [`Self::with_utf8_view`] to also decode strings
+    /// directly into a `StringViewArray`.
+    ///
+    /// # Safety
+    ///
+    /// This flag must only be set to `true` when you trust the input data and are sure all
+    /// values read as strings are valid UTF-8, otherwise undefined behavior may result.
+    ///
+    /// For example, some programs may wish to trust reading Avro files written by the same
+    /// process that created the files.
+    pub unsafe fn with_skip_utf8_validation(mut self, skip_utf8_validation: bool) -> Self {
+        self.skip_utf8_validation = skip_utf8_validation;
+        self
+    }
+
+    /// Only decode the rows of an OCF file selected by `predicate`.
+    ///
+    /// For each block, the fields in the predicate's projection are decoded first and the
+    /// predicate evaluated on them. The rows it selects are then decoded, while the other
+    /// rows are skipped without materializing any of their fields.
+    ///
+    /// This is only supported by `Self::build`; `Self::build_async` and
+    /// `Self::build_decoder` return an error if a predicate is set.
+    ///
+    /// ```
+    /// # use std::io::Cursor;
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{cast::AsArray, types::Int64Type, ArrayRef, Int64Array, RecordBatch};
+    /// # use arrow_avro::reader::{AvroPredicate, ReaderBuilder};
+    /// # use arrow_avro::writer::AvroWriter;
+    /// # use arrow::compute::kernels::cmp::gt;
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let schema = Schema::new(vec![Field::new("id", DataType::Int64, false)]);
+    /// # let batch = RecordBatch::try_new(
+    /// #     Arc::new(schema.clone()),
+    /// #     vec![Arc::new(Int64Array::from(vec![1, 2, 3])) as ArrayRef],
+    /// # )?;
+    /// # let mut writer = AvroWriter::new(Vec::new(), schema)?;
+    /// # writer.write(&batch)?;
+    /// # writer.finish()?;
+    /// # let bytes = writer.into_inner();
+    /// // Only read the rows where `id > 1`
+    /// let predicate = AvroPredicate::new(vec![0], |batch| {
+    ///     gt(batch.column(0), &Int64Array::new_scalar(1))
+    /// });
+    /// let mut reader = ReaderBuilder::new()
+    ///     .with_predicate(predicate)
+    ///     .build(Cursor::new(bytes))?;
+    /// let batch = reader.next().unwrap()?;
+    /// assert_eq!(batch.column(0).as_primitive::<Int64Type>().values(), &[2, 3]);
+    /// # Ok(()) }
+    /// ```
+    pub fn with_predicate(mut self, predicate: AvroPredicate) -> Self {
+        self.predicate = Some(predicate);
+        self
+    }
+
     /// Build a `Reader` (OCF) from this builder and a `BufRead`.
     ///
     /// This reads and validates the OCF header, initializes an internal row decoder from
     /// the discovered writer (and optional reader) schema, and prepares to iterate blocks,
     /// decompressing if necessary.
-    pub fn build<R: BufRead>(self, mut reader: R) -> Result<Reader<R>, ArrowError> {
-        let (header, _) = read_header(&mut reader)?;
+    pub fn build<R: BufRead>(mut self, mut reader: R) -> Result<Reader<R>, ArrowError> {
+        let (header, header_len) = read_header(&mut reader)?;
         let decoder = self.make_decoder(Some(&header), self.reader_schema.as_ref())?;
+        let filter = self
+            .predicate
+            .take()
+            .map(|predicate| self.make_filter(predicate, &header))
+            .transpose()?;
         Ok(Reader {
             reader,
             header,
             decoder,
+            filter,
+            block_selection: None,
             block_decoder: BlockDecoder::default(),
             block_data: Vec::new(),
             block_count: 0,
             block_cursor: 0,
+            block_index: 0,
+            block_offset: header_len,
+            offset: header_len,
+            current_block: (0, header_len),
+            partial_on_error: self.partial_on_error,
+            pending_error: None,
+            failed: false,
             finished: false,
         })
     }
 
+    /// Build an [`AsyncAvroReader`] (OCF) from this builder and a [`tokio::io::AsyncRead`].
+    ///
+    /// Like [`Self::build`], this reads and validates the OCF header before returning.
+    /// The file is then read sequentially, one block at a time, as the returned
+    /// [`Stream`](futures::Stream) is polled.
+    #[cfg(feature = "async")]
+    pub async fn build_async<R: tokio::io::AsyncRead + Unpin>(
+        self,
+        reader: R,
+    ) -> Result<AsyncAvroReader<R>, ArrowError> {
+        if self.predicate.is_some() {
+            return Err(ArrowError::InvalidArgumentError(
+                "A predicate is only supported when building a Reader".to_string(),
+            ));
+        }
+        let reader_schema = self.reader_schema.clone();
+        let partial_on_error = self.partial_on_error;
+        AsyncAvroReader::try_new(
+            reader,
+            |header| self.make_decoder(Some(header), reader_schema.as_ref()),
+            partial_on_error,
+        )
+        .await
+        .map_err(ArrowError::from)
+    }
+
     /// Build a streaming `Decoder` from this builder.
     ///
     /// # Requirements
@@ -1315,6 +1579,11 @@ impl ReaderBuilder {
                 "Building a decoder requires a writer schema store".to_string(),
             ));
         }
+        if self.predicate.is_some() {
+            return Err(ArrowError::InvalidArgumentError(
+                "A predicate is only supported when building a Reader".to_string(),
+            ));
+        }
         self.make_decoder(None, self.reader_schema.as_ref())
             .map_err(ArrowError::from)
     }
@@ -1334,10 +1603,25 @@ pub struct Reader<R: BufRead> {
     reader: R,
     header: Header,
     decoder: Decoder,
+    filter: Option<BlockFilter>,
+    /// The rows of the current block selected by `filter`
+    block_selection: Option<BooleanBuffer>,
     block_decoder: BlockDecoder,
     block_data: Vec<u8>,
     block_count: usize,
     block_cursor: usize,
+    /// The index of the next block to read from `reader`
+    block_index: usize,
+    /// The byte offset of the next block to read from `reader`
+    block_offset: u64,
+    /// The number of bytes consumed from `reader`
+    offset: u64,
+    /// The index and byte offset of the block in `block_data`
+    current_block: (usize, u64),
+    partial_on_error: bool,
+    /// The error to return after the rows decoded before it, see `partial_on_error`
+    pending_error: Option<AvroError>,
+    failed: bool,
     finished: bool,
 }
 
@@ -1358,26 +1642,78 @@ impl<R: BufRead> Reader<R> {
     /// Batches are bounded by `batch_size`; a single OCF block may yield multiple batches,
     /// and a batch may also span multiple blocks.
     fn read(&mut self) -> Result<Option<RecordBatch>, AvroError> {
+        if self.failed {
+            return self.pending_error.take().map_or(Ok(None), Err);
+        }
         'outer: while !self.finished && !self.decoder.batch_is_full() {
-            while self.block_cursor == self.block_data.len() {
+            while self.block_count == 0 {
+                if self.block_cursor != self.block_data.len() {
+                    let (index, offset) = self.current_block;
+                    let err = block_error(
+                        index,
+                        offset,
+                        format!(
+                            "{} bytes remain after decoding the declared number of records",
+                            self.block_data.len() - self.block_cursor
+                        ),
+                    );
+                    return self.fail_block(err);
+                }
                 let buf = self.reader.fill_buf()?;
                 if buf.is_empty() {
+                    if self.offset != self.block_offset {
+                        let err = self.truncated_block_error();
+                        return self.fail_block(err);
+                    }
                     self.finished = true;
                     break 'outer;
                 }
                 // Try to decode another block from the buffered reader.
-                let consumed = self.block_decoder.decode(buf)?;
+                let consumed = match self.block_decoder.decode(buf) {
+                    Ok(consumed) => consumed,
+                    Err(e) => {
+                        let err =
+                            e.with_context(block_context(self.block_index, self.block_offset));
+                        return self.fail_block(err);
+                    }
+                };
                 self.reader.consume(consumed);
+                self.offset += consumed as u64;
                 if let Some(block) = self.block_decoder.flush() {
                     // Successfully decoded a block.
-                    self.block_data = if let Some(ref codec) = self.header.compression()? {
-                        let decompressed: Vec<u8> = codec.decompress(&block.data)?;
-                        decompressed
-                    } else {
-                        block.data
+                    let (index, offset) = (self.block_index, self.block_offset);
+                    if block.sync != self.header.sync() {
+                        let err = block_error(
+                            index,
+                            offset,
+                            format!(
+                                "sync marker at byte offset {} does not match the file header, \
+                                 the declared block size may be incorrect",
+                                self.offset - 16
+                            ),
+                        );
+                        return self.fail_block(err);
+                    }
+                    self.block_data = match self.header.compression()? {
+                        Some(codec) => codec
+                            .decompress(&block.data)
+                            .map_err(|e| e.with_context(block_context(index, offset)))?,
+                        None => block.data,
                     };
+                    if let Some(filter) = self.filter.as_mut() {
+                        match filter.evaluate(&self.block_data, block.count) {
+                            Ok(selection) => self.block_selection = Some(selection),
+                            Err(e) => {
+                                self.failed = true;
+                                return Err(e.with_context(block_context(index, offset)));
+                            }
+                        }
+                    }
                     self.block_count = block.count;
                     self.block_cursor = 0;
+                    self.current_block = (index, offset);
+                    self.block_index += 1;
+                    self.block_offset = self.offset;
                 } else if consumed == 0 {
                     // The block decoder made no progress on a non-empty buffer.
                     return Err(AvroError::ParseError(
@@ -1386,16 +1722,79 @@ impl<R: BufRead> Reader<R> {
                 }
             }
             // Decode as many rows as will fit in the current batch
-            if self.block_cursor < self.block_data.len() {
-                let (consumed, records_decoded) = self
-                    .decoder
-                    .decode_block(&self.block_data[self.block_cursor..], self.block_count)?;
-                self.block_cursor += consumed;
-                self.block_count -= records_decoded;
-            }
+            let data = &self.block_data[self.block_cursor..];
+            let decoded = match (&self.filter, &self.block_selection) {
+                (Some(filter), Some(selection)) => {
+                    let remaining =
+                        selection.slice(selection.len() - self.block_count, self.block_count);
+                    self.decoder
+                        .decode_block_filtered(data, &remaining, filter.skipper())
+                }
+                _ => self.decoder.decode_block(data, self.block_count),
+            };
+            let (consumed, records_decoded) = match decoded {
+                Ok(decoded) => decoded,
+                Err(e) => {
+                    // The rows decoded so far may be incomplete, so are never returned
+                    self.failed = true;
+                    let (index, offset) = self.current_block;
+                    return Err(e.with_context(block_context(index, offset)));
+                }
+            };
+            self.block_cursor += consumed;
+            self.block_count -= records_decoded;
         }
         self.decoder.flush_block()
     }
+
+    /// Returns the error for a block truncated by the end of the input
+    fn truncated_block_error(&self) -> AvroError {
+        let detail = match self.block_decoder.state() {
+            BlockDecoderState::Data => format!(
+                "file truncated at byte offset {} with {} bytes of block data remaining",
+                self.offset,
+                self.block_decoder.bytes_remaining()
+            ),
+            BlockDecoderState::Sync => format!(
+                "file truncated at byte offset {} with {} bytes of sync marker remaining",
+                self.offset,
+                self.block_decoder.bytes_remaining()
+            ),
+            _ => format!(
+                "file truncated at byte offset {} within the block header",
+                self.offset
+            ),
+        };
+        block_error(self.block_index, self.block_offset, detail)
+    }
+
+    /// Stops reading after an error in the framing of a block
+    ///
+    /// As the rows decoded from preceding blocks are complete, if `partial_on_error` is
+    /// set these are returned before the error
+    fn fail_block(&mut self, err: AvroError) -> Result<Option<RecordBatch>, AvroError> {
+        self.failed = true;
+        if self.partial_on_error {
+            if let Some(batch) = self.decoder.flush_block()? {
+                self.pending_error = Some(err);
+                return Ok(Some(batch));
+            }
+        }
+        Err(err)
+    }
+}
+
+/// Returns the [`ErrorContext`] locating an OCF block by its index and byte offset
+fn block_context(index: usize, offset: u64) -> ErrorContext {
+    ErrorContext::new().with_block(index).with_offset(offset)
+}
+
+/// Returns an error for a problem with the framing of an OCF block
+fn block_error(index: usize, offset: u64, detail: impl std::fmt::Display) -> AvroError {
+    AvroError::ParseError(format!(
+        "Invalid Avro block {index} at byte offset {offset}: {detail}"
+    ))
+    .with_context(block_context(index, offset))
 }
 
 impl<R: BufRead> Iterator for Reader<R> {
@@ -1415,9 +1814,10 @@ impl<R: BufRead> RecordBatchReader for Reader<R> {
 #[cfg(test)]
 mod test {
     use crate::codec::{AvroFieldBuilder, Tz};
+    use crate::errors::{AvroError, ErrorKind, error_kind};
     use crate::reader::header::HeaderDecoder;
     use crate::reader::record::RecordDecoder;
-    use crate::reader::{Decoder, Reader, ReaderBuilder};
+    use crate::reader::{AvroPredicate, Decoder, Reader, ReaderBuilder};
     use crate::schema::{
         AVRO_ENUM_SYMBOLS_METADATA_KEY, AVRO_NAME_METADATA_KEY, AVRO_NAMESPACE_METADATA_KEY,
         AvroSchema, CONFLUENT_MAGIC, Fingerprint, FingerprintAlgorithm, PrimitiveType,
@@ -1432,14 +1832,12 @@ mod test {
     #[cfg(feature = "snappy")]
     use arrow_array::builder::{Float64Builder, MapFieldNames};
     use arrow_array::cast::AsArray;
-    #[cfg(not(feature = "avro_custom_types"))]
-    use arrow_array::types::Int64Type;
     #[cfg(feature = "avro_custom_types")]
     use arrow_array::types::{
         DurationMicrosecondType, DurationMillisecondType, DurationNanosecondType,
         DurationSecondType,
     };
-    use arrow_array::types::{Int32Type, IntervalMonthDayNanoType};
+    use arrow_array::types::{Int32Type, Int64Type, IntervalMonthDayNanoType};
     use arrow_array::*;
     #[cfg(feature = "snappy")]
     use arrow_buffer::{Buffer, NullBuffer};
@@ -1458,6 +1856,7 @@ mod test {
     use futures::{Stream, StreamExt, TryStreamExt, stream};
     use serde_json::{Value, json};
     use std::collections::HashMap;
+    use std::error::Error;
     use std::fs::File;
     use std::io::{BufReader, Cursor};
     use std::sync::Arc;
@@ -1794,6 +2193,174 @@ mod test {
         w.into_inner()
     }
 
+    /// Reads `bytes` in small chunks, returning the number of rows in each batch
+    /// read before the first error, and that error
+    fn read_ocf_until_error(
+        bytes: &[u8],
+        batch_size: usize,
+        partial_on_error: bool,
+    ) -> (Vec<usize>, Option<String>) {
+        let mut reader = ReaderBuilder::new()
+            .with_batch_size(batch_size)
+            .with_partial_on_error(partial_on_error)
+            .build(BufReader::with_capacity(5, Cursor::new(bytes)))
+            .unwrap();
+        let mut rows = vec![];
+        for batch in &mut reader {
+            match batch {
+                Ok(batch) => rows.push(batch.num_rows()),
+                Err(e) => {
+                    // The error is final
+                    assert!(reader.next().is_none());
+                    return (rows, Some(e.to_string()));
+                }
+            }
+        }
+        (rows, None)
+    }
+
+    #[test]
+    fn test_ocf_block_framing_errors() {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+        let batches: Vec<_> = (0..3)
+            .map(|i| {
+                let a = Int32Array::from(vec![i; 4]);
+                RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(a)]).unwrap()
+            })
+            .collect();
+        let bytes = write_ocf(&schema, &batches);
+        let h = crate::reader::header::read_header_info(Cursor::new(&bytes))
+            .unwrap()
+            .header_len() as usize;
+        // Each block is a 1 byte count, 1 byte size, 4 bytes of data and a 16 byte sync marker
+        assert_eq!(bytes.len(), h + 3 * 22);
+        let err = |block: usize, detail: String| {
+            let offset = h + 22 * block;
+            Some(format!(
+                "External error: Parser error: Invalid Avro block {block} at byte offset {offset}: {detail}"
+            ))
+        };
+
+        // Reading in 5 byte chunks splits the blocks and sync markers
+        assert_eq!(read_ocf_until_error(&bytes, 1024, false), (vec![12], None));
+        assert_eq!(
+            read_ocf_until_error(&bytes[..h + 44], 1024, false),
+            (vec![8], None)
+        );
+
+        let cases = [
+            (
+                h + 1,
+                0,
+                format!(
+                    "file truncated at byte offset {} within the block header",
+                    h + 1
+                ),
+            ),
+            (
+                h + 25,
+                1,
+                format!(
+                    "file truncated at byte offset {} with 3 bytes of block data remaining",
+                    h + 25
+                ),
+            ),
+            (
+                h + 61,
+                2,
+                format!(
+                    "file truncated at byte offset {} with 5 bytes of sync marker remaining",
+                    h + 61
+                ),
+            ),
+        ];
+        for (len, block, detail) in cases {
+            let truncated = &bytes[..len];
+            assert_eq!(
+                read_ocf_until_error(truncated, 1024, false),
+                (vec![], err(block, detail.clone())),
+                "truncated to {len}"
+            );
+            // The rows of complete blocks are returned before the error
+            let rows = match block {
+                0 => vec![],
+                _ => vec![block * 4],
+            };
+            assert_eq!(
+                read_ocf_until_error(truncated, 1024, true),
+                (rows, err(block, detail.clone())),
+                "truncated to {len}"
+            );
+            // Full batches are returned regardless
+            assert_eq!(
+                read_ocf_until_error(truncated, 3, false),
+                (vec![3; block * 4 / 3], err(block, detail)),
+                "truncated to {len}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ocf_block_corrupt_framing() {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+        let batches: Vec<_> = (0..3)
+            .map(|i| {
+                let a = Int32Array::from(vec![i; 4]);
+                RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(a)]).unwrap()
+            })
+            .collect();
+        let bytes = write_ocf(&schema, &batches);
+        let h = crate::reader::header::read_header_info(Cursor::new(&bytes))
+            .unwrap()
+            .header_len() as usize;
+        let prefix = format!(
+            "External error: Parser error: Invalid Avro block 1 at byte offset {}: ",
+            h + 22
+        );
+        // Replaces the zig-zag encoded byte at `pos` with `value`
+        let corrupt = |pos: usize, value: u8| {
+            let mut bytes = bytes.clone();
+            bytes[pos] = value << 1;
+            bytes
+        };
+
+        // Declared size of block 1 too large
+        let (rows, e) = read_ocf_until_error(&corrupt(h + 23, 5), 1024, true);
+        assert_eq!(rows, vec![4]);
+        assert_eq!(
+            e.unwrap(),
+            format!(
+                "{prefix}sync marker at byte offset {} does not match the file header, \
+                 the declared block size may be incorrect",
+                h + 29
+            )
+        );
+
+        // Declared count of block 1 too small
+        let (rows, e) = read_ocf_until_error(&corrupt(h + 22, 3), 1024, true);
+        assert_eq!(rows, vec![7]);
+        assert_eq!(
+            e.unwrap(),
+            format!("{prefix}1 bytes remain after decoding the declared number of records")
+        );
+
+        // Declared count of block 1 too large
+        let (rows, e) = read_ocf_until_error(&corrupt(h + 22, 5), 1024, true);
+        assert!(rows.is_empty());
+        assert_eq!(e.unwrap(), "External error: Parser error: bad varint");
+        let err = ReaderBuilder::new()
+            .build(Cursor::new(corrupt(h + 22, 5)))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert_eq!(error_kind(&err), ErrorKind::Corrupt);
+        let source = err.source().unwrap().downcast_ref::<AvroError>().unwrap();
+        let context = source.context().unwrap();
+        assert_eq!(context.field, vec!["a".to_string()]);
+        assert_eq!(context.block, Some(1));
+        assert_eq!(context.offset, Some(h as u64 + 22));
+    }
+
     #[test]
     fn ocf_projection_no_reader_schema_reorder() -> Result<(), Box<dyn std::error::Error>> {
         // Writer: { id: int, name: string, is_active: boolean }
@@ -1893,13 +2460,13 @@ mod test {
             .with_projection(vec![2])
             .build(Cursor::new(bytes.clone()))
             .unwrap_err();
-        assert!(matches!(err, ArrowError::AvroError(_)));
+        assert!(matches!(err, ArrowError::InvalidArgumentError(_)));
         assert!(err.to_string().contains("out of bounds"));
         let err = ReaderBuilder::new()
             .with_projection(vec![0, 0])
             .build(Cursor::new(bytes))
             .unwrap_err();
-        assert!(matches!(err, ArrowError::AvroError(_)));
+        assert!(matches!(err, ArrowError::InvalidArgumentError(_)));
         assert!(err.to_string().contains("Duplicate projection index"));
         Ok(())
     }
@@ -2551,7 +3118,8 @@ mod test {
         let mut decoder = make_decoder(&store, fp_int, &schema_long);
         let writer_schema_long = schema_long.schema().unwrap();
         let root_long = AvroFieldBuilder::new(&writer_schema_long).build().unwrap();
-        let long_decoder = RecordDecoder::try_new_with_options(root_long.data_type()).unwrap();
+        let long_decoder =
+            RecordDecoder::try_new_with_options(root_long.data_type(), false).unwrap();
         let _ = decoder.cache.insert(fp_long, long_decoder);
         let mut buf = Vec::from(SINGLE_OBJECT_MAGIC);
         match fp_long {
@@ -4962,6 +5530,385 @@ mod test {
         assert_eq!(binary_array.value(2), b"some bytes");
     }
 
+    #[test]
+    fn test_read_root_array_long() {
+        let batch = read_file("test/data/root_array_long.avro", 2, false);
+        let schema = batch.schema();
+        assert_eq!(schema.fields().len(), 1);
+        assert_eq!(schema.field(0).name(), "value");
+        assert!(matches!(schema.field(0).data_type(), DataType::List(_)));
+        let list = batch.column(0).as_list::<i32>();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.null_count(), 0);
+        assert_eq!(list.value_offsets(), &[0, 3, 3, 5]);
+        assert_eq!(
+            list.values().as_primitive::<Int64Type>().values(),
+            &[1, 2, 3, -4, i64::MAX]
+        );
+    }
+
+    #[test]
+    fn test_read_root_nullable_string() {
+        let file = File::open("test/data/root_nullable_string.avro").unwrap();
+        let mut reader = ReaderBuilder::new()
+            .with_root_field_name("name")
+            .build(BufReader::new(file))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert!(reader.next().is_none());
+        let expected = RecordBatch::try_from_iter_with_nullable([(
+            "name",
+            Arc::new(StringArray::from(vec![Some("a"), None, Some("héllo")])) as ArrayRef,
+            true,
+        )])
+        .unwrap();
+        assert_eq!(batch, expected);
+    }
+
+    #[test]
+    fn test_decode_root_union() {
+        let schema = AvroSchema::new(r#"["int","string"]"#.to_string());
+        let mut store = SchemaStore::new();
+        let fp = store.register(schema).unwrap();
+        let mut decoder = ReaderBuilder::new()
+            .with_writer_schema_store(store)
+            .build_decoder()
+            .unwrap();
+        let mut data = make_prefix(fp);
+        data.extend_from_slice(&[0x00, 0x0e]);
+        data.extend_from_slice(&make_prefix(fp));
+        data.extend_from_slice(&[0x02, 0x02, b'x']);
+        assert_eq!(decoder.decode(&data).unwrap(), data.len());
+        let batch = decoder.flush().unwrap().unwrap();
+        assert_eq!(batch.schema().field(0).name(), "value");
+        let union = batch.column(0).as_union();
+        assert_eq!(union.len(), 2);
+        assert_eq!(union.value(0).as_primitive::<Int32Type>().value(0), 7);
+        assert_eq!(union.value(1).as_string::<i32>().value(0), "x");
+    }
+
+    fn field_type_ocf() -> Vec<u8> {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("payload", DataType::Binary, true),
+            Field::new("name", DataType::Utf8, false),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+                Arc::new(BinaryArray::from(vec![
+                    Some(&b"h\xC3\xA9llo"[..]),
+                    None,
+                    Some(&b"a\xFFb"[..]),
+                ])) as ArrayRef,
+                Arc::new(StringArray::from(vec!["x", "y", "z"])) as ArrayRef,
+            ],
+        )
+        .unwrap();
+        write_ocf(&schema, &[batch])
+    }
+
+    fn read_with_field_types(
+        bytes: &[u8],
+        builder: ReaderBuilder,
+    ) -> Result<RecordBatch, ArrowError> {
+        let mut reader = builder.build(Cursor::new(bytes.to_vec()))?;
+        reader.next().unwrap()
+    }
+
+    #[test]
+    fn test_field_type_override() {
+        let bytes = field_type_ocf();
+        let builder = ReaderBuilder::new()
+            .with_field_type("payload", DataType::Utf8)
+            .with_field_type("name", DataType::Binary)
+            .with_replace_invalid_utf8(true);
+        let batch = read_with_field_types(&bytes, builder).unwrap();
+        assert_eq!(batch.schema().field(1).data_type(), &DataType::Utf8);
+        assert!(batch.schema().field(1).is_nullable());
+        assert_eq!(
+            batch.column(1).as_string::<i32>(),
+            &StringArray::from(vec![Some("héllo"), None, Some("a\u{FFFD}b")])
+        );
+        assert_eq!(
+            batch.column(2).as_binary::<i32>(),
+            &BinaryArray::from(vec![&b"x"[..], b"y", b"z"])
+        );
+
+        let builder = ReaderBuilder::new()
+            .with_field_type("name", DataType::Utf8View)
+            .with_utf8_view(false);
+        let batch = read_with_field_types(&bytes, builder).unwrap();
+        assert_eq!(batch.schema().field(2).data_type(), &DataType::Utf8View);
+        assert_eq!(batch.column(2).as_string_view().value(1), "y");
+    }
+
+    #[test]
+    fn test_field_type_override_invalid_utf8() {
+        let bytes = field_type_ocf();
+        let builder = ReaderBuilder::new().with_field_type("payload", DataType::Utf8);
+        let err = read_with_field_types(&bytes, builder).unwrap_err();
+        assert!(
+            err.to_string().contains("Encountered non UTF-8 data"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_field_type_override_with_reader_schema() {
+        let bytes = field_type_ocf();
+        let reader_schema = AvroSchema::new(
+            r#"{"type":"record","name":"topLevelRecord","fields":[
+                {"name":"payload","type":["null","string"],"default":null},
+                {"name":"extra","type":"string","default":"d"}
+            ]}"#
+            .to_string(),
+        );
+        let builder = ReaderBuilder::new()
+            .with_reader_schema(reader_schema.clone())
+            .with_replace_invalid_utf8(true);
+        let batch = read_with_field_types(&bytes, builder).unwrap();
+        assert_eq!(
+            batch.column(0).as_string::<i32>(),
+            &StringArray::from(vec![Some("héllo"), None, Some("a\u{FFFD}b")])
+        );
+
+        let builder = ReaderBuilder::new()
+            .with_reader_schema(reader_schema.clone())
+            .with_field_type("payload", DataType::Binary);
+        let batch = read_with_field_types(&bytes, builder).unwrap();
+        assert_eq!(batch.column(0).as_binary::<i32>().value(2), b"a\xFFb");
+
+        let builder = ReaderBuilder::new()
+            .with_reader_schema(reader_schema)
+            .with_field_type("extra", DataType::Binary);
+        let err = read_with_field_types(&bytes, builder).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Cannot read field \"extra\" as Binary: field not found in writer schema"
+        );
+    }
+
+    fn long_list_message(fp: Fingerprint, values: &[i64]) -> Vec<u8> {
+        let mut msg = make_prefix(fp);
+        for v in values {
+            msg.extend(encode_zigzag(*v));
+            msg.push(0x02); // the `LongList` branch of `next`
+        }
+        msg.pop();
+        msg.push(0x00); // the `null` branch of the last `next`
+        msg
+    }
+
+    #[test]
+    fn test_recursive_schema() {
+        let mut store = SchemaStore::new();
+        let fp = store
+            .register(AvroSchema::new(
+                r#"{"type":"record","name":"LongList","fields":[
+                    {"name":"value","type":"long"},
+                    {"name":"next","type":["null","LongList"]}
+                ]}"#
+                .to_string(),
+            ))
+            .unwrap();
+        let mut decoder = ReaderBuilder::new()
+            .with_writer_schema_store(store)
+            .with_active_fingerprint(fp)
+            .with_max_recursion_depth(2)
+            .build_decoder()
+            .unwrap();
+
+        let mut data = long_list_message(fp, &[1, 2, 3]);
+        data.extend(long_list_message(fp, &[4]));
+        decoder.decode(&data).unwrap();
+        let batch = decoder.flush().unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(
+            batch.column(0).as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![1, 4])
+        );
+        let next = batch.column(1).as_struct();
+        assert_eq!(
+            next.logical_nulls(),
+            Some(NullBuffer::from(vec![true, false]))
+        );
+        assert_eq!(next.column(0).as_primitive::<Int64Type>().value(0), 2);
+        let next = next.column(1).as_struct();
+        assert_eq!(next.column(0).as_primitive::<Int64Type>().value(0), 3);
+        // The self-reference beyond the maximum recursion depth is read as nulls
+        assert_eq!(next.column(1).data_type(), &DataType::Null);
+        assert_eq!(next.column(1).len(), 2);
+
+        let err = decoder
+            .decode(&long_list_message(fp, &[1, 2, 3, 4]))
+            .unwrap_err();
+        assert!(
+            err.to_string().contains(
+                "Value of recursive type LongList is nested deeper than the maximum recursion depth"
+            ),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn test_skip_utf8_validation() {
+        let schema = Schema::new(vec![Field::new("s", DataType::Utf8, true)]);
+        let values = StringArray::from(vec![Some("a"), None, Some("héllo")]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(values.clone())]).unwrap();
+        let bytes = write_ocf(&schema, &[batch]);
+        for utf8_view in [false, true] {
+            let builder = ReaderBuilder::new().with_utf8_view(utf8_view);
+            // Safety: the data was written above from a valid StringArray
+            let builder = unsafe { builder.with_skip_utf8_validation(true) };
+            let batch = read_with_field_types(&bytes, builder).unwrap();
+            match utf8_view {
+                true => assert_eq!(
+                    batch.column(0).as_string_view(),
+                    &StringViewArray::from(&values)
+                ),
+                false => assert_eq!(batch.column(0).as_string::<i32>(), &values),
+            }
+        }
+    }
+
+    fn predicate_ocf() -> Vec<u8> {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+        let batches: Vec<_> = [0..5, 5..12]
+            .into_iter()
+            .map(|ids| {
+                let names = ids.clone().map(|i| (i % 3 != 0).then(|| format!("n{i}")));
+                RecordBatch::try_new(
+                    Arc::new(schema.clone()),
+                    vec![
+                        Arc::new(Int64Array::from_iter_values(ids)) as ArrayRef,
+                        Arc::new(StringArray::from_iter(names)) as ArrayRef,
+                    ],
+                )
+                .unwrap()
+            })
+            .collect();
+        write_ocf(&schema, &batches)
+    }
+
+    #[test]
+    fn test_predicate() {
+        let bytes = predicate_ocf();
+        // Select the even ids, with null for ids divisible by 3, which are skipped
+        let predicate = AvroPredicate::new(vec![0], |batch| {
+            let ids = batch.column(0).as_primitive::<Int64Type>();
+            Ok(ids
+                .values()
+                .iter()
+                .map(|id| (id % 3 != 0).then_some(id % 2 == 0))
+                .collect())
+        });
+        let reader = ReaderBuilder::new()
+            .with_batch_size(2)
+            .with_predicate(predicate)
+            .with_projection(vec![1, 0])
+            .build(Cursor::new(bytes))
+            .unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(
+            batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>(),
+            vec![2, 2]
+        );
+        let batch = arrow::compute::concat_batches(&batches[0].schema(), &batches).unwrap();
+        assert_eq!(
+            batch.column(0).as_string::<i32>(),
+            &StringArray::from(vec!["n2", "n4", "n8", "n10"])
+        );
+        assert_eq!(
+            batch.column(1).as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![2, 4, 8, 10])
+        );
+
+        // Rows can be selected by fields that are not read
+        let predicate =
+            AvroPredicate::new(vec![1], |batch| arrow::compute::is_null(batch.column(0)));
+        let reader = ReaderBuilder::new()
+            .with_predicate(predicate)
+            .with_projection(vec![0])
+            .build(Cursor::new(predicate_ocf()))
+            .unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let batch = arrow::compute::concat_batches(&batches[0].schema(), &batches).unwrap();
+        assert_eq!(
+            batch.column(0).as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![0, 3, 6, 9])
+        );
+    }
+
+    #[test]
+    fn test_predicate_errors() {
+        let predicate = AvroPredicate::new(vec![0], |_| Ok(BooleanArray::from(vec![true])));
+        let mut reader = ReaderBuilder::new()
+            .with_predicate(predicate)
+            .build(Cursor::new(predicate_ocf()))
+            .unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Predicate returned 1 values for a block of 5 rows"),
+            "unexpected error: {err}"
+        );
+        assert!(reader.next().is_none());
+
+        let predicate = AvroPredicate::new(vec![0], |_| Ok(BooleanArray::from(vec![true])));
+        let err = ReaderBuilder::new()
+            .with_writer_schema_store(SchemaStore::new())
+            .with_predicate(predicate)
+            .build_decoder()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: A predicate is only supported when building a Reader"
+        );
+    }
+
+    #[test]
+    fn test_field_type_override_errors() {
+        let bytes = field_type_ocf();
+        let cases = [
+            (
+                "missing",
+                DataType::Utf8,
+                "Cannot read field \"missing\" as Utf8: field not found in Avro schema",
+            ),
+            (
+                "id",
+                DataType::Utf8,
+                "Cannot read field \"id\" as Utf8: only bytes and string fields can be overridden, found Int32",
+            ),
+            (
+                "payload",
+                DataType::Int64,
+                "Cannot read field \"payload\" of Avro type bytes as Int64",
+            ),
+            (
+                "payload",
+                DataType::Utf8View,
+                "Cannot read field \"payload\" of Avro type bytes as Utf8View",
+            ),
+            (
+                "name",
+                DataType::LargeUtf8,
+                "Cannot read field \"name\" of Avro type string as LargeUtf8",
+            ),
+        ];
+        for (name, data_type, expected) in cases {
+            let builder = ReaderBuilder::new().with_field_type(name, data_type);
+            let err = builder.build(Cursor::new(bytes.clone())).unwrap_err();
+            assert_eq!(err.to_string(), format!("Schema error: {expected}"));
+        }
+    }
+
     #[test]
     fn test_alltypes() {
         let expected = RecordBatch::try_from_iter_with_nullable([
@@ -7230,8 +8177,13 @@ mod test {
             .expect("build full reader")
             .collect::<Result<Vec<_>, _>>()
             .expect_err("full decode should reject malformed varint");
-        assert!(matches!(err, ArrowError::AvroError(_)));
+        assert!(matches!(err, ArrowError::ExternalError(_)));
         assert!(err.to_string().contains("bad varint"));
+        assert_eq!(error_kind(&err), ErrorKind::Corrupt);
+        let source = err.source().unwrap().downcast_ref::<AvroError>().unwrap();
+        let context = source.context().unwrap();
+        assert_eq!(context.field, vec!["bad_long".to_string()]);
+        assert_eq!(context.block, Some(0));
 
         let err = ReaderBuilder::new()
             .with_projection(vec![1])
@@ -7239,7 +8191,7 @@ mod test {
             .expect("build projected reader")
             .collect::<Result<Vec<_>, _>>()
             .expect_err("projection must also reject malformed skipped varint");
-        assert!(matches!(err, ArrowError::AvroError(_)));
+        assert!(matches!(err, ArrowError::ExternalError(_)));
         assert!(err.to_string().contains("bad varint"));
     }
 
@@ -7268,7 +8220,7 @@ mod test {
             .expect("build full reader")
             .collect::<Result<Vec<_>, _>>()
             .expect_err("full decode should reject int overflow");
-        assert!(matches!(err, ArrowError::AvroError(_)));
+        assert!(matches!(err, ArrowError::ExternalError(_)));
         assert!(err.to_string().contains("varint overflow"));
 
         let err = ReaderBuilder::new()
@@ -7277,7 +8229,7 @@ mod test {
             .expect("build projected reader")
             .collect::<Result<Vec<_>, _>>()
             .expect_err("projection must also reject skipped int overflow");
-        assert!(matches!(err, ArrowError::AvroError(_)));
+        assert!(matches!(err, ArrowError::ExternalError(_)));
         assert!(err.to_string().contains("varint overflow"));
     }
 
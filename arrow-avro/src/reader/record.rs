@@ -21,7 +21,7 @@ use crate::codec::{
     AvroDataType, AvroLiteral, Codec, EnumMapping, Promotion, ResolutionInfo, ResolvedField,
     ResolvedRecord, ResolvedUnion, Tz,
 };
-use crate::errors::AvroError;
+use crate::errors::{AvroError, ErrorContext};
 use crate::reader::cursor::AvroCursor;
 use crate::schema::Nullability;
 #[cfg(feature = "small_decimals")]
@@ -103,11 +103,14 @@ impl RecordDecoder {
     ///
     /// # Arguments
     /// * `data_type` - The Avro data type to decode.
-    /// * `use_utf8view` - A flag indicating whether to use `Utf8View` for string types.
+    /// * `skip_utf8_validation` - If set, strings are trusted to be valid UTF-8 and are not validated.
     ///
     /// # Errors
     /// This function will return an error if the provided `data_type` is not a `Record`.
-    pub(crate) fn try_new_with_options(data_type: &AvroDataType) -> Result<Self, AvroError> {
+    pub(crate) fn try_new_with_options(
+        data_type: &AvroDataType,
+        skip_utf8_validation: bool,
+    ) -> Result<Self, AvroError> {
         match data_type.codec() {
             Codec::Struct(reader_fields) => {
                 // Build Arrow schema fields and per-child decoders
@@ -116,7 +119,11 @@ impl RecordDecoder {
                 let mut field_defaults = Vec::with_capacity(reader_fields.len());
                 for avro_field in reader_fields.iter() {
                     arrow_fields.push(avro_field.field());
-                    encodings.push(Decoder::try_new(avro_field.data_type())?);
+                    let mut encoding = Decoder::try_new(avro_field.data_type())?;
+                    if skip_utf8_validation {
+                        encoding.skip_utf8_validation();
+                    }
+                    encodings.push(encoding);
 
                     if let Some(ResolutionInfo::DefaultValue(lit)) =
                         avro_field.data_type().resolution.as_ref()
@@ -138,7 +145,7 @@ impl RecordDecoder {
                     projector,
                 })
             }
-            other => Err(AvroError::ParseError(format!(
+            other => Err(AvroError::SchemaError(format!(
                 "Expected record got {other:?}"
             ))),
         }
@@ -154,14 +161,18 @@ impl RecordDecoder {
         let mut cursor = AvroCursor::new(buf);
         match self.projector.as_mut() {
             Some(proj) => {
+                let fields = self.schema.fields();
                 for _ in 0..count {
-                    proj.project_record(&mut cursor, &mut self.fields)?;
+                    proj.project_record(&mut cursor, &mut self.fields, fields)?;
                 }
             }
             None => {
+                let fields = self.schema.fields();
                 for _ in 0..count {
-                    for field in &mut self.fields {
-                        field.decode(&mut cursor)?;
+                    for (decoder, field) in self.fields.iter_mut().zip(fields) {
+                        decoder
+                            .decode(&mut cursor)
+                            .map_err(|e| field_error(e, field))?;
                     }
                 }
             }
@@ -180,9 +191,30 @@ impl RecordDecoder {
     }
 }
 
+/// Skips whole records without decoding them
+#[derive(Debug)]
+pub(crate) struct RecordSkipper(Skipper);
+
+impl RecordSkipper {
+    /// Creates a new [`RecordSkipper`] for records written with the writer `data_type`
+    pub(crate) fn try_new(data_type: &AvroDataType) -> Result<Self, AvroError> {
+        Ok(Self(Skipper::from_avro(data_type)?))
+    }
+
+    /// Skip `count` records from `buf`, returning the number of bytes consumed
+    pub(crate) fn skip(&self, buf: &[u8], count: usize) -> Result<usize, AvroError> {
+        let mut cursor = AvroCursor::new(buf);
+        for _ in 0..count {
+            self.0.skip(&mut cursor)?;
+        }
+        Ok(cursor.position())
+    }
+}
+
 #[derive(Debug, AsRefStr)]
 enum Decoder {
     Null(usize),
+    RecursionLimit(usize, Arc<str>),
     Boolean(BooleanBufferBuilder),
     Int32(Vec<i32>),
     Int64(Vec<i64>),
@@ -237,12 +269,18 @@ enum Decoder {
     Int64ToFloat64(Vec<f64>),
     Float32ToFloat64(Vec<f64>),
     BytesToString(OffsetBufferBuilder<i32>, Vec<u8>),
+    /// Bytes mapped to Arrow's StringArray, replacing invalid UTF-8 with `U+FFFD`
+    BytesToStringLossy(OffsetBufferBuilder<i32>, Vec<u8>),
     StringToBytes(OffsetBufferBuilder<i32>, Vec<u8>),
     Binary(OffsetBufferBuilder<i32>, Vec<u8>),
     /// String data encoded as UTF-8 bytes, mapped to Arrow's StringArray
     String(OffsetBufferBuilder<i32>, Vec<u8>),
     /// String data encoded as UTF-8 bytes, but mapped to Arrow's StringViewArray
     StringView(OffsetBufferBuilder<i32>, Vec<u8>),
+    /// Like [`Self::String`], but trusts the data to be valid UTF-8 without validating it
+    StringUnchecked(OffsetBufferBuilder<i32>, Vec<u8>),
+    /// Like [`Self::StringView`], but trusts the data to be valid UTF-8 without validating it
+    StringViewUnchecked(OffsetBufferBuilder<i32>, Vec<u8>),
     Array(FieldRef, OffsetBufferBuilder<i32>, Box<Decoder>),
     Record(
         Fields,
@@ -322,11 +360,17 @@ impl Decoder {
                 OffsetBufferBuilder::new(DEFAULT_CAPACITY),
                 Vec::with_capacity(DEFAULT_CAPACITY),
             ),
+            (Codec::Utf8, Some(Promotion::BytesToStringLossy))
+            | (Codec::Utf8View, Some(Promotion::BytesToStringLossy)) => Self::BytesToStringLossy(
+                OffsetBufferBuilder::new(DEFAULT_CAPACITY),
+                Vec::with_capacity(DEFAULT_CAPACITY),
+            ),
             (Codec::Binary, Some(Promotion::StringToBytes)) => Self::StringToBytes(
                 OffsetBufferBuilder::new(DEFAULT_CAPACITY),
                 Vec::with_capacity(DEFAULT_CAPACITY),
             ),
             (Codec::Null, _) => Self::Null(0),
+            (Codec::RecursionLimit(name), _) => Self::RecursionLimit(0, Arc::clone(name)),
             (Codec::Boolean, _) => Self::Boolean(BooleanBufferBuilder::new(DEFAULT_CAPACITY)),
             (Codec::Int32, _) => Self::Int32(Vec::with_capacity(DEFAULT_CAPACITY)),
             (Codec::Int64, _) => Self::Int64(Vec::with_capacity(DEFAULT_CAPACITY)),
@@ -433,7 +477,7 @@ impl Decoder {
                             .with_precision_and_scale(prec, scl)?;
                         Self::Decimal256(p, s, *size, builder)
                     } else {
-                        return Err(AvroError::ParseError(format!(
+                        return Err(AvroError::SchemaError(format!(
                             "Decimal precision {p} exceeds maximum supported"
                         )));
                     }
@@ -449,7 +493,7 @@ impl Decoder {
                             .with_precision_and_scale(prec, scl)?;
                         Self::Decimal256(p, s, *size, builder)
                     } else {
-                        return Err(AvroError::ParseError(format!(
+                        return Err(AvroError::SchemaError(format!(
                             "Decimal precision {p} exceeds maximum supported"
                         )));
                     }
@@ -625,10 +669,42 @@ impl Decoder {
         })
     }
 
+    /// Switches every string decoder in this decoder to trust its data to be valid UTF-8
+    fn skip_utf8_validation(&mut self) {
+        let take_offsets =
+            |o: &mut OffsetBufferBuilder<i32>| mem::replace(o, OffsetBufferBuilder::new(0));
+        match self {
+            Self::String(offsets, values) => {
+                *self = Self::StringUnchecked(take_offsets(offsets), mem::take(values))
+            }
+            Self::StringView(offsets, values) => {
+                *self = Self::StringViewUnchecked(take_offsets(offsets), mem::take(values))
+            }
+            Self::Array(_, _, inner)
+            | Self::Map(_, _, _, _, inner)
+            | Self::Nullable(_, _, inner) => inner.skip_utf8_validation(),
+            #[cfg(feature = "avro_custom_types")]
+            Self::RunEndEncoded(_, _, inner) => inner.skip_utf8_validation(),
+            Self::Record(_, encodings, _, _) => {
+                encodings.iter_mut().for_each(Self::skip_utf8_validation)
+            }
+            Self::Union(u) => {
+                u.branches
+                    .decoders
+                    .iter_mut()
+                    .for_each(Self::skip_utf8_validation);
+                if let UnionReadPlan::ToSingle { target, .. } = &mut u.plan {
+                    target.skip_utf8_validation()
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Append a null record
     fn append_null(&mut self) -> Result<(), AvroError> {
         match self {
-            Self::Null(count) => *count += 1,
+            Self::Null(count) | Self::RecursionLimit(count, _) => *count += 1,
             Self::Boolean(b) => b.append(false),
             Self::Int32(v) | Self::Date32(v) | Self::TimeMillis(v) => v.push(0),
             Self::Int64(v)
@@ -672,7 +748,10 @@ impl Decoder {
             Self::Binary(offsets, _)
             | Self::String(offsets, _)
             | Self::StringView(offsets, _)
+            | Self::StringUnchecked(offsets, _)
+            | Self::StringViewUnchecked(offsets, _)
             | Self::BytesToString(offsets, _)
+            | Self::BytesToStringLossy(offsets, _)
             | Self::StringToBytes(offsets, _) => {
                 offsets.push_length(0);
             }
@@ -727,7 +806,7 @@ impl Decoder {
                     inner.append_default(lit)
                 }
             }
-            Self::Null(count) => match lit {
+            Self::Null(count) | Self::RecursionLimit(count, _) => match lit {
                 AvroLiteral::Null => {
                     *count += 1;
                     Ok(())
@@ -1000,8 +1079,11 @@ impl Decoder {
                 )),
             },
             Self::BytesToString(offsets, values)
+            | Self::BytesToStringLossy(offsets, values)
             | Self::String(offsets, values)
-            | Self::StringView(offsets, values) => match lit {
+            | Self::StringView(offsets, values)
+            | Self::StringUnchecked(offsets, values)
+            | Self::StringViewUnchecked(offsets, values) => match lit {
                 AvroLiteral::String(s) => {
                     let b = s.as_bytes();
                     offsets.push_length(b.len());
@@ -1159,6 +1241,7 @@ impl Decoder {
     fn decode(&mut self, buf: &mut AvroCursor<'_>) -> Result<(), AvroError> {
         match self {
             Self::Null(x) => *x += 1,
+            Self::RecursionLimit(_, name) => return Err(recursion_limit_error(name)),
             Self::Boolean(values) => values.append(buf.get_bool()?),
             Self::Int32(values) | Self::Date32(values) | Self::TimeMillis(values) => {
                 values.push(buf.get_int()?)
@@ -1265,11 +1348,18 @@ impl Decoder {
             | Self::BytesToString(offsets, values)
             | Self::Binary(offsets, values)
             | Self::String(offsets, values)
-            | Self::StringView(offsets, values) => {
+            | Self::StringView(offsets, values)
+            | Self::StringUnchecked(offsets, values)
+            | Self::StringViewUnchecked(offsets, values) => {
                 let data = buf.get_bytes()?;
                 offsets.push_length(data.len());
                 values.extend_from_slice(data);
             }
+            Self::BytesToStringLossy(offsets, values) => {
+                let data = String::from_utf8_lossy(buf.get_bytes()?);
+                offsets.push_length(data.len());
+                values.extend_from_slice(data.as_bytes());
+            }
             Self::Uuid(values) => {
                 let s_bytes = buf.get_bytes()?;
                 let s = std::str::from_utf8(s_bytes).map_err(|e| {
@@ -1283,13 +1373,13 @@ impl Decoder {
                 let total_items = read_blocks(buf, |cursor| encoding.decode(cursor))?;
                 off.push_length(total_items);
             }
-            Self::Record(_, encodings, _, None) => {
-                for encoding in encodings {
-                    encoding.decode(buf)?;
+            Self::Record(fields, encodings, _, None) => {
+                for (encoding, field) in encodings.iter_mut().zip(fields.iter()) {
+                    encoding.decode(buf).map_err(|e| field_error(e, field))?;
                 }
             }
-            Self::Record(_, encodings, _, Some(proj)) => {
-                proj.project_record(buf, encodings)?;
+            Self::Record(fields, encodings, _, Some(proj)) => {
+                proj.project_record(buf, encodings, fields)?;
             }
             Self::Map(_, koff, moff, kdata, valdec) => {
                 let newly_added = read_blocks(buf, |cur| {
@@ -1388,7 +1478,7 @@ impl Decoder {
                         v.push(x as $to);
                         Ok(())
                     }
-                    other => Err(AvroError::ParseError(format!(
+                    other => Err(AvroError::SchemaError(format!(
                         "Promotion {promotion} target mismatch: expected {}, got {}",
                         stringify!($variant),
                         <Self as ::std::convert::AsRef<str>>::as_ref(other)
@@ -1411,7 +1501,7 @@ impl Decoder {
                     values.extend_from_slice(data);
                     Ok(())
                 }
-                other => Err(AvroError::ParseError(format!(
+                other => Err(AvroError::SchemaError(format!(
                     "Promotion {promotion} target mismatch: expected bytes (Binary/StringToBytes), got {}",
                     <Self as AsRef<str>>::as_ref(other)
                 ))),
@@ -1419,17 +1509,35 @@ impl Decoder {
             Promotion::BytesToString => match self {
                 Self::String(offsets, values)
                 | Self::StringView(offsets, values)
+                | Self::StringUnchecked(offsets, values)
+                | Self::StringViewUnchecked(offsets, values)
                 | Self::BytesToString(offsets, values) => {
                     let data = buf.get_bytes()?;
                     offsets.push_length(data.len());
                     values.extend_from_slice(data);
                     Ok(())
                 }
-                other => Err(AvroError::ParseError(format!(
+                other => Err(AvroError::SchemaError(format!(
                     "Promotion {promotion} target mismatch: expected string (String/StringView/BytesToString), got {}",
                     <Self as AsRef<str>>::as_ref(other)
                 ))),
             },
+            Promotion::BytesToStringLossy => match self {
+                Self::String(offsets, values)
+                | Self::StringView(offsets, values)
+                | Self::StringUnchecked(offsets, values)
+                | Self::StringViewUnchecked(offsets, values)
+                | Self::BytesToStringLossy(offsets, values) => {
+                    let data = String::from_utf8_lossy(buf.get_bytes()?);
+                    offsets.push_length(data.len());
+                    values.extend_from_slice(data.as_bytes());
+                    Ok(())
+                }
+                other => Err(AvroError::SchemaError(format!(
+                    "Promotion {promotion} target mismatch: expected string (String/StringView/BytesToStringLossy), got {}",
+                    <Self as AsRef<str>>::as_ref(other)
+                ))),
+            },
         }
     }
 
@@ -1462,12 +1570,12 @@ impl Decoder {
                 Ok(())
             }
             ResolutionPlan::Record(proj) => {
-                let Self::Record(_, encodings, _, _) = self else {
+                let Self::Record(fields, encodings, _, _) = self else {
                     return Err(AvroError::SchemaError(
                         "record projection provided for non-record decoder".into(),
                     ));
                 };
-                proj.project_record(buf, encodings)
+                proj.project_record(buf, encodings, fields)
             }
         }
     }
@@ -1476,7 +1584,9 @@ impl Decoder {
     fn flush(&mut self, nulls: Option<NullBuffer>) -> Result<ArrayRef, AvroError> {
         Ok(match self {
             Self::Nullable(_, n, e) => e.flush(n.finish())?,
-            Self::Null(size) => Arc::new(NullArray::new(std::mem::replace(size, 0))),
+            Self::Null(size) | Self::RecursionLimit(size, _) => {
+                Arc::new(NullArray::new(std::mem::replace(size, 0)))
+            }
             Self::Boolean(b) => Arc::new(BooleanArray::new(b.finish(), nulls)),
             Self::Int32(values) => Arc::new(flush_primitive::<Int32Type>(values, nulls)),
             Self::Date32(values) => Arc::new(flush_primitive::<Date32Type>(values, nulls)),
@@ -1579,25 +1689,32 @@ impl Decoder {
                 let values = flush_values(values).into();
                 Arc::new(BinaryArray::try_new(offsets, values, nulls)?)
             }
-            Self::BytesToString(offsets, values) | Self::String(offsets, values) => {
+            Self::BytesToString(offsets, values)
+            | Self::BytesToStringLossy(offsets, values)
+            | Self::String(offsets, values) => {
                 let offsets = flush_offsets(offsets);
                 let values = flush_values(values).into();
                 Arc::new(StringArray::try_new(offsets, values, nulls)?)
             }
+            Self::StringUnchecked(offsets, values) => {
+                let offsets = flush_offsets(offsets);
+                let values = flush_values(values).into();
+                // Safety: the offsets and nulls are built alongside the values, and the
+                // caller of `with_skip_utf8_validation` asserts the values are valid UTF-8
+                Arc::new(unsafe { StringArray::new_unchecked(offsets, values, nulls) })
+            }
             Self::StringView(offsets, values) => {
                 let offsets = flush_offsets(offsets);
                 let values = flush_values(values);
-                let array = StringArray::try_new(offsets, values.into(), nulls.clone())?;
-                let values: Vec<&str> = (0..array.len())
-                    .map(|i| {
-                        if array.is_valid(i) {
-                            array.value(i)
-                        } else {
-                            ""
-                        }
-                    })
-                    .collect();
-                Arc::new(StringViewArray::from(values))
+                let array = StringArray::try_new(offsets, values.into(), nulls)?;
+                Arc::new(StringViewArray::from(&array))
+            }
+            Self::StringViewUnchecked(offsets, values) => {
+                let offsets = flush_offsets(offsets);
+                let values = flush_values(values).into();
+                // Safety: as for `Self::StringUnchecked`
+                let array = unsafe { StringArray::new_unchecked(offsets, values, nulls) };
+                Arc::new(StringViewArray::from(&array))
             }
             Self::Array(field, offsets, values) => {
                 let values = values.flush(None)?;
@@ -2241,6 +2358,20 @@ fn skip_blocks(
     )
 }
 
+/// Returns the error for a non-null value of the recursive type `name` found beyond
+/// the maximum recursion depth
+fn recursion_limit_error(name: &str) -> AvroError {
+    AvroError::ParseError(format!(
+        "Value of recursive type {name} is nested deeper than the maximum recursion depth"
+    ))
+}
+
+/// Annotates an error decoding the value of a record `field` with its name
+#[cold]
+fn field_error(e: AvroError, field: &ArrowField) -> AvroError {
+    e.with_context(ErrorContext::new().with_field(field.name().as_str()))
+}
+
 #[inline]
 fn flush_dict(
     indices: &mut Vec<i32>,
@@ -2478,15 +2609,20 @@ impl Projector {
         &self,
         buf: &mut AvroCursor<'_>,
         encodings: &mut [Decoder],
+        fields: &Fields,
     ) -> Result<(), AvroError> {
         for field_proj in self.writer_projections.iter() {
             match field_proj {
-                FieldProjection::ToReader(index) => encodings[*index].decode(buf)?,
+                FieldProjection::ToReader(index) => encodings[*index]
+                    .decode(buf)
+                    .map_err(|e| field_error(e, &fields[*index]))?,
                 FieldProjection::Skip(skipper) => skipper.skip(buf)?,
             }
         }
         for (reader_index, lit) in self.default_injections.as_ref() {
-            encodings[*reader_index].append_default(lit)?;
+            encodings[*reader_index]
+                .append_default(lit)
+                .map_err(|e| field_error(e, &fields[*reader_index]))?;
         }
         Ok(())
     }
@@ -2521,6 +2657,7 @@ enum Skipper {
     Struct(Vec<Skipper>),
     Union(Vec<Skipper>),
     Nullable(Nullability, Box<Skipper>),
+    RecursionLimit(Arc<str>),
     #[cfg(feature = "avro_custom_types")]
     RunEndEncoded(Box<Skipper>),
 }
@@ -2529,6 +2666,7 @@ impl Skipper {
     fn from_avro(dt: &AvroDataType) -> Result<Self, AvroError> {
         let mut base = match dt.codec() {
             Codec::Null => Self::Null,
+            Codec::RecursionLimit(name) => Self::RecursionLimit(Arc::clone(name)),
             Codec::Boolean => Self::Boolean,
             Codec::Int32 | Codec::Date32 | Codec::TimeMillis => Self::Int32,
             Codec::Int64 => Self::Int64,
@@ -2607,6 +2745,7 @@ impl Skipper {
     fn skip(&self, buf: &mut AvroCursor<'_>) -> Result<(), AvroError> {
         match self {
             Self::Null => Ok(()),
+            Self::RecursionLimit(name) => Err(recursion_limit_error(name)),
             Self::Boolean => {
                 buf.get_bool()?;
                 Ok(())
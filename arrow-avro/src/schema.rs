@@ -64,6 +64,9 @@ pub const AVRO_DOC_METADATA_KEY: &str = "avro.doc";
 /// Default name for the root record in an Avro schema.
 pub const AVRO_ROOT_RECORD_DEFAULT_NAME: &str = "topLevelRecord";
 
+/// Default name of the single column used to read an Avro schema whose root is not a record.
+pub const AVRO_NON_RECORD_ROOT_FIELD_NAME: &str = "value";
+
 /// Avro types are not nullable, with nullability instead encoded as a union
 /// where one of the variants is the null type.
 ///
@@ -371,7 +374,7 @@ impl AvroSchema {
 
     pub(crate) fn schema(&self) -> Result<Schema<'_>, ArrowError> {
         serde_json::from_str(self.json_string.as_str())
-            .map_err(|e| ArrowError::ParseError(format!("Invalid Avro schema JSON: {e}")))
+            .map_err(|e| ArrowError::SchemaError(format!("Invalid Avro schema JSON: {e}")))
     }
 
     /// Returns the fingerprint of the schema, computed using the specified [`FingerprintAlgorithm`].
@@ -405,35 +408,64 @@ impl AvroSchema {
         Self::generate_fingerprint(&self.schema()?, hash_type)
     }
 
+    /// Returns the schema's Parsed Canonical Form as defined by the Avro specification.
+    ///
+    /// This is the form [`Self::fingerprint`] is computed over: names are fully qualified,
+    /// attributes other than those needed to parse the data (such as `doc`, `aliases`
+    /// and logical types) are removed, and whitespace is stripped.
+    ///
+    /// See also: <https://avro.apache.org/docs/1.11.1/specification/#parsing-canonical-form-for-schemas>
+    ///
+    /// # Errors
+    /// Returns an error if deserializing the schema fails, or if generating the
+    /// canonical form of the schema fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrow_avro::schema::AvroSchema;
+    ///
+    /// let avro = AvroSchema::new(
+    ///     r#"{"type": "record", "name": "R", "namespace": "ns", "doc": "a record",
+    ///         "fields": [{"name": "a", "type": "long"}]}"#.to_string(),
+    /// );
+    /// assert_eq!(
+    ///     avro.canonical_form().unwrap(),
+    ///     r#"{"name":"ns.R","type":"record","fields":[{"name":"a","type":"long"}]}"#
+    /// );
+    /// ```
+    pub fn canonical_form(&self) -> Result<String, ArrowError> {
+        Self::generate_canonical_form(&self.schema()?)
+    }
+
     pub(crate) fn project(&self, projection: &[usize]) -> Result<Self, ArrowError> {
         let mut value: Value = serde_json::from_str(&self.json_string)
-            .map_err(|e| ArrowError::AvroError(format!("Invalid Avro schema JSON: {e}")))?;
+            .map_err(|e| ArrowError::SchemaError(format!("Invalid Avro schema JSON: {e}")))?;
         let obj = value.as_object_mut().ok_or_else(|| {
-            ArrowError::AvroError(
+            ArrowError::SchemaError(
                 "Projected schema must be a JSON object Avro record schema".to_string(),
             )
         })?;
         match obj.get("type").and_then(|v| v.as_str()) {
             Some("record") => {}
             Some(other) => {
-                return Err(ArrowError::AvroError(format!(
+                return Err(ArrowError::SchemaError(format!(
                     "Projected schema must be an Avro record, found type '{other}'"
                 )));
             }
             None => {
-                return Err(ArrowError::AvroError(
+                return Err(ArrowError::SchemaError(
                     "Projected schema missing required 'type' field".to_string(),
                 ));
             }
         }
         let fields_val = obj.get_mut("fields").ok_or_else(|| {
-            ArrowError::AvroError("Avro record schema missing required 'fields'".to_string())
+            ArrowError::SchemaError("Avro record schema missing required 'fields'".to_string())
         })?;
         let projected_fields = {
             let mut original_fields = match fields_val {
                 Value::Array(arr) => std::mem::take(arr),
                 _ => {
-                    return Err(ArrowError::AvroError(
+                    return Err(ArrowError::SchemaError(
                         "Avro record schema 'fields' must be an array".to_string(),
                     ));
                 }
@@ -443,12 +475,12 @@ impl AvroSchema {
             let mut out: Vec<Value> = Vec::with_capacity(projection.len());
             for &i in projection {
                 if i >= len {
-                    return Err(ArrowError::AvroError(format!(
+                    return Err(ArrowError::InvalidArgumentError(format!(
                         "Projection index {i} out of bounds for record with {len} fields"
                     )));
                 }
                 if !seen.insert(i) {
-                    return Err(ArrowError::AvroError(format!(
+                    return Err(ArrowError::InvalidArgumentError(format!(
                         "Duplicate projection index {i}"
                     )));
                 }
@@ -458,7 +490,7 @@ impl AvroSchema {
         };
         *fields_val = Value::Array(projected_fields);
         let json_string = serde_json::to_string(&value).map_err(|e| {
-            ArrowError::AvroError(format!(
+            ArrowError::SchemaError(format!(
                 "Failed to serialize projected Avro schema JSON: {e}"
             ))
         })?;
@@ -1170,7 +1202,7 @@ fn extend_with_passthrough_metadata(
 }
 
 // Sanitize an arbitrary string so it is a valid Avro field or type name
-fn sanitise_avro_name(base_name: &str) -> String {
+pub(crate) fn sanitise_avro_name(base_name: &str) -> String {
     if base_name.is_empty() {
         return "_".to_owned();
     }
@@ -1784,7 +1816,7 @@ fn datatype_to_avro(
         DataType::Dictionary(_, value) => {
             if let Some(j) = metadata.get(AVRO_ENUM_SYMBOLS_METADATA_KEY) {
                 let symbols: Vec<&str> =
-                    serde_json::from_str(j).map_err(|e| ArrowError::ParseError(e.to_string()))?;
+                    serde_json::from_str(j).map_err(|e| ArrowError::SchemaError(e.to_string()))?;
                 // Prefer avro.name/namespace when provided for enums
                 let chosen_name = metadata
                     .get(AVRO_NAME_METADATA_KEY)
@@ -1976,7 +2008,7 @@ fn arrow_field_to_avro(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::codec::{AvroField, AvroFieldBuilder};
+    use crate::codec::{AvroField, AvroFieldBuilder, DEFAULT_MAX_RECURSION_DEPTH};
     use arrow_schema::{DataType, Fields, SchemaBuilder, TimeUnit, UnionFields};
     use serde_json::json;
     use std::sync::Arc;
@@ -2176,9 +2208,18 @@ mod tests {
             }))
         );
 
-        // Recursive schema are not supported
-        let err = AvroField::try_from(&schema).unwrap_err().to_string();
-        assert_eq!(err, "Parser error: Failed to resolve .LongList");
+        // Recursive schemas are unrolled up to the maximum recursion depth
+        let field = AvroField::try_from(&schema).unwrap();
+        let mut data_type = field.field().data_type().clone();
+        for _ in 0..=DEFAULT_MAX_RECURSION_DEPTH {
+            let DataType::Struct(fields) = data_type else {
+                panic!("expected struct, got {data_type}");
+            };
+            assert_eq!(fields[0].data_type(), &DataType::Int64);
+            assert!(fields[1].is_nullable());
+            data_type = fields[1].data_type().clone();
+        }
+        assert_eq!(data_type, DataType::Null);
 
         let schema: Schema = serde_json::from_str(
             r#"{
@@ -2628,6 +2669,26 @@ mod tests {
         assert_eq!(canonical_form, expected_canonical_form);
     }
 
+    #[test]
+    fn test_canonical_form_matches_fingerprint() {
+        let avro = AvroSchema::new(
+            r#"{"type":"record","name":"test","fields":[
+                {"name":"a","type":"long","doc":"ignored"},
+                {"name":"b","type":"string"}
+            ]}"#
+            .to_string(),
+        );
+        let canonical_form = avro.canonical_form().unwrap();
+        assert_eq!(
+            canonical_form,
+            r#"{"name":"test","type":"record","fields":[{"name":"a","type":"long"},{"name":"b","type":"string"}]}"#
+        );
+        assert_eq!(
+            avro.fingerprint(FingerprintAlgorithm::Rabin).unwrap(),
+            Fingerprint::Rabin(compute_fingerprint_rabin(&canonical_form))
+        );
+    }
+
     #[test]
     fn test_fingerprint_calculation() {
         let canonical_form = r#"{"fields":[{"name":"a","type":"long"},{"name":"b","type":"string"}],"name":"test","type":"record"}"#;
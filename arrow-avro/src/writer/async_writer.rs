@@ -0,0 +1,323 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Async Avro writer for [`AsyncWrite`] sinks.
+
+use crate::errors::AvroError;
+use crate::writer::format::{AvroFormat, AvroOcfFormat, AvroSoeFormat};
+use crate::writer::{Writer, WriterBuilder};
+use arrow_array::RecordBatch;
+use arrow_schema::{Schema, SchemaRef};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// Encodes [`RecordBatch`]es as Avro, writing the output to an [`AsyncWrite`]
+///
+/// This shares its encoding with the synchronous [`Writer`]: each batch is encoded
+/// (and for Object Container Files, compressed) into an in-memory buffer, which is
+/// then written to the sink before [`Self::write`] returns. Back-pressure therefore
+/// comes from the sink, and at most one encoded batch is buffered at a time.
+///
+/// By default encoding runs on the calling task. [`Self::with_spawn_blocking`]
+/// moves it to tokio's blocking thread pool instead, so that compressing large
+/// batches does not stall the async runtime.
+///
+/// Created with [`WriterBuilder::build_async`](crate::writer::WriterBuilder::build_async),
+/// or with [`AsyncAvroWriter::new`] / [`AsyncAvroStreamWriter::new`].
+///
+/// ### Example
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int64Array, RecordBatch};
+/// # use arrow_schema::{DataType, Field, Schema};
+/// # use arrow_avro::writer::AsyncAvroWriter;
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let schema = Schema::new(vec![Field::new("id", DataType::Int64, false)]);
+/// let batch = RecordBatch::try_new(
+///     Arc::new(schema.clone()),
+///     vec![Arc::new(Int64Array::from(vec![1, 2, 3])) as ArrayRef],
+/// )?;
+///
+/// let mut writer = AsyncAvroWriter::new(Vec::<u8>::new(), schema)?;
+/// writer.write(&batch).await?;
+/// writer.finish().await?;
+/// let bytes = writer.into_inner();
+/// assert!(!bytes.is_empty());
+/// # Ok(()) }
+/// ```
+///
+/// To upload to object storage, wrap a [`BufWriter`] which uses multipart uploads
+/// for large objects:
+///
+/// ```ignore
+/// use object_store::buffered::BufWriter;
+///
+/// let sink = BufWriter::new(store, Path::from("data/example.avro"));
+/// let mut writer = AsyncAvroWriter::new(sink, schema)?;
+/// writer.write(&batch).await?;
+/// writer.finish().await?;
+/// ```
+///
+/// [`BufWriter`]: https://docs.rs/object_store/latest/object_store/buffered/struct.BufWriter.html
+#[derive(Debug)]
+pub struct AsyncWriter<W, F: AvroFormat> {
+    /// The synchronous writer encoding into an in-memory buffer, only `None`
+    /// while moved to a blocking task
+    sync_writer: Option<Writer<Vec<u8>, F>>,
+    async_writer: W,
+    schema: SchemaRef,
+    sync_marker: Option<[u8; 16]>,
+    spawn_blocking: bool,
+}
+
+/// Alias for an async Avro **Object Container File** writer.
+pub type AsyncAvroWriter<W> = AsyncWriter<W, AvroOcfFormat>;
+
+/// Alias for an async Avro **Single Object Encoding** stream writer.
+pub type AsyncAvroStreamWriter<W> = AsyncWriter<W, AvroSoeFormat>;
+
+impl<W: AsyncWrite + Unpin + Send> AsyncWriter<W, AvroOcfFormat> {
+    /// Convenience constructor – same as
+    /// [`WriterBuilder::build_async`](crate::writer::WriterBuilder::build_async)
+    /// with `AvroOcfFormat`.
+    pub fn new(writer: W, schema: Schema) -> Result<Self, AvroError> {
+        WriterBuilder::new(schema).build_async::<W, AvroOcfFormat>(writer)
+    }
+
+    /// Return a reference to the 16‑byte sync marker generated for this file.
+    pub fn sync_marker(&self) -> Option<&[u8; 16]> {
+        self.sync_marker.as_ref()
+    }
+}
+
+impl<W: AsyncWrite + Unpin + Send> AsyncWriter<W, AvroSoeFormat> {
+    /// Convenience constructor to create a new [`AsyncAvroStreamWriter`].
+    pub fn new(writer: W, schema: Schema) -> Result<Self, AvroError> {
+        WriterBuilder::new(schema).build_async::<W, AvroSoeFormat>(writer)
+    }
+}
+
+impl<W, F> AsyncWriter<W, F>
+where
+    W: AsyncWrite + Unpin + Send,
+    F: AvroFormat + Send + 'static,
+{
+    pub(crate) fn new_with_sync_writer(sync_writer: Writer<Vec<u8>, F>, async_writer: W) -> Self {
+        Self {
+            schema: sync_writer.schema.clone(),
+            sync_marker: sync_writer.format.sync_marker().copied(),
+            sync_writer: Some(sync_writer),
+            async_writer,
+            spawn_blocking: false,
+        }
+    }
+
+    /// Encode and compress batches on tokio's blocking thread pool, using
+    /// [`tokio::task::spawn_blocking`], instead of on the calling task.
+    ///
+    /// This requires [`Self::write`] to be called from within a tokio runtime.
+    pub fn with_spawn_blocking(mut self, spawn_blocking: bool) -> Self {
+        self.spawn_blocking = spawn_blocking;
+        self
+    }
+
+    /// Serialize one [`RecordBatch`] and write it to the underlying sink.
+    pub async fn write(&mut self, batch: &RecordBatch) -> Result<(), AvroError> {
+        if self.spawn_blocking {
+            let mut sync_writer = self.sync_writer.take().ok_or_else(poisoned)?;
+            let batch = batch.clone();
+            let task = tokio::task::spawn_blocking(move || {
+                let result = sync_writer.write(&batch);
+                (sync_writer, result)
+            });
+            let (sync_writer, result) = match task.await {
+                Ok(r) => r,
+                Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+                Err(e) => return Err(AvroError::External(Box::new(e))),
+            };
+            self.sync_writer = Some(sync_writer);
+            result?;
+        } else {
+            self.sync_writer_mut()?.write(batch)?;
+        }
+        self.flush_buffer().await
+    }
+
+    /// A convenience method to write a slice of [`RecordBatch`].
+    ///
+    /// This is equivalent to calling `write` for each batch in the slice.
+    pub async fn write_batches(&mut self, batches: &[&RecordBatch]) -> Result<(), AvroError> {
+        for b in batches {
+            self.write(b).await?;
+        }
+        Ok(())
+    }
+
+    /// Write any buffered data, including the header if no batches were written,
+    /// and flush and shut down the underlying sink.
+    pub async fn finish(&mut self) -> Result<(), AvroError> {
+        self.flush_buffer().await?;
+        self.async_writer
+            .shutdown()
+            .await
+            .map_err(|e| AvroError::IoError(format!("Error closing writer: {e}"), e))
+    }
+
+    /// Returns the Arrow schema of the batches accepted by this writer.
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Consume the writer, returning the underlying sink.
+    ///
+    /// Any data not yet written by [`Self::write`] or [`Self::finish`] is lost.
+    pub fn into_inner(self) -> W {
+        self.async_writer
+    }
+
+    async fn flush_buffer(&mut self) -> Result<(), AvroError> {
+        let buffer = &mut self.sync_writer.as_mut().ok_or_else(poisoned)?.writer;
+        if buffer.is_empty() {
+            return Ok(());
+        }
+        self.async_writer
+            .write_all(buffer)
+            .await
+            .map_err(|e| AvroError::IoError(format!("Error writing Avro data: {e}"), e))?;
+        buffer.clear();
+        Ok(())
+    }
+
+    fn sync_writer_mut(&mut self) -> Result<&mut Writer<Vec<u8>, F>, AvroError> {
+        self.sync_writer.as_mut().ok_or_else(poisoned)
+    }
+}
+
+fn poisoned() -> AvroError {
+    AvroError::General("AsyncWriter used after a write was cancelled".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compression::CompressionCodec;
+    use crate::reader::ReaderBuilder;
+    use arrow::compute::concat_batches;
+    use arrow_array::{ArrayRef, Int64Array, StringArray};
+    use arrow_schema::{DataType, Field};
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    fn make_batch(start: i64, len: usize) -> RecordBatch {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+        let ids = Int64Array::from_iter_values(start..start + len as i64);
+        let names = StringArray::from_iter((0..len).map(|i| (i % 3 != 0).then(|| i.to_string())));
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(ids) as ArrayRef, Arc::new(names) as ArrayRef],
+        )
+        .unwrap()
+    }
+
+    fn read_all(bytes: Vec<u8>) -> Vec<RecordBatch> {
+        ReaderBuilder::new()
+            .build(Cursor::new(bytes))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    fn assert_same_rows(read: &[RecordBatch], expected: &[RecordBatch]) {
+        let schema = expected[0].schema();
+        let read = concat_batches(&read[0].schema(), read).unwrap();
+        let expected = concat_batches(&schema, expected).unwrap();
+        assert_eq!(read.columns(), expected.columns());
+    }
+
+    #[tokio::test]
+    async fn test_async_writer_roundtrip() -> Result<(), AvroError> {
+        let batches = [make_batch(0, 100), make_batch(100, 50)];
+        let mut writer = AsyncAvroWriter::new(Vec::new(), batches[0].schema().as_ref().clone())?;
+        writer.write_batches(&[&batches[0], &batches[1]]).await?;
+        writer.finish().await?;
+
+        let read = read_all(writer.into_inner());
+        assert_same_rows(&read, &batches);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_async_writer_matches_sync_writer() -> Result<(), AvroError> {
+        let batch = make_batch(0, 10);
+        let schema = batch.schema().as_ref().clone();
+
+        let mut writer = AsyncAvroStreamWriter::new(Vec::new(), schema.clone())?;
+        writer.write(&batch).await?;
+        writer.finish().await?;
+
+        let mut sync_writer = crate::writer::AvroStreamWriter::new(Vec::new(), schema)?;
+        sync_writer.write(&batch)?;
+        sync_writer.finish()?;
+
+        assert_eq!(writer.into_inner(), sync_writer.into_inner());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_async_writer_header_only() -> Result<(), AvroError> {
+        let batch = make_batch(0, 0);
+        let mut writer = AsyncAvroWriter::new(Vec::new(), batch.schema().as_ref().clone())?;
+        writer.finish().await?;
+
+        let bytes = writer.into_inner();
+        assert!(bytes.starts_with(b"Obj\x01"));
+        assert!(read_all(bytes).is_empty());
+        Ok(())
+    }
+
+    #[cfg(feature = "deflate")]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_async_writer_spawn_blocking_compressed() -> Result<(), AvroError> {
+        let batches = [make_batch(0, 1000), make_batch(1000, 1000)];
+        let mut writer = WriterBuilder::new(batches[0].schema().as_ref().clone())
+            .with_compression(Some(CompressionCodec::Deflate))
+            .build_async::<_, AvroOcfFormat>(Vec::new())?
+            .with_spawn_blocking(true);
+        for batch in &batches {
+            writer.write(batch).await?;
+        }
+        writer.finish().await?;
+
+        let read = read_all(writer.into_inner());
+        assert_same_rows(&read, &batches);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_async_writer_schema_mismatch() -> Result<(), AvroError> {
+        let batch = make_batch(0, 1);
+        let schema = Schema::new(vec![Field::new("other", DataType::Int64, false)]);
+        let mut writer = AsyncAvroWriter::new(Vec::new(), schema)?;
+        let err = writer.write(&batch).await.unwrap_err();
+        assert!(matches!(err, AvroError::SchemaError(_)), "{err}");
+        Ok(())
+    }
+}
@@ -715,6 +715,13 @@ impl<'a> FieldEncoder<'a> {
                     )));
                 }
             },
+            FieldPlan::BinaryToString => {
+                Encoder::BinaryToUtf8(BinaryToUtf8Encoder(BinaryValues::try_new(array)?))
+            }
+            FieldPlan::BinaryToFixed { size } => Encoder::BinaryToFixed(BinaryToFixedEncoder {
+                values: BinaryValues::try_new(array)?,
+                size: *size,
+            }),
             FieldPlan::TimeMillisFromSecs => match array.data_type() {
                 DataType::Time32(TimeUnit::Second) => Encoder::Time32SecsToMillis(
                     Time32SecondsToMillisEncoder(array.as_primitive::<Time32SecondType>()),
@@ -806,6 +813,10 @@ enum FieldPlan {
     /// Used when `Codec::TimeMillis` is resolved for a `Time32(Second)` Arrow column,
     /// ensuring the scaling encoder is selected regardless of the `avro_custom_types` feature flag.
     TimeMillisFromSecs,
+    /// Arrow binary column mapped to Avro `string` — values must be valid UTF-8.
+    BinaryToString,
+    /// Arrow binary column mapped to Avro `fixed` — values must be exactly `size` bytes.
+    BinaryToFixed { size: usize },
 }
 
 #[derive(Debug, Clone)]
@@ -948,7 +959,7 @@ impl RecordEncoder {
     ) -> Result<(), AvroError> {
         let out_len = out.len();
         if offsets.first() != Some(&0) || offsets.last() != Some(&out_len) {
-            return Err(AvroError::General(
+            return Err(AvroError::InvalidArgument(
                 "encode_rows requires offsets to start with 0 and end at out.len()".to_string(),
             ));
         }
@@ -957,7 +968,7 @@ impl RecordEncoder {
             return Ok(());
         }
         if offsets.len().checked_add(n).is_none() {
-            return Err(AvroError::General(
+            return Err(AvroError::InvalidArgument(
                 "encode_rows cannot append offsets: too many rows".to_string(),
             ));
         }
@@ -1250,6 +1261,20 @@ impl FieldPlan {
                 DataType::Time32(TimeUnit::Second) => Ok(FieldPlan::TimeMillisFromSecs),
                 _ => Ok(FieldPlan::Scalar),
             },
+            Codec::Utf8 | Codec::Utf8View => match arrow_field.data_type() {
+                DataType::Binary | DataType::LargeBinary | DataType::BinaryView => {
+                    Ok(FieldPlan::BinaryToString)
+                }
+                _ => Ok(FieldPlan::Scalar),
+            },
+            Codec::Fixed(size) => match arrow_field.data_type() {
+                DataType::Binary | DataType::LargeBinary | DataType::BinaryView => {
+                    Ok(FieldPlan::BinaryToFixed {
+                        size: *size as usize,
+                    })
+                }
+                _ => Ok(FieldPlan::Scalar),
+            },
             _ => Ok(FieldPlan::Scalar),
         }
     }
@@ -1288,6 +1313,10 @@ enum Encoder<'a> {
     Struct(Box<StructEncoder<'a>>),
     /// Avro `fixed` encoder (raw bytes, no length)
     Fixed(FixedEncoder<'a>),
+    /// Avro `string` encoder for Arrow binary values
+    BinaryToUtf8(BinaryToUtf8Encoder<'a>),
+    /// Avro `fixed` encoder for Arrow binary values
+    BinaryToFixed(BinaryToFixedEncoder<'a>),
     /// Avro `uuid` logical type encoder (string with RFC‑4122 hyphenated text)
     Uuid(UuidEncoder<'a>),
     /// Avro `duration` logical type encoder (`fixed(12)` months/days/millis) for MonthDayNano.
@@ -1395,6 +1424,8 @@ impl<'a> Encoder<'a> {
             Encoder::FixedSizeList(e) => e.encode(out, idx),
             Encoder::Struct(e) => e.encode(out, idx),
             Encoder::Fixed(e) => (e).encode(out, idx),
+            Encoder::BinaryToUtf8(e) => e.encode(out, idx),
+            Encoder::BinaryToFixed(e) => e.encode(out, idx),
             Encoder::Uuid(e) => (e).encode(out, idx),
             Encoder::IntervalMonthDayNanoDuration(e) => e.encode(out, idx),
             #[cfg(feature = "avro_custom_types")]
@@ -2147,6 +2178,68 @@ impl FixedEncoder<'_> {
     }
 }
 
+/// The values of an Arrow `Binary`, `LargeBinary` or `BinaryView` array.
+enum BinaryValues<'a> {
+    Binary(&'a GenericBinaryArray<i32>),
+    LargeBinary(&'a GenericBinaryArray<i64>),
+    BinaryView(&'a BinaryViewArray),
+}
+
+impl<'a> BinaryValues<'a> {
+    fn try_new(array: &'a dyn Array) -> Result<Self, AvroError> {
+        match array.data_type() {
+            DataType::Binary => Ok(Self::Binary(array.as_binary::<i32>())),
+            DataType::LargeBinary => Ok(Self::LargeBinary(array.as_binary::<i64>())),
+            DataType::BinaryView => Ok(Self::BinaryView(array.as_binary_view())),
+            other => Err(AvroError::SchemaError(format!(
+                "Expected an Arrow binary type, found: {other:?}"
+            ))),
+        }
+    }
+
+    fn value(&self, idx: usize) -> &'a [u8] {
+        match self {
+            Self::Binary(a) => a.value(idx),
+            Self::LargeBinary(a) => a.value(idx),
+            Self::BinaryView(a) => a.value(idx),
+        }
+    }
+}
+
+/// Avro `string` encoder for Arrow binary values, which must be valid UTF-8.
+struct BinaryToUtf8Encoder<'a>(BinaryValues<'a>);
+impl BinaryToUtf8Encoder<'_> {
+    fn encode<W: Write + ?Sized>(&mut self, out: &mut W, idx: usize) -> Result<(), AvroError> {
+        let v = self.0.value(idx);
+        std::str::from_utf8(v).map_err(|e| {
+            AvroError::InvalidArgument(format!(
+                "Binary value at index {idx} is not valid UTF-8 for Avro string: {e}"
+            ))
+        })?;
+        write_len_prefixed(out, v)
+    }
+}
+
+/// Avro `fixed` encoder for Arrow binary values, which must be exactly `size` bytes.
+struct BinaryToFixedEncoder<'a> {
+    values: BinaryValues<'a>,
+    size: usize,
+}
+impl BinaryToFixedEncoder<'_> {
+    fn encode<W: Write + ?Sized>(&mut self, out: &mut W, idx: usize) -> Result<(), AvroError> {
+        let v = self.values.value(idx);
+        if v.len() != self.size {
+            return Err(AvroError::InvalidArgument(format!(
+                "Binary value at index {idx} has length {}, expected {} for Avro fixed",
+                v.len(),
+                self.size
+            )));
+        }
+        out.write_all(v)?;
+        Ok(())
+    }
+}
+
 /// Avro UUID logical type encoder: Arrow FixedSizeBinary(16) to Avro string (UUID).
 /// Spec: uuid is a logical type over string (RFC‑4122). We output hyphenated form.
 struct UuidEncoder<'a>(&'a FixedSizeBinaryArray);
@@ -19,7 +19,9 @@
 
 use crate::compression::{CODEC_METADATA_KEY, CompressionCodec};
 use crate::errors::AvroError;
-use crate::schema::{AvroSchema, AvroSchemaOptions, SCHEMA_METADATA_KEY};
+use crate::schema::{
+    AvroSchema, AvroSchemaOptions, ComplexType, SCHEMA_METADATA_KEY, Schema as AvroSchemaDef,
+};
 use crate::writer::encoder::write_long;
 use arrow_schema::Schema;
 use rand::RngCore;
@@ -63,38 +65,7 @@ impl AvroFormat for AvroOcfFormat {
     ) -> Result<(), AvroError> {
         let mut rng = rand::rng();
         rng.fill_bytes(&mut self.sync_marker);
-        // Choose the Avro schema JSON that the file will advertise.
-        // If `schema.metadata[SCHEMA_METADATA_KEY]` exists, AvroSchema::try_from
-        // uses it verbatim; otherwise it is generated from the Arrow schema.
-        let avro_schema = AvroSchema::from_arrow_with_options(
-            schema,
-            Some(AvroSchemaOptions {
-                null_order: None,
-                strip_metadata: true,
-            }),
-        )
-        .map_err(|e| AvroError::SchemaError(format!("{:?}", e)))?;
-        // Magic
-        writer.write_all(b"Obj\x01")?;
-        // File metadata map: { "avro.schema": <json>, "avro.codec": <codec> }
-        let codec_str = match compression {
-            Some(CompressionCodec::Deflate) => "deflate",
-            Some(CompressionCodec::Snappy) => "snappy",
-            Some(CompressionCodec::ZStandard) => "zstandard",
-            Some(CompressionCodec::Bzip2) => "bzip2",
-            Some(CompressionCodec::Xz) => "xz",
-            None => "null",
-        };
-        // Map block: count=2, then key/value pairs, then terminating count=0
-        write_long(writer, 2)?;
-        write_string(writer, SCHEMA_METADATA_KEY)?;
-        write_bytes(writer, avro_schema.json_string.as_bytes())?;
-        write_string(writer, CODEC_METADATA_KEY)?;
-        write_bytes(writer, codec_str.as_bytes())?;
-        write_long(writer, 0)?;
-        // Sync marker (16 bytes)
-        writer.write_all(&self.sync_marker)?;
-        Ok(())
+        write_ocf_header(writer, schema, compression, &self.sync_marker)
     }
 
     fn sync_marker(&self) -> Option<&[u8; 16]> {
@@ -167,6 +138,76 @@ impl AvroFormat for AvroBinaryFormat {
     }
 }
 
+/// Write an Object Container File header advertising `schema` and `compression`,
+/// terminated by `sync_marker`.
+pub(crate) fn write_ocf_header<W: Write>(
+    writer: &mut W,
+    schema: &Schema,
+    compression: Option<CompressionCodec>,
+    sync_marker: &[u8; 16],
+) -> Result<(), AvroError> {
+    // Choose the Avro schema JSON that the file will advertise.
+    // A schema in `schema.metadata[SCHEMA_METADATA_KEY]` that cannot be regenerated
+    // from the Arrow schema is used verbatim; otherwise the record schema is
+    // generated from the Arrow schema.
+    let avro_schema = match verbatim_schema(schema)? {
+        Some(avro_schema) => avro_schema,
+        None => AvroSchema::from_arrow_with_options(
+            schema,
+            Some(AvroSchemaOptions {
+                null_order: None,
+                strip_metadata: true,
+            }),
+        )
+        .map_err(|e| AvroError::SchemaError(format!("{:?}", e)))?,
+    };
+    // Magic
+    writer.write_all(b"Obj\x01")?;
+    // File metadata map: { "avro.schema": <json>, "avro.codec": <codec> }
+    let codec_str = match compression {
+        Some(CompressionCodec::Deflate) => "deflate",
+        Some(CompressionCodec::Snappy) => "snappy",
+        Some(CompressionCodec::ZStandard) => "zstandard",
+        Some(CompressionCodec::Bzip2) => "bzip2",
+        Some(CompressionCodec::Xz) => "xz",
+        None => "null",
+    };
+    // Map block: count=2, then key/value pairs, then terminating count=0
+    write_long(writer, 2)?;
+    write_string(writer, SCHEMA_METADATA_KEY)?;
+    write_bytes(writer, avro_schema.json_string.as_bytes())?;
+    write_string(writer, CODEC_METADATA_KEY)?;
+    write_bytes(writer, codec_str.as_bytes())?;
+    write_long(writer, 0)?;
+    // Sync marker (16 bytes)
+    writer.write_all(sync_marker)?;
+    Ok(())
+}
+
+/// Returns the Avro schema stored in the metadata of `schema` if it cannot be regenerated
+/// from the Arrow schema, i.e. its root is not a record, or it differs from the record
+/// generated from the Arrow fields, such as when field types are overridden.
+fn verbatim_schema(schema: &Schema) -> Result<Option<AvroSchema>, AvroError> {
+    let Some(json) = schema.metadata.get(SCHEMA_METADATA_KEY) else {
+        return Ok(None);
+    };
+    let avro_schema = AvroSchema::new(json.clone());
+    let is_record = matches!(
+        avro_schema.schema()?,
+        AvroSchemaDef::Complex(ComplexType::Record(_))
+    );
+    if !is_record {
+        return Ok(Some(avro_schema));
+    }
+    let mut metadata = schema.metadata.clone();
+    metadata.remove(SCHEMA_METADATA_KEY);
+    let generated = AvroSchema::try_from(&Schema::new_with_metadata(
+        schema.fields().clone(),
+        metadata,
+    ))?;
+    Ok((generated.json_string != *json).then_some(avro_schema))
+}
+
 #[inline]
 fn write_string<W: Write>(writer: &mut W, s: &str) -> Result<(), AvroError> {
     write_bytes(writer, s.as_bytes())
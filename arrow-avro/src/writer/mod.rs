@@ -42,6 +42,7 @@
 //! |----------|------------------|
 //! | Write an OCF file to disk | [`crate::writer::AvroWriter`] |
 //! | Stream records continuously to a file/socket | [`crate::writer::AvroStreamWriter`] |
+//! | Write an OCF file with fixed-size blocks to a pipe/socket | [`crate::writer::StreamingAvroWriter`] |
 //! | Publish individual records to Kafka/Pulsar | [`crate::writer::Encoder`] |
 //! | Need per-row byte slices for custom framing | [`crate::writer::Encoder`] |
 //!
@@ -147,20 +148,33 @@ use crate::codec::AvroFieldBuilder;
 use crate::compression::CompressionCodec;
 use crate::errors::AvroError;
 use crate::schema::{
-    AvroSchema, Fingerprint, FingerprintAlgorithm, FingerprintStrategy, SCHEMA_METADATA_KEY,
+    AVRO_NON_RECORD_ROOT_FIELD_NAME, AvroSchema, Fingerprint, FingerprintAlgorithm,
+    FingerprintStrategy, SCHEMA_METADATA_KEY, sanitise_avro_name,
 };
 use crate::writer::encoder::{RecordEncoder, RecordEncoderBuilder, write_long};
 use crate::writer::format::{AvroFormat, AvroOcfFormat, AvroSoeFormat};
 use arrow_array::RecordBatch;
-use arrow_schema::{Schema, SchemaRef};
+use arrow_schema::{DataType, Schema, SchemaRef};
 use bytes::{Bytes, BytesMut};
+use indexmap::IndexMap;
+use serde_json::{Value, json};
+use std::fmt;
 use std::io::Write;
 use std::sync::Arc;
 
+/// Async writer for [`tokio::io::AsyncWrite`] sinks.
+#[cfg(feature = "async")]
+mod async_writer;
 /// Encodes `RecordBatch` into the Avro binary format.
 mod encoder;
 /// Logic for different Avro container file formats.
 pub mod format;
+/// Object Container File writer for sinks that cannot seek.
+mod streaming;
+
+#[cfg(feature = "async")]
+pub use async_writer::{AsyncAvroStreamWriter, AsyncAvroWriter, AsyncWriter};
+pub use streaming::{DEFAULT_BLOCK_SIZE, StreamingAvroWriter, WriterOptions};
 
 /// A contiguous set of Avro encoded rows.
 ///
@@ -254,10 +268,7 @@ impl EncodedRows {
     /// ```
     pub fn row(&self, n: usize) -> Result<Bytes, AvroError> {
         if n >= self.len() {
-            return Err(AvroError::General(format!(
-                "Row index {n} out of bounds for len {}",
-                self.len()
-            )));
+            return Err(AvroError::IndexOutOfBound(n, self.len()));
         }
         // SAFETY:
         // self.len() is defined as self.offsets.len().saturating_sub(1).
@@ -313,6 +324,60 @@ impl EncodedRows {
     }
 }
 
+/// The Avro type to write an Arrow field as, see [`WriterBuilder::with_field_type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AvroFieldType {
+    /// Avro `bytes`
+    Bytes,
+    /// Avro `string`, each value of a binary field must be valid UTF-8
+    String,
+    /// Avro `fixed` of the given size, each value of a binary field must be exactly
+    /// this many bytes
+    Fixed(usize),
+}
+
+impl AvroFieldType {
+    /// Returns true if an Arrow field of `data_type` can be written as this Avro type
+    fn supports(&self, data_type: &DataType) -> bool {
+        match (self, data_type) {
+            (
+                Self::Bytes | Self::String,
+                DataType::Binary
+                | DataType::LargeBinary
+                | DataType::BinaryView
+                | DataType::Utf8
+                | DataType::LargeUtf8
+                | DataType::Utf8View,
+            ) => true,
+            (Self::Fixed(_), DataType::Binary | DataType::LargeBinary | DataType::BinaryView) => {
+                true
+            }
+            (Self::Fixed(size), DataType::FixedSizeBinary(n)) => usize::try_from(*n) == Ok(*size),
+            _ => false,
+        }
+    }
+
+    /// Returns the Avro schema of this type for the field `name`
+    fn to_json(self, name: &str) -> Value {
+        match self {
+            Self::Bytes => json!("bytes"),
+            Self::String => json!("string"),
+            Self::Fixed(size) => json!({"type": "fixed", "name": name, "size": size}),
+        }
+    }
+}
+
+impl fmt::Display for AvroFieldType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bytes => write!(f, "bytes"),
+            Self::String => write!(f, "string"),
+            Self::Fixed(size) => write!(f, "fixed({size})"),
+        }
+    }
+}
+
 /// Builder to configure and create a `Writer`.
 #[derive(Debug, Clone)]
 pub struct WriterBuilder {
@@ -321,6 +386,8 @@ pub struct WriterBuilder {
     row_capacity: Option<usize>,
     capacity: usize,
     fingerprint_strategy: Option<FingerprintStrategy>,
+    bare_root: bool,
+    field_types: IndexMap<String, AvroFieldType>,
 }
 
 impl WriterBuilder {
@@ -337,6 +404,8 @@ impl WriterBuilder {
             row_capacity: None,
             capacity: 1024,
             fingerprint_strategy: None,
+            bare_root: false,
+            field_types: IndexMap::new(),
         }
     }
 
@@ -370,11 +439,61 @@ impl WriterBuilder {
         self
     }
 
+    /// Write the single column of the schema as a bare, non-record, Avro root.
+    ///
+    /// Avro permits any schema at the root, e.g. `{"type":"array","items":"long"}`. When
+    /// `true`, the schema must have exactly one column, and each row is written as the
+    /// Avro datum of that column's type rather than as a record with one field. The
+    /// column's Avro type becomes the schema written to the file header and used for
+    /// fingerprinting.
+    ///
+    /// Defaults to `false`.
+    pub fn with_bare_root(mut self, bare_root: bool) -> Self {
+        self.bare_root = bare_root;
+        self
+    }
+
+    /// Writes the top-level field `name` as the Avro type `field_type`, rather than the
+    /// type derived from its Arrow type.
+    ///
+    /// This resolves the ambiguity in writing a binary or string column, allowing e.g. a
+    /// `Binary` column to be written as an Avro `string` or `fixed`, or a `Utf8` column as
+    /// Avro `bytes`, without casting the column. The nullability of the field is unchanged.
+    ///
+    /// * [`AvroFieldType::Bytes`] and [`AvroFieldType::String`] support binary and string
+    ///   columns, returning an error when writing a binary value that is not valid UTF-8
+    ///   as a `string`
+    /// * [`AvroFieldType::Fixed`] supports binary columns and `FixedSizeBinary` columns of
+    ///   the same size, returning an error when writing a binary value of any other size.
+    ///   The `fixed` type is named after the field
+    ///
+    /// Any other override results in an error naming the field when the `Writer` or
+    /// `Encoder` is built.
+    pub fn with_field_type(mut self, name: impl Into<String>, field_type: AvroFieldType) -> Self {
+        self.field_types.insert(name.into(), field_type);
+        self
+    }
+
     fn prepare_encoder<F: AvroFormat>(&self) -> Result<(Arc<Schema>, RecordEncoder), AvroError> {
         let avro_schema = match self.schema.metadata.get(SCHEMA_METADATA_KEY) {
             Some(json) => AvroSchema::new(json.clone()),
             None => AvroSchema::try_from(&self.schema)?,
         };
+        let avro_schema = match self.field_types.is_empty() {
+            true => avro_schema,
+            false => self.apply_field_types(avro_schema)?,
+        };
+        let (avro_schema, root_field_name) = if self.bare_root {
+            let [field] = self.schema.fields().as_ref() else {
+                return Err(AvroError::InvalidArgument(format!(
+                    "Writing a bare root schema requires exactly one column, found {}",
+                    self.schema.fields().len()
+                )));
+            };
+            (bare_root_schema(avro_schema)?, field.name().as_str())
+        } else {
+            (avro_schema, AVRO_NON_RECORD_ROOT_FIELD_NAME)
+        };
         let maybe_fingerprint = if F::NEEDS_PREFIX {
             match &self.fingerprint_strategy {
                 Some(FingerprintStrategy::Id(id)) => Some(Fingerprint::Id(*id)),
@@ -396,13 +515,66 @@ impl WriterBuilder {
             avro_schema.clone().json_string,
         );
         let schema = Arc::new(Schema::new_with_metadata(self.schema.fields().clone(), md));
-        let avro_root = AvroFieldBuilder::new(&avro_schema.schema()?).build()?;
+        let avro_root = AvroFieldBuilder::new(&avro_schema.schema()?)
+            .with_root_field_name(root_field_name)
+            .build()?;
         let encoder = RecordEncoderBuilder::new(&avro_root, schema.as_ref())
             .with_fingerprint(maybe_fingerprint)
             .build()?;
         Ok((schema, encoder))
     }
 
+    /// Returns `avro_schema` with the fields named in `field_types` replaced by the
+    /// corresponding Avro type, or the non-null branch for a nullable field.
+    fn apply_field_types(&self, avro_schema: AvroSchema) -> Result<AvroSchema, AvroError> {
+        let mut root: Value = serde_json::from_str(&avro_schema.json_string)
+            .map_err(|e| AvroError::SchemaError(format!("Invalid Avro schema JSON: {e}")))?;
+        let Some(Value::Array(fields)) = root.get_mut("fields") else {
+            return Err(AvroError::SchemaError(
+                "Field types can only be overridden for a record".to_string(),
+            ));
+        };
+        for (name, field_type) in &self.field_types {
+            let arrow_field = self.schema.field_with_name(name).map_err(|_| {
+                AvroError::SchemaError(format!(
+                    "Cannot write field \"{name}\" as Avro {field_type}: field not found in schema"
+                ))
+            })?;
+            if !field_type.supports(arrow_field.data_type()) {
+                return Err(AvroError::SchemaError(format!(
+                    "Cannot write field \"{name}\" of type {} as Avro {field_type}",
+                    arrow_field.data_type()
+                )));
+            }
+            let avro_name = sanitise_avro_name(name);
+            let avro_type = fields
+                .iter_mut()
+                .find(|f| f.get("name").and_then(Value::as_str) == Some(avro_name.as_str()))
+                .and_then(|f| f.get_mut("type"))
+                .ok_or_else(|| {
+                    AvroError::SchemaError(format!(
+                        "Cannot write field \"{name}\" as Avro {field_type}: field not found in Avro schema"
+                    ))
+                })?;
+            let replacement = field_type.to_json(&avro_name);
+            match avro_type {
+                Value::Array(branches) => {
+                    let mut non_null = branches.iter_mut().filter(|b| *b != "null");
+                    match (non_null.next(), non_null.next()) {
+                        (Some(branch), None) => *branch = replacement,
+                        _ => {
+                            return Err(AvroError::SchemaError(format!(
+                                "Cannot write field \"{name}\" as Avro {field_type}: field is a union"
+                            )));
+                        }
+                    }
+                }
+                other => *other = replacement,
+            }
+        }
+        Ok(AvroSchema::new(root.to_string()))
+    }
+
     /// Build a new [`Encoder`] for the given [`AvroFormat`].
     ///
     /// `Encoder` only supports stream formats (no OCF sync markers). Attempting to build an
@@ -447,6 +619,65 @@ impl WriterBuilder {
             encoder,
         })
     }
+
+    /// Build a new [`AsyncWriter`] with the specified [`AvroFormat`] and builder options.
+    ///
+    /// Any header is buffered, and written to `writer` with the first batch or by
+    /// [`AsyncWriter::finish`].
+    #[cfg(feature = "async")]
+    pub fn build_async<W, F>(self, writer: W) -> Result<AsyncWriter<W, F>, AvroError>
+    where
+        W: tokio::io::AsyncWrite + Unpin + Send,
+        F: AvroFormat + Send + 'static,
+    {
+        let sync_writer = self.build::<Vec<u8>, F>(Vec::new())?;
+        Ok(AsyncWriter::new_with_sync_writer(sync_writer, writer))
+    }
+}
+
+/// Returns the schema of the only field of the record `avro_schema`, or `avro_schema`
+/// itself if its root is not a record.
+fn bare_root_schema(avro_schema: AvroSchema) -> Result<AvroSchema, AvroError> {
+    let root: Value = serde_json::from_str(&avro_schema.json_string)
+        .map_err(|e| AvroError::SchemaError(format!("Invalid Avro schema JSON: {e}")))?;
+    let Value::Object(mut record) = root else {
+        return Ok(avro_schema);
+    };
+    if record.get("type").and_then(Value::as_str) != Some("record") {
+        return Ok(avro_schema);
+    }
+    let mut fields = match record.remove("fields") {
+        Some(Value::Array(fields)) if fields.len() == 1 => fields,
+        _ => {
+            return Err(AvroError::SchemaError(
+                "Writing a bare root schema requires a record with exactly one field".to_string(),
+            ));
+        }
+    };
+    let mut value_type = match fields.pop() {
+        Some(Value::Object(mut field)) => field.remove("type").ok_or_else(|| {
+            AvroError::SchemaError("Avro record field is missing a type".to_string())
+        })?,
+        _ => {
+            return Err(AvroError::SchemaError(
+                "Avro record field must be a JSON object".to_string(),
+            ));
+        }
+    };
+    // A named type nested in the record inherits the record's namespace, which must be
+    // made explicit once the record is removed
+    if let (Some(Value::String(namespace)), Value::Object(named)) =
+        (record.get("namespace"), &mut value_type)
+    {
+        let unqualified = named
+            .get("name")
+            .and_then(Value::as_str)
+            .is_some_and(|name| !name.contains('.'));
+        if unqualified && !named.contains_key("namespace") {
+            named.insert("namespace".to_string(), Value::String(namespace.clone()));
+        }
+    }
+    Ok(AvroSchema::new(value_type.to_string()))
 }
 
 /// A row-by-row encoder for Avro *stream/message* formats (SOE / registry wire formats / raw binary).
@@ -788,21 +1019,25 @@ mod tests {
     use arrow_array::Float32Array;
     #[cfg(feature = "avro_custom_types")]
     use arrow_array::RunArray;
-    use arrow_array::builder::{Int32Builder, ListBuilder};
+    use arrow_array::builder::{
+        Int32Builder, Int64Builder, ListBuilder, MapBuilder, StringBuilder,
+    };
     use arrow_array::cast::AsArray;
     #[cfg(feature = "avro_custom_types")]
-    use arrow_array::types::{Int16Type, Int64Type};
+    use arrow_array::types::Int16Type;
+    use arrow_array::types::Int64Type;
     use arrow_array::types::{
         Int32Type, Time32MillisecondType, Time64MicrosecondType, TimestampMicrosecondType,
         TimestampMillisecondType, TimestampNanosecondType,
     };
     use arrow_array::{
-        Array, ArrayRef, BinaryArray, BooleanArray, Date32Array, Date64Array, Float16Array,
-        Int8Array, Int16Array, Int32Array, Int64Array, IntervalDayTimeArray,
+        Array, ArrayRef, BinaryArray, BinaryViewArray, BooleanArray, Date32Array, Date64Array,
+        Float16Array, Int8Array, Int16Array, Int32Array, Int64Array, IntervalDayTimeArray,
         IntervalMonthDayNanoArray, IntervalYearMonthArray, PrimitiveArray, RecordBatch,
         StringArray, StructArray, Time32MillisecondArray, Time32SecondArray,
-        Time64MicrosecondArray, Time64NanosecondArray, TimestampMillisecondArray,
-        TimestampSecondArray, UInt8Array, UInt16Array, UInt32Array, UInt64Array, UnionArray,
+        Time64MicrosecondArray, Time64NanosecondArray, TimestampMicrosecondArray,
+        TimestampMillisecondArray, TimestampSecondArray, UInt8Array, UInt16Array, UInt32Array,
+        UInt64Array, UnionArray,
     };
     use arrow_buffer::{IntervalDayTime, IntervalMonthDayNano};
     #[cfg(not(feature = "avro_custom_types"))]
@@ -1354,6 +1589,247 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_round_trip_bare_root_ocf() -> Result<(), AvroError> {
+        for (file, expected_schema) in [
+            (
+                "root_array_long.avro",
+                json!({"type": "array", "items": "long"}),
+            ),
+            ("root_nullable_string.avro", json!(["null", "string"])),
+        ] {
+            let path = format!("test/data/{file}");
+            let reader = ReaderBuilder::new().build(BufReader::new(File::open(&path)?))?;
+            let schema = reader.schema();
+            let batches = reader.collect::<Result<Vec<_>, _>>()?;
+            let original = arrow::compute::concat_batches(&schema, &batches)?;
+            let mut writer = WriterBuilder::new(schema.as_ref().clone())
+                .with_bare_root(true)
+                .build::<_, AvroOcfFormat>(Vec::new())?;
+            writer.write(&original)?;
+            writer.finish()?;
+            let bytes = writer.into_inner();
+            let rt_reader = ReaderBuilder::new().build(Cursor::new(bytes))?;
+            let header_schema: Value =
+                serde_json::from_slice(rt_reader.avro_header().get(SCHEMA_METADATA_KEY).unwrap())
+                    .unwrap();
+            assert_eq!(header_schema, expected_schema, "{file}");
+            let rt_batches = rt_reader.collect::<Result<Vec<_>, _>>()?;
+            let round_trip = arrow::compute::concat_batches(&schema, &rt_batches)?;
+            assert_eq!(round_trip, original, "{file}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip_bare_root_map() -> Result<(), AvroError> {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int64Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.append(true)?;
+        builder.append(true)?;
+        builder.keys().append_value("b");
+        builder.values().append_value(-2);
+        builder.keys().append_value("c");
+        builder.values().append_value(3);
+        builder.append(true)?;
+        let map = builder.finish();
+        let schema = Schema::new(vec![Field::new("counts", map.data_type().clone(), false)]);
+        let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(map)])?;
+        let mut writer = WriterBuilder::new(schema)
+            .with_bare_root(true)
+            .build::<_, AvroOcfFormat>(Vec::new())?;
+        writer.write(&batch)?;
+        writer.finish()?;
+        let rt_reader = ReaderBuilder::new()
+            .with_root_field_name("counts")
+            .build(Cursor::new(writer.into_inner()))?;
+        let header_schema: Value =
+            serde_json::from_slice(rt_reader.avro_header().get(SCHEMA_METADATA_KEY).unwrap())
+                .unwrap();
+        assert_eq!(header_schema["type"], "map");
+        assert_eq!(header_schema["values"], json!(["null", "long"]));
+        let rt_batches = rt_reader.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(rt_batches.len(), 1);
+        let rt_map = rt_batches[0].column(0).as_map();
+        assert_eq!(rt_map.value_offsets(), &[0, 1, 1, 3]);
+        assert_eq!(
+            rt_map.values().as_primitive::<Int64Type>().values(),
+            &[1, -2, 3]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_bare_root_schema_keeps_namespace() -> Result<(), AvroError> {
+        let record = json!({
+            "type": "record",
+            "name": "Wrapper",
+            "namespace": "com.example",
+            "fields": [{
+                "name": "color",
+                "type": {"type": "enum", "name": "Color", "symbols": ["RED", "GREEN"]}
+            }]
+        });
+        let bare = bare_root_schema(AvroSchema::new(record.to_string()))?;
+        let bare: Value = serde_json::from_str(&bare.json_string).unwrap();
+        assert_eq!(
+            bare,
+            json!({
+                "type": "enum",
+                "name": "Color",
+                "namespace": "com.example",
+                "symbols": ["RED", "GREEN"]
+            })
+        );
+        let primitive = AvroSchema::new(r#""long""#.to_string());
+        assert_eq!(bare_root_schema(primitive.clone())?, primitive);
+        Ok(())
+    }
+
+    fn write_with_field_types(
+        batch: &RecordBatch,
+        field_types: &[(&str, AvroFieldType)],
+    ) -> Result<Vec<u8>, AvroError> {
+        let mut builder = WriterBuilder::new(batch.schema().as_ref().clone());
+        for (name, field_type) in field_types {
+            builder = builder.with_field_type(*name, *field_type);
+        }
+        let mut writer = builder.build::<_, AvroOcfFormat>(Vec::new())?;
+        writer.write(batch)?;
+        writer.finish()?;
+        Ok(writer.into_inner())
+    }
+
+    #[test]
+    fn test_field_type_override() -> Result<(), AvroError> {
+        let batch = RecordBatch::try_from_iter_with_nullable([
+            (
+                "payload",
+                Arc::new(BinaryArray::from(vec![
+                    Some("héllo".as_bytes()),
+                    None,
+                    Some(b"x"),
+                ])) as ArrayRef,
+                true,
+            ),
+            (
+                "name",
+                Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef,
+                false,
+            ),
+            (
+                "id",
+                Arc::new(BinaryViewArray::from(vec![&b"01"[..], b"23", b"45"])) as ArrayRef,
+                false,
+            ),
+        ])?;
+        let bytes = write_with_field_types(
+            &batch,
+            &[
+                ("payload", AvroFieldType::String),
+                ("name", AvroFieldType::Bytes),
+                ("id", AvroFieldType::Fixed(2)),
+            ],
+        )?;
+        let reader = ReaderBuilder::new().build(Cursor::new(bytes))?;
+        let header_schema: Value =
+            serde_json::from_slice(reader.avro_header().get(SCHEMA_METADATA_KEY).unwrap()).unwrap();
+        let fields = &header_schema["fields"];
+        assert_eq!(fields[0]["type"], json!(["null", "string"]));
+        assert_eq!(fields[1]["type"], json!("bytes"));
+        assert_eq!(
+            fields[2]["type"],
+            json!({"type": "fixed", "name": "id", "size": 2})
+        );
+        let batches = reader.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            batches[0].column(0).as_string::<i32>(),
+            &StringArray::from(vec![Some("héllo"), None, Some("x")])
+        );
+        assert_eq!(
+            batches[0].column(1).as_binary::<i32>(),
+            &BinaryArray::from(vec![&b"a"[..], b"b", b"c"])
+        );
+        let id = batches[0].column(2).as_fixed_size_binary();
+        assert_eq!(id.value_length(), 2);
+        assert_eq!(id.value(2), b"45");
+        Ok(())
+    }
+
+    #[test]
+    fn test_field_type_override_invalid_values() {
+        let batch = RecordBatch::try_from_iter([(
+            "payload",
+            Arc::new(BinaryArray::from(vec![&b"ok"[..], b"a\xFFb"])) as ArrayRef,
+        )])
+        .unwrap();
+        let err = write_with_field_types(&batch, &[("payload", AvroFieldType::String)])
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("Binary value at index 1 is not valid UTF-8 for Avro string"),
+            "{err}"
+        );
+        let err = write_with_field_types(&batch, &[("payload", AvroFieldType::Fixed(2))])
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("Binary value at index 1 has length 3, expected 2 for Avro fixed"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_field_type_override_errors() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::FixedSizeBinary(4), true),
+        ]);
+        let cases = [
+            (
+                "missing",
+                AvroFieldType::Bytes,
+                "Cannot write field \"missing\" as Avro bytes: field not found in schema",
+            ),
+            (
+                "a",
+                AvroFieldType::String,
+                "Cannot write field \"a\" of type Int32 as Avro string",
+            ),
+            (
+                "b",
+                AvroFieldType::Fixed(2),
+                "Cannot write field \"b\" of type FixedSizeBinary(4) as Avro fixed(2)",
+            ),
+            (
+                "b",
+                AvroFieldType::Bytes,
+                "Cannot write field \"b\" of type FixedSizeBinary(4) as Avro bytes",
+            ),
+        ];
+        for (name, field_type, expected) in cases {
+            let err = WriterBuilder::new(schema.clone())
+                .with_field_type(name, field_type)
+                .build::<_, AvroOcfFormat>(Vec::new())
+                .unwrap_err();
+            assert_eq!(err.to_string(), format!("Schema error: {expected}"));
+        }
+    }
+
+    #[test]
+    fn test_bare_root_requires_single_column() {
+        let err = WriterBuilder::new(make_schema())
+            .with_bare_root(true)
+            .build::<_, AvroOcfFormat>(Vec::new())
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("requires exactly one column, found 2"),
+            "{err}"
+        );
+    }
+
     #[test]
     fn test_round_trip_simple_fixed_ocf() -> Result<(), AvroError> {
         let path = arrow_test_data("avro/simple_fixed.avro");
@@ -2773,6 +3249,69 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_roundtrip_local_timestamp_logical_types() -> Result<(), AvroError> {
+        let utc: Arc<str> = "+00:00".into();
+        let schema = Schema::new(vec![
+            Field::new(
+                "local_ms",
+                DataType::Timestamp(TimeUnit::Millisecond, None),
+                true,
+            ),
+            Field::new(
+                "local_us",
+                DataType::Timestamp(TimeUnit::Microsecond, None),
+                true,
+            ),
+            Field::new(
+                "utc_us",
+                DataType::Timestamp(TimeUnit::Microsecond, Some(utc.clone())),
+                false,
+            ),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![
+                Arc::new(TimestampMillisecondArray::from(vec![
+                    Some(1_700_000_000_123),
+                    None,
+                    Some(-1),
+                ])) as ArrayRef,
+                Arc::new(TimestampMicrosecondArray::from(vec![
+                    None,
+                    Some(1_700_000_000_123_456),
+                    Some(i64::MIN),
+                ])) as ArrayRef,
+                Arc::new(TimestampMicrosecondArray::from(vec![0, 1, -1]).with_timezone(utc.clone()))
+                    as ArrayRef,
+            ],
+        )?;
+        let mut writer = AvroWriter::new(Vec::<u8>::new(), schema.clone())?;
+        writer.write(&batch)?;
+        writer.finish()?;
+        let rt_reader = ReaderBuilder::new().build(Cursor::new(writer.into_inner()))?;
+        let header_schema: Value =
+            serde_json::from_slice(rt_reader.avro_header().get(SCHEMA_METADATA_KEY).unwrap())
+                .unwrap();
+        let fields = &header_schema["fields"];
+        assert_eq!(
+            fields[0]["type"],
+            json!(["null", {"type": "long", "logicalType": "local-timestamp-millis"}])
+        );
+        assert_eq!(
+            fields[1]["type"],
+            json!(["null", {"type": "long", "logicalType": "local-timestamp-micros"}])
+        );
+        assert_eq!(
+            fields[2]["type"],
+            json!({"type": "long", "logicalType": "timestamp-micros"})
+        );
+        assert_eq!(rt_reader.schema().as_ref(), &schema);
+        let rt_batches = rt_reader.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(rt_batches, vec![batch]);
+        Ok(())
+    }
+
     fn make_encoder_schema() -> Schema {
         Schema::new(vec![
             Field::new("a", DataType::Int32, false),
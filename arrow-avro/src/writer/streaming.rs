@@ -0,0 +1,418 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Avro Object Container File writer for sinks that cannot seek.
+
+use crate::compression::CompressionCodec;
+use crate::errors::AvroError;
+use crate::writer::WriterBuilder;
+use crate::writer::encoder::{RecordEncoder, write_long};
+use crate::writer::format::{AvroOcfFormat, write_ocf_header};
+use arrow_array::RecordBatch;
+use arrow_schema::{Schema, SchemaRef};
+use bytes::BytesMut;
+use rand::RngCore;
+use std::io::Write;
+
+/// The default target size of a block in bytes, matching the default sync
+/// interval of the Java Avro implementation.
+pub const DEFAULT_BLOCK_SIZE: usize = 64_000;
+
+/// Options for a [`StreamingAvroWriter`].
+#[derive(Debug, Clone)]
+pub struct WriterOptions {
+    /// The compression codec applied to each block, or `None` for no compression.
+    pub codec: Option<CompressionCodec>,
+    /// The 16‑byte sync marker written after the header and after every block.
+    pub sync_marker: [u8; 16],
+    /// The target size in bytes of the uncompressed data in each block.
+    ///
+    /// A block is emitted as soon as the buffered rows reach this size, so blocks
+    /// may exceed it by at most one row. A block always contains at least one row.
+    pub block_size: usize,
+}
+
+impl Default for WriterOptions {
+    /// No compression, a randomly generated sync marker and [`DEFAULT_BLOCK_SIZE`].
+    fn default() -> Self {
+        let mut sync_marker = [0; 16];
+        rand::rng().fill_bytes(&mut sync_marker);
+        Self {
+            codec: None,
+            sync_marker,
+            block_size: DEFAULT_BLOCK_SIZE,
+        }
+    }
+}
+
+/// Writes an Avro **Object Container File** to a sink that is only [`Write`].
+///
+/// Unlike [`AvroWriter`](crate::writer::AvroWriter), which emits one block per
+/// [`RecordBatch`], this writer decouples the block layout from the batches it is
+/// given: encoded rows are staged in a single in-memory block, and every block is
+/// written out in full, i.e. the row count, byte count, data and sync marker, once it
+/// reaches [`WriterOptions::block_size`]. No previously written bytes are ever
+/// revisited, so the output can be a network stream, a pipe or any other
+/// forward-only sink.
+///
+/// Call [`finish`](Self::finish) once all batches have been written to emit the
+/// final, partially filled block.
+///
+/// ### Example
+///
+/// ```
+/// use std::io::Cursor;
+/// use std::sync::Arc;
+/// use arrow_array::{ArrayRef, Int64Array, RecordBatch};
+/// use arrow_schema::{DataType, Field, Schema};
+/// use arrow_avro::reader::ReaderBuilder;
+/// use arrow_avro::writer::{StreamingAvroWriter, WriterOptions};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let schema = Schema::new(vec![Field::new("id", DataType::Int64, false)]);
+/// let batch = RecordBatch::try_new(
+///     Arc::new(schema.clone()),
+///     vec![Arc::new(Int64Array::from_iter_values(0..1000)) as ArrayRef],
+/// )?;
+///
+/// let options = WriterOptions {
+///     block_size: 256,
+///     ..Default::default()
+/// };
+/// let mut writer = StreamingAvroWriter::try_new(Vec::<u8>::new(), schema, options)?;
+/// writer.write(&batch)?;
+/// writer.finish()?;
+/// let bytes = writer.into_inner();
+///
+/// let reader = ReaderBuilder::new().build(Cursor::new(bytes))?;
+/// let rows: usize = reader.map(|b| b.unwrap().num_rows()).sum();
+/// assert_eq!(rows, 1000);
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct StreamingAvroWriter<W: Write> {
+    writer: W,
+    schema: SchemaRef,
+    encoder: RecordEncoder,
+    options: WriterOptions,
+    /// Encoded rows that have not yet been written out as part of a block
+    buffer: BytesMut,
+    /// Row boundaries within `buffer`, seeded with `0`
+    offsets: Vec<usize>,
+}
+
+impl<W: Write> StreamingAvroWriter<W> {
+    /// Create a new writer, writing the file header to `writer` immediately.
+    ///
+    /// The Avro schema is determined in the same way as for [`WriterBuilder::new`].
+    pub fn try_new(
+        mut writer: W,
+        schema: Schema,
+        options: WriterOptions,
+    ) -> Result<Self, AvroError> {
+        if options.block_size == 0 {
+            return Err(AvroError::InvalidArgument(
+                "block_size must be greater than 0".to_string(),
+            ));
+        }
+        let (schema, encoder) = WriterBuilder::new(schema).prepare_encoder::<AvroOcfFormat>()?;
+        write_ocf_header(&mut writer, &schema, options.codec, &options.sync_marker)?;
+        Ok(Self {
+            writer,
+            schema,
+            encoder,
+            buffer: BytesMut::with_capacity(options.block_size),
+            offsets: vec![0],
+            options,
+        })
+    }
+
+    /// Encode one [`RecordBatch`], writing out every block that becomes full.
+    pub fn write(&mut self, batch: &RecordBatch) -> Result<(), AvroError> {
+        if batch.schema().fields() != self.schema.fields() {
+            return Err(AvroError::SchemaError(
+                "Schema of RecordBatch differs from Writer schema".to_string(),
+            ));
+        }
+        self.encoder
+            .encode_rows(batch, 0, &mut self.buffer, &mut self.offsets)?;
+        self.write_full_blocks()
+    }
+
+    /// A convenience method to write a slice of [`RecordBatch`].
+    ///
+    /// This is equivalent to calling `write` for each batch in the slice.
+    pub fn write_batches(&mut self, batches: &[&RecordBatch]) -> Result<(), AvroError> {
+        for b in batches {
+            self.write(b)?;
+        }
+        Ok(())
+    }
+
+    /// Write out any buffered rows as a final block and flush the underlying writer.
+    ///
+    /// Further batches may still be written afterwards, starting a new block.
+    pub fn finish(&mut self) -> Result<(), AvroError> {
+        self.write_remaining()?;
+        self.writer
+            .flush()
+            .map_err(|e| AvroError::IoError(format!("Error flushing writer: {e}"), e))
+    }
+
+    /// Returns the number of encoded rows that have not yet been written out.
+    pub fn buffered_rows(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Returns the Arrow schema of this writer.
+    ///
+    /// The returned schema includes the Avro schema JSON under the `avro.schema`
+    /// metadata key.
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Returns the options of this writer.
+    pub fn options(&self) -> &WriterOptions {
+        &self.options
+    }
+
+    /// Consume the writer, returning the underlying output object.
+    ///
+    /// Any rows buffered since the last call to [`finish`](Self::finish) are discarded.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Write out blocks for as long as the buffered rows reach `block_size`.
+    fn write_full_blocks(&mut self) -> Result<(), AvroError> {
+        let mut start = 0;
+        for end in 1..self.offsets.len() {
+            if self.offsets[end] - self.offsets[start] >= self.options.block_size {
+                self.write_block(start, end)?;
+                start = end;
+            }
+        }
+        self.consume(start);
+        Ok(())
+    }
+
+    /// Write out all buffered rows as a single block.
+    fn write_remaining(&mut self) -> Result<(), AvroError> {
+        let rows = self.buffered_rows();
+        if rows > 0 {
+            self.write_block(0, rows)?;
+            self.consume(rows);
+        }
+        Ok(())
+    }
+
+    /// Write the buffered rows `start..end` as a single block.
+    fn write_block(&mut self, start: usize, end: usize) -> Result<(), AvroError> {
+        let data = &self.buffer[self.offsets[start]..self.offsets[end]];
+        let compressed;
+        let data = match self.options.codec {
+            Some(codec) => {
+                compressed = codec.compress(data)?;
+                compressed.as_slice()
+            }
+            None => data,
+        };
+        write_long(&mut self.writer, (end - start) as i64)?;
+        write_long(&mut self.writer, data.len() as i64)?;
+        self.writer
+            .write_all(data)
+            .map_err(|e| AvroError::IoError(format!("Error writing Avro block: {e}"), e))?;
+        self.writer
+            .write_all(&self.options.sync_marker)
+            .map_err(|e| AvroError::IoError(format!("Error writing Avro sync: {e}"), e))?;
+        Ok(())
+    }
+
+    /// Discard the first `rows` buffered rows once they have been written out.
+    fn consume(&mut self, rows: usize) {
+        if rows == 0 {
+            return;
+        }
+        let len = self.offsets[rows];
+        let _ = self.buffer.split_to(len);
+        // Rebase the remaining row boundaries onto the start of the buffer
+        self.offsets.drain(..rows);
+        self.offsets.iter_mut().for_each(|o| *o -= len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReaderBuilder;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_array::{ArrayRef, Int64Array, StringArray};
+    use arrow_schema::{DataType, Field};
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    /// A sink that only implements `Write`, recording every write
+    #[derive(Debug, Default)]
+    struct ForwardOnly {
+        data: Vec<u8>,
+        writes: usize,
+    }
+
+    impl Write for ForwardOnly {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.writes += 1;
+            self.data.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn make_batch(start: i64, len: usize) -> RecordBatch {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+        let ids = Int64Array::from_iter_values(start..start + len as i64);
+        let names: StringArray = (0..len)
+            .map(|i| (i % 3 != 0).then(|| "x".repeat(i % 17)))
+            .collect();
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(ids) as ArrayRef, Arc::new(names) as ArrayRef],
+        )
+        .unwrap()
+    }
+
+    fn read_ids(bytes: Vec<u8>) -> (Vec<i64>, usize) {
+        let reader = ReaderBuilder::new()
+            .with_batch_size(1_000_000)
+            .build(Cursor::new(bytes))
+            .unwrap();
+        let mut ids = vec![];
+        let mut batches = 0;
+        for batch in reader {
+            let batch = batch.unwrap();
+            batches += 1;
+            ids.extend(batch.column(0).as_primitive::<Int64Type>().values());
+        }
+        (ids, batches)
+    }
+
+    fn count_sync_markers(bytes: &[u8], sync_marker: &[u8; 16]) -> usize {
+        bytes.windows(16).filter(|w| *w == sync_marker).count()
+    }
+
+    #[test]
+    fn test_streaming_writer_splits_blocks() -> Result<(), AvroError> {
+        let sync_marker = *b"0123456789abcdef";
+        let options = WriterOptions {
+            codec: None,
+            sync_marker,
+            block_size: 128,
+        };
+        let batch = make_batch(0, 500);
+        let mut writer = StreamingAvroWriter::try_new(
+            ForwardOnly::default(),
+            batch.schema().as_ref().clone(),
+            options,
+        )?;
+        writer.write(&batch)?;
+        assert!(writer.buffered_rows() < 500);
+        writer.write(&make_batch(500, 3))?;
+        writer.finish()?;
+        assert_eq!(writer.buffered_rows(), 0);
+
+        let sink = writer.into_inner();
+        assert!(sink.writes > 0);
+        // One marker ends the header, the rest end one block each
+        let blocks = count_sync_markers(&sink.data, &sync_marker) - 1;
+        assert!(blocks > 10, "expected many blocks, got {blocks}");
+
+        let (ids, _) = read_ids(sink.data);
+        assert_eq!(ids, (0..503).collect::<Vec<_>>());
+        Ok(())
+    }
+
+    #[test]
+    fn test_streaming_writer_buffers_small_batches() -> Result<(), AvroError> {
+        let sync_marker = [7; 16];
+        let options = WriterOptions {
+            codec: None,
+            sync_marker,
+            block_size: 1 << 20,
+        };
+        let batch = make_batch(0, 10);
+        let mut writer =
+            StreamingAvroWriter::try_new(Vec::new(), batch.schema().as_ref().clone(), options)?;
+        let header_len = writer.writer.len();
+        for i in 0..10 {
+            writer.write(&make_batch(i * 10, 10))?;
+        }
+        // Nothing beyond the header is written until the block is full or finished
+        assert_eq!(writer.writer.len(), header_len);
+        assert_eq!(writer.buffered_rows(), 100);
+        writer.finish()?;
+
+        let bytes = writer.into_inner();
+        assert_eq!(count_sync_markers(&bytes, &sync_marker), 2);
+        let (ids, batches) = read_ids(bytes);
+        assert_eq!(ids, (0..100).collect::<Vec<_>>());
+        assert_eq!(batches, 1);
+        Ok(())
+    }
+
+    #[cfg(feature = "deflate")]
+    #[test]
+    fn test_streaming_writer_compressed() -> Result<(), AvroError> {
+        let options = WriterOptions {
+            codec: Some(CompressionCodec::Deflate),
+            block_size: 1024,
+            ..Default::default()
+        };
+        let batch = make_batch(0, 2000);
+        let mut writer =
+            StreamingAvroWriter::try_new(Vec::new(), batch.schema().as_ref().clone(), options)?;
+        writer.write(&batch)?;
+        writer.finish()?;
+        let (ids, _) = read_ids(writer.into_inner());
+        assert_eq!(ids, (0..2000).collect::<Vec<_>>());
+        Ok(())
+    }
+
+    #[test]
+    fn test_streaming_writer_rejects_invalid_input() {
+        let batch = make_batch(0, 1);
+        let schema = batch.schema().as_ref().clone();
+        let options = WriterOptions {
+            block_size: 0,
+            ..Default::default()
+        };
+        let err = StreamingAvroWriter::try_new(Vec::new(), schema.clone(), options).unwrap_err();
+        assert!(matches!(err, AvroError::InvalidArgument(_)));
+
+        let other = Schema::new(vec![Field::new("x", DataType::Int32, false)]);
+        let mut writer =
+            StreamingAvroWriter::try_new(Vec::new(), other, WriterOptions::default()).unwrap();
+        let err = writer.write(&batch).unwrap_err();
+        assert!(matches!(err, AvroError::SchemaError(_)));
+    }
+}
@@ -23,7 +23,7 @@ use crate::{
     buffer_bin_xor,
 };
 
-use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Range};
 
 /// A slice-able [`Buffer`] containing bit-packed booleans
 ///
@@ -153,6 +153,48 @@ impl BooleanBuffer {
         }
     }
 
+    /// Create a new [`BooleanBuffer`] of `len` bits where only the bits within `ranges` are set
+    ///
+    /// This is the inverse of [`Self::set_ranges`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ranges` are not in ascending order, overlap, or extend beyond `len`
+    pub fn from_set_ranges<I: IntoIterator<Item = Range<usize>>>(ranges: I, len: usize) -> Self {
+        let mut builder = BooleanBufferBuilder::new(len);
+        for range in ranges {
+            assert!(
+                range.start >= builder.len() && range.start <= range.end && range.end <= len,
+                "range {range:?} must be in ascending order, not overlap, and be within 0..{len}"
+            );
+            builder.append_n(range.start - builder.len(), false);
+            builder.append_n(range.end - range.start, true);
+        }
+        builder.append_n(len - builder.len(), false);
+        builder.finish()
+    }
+
+    /// Create a new [`BooleanBuffer`] of `len` bits containing the bitwise OR of `buffers`
+    ///
+    /// This processes the input a `u64` word at a time into a single output allocation,
+    /// and so is more efficient than repeatedly applying `|` to many buffers
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of `buffers` does not have length `len`
+    pub fn from_unioned<'a, I>(len: usize, buffers: I) -> Self
+    where
+        I: IntoIterator<Item = &'a BooleanBuffer>,
+    {
+        let mut words = vec![0_u64; len.div_ceil(64)];
+        for buffer in buffers {
+            assert_eq!(buffer.len(), len, "buffers must all have the same length");
+            let chunks = buffer.bit_chunks().iter_padded();
+            words.iter_mut().zip(chunks).for_each(|(w, c)| *w |= c);
+        }
+        Self::new(Buffer::from_vec(words), 0, len)
+    }
+
     /// Invokes `f` with indexes `0..len` collecting the boolean results into a new `BooleanBuffer`
     pub fn collect_bool<F: FnMut(usize) -> bool>(len: usize, f: F) -> Self {
         let buffer = MutableBuffer::collect_bool(len, f);
@@ -440,6 +482,21 @@ impl BooleanBuffer {
             .count_set_bits_offset(self.bit_offset, self.bit_len)
     }
 
+    /// Returns the number of set bits within `range` of this buffer
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds
+    pub fn count_set_bits_in_range(&self, range: Range<usize>) -> usize {
+        assert!(
+            range.start <= range.end && range.end <= self.bit_len,
+            "range {range:?} is out of bounds for buffer of length {}",
+            self.bit_len
+        );
+        self.buffer
+            .count_set_bits_offset(self.bit_offset + range.start, range.end - range.start)
+    }
+
     /// Finds the position of the n-th set bit (1-based) starting from `start` index.
     /// If fewer than `n` set bits are found, returns the length of the buffer.
     pub fn find_nth_set_bit_position(&self, start: usize, n: usize) -> usize {
@@ -625,6 +682,13 @@ impl BooleanBuffer {
     pub fn set_slices(&self) -> BitSliceIterator<'_> {
         BitSliceIterator::new(self.values(), self.bit_offset, self.bit_len)
     }
+
+    /// Returns an iterator over the contiguous, non-empty, ranges of set bits in ascending order
+    ///
+    /// This is the inverse of [`Self::from_set_ranges`]
+    pub fn set_ranges(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        self.set_slices().map(|(start, end)| start..end)
+    }
 }
 
 impl Not for &BooleanBuffer {
@@ -1245,4 +1309,104 @@ mod tests {
         let buffer = BooleanBuffer::new_unset(100);
         assert_eq!(buffer.clone().find_nth_set_bit_position(0, 1), 100);
     }
+
+    /// Returns `len` random booleans with runs of equal values
+    fn random_runs(len: usize) -> Vec<bool> {
+        let mut v = rand::random::<bool>();
+        (0..len)
+            .map(|_| {
+                if rand::random::<u8>() < 32 {
+                    v = !v;
+                }
+                v
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_count_set_bits_in_range() {
+        let bools = random_runs(300);
+        let buffer = BooleanBuffer::from(bools.as_slice());
+        for (offset, len) in [(0, 300), (3, 200), (64, 128), (100, 0)] {
+            let sliced = buffer.slice(offset, len);
+            for range in [0..len, 0..len / 2, len / 3..len, len / 2..len / 2] {
+                let expected = bools[offset..offset + len][range.clone()]
+                    .iter()
+                    .filter(|v| **v)
+                    .count();
+                assert_eq!(sliced.count_set_bits_in_range(range), expected);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_count_set_bits_in_range_out_of_bounds() {
+        BooleanBuffer::new_set(10).count_set_bits_in_range(5..11);
+    }
+
+    #[test]
+    fn test_set_ranges_round_trip() {
+        for len in [0, 1, 63, 64, 65, 1000] {
+            let bools = random_runs(len + 5);
+            let buffer = BooleanBuffer::from(bools.as_slice()).slice(5, len);
+
+            let ranges: Vec<_> = buffer.set_ranges().collect();
+            for (i, v) in bools[5..].iter().enumerate() {
+                assert_eq!(ranges.iter().any(|r| r.contains(&i)), *v);
+            }
+            // ranges are non-empty and separated by at least one unset bit
+            assert!(ranges.iter().all(|r| !r.is_empty()));
+            assert!(ranges.windows(2).all(|w| w[0].end < w[1].start));
+
+            let round_trip = BooleanBuffer::from_set_ranges(ranges.clone(), len);
+            assert_eq!(round_trip, buffer);
+            assert_eq!(round_trip.set_ranges().collect::<Vec<_>>(), ranges);
+        }
+    }
+
+    #[test]
+    fn test_from_set_ranges() {
+        let buffer = BooleanBuffer::from_set_ranges([1..3, 3..4, 4..4, 6..7], 8);
+        let expected = [false, true, true, true, false, false, true, false];
+        assert_eq!(buffer, BooleanBuffer::from(&expected[..]));
+        assert_eq!(buffer.set_ranges().collect::<Vec<_>>(), vec![1..4, 6..7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "ascending order")]
+    fn test_from_set_ranges_overlapping() {
+        BooleanBuffer::from_set_ranges([1..3, 2..4], 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "ascending order")]
+    fn test_from_set_ranges_out_of_bounds() {
+        BooleanBuffer::from_set_ranges([4..6, 6..9], 8);
+    }
+
+    #[test]
+    fn test_from_unioned() {
+        let len = 200;
+        let buffers: Vec<_> = (0..5)
+            .map(|i| BooleanBuffer::from(random_runs(len + i).as_slice()).slice(i, len))
+            .collect();
+
+        let expected = buffers
+            .iter()
+            .fold(BooleanBuffer::new_unset(len), |acc, b| &acc | b);
+        assert_eq!(BooleanBuffer::from_unioned(len, &buffers), expected);
+        assert_eq!(
+            BooleanBuffer::from_unioned(len, []),
+            BooleanBuffer::new_unset(len)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn test_from_unioned_different_lengths() {
+        let a = BooleanBuffer::new_set(10);
+        let b = BooleanBuffer::new_set(11);
+        BooleanBuffer::from_unioned(10, [&a, &b]);
+    }
 }
@@ -167,6 +167,13 @@ impl BooleanBufferBuilder {
         }
     }
 
+    /// Shrinks the capacity of the internal buffer as much as possible, freeing unused memory.
+    ///
+    /// See [`MutableBuffer::shrink_to_fit`] for more details.
+    pub fn shrink_to_fit(&mut self) {
+        self.buffer.shrink_to_fit();
+    }
+
     /// Resizes the buffer, either truncating its contents (with no change in capacity), or
     /// growing it (potentially reallocating it) and writing `false` in the newly available bits.
     #[inline]
@@ -185,6 +185,24 @@ impl<T: ArrowNativeType> BufferBuilder<T> {
         self.buffer.reserve(n * std::mem::size_of::<T>());
     }
 
+    /// Shrinks the capacity of the internal buffer as much as possible, freeing unused memory.
+    ///
+    /// See [`MutableBuffer::shrink_to_fit`] for more details.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use arrow_buffer::builder::BufferBuilder;
+    /// let mut builder = BufferBuilder::<u32>::new(1024);
+    /// builder.append(42);
+    /// builder.shrink_to_fit();
+    ///
+    /// assert!(builder.capacity() < 1024);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.buffer.shrink_to_fit();
+    }
+
     /// Appends a value of type `T` into the builder,
     /// growing the internal buffer as needed.
     ///
@@ -258,6 +258,15 @@ impl NullBufferBuilder {
             .unwrap_or(0)
     }
 
+    /// Shrinks the capacity of the bitmap as much as possible, freeing unused memory.
+    ///
+    /// This has no effect if no nulls have been appended, as no bitmap is allocated.
+    pub fn shrink_to_fit(&mut self) {
+        if let Some(b) = self.bitmap_builder.as_mut() {
+            b.shrink_to_fit();
+        }
+    }
+
     /// Return the number of bits in the buffer.
     pub fn len(&self) -> usize {
         self.bitmap_builder.as_ref().map_or(self.len, |b| b.len())
@@ -419,4 +428,16 @@ mod tests {
 
         assert_eq!(builder.finish(), None);
     }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut builder = NullBufferBuilder::new(8192);
+        builder.append_non_null();
+        builder.append_null();
+        assert!(builder.allocated_size() >= 1024);
+
+        builder.shrink_to_fit();
+        assert!(builder.allocated_size() < 1024);
+        assert_eq!(builder.as_slice().unwrap(), &[0b01_u8]);
+    }
 }
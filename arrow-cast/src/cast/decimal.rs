@@ -921,9 +921,380 @@ where
     Ok(Arc::new(array))
 }
 
+/// Rounding behaviour applied when a decimal cast discards fractional digits
+///
+/// See [`DecimalCastOptions`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DecimalRoundingMode {
+    /// Discard the excess digits, rounding toward zero
+    Truncate,
+    /// Round to the nearest value, with ties rounding away from zero
+    ///
+    /// This is the rounding used by [`cast_with_options`]
+    #[default]
+    HalfUp,
+    /// Round to the nearest value, with ties rounding to the nearest even value
+    HalfEven,
+}
+
+/// Behaviour when a decimal cast produces a value that does not fit in the
+/// target type
+///
+/// See [`DecimalCastOptions`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DecimalOverflowMode {
+    /// Return an error
+    Error,
+    /// Replace the value with null
+    #[default]
+    Null,
+    /// Replace the value with the largest (or smallest) value representable by the
+    /// target type, e.g. `999.99` for `Decimal128(5, 2)`
+    ///
+    /// Values that have no magnitude, such as `NaN`, are replaced with null
+    Saturate,
+}
+
+/// Options controlling the rounding and overflow behaviour of
+/// [`cast_decimal_with_options`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DecimalCastOptions {
+    /// How to round values that have more fractional digits than the target scale
+    pub rounding: DecimalRoundingMode,
+    /// How to handle values that do not fit in the target type
+    pub overflow: DecimalOverflowMode,
+}
+
+impl DecimalCastOptions {
+    /// Set the [`DecimalRoundingMode`]
+    pub fn with_rounding(mut self, rounding: DecimalRoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Set the [`DecimalOverflowMode`]
+    pub fn with_overflow(mut self, overflow: DecimalOverflowMode) -> Self {
+        self.overflow = overflow;
+        self
+    }
+}
+
+/// Returns `10^exp`, or `None` if it does not fit in an [`i256`]
+fn pow10_i256(exp: u32) -> Option<i256> {
+    i256::from_i128(10).pow_checked(exp).ok()
+}
+
+/// Divides `x` by the positive `div`, rounding according to `mode`
+fn div_round_i256(x: i256, div: i256, mode: DecimalRoundingMode) -> i256 {
+    let d = x.wrapping_div(div);
+    let r = x.wrapping_rem(div).wrapping_abs();
+    // `r < div <= 10^76`, so comparing against `div - r` avoids overflowing `2 * r`
+    let away_from_zero = match mode {
+        DecimalRoundingMode::Truncate => false,
+        DecimalRoundingMode::HalfUp => r >= div.wrapping_sub(r),
+        DecimalRoundingMode::HalfEven => {
+            let other = div.wrapping_sub(r);
+            r > other || (r == other && d.wrapping_rem(i256::from_i128(2)) != i256::ZERO)
+        }
+    };
+    match away_from_zero {
+        true if x < i256::ZERO => d.wrapping_sub(i256::ONE),
+        true => d.wrapping_add(i256::ONE),
+        false => d,
+    }
+}
+
+/// Rescales `x` by `10^delta_scale`, rounding according to `mode` when `delta_scale`
+/// is negative. Returns `None` if the result does not fit in an [`i256`].
+///
+/// As rounding is performed before any precision check, a carry that adds a digit
+/// (e.g. `99.95` to `100.0`) is always reflected in the returned value.
+fn rescale_i256(x: i256, delta_scale: i32, mode: DecimalRoundingMode) -> Option<i256> {
+    if delta_scale >= 0 {
+        x.checked_mul(pow10_i256(delta_scale as u32)?)
+    } else {
+        match pow10_i256(delta_scale.unsigned_abs()) {
+            Some(div) => Some(div_round_i256(x, div, mode)),
+            // |x| < 10^77 / 2, so every value rounds to zero
+            None => Some(i256::ZERO),
+        }
+    }
+}
+
+/// Returns the value with the largest magnitude for `precision` with the sign of `x`
+fn saturate_decimal<O: DecimalType>(x: i256, precision: u8) -> O::Native
+where
+    O::Native: DecimalCast + ArrowNativeTypeOp,
+{
+    let max = O::MAX_FOR_EACH_PRECISION[precision as usize];
+    match x < i256::ZERO {
+        true => max.neg_wrapping(),
+        false => max,
+    }
+}
+
+/// Applies the fallible `f` to every value of `array`, handling values for which `f`
+/// returns `None` according to `overflow`
+fn apply_with_overflow<I, O>(
+    array: &PrimitiveArray<I>,
+    overflow: DecimalOverflowMode,
+    f: impl Fn(I::Native) -> Option<O::Native>,
+    saturate: impl Fn(I::Native) -> Option<O::Native>,
+    error: impl Fn(I::Native) -> ArrowError,
+) -> Result<PrimitiveArray<O>, ArrowError>
+where
+    I: ArrowPrimitiveType,
+    O: ArrowPrimitiveType,
+{
+    match overflow {
+        DecimalOverflowMode::Error => array.try_unary(|x| f(x).ok_or_else(|| error(x))),
+        DecimalOverflowMode::Null => Ok(array.unary_opt(f)),
+        DecimalOverflowMode::Saturate => Ok(array.unary_opt(|x| f(x).or_else(|| saturate(x)))),
+    }
+}
+
+fn cast_decimal_to_decimal_with_options<I, O>(
+    array: &PrimitiveArray<I>,
+    input_scale: i8,
+    output_precision: u8,
+    output_scale: i8,
+    options: &DecimalCastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    I: DecimalType,
+    O: DecimalType,
+    I::Native: DecimalCast + ArrowNativeTypeOp,
+    O::Native: DecimalCast + ArrowNativeTypeOp,
+{
+    validate_decimal_precision_and_scale::<O>(output_precision, output_scale)?;
+    let delta_scale = output_scale as i32 - input_scale as i32;
+    let max = O::MAX_FOR_EACH_PRECISION[output_precision as usize]
+        .to_i256()
+        .unwrap();
+
+    let f = |x: I::Native| {
+        let v = rescale_i256(x.to_i256()?, delta_scale, options.rounding)?;
+        match v.wrapping_abs() <= max {
+            true => O::Native::from_decimal(v),
+            false => None,
+        }
+    };
+    let saturate = |x: I::Native| Some(saturate_decimal::<O>(x.to_i256()?, output_precision));
+    let error = cast_decimal_to_decimal_error::<I, O>(output_precision, output_scale);
+
+    let array = apply_with_overflow::<I, O>(array, options.overflow, f, saturate, error)?;
+    Ok(Arc::new(array.with_precision_and_scale(
+        output_precision,
+        output_scale,
+    )?))
+}
+
+fn cast_decimal_to_integer_with_options<D, T>(
+    array: &PrimitiveArray<D>,
+    input_scale: i8,
+    options: &DecimalCastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    D: DecimalType,
+    D::Native: DecimalCast,
+    T: ArrowPrimitiveType,
+    T::Native: NumCast + Bounded,
+{
+    let f = |x: D::Native| {
+        let v = rescale_i256(x.to_i256()?, -(input_scale as i32), options.rounding)?;
+        <T::Native as NumCast>::from(v.to_i128()?)
+    };
+    let saturate = |x: D::Native| match x.to_i256()? < i256::ZERO {
+        true => Some(T::Native::min_value()),
+        false => Some(T::Native::max_value()),
+    };
+    let error = |x: D::Native| {
+        ArrowError::CastError(format!("value of {:?} is out of range {}", x, T::DATA_TYPE))
+    };
+
+    let array = apply_with_overflow::<D, T>(array, options.overflow, f, saturate, error)?;
+    Ok(Arc::new(array))
+}
+
+fn cast_floating_point_to_decimal_with_options<T, D>(
+    array: &PrimitiveArray<T>,
+    precision: u8,
+    scale: i8,
+    options: &DecimalCastOptions,
+) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    T::Native: AsPrimitive<f64>,
+    D: DecimalType,
+    D::Native: DecimalCast + ArrowNativeTypeOp,
+{
+    validate_decimal_precision_and_scale::<D>(precision, scale)?;
+    let mul = 10_f64.powi(scale as i32);
+
+    let f = |v: T::Native| {
+        let v = mul * v.as_();
+        let v = match options.rounding {
+            DecimalRoundingMode::Truncate => v.trunc(),
+            DecimalRoundingMode::HalfUp => v.round(),
+            DecimalRoundingMode::HalfEven => v.round_ties_even(),
+        };
+        D::Native::from_f64(v).filter(|v| D::is_valid_decimal_precision(*v, precision))
+    };
+    let saturate = |v: T::Native| {
+        let v: f64 = v.as_();
+        match v.is_nan() {
+            true => None,
+            false => Some(saturate_decimal::<D>(
+                i256::from_i128(v.signum() as i128),
+                precision,
+            )),
+        }
+    };
+    let error = |v: T::Native| {
+        ArrowError::CastError(format!(
+            "Cannot cast to {}({}, {}). Overflowing on {:?}",
+            D::PREFIX,
+            precision,
+            scale,
+            v
+        ))
+    };
+
+    let array = apply_with_overflow::<T, D>(array, options.overflow, f, saturate, error)?;
+    Ok(Arc::new(array.with_precision_and_scale(precision, scale)?))
+}
+
+/// Cast `array` to `to_type`, using `options` to control rounding and overflow
+///
+/// This applies to the following casts, for all decimal widths:
+///
+/// * decimal to decimal
+/// * decimal to integer
+/// * floating point to decimal
+///
+/// Any other cast is delegated to [`cast_with_options`], where
+/// [`DecimalOverflowMode::Error`] corresponds to `safe: false`
+///
+/// ```
+/// # use arrow_array::{Array, Decimal128Array, cast::AsArray, types::Decimal128Type};
+/// # use arrow_cast::cast::{cast_decimal_with_options, DecimalCastOptions, DecimalOverflowMode, DecimalRoundingMode};
+/// # use arrow_schema::DataType;
+/// let array = Decimal128Array::from(vec![12345, 12355, 999999])
+///     .with_precision_and_scale(10, 3)
+///     .unwrap();
+/// let options = DecimalCastOptions::default()
+///     .with_rounding(DecimalRoundingMode::HalfEven)
+///     .with_overflow(DecimalOverflowMode::Saturate);
+/// let result = cast_decimal_with_options(&array, &DataType::Decimal128(4, 2), &options).unwrap();
+/// let result = result.as_primitive::<Decimal128Type>();
+/// // 12.345 -> 12.34, 12.355 -> 12.36, 999.999 -> 99.99 (saturated)
+/// assert_eq!(result.values(), &[1234, 1236, 9999]);
+/// ```
+pub fn cast_decimal_with_options(
+    array: &dyn Array,
+    to_type: &DataType,
+    options: &DecimalCastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    use DataType::*;
+
+    let fallback = || {
+        let cast_options = CastOptions {
+            safe: options.overflow != DecimalOverflowMode::Error,
+            ..Default::default()
+        };
+        cast_with_options(array, to_type, &cast_options)
+    };
+
+    macro_rules! from_decimal {
+        ($d:ty, $scale:expr) => {{
+            let array = array.as_primitive::<$d>();
+            match to_type {
+                Decimal32(p, s) => cast_decimal_to_decimal_with_options::<$d, Decimal32Type>(
+                    array, $scale, *p, *s, options,
+                ),
+                Decimal64(p, s) => cast_decimal_to_decimal_with_options::<$d, Decimal64Type>(
+                    array, $scale, *p, *s, options,
+                ),
+                Decimal128(p, s) => cast_decimal_to_decimal_with_options::<$d, Decimal128Type>(
+                    array, $scale, *p, *s, options,
+                ),
+                Decimal256(p, s) => cast_decimal_to_decimal_with_options::<$d, Decimal256Type>(
+                    array, $scale, *p, *s, options,
+                ),
+                Int8 => {
+                    cast_decimal_to_integer_with_options::<$d, Int8Type>(array, $scale, options)
+                }
+                Int16 => {
+                    cast_decimal_to_integer_with_options::<$d, Int16Type>(array, $scale, options)
+                }
+                Int32 => {
+                    cast_decimal_to_integer_with_options::<$d, Int32Type>(array, $scale, options)
+                }
+                Int64 => {
+                    cast_decimal_to_integer_with_options::<$d, Int64Type>(array, $scale, options)
+                }
+                UInt8 => {
+                    cast_decimal_to_integer_with_options::<$d, UInt8Type>(array, $scale, options)
+                }
+                UInt16 => {
+                    cast_decimal_to_integer_with_options::<$d, UInt16Type>(array, $scale, options)
+                }
+                UInt32 => {
+                    cast_decimal_to_integer_with_options::<$d, UInt32Type>(array, $scale, options)
+                }
+                UInt64 => {
+                    cast_decimal_to_integer_with_options::<$d, UInt64Type>(array, $scale, options)
+                }
+                _ => fallback(),
+            }
+        }};
+    }
+
+    macro_rules! from_float {
+        ($f:ty) => {{
+            let array = array.as_primitive::<$f>();
+            match to_type {
+                Decimal32(p, s) => {
+                    cast_floating_point_to_decimal_with_options::<$f, Decimal32Type>(
+                        array, *p, *s, options,
+                    )
+                }
+                Decimal64(p, s) => {
+                    cast_floating_point_to_decimal_with_options::<$f, Decimal64Type>(
+                        array, *p, *s, options,
+                    )
+                }
+                Decimal128(p, s) => {
+                    cast_floating_point_to_decimal_with_options::<$f, Decimal128Type>(
+                        array, *p, *s, options,
+                    )
+                }
+                Decimal256(p, s) => {
+                    cast_floating_point_to_decimal_with_options::<$f, Decimal256Type>(
+                        array, *p, *s, options,
+                    )
+                }
+                _ => fallback(),
+            }
+        }};
+    }
+
+    match array.data_type() {
+        Decimal32(_, s) => from_decimal!(Decimal32Type, *s),
+        Decimal64(_, s) => from_decimal!(Decimal64Type, *s),
+        Decimal128(_, s) => from_decimal!(Decimal128Type, *s),
+        Decimal256(_, s) => from_decimal!(Decimal256Type, *s),
+        Float32 => from_float!(Float32Type),
+        Float64 => from_float!(Float64Type),
+        _ => fallback(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use num_bigint::{BigInt, Sign};
+    use rand::{Rng, SeedableRng, rngs::StdRng};
 
     #[test]
     fn test_parse_string_to_decimal_native() -> Result<(), ArrowError> {
@@ -1018,4 +1389,278 @@ mod tests {
         let result = rescale_decimal::<Decimal128Type, Decimal128Type>(123_i128, 38, 38, 39, 39);
         assert_eq!(result, None);
     }
+
+    fn decimal_cast_options(
+        rounding: DecimalRoundingMode,
+        overflow: DecimalOverflowMode,
+    ) -> DecimalCastOptions {
+        DecimalCastOptions::default()
+            .with_rounding(rounding)
+            .with_overflow(overflow)
+    }
+
+    #[test]
+    fn test_cast_decimal_with_options_rounding() {
+        // -1.25, -1.15, 1.15, 1.25, 1.26 with scale 2
+        let array = Decimal128Array::from(vec![-125, -115, 115, 125, 126])
+            .with_precision_and_scale(38, 2)
+            .unwrap();
+        let cases = [
+            (DecimalRoundingMode::Truncate, vec![-12, -11, 11, 12, 12]),
+            (DecimalRoundingMode::HalfUp, vec![-13, -12, 12, 13, 13]),
+            (DecimalRoundingMode::HalfEven, vec![-12, -12, 12, 12, 13]),
+        ];
+        for (rounding, expected) in cases {
+            let options = decimal_cast_options(rounding, DecimalOverflowMode::Error);
+            let result =
+                cast_decimal_with_options(&array, &DataType::Decimal128(18, 1), &options).unwrap();
+            assert_eq!(
+                result.as_primitive::<Decimal128Type>().values(),
+                expected.as_slice(),
+                "{rounding:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cast_decimal_with_options_overflow() {
+        let array = Decimal128Array::from(vec![Some(12_345), None, Some(-99_999)])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let to_type = DataType::Decimal128(4, 1);
+
+        let options = decimal_cast_options(DecimalRoundingMode::HalfUp, DecimalOverflowMode::Error);
+        let err = cast_decimal_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast to Decimal128(4, 1). Overflowing on -99999"
+        );
+
+        let options = decimal_cast_options(DecimalRoundingMode::HalfUp, DecimalOverflowMode::Null);
+        let result = cast_decimal_with_options(&array, &to_type, &options).unwrap();
+        let result = result.as_primitive::<Decimal128Type>();
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            vec![Some(1235), None, None]
+        );
+
+        let options =
+            decimal_cast_options(DecimalRoundingMode::HalfUp, DecimalOverflowMode::Saturate);
+        let result = cast_decimal_with_options(&array, &to_type, &options).unwrap();
+        let result = result.as_primitive::<Decimal128Type>();
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            vec![Some(1235), None, Some(-9999)]
+        );
+        assert_eq!(result.data_type(), &to_type);
+    }
+
+    #[test]
+    fn test_cast_decimal256_with_options_rounding_carry() {
+        // 10^76 - 1 with scale 1 rounds up to 10^75, which needs 76 digits
+        let max = i256::from_string(&"9".repeat(76)).unwrap();
+        let array = Decimal256Array::from(vec![max, max.neg_wrapping()])
+            .with_precision_and_scale(76, 1)
+            .unwrap();
+        let ten_75 = pow10_i256(75).unwrap();
+
+        let options = decimal_cast_options(DecimalRoundingMode::HalfUp, DecimalOverflowMode::Null);
+        let result =
+            cast_decimal_with_options(&array, &DataType::Decimal256(76, 0), &options).unwrap();
+        let result = result.as_primitive::<Decimal256Type>();
+        assert_eq!(result.values(), &[ten_75, ten_75.neg_wrapping()]);
+
+        let result =
+            cast_decimal_with_options(&array, &DataType::Decimal256(75, 0), &options).unwrap();
+        assert_eq!(result.null_count(), 2);
+
+        let options =
+            decimal_cast_options(DecimalRoundingMode::HalfUp, DecimalOverflowMode::Saturate);
+        let result =
+            cast_decimal_with_options(&array, &DataType::Decimal128(38, 0), &options).unwrap();
+        let result = result.as_primitive::<Decimal128Type>();
+        let max_128 = Decimal128Type::MAX_FOR_EACH_PRECISION[38];
+        assert_eq!(result.values(), &[max_128, -max_128]);
+
+        let options =
+            decimal_cast_options(DecimalRoundingMode::Truncate, DecimalOverflowMode::Error);
+        let result =
+            cast_decimal_with_options(&array, &DataType::Decimal256(75, 0), &options).unwrap();
+        let expected = i256::from_string(&"9".repeat(75)).unwrap();
+        let result = result.as_primitive::<Decimal256Type>();
+        assert_eq!(result.values(), &[expected, expected.neg_wrapping()]);
+    }
+
+    #[test]
+    fn test_cast_decimal_to_integer_with_options() {
+        let array = Decimal128Array::from(vec![Some(250), Some(-350), Some(100_000), None])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+
+        let options =
+            decimal_cast_options(DecimalRoundingMode::HalfEven, DecimalOverflowMode::Saturate);
+        let result = cast_decimal_with_options(&array, &DataType::Int8, &options).unwrap();
+        let result = result.as_primitive::<Int8Type>();
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            vec![Some(2), Some(-4), Some(127), None]
+        );
+
+        let result = cast_decimal_with_options(&array, &DataType::UInt8, &options).unwrap();
+        let result = result.as_primitive::<UInt8Type>();
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            vec![Some(2), Some(0), Some(255), None]
+        );
+
+        let options = decimal_cast_options(DecimalRoundingMode::HalfUp, DecimalOverflowMode::Error);
+        let err = cast_decimal_with_options(&array, &DataType::Int8, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: value of 100000 is out of range Int8"
+        );
+
+        // negative scale multiplies rather than divides
+        let array = Decimal256Array::from(vec![i256::from_i128(12)])
+            .with_precision_and_scale(10, -2)
+            .unwrap();
+        let result = cast_decimal_with_options(&array, &DataType::Int64, &options).unwrap();
+        assert_eq!(result.as_primitive::<Int64Type>().values(), &[1200]);
+    }
+
+    #[test]
+    fn test_cast_float_to_decimal_with_options() {
+        let array = Float64Array::from(vec![1.125, -1.125, 2.5e10, f64::NAN, f64::INFINITY]);
+        let to_type = DataType::Decimal128(6, 2);
+
+        let options =
+            decimal_cast_options(DecimalRoundingMode::HalfEven, DecimalOverflowMode::Saturate);
+        let result = cast_decimal_with_options(&array, &to_type, &options).unwrap();
+        let result = result.as_primitive::<Decimal128Type>();
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            vec![Some(112), Some(-112), Some(999_999), None, Some(999_999)]
+        );
+
+        let options =
+            decimal_cast_options(DecimalRoundingMode::Truncate, DecimalOverflowMode::Null);
+        let result =
+            cast_decimal_with_options(&array, &DataType::Decimal256(6, 2), &options).unwrap();
+        let result = result.as_primitive::<Decimal256Type>();
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            vec![
+                Some(i256::from_i128(112)),
+                Some(i256::from_i128(-112)),
+                None,
+                None,
+                None
+            ]
+        );
+
+        let options = decimal_cast_options(DecimalRoundingMode::HalfUp, DecimalOverflowMode::Error);
+        let err = cast_decimal_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast to Decimal128(6, 2). Overflowing on 25000000000.0"
+        );
+    }
+
+    #[test]
+    fn test_cast_decimal_with_options_fallback() {
+        let array = Decimal128Array::from(vec![1234])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let options = DecimalCastOptions::default();
+        let result = cast_decimal_with_options(&array, &DataType::Utf8, &options).unwrap();
+        assert_eq!(result.as_string::<i32>().value(0), "12.34");
+    }
+
+    /// Reference implementation of decimal rescaling using arbitrary precision arithmetic
+    fn bigint_rescale(x: &BigInt, delta_scale: i32, rounding: DecimalRoundingMode) -> BigInt {
+        let ten = BigInt::from(10);
+        if delta_scale >= 0 {
+            return x * ten.pow(delta_scale as u32);
+        }
+        let div = ten.pow(delta_scale.unsigned_abs());
+        let (q, r) = (
+            x.magnitude() / div.magnitude(),
+            x.magnitude() % div.magnitude(),
+        );
+        let twice = r * 2_u32;
+        let round_up = match rounding {
+            DecimalRoundingMode::Truncate => false,
+            DecimalRoundingMode::HalfUp => twice >= *div.magnitude(),
+            DecimalRoundingMode::HalfEven => {
+                twice > *div.magnitude() || (twice == *div.magnitude() && q.bit(0))
+            }
+        };
+        let q = BigInt::from(q + round_up as u32);
+        match x.sign() {
+            Sign::Minus => -q,
+            _ => q,
+        }
+    }
+
+    fn random_decimal(rng: &mut StdRng, precision: u8) -> i256 {
+        let digits = rng.random_range(1..=precision as usize);
+        let mut s: String = (0..digits)
+            .map(|_| char::from(b'0' + rng.random_range(0..10_u8)))
+            .collect();
+        if rng.random_bool(0.5) {
+            s.insert(0, '-');
+        }
+        i256::from_string(&s).unwrap()
+    }
+
+    fn check_randomized<I: DecimalType, O: DecimalType>(
+        rng: &mut StdRng,
+        input: (u8, i8),
+        output: (u8, i8),
+    ) where
+        I::Native: DecimalCast + ArrowNativeTypeOp,
+        O::Native: DecimalCast + ArrowNativeTypeOp,
+    {
+        let values: Vec<I::Native> = (0..500)
+            .map(|_| I::Native::from_decimal(random_decimal(rng, input.0)).unwrap())
+            .collect();
+        let array = PrimitiveArray::<I>::from_iter_values(values.iter().copied())
+            .with_precision_and_scale(input.0, input.1)
+            .unwrap();
+        let to_type = O::TYPE_CONSTRUCTOR(output.0, output.1);
+        let max: BigInt = BigInt::from(10).pow(output.0 as u32) - 1;
+
+        let roundings = [
+            DecimalRoundingMode::Truncate,
+            DecimalRoundingMode::HalfUp,
+            DecimalRoundingMode::HalfEven,
+        ];
+        for rounding in roundings {
+            let options = decimal_cast_options(rounding, DecimalOverflowMode::Saturate);
+            let result = cast_decimal_with_options(&array, &to_type, &options).unwrap();
+            let result = result.as_primitive::<O>();
+            assert_eq!(result.null_count(), 0);
+
+            for (x, actual) in values.iter().zip(result.values()) {
+                let x: BigInt = x.to_i256().unwrap().to_string().parse().unwrap();
+                let exact = bigint_rescale(&x, (output.1 - input.1) as i32, rounding);
+                let expected = exact.clone().clamp(-max.clone(), max.clone());
+                let actual: BigInt = actual.to_i256().unwrap().to_string().parse().unwrap();
+                assert_eq!(actual, expected, "{x} {rounding:?} {input:?} -> {output:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_cast_decimal_with_options_randomized() {
+        let mut rng = StdRng::seed_from_u64(42);
+        check_randomized::<Decimal128Type, Decimal128Type>(&mut rng, (38, 10), (18, 2));
+        check_randomized::<Decimal128Type, Decimal128Type>(&mut rng, (38, 10), (38, 20));
+        check_randomized::<Decimal128Type, Decimal64Type>(&mut rng, (38, 0), (18, -3));
+        check_randomized::<Decimal128Type, Decimal256Type>(&mut rng, (38, 10), (76, 40));
+        check_randomized::<Decimal256Type, Decimal256Type>(&mut rng, (76, 30), (40, 1));
+        check_randomized::<Decimal256Type, Decimal256Type>(&mut rng, (76, 1), (76, 0));
+        check_randomized::<Decimal256Type, Decimal128Type>(&mut rng, (76, 50), (38, 12));
+        check_randomized::<Decimal32Type, Decimal256Type>(&mut rng, (9, 9), (76, 0));
+    }
 }
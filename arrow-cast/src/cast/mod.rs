@@ -68,9 +68,12 @@ use arrow_data::ArrayData;
 use arrow_data::transform::MutableArrayData;
 use arrow_schema::*;
 use arrow_select::take::take;
-use num_traits::{NumCast, ToPrimitive, cast::AsPrimitive};
+use num_traits::{Bounded, NumCast, ToPrimitive, cast::AsPrimitive};
 
-pub use decimal::{DecimalCast, rescale_decimal};
+pub use decimal::{
+    DecimalCast, DecimalCastOptions, DecimalOverflowMode, DecimalRoundingMode,
+    cast_decimal_with_options, rescale_decimal,
+};
 
 /// CastOptions provides a way to override the default cast behaviors
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -335,6 +338,108 @@ pub fn cast(array: &dyn Array, to_type: &DataType) -> Result<ArrayRef, ArrowErro
     cast_with_options(array, to_type, &CastOptions::default())
 }
 
+/// Cast `array` with the corresponding `field` to `to_type`, see [`cast_with_options`]
+///
+/// Returns the cast array along with `field` updated to have type `to_type`, preserving
+/// any extension type and other metadata of `field`. Returns an error if `array` does
+/// not have the data type of `field`.
+///
+/// If `field` has an extension type, the cast must preserve a storage type supported
+/// by that extension type. With the `canonical_extension_types` feature, casts between
+/// storage types supported by a canonical extension type, such as `Utf8` to `LargeUtf8`
+/// for `arrow.json`, are permitted. Otherwise, changing the storage type of an
+/// extension type is an error, as the extension type may not support the new type. Use
+/// [`cast_with_options`] to cast the storage of an extension type without its field.
+///
+/// The returned field is nullable if the cast array contains nulls, for example
+/// values that could not be cast when [`CastOptions::safe`] is `true`.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{Array, Int32Array};
+/// # use arrow_cast::{CastOptions, cast_with_field};
+/// # use arrow_schema::{DataType, Field};
+/// let metadata = [("unit".to_string(), "meters".to_string())];
+/// let field = Arc::new(Field::new("a", DataType::Int32, true).with_metadata(metadata.into()));
+/// let array = Int32Array::from(vec![1, 2, 3]);
+///
+/// let options = CastOptions::default();
+/// let (cast_field, cast) = cast_with_field(&field, &array, &DataType::Int64, &options).unwrap();
+/// assert_eq!(cast_field.data_type(), &DataType::Int64);
+/// assert_eq!(cast_field.metadata(), field.metadata());
+/// assert_eq!(cast.data_type(), &DataType::Int64);
+/// ```
+pub fn cast_with_field(
+    field: &FieldRef,
+    array: &dyn Array,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<(FieldRef, ArrayRef), ArrowError> {
+    if field.data_type() != array.data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Field '{}' has data type {} but array has data type {}",
+            field.name(),
+            field.data_type(),
+            array.data_type()
+        )));
+    }
+    if field.data_type() == to_type {
+        return Ok((Arc::clone(field), make_array(array.to_data())));
+    }
+
+    let mut cast_field = field.as_ref().clone().with_data_type(to_type.clone());
+    if let Some(name) = field.extension_type_name() {
+        check_extension_storage(&cast_field, name)?;
+    }
+    let array = cast_with_options(array, to_type, cast_options)?;
+    if array.logical_null_count() > 0 {
+        cast_field.set_nullable(true);
+    }
+    Ok((Arc::new(cast_field), array))
+}
+
+/// Returns an error if the extension type `name` of `field` may not support its data type
+fn check_extension_storage(field: &Field, name: &str) -> Result<(), ArrowError> {
+    #[cfg(feature = "canonical_extension_types")]
+    if name.starts_with("arrow.") {
+        return match field.try_canonical_extension_type() {
+            Ok(_) => Ok(()),
+            Err(e) => Err(ArrowError::CastError(format!(
+                "Cannot cast field '{}' with extension type {name} to {}: {e}",
+                field.name(),
+                field.data_type()
+            ))),
+        };
+    }
+    Err(ArrowError::CastError(format!(
+        "Cannot cast field '{}' with extension type {name} to {}: the extension type may not support this storage type",
+        field.name(),
+        field.data_type()
+    )))
+}
+
+/// Cast `array` to the provided data type and return a new Array with type `to_type`,
+/// returning an error if the cast is not supported or fails.
+///
+/// This is equivalent to [`cast`], and is provided for callers that prefer the
+/// `try_` naming convention of [`TryFrom`] to make the fallibility of the
+/// conversion explicit at the call site.
+///
+/// ```
+/// # use arrow_array::{Array, Int32Array, StringArray, cast::AsArray, types::Int32Type};
+/// # use arrow_cast::try_cast;
+/// # use arrow_schema::DataType;
+/// let array = StringArray::from(vec![Some("1"), Some("2"), None]);
+/// let result = try_cast(&array, &DataType::Int32).unwrap();
+/// assert_eq!(result.as_primitive::<Int32Type>(), &Int32Array::from(vec![Some(1), Some(2), None]));
+///
+/// // Unsupported casts return an error
+/// assert!(try_cast(&array, &DataType::Struct(Default::default())).is_err());
+/// ```
+pub fn try_cast(array: &dyn Array, to_type: &DataType) -> Result<ArrayRef, ArrowError> {
+    cast(array, to_type)
+}
+
 fn cast_integer_to_decimal<
     T: ArrowPrimitiveType,
     D: DecimalType + ArrowPrimitiveType<Native = M>,
@@ -2757,6 +2862,7 @@ mod tests {
     use arrow_schema::{DataType, Field};
     use chrono::NaiveDate;
     use half::f16;
+    use std::collections::HashMap;
     use std::sync::Arc;
 
     #[derive(Clone)]
@@ -13347,4 +13453,63 @@ mod tests {
 
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn test_cast_with_field() {
+        let options = CastOptions::default();
+        let json: HashMap<_, _> = [
+            ("ARROW:extension:name".to_string(), "arrow.json".to_string()),
+            ("ARROW:extension:metadata".to_string(), "{}".to_string()),
+        ]
+        .into();
+        let field = Arc::new(Field::new("a", DataType::Utf8, true).with_metadata(json));
+        let array = StringArray::from(vec!["{}", "[1]"]);
+
+        let (same_field, same) =
+            cast_with_field(&field, &array, &DataType::Utf8, &options).unwrap();
+        assert_eq!(same_field, field);
+        assert_eq!(same.as_string::<i32>(), &array);
+
+        // The storage type of an extension type may only change to a supported type
+        let result = cast_with_field(&field, &array, &DataType::LargeUtf8, &options);
+        #[cfg(feature = "canonical_extension_types")]
+        {
+            let (cast_field, cast) = result.unwrap();
+            assert_eq!(cast_field.data_type(), &DataType::LargeUtf8);
+            assert_eq!(cast_field.extension_type_name(), Some("arrow.json"));
+            assert_eq!(cast.as_string::<i64>().value(1), "[1]");
+
+            let err = cast_with_field(&field, &array, &DataType::Binary, &options).unwrap_err();
+            assert!(
+                err.to_string().starts_with(
+                    "Cast error: Cannot cast field 'a' with extension type arrow.json to Binary: "
+                ),
+                "{err}"
+            );
+        }
+        #[cfg(not(feature = "canonical_extension_types"))]
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Cast error: Cannot cast field 'a' with extension type arrow.json to LargeUtf8: the extension type may not support this storage type"
+        );
+
+        let array = Int32Array::from(vec![1, 2]);
+        let err = cast_with_field(&field, &array, &DataType::Int64, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Field 'a' has data type Utf8 but array has data type Int32"
+        );
+
+        // Values that fail a safe cast become null, so the field must be nullable
+        let field = Arc::new(Field::new("b", DataType::Utf8, false));
+        let array = StringArray::from(vec!["1", "x"]);
+        let (cast_field, cast) =
+            cast_with_field(&field, &array, &DataType::Int32, &options).unwrap();
+        assert!(cast_field.is_nullable());
+        assert_eq!(cast.null_count(), 1);
+
+        let array = StringArray::from(vec!["1", "2"]);
+        let (cast_field, _) = cast_with_field(&field, &array, &DataType::Int32, &options).unwrap();
+        assert!(!cast_field.is_nullable());
+    }
 }
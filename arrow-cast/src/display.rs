@@ -47,6 +47,8 @@ pub enum DurationFormat {
     ISO8601,
     /// A human readable representation - `198 days 16 hours 34 mins 15.407810000 secs`
     Pretty,
+    /// The integer value in the unit of the duration - `17166815407810000`
+    Raw,
 }
 
 /// Options for formatting arrays
@@ -852,6 +854,7 @@ macro_rules! duration_display {
                 match fmt {
                     DurationFormat::ISO8601 => write!(f, "{}", $convert(v))?,
                     DurationFormat::Pretty => duration_fmt!(f, v, $scale)?,
+                    DurationFormat::Raw => write!(f, "{v}")?,
                 }
                 Ok(())
             }
@@ -880,6 +883,7 @@ macro_rules! duration_option_display {
                         Some(_) => duration_fmt!(f, v, $scale)?,
                         None => write!(f, "<invalid>")?,
                     },
+                    DurationFormat::Raw => write!(f, "{v}")?,
                 }
                 Ok(())
             }
@@ -1558,6 +1562,25 @@ mod tests {
         assert_eq!(pretty[5], "-45 days -14 hours -2 mins -34 secs");
     }
 
+    #[test]
+    fn test_array_value_to_string_duration_raw() {
+        let raw_fmt = FormatOptions::new()
+            .with_duration_format(DurationFormat::Raw)
+            .with_null("NULL");
+
+        let array = DurationNanosecondArray::from(vec![Some(1), None, Some(-1000), Some(i64::MIN)]);
+        assert_eq!(
+            format_array(&array, &raw_fmt),
+            ["1", "NULL", "-1000", "-9223372036854775808"]
+        );
+
+        let array = DurationSecondArray::from(vec![Some(3938554), Some(i64::MAX), None]);
+        assert_eq!(
+            format_array(&array, &raw_fmt),
+            ["3938554", "9223372036854775807", "NULL"]
+        );
+    }
+
     #[test]
     fn test_null() {
         let array = NullArray::new(2);
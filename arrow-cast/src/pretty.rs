@@ -278,7 +278,9 @@ mod tests {
     use arrow_array::cast::AsArray;
     use arrow_array::types::*;
     use arrow_array::*;
-    use arrow_buffer::{IntervalDayTime, IntervalMonthDayNano, ScalarBuffer};
+    use arrow_buffer::{
+        IntervalDayTime, IntervalMonthDayNano, NullBuffer, OffsetBuffer, ScalarBuffer,
+    };
     use arrow_schema::*;
     use half::f16;
 
@@ -1243,6 +1245,74 @@ mod tests {
         ");
     }
 
+    #[test]
+    fn duration_and_null_nested() {
+        // Build List<Struct<{d: Duration(Second), l: List<Int32>}>> with nulls at every level:
+        // [[{d: 3661, l: [1, NULL]}, NULL], NULL, [{d: NULL, l: NULL}], []]
+        let d = DurationSecondArray::from(vec![Some(3661), Some(0), None]);
+        let l = ListArray::new(
+            Arc::new(Field::new_list_field(DataType::Int32, true)),
+            OffsetBuffer::from_lengths([2, 0, 0]),
+            Arc::new(Int32Array::from(vec![Some(1), None])),
+            Some(NullBuffer::from(vec![true, true, false])),
+        );
+        let fields = Fields::from(vec![
+            Field::new("d", d.data_type().clone(), true),
+            Field::new("l", l.data_type().clone(), true),
+        ]);
+        let s = StructArray::new(
+            fields.clone(),
+            vec![Arc::new(d), Arc::new(l)],
+            Some(NullBuffer::from(vec![true, false, true])),
+        );
+        let array: ArrayRef = Arc::new(ListArray::new(
+            Arc::new(Field::new_list_field(DataType::Struct(fields), true)),
+            OffsetBuffer::from_lengths([2, 0, 1, 0]),
+            Arc::new(s),
+            Some(NullBuffer::from(vec![true, false, true, true])),
+        ));
+
+        let render = |format| {
+            let opts = FormatOptions::default()
+                .with_null("NULL")
+                .with_duration_format(format);
+            pretty_format_columns_with_options("nested", std::slice::from_ref(&array), &opts)
+                .unwrap()
+                .to_string()
+        };
+
+        insta::assert_snapshot!(render(DurationFormat::ISO8601), @"
+        +------------------------------------+
+        | nested                             |
+        +------------------------------------+
+        | [{d: PT3661S, l: [1, NULL]}, NULL] |
+        | NULL                               |
+        | [{d: NULL, l: NULL}]               |
+        | []                                 |
+        +------------------------------------+
+        ");
+        insta::assert_snapshot!(render(DurationFormat::Pretty), @"
+        +---------------------------------------------------------+
+        | nested                                                  |
+        +---------------------------------------------------------+
+        | [{d: 0 days 1 hours 1 mins 1 secs, l: [1, NULL]}, NULL] |
+        | NULL                                                    |
+        | [{d: NULL, l: NULL}]                                    |
+        | []                                                      |
+        +---------------------------------------------------------+
+        ");
+        insta::assert_snapshot!(render(DurationFormat::Raw), @"
+        +---------------------------------+
+        | nested                          |
+        +---------------------------------+
+        | [{d: 3661, l: [1, NULL]}, NULL] |
+        | NULL                            |
+        | [{d: NULL, l: NULL}]            |
+        | []                              |
+        +---------------------------------+
+        ");
+    }
+
     //
     // Custom Formatting
     //
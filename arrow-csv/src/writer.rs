@@ -217,6 +217,8 @@ pub struct Writer<W: Write> {
     timestamp_tz_format: Option<String>,
     /// The time format for time arrays, defaults to RFC3339
     time_format: Option<String>,
+    /// The format for duration arrays, defaults to [`DurationFormat::ISO8601`]
+    duration_format: DurationFormat,
     /// Is the beginning-of-writer
     beginning: bool,
     /// The value to represent null entries, defaults to [`DEFAULT_NULL_VALUE`]
@@ -261,7 +263,8 @@ impl<W: Write> Writer<W> {
             .with_datetime_format(self.datetime_format.as_deref())
             .with_timestamp_format(self.timestamp_format.as_deref())
             .with_timestamp_tz_format(self.timestamp_tz_format.as_deref())
-            .with_time_format(self.time_format.as_deref());
+            .with_time_format(self.time_format.as_deref())
+            .with_duration_format(self.duration_format);
 
         let converters = batch
             .columns()
@@ -370,6 +373,8 @@ pub struct WriterBuilder {
     timestamp_tz_format: Option<String>,
     /// Optional time format for time arrays
     time_format: Option<String>,
+    /// The format for duration arrays. Defaults to [`DurationFormat::ISO8601`]
+    duration_format: DurationFormat,
     /// Optional value to represent null
     null_value: Option<String>,
     /// Whether to ignore leading whitespace in string values. Defaults to `false`
@@ -393,6 +398,7 @@ impl Default for WriterBuilder {
             timestamp_format: None,
             timestamp_tz_format: None,
             time_format: None,
+            duration_format: DurationFormat::ISO8601,
             null_value: None,
             ignore_leading_whitespace: false,
             ignore_trailing_whitespace: false,
@@ -527,6 +533,17 @@ impl WriterBuilder {
         self.time_format.as_deref()
     }
 
+    /// Set the CSV file's duration format, defaults to [`DurationFormat::ISO8601`]
+    pub fn with_duration_format(mut self, format: DurationFormat) -> Self {
+        self.duration_format = format;
+        self
+    }
+
+    /// Get the CSV file's duration format
+    pub fn duration_format(&self) -> DurationFormat {
+        self.duration_format
+    }
+
     /// Set the CSV file's timestamp format
     pub fn with_timestamp_format(mut self, format: String) -> Self {
         self.timestamp_format = Some(format);
@@ -626,6 +643,7 @@ impl WriterBuilder {
             date_format: self.date_format,
             datetime_format: self.datetime_format,
             time_format: self.time_format,
+            duration_format: self.duration_format,
             timestamp_format: self.timestamp_format,
             timestamp_tz_format: self.timestamp_tz_format,
             null_value: self.null_value,
@@ -1033,6 +1051,36 @@ sed do eiusmod tempor,-556132.25,1,,2019-04-18T02:45:55.555,23:46:03,foo
         );
     }
 
+    #[test]
+    fn test_write_csv_duration_format() {
+        let schema = Schema::new(vec![
+            Field::new("c1", DataType::Duration(TimeUnit::Second), true),
+            Field::new("c2", DataType::Duration(TimeUnit::Millisecond), true),
+        ]);
+        let c1 = DurationSecondArray::from(vec![Some(3661), None]);
+        let c2 = DurationMillisecondArray::from(vec![Some(-1500), Some(0)]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(c1), Arc::new(c2)]).unwrap();
+
+        let write = |builder: WriterBuilder| {
+            let mut buffer = vec![];
+            builder.build(&mut buffer).write(&batch).unwrap();
+            String::from_utf8(buffer).unwrap()
+        };
+
+        let builder = WriterBuilder::new().with_null("NULL".to_string());
+        assert_eq!(builder.duration_format(), DurationFormat::ISO8601);
+        assert_eq!(write(builder.clone()), "c1,c2\nPT3661S,-PT1.5S\nNULL,P0D\n");
+        assert_eq!(
+            write(builder.clone().with_duration_format(DurationFormat::Pretty)),
+            "c1,c2\n0 days 1 hours 1 mins 1 secs,0 days 0 hours 0 mins -1.500 secs\nNULL,0 days 0 hours 0 mins 0.000 secs\n"
+        );
+        assert_eq!(
+            write(builder.with_duration_format(DurationFormat::Raw)),
+            "c1,c2\n3661,-1500\nNULL,0\n"
+        );
+    }
+
     #[test]
     fn test_write_csv_binary() {
         let fixed_size = 8;
@@ -479,6 +479,19 @@ impl ArrayData {
         size
     }
 
+    /// Shrinks the capacity of the buffers owned by this [`ArrayData`] and all of its
+    /// children as much as possible, freeing unused memory.
+    ///
+    /// Buffers shared with other [`ArrayData`]s are left untouched, see
+    /// [`Buffer::shrink_to_fit`] for more details.
+    pub fn shrink_to_fit(&mut self) {
+        self.buffers.iter_mut().for_each(|b| b.shrink_to_fit());
+        if let Some(nulls) = &mut self.nulls {
+            nulls.shrink_to_fit();
+        }
+        self.child_data.iter_mut().for_each(|c| c.shrink_to_fit());
+    }
+
     /// Returns the total number of the bytes of memory occupied by
     /// the buffers by this slice of [`ArrayData`] (See also diagram on [`ArrayData`]).
     ///
@@ -2584,4 +2597,20 @@ mod tests {
             assert!(array.is_null(i));
         }
     }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut values = MutableBuffer::with_capacity(1024);
+        values.extend_from_slice(&[1i32, 2, 3]);
+        let mut data = ArrayData::builder(DataType::Int32)
+            .len(3)
+            .add_buffer(values.into())
+            .build()
+            .unwrap();
+        assert!(data.get_buffer_memory_size() >= 1024);
+
+        data.shrink_to_fit();
+        assert_eq!(data.get_buffer_memory_size(), 12);
+        assert_eq!(data.buffers()[0].typed_data::<i32>(), &[1, 2, 3]);
+    }
 }
@@ -216,6 +216,51 @@ impl FlightClient {
         .with_trailers(trailers))
     }
 
+    /// Make a `DoGet` call to the server with the provided ticket, as
+    /// [`Self::do_get`], that transparently resumes the response if it fails
+    ///
+    /// If the response fails with a transient error, the request is re-issued,
+    /// up to `max_reconnects` times, with the last checkpoint received from the
+    /// server. Any [`RecordBatch`](arrow_array::RecordBatch)es received after that
+    /// checkpoint are discarded from the resumed response, so that each is
+    /// returned exactly once.
+    ///
+    /// This requires the server to support the convention described in the
+    /// [`resume`](crate::resume) module. Otherwise the response is resumed
+    /// from the beginning.
+    ///
+    /// # Example:
+    /// ```no_run
+    /// # async fn run() {
+    /// # use bytes::Bytes;
+    /// # use arrow_flight::FlightClient;
+    /// # use arrow_flight::Ticket;
+    /// # use arrow_array::RecordBatch;
+    /// # use futures::stream::TryStreamExt;
+    /// # let channel: tonic::transport::Channel = unimplemented!();
+    /// # let ticket = Ticket { ticket: Bytes::from("foo") };
+    /// let client = FlightClient::new(channel);
+    ///
+    /// // Retry the request up to 3 times
+    /// let batches: Vec<RecordBatch> = client
+    ///    .do_get_resumable(ticket, 3)
+    ///    .try_collect()
+    ///    .await
+    ///    .expect("no stream errors");
+    /// # }
+    /// ```
+    pub fn do_get_resumable(
+        &self,
+        ticket: Ticket,
+        max_reconnects: usize,
+    ) -> BoxStream<'static, Result<arrow_array::RecordBatch>> {
+        let client = Self {
+            metadata: self.metadata.clone(),
+            inner: self.inner.clone(),
+        };
+        crate::resume::do_get_resumable(client, ticket, max_reconnects)
+    }
+
     /// Make a `GetFlightInfo` call to the server with the provided
     /// [`FlightDescriptor`] and return the [`FlightInfo`] from the
     /// server. The [`FlightInfo`] can be used with [`Self::do_get`]
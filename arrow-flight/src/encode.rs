@@ -17,9 +17,11 @@
 
 use std::{collections::VecDeque, fmt::Debug, pin::Pin, sync::Arc, task::Poll};
 
+use crate::resume::encode_checkpoint;
 use crate::{FlightData, FlightDescriptor, SchemaAsIpc, error::Result};
 
 use arrow_array::{Array, ArrayRef, RecordBatch, RecordBatchOptions, UnionArray};
+use arrow_ipc::split::{DictionarySizePolicy, split_batch};
 use arrow_ipc::writer::{CompressionContext, DictionaryTracker, IpcDataGenerator, IpcWriteOptions};
 
 use arrow_schema::{DataType, Field, FieldRef, Fields, Schema, SchemaRef, UnionMode};
@@ -157,6 +159,11 @@ pub struct FlightDataEncoderBuilder {
     /// Deterimines how `DictionaryArray`s are encoded for transport.
     /// See [`DictionaryHandling`] for more information.
     dictionary_handling: DictionaryHandling,
+    /// Optional number of batches between resume checkpoints
+    /// (see details on [`Self::with_checkpoint_interval`]).
+    checkpoint_interval: Option<u64>,
+    /// The ordinal of the first batch, used to number resume checkpoints
+    checkpoint_offset: u64,
 }
 
 /// Default target size for encoded [`FlightData`].
@@ -174,6 +181,8 @@ impl Default for FlightDataEncoderBuilder {
             schema: None,
             descriptor: None,
             dictionary_handling: DictionaryHandling::Hydrate,
+            checkpoint_interval: None,
+            checkpoint_offset: 0,
         }
     }
 }
@@ -234,6 +243,31 @@ impl FlightDataEncoderBuilder {
         self
     }
 
+    /// Embed a resume checkpoint every `interval` [`RecordBatch`]es (defaults to disabled)
+    ///
+    /// A checkpoint records the number of input [`RecordBatch`]es sent so far, and is
+    /// encoded in the [`FlightData::app_metadata`] of the last [`FlightData`] of the
+    /// checkpointed batch. See the [`resume`](crate::resume) module for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero
+    pub fn with_checkpoint_interval(mut self, interval: u64) -> Self {
+        assert_ne!(interval, 0, "checkpoint interval must be greater than zero");
+        self.checkpoint_interval = Some(interval);
+        self
+    }
+
+    /// Set the ordinal of the first [`RecordBatch`] used to number resume checkpoints
+    /// (defaults to `0`)
+    ///
+    /// This should be set to the checkpoint a response is resumed from, so that
+    /// subsequent checkpoints count all batches sent across connections.
+    pub fn with_checkpoint_offset(mut self, offset: u64) -> Self {
+        self.checkpoint_offset = offset;
+        self
+    }
+
     /// Takes a [`Stream`] of [`Result<RecordBatch>`] and returns a [`Stream`]
     /// of [`FlightData`], consuming self.
     ///
@@ -249,9 +283,11 @@ impl FlightDataEncoderBuilder {
             schema,
             descriptor,
             dictionary_handling,
+            checkpoint_interval,
+            checkpoint_offset,
         } = self;
 
-        FlightDataEncoder::new(
+        let mut encoder = FlightDataEncoder::new(
             input.boxed(),
             schema,
             max_flight_data_size,
@@ -259,7 +295,10 @@ impl FlightDataEncoderBuilder {
             app_metadata,
             descriptor,
             dictionary_handling,
-        )
+        );
+        encoder.checkpoint_interval = checkpoint_interval;
+        encoder.batch_ordinal = checkpoint_offset;
+        encoder
     }
 }
 
@@ -287,6 +326,10 @@ pub struct FlightDataEncoder {
     /// Deterimines how `DictionaryArray`s are encoded for transport.
     /// See [`DictionaryHandling`] for more information.
     dictionary_handling: DictionaryHandling,
+    /// Optional number of batches between resume checkpoints
+    checkpoint_interval: Option<u64>,
+    /// The ordinal of the next input batch
+    batch_ordinal: u64,
 }
 
 impl FlightDataEncoder {
@@ -312,6 +355,8 @@ impl FlightDataEncoder {
             done: false,
             descriptor,
             dictionary_handling,
+            checkpoint_interval: None,
+            batch_ordinal: 0,
         };
 
         // If schema is known up front, enqueue it immediately
@@ -379,11 +424,21 @@ impl FlightDataEncoder {
             DictionaryHandling::Hydrate => hydrate_dictionaries(&batch, schema)?,
         };
 
+        let mut last_batch = None;
         for batch in split_batch_for_grpc_response(batch, self.max_flight_data_size) {
             let (flight_dictionaries, flight_batch) = self.encoder.encode_batch(&batch)?;
 
             self.queue_messages(flight_dictionaries);
             self.queue_message(flight_batch);
+            last_batch = Some(self.queue.len() - 1);
+        }
+
+        self.batch_ordinal += 1;
+        if let Some(interval) = self.checkpoint_interval {
+            // Empty batches produce no messages, in which case the checkpoint is skipped
+            if let Some(idx) = last_batch.filter(|_| self.batch_ordinal % interval == 0) {
+                self.queue[idx].app_metadata = encode_checkpoint(self.batch_ordinal);
+            }
         }
 
         Ok(())
@@ -664,34 +719,15 @@ fn prepare_schema_for_flight(
 
 /// Split [`RecordBatch`] so it hopefully fits into a gRPC response.
 ///
-/// Data is zero-copy sliced into batches.
+/// Data is zero-copy sliced into batches, see [`split_batch`].
 ///
-/// Note: this method does not take into account already sliced
-/// arrays: <https://github.com/apache/arrow-rs/issues/3407>
+/// Dictionaries are sent in their own messages, and so are not counted
+/// towards the size of each batch.
 fn split_batch_for_grpc_response(
     batch: RecordBatch,
     max_flight_data_size: usize,
 ) -> Vec<RecordBatch> {
-    let size = batch
-        .columns()
-        .iter()
-        .map(|col| col.get_buffer_memory_size())
-        .sum::<usize>();
-
-    let n_batches =
-        (size / max_flight_data_size + usize::from(size % max_flight_data_size != 0)).max(1);
-    let rows_per_batch = (batch.num_rows() / n_batches).max(1);
-    let mut out = Vec::with_capacity(n_batches + 1);
-
-    let mut offset = 0;
-    while offset < batch.num_rows() {
-        let length = (rows_per_batch).min(batch.num_rows() - offset);
-        out.push(batch.slice(offset, length));
-
-        offset += length;
-    }
-
-    out
+    split_batch(&batch, max_flight_data_size, DictionarySizePolicy::Exclude)
 }
 
 /// The data needed to encode a stream of flight data, holding on to
@@ -1849,7 +1885,7 @@ mod tests {
         let batch = RecordBatch::try_from_iter(vec![("a", Arc::new(c) as ArrayRef)])
             .expect("cannot create record batch");
         let split = split_batch_for_grpc_response(batch.clone(), max_flight_data_size);
-        assert_eq!(split.len(), 3);
+        assert_eq!(split.len(), 2);
         assert_eq!(
             split.iter().map(|batch| batch.num_rows()).sum::<usize>(),
             n_rows
@@ -1861,14 +1897,20 @@ mod tests {
 
     #[test]
     fn test_split_batch_for_grpc_response_sizes() {
-        // 2000 8 byte entries into 2k pieces: 8 chunks of 250 rows
-        verify_split(2000, 2 * 1024, vec![250, 250, 250, 250, 250, 250, 250, 250]);
+        // Each piece has an allowance of 64 bytes for metadata and padding
+
+        // 2000 8 byte entries into 2k pieces: 8 chunks of 248 rows and 1 chunk of 16 rows
+        verify_split(
+            2000,
+            2 * 1024,
+            vec![248, 248, 248, 248, 248, 248, 248, 248, 16],
+        );
 
-        // 2000 8 byte entries into 4k pieces: 4 chunks of 500 rows
-        verify_split(2000, 4 * 1024, vec![500, 500, 500, 500]);
+        // 2000 8 byte entries into 4k pieces: 3 chunks of 504 rows and 1 chunk of 488 rows
+        verify_split(2000, 4 * 1024, vec![504, 504, 504, 488]);
 
         // 2023 8 byte entries into 3k pieces does not divide evenly
-        verify_split(2023, 3 * 1024, vec![337, 337, 337, 337, 337, 337, 1]);
+        verify_split(2023, 3 * 1024, vec![376, 376, 376, 376, 376, 143]);
 
         // 10 8 byte entries into 1 byte pieces means each rows gets its own
         verify_split(10, 1, vec![1, 1, 1, 1, 1, 1, 1, 1, 1, 1]);
@@ -1918,7 +1960,7 @@ mod tests {
         ])
         .unwrap();
 
-        verify_encoded_split(batch, 120).await;
+        verify_encoded_split(batch, 88).await;
     }
 
     #[tokio::test]
@@ -1927,9 +1969,7 @@ mod tests {
         let array = StringArray::from_iter_values((0..1024).map(|i| "*".repeat(i)));
         let batch = RecordBatch::try_from_iter(vec![("data", Arc::new(array) as _)]).unwrap();
 
-        // overage is much higher than ideal
-        // https://github.com/apache/arrow-rs/issues/3478
-        verify_encoded_split(batch, 4312).await;
+        verify_encoded_split(batch, 168).await;
     }
 
     #[tokio::test]
@@ -1981,7 +2021,7 @@ mod tests {
 
         let batch = RecordBatch::try_from_iter(vec![("a1", Arc::new(array) as _)]).unwrap();
 
-        verify_encoded_split(batch, 56).await;
+        verify_encoded_split(batch, 67).await;
     }
 
     #[tokio::test]
@@ -1995,7 +2035,7 @@ mod tests {
 
         // overage is much higher than ideal
         // https://github.com/apache/arrow-rs/issues/3478
-        verify_encoded_split(batch, 3336).await;
+        verify_encoded_split(batch, 1192).await;
     }
 
     #[tokio::test]
@@ -2034,7 +2074,7 @@ mod tests {
 
         // overage is much higher than ideal
         // https://github.com/apache/arrow-rs/issues/3478
-        verify_encoded_split(batch, 4136).await;
+        verify_encoded_split(batch, 1528).await;
     }
 
     /// Return size, in memory of flight data
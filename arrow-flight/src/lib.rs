@@ -101,6 +101,8 @@ pub mod encode;
 /// Common error types
 pub mod error;
 
+pub mod resume;
+
 pub use r#gen::Action;
 pub use r#gen::ActionType;
 pub use r#gen::BasicAuth;
@@ -0,0 +1,306 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers for resuming interrupted `DoGet` responses
+//!
+//! Without these, a network interruption part way through a large `DoGet`
+//! response requires restarting it from the beginning. This module provides an
+//! opt-in convention, layered on the existing protocol, to instead resume the
+//! response from the last checkpoint received by the client:
+//!
+//! 1. The server encodes its response with
+//!    [`FlightDataEncoderBuilder::with_checkpoint_interval`], which periodically
+//!    embeds a checkpoint, the number of [`RecordBatch`]es sent so far, in the
+//!    [`FlightData::app_metadata`](crate::FlightData::app_metadata) of the response.
+//!
+//! 2. If the response fails, [`FlightClient::do_get_resumable`] re-issues the
+//!    `DoGet` request with the same [`Ticket`] and the last checkpoint received in
+//!    the [`RESUME_CHECKPOINT_HEADER`] header.
+//!
+//! 3. The server responds with the [`RecordBatch`]es following the checkpoint,
+//!    see [`ResumableDoGet`] and [`resumable_do_get`].
+//!
+//! 4. The client discards the [`RecordBatch`]es it already received after the
+//!    checkpoint, and continues the stream.
+//!
+//! For this to be correct, the server must produce the same [`RecordBatch`]es, in
+//! the same order, each time it is asked for a given [`Ticket`], and must encode
+//! them using the same [`FlightDataEncoderBuilder`] configuration.
+//!
+//! # Example: Server
+//! ```no_run
+//! # use arrow_array::RecordBatch;
+//! # use arrow_flight::{FlightData, Ticket};
+//! # use arrow_flight::encode::FlightDataEncoderBuilder;
+//! # use arrow_flight::error::Result;
+//! # use arrow_flight::resume::{ResumableDoGet, resumable_do_get};
+//! # use futures::stream::{BoxStream, StreamExt, TryStreamExt};
+//! # use tonic::{Request, Response, Status};
+//! struct Server {
+//!     batches: Vec<RecordBatch>,
+//! }
+//!
+//! #[tonic::async_trait]
+//! impl ResumableDoGet for Server {
+//!     async fn do_get_from(
+//!         &self,
+//!         _ticket: Ticket,
+//!         checkpoint: u64,
+//!     ) -> Result<BoxStream<'static, Result<RecordBatch>>> {
+//!         let batches = self.batches.clone().into_iter().skip(checkpoint as usize);
+//!         Ok(futures::stream::iter(batches.map(Ok)).boxed())
+//!     }
+//! }
+//!
+//! // Within the implementation of FlightService::do_get
+//! # async fn do_get(server: &Server, request: Request<Ticket>)
+//! #   -> std::result::Result<Response<BoxStream<'static, std::result::Result<FlightData, Status>>>, Status> {
+//! let encoder = FlightDataEncoderBuilder::new().with_checkpoint_interval(10);
+//! let stream = resumable_do_get(server, request, encoder).await?;
+//! Ok(Response::new(stream.map_err(Status::from).boxed()))
+//! # }
+//! ```
+//!
+//! [`FlightDataEncoderBuilder::with_checkpoint_interval`]: crate::encode::FlightDataEncoderBuilder::with_checkpoint_interval
+//! [`FlightClient::do_get_resumable`]: crate::FlightClient::do_get_resumable
+
+use crate::FlightClient;
+use crate::Ticket;
+use crate::decode::{DecodedPayload, FlightDataDecoder};
+use crate::encode::{FlightDataEncoder, FlightDataEncoderBuilder};
+use crate::error::{FlightError, Result};
+use arrow_array::RecordBatch;
+use bytes::Bytes;
+use futures::StreamExt;
+use futures::stream::BoxStream;
+use tonic::metadata::MetadataMap;
+use tonic::{Code, Request, Status};
+
+/// The gRPC header containing the checkpoint a `DoGet` request should resume from
+pub const RESUME_CHECKPOINT_HEADER: &str = "x-arrow-flight-resume-checkpoint";
+
+/// Prefix identifying a checkpoint encoded in `app_metadata`
+const CHECKPOINT_PREFIX: &[u8] = b"ARROW:flight:resume:checkpoint:";
+
+/// Encodes `checkpoint` for inclusion in [`FlightData::app_metadata`](crate::FlightData::app_metadata)
+pub fn encode_checkpoint(checkpoint: u64) -> Bytes {
+    let mut buf = Vec::with_capacity(CHECKPOINT_PREFIX.len() + 8);
+    buf.extend_from_slice(CHECKPOINT_PREFIX);
+    buf.extend_from_slice(&checkpoint.to_le_bytes());
+    buf.into()
+}
+
+/// Decodes a checkpoint previously encoded with [`encode_checkpoint`], returning
+/// `None` if `app_metadata` does not contain a checkpoint
+pub fn decode_checkpoint(app_metadata: &[u8]) -> Option<u64> {
+    let checkpoint = app_metadata.strip_prefix(CHECKPOINT_PREFIX)?;
+    Some(u64::from_le_bytes(checkpoint.try_into().ok()?))
+}
+
+/// Returns the checkpoint in the [`RESUME_CHECKPOINT_HEADER`] of `metadata`, if any
+pub fn checkpoint_from_metadata(metadata: &MetadataMap) -> Result<Option<u64>> {
+    let Some(value) = metadata.get(RESUME_CHECKPOINT_HEADER) else {
+        return Ok(None);
+    };
+    value
+        .to_str()
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .map(Some)
+        .ok_or_else(|| {
+            Status::invalid_argument(format!(
+                "Invalid {RESUME_CHECKPOINT_HEADER} header: {value:?}"
+            ))
+            .into()
+        })
+}
+
+/// A source of `DoGet` responses that can be resumed from a checkpoint
+///
+/// See the [module level documentation](self) for details
+#[tonic::async_trait]
+pub trait ResumableDoGet: Send + Sync {
+    /// Returns the [`RecordBatch`]es of the response to `ticket`, skipping the
+    /// first `checkpoint` batches
+    async fn do_get_from(
+        &self,
+        ticket: Ticket,
+        checkpoint: u64,
+    ) -> Result<BoxStream<'static, Result<RecordBatch>>>;
+}
+
+/// Returns a [`FlightDataEncoder`] for the response to a `DoGet` `request`, resuming
+/// from the checkpoint in its [`RESUME_CHECKPOINT_HEADER`], if any
+///
+/// `encoder` should be configured with
+/// [`FlightDataEncoderBuilder::with_checkpoint_interval`], otherwise clients can only
+/// resume the response from the beginning
+pub async fn resumable_do_get<S: ResumableDoGet + ?Sized>(
+    source: &S,
+    request: Request<Ticket>,
+    encoder: FlightDataEncoderBuilder,
+) -> Result<FlightDataEncoder> {
+    let checkpoint = checkpoint_from_metadata(request.metadata())?.unwrap_or_default();
+    let batches = source.do_get_from(request.into_inner(), checkpoint).await?;
+    Ok(encoder.with_checkpoint_offset(checkpoint).build(batches))
+}
+
+/// Returns true if `error` may be caused by a transient failure, and the request retried
+fn is_retryable(error: &FlightError) -> bool {
+    match error {
+        FlightError::Tonic(status) => matches!(
+            status.code(),
+            Code::Unavailable | Code::Unknown | Code::Cancelled | Code::Aborted
+        ),
+        _ => false,
+    }
+}
+
+/// The state of a resumable `DoGet` response
+struct ResumableStream {
+    client: FlightClient,
+    ticket: Ticket,
+    /// The current response, if connected
+    stream: Option<FlightDataDecoder>,
+    /// The last checkpoint received
+    checkpoint: Option<u64>,
+    /// The number of batches returned since the last checkpoint
+    since_checkpoint: usize,
+    /// The number of batches of the current response already returned
+    skip: usize,
+    /// The number of reconnects remaining
+    reconnects: usize,
+    /// Whether the stream has terminated
+    done: bool,
+}
+
+impl ResumableStream {
+    async fn connect(&mut self) -> Result<FlightDataDecoder> {
+        if let Some(checkpoint) = self.checkpoint {
+            self.client
+                .add_header(RESUME_CHECKPOINT_HEADER, &checkpoint.to_string())?;
+        }
+        self.skip = self.since_checkpoint;
+        let response = self.client.do_get(self.ticket.clone()).await?;
+        Ok(response.into_inner())
+    }
+
+    async fn next(&mut self) -> Option<Result<RecordBatch>> {
+        loop {
+            let result = match self.stream.as_mut() {
+                Some(stream) => stream.next().await,
+                None => match self.connect().await {
+                    Ok(stream) => {
+                        self.stream = Some(stream);
+                        continue;
+                    }
+                    Err(e) => Some(Err(e)),
+                },
+            };
+
+            match result {
+                None => return None,
+                Some(Ok(data)) => {
+                    let batch = match data.payload {
+                        DecodedPayload::RecordBatch(_) if self.skip > 0 => {
+                            self.skip -= 1;
+                            None
+                        }
+                        DecodedPayload::RecordBatch(batch) => {
+                            self.since_checkpoint += 1;
+                            Some(batch)
+                        }
+                        DecodedPayload::Schema(_) | DecodedPayload::None => None,
+                    };
+                    if let Some(checkpoint) = decode_checkpoint(&data.inner.app_metadata) {
+                        self.checkpoint = Some(checkpoint);
+                        self.since_checkpoint = 0;
+                    }
+                    if let Some(batch) = batch {
+                        return Some(Ok(batch));
+                    }
+                }
+                Some(Err(e)) if self.reconnects > 0 && is_retryable(&e) => {
+                    self.reconnects -= 1;
+                    self.stream = None;
+                }
+                Some(Err(e)) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+/// See [`FlightClient::do_get_resumable`]
+pub(crate) fn do_get_resumable(
+    client: FlightClient,
+    ticket: Ticket,
+    max_reconnects: usize,
+) -> BoxStream<'static, Result<RecordBatch>> {
+    let state = ResumableStream {
+        client,
+        ticket,
+        stream: None,
+        checkpoint: None,
+        since_checkpoint: 0,
+        skip: 0,
+        reconnects: max_reconnects,
+        done: false,
+    };
+    futures::stream::unfold(state, |mut state| async move {
+        if state.done {
+            return None;
+        }
+        let next = state.next().await;
+        state.done = !matches!(next, Some(Ok(_)));
+        next.map(|next| (next, state))
+    })
+    .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checkpoint_roundtrip() {
+        for checkpoint in [0, 1, 1024, u64::MAX] {
+            assert_eq!(
+                decode_checkpoint(&encode_checkpoint(checkpoint)),
+                Some(checkpoint)
+            );
+        }
+        assert_eq!(decode_checkpoint(b""), None);
+        assert_eq!(decode_checkpoint(b"My Metadata"), None);
+        assert_eq!(decode_checkpoint(&encode_checkpoint(1)[..10]), None);
+    }
+
+    #[test]
+    fn test_checkpoint_from_metadata() {
+        let mut metadata = MetadataMap::new();
+        assert_eq!(checkpoint_from_metadata(&metadata).unwrap(), None);
+
+        metadata.insert(RESUME_CHECKPOINT_HEADER, "42".parse().unwrap());
+        assert_eq!(checkpoint_from_metadata(&metadata).unwrap(), Some(42));
+
+        metadata.insert(RESUME_CHECKPOINT_HEADER, "foo".parse().unwrap());
+        let err = checkpoint_from_metadata(&metadata).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Invalid x-arrow-flight-resume-checkpoint header")
+        );
+    }
+}
@@ -36,7 +36,8 @@ use super::{
 use crate::{
     Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
     HandshakeRequest, HandshakeResponse, PutResult, SchemaResult, Ticket,
-    flight_service_server::FlightService, r#gen::PollInfo,
+    flight_service_server::{FlightService, FlightServiceServer},
+    r#gen::PollInfo,
 };
 use futures::{Stream, StreamExt, stream::Peekable};
 use prost::Message;
@@ -52,6 +53,42 @@ pub(crate) static END_SAVEPOINT: &str = "EndSavepoint";
 pub(crate) static CANCEL_QUERY: &str = "CancelQuery";
 
 /// Implements FlightSqlService to handle the flight sql protocol
+///
+/// All methods other than the associated [`FlightSqlService::FlightService`] type
+/// have default implementations, which return [`Status::unimplemented`] or do nothing.
+/// Implementors therefore only need to override the methods they support, making it
+/// straightforward to write minimal servers for testing and prototyping.
+///
+/// Any [`FlightSqlService`] can be served with
+/// [`FlightServiceServer::from_sql_service`](crate::flight_service_server::FlightServiceServer::from_sql_service).
+///
+/// # Example
+///
+/// ```
+/// # use arrow_flight::FlightInfo;
+/// # use arrow_flight::flight_service_server::FlightServiceServer;
+/// # use arrow_flight::sql::CommandStatementQuery;
+/// # use arrow_flight::sql::server::FlightSqlService;
+/// # use arrow_flight::FlightDescriptor;
+/// # use tonic::{Request, Response, Status};
+/// #[derive(Default)]
+/// struct MyServer {}
+///
+/// #[tonic::async_trait]
+/// impl FlightSqlService for MyServer {
+///     type FlightService = Self;
+///
+///     async fn get_flight_info_statement(
+///         &self,
+///         query: CommandStatementQuery,
+///         _request: Request<FlightDescriptor>,
+///     ) -> Result<Response<FlightInfo>, Status> {
+///         Err(Status::invalid_argument(format!("unsupported query: {}", query.query)))
+///     }
+/// }
+///
+/// let server = FlightServiceServer::from_sql_service(MyServer::default());
+/// ```
 #[tonic::async_trait]
 pub trait FlightSqlService: Sync + Send + Sized + 'static {
     /// When impl FlightSqlService, you can always set FlightService to Self
@@ -570,7 +607,16 @@ pub trait FlightSqlService: Sync + Send + Sized + 'static {
     }
 
     /// Register a new SqlInfo result, making it available when calling GetSqlInfo.
-    async fn register_sql_info(&self, id: i32, result: &SqlInfo);
+    ///
+    /// The default implementation does nothing
+    async fn register_sql_info(&self, _id: i32, _result: &SqlInfo) {}
+}
+
+impl<T: FlightSqlService> FlightServiceServer<T> {
+    /// Creates a [`FlightServiceServer`] that handles the flight sql protocol with `service`
+    pub fn from_sql_service(service: T) -> Self {
+        Self::new(service)
+    }
 }
 
 /// Implements the lower level interface to handle FlightSQL
@@ -27,6 +27,7 @@ use arrow_flight::{
     decode::{DecodedPayload, FlightDataDecoder, FlightRecordBatchStream},
     encode::FlightDataEncoderBuilder,
     error::FlightError,
+    resume::decode_checkpoint,
 };
 use arrow_schema::{DataType, Field, Fields, Schema, SchemaRef};
 use bytes::Bytes;
@@ -232,6 +233,29 @@ async fn test_app_metadata() {
     assert!(matches!(message2.payload, DecodedPayload::RecordBatch(_)));
 }
 
+#[tokio::test]
+async fn test_checkpoints() {
+    let batch = make_primitive_batch(5);
+    let empty = RecordBatch::new_empty(batch.schema());
+    // checkpoints are the number of batches sent, 5, 6, 7, 8 (after the empty batch) and 9
+    let batches = vec![batch.clone(), batch.clone(), batch.clone(), empty, batch];
+    let input_batch_stream = futures::stream::iter(batches.into_iter().map(Ok));
+
+    let encoder = FlightDataEncoderBuilder::default()
+        .with_checkpoint_interval(2)
+        .with_checkpoint_offset(4);
+    let encode_stream = encoder.build(input_batch_stream);
+    let decode_stream = FlightRecordBatchStream::new_from_flight_data(encode_stream).into_inner();
+    let messages: Vec<_> = decode_stream.try_collect().await.expect("encode fails");
+
+    // the checkpoint following the empty batch is skipped as it has no message
+    let checkpoints: Vec<_> = messages
+        .iter()
+        .map(|m| decode_checkpoint(&m.app_metadata()))
+        .collect();
+    assert_eq!(checkpoints, vec![None, None, Some(6), None, None]);
+}
+
 #[tokio::test]
 async fn test_max_message_size() {
     let input_batch_stream = futures::stream::iter(vec![Ok(make_primitive_batch(5))]);
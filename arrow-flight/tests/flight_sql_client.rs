@@ -29,16 +29,16 @@ use arrow_flight::sql::client::FlightSqlServiceClient;
 use arrow_flight::sql::server::{FlightSqlService, PeekableFlightDataStream};
 use arrow_flight::sql::{
     ActionBeginTransactionRequest, ActionBeginTransactionResult, ActionEndTransactionRequest,
-    CommandStatementIngest, EndTransaction, FallibleRequestStream, ProstMessageExt, SqlInfo,
-    TableDefinitionOptions, TableExistsOption, TableNotExistOption,
+    CommandStatementIngest, CommandStatementQuery, EndTransaction, FallibleRequestStream,
+    ProstMessageExt, SqlInfo, TableDefinitionOptions, TableExistsOption, TableNotExistOption,
 };
-use arrow_flight::{Action, FlightData, FlightDescriptor};
+use arrow_flight::{Action, FlightData, FlightDescriptor, FlightInfo};
 use futures::{StreamExt, TryStreamExt};
 use prost::Message;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
-use tonic::{IntoStreamingRequest, Request, Status};
+use tonic::{IntoStreamingRequest, Request, Response, Status};
 use uuid::Uuid;
 
 #[tokio::test]
@@ -206,6 +206,48 @@ pub async fn test_do_put_missing_flight_descriptor() {
     );
 }
 
+#[tokio::test]
+pub async fn test_minimal_service() {
+    /// A server that only overrides the methods it supports
+    #[derive(Default)]
+    struct MinimalService {}
+
+    #[tonic::async_trait]
+    impl FlightSqlService for MinimalService {
+        type FlightService = Self;
+
+        async fn get_flight_info_statement(
+            &self,
+            query: CommandStatementQuery,
+            _request: Request<FlightDescriptor>,
+        ) -> Result<Response<FlightInfo>, Status> {
+            Ok(Response::new(
+                FlightInfo::new().with_descriptor(FlightDescriptor::new_cmd(query.query)),
+            ))
+        }
+    }
+
+    let fixture = TestFixture::new(FlightServiceServer::from_sql_service(
+        MinimalService::default(),
+    ))
+    .await;
+    let channel = fixture.channel().await;
+    let mut flight_sql_client = FlightSqlServiceClient::new(channel);
+
+    let info = flight_sql_client
+        .execute("SELECT 1".to_string(), None)
+        .await
+        .unwrap();
+    assert_eq!(info.flight_descriptor.unwrap().cmd, "SELECT 1".as_bytes());
+
+    // Methods that are not overridden are unimplemented
+    let err = flight_sql_client.get_catalogs().await.unwrap_err();
+    assert!(
+        err.to_string().contains("no default implementation"),
+        "{err}"
+    );
+}
+
 fn make_ingest_command() -> CommandStatementIngest {
     CommandStatementIngest {
         table_definition_options: Some(TableDefinitionOptions {
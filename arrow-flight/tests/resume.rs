@@ -0,0 +1,248 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tests for resuming interrupted DoGet responses
+
+mod common;
+
+use crate::common::fixture::TestFixture;
+use arrow_array::cast::AsArray;
+use arrow_array::types::UInt64Type;
+use arrow_array::{RecordBatch, UInt64Array};
+use arrow_flight::encode::FlightDataEncoderBuilder;
+use arrow_flight::error::{FlightError, Result};
+use arrow_flight::flight_service_server::{FlightService, FlightServiceServer};
+use arrow_flight::resume::{ResumableDoGet, checkpoint_from_metadata, resumable_do_get};
+use arrow_flight::{
+    Action, ActionType, Criteria, Empty, FlightClient, FlightData, FlightDescriptor, FlightInfo,
+    HandshakeRequest, HandshakeResponse, PollInfo, PutResult, SchemaResult, Ticket,
+};
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use futures::{StreamExt, TryStreamExt};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use tonic::{Request, Response, Status, Streaming};
+
+const NUM_BATCHES: u64 = 10;
+const BATCH_SIZE: u64 = 100;
+
+/// A server whose DoGet responses fail part way through, until configured otherwise
+#[derive(Debug, Clone, Default)]
+struct ResumeServer {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// The number of [`FlightData`] each successive DoGet response sends before failing
+    failures: VecDeque<usize>,
+    /// The checkpoint requested by each DoGet request
+    checkpoints: Vec<Option<u64>>,
+}
+
+impl ResumeServer {
+    fn new(failures: impl IntoIterator<Item = usize>) -> Self {
+        let server = Self::default();
+        server.state.lock().unwrap().failures = failures.into_iter().collect();
+        server
+    }
+
+    fn checkpoints(&self) -> Vec<Option<u64>> {
+        self.state.lock().unwrap().checkpoints.clone()
+    }
+}
+
+fn make_batch(ordinal: u64) -> RecordBatch {
+    let start = ordinal * BATCH_SIZE;
+    let values = UInt64Array::from_iter_values(start..start + BATCH_SIZE);
+    RecordBatch::try_from_iter([("v", Arc::new(values) as _)]).unwrap()
+}
+
+#[tonic::async_trait]
+impl ResumableDoGet for ResumeServer {
+    async fn do_get_from(
+        &self,
+        _ticket: Ticket,
+        checkpoint: u64,
+    ) -> Result<BoxStream<'static, Result<RecordBatch>>> {
+        let batches = (checkpoint..NUM_BATCHES).map(|i| Ok(make_batch(i)));
+        Ok(futures::stream::iter(batches).boxed())
+    }
+}
+
+#[tonic::async_trait]
+impl FlightService for ResumeServer {
+    type HandshakeStream = BoxStream<'static, std::result::Result<HandshakeResponse, Status>>;
+    type ListFlightsStream = BoxStream<'static, std::result::Result<FlightInfo, Status>>;
+    type DoGetStream = BoxStream<'static, std::result::Result<FlightData, Status>>;
+    type DoPutStream = BoxStream<'static, std::result::Result<PutResult, Status>>;
+    type DoActionStream = BoxStream<'static, std::result::Result<arrow_flight::Result, Status>>;
+    type ListActionsStream = BoxStream<'static, std::result::Result<ActionType, Status>>;
+    type DoExchangeStream = BoxStream<'static, std::result::Result<FlightData, Status>>;
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> std::result::Result<Response<Self::DoGetStream>, Status> {
+        let failure = {
+            let mut state = self.state.lock().unwrap();
+            let checkpoint = checkpoint_from_metadata(request.metadata())?;
+            state.checkpoints.push(checkpoint);
+            state.failures.pop_front()
+        };
+
+        // Use a small message size so that each batch is split into several messages
+        let encoder = FlightDataEncoderBuilder::new()
+            .with_max_flight_data_size(256)
+            .with_checkpoint_interval(3);
+        let stream = resumable_do_get(self, request, encoder).await?;
+
+        let stream = match failure {
+            Some(n) => stream
+                .take(n)
+                .chain(futures::stream::once(async {
+                    Err(Status::unavailable("connection reset").into())
+                }))
+                .boxed(),
+            None => stream.boxed(),
+        };
+        Ok(Response::new(stream.map_err(Status::from).boxed()))
+    }
+
+    async fn handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> std::result::Result<Response<Self::HandshakeStream>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    async fn list_flights(
+        &self,
+        _request: Request<Criteria>,
+    ) -> std::result::Result<Response<Self::ListFlightsStream>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    async fn get_flight_info(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> std::result::Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    async fn poll_flight_info(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> std::result::Result<Response<PollInfo>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    async fn get_schema(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> std::result::Result<Response<SchemaResult>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    async fn do_put(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> std::result::Result<Response<Self::DoPutStream>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    async fn do_action(
+        &self,
+        _request: Request<Action>,
+    ) -> std::result::Result<Response<Self::DoActionStream>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> std::result::Result<Response<Self::ListActionsStream>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> std::result::Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+}
+
+/// Returns the values of `batches`, which must each have a single UInt64 column
+fn values(batches: &[RecordBatch]) -> Vec<u64> {
+    batches
+        .iter()
+        .flat_map(|b| b.column(0).as_primitive::<UInt64Type>().values().to_vec())
+        .collect()
+}
+
+/// Runs `do_get_resumable` against a server configured with `failures`, returning the
+/// result and the checkpoints requested from the server
+async fn do_get_resumable(
+    failures: impl IntoIterator<Item = usize>,
+    max_reconnects: usize,
+) -> (Result<Vec<RecordBatch>>, Vec<Option<u64>>) {
+    let server = ResumeServer::new(failures);
+    let fixture = TestFixture::new(FlightServiceServer::new(server.clone())).await;
+    let client = FlightClient::new(fixture.channel().await);
+
+    let ticket = Ticket::new(Bytes::from("ticket"));
+    let result = client
+        .do_get_resumable(ticket, max_reconnects)
+        .try_collect()
+        .await;
+
+    fixture.shutdown_and_wait().await;
+    (result, server.checkpoints())
+}
+
+#[tokio::test]
+async fn test_resume_uninterrupted() {
+    let (batches, checkpoints) = do_get_resumable([], 3).await;
+    let expected: Vec<_> = (0..NUM_BATCHES * BATCH_SIZE).collect();
+    assert_eq!(values(&batches.unwrap()), expected);
+    assert_eq!(checkpoints, vec![None]);
+}
+
+#[tokio::test]
+async fn test_resume_after_failures() {
+    // Each batch is encoded as 5 messages, following the schema message, so fail
+    // before the first checkpoint, then part way through the batch following it,
+    // and then immediately after resuming
+    let (batches, checkpoints) = do_get_resumable([8, 19, 1], 3).await;
+    let expected: Vec<_> = (0..NUM_BATCHES * BATCH_SIZE).collect();
+    assert_eq!(values(&batches.unwrap()), expected);
+
+    assert_eq!(checkpoints, vec![None, None, Some(3), Some(3)]);
+}
+
+#[tokio::test]
+async fn test_resume_too_many_failures() {
+    let (result, checkpoints) = do_get_resumable([5, 5, 5], 2).await;
+    let err = result.unwrap_err();
+    let FlightError::Tonic(status) = err else {
+        panic!("unexpected error: {err}")
+    };
+    assert_eq!(status.code(), tonic::Code::Unavailable);
+    assert_eq!(checkpoints.len(), 3);
+}
@@ -46,6 +46,7 @@
 #![warn(missing_docs)]
 pub mod convert;
 pub mod reader;
+pub mod split;
 pub mod writer;
 
 mod compression;
@@ -1354,11 +1354,34 @@ impl<R: Read + Seek> FileReader<R> {
         &self.custom_metadata
     }
 
+    /// Return user defined customized metadata
+    ///
+    /// This is an alias for [`FileReader::custom_metadata`]
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        self.custom_metadata()
+    }
+
     /// Return the number of batches in the file
+    ///
+    /// This is read from the file footer, and does not require reading any batches
     pub fn num_batches(&self) -> usize {
         self.total_blocks
     }
 
+    /// Return the size in bytes of the batch at `index`, as stored in the file
+    ///
+    /// This is the length of the message metadata and (possibly compressed) body
+    /// recorded in the file footer, and can be used to partition the batches of a
+    /// file without reading them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than [`FileReader::num_batches`]
+    pub fn block_size(&self, index: usize) -> usize {
+        let block = &self.blocks[index];
+        block.metaDataLength() as usize + block.bodyLength() as usize
+    }
+
     /// Return the schema of the file
     pub fn schema(&self) -> SchemaRef {
         self.decoder.schema.clone()
@@ -2317,6 +2340,39 @@ mod tests {
         assert_eq!(reader.custom_metadata(), &test_metadata);
     }
 
+    #[test]
+    fn test_file_reader_blocks() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let mut buf = Vec::new();
+        let mut writer = crate::writer::FileWriter::try_new(&mut buf, &schema).unwrap();
+        writer.write_metadata("key", "value");
+        for len in [1, 100, 1000] {
+            let array = Int32Array::from_iter_values(0..len);
+            let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap();
+            writer.write(&batch).unwrap();
+        }
+        writer.finish().unwrap();
+        drop(writer);
+
+        let reader = FileReader::try_new(std::io::Cursor::new(buf), None).unwrap();
+        assert_eq!(reader.num_batches(), 3);
+        assert_eq!(reader.schema(), schema);
+        assert_eq!(
+            reader.metadata().get("key").map(String::as_str),
+            Some("value")
+        );
+
+        let sizes: Vec<_> = (0..3).map(|i| reader.block_size(i)).collect();
+        assert!(sizes[0] < sizes[1] && sizes[1] < sizes[2]);
+        for (i, size) in sizes.iter().enumerate() {
+            let block = &reader.blocks[i];
+            assert_eq!(
+                *size as i64,
+                block.metaDataLength() as i64 + block.bodyLength()
+            );
+        }
+    }
+
     #[test]
     fn test_roundtrip_nested_dict() {
         let inner: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
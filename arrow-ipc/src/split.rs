@@ -0,0 +1,483 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Split [`RecordBatch`]es into zero-copy slices with a bounded IPC encoded size
+//!
+//! This is useful when framing data for transports that limit the size of a
+//! single message, such as gRPC in Arrow Flight.
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    BinaryType, BinaryViewType, ByteArrayType, ByteViewType, LargeBinaryType, LargeUtf8Type,
+    StringViewType, Utf8Type,
+};
+use arrow_array::{Array, OffsetSizeTrait, RecordBatch};
+use arrow_buffer::ArrowNativeType;
+use arrow_data::layout;
+use arrow_schema::DataType;
+
+/// The size of a `FieldNode` in the message metadata
+const FIELD_NODE_SIZE: u64 = 16;
+/// The size of a `Buffer` in the message metadata
+const BUFFER_SIZE: u64 = 16;
+/// The expected padding of each buffer in the message body
+const BUFFER_PADDING: u64 = 8;
+
+/// How the values of dictionary encoded columns contribute to the estimated
+/// size of each slice produced by [`split_batch`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DictionarySizePolicy {
+    /// Count the full dictionary values once in every slice
+    ///
+    /// This is appropriate when each slice is sent together with its dictionaries
+    #[default]
+    PerSlice,
+    /// Only count the dictionary keys
+    ///
+    /// This is appropriate when dictionaries are sent as separate messages, and
+    /// so do not count towards the size of the message containing the slice
+    Exclude,
+}
+
+/// Splits `batch` into zero-copy slices, each with an estimated IPC encoded
+/// size of at most `max_encoded_size` bytes
+///
+/// The estimate covers the body of the record batch message: validity bitmaps,
+/// offsets, views and values, plus dictionary values according to
+/// `dictionary_policy`. It also includes an allowance for the padding of each
+/// buffer and its description in the message metadata, but not the fixed size
+/// message header, which callers should leave room for in `max_encoded_size`.
+///
+/// The size of each row is computed from the columns themselves, so rows of
+/// variable length and nested columns are accounted for individually rather
+/// than assuming a uniform size. The data referenced by the views of
+/// [`StringViewArray`] and [`BinaryViewArray`] is counted per row, assuming the
+/// data buffers contain no unreferenced data, see [`StringViewArray::gc`].
+///
+/// A row whose estimated size exceeds `max_encoded_size` on its own is returned
+/// as a single row slice. An empty `batch` yields no slices.
+///
+/// # Example
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int64Array, RecordBatch};
+/// # use arrow_ipc::split::{split_batch, DictionarySizePolicy};
+/// let array = Int64Array::from_iter_values(0..100);
+/// let batch = RecordBatch::try_from_iter([("a", Arc::new(array) as ArrayRef)]).unwrap();
+///
+/// // Each slice has 64 bytes of metadata and each row is 8 bytes,
+/// // so at most 32 rows fit in 320 bytes
+/// let slices = split_batch(&batch, 320, DictionarySizePolicy::PerSlice);
+/// let rows: Vec<_> = slices.iter().map(|b| b.num_rows()).collect();
+/// assert_eq!(rows, vec![32, 32, 32, 4]);
+/// ```
+///
+/// [`StringViewArray`]: arrow_array::StringViewArray
+/// [`BinaryViewArray`]: arrow_array::BinaryViewArray
+/// [`StringViewArray::gc`]: arrow_array::StringViewArray::gc
+pub fn split_batch(
+    batch: &RecordBatch,
+    max_encoded_size: usize,
+    dictionary_policy: DictionarySizePolicy,
+) -> Vec<RecordBatch> {
+    let num_rows = batch.num_rows();
+    if num_rows == 0 {
+        return vec![];
+    }
+
+    let mut sizes = RowSizes::new(num_rows);
+    for column in batch.columns() {
+        sizes.add_array(column.as_ref(), dictionary_policy);
+    }
+
+    let budget = (max_encoded_size as u64).saturating_mul(8);
+    let mut out = vec![];
+    let mut start = 0;
+    let mut current = sizes.fixed;
+    for (row, row_size) in sizes.rows.iter().enumerate() {
+        if row > start && current + row_size > budget {
+            out.push(batch.slice(start, row - start));
+            start = row;
+            current = sizes.fixed;
+        }
+        current += row_size;
+    }
+    out.push(batch.slice(start, num_rows - start));
+    out
+}
+
+/// The estimated encoded size, in bits, of each row of an array, along with
+/// the size of any data encoded once per slice regardless of its row count
+struct RowSizes {
+    rows: Vec<u64>,
+    fixed: u64,
+}
+
+impl RowSizes {
+    fn new(len: usize) -> Self {
+        Self {
+            rows: vec![0; len],
+            fixed: 0,
+        }
+    }
+
+    /// Returns the estimated encoded size of all rows, in bits
+    fn total(&self) -> u64 {
+        self.fixed + self.rows.iter().sum::<u64>()
+    }
+
+    fn add_per_row(&mut self, bits: u64) {
+        self.rows.iter_mut().for_each(|r| *r += bits);
+    }
+
+    /// Adds the estimated size of each row of `array`, which must have the
+    /// same length as `self`
+    fn add_array(&mut self, array: &dyn Array, dictionary_policy: DictionarySizePolicy) {
+        debug_assert_eq!(array.len(), self.rows.len());
+        // Each array is described by a field node and a list of buffers, including
+        // its validity buffer, in the message metadata, and each buffer is padded
+        let buffers = layout(array.data_type()).buffers.len() as u64 + 1;
+        self.fixed += (FIELD_NODE_SIZE + buffers * (BUFFER_SIZE + BUFFER_PADDING)) * 8;
+        if array.null_count() > 0 {
+            self.add_per_row(1);
+        }
+
+        match array.data_type() {
+            DataType::Null => {}
+            DataType::Boolean => self.add_per_row(1),
+            DataType::Utf8 => self.add_bytes::<Utf8Type>(array),
+            DataType::LargeUtf8 => self.add_bytes::<LargeUtf8Type>(array),
+            DataType::Binary => self.add_bytes::<BinaryType>(array),
+            DataType::LargeBinary => self.add_bytes::<LargeBinaryType>(array),
+            DataType::Utf8View => self.add_views::<StringViewType>(array),
+            DataType::BinaryView => self.add_views::<BinaryViewType>(array),
+            DataType::FixedSizeBinary(width) => self.add_per_row(*width as u64 * 8),
+            DataType::List(_) => self.add_list::<i32>(array, dictionary_policy),
+            DataType::LargeList(_) => self.add_list::<i64>(array, dictionary_policy),
+            DataType::Map(_, _) => {
+                let map = array.as_map();
+                self.add_ranges(map.value_offsets(), map.entries(), dictionary_policy);
+            }
+            DataType::ListView(_) => self.add_list_view::<i32>(array, dictionary_policy),
+            DataType::LargeListView(_) => self.add_list_view::<i64>(array, dictionary_policy),
+            DataType::FixedSizeList(_, size) => {
+                let list = array.as_fixed_size_list();
+                let size = *size as usize;
+                let values = list.values();
+                let mut child = RowSizes::new(values.len());
+                child.add_array(values.as_ref(), dictionary_policy);
+                self.fixed += child.fixed;
+                if size > 0 {
+                    for (row, chunk) in self.rows.iter_mut().zip(child.rows.chunks(size)) {
+                        *row += chunk.iter().sum::<u64>();
+                    }
+                }
+            }
+            DataType::Struct(_) => {
+                for column in array.as_struct().columns() {
+                    self.add_array(column.as_ref(), dictionary_policy);
+                }
+            }
+            DataType::Dictionary(key, _) => {
+                self.add_per_row(key.primitive_width().unwrap() as u64 * 8);
+                if dictionary_policy == DictionarySizePolicy::PerSlice {
+                    let values = array.as_any_dictionary().values();
+                    let mut child = RowSizes::new(values.len());
+                    child.add_array(values.as_ref(), dictionary_policy);
+                    self.fixed += child.total();
+                }
+            }
+            d => match d.primitive_width() {
+                Some(width) => self.add_per_row(width as u64 * 8),
+                // Types such as unions and run end encoded arrays do not map rows to
+                // contiguous ranges of their buffers, spread their size evenly instead
+                None => {
+                    let bits = array.get_buffer_memory_size() as u64 * 8;
+                    self.add_per_row(bits.div_ceil(array.len().max(1) as u64));
+                }
+            },
+        }
+    }
+
+    fn add_bytes<T: ByteArrayType>(&mut self, array: &dyn Array) {
+        let offsets = array.as_bytes::<T>().value_offsets();
+        let offset_bits = std::mem::size_of::<T::Offset>() as u64 * 8;
+        self.fixed += offset_bits;
+        for (row, w) in self.rows.iter_mut().zip(offsets.windows(2)) {
+            *row += offset_bits + (w[1] - w[0]).as_usize() as u64 * 8;
+        }
+    }
+
+    fn add_views<T: ByteViewType>(&mut self, array: &dyn Array) {
+        let array = array.as_byte_view::<T>();
+        for (row, view) in self.rows.iter_mut().zip(array.views()) {
+            let len = *view as u32;
+            *row += 128;
+            if len > 12 {
+                *row += len as u64 * 8;
+            }
+        }
+    }
+
+    fn add_list<O: OffsetSizeTrait>(
+        &mut self,
+        array: &dyn Array,
+        dictionary_policy: DictionarySizePolicy,
+    ) {
+        let list = array.as_list::<O>();
+        self.add_ranges(list.value_offsets(), list.values(), dictionary_policy);
+    }
+
+    /// Adds the offsets and the values of a list like array, where row `i`
+    /// contains the values in `offsets[i]..offsets[i + 1]`
+    fn add_ranges<O: OffsetSizeTrait>(
+        &mut self,
+        offsets: &[O],
+        values: &dyn Array,
+        dictionary_policy: DictionarySizePolicy,
+    ) {
+        let offset_bits = std::mem::size_of::<O>() as u64 * 8;
+        self.fixed += offset_bits;
+
+        let start = offsets[0].as_usize();
+        let end = offsets[offsets.len() - 1].as_usize();
+        let values = values.slice(start, end - start);
+        let prefix = child_prefix_sums(values.as_ref(), dictionary_policy, &mut self.fixed);
+        for (row, w) in self.rows.iter_mut().zip(offsets.windows(2)) {
+            let (s, e) = (w[0].as_usize() - start, w[1].as_usize() - start);
+            *row += offset_bits + prefix[e] - prefix[s];
+        }
+    }
+
+    fn add_list_view<O: OffsetSizeTrait>(
+        &mut self,
+        array: &dyn Array,
+        dictionary_policy: DictionarySizePolicy,
+    ) {
+        // List views may reference overlapping or out of order ranges of their
+        // values, which are written in full, count the values referenced by each row
+        let list = array.as_list_view::<O>();
+        let offset_bits = std::mem::size_of::<O>() as u64 * 16;
+        let prefix = child_prefix_sums(list.values().as_ref(), dictionary_policy, &mut self.fixed);
+        let ranges = list.value_offsets().iter().zip(list.value_sizes());
+        for (row, (offset, size)) in self.rows.iter_mut().zip(ranges) {
+            let (s, e) = (offset.as_usize(), offset.as_usize() + size.as_usize());
+            *row += offset_bits + prefix[e] - prefix[s];
+        }
+    }
+}
+
+/// Returns the prefix sums of the estimated row sizes of `values`, adding any
+/// fixed size to `fixed`
+fn child_prefix_sums(
+    values: &dyn Array,
+    dictionary_policy: DictionarySizePolicy,
+    fixed: &mut u64,
+) -> Vec<u64> {
+    let mut child = RowSizes::new(values.len());
+    child.add_array(values, dictionary_policy);
+    *fixed += child.fixed;
+
+    let mut prefix = Vec::with_capacity(child.rows.len() + 1);
+    prefix.push(0);
+    let mut acc = 0;
+    for size in child.rows {
+        acc += size;
+        prefix.push(acc);
+    }
+    prefix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::{CompressionContext, DictionaryTracker, IpcDataGenerator, IpcWriteOptions};
+    use arrow_array::builder::{ListBuilder, StringBuilder};
+    use arrow_array::types::Int32Type;
+    use arrow_array::{
+        ArrayRef, DictionaryArray, Int32Array, StringArray, StringViewArray, StructArray,
+        UInt8Array,
+    };
+    use arrow_schema::Field;
+    use std::sync::Arc;
+
+    fn batch(columns: Vec<(&str, ArrayRef)>) -> RecordBatch {
+        RecordBatch::try_from_iter(columns).unwrap()
+    }
+
+    fn num_rows(slices: &[RecordBatch]) -> Vec<usize> {
+        slices.iter().map(|b| b.num_rows()).collect()
+    }
+
+    /// Returns the size of the body of the IPC message encoding `batch`
+    fn encoded_size(batch: &RecordBatch) -> usize {
+        let options = IpcWriteOptions::try_new(8, false, crate::MetadataVersion::V5).unwrap();
+        let mut tracker = DictionaryTracker::new(false);
+        let (_, encoded) = IpcDataGenerator::default()
+            .encode(
+                batch,
+                &mut tracker,
+                &options,
+                &mut CompressionContext::default(),
+            )
+            .unwrap();
+        encoded.arrow_data.len()
+    }
+
+    /// Checks that the slices reassemble to `batch` and that each slice with
+    /// more than one row is encoded in at most `max` bytes, allowing for padding
+    fn verify(batch: &RecordBatch, slices: &[RecordBatch], max: usize) {
+        let concat = arrow_select::concat::concat_batches(&batch.schema(), slices).unwrap();
+        assert_eq!(&concat, batch);
+        let buffers = 64 * batch.num_columns() * 3;
+        for slice in slices.iter().filter(|s| s.num_rows() > 1) {
+            let size = encoded_size(slice);
+            assert!(size <= max + buffers, "{size} > {max}");
+        }
+    }
+
+    #[test]
+    fn test_split_primitive() {
+        let a = Int32Array::from_iter_values(0..1000);
+        let b = batch(vec![("a", Arc::new(a))]);
+        // 64 bytes of metadata and 1000 bytes of values
+        let slices = split_batch(&b, 1064, DictionarySizePolicy::PerSlice);
+        assert_eq!(num_rows(&slices), vec![250, 250, 250, 250]);
+        verify(&b, &slices, 1064);
+
+        // Validity adds one bit per row
+        let a = Int32Array::from_iter((0..1000).map(|x| (x % 2 == 0).then_some(x)));
+        let b = batch(vec![("a", Arc::new(a))]);
+        let slices = split_batch(&b, 1064, DictionarySizePolicy::PerSlice);
+        assert_eq!(num_rows(&slices), vec![242, 242, 242, 242, 32]);
+        verify(&b, &slices, 1064);
+
+        // A budget larger than the batch returns a single slice
+        let slices = split_batch(&b, 1 << 20, DictionarySizePolicy::PerSlice);
+        assert_eq!(num_rows(&slices), vec![1000]);
+    }
+
+    #[test]
+    fn test_split_empty() {
+        let b = batch(vec![("a", Arc::new(Int32Array::from(Vec::<i32>::new())))]);
+        let slices = split_batch(&b, 10, DictionarySizePolicy::PerSlice);
+        assert!(slices.is_empty());
+    }
+
+    #[test]
+    fn test_split_strings() {
+        // Rows of increasing length are not split evenly
+        let a: StringArray = (0..100).map(|i| Some("x".repeat(i))).collect();
+        let b = batch(vec![("a", Arc::new(a))]);
+        let slices = split_batch(&b, 1000, DictionarySizePolicy::PerSlice);
+        let rows = num_rows(&slices);
+        assert!(rows[0] > rows[rows.len() - 2], "{rows:?}");
+        verify(&b, &slices, 1000);
+
+        // Sliced input only counts the referenced data
+        let sliced = b.slice(90, 10);
+        let slices = split_batch(&sliced, 2000, DictionarySizePolicy::PerSlice);
+        assert_eq!(num_rows(&slices), vec![10]);
+    }
+
+    #[test]
+    fn test_split_oversized_rows() {
+        let a = StringArray::from(vec!["a", &"b".repeat(2000), "c", &"d".repeat(3000), "e"]);
+        let b = batch(vec![("a", Arc::new(a))]);
+        let slices = split_batch(&b, 100, DictionarySizePolicy::PerSlice);
+        assert_eq!(num_rows(&slices), vec![1, 1, 1, 1, 1]);
+
+        // Budget of zero still makes progress
+        let slices = split_batch(&b, 0, DictionarySizePolicy::PerSlice);
+        assert_eq!(num_rows(&slices), vec![1, 1, 1, 1, 1]);
+        verify(&b, &slices, 0);
+    }
+
+    #[test]
+    fn test_split_views() {
+        let long = "y".repeat(100);
+        let a: StringViewArray = (0..100)
+            .map(|i| Some(if i % 2 == 0 { "short" } else { long.as_str() }))
+            .collect();
+        let b = batch(vec![("a", Arc::new(a))]);
+        // 64 bytes of metadata, and each pair of rows is 16 * 2 + 100 bytes
+        let slices = split_batch(&b, 1384, DictionarySizePolicy::PerSlice);
+        assert_eq!(num_rows(&slices), vec![20, 20, 20, 20, 20]);
+    }
+
+    #[test]
+    fn test_split_lists() {
+        let mut builder = ListBuilder::new(StringBuilder::new());
+        for i in 0..100 {
+            for _ in 0..i % 5 {
+                builder.values().append_value("abcdefgh");
+            }
+            builder.append(true);
+        }
+        let a = builder.finish();
+        let b = batch(vec![("a", Arc::new(a))]);
+        let slices = split_batch(&b, 500, DictionarySizePolicy::PerSlice);
+        assert!(slices.len() > 1);
+        verify(&b, &slices, 500);
+
+        // Slicing the list only accounts for its referenced values
+        let sliced = b.slice(50, 50);
+        let slices_sliced = split_batch(&sliced, 500, DictionarySizePolicy::PerSlice);
+        assert_eq!(
+            num_rows(&slices_sliced).iter().sum::<usize>(),
+            50,
+            "{slices_sliced:?}"
+        );
+        assert!(slices_sliced.len() < slices.len());
+        verify(&sliced, &slices_sliced, 500);
+    }
+
+    #[test]
+    fn test_split_struct() {
+        let s = StructArray::from(vec![
+            (
+                Arc::new(Field::new("a", DataType::Int32, false)),
+                Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("b", DataType::UInt8, false)),
+                Arc::new(UInt8Array::from_iter_values(0..100)) as ArrayRef,
+            ),
+        ]);
+        let b = batch(vec![("s", Arc::new(s))]);
+        // 168 bytes of metadata for the struct and its children, and 5 bytes per row
+        let slices = split_batch(&b, 268, DictionarySizePolicy::PerSlice);
+        assert_eq!(num_rows(&slices), vec![20, 20, 20, 20, 20]);
+    }
+
+    #[test]
+    fn test_split_dictionary() {
+        let values = StringArray::from(vec!["a".repeat(400), "b".repeat(400)]);
+        let keys = Int32Array::from_iter_values((0..100).map(|i| i % 2));
+        let a = DictionaryArray::<Int32Type>::try_new(keys, Arc::new(values)).unwrap();
+        let b = batch(vec![("a", Arc::new(a))]);
+
+        // Dictionary values, offsets and metadata take 964 bytes of each slice,
+        // leaving room for 47 keys
+        let slices = split_batch(&b, 1152, DictionarySizePolicy::PerSlice);
+        assert_eq!(num_rows(&slices), vec![47, 47, 6]);
+
+        let slices = split_batch(&b, 164, DictionarySizePolicy::Exclude);
+        assert_eq!(num_rows(&slices), vec![25, 25, 25, 25]);
+    }
+}
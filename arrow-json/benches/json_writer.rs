@@ -17,7 +17,7 @@
 
 use arrow_array::builder::{FixedSizeListBuilder, Int64Builder, ListBuilder};
 use arrow_array::{Array, RecordBatch};
-use arrow_json::LineDelimitedWriter;
+use arrow_json::{ArrayWriter, LineDelimitedWriter};
 use arrow_schema::{Field, Schema};
 use criterion::{Criterion, Throughput, criterion_group, criterion_main};
 use std::sync::Arc;
@@ -125,5 +125,35 @@ fn bench_write_fixed_size_list(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_write_list, bench_write_fixed_size_list);
+fn bench_to_values(c: &mut Criterion) {
+    let batch = build_list_batch(ROWS, LIST_SHORT_ELEMENTS);
+
+    let mut group = c.benchmark_group("to_values");
+    group.throughput(Throughput::Elements(ROWS as u64));
+
+    group.bench_function("to_values", |b| {
+        b.iter(|| arrow_json::writer::to_values(&batch).unwrap())
+    });
+
+    // Workaround of writing the JSON text of the batch and parsing it
+    group.bench_function("write_and_parse", |b| {
+        b.iter(|| {
+            let mut writer = ArrayWriter::new(Vec::new());
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+            let values: Vec<serde_json::Value> =
+                serde_json::from_slice(&writer.into_inner()).unwrap();
+            values
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_write_list,
+    bench_write_fixed_size_list,
+    bench_to_values
+);
 criterion_main!(benches);
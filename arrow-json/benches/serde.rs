@@ -36,6 +36,30 @@ fn do_bench<R: Serialize>(c: &mut Criterion, name: &str, rows: &[R], schema: &Sc
     });
 }
 
+fn do_bench_values(c: &mut Criterion, rows: &[serde_json::Value], schema: &Schema) {
+    let schema = Arc::new(schema.clone());
+    c.bench_function("from_values", |b| {
+        b.iter(|| arrow_json::reader::from_values(schema.clone(), rows).unwrap())
+    });
+
+    // Workaround of serializing the values to JSON text and decoding it
+    c.bench_function("stringify_and_decode", |b| {
+        b.iter(|| {
+            let mut text = Vec::new();
+            for row in rows {
+                serde_json::to_writer(&mut text, row).unwrap();
+                text.push(b'\n');
+            }
+            let mut decoder = ReaderBuilder::new(schema.clone())
+                .with_batch_size(rows.len())
+                .build_decoder()
+                .unwrap();
+            decoder.decode(&text).unwrap();
+            decoder.flush().unwrap()
+        })
+    });
+}
+
 fn criterion_benchmark(c: &mut Criterion) {
     let mut rng = rng();
     let schema = Schema::new(vec![Field::new("i32", DataType::Int32, false)]);
@@ -60,6 +84,24 @@ fn criterion_benchmark(c: &mut Criterion) {
     do_bench(c, "small_f32", &v, &schema);
     let v: Vec<f32> = (0..ROWS).map(|_| rng.random_range(0.0..f32::MAX)).collect();
     do_bench(c, "large_f32", &v, &schema);
+
+    let schema = Schema::new(vec![
+        Field::new("i64", DataType::Int64, false),
+        Field::new("str", DataType::Utf8, false),
+        Field::new_list(
+            "list",
+            Field::new_list_field(DataType::Float64, false),
+            true,
+        ),
+    ]);
+    let v: Vec<_> = (0..ROWS)
+        .map(|_| {
+            let i: i64 = rng.random();
+            let list: Vec<f64> = (0..rng.random_range(0..5)).map(|_| rng.random()).collect();
+            serde_json::json!({"i64": i, "str": i.to_string(), "list": list})
+        })
+        .collect();
+    do_bench_values(c, &v, &schema);
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -23,7 +23,8 @@
 //!
 //! The reader ignores whitespace between JSON values, including `\n` and `\r`, allowing
 //! parsing of sequences of one or more arbitrarily formatted JSON values, including
-//! but not limited to newline-delimited JSON.
+//! but not limited to newline-delimited JSON. A single top-level JSON array of values
+//! can instead be read with [`ReaderBuilder::with_array_mode`].
 //!
 //! # Basic Usage
 //!
@@ -189,6 +190,7 @@ pub struct ReaderBuilder {
     strict_mode: bool,
     is_field: bool,
     struct_mode: StructMode,
+    array_mode: bool,
 
     schema: SchemaRef,
 }
@@ -209,6 +211,7 @@ impl ReaderBuilder {
             strict_mode: false,
             is_field: false,
             struct_mode: Default::default(),
+            array_mode: false,
             schema,
         }
     }
@@ -250,6 +253,7 @@ impl ReaderBuilder {
             strict_mode: false,
             is_field: true,
             struct_mode: Default::default(),
+            array_mode: false,
             schema: Arc::new(Schema::new([field.into()])),
         }
     }
@@ -290,6 +294,34 @@ impl ReaderBuilder {
         }
     }
 
+    /// Sets if the input is a single top-level JSON array, e.g. `[{"a": 1}, {"a": 2}]`,
+    /// with each element decoded as a row, instead of a sequence of JSON values such as
+    /// newline-delimited JSON. Default is `false`.
+    ///
+    /// The array is decoded incrementally, so need not fit in memory. [`Reader`] returns
+    /// an error if the input ends before the closing `]`.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::Int64Type;
+    /// # use arrow_json::ReaderBuilder;
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// let data = r#"[{"a": 1}, {"a": 2}, {"a": 3}]"#;
+    /// let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+    /// let mut reader = ReaderBuilder::new(schema)
+    ///     .with_array_mode(true)
+    ///     .build(data.as_bytes())
+    ///     .unwrap();
+    /// let batch = reader.next().unwrap().unwrap();
+    /// assert_eq!(batch.column(0).as_primitive::<Int64Type>().values(), &[1, 2, 3]);
+    /// ```
+    ///
+    /// The schema of such input can be inferred with [`infer_json_schema_from_array`].
+    pub fn with_array_mode(self, array_mode: bool) -> Self {
+        Self { array_mode, ..self }
+    }
+
     /// Create a [`Reader`] with the provided [`BufRead`]
     pub fn build<R: BufRead>(self, reader: R) -> Result<Reader<R>, ArrowError> {
         Ok(Reader {
@@ -321,7 +353,8 @@ impl ReaderBuilder {
         Ok(Decoder {
             decoder,
             is_field: self.is_field,
-            tape_decoder: TapeDecoder::new(self.batch_size, num_fields),
+            tape_decoder: TapeDecoder::new(self.batch_size, num_fields)
+                .with_array_mode(self.array_mode),
             batch_size: self.batch_size,
             schema: self.schema,
         })
@@ -350,6 +383,7 @@ impl<R: BufRead> Reader<R> {
         loop {
             let buf = self.reader.fill_buf()?;
             if buf.is_empty() {
+                self.decoder.tape_decoder.finish_input()?;
                 break;
             }
             let read = buf.len();
@@ -679,6 +713,41 @@ impl Decoder {
     }
 }
 
+/// Decodes a slice of [`serde_json::Value`] into a [`RecordBatch`] with the given `schema`
+///
+/// The values are written directly to the tape used by the [`Decoder`], without first
+/// being serialized to a JSON string, and so are decoded in the same way as their JSON
+/// text would be by a [`ReaderBuilder`] with the default options. To customise these
+/// options see [`Decoder::serialize`].
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_schema::{DataType, Field, Schema};
+/// # use serde_json::json;
+/// let schema = Arc::new(Schema::new(vec![
+///     Field::new("a", DataType::Int32, true),
+///     Field::new("b", DataType::Utf8, true),
+/// ]));
+/// let values = [json!({"a": 1, "b": "foo"}), json!({"a": 2}), json!({"b": "bar"})];
+///
+/// let batch = arrow_json::reader::from_values(schema, &values).unwrap();
+/// assert_eq!(batch.num_rows(), 3);
+/// let a = batch.column(0).as_primitive::<Int32Type>();
+/// assert_eq!(a.iter().collect::<Vec<_>>(), [Some(1), Some(2), None]);
+/// ```
+pub fn from_values(
+    schema: SchemaRef,
+    values: &[serde_json::Value],
+) -> Result<RecordBatch, ArrowError> {
+    let mut decoder = ReaderBuilder::new(schema.clone()).build_decoder()?;
+    decoder.serialize(values)?;
+    Ok(decoder
+        .flush()?
+        .unwrap_or_else(|| RecordBatch::new_empty(schema)))
+}
+
 trait ArrayDecoder: Send {
     /// Decode elements from `tape` starting at the indexes contained in `pos`
     fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData, ArrowError>;
@@ -818,8 +887,8 @@ mod tests {
 
     use arrow_array::cast::AsArray;
     use arrow_array::{
-        Array, BooleanArray, Float64Array, GenericListViewArray, ListArray, OffsetSizeTrait,
-        StringArray, StringViewArray,
+        Array, BooleanArray, Float64Array, GenericListViewArray, Int32Array, ListArray,
+        OffsetSizeTrait, StringArray, StringViewArray,
     };
     use arrow_buffer::{ArrowNativeType, Buffer};
     use arrow_cast::display::{ArrayFormatter, FormatOptions};
@@ -2287,6 +2356,125 @@ mod tests {
         assert_eq!(&DataType::Int64, c.1.data_type());
     }
 
+    fn read_array_mode(
+        buf: &str,
+        builder: impl Fn() -> ReaderBuilder,
+        capacity: usize,
+    ) -> Result<Vec<RecordBatch>, ArrowError> {
+        builder()
+            .with_array_mode(true)
+            .build(BufReader::with_capacity(
+                capacity,
+                Cursor::new(buf.as_bytes()),
+            ))?
+            .collect()
+    }
+
+    #[test]
+    fn test_array_mode() {
+        let ndjson = r#"
+            {"a": 1, "b": [1, 2], "c": {"d": "x"}}
+            {"a": -2, "b": [], "c": {"d": "[,]"}}
+            {"a": null}
+            {"a": 4, "b": [3], "c": {"d": "y"}}
+            {"b": null, "c": null}
+        "#;
+        let array = r#" [
+            {"a": 1, "b": [1, 2], "c": {"d": "x"}},
+            {"a": -2, "b": [], "c": {"d": "[,]"}}  ,{"a": null},
+            {"a": 4, "b": [3], "c": {"d": "y"}} ,
+            {"b": null, "c": null}
+        ]
+        "#;
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new_list("b", Field::new_list_field(DataType::Int64, true), true),
+            Field::new_struct("c", vec![Field::new("d", DataType::Utf8, true)], true),
+        ]));
+
+        for batch_size in [1, 2, 5, 100] {
+            let builder = || ReaderBuilder::new(schema.clone()).with_batch_size(batch_size);
+            let expected = builder()
+                .build(Cursor::new(ndjson.as_bytes()))
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(expected.len(), 5_usize.div_ceil(batch_size));
+
+            // Test with different buffer sizes to test for boundary conditions
+            for capacity in [1, 3, 5, 1024] {
+                let actual = read_array_mode(array, builder, capacity).unwrap();
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_array_mode_scalars() {
+        let field = Field::new("int", DataType::Int32, true);
+        for capacity in [1, 1024] {
+            let builder = || ReaderBuilder::new_with_field(field.clone());
+            let batches = read_array_mode("[1, 2,null ,3]", builder, capacity).unwrap();
+            assert_eq!(batches.len(), 1);
+            let values = batches[0].column(0).as_primitive::<Int32Type>();
+            assert_eq!(
+                values,
+                &Int32Array::from(vec![Some(1), Some(2), None, Some(3)])
+            );
+        }
+    }
+
+    #[test]
+    fn test_array_mode_empty() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        let builder = || ReaderBuilder::new(schema.clone());
+        for data in ["[]", " \n[ \n ] \n"] {
+            assert!(read_array_mode(data, builder, 1024).unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_array_mode_invalid() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        let builder = || ReaderBuilder::new(schema.clone());
+        let cases = [
+            (
+                r#"{"a": 1}"#,
+                "Json error: Encountered unexpected '{' whilst parsing start of array",
+            ),
+            (
+                r#"[{"a": 1},]"#,
+                "Json error: Encountered unexpected ']' whilst parsing value",
+            ),
+            (
+                r#"[{"a": 1} {"a": 2}]"#,
+                "Json error: Encountered unexpected '{' whilst parsing array separator",
+            ),
+            (
+                r#"[{"a": 1}] {"a": 2}"#,
+                "Json error: Encountered unexpected '{' whilst parsing end of array",
+            ),
+            (
+                r#"[{"a": 1}, {"a": 2}"#,
+                "Json error: Truncated JSON array, expected ']'",
+            ),
+            (
+                r#"[{"a": 1},"#,
+                "Json error: Truncated JSON array, expected ']'",
+            ),
+            (
+                "  ",
+                "Json error: Expected a top-level JSON array, found end of input",
+            ),
+        ];
+        for (data, expected) in cases {
+            for capacity in [1, 1024] {
+                let err = read_array_mode(data, builder, capacity).unwrap_err();
+                assert_eq!(err.to_string(), expected, "{data}");
+            }
+        }
+    }
+
     #[test]
     fn test_with_multiple_batches() {
         let file = File::open("test/data/basic_nulls.json").unwrap();
@@ -2602,6 +2790,55 @@ mod tests {
         assert_eq!(values.values(), &[1234, 1234, 1234000, 1234000]);
     }
 
+    #[test]
+    fn test_from_values() {
+        let values = vec![
+            json!({"a": 1, "b": {"c": [1.5, null]}, "d": "1.234", "t": "2023-04-12T17:09:53Z"}),
+            json!({"a": null, "b": {"c": []}, "d": -12, "t": 1681319393}),
+            json!({"b": null, "d": 0.5}),
+        ];
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new_struct(
+                "b",
+                vec![Field::new_list(
+                    "c",
+                    Field::new_list_field(DataType::Float64, true),
+                    true,
+                )],
+                true,
+            ),
+            Field::new("d", DataType::Decimal128(10, 3), true),
+            Field::new("t", DataType::Timestamp(TimeUnit::Second, None), true),
+        ]));
+
+        let batch = from_values(schema.clone(), &values).unwrap();
+        assert_eq!(batch.num_rows(), 3);
+        let d = batch.column(2).as_primitive::<Decimal128Type>();
+        assert_eq!(d.values(), &[1234, -12000, 500]);
+        let t = batch.column(3).as_primitive::<TimestampSecondType>();
+        let expected = [Some(1681319393), Some(1681319393), None];
+        assert_eq!(t.iter().collect::<Vec<_>>(), expected);
+
+        // Matches decoding the JSON text of the values
+        let text = values
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let expected = do_read(&text, 1024, false, false, schema.clone());
+        assert_eq!(expected, vec![batch]);
+
+        let batch = from_values(schema.clone(), &[]).unwrap();
+        assert_eq!(batch, RecordBatch::new_empty(schema.clone()));
+
+        let err = from_values(schema, &[json!({"a": "foo"})]).unwrap_err();
+        assert!(
+            err.to_string().contains("whilst decoding field 'a'"),
+            "{err}"
+        );
+    }
+
     #[test]
     fn test_serde_field() {
         let field = Field::new("int", DataType::Int32, true);
@@ -19,6 +19,7 @@ use super::ValueIter;
 use arrow_schema::{ArrowError, DataType, Field, Fields, Schema};
 use indexmap::map::IndexMap as HashMap;
 use indexmap::set::IndexSet as HashSet;
+use serde_core::de::{Deserializer, Error as _, SeqAccess, Visitor};
 use serde_json::Value;
 use std::borrow::Borrow;
 use std::io::{BufRead, Seek};
@@ -209,6 +210,109 @@ pub fn infer_json_schema<R: BufRead>(
     Ok((schema, values.record_count()))
 }
 
+/// Infer the fields of a JSON file containing a single top-level array of objects, such
+/// as `[{"a": 1}, {"a": 2}]`, by reading the first n elements of the array, with
+/// `max_read_records` controlling the maximum number of elements to read.
+///
+/// If `max_read_records` is not set, the whole array is read to infer its field types.
+/// The array is read incrementally, so need not fit in memory.
+///
+/// Returns inferred schema and number of records read.
+///
+/// See [`ReaderBuilder::with_array_mode`] to read such a file, and [`infer_json_schema`]
+/// for further details.
+///
+/// [`ReaderBuilder::with_array_mode`]: crate::ReaderBuilder::with_array_mode
+///
+/// # Examples
+/// ```
+/// use arrow_json::reader::infer_json_schema_from_array;
+/// use arrow_schema::DataType;
+///
+/// let data = r#"[{"a": 1, "b": "x"}, {"a": 2.5}]"#;
+/// let (schema, records) = infer_json_schema_from_array(data.as_bytes(), None).unwrap();
+/// assert_eq!(records, 2);
+/// assert_eq!(schema.field(0).data_type(), &DataType::Float64);
+/// assert_eq!(schema.field(1).data_type(), &DataType::Utf8);
+/// ```
+pub fn infer_json_schema_from_array<R: BufRead>(
+    reader: R,
+    max_read_records: Option<usize>,
+) -> Result<(Schema, usize), ArrowError> {
+    let mut visitor = ArrayVisitor {
+        field_types: HashMap::new(),
+        max_read_records,
+        record_count: 0,
+        error: None,
+        stopped: false,
+    };
+
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let result = deserializer
+        .deserialize_seq(&mut visitor)
+        .and_then(|_| match visitor.stopped {
+            true => Ok(()),
+            false => deserializer.end(),
+        });
+    if let Some(e) = visitor.error {
+        return Err(e);
+    }
+    match result {
+        // Stopping part way through the array leaves its end unread
+        Err(_) if visitor.stopped => {}
+        Err(e) => return Err(ArrowError::JsonError(format!("Not valid JSON: {e}"))),
+        Ok(()) => {}
+    }
+
+    let schema = generate_schema(visitor.field_types)?;
+    Ok((schema, visitor.record_count))
+}
+
+/// A [`Visitor`] inferring the field types of the elements of a JSON array
+struct ArrayVisitor {
+    field_types: HashMap<String, InferredType>,
+    max_read_records: Option<usize>,
+    record_count: usize,
+    /// An error inferring the field types, as opposed to deserializing the JSON
+    error: Option<ArrowError>,
+    /// Whether `max_read_records` were read before the end of the array
+    stopped: bool,
+}
+
+impl<'de> Visitor<'de> for &mut ArrayVisitor {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a JSON array of objects")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        loop {
+            if self
+                .max_read_records
+                .is_some_and(|max| self.record_count >= max)
+            {
+                self.stopped = true;
+                return Ok(());
+            }
+            let result = match seq.next_element::<Value>()? {
+                None => return Ok(()),
+                Some(Value::Object(map)) => {
+                    collect_field_types_from_object(&mut self.field_types, &map)
+                }
+                Some(value) => Err(ArrowError::JsonError(format!(
+                    "Expected JSON record to be an object, found {value:?}"
+                ))),
+            };
+            if let Err(e) = result {
+                self.error = Some(e);
+                return Err(A::Error::custom("failed to infer schema"));
+            }
+            self.record_count += 1;
+        }
+    }
+}
+
 fn set_object_scalar_field_type(
     field_types: &mut HashMap<String, InferredType>,
     key: &str,
@@ -671,4 +775,66 @@ mod tests {
         )]);
         assert_eq!(inferred_schema, schema);
     }
+
+    #[test]
+    fn test_infer_json_schema_from_array() {
+        let data = r#"[
+            {"a": 1, "b": [2.0, 1.3], "c": false},
+            {"a": -10, "b": null, "d": {"e": "x"}},
+            {"a": 2.5, "c": true}
+        ]"#;
+        let (schema, records) = infer_json_schema_from_array(Cursor::new(data), None).unwrap();
+        assert_eq!(records, 3);
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("a", DataType::Float64, true),
+                Field::new_list("b", Field::new_list_field(DataType::Float64, true), true),
+                Field::new("c", DataType::Boolean, true),
+                Field::new_struct("d", vec![Field::new("e", DataType::Utf8, true)], true),
+            ])
+        );
+
+        // Only the first two records are read, so the rest need not be valid
+        let data = r#"[{"a": 1}, {"b": "x"}, {"c": 1.5}, invalid"#;
+        let (schema, records) = infer_json_schema_from_array(Cursor::new(data), Some(2)).unwrap();
+        assert_eq!(records, 2);
+        assert_eq!(
+            schema,
+            Schema::new(vec![
+                Field::new("a", DataType::Int64, true),
+                Field::new("b", DataType::Utf8, true),
+            ])
+        );
+
+        let (schema, records) = infer_json_schema_from_array(Cursor::new("[]"), None).unwrap();
+        assert_eq!(records, 0);
+        assert!(schema.fields().is_empty());
+    }
+
+    #[test]
+    fn test_infer_json_schema_from_array_invalid() {
+        let cases = [
+            (
+                r#"{"a": 1}"#,
+                "Json error: Not valid JSON: invalid type: map, expected a JSON array of objects at line 1 column 1",
+            ),
+            (
+                r#"[{"a": 1}, 2]"#,
+                "Json error: Expected JSON record to be an object, found Number(2)",
+            ),
+            (
+                r#"[{"a": 1}"#,
+                "Json error: Not valid JSON: EOF while parsing a list at line 1 column 9",
+            ),
+            (
+                r#"[{"a": 1}] [{"a": 2}]"#,
+                "Json error: Not valid JSON: trailing characters at line 1 column 12",
+            ),
+        ];
+        for (data, expected) in cases {
+            let err = infer_json_schema_from_array(Cursor::new(data), None).unwrap_err();
+            assert_eq!(err.to_string(), expected, "{data}");
+        }
+    }
 }
@@ -294,6 +294,21 @@ macro_rules! next {
     };
 }
 
+/// The position within a top-level JSON array of rows, see [`TapeDecoder::with_array_mode`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum ArrayState {
+    /// Expecting the opening `[`
+    Start,
+    /// Expecting the first row or `]`
+    First,
+    /// Expecting `,` or `]` after a row
+    Separator,
+    /// Expecting a row after `,`
+    Next,
+    /// Expecting only whitespace after the closing `]`
+    End,
+}
+
 /// Implements a state machine for decoding JSON to a tape
 pub struct TapeDecoder {
     elements: Vec<TapeElement>,
@@ -315,6 +330,9 @@ pub struct TapeDecoder {
 
     /// A stack of [`DecoderState`]
     stack: Vec<DecoderState>,
+
+    /// The position within the top-level array of rows, if decoding an array
+    array: Option<ArrayState>,
 }
 
 impl TapeDecoder {
@@ -335,6 +353,16 @@ impl TapeDecoder {
             cur_row: 0,
             bytes: Vec::with_capacity(num_fields * 2 * 8),
             stack: Vec::with_capacity(10),
+            array: None,
+        }
+    }
+
+    /// Decode rows from the elements of a single top-level JSON array, e.g.
+    /// `[{"a": 1}, {"a": 2}]`, instead of a sequence of whitespace separated values
+    pub fn with_array_mode(self, array_mode: bool) -> Self {
+        Self {
+            array: array_mode.then_some(ArrayState::Start),
+            ..self
         }
     }
 
@@ -346,10 +374,44 @@ impl TapeDecoder {
                 Some(l) => l,
                 None => {
                     iter.skip_whitespace();
-                    if iter.is_empty() || self.cur_row >= self.batch_size {
+                    if iter.is_empty() {
                         break;
                     }
 
+                    match self.array {
+                        Some(ArrayState::Start) => {
+                            match next!(iter) {
+                                b'[' => self.array = Some(ArrayState::First),
+                                b => return Err(err(b, "parsing start of array")),
+                            }
+                            continue;
+                        }
+                        Some(ArrayState::First) if iter.peek() == Some(b']') => {
+                            iter.next();
+                            self.array = Some(ArrayState::End);
+                            continue;
+                        }
+                        Some(ArrayState::Separator) => {
+                            match next!(iter) {
+                                b',' => self.array = Some(ArrayState::Next),
+                                b']' => self.array = Some(ArrayState::End),
+                                b => return Err(err(b, "parsing array separator")),
+                            }
+                            continue;
+                        }
+                        Some(ArrayState::End) => {
+                            return Err(err(next!(iter), "parsing end of array"));
+                        }
+                        Some(ArrayState::First | ArrayState::Next) | None => {}
+                    }
+
+                    if self.cur_row >= self.batch_size {
+                        break;
+                    }
+                    if self.array.is_some() {
+                        self.array = Some(ArrayState::Separator);
+                    }
+
                     // Start of row
                     self.cur_row += 1;
                     self.stack.push(DecoderState::Value);
@@ -557,6 +619,20 @@ impl TapeDecoder {
         !self.stack.is_empty()
     }
 
+    /// Returns an error if decoding a top-level array that has not been closed, to be
+    /// called once all input has been decoded
+    pub fn finish_input(&self) -> Result<(), ArrowError> {
+        match self.array {
+            None | Some(ArrayState::End) => Ok(()),
+            Some(ArrayState::Start) => Err(ArrowError::JsonError(
+                "Expected a top-level JSON array, found end of input".to_string(),
+            )),
+            Some(_) => Err(ArrowError::JsonError(
+                "Truncated JSON array, expected ']'".to_string(),
+            )),
+        }
+    }
+
     /// Finishes the current [`Tape`]
     pub fn finish(&self) -> Result<Tape<'_>, ArrowError> {
         if let Some(b) = self.stack.last() {
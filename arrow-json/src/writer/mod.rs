@@ -77,8 +77,8 @@
 //!
 //! ## Writing to [serde_json] JSON Objects
 //!
-//! To serialize [`RecordBatch`]es into an array of
-//! [JSON](https://docs.serde.rs/serde_json/) objects you can reparse the resulting JSON string.
+//! To convert [`RecordBatch`]es into
+//! [JSON](https://docs.serde.rs/serde_json/) objects use [`to_values`].
 //! Note that this is less efficient than using the `Writer` API.
 //!
 //! ```
@@ -89,22 +89,11 @@
 //! let a = Int32Array::from(vec![1, 2, 3]);
 //! let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(a)]).unwrap();
 //!
-//! // Write the record batch out as json bytes (string)
-//! let buf = Vec::new();
-//! let mut writer = arrow_json::ArrayWriter::new(buf);
-//! writer.write_batches(&vec![&batch]).unwrap();
-//! writer.finish().unwrap();
-//! let json_data = writer.into_inner();
-//!
-//! // Parse the string using serde_json
-//! use serde_json::{Map, Value};
-//! let json_rows: Vec<Map<String, Value>> = serde_json::from_reader(json_data.as_slice()).unwrap();
-//! assert_eq!(
-//!     serde_json::Value::Object(json_rows[1].clone()),
-//!     serde_json::json!({"a": 2}),
-//! );
+//! let json_rows = arrow_json::writer::to_values(&batch).unwrap();
+//! assert_eq!(json_rows[1], serde_json::json!({"a": 2}));
 //! ```
 mod encoder;
+mod value;
 
 use std::{fmt::Debug, io::Write, sync::Arc};
 
@@ -476,6 +465,26 @@ where
     }
 }
 
+/// Converts each row of `batch` to a JSON object
+///
+/// The [`serde_json::Value`]s are built directly from the arrays of `batch`, without
+/// first being written as JSON text. Values are represented identically to the output
+/// of [`LineDelimitedWriter`], with keys omitted for null values.
+pub fn to_values(batch: &RecordBatch) -> Result<Vec<serde_json::Value>, ArrowError> {
+    to_values_with_options(batch, &EncoderOptions::default())
+}
+
+/// Converts each row of `batch` to a JSON value, as written by a [`Writer`] with `options`
+///
+/// See [`to_values`]. If `options` has an [`EncoderFactory`], each row is instead
+/// encoded as JSON text and parsed, as the encoders it returns may write any JSON.
+pub fn to_values_with_options(
+    batch: &RecordBatch,
+    options: &EncoderOptions,
+) -> Result<Vec<serde_json::Value>, ArrowError> {
+    value::batch_to_values(batch, options)
+}
+
 #[cfg(test)]
 mod tests {
     use core::str;
@@ -2811,4 +2820,313 @@ mod tests {
         assert!(values.is_null(1));
         assert_eq!(values.value(2), "b");
     }
+
+    #[test]
+    fn test_to_values() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new_list("l", Field::new_list_field(DataType::Utf8, true), true),
+            Field::new("d", DataType::Decimal128(10, 3), true),
+            Field::new("t", DataType::Timestamp(TimeUnit::Second, None), true),
+        ]));
+        let input = [
+            json!({"a": 1, "l": ["x", null], "d": 1.234, "t": 1681319393}),
+            json!({"a": null, "l": [], "d": -12}),
+            json!({}),
+        ];
+        let batch = from_values(schema, &input).unwrap();
+
+        let values = to_values(&batch).unwrap();
+        assert_eq!(
+            values,
+            [
+                json!({"a": 1, "l": ["x", null], "d": 1.234, "t": "2023-04-12T17:09:53"}),
+                json!({"l": [], "d": -12.000}),
+                json!({}),
+            ]
+        );
+
+        // Matches parsing the output of the LineDelimitedWriter
+        let mut buf = Vec::new();
+        let mut writer = LineDelimitedWriter::new(&mut buf);
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        let expected: Vec<Value> = buf
+            .split(|b| *b == b'\n')
+            .filter(|s| !s.is_empty())
+            .map(|s| serde_json::from_slice(s).unwrap())
+            .collect();
+        assert_eq!(values, expected);
+
+        // Round trips back to the same batch
+        assert_eq!(from_values(batch.schema(), &values).unwrap(), batch);
+    }
+
+    /// Overrides the encoding of booleans
+    #[derive(Debug)]
+    struct YesNoEncoderFactory;
+
+    impl EncoderFactory for YesNoEncoderFactory {
+        fn make_default_encoder<'a>(
+            &self,
+            _field: &'a FieldRef,
+            array: &'a dyn Array,
+            _options: &'a EncoderOptions,
+        ) -> Result<Option<NullableEncoder<'a>>, ArrowError> {
+            struct YesNoEncoder(BooleanArray);
+            impl Encoder for YesNoEncoder {
+                fn encode(&mut self, idx: usize, out: &mut Vec<u8>) {
+                    let value: &[u8] = if self.0.value(idx) { b"yes" } else { b"no" };
+                    out.extend_from_slice(&[b"\"", value, b"\""].concat());
+                }
+            }
+            Ok(match array.data_type() {
+                DataType::Boolean => {
+                    let array = array.as_boolean().clone();
+                    let nulls = array.nulls().cloned();
+                    Some(NullableEncoder::new(Box::new(YesNoEncoder(array)), nulls))
+                }
+                _ => None,
+            })
+        }
+    }
+
+    #[test]
+    fn test_to_values_matches_writer() {
+        let ints: ArrayRef = Arc::new(Int64Array::from(vec![Some(1), None, Some(100)]));
+        let floats: ArrayRef = Arc::new(Float64Array::from(vec![Some(0.1), None, Some(f64::NAN)]));
+        let strings: ArrayRef = Arc::new(StringArray::from(vec![Some("a\"b"), None, Some("a\"b")]));
+        let binaries: ArrayRef = Arc::new(BinaryArray::from(vec![
+            Some(b"ab".as_ref()),
+            None,
+            Some(b"cd"),
+        ]));
+        let lists: ArrayRef = Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![]),
+        ]));
+        let decimals: ArrayRef = Arc::new(
+            Decimal128Array::from(vec![Some(1234), None, Some(-5)])
+                .with_precision_and_scale(9, 3)
+                .unwrap(),
+        );
+        let dates: ArrayRef = Arc::new(Date32Array::from(vec![Some(0), None, Some(19000)]));
+        let times: ArrayRef = Arc::new(Time64NanosecondArray::from(vec![
+            Some(3_723_000_000_000),
+            None,
+            Some(0),
+        ]));
+        let timestamps: ArrayRef = Arc::new(TimestampNanosecondArray::from(vec![
+            Some(1_681_319_393_000_000_000),
+            None,
+            Some(-1_000_000_000),
+        ]));
+        let durations: ArrayRef = Arc::new(DurationNanosecondArray::from(vec![
+            Some(1_500_000_000),
+            None,
+            Some(0),
+        ]));
+
+        let tz = Some("+01:00".into());
+        let casts = [
+            (&ints, DataType::Int8),
+            (&ints, DataType::Int16),
+            (&ints, DataType::Int32),
+            (&ints, DataType::Int64),
+            (&ints, DataType::UInt8),
+            (&ints, DataType::UInt16),
+            (&ints, DataType::UInt32),
+            (&ints, DataType::UInt64),
+            (&floats, DataType::Float16),
+            (&floats, DataType::Float32),
+            (&floats, DataType::Float64),
+            (&strings, DataType::Utf8),
+            (&strings, DataType::LargeUtf8),
+            (&strings, DataType::Utf8View),
+            (
+                &strings,
+                DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+            ),
+            (
+                &strings,
+                DataType::Dictionary(Box::new(DataType::UInt64), Box::new(DataType::Utf8View)),
+            ),
+            (
+                &strings,
+                DataType::RunEndEncoded(
+                    Arc::new(Field::new("run_ends", DataType::Int32, false)),
+                    Arc::new(Field::new("values", DataType::Utf8, true)),
+                ),
+            ),
+            (&binaries, DataType::Binary),
+            (&binaries, DataType::LargeBinary),
+            (&binaries, DataType::BinaryView),
+            (&binaries, DataType::FixedSizeBinary(2)),
+            (
+                &binaries,
+                DataType::Dictionary(Box::new(DataType::Int16), Box::new(DataType::Binary)),
+            ),
+            (&lists, DataType::new_list(DataType::Int32, true)),
+            (&lists, DataType::new_large_list(DataType::Int32, true)),
+            (
+                &lists,
+                DataType::ListView(Arc::new(Field::new_list_field(DataType::Int32, true))),
+            ),
+            (
+                &lists,
+                DataType::LargeListView(Arc::new(Field::new_list_field(DataType::Int32, true))),
+            ),
+            (&decimals, DataType::Decimal32(9, 3)),
+            (&decimals, DataType::Decimal64(9, 3)),
+            (&decimals, DataType::Decimal128(9, 3)),
+            (&decimals, DataType::Decimal256(9, 3)),
+            (&dates, DataType::Date32),
+            (&dates, DataType::Date64),
+            (&times, DataType::Time32(TimeUnit::Second)),
+            (&times, DataType::Time32(TimeUnit::Millisecond)),
+            (&times, DataType::Time64(TimeUnit::Microsecond)),
+            (&times, DataType::Time64(TimeUnit::Nanosecond)),
+            (&timestamps, DataType::Timestamp(TimeUnit::Second, None)),
+            (
+                &timestamps,
+                DataType::Timestamp(TimeUnit::Millisecond, None),
+            ),
+            (
+                &timestamps,
+                DataType::Timestamp(TimeUnit::Microsecond, None),
+            ),
+            (&timestamps, DataType::Timestamp(TimeUnit::Nanosecond, tz)),
+            (&durations, DataType::Duration(TimeUnit::Second)),
+            (&durations, DataType::Duration(TimeUnit::Millisecond)),
+            (&durations, DataType::Duration(TimeUnit::Microsecond)),
+            (&durations, DataType::Duration(TimeUnit::Nanosecond)),
+        ];
+        let mut columns: Vec<(String, ArrayRef)> = casts
+            .into_iter()
+            .map(|(array, to_type)| {
+                let array = arrow_cast::cast(array.as_ref(), &to_type).unwrap();
+                assert_eq!(array.data_type(), &to_type);
+                (format!("{to_type}"), array)
+            })
+            .collect();
+
+        let mut map = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        map.keys().append_value("x");
+        map.values().append_value(1);
+        map.keys().append_value("y");
+        map.values().append_null();
+        map.append(true).unwrap();
+        map.append(false).unwrap();
+        map.append(true).unwrap();
+
+        // Null dictionary values and run values with valid keys and run ends
+        let dict = DictionaryArray::<Int32Type>::try_new(
+            Int32Array::from(vec![Some(0), None, Some(1)]),
+            Arc::new(StringArray::from(vec![Some("a"), None])),
+        )
+        .unwrap();
+        let run = RunArray::try_new(
+            &Int32Array::from(vec![2, 3]),
+            &Float32Array::from(vec![Some(0.1), None]),
+        )
+        .unwrap();
+        let mut fixed_size_list = FixedSizeListBuilder::new(Int32Builder::new(), 2);
+        fixed_size_list.values().append_value(1);
+        fixed_size_list.values().append_null();
+        fixed_size_list.append(true);
+        fixed_size_list.values().append_nulls(2);
+        fixed_size_list.append(false);
+        fixed_size_list.values().append_slice(&[3, 4]);
+        fixed_size_list.append(true);
+        let nested = StructArray::try_new(
+            Fields::from(vec![
+                Field::new("f", DataType::Float64, true),
+                Field::new("s", DataType::Utf8, true),
+            ]),
+            vec![floats.clone(), strings.clone()],
+            Some(NullBuffer::from(vec![true, true, false])),
+        )
+        .unwrap();
+
+        columns.extend([
+            ("null".to_string(), Arc::new(NullArray::new(3)) as ArrayRef),
+            (
+                "bool".to_string(),
+                Arc::new(BooleanArray::from(vec![Some(true), None, Some(false)])) as _,
+            ),
+            (
+                "u64_max".to_string(),
+                Arc::new(UInt64Array::from(vec![u64::MAX; 3])) as _,
+            ),
+            (
+                "interval_year_month".to_string(),
+                Arc::new(IntervalYearMonthArray::from(vec![Some(14), None, Some(-1)])) as _,
+            ),
+            (
+                "interval_day_time".to_string(),
+                Arc::new(IntervalDayTimeArray::from(vec![
+                    Some(IntervalDayTime::new(1, 1000)),
+                    None,
+                    Some(IntervalDayTime::ZERO),
+                ])) as _,
+            ),
+            (
+                "interval_month_day_nano".to_string(),
+                Arc::new(IntervalMonthDayNanoArray::from(vec![
+                    Some(IntervalMonthDayNano::new(1, 2, 3)),
+                    None,
+                    Some(IntervalMonthDayNano::ZERO),
+                ])) as _,
+            ),
+            ("map".to_string(), Arc::new(map.finish()) as _),
+            ("dict".to_string(), Arc::new(dict) as _),
+            ("run".to_string(), Arc::new(run) as _),
+            (
+                "fixed_size_list".to_string(),
+                Arc::new(fixed_size_list.finish()) as _,
+            ),
+            ("nested".to_string(), Arc::new(nested) as _),
+        ]);
+        let batch = RecordBatch::try_from_iter(columns).unwrap();
+
+        let values = to_values(&batch).unwrap();
+        assert_eq!(values[0]["Utf8"], json!("a\"b"));
+        assert_eq!(values[0]["UInt64"], json!(1));
+        assert_eq!(values[0]["map"], json!({"x": 1}));
+        assert_eq!(values[0]["nested"], json!({"f": 0.1, "s": "a\"b"}));
+        assert_eq!(values[1].get("Int8"), None);
+        // A null dictionary value with a valid key is written as its slot, see test_writer_null_dict
+        assert_eq!(values[2]["dict"], json!(""));
+
+        let formats = EncoderOptions::default()
+            .with_date_format("%d/%m/%Y".to_string())
+            .with_datetime_format("%d/%m/%Y %H:%M".to_string())
+            .with_time_format("%H-%M-%S".to_string())
+            .with_timestamp_format("%Y %j %T".to_string())
+            .with_timestamp_tz_format("%Y %j %T %z".to_string());
+        for options in [
+            EncoderOptions::default(),
+            EncoderOptions::default().with_explicit_nulls(true),
+            EncoderOptions::default().with_struct_mode(StructMode::ListOnly),
+            EncoderOptions::default()
+                .with_explicit_nulls(true)
+                .with_struct_mode(StructMode::ListOnly),
+            formats,
+            EncoderOptions::default().with_encoder_factory(Arc::new(YesNoEncoderFactory)),
+        ] {
+            let values = to_values_with_options(&batch, &options).unwrap();
+
+            let mut buf = Vec::new();
+            let mut writer = WriterBuilder(options.clone()).build::<_, LineDelimited>(&mut buf);
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+            let expected: Vec<Value> = buf
+                .split(|b| *b == b'\n')
+                .filter(|s| !s.is_empty())
+                .map(|s| serde_json::from_slice(s).unwrap())
+                .collect();
+            assert_eq!(values, expected, "{options:?}");
+        }
+    }
 }
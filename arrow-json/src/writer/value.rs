@@ -0,0 +1,310 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversion of arrays to [`serde_json::Value`], see [`to_values`](super::to_values)
+
+use std::fmt::Write;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{ArrowNativeType, NullBuffer};
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
+use arrow_schema::{ArrowError, DataType, Field};
+use half::f16;
+use lexical_core::FormattedSize;
+use serde_json::{Map, Number, Value};
+
+use super::{EncoderOptions, make_encoder};
+use crate::StructMode;
+
+/// Converts each row of `batch` to a [`Value`], see [`to_values_with_options`](super::to_values_with_options)
+pub(crate) fn batch_to_values(
+    batch: &RecordBatch,
+    options: &EncoderOptions,
+) -> Result<Vec<Value>, ArrowError> {
+    let array = StructArray::from(batch.clone());
+    if options.encoder_factory().is_none() {
+        return struct_values(&array, options);
+    }
+
+    // A custom encoder may write any JSON, so encode and parse each row
+    let field = Arc::new(Field::new_struct(
+        "",
+        batch.schema().fields().clone(),
+        false,
+    ));
+    let mut encoder = make_encoder(&field, &array, options)?;
+    let mut buffer = Vec::new();
+    (0..array.len())
+        .map(|idx| {
+            buffer.clear();
+            encoder.encode(idx, &mut buffer);
+            serde_json::from_slice(&buffer).map_err(|e| ArrowError::JsonError(e.to_string()))
+        })
+        .collect()
+}
+
+/// Converts each element of `array` to a [`Value`], with [`Value::Null`] for nulls
+///
+/// Values are represented as they would be by [`make_encoder`] with `options`, which
+/// must not have an [`EncoderFactory`](super::EncoderFactory)
+fn array_to_values(array: &dyn Array, options: &EncoderOptions) -> Result<Vec<Value>, ArrowError> {
+    macro_rules! primitive_helper {
+        ($t:ty) => {
+            primitive_values(array.as_primitive::<$t>(), Value::from)
+        };
+    }
+
+    let values = downcast_integer! {
+        array.data_type() => (primitive_helper),
+        DataType::Float16 => primitive_values(array.as_primitive::<Float16Type>(), |v: f16| {
+            f32_value(v.to_f32())
+        }),
+        DataType::Float32 => primitive_values(array.as_primitive::<Float32Type>(), f32_value),
+        DataType::Float64 => primitive_values(array.as_primitive::<Float64Type>(), f64_value),
+        DataType::Boolean => {
+            let array = array.as_boolean();
+            values_with_nulls(array.nulls(), array.len(), |idx| Value::Bool(array.value(idx)))
+        }
+        DataType::Null => vec![Value::Null; array.len()],
+        DataType::Utf8 => string_values(array.as_string::<i32>()),
+        DataType::LargeUtf8 => string_values(array.as_string::<i64>()),
+        DataType::Utf8View => string_values(array.as_string_view()),
+        DataType::Binary => binary_values(array.as_binary::<i32>()),
+        DataType::LargeBinary => binary_values(array.as_binary::<i64>()),
+        DataType::BinaryView => binary_values(array.as_binary_view()),
+        DataType::FixedSizeBinary(_) => binary_values(array.as_fixed_size_binary()),
+        DataType::List(_) => list_values(array.as_list::<i32>(), options)?,
+        DataType::LargeList(_) => list_values(array.as_list::<i64>(), options)?,
+        DataType::ListView(_) => list_values(array.as_list_view::<i32>(), options)?,
+        DataType::LargeListView(_) => list_values(array.as_list_view::<i64>(), options)?,
+        DataType::FixedSizeList(_, _) => list_values(array.as_fixed_size_list(), options)?,
+        DataType::Dictionary(_, _) => downcast_dictionary_array! {
+            array => {
+                // The encoder writes the dictionary value of each valid key, even if null
+                let values = array.values().to_data().into_builder().nulls(None).build()?;
+                let values = array_to_values(make_array(values).as_ref(), options)?;
+                let keys = array.keys();
+                values_with_nulls(keys.nulls(), keys.len(), |idx| {
+                    values[keys.value(idx).as_usize()].clone()
+                })
+            },
+            _ => unreachable!()
+        },
+        DataType::RunEndEncoded(_, _) => downcast_run_array! {
+            array => {
+                let values = array_to_values(array.values().as_ref(), options)?;
+                (0..array.len())
+                    .map(|idx| values[array.get_physical_index(idx)].clone())
+                    .collect()
+            },
+            _ => unreachable!()
+        },
+        DataType::Map(_, _) => map_values(array.as_map(), options)?,
+        DataType::Struct(_) => struct_values(array.as_struct(), options)?,
+        DataType::Decimal32(_, _)
+        | DataType::Decimal64(_, _)
+        | DataType::Decimal128(_, _)
+        | DataType::Decimal256(_, _) => {
+            let options = FormatOptions::new().with_display_error(true);
+            let formatter = ArrayFormatter::try_new(array, &options)?;
+            (0..array.len())
+                .map(|idx| match array.is_null(idx) {
+                    true => Ok(Value::Null),
+                    false => formatter
+                        .value(idx)
+                        .to_string()
+                        .parse::<Number>()
+                        .map(Value::Number)
+                        .map_err(|e| ArrowError::JsonError(e.to_string())),
+                })
+                .collect::<Result<_, _>>()?
+        }
+        d => match d.is_temporal() {
+            true => {
+                let options = FormatOptions::new()
+                    .with_display_error(true)
+                    .with_date_format(options.date_format())
+                    .with_datetime_format(options.datetime_format())
+                    .with_timestamp_format(options.timestamp_format())
+                    .with_timestamp_tz_format(options.timestamp_tz_format())
+                    .with_time_format(options.time_format());
+                let formatter = ArrayFormatter::try_new(array, &options)?;
+                values_with_nulls(array.nulls(), array.len(), |idx| {
+                    Value::String(formatter.value(idx).to_string())
+                })
+            }
+            false => {
+                return Err(ArrowError::JsonError(format!(
+                    "Unsupported data type for JSON encoding: {d:?}",
+                )));
+            }
+        },
+    };
+    Ok(values)
+}
+
+/// Returns the nulls used by [`make_encoder`](super::make_encoder) for `array`
+fn encoder_nulls(array: &dyn Array) -> Option<NullBuffer> {
+    match array.data_type() {
+        DataType::Null | DataType::RunEndEncoded(_, _) => array.logical_nulls(),
+        _ => array.nulls().cloned(),
+    }
+}
+
+fn values_with_nulls(
+    nulls: Option<&NullBuffer>,
+    len: usize,
+    mut f: impl FnMut(usize) -> Value,
+) -> Vec<Value> {
+    (0..len)
+        .map(|idx| match nulls.is_some_and(|n| n.is_null(idx)) {
+            true => Value::Null,
+            false => f(idx),
+        })
+        .collect()
+}
+
+fn primitive_values<T: ArrowPrimitiveType>(
+    array: &PrimitiveArray<T>,
+    f: impl Fn(T::Native) -> Value,
+) -> Vec<Value> {
+    values_with_nulls(array.nulls(), array.len(), |idx| f(array.value(idx)))
+}
+
+/// Non-finite values are encoded as `null`
+fn f64_value(v: f64) -> Value {
+    Number::from_f64(v).map_or(Value::Null, Value::Number)
+}
+
+/// Widens `v` using its shortest decimal representation, as the JSON text written by
+/// the encoder would be read, so that e.g. `0.1f32` becomes `0.1f64`
+fn f32_value(v: f32) -> Value {
+    if !v.is_finite() {
+        return Value::Null;
+    }
+    let mut buffer = [0; f32::FORMATTED_SIZE];
+    let text = lexical_core::write(v, &mut buffer);
+    f64_value(lexical_core::parse(text).unwrap())
+}
+
+fn string_values<'a>(array: impl ArrayAccessor<Item = &'a str>) -> Vec<Value> {
+    values_with_nulls(array.nulls(), array.len(), |idx| {
+        Value::String(array.value(idx).to_string())
+    })
+}
+
+/// Binary values are encoded as hex strings
+fn binary_values<'a>(array: impl ArrayAccessor<Item = &'a [u8]>) -> Vec<Value> {
+    values_with_nulls(array.nulls(), array.len(), |idx| {
+        let bytes = array.value(idx);
+        let mut hex = String::with_capacity(bytes.len() * 2);
+        for byte in bytes {
+            // this write is infallible
+            write!(hex, "{byte:02x}").unwrap();
+        }
+        Value::String(hex)
+    })
+}
+
+fn list_values<L: ListLikeArray>(
+    array: &L,
+    options: &EncoderOptions,
+) -> Result<Vec<Value>, ArrowError> {
+    let values = array_to_values(array.values().as_ref(), options)?;
+    Ok(values_with_nulls(array.nulls(), array.len(), |idx| {
+        Value::Array(values[array.element_range(idx)].to_vec())
+    }))
+}
+
+/// Structs are encoded as objects or arrays depending on [`StructMode`], omitting null
+/// fields of objects unless nulls are explicit
+fn struct_values(array: &StructArray, options: &EncoderOptions) -> Result<Vec<Value>, ArrowError> {
+    let columns = array
+        .columns()
+        .iter()
+        .map(|c| {
+            Ok((
+                encoder_nulls(c.as_ref()),
+                array_to_values(c.as_ref(), options)?,
+            ))
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    let names = array.column_names();
+    let struct_mode = options.struct_mode();
+    let drop_nulls = struct_mode == StructMode::ObjectOnly && !options.explicit_nulls();
+    Ok(values_with_nulls(array.nulls(), array.len(), |idx| {
+        let fields = names.iter().zip(&columns).filter(|(_, (nulls, _))| {
+            !drop_nulls || !nulls.as_ref().is_some_and(|n| n.is_null(idx))
+        });
+        match struct_mode {
+            StructMode::ObjectOnly => Value::Object(
+                fields
+                    .map(|(name, (_, values))| (name.to_string(), values[idx].clone()))
+                    .collect(),
+            ),
+            StructMode::ListOnly => {
+                Value::Array(fields.map(|(_, (_, values))| values[idx].clone()).collect())
+            }
+        }
+    }))
+}
+
+/// Maps are encoded as objects, omitting null values unless nulls are explicit
+fn map_values(array: &MapArray, options: &EncoderOptions) -> Result<Vec<Value>, ArrowError> {
+    let keys = array.keys();
+    if !matches!(
+        keys.data_type(),
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View
+    ) {
+        return Err(ArrowError::JsonError(format!(
+            "Only UTF8 keys supported by JSON MapArray Writer: got {:?}",
+            keys.data_type()
+        )));
+    }
+    if keys.null_count() != 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "Encountered nulls in MapArray keys".to_string(),
+        ));
+    }
+    if array.entries().nulls().is_some_and(|x| x.null_count() != 0) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Encountered nulls in MapArray entries".to_string(),
+        ));
+    }
+
+    let keys = array_to_values(keys.as_ref(), options)?;
+    let value_nulls = encoder_nulls(array.values().as_ref());
+    let values = array_to_values(array.values().as_ref(), options)?;
+    let explicit_nulls = options.explicit_nulls();
+    let offsets = array.offsets();
+    Ok(values_with_nulls(array.nulls(), array.len(), |idx| {
+        let mut object = Map::new();
+        for entry in offsets[idx].as_usize()..offsets[idx + 1].as_usize() {
+            if explicit_nulls || !value_nulls.as_ref().is_some_and(|n| n.is_null(entry)) {
+                let Value::String(key) = &keys[entry] else {
+                    unreachable!()
+                };
+                object.insert(key.clone(), values[entry].clone());
+            }
+        }
+        Value::Object(object)
+    }))
+}
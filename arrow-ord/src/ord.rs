@@ -172,6 +172,50 @@ fn compare_byte_view<T: ByteViewType>(
     })
 }
 
+fn compare_fixed_size_binary(
+    left: &dyn Array,
+    right: &dyn Array,
+    opts: SortOptions,
+) -> DynComparator {
+    let left = left.as_fixed_size_binary();
+    let right = right.as_fixed_size_binary();
+
+    // Values of common widths are compared as big-endian integers, which orders
+    // them the same as comparing their bytes
+    match (left.value_length(), right.value_length()) {
+        (4, 4) => compare_fixed_width(left, right, opts, |v| {
+            u32::from_be_bytes(v.try_into().unwrap())
+        }),
+        (8, 8) => compare_fixed_width(left, right, opts, |v| {
+            u64::from_be_bytes(v.try_into().unwrap())
+        }),
+        (16, 16) => compare_fixed_width(left, right, opts, |v| {
+            u128::from_be_bytes(v.try_into().unwrap())
+        }),
+        _ => {
+            let l = left.clone();
+            let r = right.clone();
+            compare(left, right, opts, move |i, j| l.value(i).cmp(r.value(j)))
+        }
+    }
+}
+
+fn compare_fixed_width<K: Ord>(
+    left: &FixedSizeBinaryArray,
+    right: &FixedSizeBinaryArray,
+    opts: SortOptions,
+    key: impl Fn(&[u8]) -> K + Send + Sync + 'static,
+) -> DynComparator {
+    let width = left.value_length() as usize;
+    let l = left.values().clone();
+    let r = right.values().clone();
+    compare(left, right, opts, move |i, j| {
+        let l = &l[i * width..(i + 1) * width];
+        let r = &r[j * width..(j + 1) * width];
+        key(l).cmp(&key(r))
+    })
+}
+
 fn compare_dict<K: ArrowDictionaryKeyType>(
     left: &dyn Array,
     right: &dyn Array,
@@ -516,16 +560,7 @@ pub fn make_comparator(
         (Binary, Binary) => Ok(compare_bytes::<BinaryType>(left, right, opts)),
         (LargeBinary, LargeBinary) => Ok(compare_bytes::<LargeBinaryType>(left, right, opts)),
         (BinaryView, BinaryView) => Ok(compare_byte_view::<BinaryViewType>(left, right, opts)),
-        (FixedSizeBinary(_), FixedSizeBinary(_)) => {
-            let left = left.as_fixed_size_binary();
-            let right = right.as_fixed_size_binary();
-
-            let l = left.clone();
-            let r = right.clone();
-            Ok(compare(left, right, opts, move |i, j| {
-                l.value(i).cmp(r.value(j))
-            }))
-        },
+        (FixedSizeBinary(_), FixedSizeBinary(_)) => Ok(compare_fixed_size_binary(left, right, opts)),
         (List(_), List(_)) => compare_list::<i32>(left, right, opts),
         (LargeList(_), LargeList(_)) => compare_list::<i64>(left, right, opts),
         (ListView(_), ListView(_)) => compare_list_view::<i32>(left, right, opts),
@@ -599,6 +634,27 @@ mod tests {
         assert_eq!(Ordering::Less, cmp(0, 0));
     }
 
+    #[test]
+    fn test_fixed_size_binary_widths() {
+        for width in [3, 4, 8, 16] {
+            let items = [0x00, 0x7F, 0x80, 0xFF, 0x01].into_iter().map(|b| {
+                let mut v = vec![0xAB; width];
+                v[width - 1] = b;
+                v
+            });
+            let array = FixedSizeBinaryArray::try_from_iter(items).unwrap();
+            let sliced = array.slice(1, 4);
+
+            let cmp = make_comparator(&sliced, &array, SortOptions::default()).unwrap();
+            for i in 0..sliced.len() {
+                for j in 0..array.len() {
+                    let expected = sliced.value(i).cmp(array.value(j));
+                    assert_eq!(cmp(i, j), expected, "width {width} at ({i}, {j})");
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_i32() {
         let array = Int32Array::from(vec![1, 2]);
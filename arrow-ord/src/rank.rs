@@ -17,10 +17,12 @@
 
 //! Provides `rank` function to assign a rank to each value in an array
 
+use crate::sort::{NanOrdering, nans_first_compare};
 use arrow_array::cast::AsArray;
 use arrow_array::types::*;
 use arrow_array::{
-    Array, ArrowNativeTypeOp, BooleanArray, GenericByteArray, downcast_primitive_array,
+    Array, ArrowNativeTypeOp, ArrowPrimitiveType, BooleanArray, FixedSizeBinaryArray,
+    GenericByteArray, GenericByteViewArray, PrimitiveArray, downcast_primitive_array,
 };
 use arrow_buffer::NullBuffer;
 use arrow_schema::{ArrowError, DataType, SortOptions};
@@ -34,8 +36,11 @@ pub(crate) fn can_rank(data_type: &DataType) -> bool {
             DataType::Boolean
                 | DataType::Utf8
                 | DataType::LargeUtf8
+                | DataType::Utf8View
                 | DataType::Binary
                 | DataType::LargeBinary
+                | DataType::BinaryView
+                | DataType::FixedSizeBinary(_)
         )
 }
 
@@ -60,37 +65,118 @@ pub fn rank(array: &dyn Array, options: Option<SortOptions>) -> Result<Vec<u32>,
         DataType::LargeUtf8 => bytes_rank(array.as_bytes::<LargeUtf8Type>(), options),
         DataType::Binary => bytes_rank(array.as_bytes::<BinaryType>(), options),
         DataType::LargeBinary => bytes_rank(array.as_bytes::<LargeBinaryType>(), options),
+        DataType::Utf8View => byte_view_rank(array.as_string_view(), options),
+        DataType::BinaryView => byte_view_rank(array.as_binary_view(), options),
+        DataType::FixedSizeBinary(_) => fixed_size_binary_rank(array.as_fixed_size_binary(), options),
         d => return Err(ArrowError::ComputeError(format!("{d:?} not supported in rank")))
     };
     Ok(ranks)
 }
 
-#[inline(never)]
-fn primitive_rank<T: ArrowNativeTypeOp>(
+/// Assigns a rank to each value in `array` like [`rank`], ordering floating point NaN
+/// values according to `nan_ordering`
+///
+/// This is the same as [`rank`] for [`NanOrdering::TotalOrder`] and for arrays that
+/// are not floating point.
+///
+/// ```
+/// # use arrow_array::Float64Array;
+/// # use arrow_ord::rank::rank_with_nan_ordering;
+/// # use arrow_ord::sort::NanOrdering;
+/// let array = Float64Array::from(vec![Some(1.0), Some(f64::NAN), None, Some(0.0)]);
+/// let ranks = rank_with_nan_ordering(&array, None, NanOrdering::First).unwrap();
+/// assert_eq!(ranks, &[4, 2, 1, 3]);
+/// ```
+pub fn rank_with_nan_ordering(
+    array: &dyn Array,
+    options: Option<SortOptions>,
+    nan_ordering: NanOrdering,
+) -> Result<Vec<u32>, ArrowError> {
+    if nan_ordering == NanOrdering::TotalOrder {
+        return rank(array, options);
+    }
+    let options = options.unwrap_or_default();
+    let ranks = match array.data_type() {
+        DataType::Float16 => {
+            primitive_rank_nans_first(array.as_primitive::<Float16Type>(), options)
+        }
+        DataType::Float32 => {
+            primitive_rank_nans_first(array.as_primitive::<Float32Type>(), options)
+        }
+        DataType::Float64 => {
+            primitive_rank_nans_first(array.as_primitive::<Float64Type>(), options)
+        }
+        _ => return rank(array, Some(options)),
+    };
+    Ok(ranks)
+}
+
+/// Returns the valid values of a primitive array, along with their indices
+fn primitive_to_sort<T: ArrowNativeTypeOp>(
     values: &[T],
     nulls: Option<&NullBuffer>,
-    options: SortOptions,
-) -> Vec<u32> {
+) -> Vec<(T, u32)> {
     let len: u32 = values.len().try_into().unwrap();
-    let to_sort = match nulls.filter(|n| n.null_count() > 0) {
+    match nulls.filter(|n| n.null_count() > 0) {
         Some(n) => n
             .valid_indices()
             .map(|idx| (values[idx], idx as u32))
             .collect(),
         None => values.iter().copied().zip(0..len).collect(),
-    };
+    }
+}
+
+#[inline(never)]
+fn primitive_rank<T: ArrowNativeTypeOp>(
+    values: &[T],
+    nulls: Option<&NullBuffer>,
+    options: SortOptions,
+) -> Vec<u32> {
+    let to_sort = primitive_to_sort(values, nulls);
     rank_impl(values.len(), to_sort, options, T::compare, T::is_eq)
 }
 
+#[inline(never)]
+fn primitive_rank_nans_first<T: ArrowPrimitiveType>(
+    array: &PrimitiveArray<T>,
+    options: SortOptions,
+) -> Vec<u32> {
+    let to_sort = primitive_to_sort(array.values(), array.nulls());
+    let cmp = nans_first_compare(options.descending);
+    let eq = move |a, b| cmp(a, b).is_eq();
+    rank_impl(array.len(), to_sort, options, cmp, eq)
+}
+
 #[inline(never)]
 fn bytes_rank<T: ByteArrayType>(array: &GenericByteArray<T>, options: SortOptions) -> Vec<u32> {
+    slice_rank(array, |idx| array.value(idx).as_ref(), options)
+}
+
+#[inline(never)]
+fn byte_view_rank<T: ByteViewType>(
+    array: &GenericByteViewArray<T>,
+    options: SortOptions,
+) -> Vec<u32> {
+    slice_rank(array, |idx| array.value(idx).as_ref(), options)
+}
+
+#[inline(never)]
+fn fixed_size_binary_rank(array: &FixedSizeBinaryArray, options: SortOptions) -> Vec<u32> {
+    slice_rank(array, |idx| array.value(idx), options)
+}
+
+/// Ranks an array whose values can be viewed as byte slices, as returned by `value`
+fn slice_rank<'a, F>(array: &dyn Array, value: F, options: SortOptions) -> Vec<u32>
+where
+    F: Fn(usize) -> &'a [u8],
+{
     let to_sort: Vec<(&[u8], u32)> = match array.nulls().filter(|n| n.null_count() > 0) {
         Some(n) => n
             .valid_indices()
-            .map(|idx| (array.value(idx).as_ref(), idx as u32))
+            .map(|idx| (value(idx), idx as u32))
             .collect(),
         None => (0..array.len())
-            .map(|idx| (array.value(idx).as_ref(), idx as u32))
+            .map(|idx| (value(idx), idx as u32))
             .collect(),
     };
     rank_impl(array.len(), to_sort, options, Ord::cmp, PartialEq::eq)
@@ -225,6 +311,21 @@ mod tests {
     use super::*;
     use arrow_array::*;
 
+    #[test]
+    fn test_primitive_nans_first() {
+        let a = Float64Array::from(vec![Some(1.0), Some(f64::NAN), None, Some(0.0)]);
+
+        let res = rank_with_nan_ordering(&a, None, NanOrdering::First).unwrap();
+        assert_eq!(res, &[4, 2, 1, 3]);
+
+        let options = SortOptions::default().desc();
+        let res = rank_with_nan_ordering(&a, Some(options), NanOrdering::First).unwrap();
+        assert_eq!(res, &[3, 2, 1, 4]);
+
+        let res = rank_with_nan_ordering(&a, None, NanOrdering::TotalOrder).unwrap();
+        assert_eq!(res, rank(&a, None).unwrap());
+    }
+
     #[test]
     fn test_primitive() {
         let descending = SortOptions {
@@ -358,4 +459,46 @@ mod tests {
         let res = rank(&values, None).unwrap();
         assert_eq!(res, &[3, 1, 4, 3]);
     }
+
+    #[test]
+    fn test_byte_view() {
+        let v = vec![
+            Some("foo"),
+            None,
+            Some("a longer string value"),
+            Some("bar"),
+            Some("foo"),
+        ];
+        let values = StringViewArray::from(v);
+        let res = rank(&values, None).unwrap();
+        assert_eq!(res, &[5, 1, 2, 3, 5]);
+
+        let nulls_last = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+        let res = rank(&values, Some(nulls_last)).unwrap();
+        assert_eq!(res, &[4, 5, 1, 2, 4]);
+
+        let v: Vec<&[u8]> = vec![&[1, 2], &[0], &[1, 2, 3], &[1, 2]];
+        let values = BinaryViewArray::from(v);
+        let res = rank(&values, None).unwrap();
+        assert_eq!(res, &[3, 1, 4, 3]);
+    }
+
+    #[test]
+    fn test_fixed_size_binary() {
+        let v = vec![Some([1, 2]), None, Some([0, 9]), Some([1, 2])];
+        let values =
+            FixedSizeBinaryArray::try_from_sparse_iter_with_size(v.into_iter(), 2).unwrap();
+        let res = rank(&values, None).unwrap();
+        assert_eq!(res, &[4, 1, 2, 4]);
+
+        let descending = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+        let res = rank(&values, Some(descending)).unwrap();
+        assert_eq!(res, &[3, 1, 4, 3]);
+    }
 }
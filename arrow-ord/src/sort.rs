@@ -23,16 +23,56 @@ use arrow_array::cast::*;
 use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::ArrowNativeType;
-use arrow_buffer::BooleanBufferBuilder;
+use arrow_buffer::{BooleanBufferBuilder, OffsetBuffer};
 use arrow_data::{ArrayDataBuilder, ByteView, MAX_INLINE_VIEW_LEN};
 use arrow_schema::{ArrowError, DataType};
-use arrow_select::take::take;
+use arrow_select::take::{take, take_record_batch};
 use std::cmp::Ordering;
 use std::sync::Arc;
 
 use crate::rank::{can_rank, rank};
 pub use arrow_schema::SortOptions;
 
+/// The ordering of floating point NaN values relative to other non-null values
+///
+/// This is passed alongside [`SortOptions`] to [`sort_with_nan_ordering`],
+/// [`sort_to_indices_with_nan_ordering`] and [`rank_with_nan_ordering`]. To order
+/// NaN values first in a [`lexsort_to_indices`], sort by the ranks returned by
+/// [`rank_with_nan_ordering`] instead of the floating point column.
+///
+/// [`rank_with_nan_ordering`]: crate::rank::rank_with_nan_ordering
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NanOrdering {
+    /// Order NaN values according to the IEEE 754 totalOrder predicate, i.e. as greater
+    /// than all other values, so that they sort last when ascending and first when
+    /// descending. This is the ordering used by [`sort`] and [`sort_to_indices`]
+    #[default]
+    TotalOrder,
+    /// Order NaN values before all other non-null values, regardless of
+    /// [`SortOptions::descending`]
+    First,
+}
+
+/// Returns a comparator that orders NaN values before all other values once its result
+/// is reversed for a `descending` sort, as required by [`NanOrdering::First`], and
+/// otherwise orders values by [`ArrowNativeTypeOp::compare`]
+pub(crate) fn nans_first_compare<T: ArrowNativeTypeOp>(
+    descending: bool,
+) -> impl Fn(T, T) -> Ordering + Copy + Send + Sync + 'static {
+    // NaN is the only value for which `partial_cmp` with itself is `None`
+    let is_nan = |v: T| v.partial_cmp(&v).is_none();
+    let nan = match descending {
+        true => Ordering::Greater,
+        false => Ordering::Less,
+    };
+    move |a, b| match (is_nan(a), is_nan(b)) {
+        (true, true) => Ordering::Equal,
+        (true, false) => nan,
+        (false, true) => nan.reverse(),
+        (false, false) => a.compare(b),
+    }
+}
+
 /// Sort the `ArrayRef` using `SortOptions`.
 ///
 /// Performs a sort on values and indices. Nulls are ordered according
@@ -125,6 +165,34 @@ where
     ))
 }
 
+/// Sort the `ArrayRef` using `SortOptions`, ordering floating point NaN values
+/// according to `nan_ordering`
+///
+/// This is the same as [`sort`] for [`NanOrdering::TotalOrder`] and for arrays that
+/// are not floating point.
+///
+/// # Example
+/// ```rust
+/// # use arrow_array::Float64Array;
+/// # use arrow_ord::sort::{sort_with_nan_ordering, NanOrdering, SortOptions};
+/// let array = Float64Array::from(vec![1.0, f64::NAN, -1.0]);
+/// let sorted = sort_with_nan_ordering(&array, None, NanOrdering::First).unwrap();
+/// let sorted = sorted.as_any().downcast_ref::<Float64Array>().unwrap();
+/// assert!(sorted.value(0).is_nan());
+/// assert_eq!(&sorted.values()[1..], &[-1.0, 1.0]);
+/// ```
+pub fn sort_with_nan_ordering(
+    values: &dyn Array,
+    options: Option<SortOptions>,
+    nan_ordering: NanOrdering,
+) -> Result<ArrayRef, ArrowError> {
+    if nan_ordering == NanOrdering::TotalOrder || !values.data_type().is_floating() {
+        return sort(values, options);
+    }
+    let indices = sort_to_indices_with_nan_ordering(values, options, nan_ordering, None)?;
+    take(values, &indices, None)
+}
+
 /// Sort the `ArrayRef` partially.
 ///
 /// If `limit` is specified, the resulting array will contain only
@@ -314,6 +382,55 @@ pub fn sort_to_indices(
     })
 }
 
+/// Sort elements from `ArrayRef` into an unsigned integer (`UInt32Array`) of indices,
+/// ordering floating point NaN values according to `nan_ordering`. `limit` is an option
+/// for [partial_sort].
+///
+/// This is the same as [`sort_to_indices`] for [`NanOrdering::TotalOrder`] and for
+/// arrays that are not floating point.
+pub fn sort_to_indices_with_nan_ordering(
+    array: &dyn Array,
+    options: Option<SortOptions>,
+    nan_ordering: NanOrdering,
+    limit: Option<usize>,
+) -> Result<UInt32Array, ArrowError> {
+    if nan_ordering == NanOrdering::TotalOrder {
+        return sort_to_indices(array, options, limit);
+    }
+    let options = options.unwrap_or_default();
+    let (v, n) = match array.data_type().is_floating() {
+        true => partition_validity(array),
+        false => return sort_to_indices(array, Some(options), limit),
+    };
+    Ok(match array.data_type() {
+        DataType::Float16 => {
+            sort_primitive_nans_first(array.as_primitive::<Float16Type>(), v, n, options, limit)
+        }
+        DataType::Float32 => {
+            sort_primitive_nans_first(array.as_primitive::<Float32Type>(), v, n, options, limit)
+        }
+        DataType::Float64 => {
+            sort_primitive_nans_first(array.as_primitive::<Float64Type>(), v, n, options, limit)
+        }
+        _ => unreachable!(),
+    })
+}
+
+fn sort_primitive_nans_first<T: ArrowPrimitiveType>(
+    values: &PrimitiveArray<T>,
+    value_indices: Vec<u32>,
+    nulls: Vec<u32>,
+    options: SortOptions,
+    limit: Option<usize>,
+) -> UInt32Array {
+    let mut valids = value_indices
+        .into_iter()
+        .map(|index| (index, values.value(index as usize)))
+        .collect::<Vec<(u32, T::Native)>>();
+    let cmp = nans_first_compare(options.descending);
+    sort_impl(options, &mut valids, &nulls, limit, cmp).into()
+}
+
 fn sort_boolean(
     values: &BooleanArray,
     value_indices: Vec<u32>,
@@ -328,6 +445,9 @@ fn sort_boolean(
     sort_impl(options, &mut valids, &null_indices, limit, |a, b| a.cmp(&b)).into()
 }
 
+/// The minimum number of values for which 16-bit integers are sorted using [`sort_counting`]
+const COUNTING_SORT_16_BIT_MIN_LEN: usize = 1 << 13;
+
 fn sort_primitive<T: ArrowPrimitiveType>(
     values: &PrimitiveArray<T>,
     value_indices: Vec<u32>,
@@ -335,6 +455,18 @@ fn sort_primitive<T: ArrowPrimitiveType>(
     options: SortOptions,
     limit: Option<usize>,
 ) -> UInt32Array {
+    match values.data_type() {
+        DataType::Int8 | DataType::UInt8 => {
+            return sort_counting(values, value_indices, nulls, options, limit);
+        }
+        DataType::Int16 | DataType::UInt16
+            if value_indices.len() >= COUNTING_SORT_16_BIT_MIN_LEN =>
+        {
+            return sort_counting(values, value_indices, nulls, options, limit);
+        }
+        _ => {}
+    }
+
     let mut valids = value_indices
         .into_iter()
         .map(|index| (index, values.value(index as usize)))
@@ -342,6 +474,55 @@ fn sort_primitive<T: ArrowPrimitiveType>(
     sort_impl(options, &mut valids, &nulls, limit, T::Native::compare).into()
 }
 
+/// Sorts an array of 8 or 16-bit integers using a counting sort
+///
+/// This runs in linear time, with one bucket for each possible value, and so
+/// outperforms comparison sorts for these types, other than for short 16-bit arrays
+fn sort_counting<T: ArrowPrimitiveType>(
+    values: &PrimitiveArray<T>,
+    value_indices: Vec<u32>,
+    nulls: Vec<u32>,
+    options: SortOptions,
+    limit: Option<usize>,
+) -> UInt32Array {
+    let num_buckets = 1_usize << (T::Native::get_byte_width() * 8);
+    debug_assert!(num_buckets <= 1 << 16);
+    // Maps values to buckets in sort order, offsetting signed integers so the
+    // most negative value is in the first bucket
+    let bias = match T::DATA_TYPE.is_signed_integer() {
+        true => num_buckets / 2,
+        false => 0,
+    };
+    let bucket = |idx: u32| {
+        let b = values.value(idx as usize).as_usize().wrapping_add(bias) & (num_buckets - 1);
+        match options.descending {
+            true => num_buckets - 1 - b,
+            false => b,
+        }
+    };
+
+    // Compute the position in the output of the first value of each bucket
+    let mut positions = vec![0_u32; num_buckets];
+    value_indices
+        .iter()
+        .for_each(|&idx| positions[bucket(idx)] += 1);
+    let mut total = 0;
+    for p in positions.iter_mut() {
+        let count = *p;
+        *p = total;
+        total += count;
+    }
+
+    let mut sorted = vec![0_u32; value_indices.len()];
+    for idx in value_indices {
+        let p = &mut positions[bucket(idx)];
+        sorted[*p as usize] = idx;
+        *p += 1;
+    }
+    let num_valids = sorted.len();
+    with_nulls(sorted.into_iter(), num_valids, &nulls, options, limit).into()
+}
+
 fn sort_bytes<T: ByteArrayType>(
     values: &GenericByteArray<T>,
     value_indices: Vec<u32>,
@@ -534,13 +715,36 @@ fn sort_fixed_size_binary(
     nulls: Vec<u32>,
     options: SortOptions,
     limit: Option<usize>,
+) -> UInt32Array {
+    // Common widths, such as 16 byte UUIDs, are compared as big-endian integers,
+    // which orders them the same as comparing their bytes
+    match values.value_length() {
+        4 => sort_fixed_width_keys(values, value_indices, nulls, options, limit, |v| {
+            u32::from_be_bytes(v.try_into().unwrap())
+        }),
+        8 => sort_fixed_width_keys(values, value_indices, nulls, options, limit, |v| {
+            u64::from_be_bytes(v.try_into().unwrap())
+        }),
+        16 => sort_fixed_width_keys(values, value_indices, nulls, options, limit, |v| {
+            u128::from_be_bytes(v.try_into().unwrap())
+        }),
+        _ => sort_fixed_width_keys(values, value_indices, nulls, options, limit, |v| v),
+    }
+}
+
+fn sort_fixed_width_keys<'a, K: Ord + Copy>(
+    values: &'a FixedSizeBinaryArray,
+    value_indices: Vec<u32>,
+    nulls: Vec<u32>,
+    options: SortOptions,
+    limit: Option<usize>,
+    key: impl Fn(&'a [u8]) -> K,
 ) -> UInt32Array {
     let mut valids = value_indices
-        .iter()
-        .copied()
-        .map(|index| (index, values.value(index as usize)))
-        .collect::<Vec<(u32, &[u8])>>();
-    sort_impl(options, &mut valids, &nulls, limit, Ord::cmp).into()
+        .into_iter()
+        .map(|index| (index, key(values.value(index as usize))))
+        .collect::<Vec<(u32, K)>>();
+    sort_impl(options, &mut valids, &nulls, limit, |a, b| a.cmp(&b)).into()
 }
 
 fn sort_dictionary<K: ArrowDictionaryKeyType>(
@@ -644,17 +848,35 @@ fn sort_impl<T: Copy>(
         true => sort_unstable_by(valids, v_limit, |a, b| cmp(a.1, b.1).reverse()),
     }
 
-    let len = valids.len() + nulls.len();
+    with_nulls(
+        valids.iter().map(|x| x.0),
+        valids.len(),
+        nulls,
+        options,
+        limit,
+    )
+}
+
+/// Combines the `num_valids` sorted indices of the valid values with those of the
+/// nulls, returning at most `limit` indices
+fn with_nulls(
+    valids: impl Iterator<Item = u32>,
+    num_valids: usize,
+    nulls: &[u32],
+    options: SortOptions,
+    limit: Option<usize>,
+) -> Vec<u32> {
+    let len = num_valids + nulls.len();
     let limit = limit.unwrap_or(len).min(len);
     let mut out = Vec::with_capacity(len);
     match options.nulls_first {
         true => {
             out.extend_from_slice(&nulls[..nulls.len().min(limit)]);
             let remaining = limit - out.len();
-            out.extend(valids.iter().map(|x| x.0).take(remaining));
+            out.extend(valids.take(remaining));
         }
         false => {
-            out.extend(valids.iter().map(|x| x.0).take(limit));
+            out.extend(valids.take(limit));
             let remaining = limit - out.len();
             out.extend_from_slice(&nulls[..remaining])
         }
@@ -922,6 +1144,154 @@ pub fn lexsort(columns: &[SortColumn], limit: Option<usize>) -> Result<Vec<Array
         .collect()
 }
 
+/// A column of a [`RecordBatch`], identified by name, to sort by
+///
+/// See [`sort_batch`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SortExpr {
+    /// The name of the column to sort by
+    pub column_name: String,
+    /// Sort options for this column
+    pub options: SortOptions,
+}
+
+impl SortExpr {
+    /// Create a new [`SortExpr`] sorting by `column_name` with `options`
+    pub fn new(column_name: impl Into<String>, options: SortOptions) -> Self {
+        Self {
+            column_name: column_name.into(),
+            options,
+        }
+    }
+}
+
+/// Sort the rows of a [`RecordBatch`] lexicographically by the columns in `sort_exprs`
+///
+/// This is equivalent to calling [`lexsort_to_indices`], or [`sort_to_indices`] if there
+/// is a single column, followed by [`take_record_batch`].
+///
+/// Returns an error if any column in `sort_exprs` is not present in `batch`, or is of an
+/// unsupported type.
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+/// # use arrow_ord::sort::{SortExpr, SortOptions, sort_batch};
+/// let batch = RecordBatch::try_from_iter([
+///     ("a", Arc::new(Int32Array::from(vec![2, 1, 2])) as ArrayRef),
+///     ("b", Arc::new(StringArray::from(vec!["x", "y", "z"])) as ArrayRef),
+/// ])
+/// .unwrap();
+///
+/// let sorted = sort_batch(
+///     &batch,
+///     &[
+///         SortExpr::new("a", SortOptions::default()),
+///         SortExpr::new("b", SortOptions::default().desc()),
+///     ],
+/// )
+/// .unwrap();
+///
+/// let expected = RecordBatch::try_from_iter([
+///     ("a", Arc::new(Int32Array::from(vec![1, 2, 2])) as ArrayRef),
+///     ("b", Arc::new(StringArray::from(vec!["y", "z", "x"])) as ArrayRef),
+/// ])
+/// .unwrap();
+/// assert_eq!(sorted, expected);
+/// ```
+pub fn sort_batch(batch: &RecordBatch, sort_exprs: &[SortExpr]) -> Result<RecordBatch, ArrowError> {
+    let indices = match sort_exprs {
+        [expr] => {
+            let column = batch.column(batch.schema_ref().index_of(&expr.column_name)?);
+            sort_to_indices(column, Some(expr.options), None)?
+        }
+        _ => {
+            let columns = sort_exprs
+                .iter()
+                .map(|expr| {
+                    Ok(SortColumn {
+                        values: Arc::clone(
+                            batch.column(batch.schema_ref().index_of(&expr.column_name)?),
+                        ),
+                        options: Some(expr.options),
+                    })
+                })
+                .collect::<Result<Vec<_>, ArrowError>>()?;
+            lexsort_to_indices(&columns, None)?
+        }
+    };
+    take_record_batch(batch, &indices)
+}
+
+/// Sort the rows of a [`RecordBatch`] by the column named `column_name`
+///
+/// See [`sort_batch`] for sorting by multiple columns
+pub fn sort_batch_by_column(
+    batch: &RecordBatch,
+    column_name: &str,
+    options: SortOptions,
+) -> Result<RecordBatch, ArrowError> {
+    sort_batch(batch, &[SortExpr::new(column_name, options)])
+}
+
+/// Sort the entries of each row of a [`MapArray`] by key, in ascending order
+///
+/// The values of each row are reordered to match their keys, and the returned
+/// [`MapArray`] is marked as having sorted keys. Entries with equal keys keep
+/// their relative order.
+///
+/// # Example
+/// ```
+/// # use arrow_array::Array;
+/// # use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_schema::DataType;
+/// # use arrow_ord::sort::sort_map_keys;
+/// let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+/// builder.keys().append_value("b");
+/// builder.values().append_value(2);
+/// builder.keys().append_value("a");
+/// builder.values().append_value(1);
+/// builder.append(true).unwrap();
+/// let map = builder.finish();
+///
+/// let sorted = sort_map_keys(&map).unwrap();
+/// let keys = sorted.keys().as_string::<i32>();
+/// assert_eq!(keys.iter().flatten().collect::<Vec<_>>(), ["a", "b"]);
+/// assert_eq!(sorted.values().as_primitive::<Int32Type>().values(), &[1, 2]);
+/// assert!(matches!(sorted.data_type(), DataType::Map(_, true)));
+/// ```
+pub fn sort_map_keys(map: &MapArray) -> Result<MapArray, ArrowError> {
+    let keys = map.keys();
+    let cmp = make_comparator(keys.as_ref(), keys.as_ref(), SortOptions::default())?;
+
+    let offsets = map.offsets();
+    let first = offsets[0].as_usize();
+    let last = offsets.last().unwrap().as_usize();
+
+    let mut indices: Vec<u32> = (first as u32..last as u32).collect();
+    for w in offsets.windows(2) {
+        let range = w[0].as_usize() - first..w[1].as_usize() - first;
+        indices[range].sort_by(|a, b| cmp(*a as usize, *b as usize));
+    }
+
+    let entries = take(map.entries(), &UInt32Array::from(indices), None)?;
+    let offsets = offsets.iter().map(|o| o - offsets[0]).collect::<Vec<_>>();
+    let DataType::Map(field, _) = map.data_type() else {
+        unreachable!()
+    };
+
+    MapArray::try_new(
+        Arc::clone(field),
+        OffsetBuffer::new(offsets.into()),
+        entries.as_struct().clone(),
+        map.nulls().cloned(),
+        true,
+    )
+}
+
 /// Sort elements lexicographically from a list of `ArrayRef` into an unsigned integer
 /// (`UInt32Array`) of indices.
 ///
@@ -1093,9 +1463,10 @@ impl<const N: usize> FixedLexicographicalComparator<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rank::rank_with_nan_ordering;
     use arrow_array::builder::{
-        BooleanBuilder, FixedSizeListBuilder, GenericListBuilder, Int64Builder, ListBuilder,
-        PrimitiveRunBuilder,
+        BooleanBuilder, FixedSizeListBuilder, GenericListBuilder, Int32Builder, Int64Builder,
+        ListBuilder, MapBuilder, PrimitiveRunBuilder, StringBuilder,
     };
     use arrow_buffer::{NullBuffer, i256};
     use arrow_schema::Field;
@@ -2940,6 +3311,157 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sort_primitives_nans_first() {
+        let sort_nans_first = |data: Vec<Option<f64>>, descending, nulls_first, limit| {
+            let array = Float64Array::from(data);
+            let options = Some(SortOptions::new(descending, nulls_first));
+            let indices =
+                sort_to_indices_with_nan_ordering(&array, options, NanOrdering::First, limit)
+                    .unwrap();
+            let sorted = take(&array, &indices, None).unwrap();
+            if limit.is_none() {
+                let expected = sort_with_nan_ordering(&array, options, NanOrdering::First);
+                assert_eq!(&sorted, &expected.unwrap());
+            }
+            sorted
+        };
+        let data = vec![
+            Some(1.0),
+            Some(f64::NAN),
+            None,
+            Some(-1.0),
+            Some(f64::NAN),
+            Some(0.0),
+        ];
+        let expected = |values: Vec<Option<f64>>| Arc::new(Float64Array::from(values)) as ArrayRef;
+
+        assert_eq!(
+            &sort_nans_first(data.clone(), false, true, None),
+            &expected(vec![
+                None,
+                Some(f64::NAN),
+                Some(f64::NAN),
+                Some(-1.0),
+                Some(0.0),
+                Some(1.0),
+            ])
+        );
+        assert_eq!(
+            &sort_nans_first(data.clone(), true, false, None),
+            &expected(vec![
+                Some(f64::NAN),
+                Some(f64::NAN),
+                Some(1.0),
+                Some(0.0),
+                Some(-1.0),
+                None,
+            ])
+        );
+        assert_eq!(
+            &sort_nans_first(data.clone(), true, true, None),
+            &expected(vec![
+                None,
+                Some(f64::NAN),
+                Some(f64::NAN),
+                Some(1.0),
+                Some(0.0),
+                Some(-1.0),
+            ])
+        );
+
+        // limit
+        assert_eq!(
+            &sort_nans_first(data, false, false, Some(3)),
+            &expected(vec![Some(f64::NAN), Some(f64::NAN), Some(-1.0)])
+        );
+
+        let array = Float32Array::from(vec![Some(1.0), Some(f32::NAN), None, Some(-1.0)]);
+        let indices = |descending| {
+            let options = Some(SortOptions::new(descending, false));
+            sort_to_indices_with_nan_ordering(&array, options, NanOrdering::First, None).unwrap()
+        };
+        assert_eq!(indices(false), UInt32Array::from(vec![1, 3, 0, 2]));
+        assert_eq!(indices(true), UInt32Array::from(vec![1, 0, 3, 2]));
+
+        // NaN ordering has no effect on types without NaN
+        let array = Int32Array::from(vec![Some(2), None, Some(1)]);
+        let options = Some(SortOptions::new(true, false));
+        let sorted = sort_with_nan_ordering(&array, options, NanOrdering::First).unwrap();
+        assert_eq!(
+            sorted.as_ref(),
+            &Int32Array::from(vec![Some(2), Some(1), None])
+        );
+
+        // The default ordering is totalOrder
+        let array = Float64Array::from(vec![f64::NAN, 1.0]);
+        let sorted = sort_with_nan_ordering(&array, None, NanOrdering::default()).unwrap();
+        assert_eq!(&sorted, &sort(&array, None).unwrap());
+    }
+
+    #[test]
+    fn test_lex_sort_nans_first() {
+        // Sort by the ranks of the floating point column to order its NaN values first
+        let floats = Float64Array::from(vec![1.0, f64::NAN, 1.0, f64::NAN]);
+        let options = Some(SortOptions::new(true, false));
+        let ranks = rank_with_nan_ordering(&floats, options, NanOrdering::First).unwrap();
+        let input = vec![
+            SortColumn {
+                values: Arc::new(UInt32Array::from(ranks)) as ArrayRef,
+                options: None,
+            },
+            SortColumn {
+                values: Arc::new(Int32Array::from(vec![1, 2, 3, 4])) as ArrayRef,
+                options: None,
+            },
+        ];
+        let indices = lexsort_to_indices(&input, None).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![1, 3, 0, 2]));
+    }
+
+    #[test]
+    fn test_sort_batch() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(Int32Array::from(vec![Some(2), None, Some(1), Some(2)])) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(StringArray::from(vec!["w", "x", "y", "z"])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let take_rows =
+            |indices: Vec<u32>| take_record_batch(&batch, &UInt32Array::from(indices)).unwrap();
+
+        let sorted = sort_batch_by_column(&batch, "b", SortOptions::default().desc()).unwrap();
+        assert_eq!(sorted, take_rows(vec![3, 2, 1, 0]));
+
+        let sorted = sort_batch(
+            &batch,
+            &[
+                SortExpr::new("a", SortOptions::default().nulls_last()),
+                SortExpr::new("b", SortOptions::default().desc()),
+            ],
+        )
+        .unwrap();
+        assert_eq!(sorted, take_rows(vec![2, 3, 0, 1]));
+
+        let err = sort_batch_by_column(&batch, "c", SortOptions::default()).unwrap_err();
+        assert!(
+            err.to_string().contains("Unable to get field named \"c\""),
+            "{err}"
+        );
+
+        let err = sort_batch(&batch, &[]).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Sort requires at least one column"),
+            "{err}"
+        );
+    }
+
     #[test]
     fn test_sort_to_indices_strings() {
         test_sort_to_indices_string_arrays(
@@ -4510,6 +5032,63 @@ mod tests {
         assert_eq!(&d[0..last], &before[0..last]);
     }
 
+    /// Checks the counting sort of small integer types against a comparison sort
+    fn test_sort_counting_rand<T>(len: usize)
+    where
+        T: ArrowPrimitiveType,
+        T::Native: Ord,
+        rand::distr::StandardUniform: rand::distr::Distribution<T::Native>,
+    {
+        let mut rng = StdRng::seed_from_u64(42);
+        let data: Vec<Option<T::Native>> = (0..len)
+            .map(|_| rng.random_bool(0.9).then(|| rng.random()))
+            .collect();
+        let array: PrimitiveArray<T> = data.iter().copied().collect();
+
+        for (descending, nulls_first) in
+            [(false, false), (false, true), (true, false), (true, true)]
+        {
+            let options = SortOptions {
+                descending,
+                nulls_first,
+            };
+            let mut expected = data.clone();
+            expected.sort_by(|a, b| match (a, b) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) if nulls_first => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) if nulls_first => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) if descending => b.cmp(a),
+                (Some(a), Some(b)) => a.cmp(b),
+            });
+            for limit in [None, Some(0), Some(len / 10), Some(len)] {
+                let indices = sort_to_indices(&array, Some(options), limit).unwrap();
+                let sorted: Vec<_> = indices.values().iter().map(|i| data[*i as usize]).collect();
+                let expected = &expected[..limit.unwrap_or(len)];
+                assert_eq!(sorted, expected, "{options:?} {limit:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_counting() {
+        test_sort_counting_rand::<Int8Type>(1000);
+        test_sort_counting_rand::<UInt8Type>(1000);
+        test_sort_counting_rand::<Int16Type>(COUNTING_SORT_16_BIT_MIN_LEN * 2);
+        test_sort_counting_rand::<UInt16Type>(COUNTING_SORT_16_BIT_MIN_LEN * 2);
+
+        let array = Int8Array::from(vec![Some(-1), Some(i8::MIN), None, Some(i8::MAX), Some(0)]);
+        let indices = sort_to_indices(&array, None, None).unwrap();
+        assert_eq!(indices.values(), &[2, 1, 0, 4, 3]);
+
+        // Equal values retain the order of their indices
+        let array = UInt8Array::from(vec![2, 1, 2, 1]);
+        let options = SortOptions::default().desc();
+        let indices = sort_to_indices(&array, Some(options), None).unwrap();
+        assert_eq!(indices.values(), &[0, 2, 1, 3]);
+    }
+
     #[test]
     fn test_sort_int8_dicts() {
         let keys = Int8Array::from(vec![Some(1_i8), None, Some(2), None, Some(2), Some(0)]);
@@ -5282,4 +5861,184 @@ mod tests {
         assert_eq!(sorted_strings, expected);
         assert_eq!(sorted_strings.len(), limit);
     }
+
+    /// Sorting a dictionary must order rows identically to sorting its unpacked values
+    fn test_sort_dictionary_matches_unpacked(values: ArrayRef) {
+        let mut rng = StdRng::seed_from_u64(42);
+        let keys: Int16Array = (0..100)
+            .map(|_| {
+                rng.random_bool(0.9)
+                    .then(|| rng.random_range(0..values.len() as i16))
+            })
+            .collect();
+        let dict = DictionaryArray::new(keys.clone(), values.clone());
+        let unpacked = take(values.as_ref(), &keys, None).unwrap();
+        let tiebreak: ArrayRef = Arc::new(Int32Array::from_iter_values(0..100));
+
+        for descending in [false, true] {
+            for nulls_first in [false, true] {
+                let options = SortOptions {
+                    descending,
+                    nulls_first,
+                };
+                let indices = sort_to_indices(&dict, Some(options), None).unwrap();
+                let actual = take(unpacked.as_ref(), &indices, None).unwrap();
+                let expected = sort(unpacked.as_ref(), Some(options)).unwrap();
+                assert_eq!(actual.as_ref(), expected.as_ref(), "{options:?}");
+
+                let limited = sort_to_indices(&dict, Some(options), Some(10)).unwrap();
+                assert_eq!(limited.values(), &indices.values()[..10]);
+
+                let lex_columns = |values: ArrayRef| {
+                    vec![
+                        SortColumn {
+                            values,
+                            options: Some(options),
+                        },
+                        SortColumn {
+                            values: tiebreak.clone(),
+                            options: None,
+                        },
+                    ]
+                };
+                let lex_dict = lexsort_to_indices(&lex_columns(Arc::new(dict.clone())), None);
+                let lex_unpacked = lexsort_to_indices(&lex_columns(unpacked.clone()), None);
+                assert_eq!(lex_dict.unwrap(), lex_unpacked.unwrap(), "{options:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_dictionary_non_string_values() {
+        // Dictionaries may legally contain duplicate and null values
+        let decimals = Decimal128Array::from(vec![Some(500), Some(-3), None, Some(500), Some(42)])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        test_sort_dictionary_matches_unpacked(Arc::new(decimals));
+
+        let decimals = Decimal256Array::from(vec![
+            i256::from_i128(7),
+            i256::MAX,
+            i256::from_i128(-7),
+            i256::from_i128(7),
+        ]);
+        test_sort_dictionary_matches_unpacked(Arc::new(decimals));
+
+        let timestamps = TimestampNanosecondArray::from(vec![Some(10), None, Some(-5), Some(10)])
+            .with_timezone("+01:00");
+        test_sort_dictionary_matches_unpacked(Arc::new(timestamps));
+
+        let dates = Date32Array::from(vec![3, 1, 2, 1]);
+        test_sort_dictionary_matches_unpacked(Arc::new(dates));
+
+        let floats = Float64Array::from(vec![f64::NAN, -0.0, 0.0, 1.5, f64::NAN]);
+        test_sort_dictionary_matches_unpacked(Arc::new(floats));
+
+        let booleans = BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+        test_sort_dictionary_matches_unpacked(Arc::new(booleans));
+
+        let strings = StringViewArray::from(vec![
+            Some("a string that is not inlined"),
+            Some("b"),
+            None,
+            Some("a string that is not inlined"),
+            Some("a"),
+        ]);
+        test_sort_dictionary_matches_unpacked(Arc::new(strings));
+
+        let binary = BinaryViewArray::from(vec![&b"zz"[..], b"", b"zz", b"a"]);
+        test_sort_dictionary_matches_unpacked(Arc::new(binary));
+
+        let fixed = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            vec![Some([1, 2]), None, Some([0, 9]), Some([1, 2])].into_iter(),
+            2,
+        )
+        .unwrap();
+        test_sort_dictionary_matches_unpacked(Arc::new(fixed));
+    }
+
+    #[test]
+    fn test_sort_map_keys() {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        for (k, v) in [("c", 3), ("a", 1), ("b", 2)] {
+            builder.keys().append_value(k);
+            builder.values().append_value(v);
+        }
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        for (k, v) in [("z", 26), ("y", 25)] {
+            builder.keys().append_value(k);
+            builder.values().append_value(v);
+        }
+        builder.append(true).unwrap();
+        builder.append(true).unwrap();
+        let map = builder.finish();
+
+        let sorted = sort_map_keys(&map).unwrap();
+        assert!(matches!(sorted.data_type(), DataType::Map(_, true)));
+        assert_eq!(sorted.offsets().as_ref(), &[0, 3, 3, 5, 5]);
+        assert_eq!(sorted.nulls(), map.nulls());
+        let keys = sorted.keys().as_string::<i32>();
+        assert_eq!(
+            keys.iter().flatten().collect::<Vec<_>>(),
+            ["a", "b", "c", "y", "z"]
+        );
+        let values = sorted.values().as_primitive::<Int32Type>();
+        assert_eq!(values.values(), &[1, 2, 3, 25, 26]);
+
+        // Sliced maps produce offsets starting at zero
+        let sorted = sort_map_keys(&map.slice(2, 2)).unwrap();
+        assert_eq!(sorted.offsets().as_ref(), &[0, 2, 2]);
+        let keys = sorted.keys().as_string::<i32>();
+        assert_eq!(keys.iter().flatten().collect::<Vec<_>>(), ["y", "z"]);
+        let values = sorted.values().as_primitive::<Int32Type>();
+        assert_eq!(values.values(), &[25, 26]);
+    }
+
+    #[test]
+    fn test_sort_fixed_size_binary_widths() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for width in [3, 4, 8, 16] {
+            let data = (0..200)
+                .map(|_| {
+                    rng.random_bool(0.9).then(|| {
+                        // Share a prefix so that most comparisons depend on the last byte
+                        let mut v = vec![0x80; width];
+                        v[width - 1] = rng.random();
+                        v
+                    })
+                })
+                .collect::<Vec<_>>();
+            let array = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+                data.iter().cloned(),
+                width as i32,
+            )
+            .unwrap();
+
+            for options in [
+                SortOptions::default(),
+                SortOptions::default().desc(),
+                SortOptions::default().with_nulls_first(false),
+            ] {
+                let sorted = sort(&array, Some(options)).unwrap();
+                let sorted = sorted.as_fixed_size_binary();
+
+                let mut expected = data.clone();
+                expected.sort_by(|a, b| match (a, b) {
+                    (None, None) => Ordering::Equal,
+                    (None, Some(_)) if options.nulls_first => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (Some(_), None) if options.nulls_first => Ordering::Greater,
+                    (Some(_), None) => Ordering::Less,
+                    (Some(a), Some(b)) if options.descending => b.cmp(a),
+                    (Some(a), Some(b)) => a.cmp(b),
+                });
+                let actual = sorted
+                    .iter()
+                    .map(|v| v.map(|v| v.to_vec()))
+                    .collect::<Vec<_>>();
+                assert_eq!(actual, expected, "width {width} {options:?}");
+            }
+        }
+    }
 }
@@ -66,11 +66,71 @@ pub enum ArrowError {
     OffsetOverflowError(usize),
 }
 
+/// The category of an error, see [`ArrowError::kind`]
+///
+/// Unlike the variants of an error type, which reflect how an error was constructed,
+/// the categories are intended to be stable and shared by the Arrow crates, allowing
+/// callers to decide how to handle an error without matching on its message.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The data or schema uses a feature that is not supported
+    Unsupported,
+    /// The data is corrupt or truncated, or violates the specification of its format
+    Corrupt,
+    /// An I/O error occurred reading or writing data
+    Io,
+    /// A schema is invalid, or is incompatible with the data
+    Schema,
+    /// An error from an external library or callback
+    External,
+    /// Any other error, such as invalid arguments or API misuse
+    Other,
+}
+
 impl ArrowError {
     /// Wraps an external error in an `ArrowError`.
     pub fn from_external_error(error: Box<dyn Error + Send + Sync>) -> Self {
         Self::ExternalError(error)
     }
+
+    /// Returns the [`ErrorKind`] of this error
+    ///
+    /// An [`ArrowError::ExternalError`] wrapping an [`std::io::Error`] or another
+    /// [`ArrowError`] is categorized by the wrapped error, any other external error
+    /// is categorized as [`ErrorKind::External`]
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            ArrowError::IoError(_, _) => ErrorKind::Io,
+            ArrowError::NotYetImplemented(_) => ErrorKind::Unsupported,
+            ArrowError::SchemaError(_) => ErrorKind::Schema,
+            ArrowError::ExternalError(e) => {
+                if e.is::<std::io::Error>() {
+                    ErrorKind::Io
+                } else if let Some(e) = e.downcast_ref::<ArrowError>() {
+                    e.kind()
+                } else {
+                    ErrorKind::External
+                }
+            }
+            ArrowError::ParseError(_)
+            | ArrowError::CsvError(_)
+            | ArrowError::JsonError(_)
+            | ArrowError::AvroError(_)
+            | ArrowError::IpcError(_) => ErrorKind::Corrupt,
+            ArrowError::CastError(_)
+            | ArrowError::ComputeError(_)
+            | ArrowError::InvalidArgumentError(_)
+            | ArrowError::MemoryError(_)
+            | ArrowError::DivideByZero
+            | ArrowError::ArithmeticOverflow(_)
+            | ArrowError::DictionaryKeyOverflowError
+            | ArrowError::RunEndIndexOverflowError
+            | ArrowError::OffsetOverflowError(_)
+            | ArrowError::ParquetError(_) => ErrorKind::Other,
+            ArrowError::CDataInterface(_) => ErrorKind::External,
+        }
+    }
 }
 
 impl From<std::io::Error> for ArrowError {
@@ -176,4 +236,26 @@ mod test {
 
         assert!(matches!(source, ArrowError::DivideByZero));
     }
+
+    #[test]
+    fn error_kind() {
+        let io = || std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "eof");
+        let schema = ArrowError::SchemaError("x".into());
+        assert_eq!(schema.kind(), ErrorKind::Schema);
+        assert_eq!(ArrowError::CastError("x".into()).kind(), ErrorKind::Other);
+        assert_eq!(ArrowError::IpcError("x".into()).kind(), ErrorKind::Corrupt);
+        assert_eq!(ArrowError::from(io()).kind(), ErrorKind::Io);
+        assert_eq!(
+            ArrowError::NotYetImplemented("x".into()).kind(),
+            ErrorKind::Unsupported
+        );
+
+        let nested = ArrowError::ExternalError(Box::new(schema));
+        assert_eq!(nested.kind(), ErrorKind::Schema);
+        let nested = ArrowError::ExternalError(Box::new(io()));
+        assert_eq!(nested.kind(), ErrorKind::Io);
+        let utf8 = String::from_utf8(vec![0xff]).unwrap_err();
+        let external = ArrowError::ExternalError(Box::new(utf8));
+        assert_eq!(external.kind(), ErrorKind::External);
+    }
 }
@@ -31,6 +31,7 @@
 //! ```
 
 use crate::dictionary::{merge_dictionary_values, should_merge_dictionary_values};
+use crate::field::merge_fields;
 use arrow_array::builder::{
     BooleanBuilder, GenericByteBuilder, GenericByteViewBuilder, PrimitiveBuilder,
 };
@@ -42,7 +43,7 @@ use arrow_buffer::{
 };
 use arrow_data::ArrayDataBuilder;
 use arrow_data::transform::{Capacities, MutableArrayData};
-use arrow_schema::{ArrowError, DataType, FieldRef, Fields, SchemaRef};
+use arrow_schema::{ArrowError, DataType, Field, FieldRef, Fields, SchemaRef};
 use std::{collections::HashSet, ops::Add, sync::Arc};
 
 fn binary_capacity<T: ByteArrayType>(arrays: &[&dyn Array]) -> Capacities {
@@ -521,6 +522,35 @@ fn concat_fallback(arrays: &[&dyn Array], capacity: Capacities) -> Result<ArrayR
     Ok(make_array(mutable.freeze()))
 }
 
+/// Concatenate arrays with their corresponding fields, see [`concat`]
+///
+/// Returns the concatenated array along with its [`FieldRef`], preserving any
+/// extension type and other metadata of the fields. The fields must all have the
+/// same data type and extension type, and the result is nullable if any of them
+/// are nullable.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, StringArray};
+/// # use arrow_schema::{DataType, Field};
+/// # use arrow_select::concat::concat_with_field;
+/// let metadata = [("ARROW:extension:name".to_string(), "arrow.json".to_string())];
+/// let field = Arc::new(Field::new("a", DataType::Utf8, false).with_metadata(metadata.into()));
+/// let a = Arc::new(StringArray::from(vec!["{}"])) as ArrayRef;
+/// let b = Arc::new(StringArray::from(vec!["[]"])) as ArrayRef;
+///
+/// let (field, array) = concat_with_field(&[(field.clone(), a), (field, b)]).unwrap();
+/// assert_eq!(field.extension_type_name(), Some("arrow.json"));
+/// assert_eq!(array.len(), 2);
+/// ```
+pub fn concat_with_field(
+    values: &[(FieldRef, ArrayRef)],
+) -> Result<(FieldRef, ArrayRef), ArrowError> {
+    let field = merge_fields(values)?;
+    let arrays: Vec<_> = values.iter().map(|(_, a)| a.as_ref()).collect();
+    Ok((field, concat(&arrays)?))
+}
+
 /// Concatenates `batches` together into a single [`RecordBatch`].
 ///
 /// The output batch has the specified `schemas`; The schema of the
@@ -557,6 +587,179 @@ pub fn concat_batches<'a>(
     RecordBatch::try_new(schema.clone(), arrays)
 }
 
+/// Options for [`concat_batches_unify`]
+#[derive(Debug, Clone, Default)]
+pub struct UnifyOptions {
+    drop_extra_columns: bool,
+}
+
+impl UnifyOptions {
+    /// Create a new [`UnifyOptions`] with the default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// If `true`, columns (and struct children) present in an input batch but not
+    /// in the target schema are dropped. If `false`, the default, such columns
+    /// result in an error
+    pub fn with_drop_extra_columns(mut self, drop_extra_columns: bool) -> Self {
+        self.drop_extra_columns = drop_extra_columns;
+        self
+    }
+}
+
+/// Concatenates `batches` into a single [`RecordBatch`] with the provided target `schema`,
+/// where the schemas of the input batches may differ from `schema`
+///
+/// Unlike [`concat_batches`], which requires every batch to have the same schema, this
+/// unifies each batch with `schema` before concatenating, as may be required after schema
+/// evolution:
+///
+/// * Columns are matched to the fields of `schema` by name, and reordered accordingly
+/// * Fields of `schema` missing from a batch are filled with nulls, provided they are
+///   nullable, otherwise an error is returned
+/// * Columns of a batch missing from `schema` are an error, unless
+///   [`UnifyOptions::with_drop_extra_columns`] is set, in which case they are dropped
+/// * Differences in field metadata and nullability are ignored, with the output taking
+///   those of `schema`
+/// * The above rules are applied recursively to the children of struct columns
+///
+/// Columns whose data types cannot be unified result in an error identifying the
+/// path of the offending field. Dictionary columns are merged as in [`concat`].
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{Int32Array, RecordBatch, StringArray};
+/// # use arrow_schema::{DataType, Field, Schema};
+/// # use arrow_select::concat::{UnifyOptions, concat_batches_unify};
+/// let v1 = RecordBatch::try_from_iter([
+///     ("id", Arc::new(Int32Array::from(vec![1, 2])) as _),
+/// ]).unwrap();
+/// // A later batch with columns reordered and a new nullable column
+/// let v2 = RecordBatch::try_from_iter([
+///     ("name", Arc::new(StringArray::from(vec!["c"])) as _),
+///     ("id", Arc::new(Int32Array::from(vec![3])) as _),
+/// ]).unwrap();
+///
+/// let schema = Arc::new(Schema::new(vec![
+///     Field::new("id", DataType::Int32, false),
+///     Field::new("name", DataType::Utf8, true),
+/// ]));
+/// let batch = concat_batches_unify(&schema, [&v1, &v2], &UnifyOptions::new()).unwrap();
+///
+/// let expected = RecordBatch::try_new(schema, vec![
+///     Arc::new(Int32Array::from(vec![1, 2, 3])),
+///     Arc::new(StringArray::from(vec![None, None, Some("c")])),
+/// ]).unwrap();
+/// assert_eq!(batch, expected);
+/// ```
+pub fn concat_batches_unify<'a>(
+    schema: &SchemaRef,
+    input_batches: impl IntoIterator<Item = &'a RecordBatch>,
+    options: &UnifyOptions,
+) -> Result<RecordBatch, ArrowError> {
+    let batches = input_batches
+        .into_iter()
+        .map(|batch| {
+            let columns = unify_columns(
+                "",
+                schema.fields(),
+                batch.schema_ref().fields(),
+                batch.columns(),
+                batch.num_rows(),
+                options,
+            )?;
+            let mut batch_options = RecordBatchOptions::default();
+            batch_options.row_count = Some(batch.num_rows());
+            RecordBatch::try_new_with_options(schema.clone(), columns, &batch_options)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    concat_batches(schema, &batches)
+}
+
+/// Returns the columns for `target`, taken by name from `columns` with the
+/// corresponding `fields`, and unified with the target data types
+fn unify_columns(
+    path: &str,
+    target: &Fields,
+    fields: &Fields,
+    columns: &[ArrayRef],
+    len: usize,
+    options: &UnifyOptions,
+) -> Result<Vec<ArrayRef>, ArrowError> {
+    let field_path = |name: &str| match path {
+        "" => name.to_string(),
+        _ => format!("{path}.{name}"),
+    };
+
+    if !options.drop_extra_columns {
+        if let Some(extra) = fields.iter().find(|f| target.find(f.name()).is_none()) {
+            return Err(ArrowError::SchemaError(format!(
+                "Field '{}' not found in target schema",
+                field_path(extra.name())
+            )));
+        }
+    }
+
+    target
+        .iter()
+        .map(|target_field| {
+            let path = field_path(target_field.name());
+            match fields.find(target_field.name()) {
+                Some((idx, _)) => unify_array(&path, target_field, &columns[idx], options),
+                None if target_field.is_nullable() => {
+                    Ok(new_null_array(target_field.data_type(), len))
+                }
+                None => Err(ArrowError::SchemaError(format!(
+                    "Non-nullable field '{path}' is missing and cannot be filled with nulls"
+                ))),
+            }
+        })
+        .collect()
+}
+
+/// Returns `array` with the data type of `target`
+fn unify_array(
+    path: &str,
+    target: &Field,
+    array: &ArrayRef,
+    options: &UnifyOptions,
+) -> Result<ArrayRef, ArrowError> {
+    if array.data_type() == target.data_type() {
+        return Ok(Arc::clone(array));
+    }
+
+    match (target.data_type(), array.data_type()) {
+        (DataType::Struct(target_fields), DataType::Struct(fields)) => {
+            let array = array.as_struct();
+            let columns = unify_columns(
+                path,
+                target_fields,
+                fields,
+                array.columns(),
+                array.len(),
+                options,
+            )?;
+            let array = StructArray::try_new_with_length(
+                target_fields.clone(),
+                columns,
+                array.nulls().cloned(),
+                array.len(),
+            )?;
+            Ok(Arc::new(array))
+        }
+        // The types only differ in nested field names, metadata or nullability
+        (t, a) if t.equals_datatype(a) => {
+            let data = array.to_data().into_builder().data_type(t.clone());
+            Ok(make_array(data.build()?))
+        }
+        (t, a) => Err(ArrowError::SchemaError(format!(
+            "Field '{path}' has data type {a} which cannot be unified with {t}"
+        ))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -564,6 +767,7 @@ mod tests {
         GenericListBuilder, Int64Builder, ListViewBuilder, StringDictionaryBuilder,
     };
     use arrow_schema::{Field, Schema};
+    use std::collections::HashMap;
     use std::fmt::Debug;
 
     #[test]
@@ -586,6 +790,166 @@ mod tests {
         assert_eq!(re.num_rows(), 200);
     }
 
+    #[test]
+    fn test_concat_batches_unify() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Float64, true),
+        ]));
+        let b1 = RecordBatch::try_from_iter([
+            ("b", Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef),
+            ("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+        ])
+        .unwrap();
+        let b2 = RecordBatch::try_from_iter([
+            ("c", Arc::new(Float64Array::from(vec![1.5])) as ArrayRef),
+            ("a", Arc::new(Int32Array::from(vec![3])) as ArrayRef),
+            ("extra", Arc::new(Int32Array::from(vec![0])) as ArrayRef),
+        ])
+        .unwrap();
+
+        let err = concat_batches_unify(&schema, [&b1, &b2], &UnifyOptions::new()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Field 'extra' not found in target schema"
+        );
+
+        let options = UnifyOptions::new().with_drop_extra_columns(true);
+        let batch = concat_batches_unify(&schema, [&b1, &b2], &options).unwrap();
+        let expected = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(StringArray::from(vec![Some("x"), Some("y"), None])),
+                Arc::new(Float64Array::from(vec![None, None, Some(1.5)])),
+            ],
+        )
+        .unwrap();
+        assert_eq!(batch, expected);
+
+        // Non-nullable fields cannot be filled with nulls
+        let b3 =
+            RecordBatch::try_from_iter([("b", Arc::new(StringArray::from(vec!["z"])) as ArrayRef)])
+                .unwrap();
+        let err = concat_batches_unify(&schema, [&b1, &b3], &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Non-nullable field 'a' is missing and cannot be filled with nulls"
+        );
+
+        // No batches
+        let batch = concat_batches_unify(&schema, [], &options).unwrap();
+        assert_eq!(batch.num_rows(), 0);
+        assert_eq!(batch.schema(), schema);
+    }
+
+    #[test]
+    fn test_concat_batches_unify_struct() {
+        let target_children = Fields::from(vec![
+            Field::new("x", DataType::Int32, true),
+            Field::new("y", DataType::Utf8, true),
+        ]);
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "s",
+            DataType::Struct(target_children.clone()),
+            true,
+        )]));
+
+        // Struct with reordered children and a missing child
+        let s1 = StructArray::new(
+            Fields::from(vec![Field::new("y", DataType::Utf8, true)]),
+            vec![Arc::new(StringArray::from(vec!["a", "b"])) as ArrayRef],
+            Some(NullBuffer::from(vec![true, false])),
+        );
+        let s2 = StructArray::new(
+            Fields::from(vec![
+                Field::new("y", DataType::Utf8, true),
+                Field::new("x", DataType::Int32, true),
+            ]),
+            vec![
+                Arc::new(StringArray::from(vec!["c"])) as ArrayRef,
+                Arc::new(Int32Array::from(vec![7])) as ArrayRef,
+            ],
+            None,
+        );
+        let b1 = RecordBatch::try_from_iter([("s", Arc::new(s1) as ArrayRef)]).unwrap();
+        let b2 = RecordBatch::try_from_iter([("s", Arc::new(s2) as ArrayRef)]).unwrap();
+
+        let batch = concat_batches_unify(&schema, [&b1, &b2], &UnifyOptions::new()).unwrap();
+        let expected = StructArray::new(
+            target_children,
+            vec![
+                Arc::new(Int32Array::from(vec![None, None, Some(7)])) as ArrayRef,
+                Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef,
+            ],
+            Some(NullBuffer::from(vec![true, false, true])),
+        );
+        assert_eq!(batch.column(0).as_struct(), &expected);
+
+        // Type mismatches report the path of the nested field
+        let s3 = StructArray::new(
+            Fields::from(vec![Field::new("x", DataType::Utf8, true)]),
+            vec![Arc::new(StringArray::from(vec!["oops"])) as ArrayRef],
+            None,
+        );
+        let b3 = RecordBatch::try_from_iter([("s", Arc::new(s3) as ArrayRef)]).unwrap();
+        let err = concat_batches_unify(&schema, [&b1, &b3], &UnifyOptions::new()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Field 's.x' has data type Utf8 which cannot be unified with Int32"
+        );
+    }
+
+    #[test]
+    fn test_concat_batches_unify_metadata_and_dictionary() {
+        let dict_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let list_field = Field::new_list_field(DataType::Int32, true).with_metadata(
+            std::collections::HashMap::from([("k".to_string(), "v".to_string())]),
+        );
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("d", dict_type.clone(), true),
+            Field::new("l", DataType::List(Arc::new(list_field)), true),
+        ]));
+
+        let batch = |dict: Vec<&str>, list: Vec<Option<Vec<Option<i32>>>>| {
+            let dict: DictionaryArray<Int32Type> = dict.into_iter().collect();
+            let list = ListArray::from_iter_primitive::<Int32Type, _, _>(list);
+            RecordBatch::try_from_iter([
+                ("l", Arc::new(list) as ArrayRef),
+                ("d", Arc::new(dict) as ArrayRef),
+            ])
+            .unwrap()
+        };
+        let b1 = batch(
+            vec!["a", "b", "a"],
+            vec![Some(vec![Some(1)]), None, Some(vec![])],
+        );
+        let b2 = batch(vec!["b", "c"], vec![None, Some(vec![Some(2), None])]);
+        // The list field metadata differs from that of the target schema
+        assert_ne!(
+            b1.schema().field(0).data_type(),
+            schema.field(1).data_type()
+        );
+
+        let out = concat_batches_unify(&schema, [&b1, &b2], &UnifyOptions::new()).unwrap();
+        assert_eq!(out.schema(), schema);
+
+        let dict = out.column(0).as_dictionary::<Int32Type>();
+        let values: Vec<_> = dict
+            .downcast_dict::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(
+            values,
+            vec![Some("a"), Some("b"), Some("a"), Some("b"), Some("c")]
+        );
+        assert_eq!(out.column(1).len(), 5);
+        assert_eq!(out.column(1).null_count(), 2);
+        assert!(out.column(1).is_null(3));
+    }
+
     #[test]
     fn test_concat_one_element_vec() {
         let arr = Arc::new(PrimitiveArray::<Int64Type>::from(vec![
@@ -1900,4 +2264,42 @@ mod tests {
         assert_eq!(values.values(), &[10, 20, 30]);
         assert_eq!(&[2, 3, 5], run_ends);
     }
+
+    #[test]
+    fn test_concat_with_field() {
+        let metadata: HashMap<_, _> =
+            [("ARROW:extension:name".to_string(), "arrow.json".to_string())].into();
+        let a = Arc::new(Field::new("a", DataType::Utf8, false).with_metadata(metadata.clone()));
+        let b = Arc::new(Field::new("b", DataType::Utf8, true).with_metadata(metadata));
+        let values = Arc::new(StringArray::from(vec!["{}", "[]"])) as ArrayRef;
+
+        let (field, array) = concat_with_field(&[
+            (Arc::clone(&a), Arc::clone(&values)),
+            (Arc::clone(&b), Arc::clone(&values)),
+        ])
+        .unwrap();
+        assert_eq!(field.name(), "a");
+        assert!(field.is_nullable());
+        assert_eq!(field.extension_type_name(), Some("arrow.json"));
+        assert_eq!(array.len(), 4);
+
+        // Concatenating arrays with different extension types is ambiguous
+        let c = Arc::new(Field::new("c", DataType::Utf8, true));
+        let err = concat_with_field(&[
+            (Arc::clone(&a), Arc::clone(&values)),
+            (c, Arc::clone(&values)),
+        ])
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot combine field 'a' with extension type Some(\"arrow.json\") and field 'c' with extension type None"
+        );
+
+        let int = Arc::new(Int32Array::from(vec![1])) as ArrayRef;
+        let err = concat_with_field(&[(a, int)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Field 'a' has data type Utf8 but array has data type Int32"
+        );
+    }
 }
@@ -0,0 +1,87 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers for the `*_with_field` kernels, which propagate the [`Field`] of their input
+
+use arrow_array::{Array, ArrayRef};
+use arrow_schema::{ArrowError, Field, FieldRef};
+use std::sync::Arc;
+
+/// Returns an error if `array` does not have the data type of `field`
+pub(crate) fn check_field(field: &Field, array: &dyn Array) -> Result<(), ArrowError> {
+    if field.data_type() != array.data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Field '{}' has data type {} but array has data type {}",
+            field.name(),
+            field.data_type(),
+            array.data_type()
+        )));
+    }
+    Ok(())
+}
+
+/// Returns `field`, made nullable if `array` contains nulls
+pub(crate) fn nullable_if_nulls(field: &FieldRef, array: &dyn Array) -> FieldRef {
+    match field.is_nullable() || array.logical_null_count() == 0 {
+        true => Arc::clone(field),
+        false => Arc::new(field.as_ref().clone().with_nullable(true)),
+    }
+}
+
+/// Returns the [`Field`] of the result of combining the arrays in `values`
+///
+/// All fields must have the same data type and extension type, as otherwise the
+/// extension type of the result would be ambiguous. The result is the first field,
+/// which is nullable if any of the fields are nullable.
+pub(crate) fn merge_fields(values: &[(FieldRef, ArrayRef)]) -> Result<FieldRef, ArrowError> {
+    let Some((first, _)) = values.first() else {
+        return Err(ArrowError::InvalidArgumentError(
+            "Cannot combine an empty list of fields".to_string(),
+        ));
+    };
+
+    let mut nullable = first.is_nullable();
+    for (field, array) in values {
+        check_field(field, array.as_ref())?;
+        if field.data_type() != first.data_type() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot combine field '{}' of type {} with field '{}' of type {}",
+                first.name(),
+                first.data_type(),
+                field.name(),
+                field.data_type()
+            )));
+        }
+        if field.extension_type_name() != first.extension_type_name()
+            || field.extension_type_metadata() != first.extension_type_metadata()
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot combine field '{}' with extension type {:?} and field '{}' with extension type {:?}",
+                first.name(),
+                first.extension_type_name(),
+                field.name(),
+                field.extension_type_name()
+            )));
+        }
+        nullable |= field.is_nullable();
+    }
+
+    Ok(match nullable == first.is_nullable() {
+        true => Arc::clone(first),
+        false => Arc::new(first.as_ref().clone().with_nullable(true)),
+    })
+}
@@ -33,6 +33,8 @@ use arrow_data::bit_iterator::{BitIndexIterator, BitSliceIterator};
 use arrow_data::transform::MutableArrayData;
 use arrow_schema::*;
 
+use crate::field::check_field;
+
 /// If the filter selects more than this fraction of rows, use
 /// [`SlicesIterator`] to copy ranges of values. Otherwise iterate
 /// over individual rows using [`IndexIterator`]
@@ -216,6 +218,20 @@ pub fn filter(values: &dyn Array, predicate: &BooleanArray) -> Result<ArrayRef,
     filter_array(values, &predicate)
 }
 
+/// Returns a filtered `values` with the corresponding `field`, see [`filter`]
+///
+/// Returns the filtered values along with their [`FieldRef`], preserving any extension
+/// type and other metadata of `field`. Returns an error if `values` does not have the
+/// data type of `field`.
+pub fn filter_with_field(
+    field: &FieldRef,
+    values: &dyn Array,
+    predicate: &BooleanArray,
+) -> Result<(FieldRef, ArrayRef), ArrowError> {
+    check_field(field, values)?;
+    Ok((Arc::clone(field), filter(values, predicate)?))
+}
+
 /// Returns a filtered [RecordBatch] where the corresponding elements of
 /// `predicate` are true.
 ///
@@ -1023,6 +1039,7 @@ mod tests {
     use rand::distr::{Alphanumeric, StandardUniform};
     use rand::prelude::*;
     use rand::rng;
+    use std::collections::HashMap;
 
     macro_rules! def_temporal_test {
         ($test:ident, $array_type: ident, $data: expr) => {
@@ -2350,4 +2367,16 @@ mod tests {
         let filter = FilterBuilder::new(&predicate).build();
         filter_native(&values, &filter);
     }
+
+    #[test]
+    fn test_filter_with_field() {
+        let metadata: HashMap<_, _> = [("key".to_string(), "value".to_string())].into();
+        let field = Arc::new(Field::new("a", DataType::Int32, false).with_metadata(metadata));
+        let values = Int32Array::from(vec![1, 2, 3]);
+        let predicate = BooleanArray::from(vec![true, false, true]);
+
+        let (filtered_field, filtered) = filter_with_field(&field, &values, &predicate).unwrap();
+        assert_eq!(filtered_field, field);
+        assert_eq!(filtered.as_primitive::<Int32Type>().values(), &[1, 3]);
+    }
 }
@@ -0,0 +1,491 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the [`hash`] kernel, computing stable 64-bit hashes of the rows of arrays
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{ArrowNativeType, ToByteSlice};
+use arrow_schema::{ArrowError, DataType};
+use twox_hash::XxHash3_64;
+
+/// The hash of a null value, see [`hash`]
+pub const NULL_HASH: u64 = 0x8445_d61a_4e77_4912;
+
+/// Computes a 64-bit hash of each row of `arrays`, starting from `seed`
+///
+/// This is intended for use by hash-based algorithms such as grouping and joins,
+/// where rows that are equal must have equal hashes. All arrays must have the same
+/// length, which is the length of the returned array. If `arrays` is empty, an empty
+/// array is returned.
+///
+/// # Algorithm
+///
+/// The hashes are stable, that is they will not change across releases or platforms,
+/// and are computed using the 64-bit variant of [XXH3] as follows.
+///
+/// The hash of each value is first computed, as:
+///
+/// * [`NULL_HASH`] for a null value, at any level of nesting
+/// * For booleans, the XXH3 of a single byte, `0` or `1`
+/// * For other primitive types, the XXH3 of the little-endian bytes of the value,
+///   with floating point values hashed by their bit pattern
+/// * For strings and binary values, the XXH3 of their bytes
+/// * For dictionary and run-end encoded arrays, the hash of the referenced value
+/// * For lists, the XXH3 of the number of elements as a little-endian `u64`,
+///   combined with the hash of each element in turn
+/// * For maps, the hash of the list of their entries
+/// * For structs, the XXH3 of the number of fields as a little-endian `u64`,
+///   combined with the hash of each field in turn
+///
+/// Where the hash `h` is combined with a value hash `v` by computing the XXH3 of
+/// the little-endian bytes of `v`, with `h` as the seed.
+///
+/// The hash of each row is then `seed`, combined with the value hash of each column
+/// in turn.
+///
+/// Consequently, values that are logically equal hash the same regardless of their
+/// physical representation. For example, dictionary encoded strings, [`StringArray`],
+/// [`LargeStringArray`] and [`StringViewArray`] with the same values produce equal
+/// hashes, as do the different list representations. Equal values of different
+/// primitive types, such as `1_i32` and `1_i64`, will generally hash differently.
+///
+/// # Errors
+///
+/// Returns an error if the arrays have different lengths, or contain a union array
+///
+/// # Example
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, DictionaryArray, Int32Array, StringArray};
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::hash::hash;
+/// let strings: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "a"]));
+/// let ints: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 1]));
+/// let hashes = hash(&[strings, ints.clone()], 0).unwrap();
+/// assert_eq!(hashes.value(0), hashes.value(2));
+/// assert_ne!(hashes.value(0), hashes.value(1));
+///
+/// // Dictionary encoded values hash the same as their values
+/// let dict: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
+/// assert_eq!(hash(&[Arc::new(dict), ints], 0).unwrap(), hashes);
+/// ```
+///
+/// [XXH3]: https://github.com/Cyan4973/xxHash/blob/dev/doc/xxhash_spec.md
+pub fn hash(arrays: &[ArrayRef], seed: u64) -> Result<UInt64Array, ArrowError> {
+    let Some(len) = arrays.first().map(|a| a.len()) else {
+        return Ok(UInt64Array::from(Vec::<u64>::new()));
+    };
+    if arrays.iter().any(|a| a.len() != len) {
+        return Err(ArrowError::InvalidArgumentError(
+            "all arrays to hash must have the same length".to_string(),
+        ));
+    }
+
+    let mut hashes = vec![seed; len];
+    for array in arrays {
+        let values = value_hashes(array.as_ref())?;
+        hashes
+            .iter_mut()
+            .zip(values)
+            .for_each(|(h, v)| *h = combine(*h, v));
+    }
+    Ok(hashes.into())
+}
+
+#[inline]
+fn combine(h: u64, v: u64) -> u64 {
+    XxHash3_64::oneshot_with_seed(h, &v.to_le_bytes())
+}
+
+#[inline]
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    XxHash3_64::oneshot(bytes)
+}
+
+/// Returns the hash of each value of `array`
+fn value_hashes(array: &dyn Array) -> Result<Vec<u64>, ArrowError> {
+    let mut hashes = downcast_primitive_array! {
+        array => primitive_hashes(array),
+        DataType::Null => vec![NULL_HASH; array.len()],
+        DataType::Boolean => array
+            .as_boolean()
+            .values()
+            .iter()
+            .map(|v| hash_bytes(&[v as u8]))
+            .collect(),
+        DataType::Utf8 => bytes_hashes(array.as_string::<i32>()),
+        DataType::LargeUtf8 => bytes_hashes(array.as_string::<i64>()),
+        DataType::Binary => bytes_hashes(array.as_binary::<i32>()),
+        DataType::LargeBinary => bytes_hashes(array.as_binary::<i64>()),
+        DataType::Utf8View => bytes_hashes(array.as_string_view()),
+        DataType::BinaryView => bytes_hashes(array.as_binary_view()),
+        DataType::FixedSizeBinary(_) => bytes_hashes(array.as_fixed_size_binary()),
+        DataType::List(_) => {
+            let list = array.as_list::<i32>();
+            list_hashes(list.values().as_ref(), list.offsets().windows(2).map(|w| (w[0].as_usize(), w[1].as_usize())))?
+        }
+        DataType::LargeList(_) => {
+            let list = array.as_list::<i64>();
+            list_hashes(list.values().as_ref(), list.offsets().windows(2).map(|w| (w[0].as_usize(), w[1].as_usize())))?
+        }
+        DataType::ListView(_) => {
+            let list = array.as_list_view::<i32>();
+            let ranges = list.offsets().iter().zip(list.sizes().iter());
+            list_hashes(list.values().as_ref(), ranges.map(|(o, s)| (o.as_usize(), (o + s).as_usize())))?
+        }
+        DataType::LargeListView(_) => {
+            let list = array.as_list_view::<i64>();
+            let ranges = list.offsets().iter().zip(list.sizes().iter());
+            list_hashes(list.values().as_ref(), ranges.map(|(o, s)| (o.as_usize(), (o + s).as_usize())))?
+        }
+        DataType::FixedSizeList(_, size) => {
+            let list = array.as_fixed_size_list();
+            let size = *size as usize;
+            let ranges = (0..list.len()).map(|i| (i * size, (i + 1) * size));
+            list_hashes(list.values().as_ref(), ranges)?
+        }
+        DataType::Map(_, _) => {
+            let map = array.as_map();
+            let entries: &dyn Array = map.entries();
+            list_hashes(entries, map.offsets().windows(2).map(|w| (w[0].as_usize(), w[1].as_usize())))?
+        }
+        DataType::Struct(fields) => {
+            let start = hash_bytes(&(fields.len() as u64).to_le_bytes());
+            let mut hashes = vec![start; array.len()];
+            for column in array.as_struct().columns() {
+                let values = value_hashes(column.as_ref())?;
+                hashes.iter_mut().zip(values).for_each(|(h, v)| *h = combine(*h, v));
+            }
+            hashes
+        }
+        DataType::Dictionary(_, _) => downcast_dictionary_array! {
+            array => {
+                let values = value_hashes(array.values().as_ref())?;
+                array.keys().values().iter().map(|k| values.get(k.as_usize()).copied().unwrap_or(NULL_HASH)).collect()
+            }
+            t => return Err(ArrowError::NotYetImplemented(format!("hash not supported for {t}")))
+        }
+        DataType::RunEndEncoded(_, _) => downcast_run_array! {
+            array => {
+                let values = value_hashes(array.values().as_ref())?;
+                let logical: Vec<u64> = (0..array.len() as u64).collect();
+                array.get_physical_indices(&logical)?.into_iter().map(|i| values[i]).collect()
+            }
+            t => return Err(ArrowError::NotYetImplemented(format!("hash not supported for {t}")))
+        }
+        t => return Err(ArrowError::NotYetImplemented(format!("hash not supported for {t}")))
+    };
+
+    // Also handles the null keys and values of dictionaries, and the null values of
+    // run-end encoded arrays
+    if let Some(nulls) = array.logical_nulls() {
+        nulls
+            .iter()
+            .zip(hashes.iter_mut())
+            .filter(|(valid, _)| !valid)
+            .for_each(|(_, h)| *h = NULL_HASH);
+    }
+    Ok(hashes)
+}
+
+fn primitive_hashes<T: ArrowPrimitiveType>(array: &PrimitiveArray<T>) -> Vec<u64> {
+    array
+        .values()
+        .iter()
+        .map(|v| hash_bytes(v.to_byte_slice()))
+        .collect()
+}
+
+fn bytes_hashes<A>(array: A) -> Vec<u64>
+where
+    A: ArrayAccessor,
+    A::Item: AsRef<[u8]>,
+{
+    (0..array.len())
+        .map(|i| match array.is_valid(i) {
+            // SAFETY: index is within bounds
+            true => hash_bytes(unsafe { array.value_unchecked(i) }.as_ref()),
+            false => NULL_HASH,
+        })
+        .collect()
+}
+
+/// Returns the hash of each list, whose elements are the `values` within the given
+/// ranges of indices
+fn list_hashes(
+    values: &dyn Array,
+    ranges: impl Iterator<Item = (usize, usize)>,
+) -> Result<Vec<u64>, ArrowError> {
+    let values = value_hashes(values)?;
+    Ok(ranges
+        .map(|(start, end)| {
+            let len = hash_bytes(&((end - start) as u64).to_le_bytes());
+            values[start..end].iter().fold(len, |h, v| combine(h, *v))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::{ListBuilder, MapBuilder, StringBuilder};
+    use arrow_buffer::{NullBuffer, OffsetBuffer, ScalarBuffer};
+    use arrow_schema::Field;
+    use std::sync::Arc;
+
+    fn hash_one(array: impl Array + 'static) -> Vec<u64> {
+        hash(&[Arc::new(array)], 0).unwrap().values().to_vec()
+    }
+
+    #[test]
+    fn test_stable() {
+        // These values must not change across releases
+        let ints = Int32Array::from(vec![Some(1), None, Some(-1)]);
+        assert_eq!(
+            hash_one(ints),
+            vec![
+                combine(0, hash_bytes(&1_i32.to_le_bytes())),
+                combine(0, NULL_HASH),
+                combine(0, hash_bytes(&(-1_i32).to_le_bytes())),
+            ]
+        );
+        // XXH3 of the empty input, from the reference implementation
+        assert_eq!(hash_bytes(b""), 0x2d06_8005_38d3_94c2);
+        assert_eq!(hash_bytes(b"arrow"), 0x6e2c_45f5_0b67_ec95);
+        assert_eq!(combine(0, NULL_HASH), 0x1af0_0465_e60c_2c91);
+
+        let strings: ArrayRef = Arc::new(StringArray::from(vec![Some("arrow"), None]));
+        let bools: ArrayRef = Arc::new(BooleanArray::from(vec![true, false]));
+        let hashes = hash(&[strings, bools], 42).unwrap();
+        let expected = [
+            combine(combine(42, hash_bytes(b"arrow")), hash_bytes(&[1])),
+            combine(combine(42, NULL_HASH), hash_bytes(&[0])),
+        ];
+        assert_eq!(hashes.values(), &expected);
+        assert_eq!(hashes.null_count(), 0);
+    }
+
+    #[test]
+    fn test_multiple_columns() {
+        let a: ArrayRef = Arc::new(StringArray::from(vec!["ab", "a", "ab"]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["c", "bc", "c"]));
+        let hashes = hash(&[a.clone(), b.clone()], 0).unwrap();
+        // Values are not concatenated, so ("ab", "c") and ("a", "bc") differ
+        assert_ne!(hashes.value(0), hashes.value(1));
+        assert_eq!(hashes.value(0), hashes.value(2));
+
+        // The order of the columns and the seed matter
+        assert_ne!(hash(&[b.clone(), a.clone()], 0).unwrap(), hashes);
+        assert_ne!(hash(&[a.clone(), b], 1).unwrap(), hashes);
+
+        let err = hash(&[a, Arc::new(Int32Array::from(vec![1]))], 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: all arrays to hash must have the same length"
+        );
+        assert!(hash(&[], 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_primitive() {
+        let floats = Float64Array::from(vec![Some(1.5), Some(-0.0), Some(0.0), None]);
+        let hashes = hash_one(floats);
+        assert_eq!(hashes[0], combine(0, hash_bytes(&1.5_f64.to_le_bytes())));
+        // Hashed by bit pattern
+        assert_ne!(hashes[1], hashes[2]);
+        assert_eq!(hashes[3], combine(0, NULL_HASH));
+
+        let decimals = Decimal128Array::from(vec![123, 123])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let hashes = hash_one(decimals);
+        assert_eq!(hashes[0], combine(0, hash_bytes(&123_i128.to_le_bytes())));
+        assert_eq!(hashes[0], hashes[1]);
+
+        let sliced = Int64Array::from(vec![1, 2, 3]).slice(1, 2);
+        assert_eq!(hash_one(sliced), hash_one(Int64Array::from(vec![2, 3])));
+
+        assert_eq!(hash_one(NullArray::new(2)), vec![combine(0, NULL_HASH); 2]);
+    }
+
+    #[test]
+    fn test_string_representations() {
+        let values = vec![Some("foo"), None, Some("a longer string value"), Some("")];
+        let expected = hash_one(StringArray::from(values.clone()));
+
+        assert_eq!(hash_one(LargeStringArray::from(values.clone())), expected);
+        assert_eq!(hash_one(StringViewArray::from(values.clone())), expected);
+        let binary: Vec<_> = values.iter().map(|v| v.map(str::as_bytes)).collect();
+        assert_eq!(hash_one(BinaryArray::from(binary.clone())), expected);
+        assert_eq!(hash_one(LargeBinaryArray::from(binary.clone())), expected);
+        assert_eq!(hash_one(BinaryViewArray::from(binary)), expected);
+
+        let dict: DictionaryArray<Int32Type> = values.iter().copied().collect();
+        assert_eq!(hash_one(dict), expected);
+
+        // Null values of the dictionary hash as nulls
+        let dict = DictionaryArray::new(
+            Int8Array::from(vec![0, 1, 2, 3]),
+            Arc::new(StringArray::from(values.clone())),
+        );
+        assert_eq!(hash_one(dict), expected);
+
+        let run = RunArray::<Int32Type>::try_new(
+            &Int32Array::from(vec![2, 3, 5]),
+            &StringArray::from(vec![Some("foo"), None, Some("bar")]),
+        )
+        .unwrap();
+        let expected = hash_one(StringArray::from(vec![
+            Some("foo"),
+            Some("foo"),
+            None,
+            Some("bar"),
+            Some("bar"),
+        ]));
+        assert_eq!(hash_one(run.clone()), expected);
+        assert_eq!(hash_one(run.slice(1, 3)), expected[1..4]);
+
+        let fixed = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            vec![Some(b"ab"), None].into_iter(),
+            2,
+        )
+        .unwrap();
+        assert_eq!(
+            hash_one(fixed),
+            hash_one(BinaryArray::from(vec![Some(b"ab".as_ref()), None]))
+        );
+    }
+
+    #[test]
+    fn test_lists() {
+        let data = vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![]),
+            Some(vec![None]),
+            Some(vec![Some(1), Some(2)]),
+        ];
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(data.clone());
+        let expected = hash_one(list.clone());
+        assert_eq!(expected[0], expected[4]);
+        assert_eq!(expected[1], combine(0, NULL_HASH));
+        // An empty list, a null and a list of a null all differ
+        assert_ne!(expected[1], expected[2]);
+        assert_ne!(expected[2], expected[3]);
+
+        let large = LargeListArray::from_iter_primitive::<Int32Type, _, _>(data.clone());
+        assert_eq!(hash_one(large), expected);
+
+        let view = ListViewArray::from(list.clone());
+        assert_eq!(hash_one(view), expected);
+
+        let sliced = list.slice(2, 3);
+        assert_eq!(hash_one(sliced), expected[2..]);
+
+        // Lists of dictionaries hash as lists of their values
+        let mut builder = ListBuilder::new(StringBuilder::new());
+        builder.append_value([Some("a"), Some("b")]);
+        builder.append_null();
+        let strings = builder.finish();
+        let values: DictionaryArray<Int32Type> = vec!["a", "b"].into_iter().collect();
+        let dicts = ListArray::new(
+            Arc::new(Field::new_list_field(values.data_type().clone(), true)),
+            OffsetBuffer::new(ScalarBuffer::from(vec![0, 2, 2])),
+            Arc::new(values),
+            Some(NullBuffer::from(vec![true, false])),
+        );
+        assert_eq!(hash_one(dicts), hash_one(strings));
+
+        let fixed = FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
+            vec![
+                Some(vec![Some(3), Some(4)]),
+                None,
+                Some(vec![Some(1), Some(2)]),
+            ],
+            2,
+        );
+        let hashes = hash_one(fixed.slice(1, 2));
+        assert_eq!(hashes, [expected[1], expected[0]]);
+    }
+
+    #[test]
+    fn test_struct_and_map() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 1]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y", "x"]));
+        let fields = vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, false),
+        ];
+        let s = StructArray::new(
+            fields.into(),
+            vec![a, b],
+            Some(NullBuffer::from(vec![true, true, false])),
+        );
+        let hashes = hash_one(s);
+        let start = hash_bytes(&2_u64.to_le_bytes());
+        let first = combine(
+            combine(start, hash_bytes(&1_i32.to_le_bytes())),
+            hash_bytes(b"x"),
+        );
+        assert_eq!(hashes[0], combine(0, first));
+        assert_ne!(hashes[0], hashes[1]);
+        assert_eq!(hashes[2], combine(0, NULL_HASH));
+
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), StringBuilder::new());
+        builder.keys().append_value("k");
+        builder.values().append_value("v");
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.keys().append_value("k");
+        builder.values().append_value("v");
+        builder.append(true).unwrap();
+        let map = builder.finish();
+        let hashes = hash_one(map);
+        let entry = combine(combine(start, hash_bytes(b"k")), hash_bytes(b"v"));
+        let expected = combine(hash_bytes(&1_u64.to_le_bytes()), entry);
+        assert_eq!(
+            hashes,
+            [
+                combine(0, expected),
+                combine(0, NULL_HASH),
+                combine(0, expected)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unsupported() {
+        let union = UnionArray::try_new(
+            vec![(0, Arc::new(Field::new("a", DataType::Int32, false)))]
+                .into_iter()
+                .collect(),
+            vec![0].into(),
+            None,
+            vec![Arc::new(Int32Array::from(vec![1])) as ArrayRef],
+        )
+        .unwrap();
+        let err = hash(&[Arc::new(union)], 0).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("Not yet implemented: hash not supported for Union"),
+            "{err}"
+        );
+    }
+}
@@ -19,6 +19,7 @@
 
 use crate::concat::concat;
 use crate::dictionary::{merge_dictionary_values, should_merge_dictionary_values};
+use crate::field::merge_fields;
 use arrow_array::builder::{BooleanBufferBuilder, PrimitiveBuilder};
 use arrow_array::cast::AsArray;
 use arrow_array::types::*;
@@ -26,7 +27,7 @@ use arrow_array::*;
 use arrow_buffer::{ArrowNativeType, BooleanBuffer, MutableBuffer, NullBuffer, OffsetBuffer};
 use arrow_data::ByteView;
 use arrow_data::transform::MutableArrayData;
-use arrow_schema::{ArrowError, DataType, FieldRef, Fields};
+use arrow_schema::{ArrowError, DataType, FieldRef, Fields, Schema};
 use std::sync::Arc;
 
 macro_rules! primitive_helper {
@@ -517,7 +518,9 @@ fn interleave_fallback_dictionary<K: ArrowDictionaryKeyType>(
 
 /// Interleave rows by index from multiple [`RecordBatch`] instances and return a new [`RecordBatch`].
 ///
-/// This function will call [`interleave`] on each array of the [`RecordBatch`] instances and assemble a new [`RecordBatch`].
+/// This function will call [`interleave_with_field`] on each array of the [`RecordBatch`] instances and assemble a new [`RecordBatch`].
+/// The schema of the result is built from the returned fields, so a field is nullable if it is
+/// nullable in any of the [`RecordBatch`] instances.
 ///
 /// # Example
 /// ```
@@ -563,17 +566,40 @@ pub fn interleave_record_batch(
     record_batches: &[&RecordBatch],
     indices: &[(usize, usize)],
 ) -> Result<RecordBatch, ArrowError> {
-    let schema = record_batches[0].schema();
-    let columns = (0..schema.fields().len())
+    let schema = record_batches[0].schema_ref();
+    let (fields, columns): (Vec<_>, Vec<_>) = (0..schema.fields().len())
         .map(|i| {
-            let column_values: Vec<&dyn Array> = record_batches
+            let values: Vec<_> = record_batches
                 .iter()
-                .map(|batch| batch.column(i).as_ref())
+                .map(|batch| {
+                    (
+                        Arc::clone(&batch.schema_ref().fields()[i]),
+                        Arc::clone(batch.column(i)),
+                    )
+                })
                 .collect();
-            interleave(&column_values, indices)
+            interleave_with_field(&values, indices)
         })
-        .collect::<Result<Vec<_>, _>>()?;
-    RecordBatch::try_new(schema, columns)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .unzip();
+    let schema = Schema::new_with_metadata(fields, schema.metadata().clone());
+    RecordBatch::try_new(Arc::new(schema), columns)
+}
+
+/// Interleave arrays with their corresponding fields, see [`interleave`]
+///
+/// Returns the interleaved array along with its [`FieldRef`], preserving any
+/// extension type and other metadata of the fields. The fields must all have the
+/// same data type and extension type, and the result is nullable if any of them
+/// are nullable.
+pub fn interleave_with_field(
+    values: &[(FieldRef, ArrayRef)],
+    indices: &[(usize, usize)],
+) -> Result<(FieldRef, ArrayRef), ArrowError> {
+    let field = merge_fields(values)?;
+    let arrays: Vec<_> = values.iter().map(|(_, a)| a.as_ref()).collect();
+    Ok((field, interleave(&arrays, indices)?))
 }
 
 #[cfg(test)]
@@ -584,6 +610,7 @@ mod tests {
     use arrow_array::types::Int8Type;
     use arrow_buffer::ScalarBuffer;
     use arrow_schema::Field;
+    use std::collections::HashMap;
 
     #[test]
     fn test_primitive() {
@@ -1544,4 +1571,48 @@ mod tests {
             &[3]
         );
     }
+
+    #[test]
+    fn test_interleave_with_field() {
+        let metadata: HashMap<_, _> = [("key".to_string(), "value".to_string())].into();
+        let field = Arc::new(Field::new("a", DataType::Int32, false).with_metadata(metadata));
+        let a = Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef;
+        let b = Arc::new(Int32Array::from(vec![3, 4])) as ArrayRef;
+
+        let values = [(Arc::clone(&field), a), (Arc::clone(&field), b)];
+        let (result_field, result) = interleave_with_field(&values, &[(1, 0), (0, 1)]).unwrap();
+        assert_eq!(result_field, field);
+        assert_eq!(result.as_primitive::<Int32Type>().values(), &[3, 2]);
+
+        // Arrays with different extension types cannot be interleaved
+        let json = Field::new("a", DataType::Utf8, true)
+            .with_metadata([("ARROW:extension:name".to_string(), "arrow.json".to_string())].into());
+        let column = Arc::new(StringArray::from(vec!["{}"])) as ArrayRef;
+        let values = [
+            (Arc::new(json), Arc::clone(&column)),
+            (Arc::new(Field::new("a", DataType::Utf8, true)), column),
+        ];
+        let err = interleave_with_field(&values, &[(0, 0), (1, 0)]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot combine field 'a' with extension type Some(\"arrow.json\") and field 'a' with extension type None"
+        );
+    }
+
+    #[test]
+    fn test_interleave_record_batch_nullable() {
+        let a = Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef;
+        let b = Arc::new(Int32Array::from(vec![Some(3), None])) as ArrayRef;
+        let schema_a = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let schema_b = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let batch_a = RecordBatch::try_new(schema_a, vec![a]).unwrap();
+        let batch_b = RecordBatch::try_new(schema_b.clone(), vec![b]).unwrap();
+
+        let result = interleave_record_batch(&[&batch_a, &batch_b], &[(0, 0), (1, 1)]).unwrap();
+        assert_eq!(result.schema(), schema_b);
+        assert_eq!(
+            result.column(0).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![Some(1), None])
+        );
+    }
 }
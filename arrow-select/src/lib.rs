@@ -27,9 +27,12 @@
 pub mod coalesce;
 pub mod concat;
 pub mod dictionary;
+mod field;
 pub mod filter;
+pub mod hash;
 pub mod interleave;
 pub mod merge;
+pub mod nested;
 pub mod nullif;
 pub mod take;
 pub mod union_extract;
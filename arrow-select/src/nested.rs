@@ -0,0 +1,474 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Element access kernels for nested arrays
+//!
+//! * [`list_element`]: extract a single element from each list
+//! * [`map_keys`], [`map_values`] and [`map_entries`]: project a [`MapArray`] into lists
+
+use crate::take::take;
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Int32Type, Int64Type};
+use arrow_array::*;
+use arrow_buffer::NullBuffer;
+use arrow_schema::{ArrowError, DataType, Field};
+use std::sync::Arc;
+
+/// Options for [`list_element`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ListElementOptions {
+    /// If `true`, return an error if an index is out of bounds for its list,
+    /// otherwise return null for that row (the default)
+    pub error_on_out_of_bounds: bool,
+}
+
+impl ListElementOptions {
+    /// Set [`Self::error_on_out_of_bounds`]
+    pub fn with_error_on_out_of_bounds(mut self, error_on_out_of_bounds: bool) -> Self {
+        self.error_on_out_of_bounds = error_on_out_of_bounds;
+        self
+    }
+}
+
+/// Extract the element at `index` from each list in `array`, following SQL semantics
+///
+/// `index` is 1-based: `1` refers to the first element of a list, and negative
+/// values count backwards from the end of the list, with `-1` referring to the last
+/// element. The index `0` is always out of bounds.
+///
+/// `index` is either a scalar, applied to every list, or an array of the same length
+/// as `array`, containing one index per list, and must be `Int32` or `Int64`.
+///
+/// The result is null where the list or the index is null. If the index is out of
+/// bounds for its list, the result is null, or an error is returned if
+/// [`ListElementOptions::error_on_out_of_bounds`] is set.
+///
+/// Supports [`ListArray`], [`LargeListArray`] and [`FixedSizeListArray`]
+///
+/// ```
+/// # use arrow_array::{Int32Array, Int64Array, ListArray, Scalar};
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::nested::{list_element, ListElementOptions};
+/// let lists = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+///     Some(vec![Some(1), Some(2), Some(3)]),
+///     None,
+///     Some(vec![Some(4)]),
+/// ]);
+/// let options = ListElementOptions::default();
+///
+/// // The last element of every list
+/// let last = Scalar::new(Int64Array::from(vec![-1]));
+/// let result = list_element(&lists, &last, &options).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(vec![Some(3), None, Some(4)]));
+///
+/// // A different element for each list
+/// let indices = Int64Array::from(vec![2, 1, 2]);
+/// let result = list_element(&lists, &indices, &options).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from(vec![Some(2), None, None]));
+/// ```
+pub fn list_element(
+    array: &dyn Array,
+    index: &dyn Datum,
+    options: &ListElementOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let (index, is_scalar) = index.get();
+    if !is_scalar && index.len() != array.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "list_element index array has length {} but list array has length {}",
+            index.len(),
+            array.len()
+        )));
+    }
+    if is_scalar && index.len() != 1 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "list_element scalar index must have length 1, got {}",
+            index.len()
+        )));
+    }
+
+    match array.data_type() {
+        DataType::List(_) => {
+            let list = array.as_list::<i32>();
+            let bounds = list
+                .value_offsets()
+                .windows(2)
+                .map(|w| (w[0] as usize, w[1] as usize));
+            list_element_impl(
+                list.values(),
+                list.nulls(),
+                bounds,
+                index,
+                is_scalar,
+                options,
+            )
+        }
+        DataType::LargeList(_) => {
+            let list = array.as_list::<i64>();
+            let bounds = list
+                .value_offsets()
+                .windows(2)
+                .map(|w| (w[0] as usize, w[1] as usize));
+            list_element_impl(
+                list.values(),
+                list.nulls(),
+                bounds,
+                index,
+                is_scalar,
+                options,
+            )
+        }
+        DataType::FixedSizeList(_, size) => {
+            let list = array.as_fixed_size_list();
+            let size = *size as usize;
+            let bounds = (0..list.len()).map(|i| {
+                let start = list.value_offset(i) as usize;
+                (start, start + size)
+            });
+            list_element_impl(
+                list.values(),
+                list.nulls(),
+                bounds,
+                index,
+                is_scalar,
+                options,
+            )
+        }
+        d => Err(ArrowError::InvalidArgumentError(format!(
+            "list_element expects a list array, got {d}"
+        ))),
+    }
+}
+
+/// Returns the index of `index` within a list spanning `start..end` of its child
+fn resolve_list_index(start: usize, end: usize, index: i64) -> Option<usize> {
+    let len = (end - start) as i64;
+    let position = if (1..=len).contains(&index) {
+        index - 1
+    } else if (-len..0).contains(&index) {
+        len + index
+    } else {
+        return None;
+    };
+    Some(start + position as usize)
+}
+
+fn list_element_impl(
+    values: &ArrayRef,
+    nulls: Option<&NullBuffer>,
+    bounds: impl Iterator<Item = (usize, usize)>,
+    index: &dyn Array,
+    is_scalar: bool,
+    options: &ListElementOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let index: Box<dyn Fn(usize) -> Option<i64>> = match index.data_type() {
+        DataType::Int32 => {
+            let index = index.as_primitive::<Int32Type>();
+            Box::new(move |i| index.is_valid(i).then(|| index.value(i) as i64))
+        }
+        DataType::Int64 => {
+            let index = index.as_primitive::<Int64Type>();
+            Box::new(move |i| index.is_valid(i).then(|| index.value(i)))
+        }
+        d => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "list_element index must be Int32 or Int64, got {d}"
+            )));
+        }
+    };
+
+    let take_indices = bounds
+        .enumerate()
+        .map(|(row, (start, end))| {
+            if nulls.is_some_and(|n| n.is_null(row)) {
+                return Ok(None);
+            }
+            let Some(idx) = index(if is_scalar { 0 } else { row }) else {
+                return Ok(None);
+            };
+            match resolve_list_index(start, end, idx) {
+                Some(position) => Ok(Some(position as u64)),
+                None if options.error_on_out_of_bounds => Err(ArrowError::InvalidArgumentError(
+                    format!(
+                        "list_element index {idx} is out of bounds for list of length {} at row {row}",
+                        end - start
+                    ),
+                )),
+                None => Ok(None),
+            }
+        })
+        .collect::<Result<UInt64Array, _>>()?;
+
+    take(values.as_ref(), &take_indices, None)
+}
+
+fn map_projection(map: &MapArray, field: Arc<Field>, values: ArrayRef) -> ListArray {
+    ListArray::new(field, map.offsets().clone(), values, map.nulls().cloned())
+}
+
+/// Returns the keys of each map in `map` as a [`ListArray`]
+///
+/// The returned list has the same nulls as `map`, and shares its offsets and
+/// key buffers without copying.
+///
+/// ```
+/// # use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+/// # use arrow_array::Array;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_select::nested::map_keys;
+/// let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+/// builder.keys().append_value("a");
+/// builder.values().append_value(1);
+/// builder.keys().append_value("b");
+/// builder.values().append_value(2);
+/// builder.append(true).unwrap();
+/// builder.append(false).unwrap();
+/// let map = builder.finish();
+///
+/// let keys = map_keys(&map);
+/// assert_eq!(keys.value(0).as_string::<i32>().value(1), "b");
+/// assert!(keys.is_null(1));
+/// ```
+pub fn map_keys(map: &MapArray) -> ListArray {
+    let keys = map.keys();
+    let field = Field::new_list_field(keys.data_type().clone(), false);
+    map_projection(map, Arc::new(field), keys.clone())
+}
+
+/// Returns the values of each map in `map` as a [`ListArray`]
+///
+/// The returned list has the same nulls as `map`, and shares its offsets and
+/// value buffers without copying.
+pub fn map_values(map: &MapArray) -> ListArray {
+    let values = map.values();
+    let nullable = map.entries_fields().1.is_nullable();
+    let field = Field::new_list_field(values.data_type().clone(), nullable);
+    map_projection(map, Arc::new(field), values.clone())
+}
+
+/// Returns the entries of each map in `map` as a [`ListArray`] of key/value structs
+///
+/// The returned list has the same nulls as `map`, and shares its offsets and
+/// entries without copying.
+pub fn map_entries(map: &MapArray) -> ListArray {
+    let entries: ArrayRef = Arc::new(map.entries().clone());
+    let field = Field::new_list_field(entries.data_type().clone(), false);
+    map_projection(map, Arc::new(field), entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+    use arrow_buffer::{OffsetBuffer, ScalarBuffer};
+
+    fn list() -> ListArray {
+        ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2), Some(3)]),
+            Some(vec![]),
+            None,
+            Some(vec![Some(4), None]),
+            Some(vec![Some(5), Some(6), Some(7), Some(8)]),
+        ])
+    }
+
+    fn scalar(v: i64) -> Scalar<Int64Array> {
+        Scalar::new(Int64Array::from(vec![v]))
+    }
+
+    fn element(array: &dyn Array, index: &dyn Datum) -> Vec<Option<i32>> {
+        let result = list_element(array, index, &ListElementOptions::default()).unwrap();
+        result.as_primitive::<Int32Type>().iter().collect()
+    }
+
+    #[test]
+    fn test_list_element_scalar() {
+        let list = list();
+        assert_eq!(
+            element(&list, &scalar(1)),
+            vec![Some(1), None, None, Some(4), Some(5)]
+        );
+        assert_eq!(
+            element(&list, &scalar(3)),
+            vec![Some(3), None, None, None, Some(7)]
+        );
+        assert_eq!(
+            element(&list, &scalar(-1)),
+            vec![Some(3), None, None, None, Some(8)]
+        );
+        assert_eq!(
+            element(&list, &scalar(-4)),
+            vec![None, None, None, None, Some(5)]
+        );
+        assert_eq!(element(&list, &scalar(0)), vec![None; 5]);
+        assert_eq!(element(&list, &scalar(i64::MIN)), vec![None; 5]);
+
+        let large = LargeListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+        ]);
+        assert_eq!(element(&large, &scalar(-2)), vec![Some(1), None]);
+
+        let null_index = Scalar::new(Int64Array::new_null(1));
+        assert_eq!(element(&list, &null_index), vec![None; 5]);
+    }
+
+    #[test]
+    fn test_list_element_array_index() {
+        let list = list();
+        let index = Int32Array::from(vec![Some(2), Some(1), Some(1), None, Some(-2)]);
+        assert_eq!(
+            element(&list, &index),
+            vec![Some(2), None, None, None, Some(7)]
+        );
+
+        let err = list_element(
+            &list,
+            &Int32Array::from(vec![1]),
+            &ListElementOptions::default(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("index array has length 1"));
+
+        let err = list_element(
+            &list,
+            &UInt8Array::from(vec![1; 5]),
+            &ListElementOptions::default(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("must be Int32 or Int64"));
+    }
+
+    #[test]
+    fn test_list_element_sliced() {
+        let list = list().slice(3, 2);
+        assert_eq!(element(&list, &scalar(1)), vec![Some(4), Some(5)]);
+        assert_eq!(element(&list, &scalar(-1)), vec![None, Some(8)]);
+        let index = Int64Array::from(vec![1, 3]);
+        assert_eq!(element(&list, &index), vec![Some(4), Some(7)]);
+
+        let fixed = FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
+            vec![
+                Some(vec![Some(1), Some(2)]),
+                None,
+                Some(vec![Some(3), Some(4)]),
+                Some(vec![Some(5), None]),
+            ],
+            2,
+        );
+        assert_eq!(
+            element(&fixed, &scalar(-1)),
+            vec![Some(2), None, Some(4), None]
+        );
+        let fixed = fixed.slice(2, 2);
+        assert_eq!(element(&fixed, &scalar(1)), vec![Some(3), Some(5)]);
+        assert_eq!(element(&fixed, &scalar(3)), vec![None, None]);
+    }
+
+    #[test]
+    fn test_list_element_out_of_bounds_error() {
+        let list = list();
+        let options = ListElementOptions::default().with_error_on_out_of_bounds(true);
+        let err = list_element(&list, &scalar(3), &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: list_element index 3 is out of bounds for list of length 0 at row 1"
+        );
+
+        // null lists and null indices are not out of bounds
+        let sliced = list.slice(2, 1);
+        let result = list_element(&sliced, &scalar(3), &options).unwrap();
+        assert_eq!(result.null_count(), 1);
+
+        let err = list_element(&Int32Array::from(vec![1]), &scalar(1), &options).unwrap_err();
+        assert!(err.to_string().contains("expects a list array"));
+    }
+
+    fn map() -> MapArray {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.keys().append_value("b");
+        builder.values().append_null();
+        builder.keys().append_value("c");
+        builder.values().append_value(3);
+        builder.append(true).unwrap();
+        builder.append(true).unwrap();
+        builder.finish()
+    }
+
+    #[test]
+    fn test_map_projections() {
+        let map = map();
+
+        let keys = map_keys(&map);
+        let expected_keys: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        assert_eq!(keys.values(), &expected_keys);
+        assert_eq!(keys.value_offsets(), &[0, 1, 1, 3, 3]);
+        assert_eq!(keys.nulls(), map.nulls());
+        assert!(!keys.value_type().is_nested());
+
+        let values = map_values(&map);
+        let expected_values: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        assert_eq!(values.values(), &expected_values);
+        assert_eq!(values.nulls(), map.nulls());
+
+        let entries = map_entries(&map);
+        let entries_struct = entries.values().as_struct();
+        assert_eq!(entries_struct, map.entries());
+        assert_eq!(entries.len(), 4);
+        assert!(entries.is_null(1));
+    }
+
+    #[test]
+    fn test_map_projections_sliced() {
+        let map = map().slice(2, 2);
+
+        let keys = map_keys(&map);
+        assert_eq!(keys.len(), 2);
+        assert_eq!(
+            keys.value(0).as_string::<i32>(),
+            &StringArray::from(vec!["b", "c"])
+        );
+        assert_eq!(keys.value_length(1), 0);
+
+        let values = map_values(&map);
+        assert_eq!(
+            values.value(0).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![None, Some(3)])
+        );
+
+        let entries = map_entries(&map);
+        assert_eq!(entries.value(0).len(), 2);
+
+        // Composes with list_element
+        let second_key = list_element(&keys, &scalar(-1), &ListElementOptions::default()).unwrap();
+        assert_eq!(
+            second_key.as_string::<i32>(),
+            &StringArray::from(vec![Some("c"), None])
+        );
+    }
+
+    #[test]
+    fn test_map_projection_offsets() {
+        let map = map();
+        let keys = map_keys(&map);
+        let offsets = OffsetBuffer::new(ScalarBuffer::from(vec![0, 1, 1, 3, 3]));
+        assert_eq!(keys.offsets(), &offsets);
+    }
+}
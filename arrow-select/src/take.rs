@@ -30,10 +30,12 @@ use arrow_buffer::{
     bit_util,
 };
 use arrow_data::ArrayDataBuilder;
-use arrow_schema::{ArrowError, DataType, FieldRef, UnionMode};
+use arrow_schema::{ArrowError, DataType, FieldRef, Schema, UnionMode};
 
 use num_traits::{One, Zero};
 
+use crate::field::{check_field, nullable_if_nulls};
+
 /// Take elements by index from [Array], creating a new [Array] from those indexes.
 ///
 /// ```text
@@ -162,6 +164,38 @@ pub fn take_arrays(
         .collect()
 }
 
+/// Take elements by index from `values` with the corresponding `field`, see [`take`]
+///
+/// Returns the taken values along with their [`FieldRef`], preserving any extension
+/// type and other metadata of `field`. The returned field is nullable if the taken
+/// values contain nulls, for example due to null `indices`. Returns an error if
+/// `values` does not have the data type of `field`.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, StringArray, UInt32Array};
+/// # use arrow_schema::{DataType, Field};
+/// # use arrow_select::take::take_with_field;
+/// let metadata = [("ARROW:extension:name".to_string(), "arrow.json".to_string())];
+/// let field = Arc::new(Field::new("a", DataType::Utf8, true).with_metadata(metadata.into()));
+/// let values = StringArray::from(vec!["{}", "[]", "1"]);
+/// let indices = UInt32Array::from(vec![2, 0]);
+///
+/// let (taken_field, taken) = take_with_field(&field, &values, &indices, None).unwrap();
+/// assert_eq!(taken_field.extension_type_name(), Some("arrow.json"));
+/// assert_eq!(taken.as_ref(), &StringArray::from(vec!["1", "{}"]));
+/// ```
+pub fn take_with_field(
+    field: &FieldRef,
+    values: &dyn Array,
+    indices: &dyn Array,
+    options: Option<TakeOptions>,
+) -> Result<(FieldRef, ArrayRef), ArrowError> {
+    check_field(field, values)?;
+    let taken = take(values, indices, options)?;
+    Ok((nullable_if_nulls(field, taken.as_ref()), taken))
+}
+
 /// Verifies that the non-null values of `indices` are all `< len`
 fn check_bounds<T: ArrowPrimitiveType>(
     len: usize,
@@ -1088,7 +1122,9 @@ to_indices_reinterpret!(Int64Type, UInt64Type);
 
 /// Take rows by index from [`RecordBatch`] and returns a new [`RecordBatch`] from those indexes.
 ///
-/// This function will call [`take`] on each array of the [`RecordBatch`] and assemble a new [`RecordBatch`].
+/// This function will call [`take_with_field`] on each array of the [`RecordBatch`] and assemble a new [`RecordBatch`].
+/// The schema of the result is built from the returned fields, so a non-nullable field becomes
+/// nullable if null `indices` introduce nulls.
 ///
 /// # Example
 /// ```
@@ -1128,12 +1164,17 @@ pub fn take_record_batch(
     record_batch: &RecordBatch,
     indices: &dyn Array,
 ) -> Result<RecordBatch, ArrowError> {
-    let columns = record_batch
-        .columns()
+    let schema = record_batch.schema_ref();
+    let (fields, columns): (Vec<_>, Vec<_>) = schema
+        .fields()
         .iter()
-        .map(|c| take(c, indices, None))
-        .collect::<Result<Vec<_>, _>>()?;
-    RecordBatch::try_new(record_batch.schema(), columns)
+        .zip(record_batch.columns())
+        .map(|(f, c)| take_with_field(f, c, indices, None))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .unzip();
+    let schema = Schema::new_with_metadata(fields, schema.metadata().clone());
+    RecordBatch::try_new(Arc::new(schema), columns)
 }
 
 #[cfg(test)]
@@ -1144,6 +1185,7 @@ mod tests {
     use arrow_data::ArrayData;
     use arrow_schema::{Field, Fields, TimeUnit, UnionFields};
     use num_traits::ToPrimitive;
+    use std::collections::HashMap;
 
     fn test_take_decimal_arrays(
         data: Vec<Option<i128>>,
@@ -2820,4 +2862,57 @@ mod tests {
         assert_eq!(run_result.run_ends().len(), 0);
         assert_eq!(run_result.values().len(), 0);
     }
+
+    #[test]
+    fn test_take_with_field() {
+        let metadata: HashMap<_, _> = [("key".to_string(), "value".to_string())].into();
+        let field = Arc::new(Field::new("a", DataType::Int32, true).with_metadata(metadata));
+        let values = Int32Array::from(vec![1, 2, 3]);
+        let indices = UInt32Array::from(vec![2, 0]);
+
+        let (taken_field, taken) = take_with_field(&field, &values, &indices, None).unwrap();
+        assert_eq!(taken_field, field);
+        assert_eq!(taken.as_primitive::<Int32Type>().values(), &[3, 1]);
+
+        // Null indices produce null values, so the field must be nullable
+        let field = Arc::new(Field::new("b", DataType::Int32, false));
+        let nulls = UInt32Array::from(vec![Some(2), None]);
+        let (taken_field, taken) = take_with_field(&field, &values, &nulls, None).unwrap();
+        assert!(taken_field.is_nullable());
+        assert_eq!(taken.null_count(), 1);
+
+        let (taken_field, _) = take_with_field(&field, &values, &indices, None).unwrap();
+        assert_eq!(taken_field, field);
+
+        let values = StringArray::from(vec!["a"]);
+        let err = take_with_field(&field, &values, &indices, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Field 'b' has data type Int32 but array has data type Utf8"
+        );
+    }
+
+    #[test]
+    fn test_take_record_batch_null_indices() {
+        let metadata = HashMap::from([("k".to_string(), "v".to_string())]);
+        let schema = Arc::new(Schema::new_with_metadata(
+            vec![Field::new("a", DataType::Int32, false)],
+            metadata.clone(),
+        ));
+        let column = Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef;
+        let batch = RecordBatch::try_new(schema, vec![column]).unwrap();
+
+        let indices = UInt32Array::from(vec![Some(2), None]);
+        let taken = take_record_batch(&batch, &indices).unwrap();
+        assert!(taken.schema().field(0).is_nullable());
+        assert_eq!(taken.schema().metadata(), &metadata);
+        assert_eq!(
+            taken.column(0).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![Some(3), None])
+        );
+
+        let indices = UInt32Array::from(vec![2, 0]);
+        let taken = take_record_batch(&batch, &indices).unwrap();
+        assert_eq!(taken.schema(), batch.schema());
+    }
 }
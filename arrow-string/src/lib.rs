@@ -31,4 +31,5 @@ pub mod length;
 pub mod like;
 mod predicate;
 pub mod regexp;
+pub mod split;
 pub mod substring;
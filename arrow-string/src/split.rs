@@ -0,0 +1,457 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to split strings on a delimiter: [`split_part`] and [`split`]
+//!
+//! Supported array types: [`GenericStringArray`] and [`StringViewArray`]
+//!
+//! A string is split on each occurrence of the delimiter, with an empty delimiter
+//! splitting the string into its characters, i.e. Unicode scalar values. An empty
+//! string always has a single, empty, part.
+
+use arrow_array::builder::{ArrayBuilder, GenericStringBuilder, StringViewBuilder};
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int64Type;
+use arrow_array::*;
+use arrow_buffer::{ArrowNativeType, NullBufferBuilder, OffsetBuffer};
+use arrow_schema::{ArrowError, DataType, Field};
+use std::sync::Arc;
+
+type OptionIter<'a, T> = Box<dyn Iterator<Item = Option<T>> + 'a>;
+type StrIter<'a> = OptionIter<'a, &'a str>;
+
+/// Returns an iterator of the values of the string array `array`
+fn str_iter<'a>(array: &'a dyn Array, name: &str) -> Result<StrIter<'a>, ArrowError> {
+    Ok(match array.data_type() {
+        DataType::Utf8 => Box::new(array.as_string::<i32>().iter()),
+        DataType::LargeUtf8 => Box::new(array.as_string::<i64>().iter()),
+        DataType::Utf8View => Box::new(array.as_string_view().iter()),
+        d => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "{name} must be Utf8, LargeUtf8 or Utf8View, got {d}"
+            )));
+        }
+    })
+}
+
+/// Returns an iterator of `len` values of `datum`, repeating the value of a scalar
+fn datum_iter<'a, T: Copy + 'a>(
+    datum: &'a dyn Datum,
+    len: usize,
+    name: &str,
+    iter: impl FnOnce(&'a dyn Array) -> Result<OptionIter<'a, T>, ArrowError>,
+) -> Result<OptionIter<'a, T>, ArrowError> {
+    let (array, is_scalar) = datum.get();
+    let mut values = iter(array)?;
+    match is_scalar {
+        true => {
+            let value = values.next().flatten();
+            Ok(Box::new(std::iter::repeat_n(value, len)))
+        }
+        false if array.len() == len => Ok(values),
+        false => Err(ArrowError::InvalidArgumentError(format!(
+            "{name} must be a scalar or an array of length {len}, got length {}",
+            array.len()
+        ))),
+    }
+}
+
+fn delimiter_iter<'a>(delimiter: &'a dyn Datum, len: usize) -> Result<StrIter<'a>, ArrowError> {
+    datum_iter(delimiter, len, "delimiter", |a| str_iter(a, "delimiter"))
+}
+
+/// Returns the `n`th part of `value`, see [`split_part`]
+fn part<'a>(value: &'a str, delimiter: &str, n: i64) -> Result<&'a str, ArrowError> {
+    let idx = match n {
+        0 => {
+            return Err(ArrowError::InvalidArgumentError(
+                "split_part index must not be zero".to_string(),
+            ));
+        }
+        // Indices beyond usize::MAX select no part
+        _ => usize::try_from(n.unsigned_abs() - 1).unwrap_or(usize::MAX),
+    };
+    let char_at = |(i, c): (usize, char)| &value[i..i + c.len_utf8()];
+    let part = match (delimiter.is_empty(), n > 0) {
+        (true, true) => value.char_indices().nth(idx).map(char_at),
+        (true, false) => value.char_indices().nth_back(idx).map(char_at),
+        (false, true) => value.split(delimiter).nth(idx),
+        (false, false) => value.rsplit(delimiter).nth(idx),
+    };
+    Ok(part.unwrap_or_default())
+}
+
+/// Returns the `n`th part of each string in `array` split on `delimiter`
+///
+/// `n` is 1-based, with negative values counting from the end of the string, so
+/// `-1` selects the last part. If a string has fewer than `|n|` parts, the result is
+/// the empty string. An error is returned if `n` is zero.
+///
+/// `delimiter` may be any string type, and `n` must be [`DataType::Int64`]. Either may be
+/// a scalar or an array of the same length as `array`. The result has the same type as
+/// `array`, and is null where any of the inputs is null.
+///
+/// See the [module documentation](self) for how strings are split.
+///
+/// ```
+/// # use arrow_array::{Int64Array, Scalar, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::split::split_part;
+/// let array = StringArray::from(vec![Some("a,b,c"), Some("d"), None, Some("")]);
+/// let delimiter = Scalar::new(StringArray::from(vec![","]));
+///
+/// let result = split_part(&array, &delimiter, &Scalar::new(Int64Array::from(vec![2]))).unwrap();
+/// let expected = StringArray::from(vec![Some("b"), Some(""), None, Some("")]);
+/// assert_eq!(result.as_string::<i32>(), &expected);
+///
+/// let result = split_part(&array, &delimiter, &Scalar::new(Int64Array::from(vec![-1]))).unwrap();
+/// let expected = StringArray::from(vec![Some("c"), Some("d"), None, Some("")]);
+/// assert_eq!(result.as_string::<i32>(), &expected);
+/// ```
+pub fn split_part(
+    array: &dyn Array,
+    delimiter: &dyn Datum,
+    n: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    let values = str_iter(array, "split_part array")?;
+    let delimiters = delimiter_iter(delimiter, array.len())?;
+    let ns = datum_iter(n, array.len(), "n", |a| match a.data_type() {
+        DataType::Int64 => Ok(Box::new(a.as_primitive::<Int64Type>().iter())),
+        d => Err(ArrowError::InvalidArgumentError(format!(
+            "split_part index must be Int64, got {d}"
+        ))),
+    })?;
+
+    let parts = values
+        .zip(delimiters)
+        .zip(ns)
+        .map(|((value, delimiter), n)| match (value, delimiter, n) {
+            (Some(value), Some(delimiter), Some(n)) => part(value, delimiter, n).map(Some),
+            _ => Ok(None),
+        });
+
+    Ok(match array.data_type() {
+        DataType::Utf8 => Arc::new(parts.collect::<Result<StringArray, _>>()?),
+        DataType::LargeUtf8 => Arc::new(parts.collect::<Result<LargeStringArray, _>>()?),
+        _ => Arc::new(parts.collect::<Result<StringViewArray, _>>()?),
+    })
+}
+
+/// Splits each string in `array` on `delimiter`, returning a [`ListArray`] of the parts
+///
+/// `delimiter` may be any string type, and may be a scalar or an array of the same
+/// length as `array`. The list elements have the same type as `array`, and a list is
+/// null where either input is null.
+///
+/// See the [module documentation](self) for how strings are split.
+///
+/// # Errors
+///
+/// Returns an error if the total number of parts exceeds [`i32::MAX`], as is possible
+/// for large [`LargeStringArray`] inputs.
+///
+/// ```
+/// # use arrow_array::{Array, Scalar, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_string::split::split;
+/// let array = StringArray::from(vec![Some("a,b,c"), Some("d"), None, Some("")]);
+/// let delimiter = Scalar::new(StringArray::from(vec![","]));
+/// let result = split(&array, &delimiter).unwrap();
+///
+/// let parts = result.as_list::<i32>();
+/// assert_eq!(parts.value(0).as_string::<i32>(), &StringArray::from(vec!["a", "b", "c"]));
+/// assert!(parts.is_null(2));
+/// assert_eq!(parts.value(3).as_string::<i32>(), &StringArray::from(vec![""]));
+/// ```
+pub fn split(array: &dyn Array, delimiter: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    let values = str_iter(array, "split array")?;
+    let delimiters = delimiter_iter(delimiter, array.len())?;
+    match array.data_type() {
+        DataType::Utf8 => split_impl(values, delimiters, GenericStringBuilder::<i32>::new()),
+        DataType::LargeUtf8 => split_impl(values, delimiters, GenericStringBuilder::<i64>::new()),
+        _ => split_impl(values, delimiters, StringViewBuilder::new()),
+    }
+}
+
+fn split_impl<'a, B>(
+    values: StrIter<'a>,
+    delimiters: StrIter<'_>,
+    mut builder: B,
+) -> Result<ArrayRef, ArrowError>
+where
+    B: ArrayBuilder + Extend<Option<&'a str>>,
+{
+    let (len, _) = values.size_hint();
+    let mut offsets = Vec::with_capacity(len + 1);
+    offsets.push(0_i32);
+    let mut nulls = NullBufferBuilder::new(len);
+
+    for (value, delimiter) in values.zip(delimiters) {
+        match (value, delimiter) {
+            (Some(value), Some(delimiter)) => {
+                if value.is_empty() {
+                    builder.extend([Some(value)]);
+                } else if delimiter.is_empty() {
+                    let chars = value.char_indices();
+                    builder.extend(chars.map(|(i, c)| Some(&value[i..i + c.len_utf8()])));
+                } else {
+                    builder.extend(value.split(delimiter).map(Some));
+                }
+                nulls.append_non_null();
+            }
+            _ => nulls.append_null(),
+        }
+        let len = builder.len();
+        offsets.push(i32::from_usize(len).ok_or(ArrowError::OffsetOverflowError(len))?);
+    }
+
+    let values = builder.finish();
+    let field = Arc::new(Field::new_list_field(values.data_type().clone(), true));
+    // SAFETY: offsets are monotonically increasing by construction
+    let offsets = unsafe { OffsetBuffer::new_unchecked(offsets.into()) };
+    Ok(Arc::new(ListArray::try_new(
+        field,
+        offsets,
+        values,
+        nulls.finish(),
+    )?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scalar_n(n: i64) -> Scalar<Int64Array> {
+        Scalar::new(Int64Array::from(vec![n]))
+    }
+
+    #[test]
+    fn test_split_part() {
+        let array = StringArray::from(vec![
+            Some("a,b,c"),
+            Some("a,,b,"),
+            Some("abc"),
+            Some(""),
+            None,
+        ]);
+        let delimiter = Scalar::new(StringArray::from(vec![","]));
+
+        let cases = [
+            (1, [Some("a"), Some("a"), Some("abc"), Some(""), None]),
+            (2, [Some("b"), Some(""), Some(""), Some(""), None]),
+            (3, [Some("c"), Some("b"), Some(""), Some(""), None]),
+            (4, [Some(""), Some(""), Some(""), Some(""), None]),
+            (5, [Some(""), Some(""), Some(""), Some(""), None]),
+            (-1, [Some("c"), Some(""), Some("abc"), Some(""), None]),
+            (-2, [Some("b"), Some("b"), Some(""), Some(""), None]),
+            (-4, [Some(""), Some("a"), Some(""), Some(""), None]),
+            (i64::MAX, [Some(""), Some(""), Some(""), Some(""), None]),
+            (i64::MIN, [Some(""), Some(""), Some(""), Some(""), None]),
+        ];
+        for (n, expected) in cases {
+            let result = split_part(&array, &delimiter, &scalar_n(n)).unwrap();
+            assert_eq!(
+                result.as_string::<i32>(),
+                &StringArray::from(expected.to_vec()),
+                "{n}"
+            );
+        }
+
+        let err = split_part(&array, &delimiter, &scalar_n(0)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: split_part index must not be zero"
+        );
+    }
+
+    #[test]
+    fn test_split_part_multi_char_delimiter() {
+        let array = StringArray::from(vec!["a<>b<>>c", "<>", "a<b"]);
+        let delimiter = Scalar::new(StringArray::from(vec!["<>"]));
+        let result = split_part(&array, &delimiter, &scalar_n(2)).unwrap();
+        assert_eq!(
+            result.as_string::<i32>(),
+            &StringArray::from(vec!["b", "", ""])
+        );
+        // rsplit finds non-overlapping matches from the end
+        let array = StringArray::from(vec!["aaa"]);
+        let delimiter = Scalar::new(StringArray::from(vec!["aa"]));
+        let result = split_part(&array, &delimiter, &scalar_n(1)).unwrap();
+        assert_eq!(result.as_string::<i32>(), &StringArray::from(vec![""]));
+        let result = split_part(&array, &delimiter, &scalar_n(-1)).unwrap();
+        assert_eq!(result.as_string::<i32>(), &StringArray::from(vec![""]));
+        let result = split_part(&array, &delimiter, &scalar_n(-2)).unwrap();
+        assert_eq!(result.as_string::<i32>(), &StringArray::from(vec!["a"]));
+    }
+
+    #[test]
+    fn test_split_part_empty_delimiter() {
+        let array = StringArray::from(vec!["aé☃", ""]);
+        let delimiter = Scalar::new(StringArray::from(vec![""]));
+        let cases = [
+            (1, ["a", ""]),
+            (2, ["é", ""]),
+            (3, ["☃", ""]),
+            (4, ["", ""]),
+            (-1, ["☃", ""]),
+            (-3, ["a", ""]),
+        ];
+        for (n, expected) in cases {
+            let result = split_part(&array, &delimiter, &scalar_n(n)).unwrap();
+            assert_eq!(
+                result.as_string::<i32>(),
+                &StringArray::from(expected.to_vec())
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_part_arrays() {
+        let array =
+            LargeStringArray::from(vec![Some("a.b|c"), Some("a.b|c"), Some("x"), Some("y")]);
+        let delimiter = StringViewArray::from(vec![Some("."), Some("|"), None, Some("y")]);
+        let n = Int64Array::from(vec![Some(-1), Some(1), Some(1), None]);
+        let result = split_part(&array, &delimiter, &n).unwrap();
+        assert_eq!(
+            result.as_string::<i64>(),
+            &LargeStringArray::from(vec![Some("b|c"), Some("a.b"), None, None])
+        );
+
+        let array = StringViewArray::from(vec![
+            Some("a long string value, longer than 12 bytes"),
+            Some("short, value"),
+            None,
+        ]);
+        let delimiter = Scalar::new(StringArray::from(vec![", "]));
+        let n = Int64Array::from(vec![2, 1, 1]);
+        let result = split_part(&array, &delimiter, &n).unwrap();
+        assert_eq!(
+            result.as_string_view(),
+            &StringViewArray::from(vec![Some("longer than 12 bytes"), Some("short"), None])
+        );
+
+        // A null scalar produces all nulls
+        let delimiter = Scalar::new(StringArray::new_null(1));
+        let result = split_part(&array, &delimiter, &scalar_n(1)).unwrap();
+        assert_eq!(result.null_count(), 3);
+    }
+
+    #[test]
+    fn test_split_part_invalid() {
+        let array = StringArray::from(vec!["a", "b"]);
+        let delimiter = Scalar::new(StringArray::from(vec![","]));
+
+        let err = split_part(&array, &StringArray::from(vec![","]), &scalar_n(1)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: delimiter must be a scalar or an array of length 2, got length 1"
+        );
+
+        let n = Scalar::new(Int32Array::from(vec![1]));
+        let err = split_part(&array, &delimiter, &n).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: split_part index must be Int64, got Int32"
+        );
+
+        let binary = BinaryArray::from(vec![b"a".as_slice()]);
+        let err = split_part(&binary, &delimiter, &scalar_n(1)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: split_part array must be Utf8, LargeUtf8 or Utf8View, got Binary"
+        );
+    }
+
+    fn parts(list: &ListArray) -> Vec<Option<Vec<String>>> {
+        (0..list.len())
+            .map(|i| {
+                list.is_valid(i).then(|| {
+                    let value = list.value(i);
+                    str_iter(&value, "")
+                        .unwrap()
+                        .map(|s| s.unwrap().to_string())
+                        .collect()
+                })
+            })
+            .collect()
+    }
+
+    fn expected(parts: &[Option<&[&str]>]) -> Vec<Option<Vec<String>>> {
+        parts
+            .iter()
+            .map(|p| p.map(|p| p.iter().map(|s| s.to_string()).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn test_split() {
+        let values = vec![Some("a,b,,c"), Some(""), None, Some(","), Some("abc")];
+        let delimiter = Scalar::new(StringArray::from(vec![","]));
+        let expected = expected(&[
+            Some(&["a", "b", "", "c"]),
+            Some(&[""]),
+            None,
+            Some(&["", ""]),
+            Some(&["abc"]),
+        ]);
+
+        let arrays: [(ArrayRef, DataType); 3] = [
+            (Arc::new(StringArray::from(values.clone())), DataType::Utf8),
+            (
+                Arc::new(LargeStringArray::from(values.clone())),
+                DataType::LargeUtf8,
+            ),
+            (Arc::new(StringViewArray::from(values)), DataType::Utf8View),
+        ];
+        for (array, value_type) in arrays {
+            let result = split(&array, &delimiter).unwrap();
+            let list = result.as_list::<i32>();
+            assert_eq!(list.value_type(), value_type);
+            assert_eq!(parts(list), expected);
+        }
+    }
+
+    #[test]
+    fn test_split_empty_delimiter() {
+        let array = StringArray::from(vec!["aé☃", ""]);
+        let delimiter = Scalar::new(StringArray::from(vec![""]));
+        let result = split(&array, &delimiter).unwrap();
+        assert_eq!(
+            parts(result.as_list()),
+            expected(&[Some(&["a", "é", "☃"]), Some(&[""])])
+        );
+    }
+
+    #[test]
+    fn test_split_arrays() {
+        let array = StringArray::from(vec![Some("a,b|c"), Some("a,b|c"), Some("x"), None]);
+        let delimiter = StringArray::from(vec![Some(","), Some("|"), None, Some(",")]);
+        let result = split(&array, &delimiter).unwrap();
+        assert_eq!(
+            parts(result.as_list()),
+            expected(&[Some(&["a", "b|c"]), Some(&["a,b", "c"]), None, None])
+        );
+
+        let sliced = array.slice(1, 2);
+        let delimiter = Scalar::new(StringArray::from(vec!["b"]));
+        let result = split(&sliced, &delimiter).unwrap();
+        assert_eq!(
+            parts(result.as_list()),
+            expected(&[Some(&["a,", "|c"]), Some(&["x"])])
+        );
+    }
+}
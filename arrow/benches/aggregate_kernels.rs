@@ -25,7 +25,9 @@ extern crate arrow;
 use arrow::compute::kernels::aggregate::*;
 use arrow::util::bench_util::*;
 use arrow::{array::*, datatypes::Float32Type};
-use arrow_array::types::{Float64Type, Int8Type, Int16Type, Int32Type, Int64Type};
+use arrow_array::types::{
+    Float64Type, Int8Type, Int16Type, Int32Type, Int64Type, UInt8Type, UInt16Type,
+};
 
 const BATCH_SIZE: usize = 64 * 1024;
 
@@ -56,6 +58,9 @@ fn add_benchmark(c: &mut Criterion) {
     primitive_benchmark::<Int32Type>(c, "int32");
     primitive_benchmark::<Int64Type>(c, "int64");
 
+    primitive_benchmark::<UInt8Type>(c, "uint8");
+    primitive_benchmark::<UInt16Type>(c, "uint16");
+
     {
         let nonnull_strings = create_string_array_with_len::<i32>(BATCH_SIZE, 0.0, 16);
         let nullable_strings = create_string_array_with_len::<i32>(BATCH_SIZE, 0.5, 16);
@@ -19,10 +19,11 @@ extern crate arrow;
 #[macro_use]
 extern crate criterion;
 
+use arrow::array::*;
 use arrow::compute::kernels::cmp::*;
+use arrow::datatypes::{Float32Type, Int8Type, Int16Type, Int32Type};
 use arrow::util::bench_util::*;
 use arrow::util::test_util::seedable_rng;
-use arrow::{array::*, datatypes::Float32Type, datatypes::Int32Type};
 use arrow_buffer::IntervalMonthDayNano;
 use arrow_string::like::*;
 use arrow_string::regexp::regexp_is_match_scalar;
@@ -168,6 +169,34 @@ fn add_benchmark(c: &mut Criterion) {
         b.iter(|| gt_eq(&arr_a, &scalar).unwrap())
     });
 
+    let arr_a = create_primitive_array_with_seed::<Int8Type>(SIZE, 0.0, 42);
+    let arr_b = create_primitive_array_with_seed::<Int8Type>(SIZE, 0.0, 43);
+    let scalar = Int8Array::new_scalar(1);
+
+    c.bench_function("eq Int8", |b| b.iter(|| eq(&arr_a, &arr_b)));
+    c.bench_function("eq scalar Int8", |b| {
+        b.iter(|| eq(&arr_a, &scalar).unwrap())
+    });
+
+    c.bench_function("lt Int8", |b| b.iter(|| lt(&arr_a, &arr_b)));
+    c.bench_function("lt scalar Int8", |b| {
+        b.iter(|| lt(&arr_a, &scalar).unwrap())
+    });
+
+    let arr_a = create_primitive_array_with_seed::<Int16Type>(SIZE, 0.0, 42);
+    let arr_b = create_primitive_array_with_seed::<Int16Type>(SIZE, 0.0, 43);
+    let scalar = Int16Array::new_scalar(1);
+
+    c.bench_function("eq Int16", |b| b.iter(|| eq(&arr_a, &arr_b)));
+    c.bench_function("eq scalar Int16", |b| {
+        b.iter(|| eq(&arr_a, &scalar).unwrap())
+    });
+
+    c.bench_function("lt Int16", |b| b.iter(|| lt(&arr_a, &arr_b)));
+    c.bench_function("lt scalar Int16", |b| {
+        b.iter(|| lt(&arr_a, &scalar).unwrap())
+    });
+
     c.bench_function("eq MonthDayNano", |b| {
         b.iter(|| eq(&arr_month_day_nano_a, &arr_month_day_nano_b))
     });
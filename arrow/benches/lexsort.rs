@@ -18,7 +18,8 @@
 use arrow::compute::{SortColumn, lexsort_to_indices};
 use arrow::row::{RowConverter, SortField};
 use arrow::util::bench_util::{
-    create_dict_from_values, create_primitive_array, create_string_array_with_len,
+    create_dict_from_values, create_fsb_array, create_longer_string_view_array_with_same_prefix,
+    create_primitive_array, create_string_array_with_len,
 };
 use arrow::util::data_gen::create_random_array;
 use arrow_array::types::Int32Type;
@@ -39,6 +40,8 @@ enum Column {
     OptionalI32List,
     Required4CharStringList,
     Optional4CharStringList,
+    RequiredFixedSizeBinary16,
+    OptionalStringViewSamePrefix,
 }
 
 impl std::fmt::Debug for Column {
@@ -54,6 +57,8 @@ impl std::fmt::Debug for Column {
             Column::OptionalI32List => "i32_list_opt",
             Column::Required4CharStringList => "str_list(4)",
             Column::Optional4CharStringList => "str_list_opt(4)",
+            Column::RequiredFixedSizeBinary16 => "fsb(16)",
+            Column::OptionalStringViewSamePrefix => "str_view_prefix_opt",
         };
         f.write_str(s)
     }
@@ -112,6 +117,10 @@ impl Column {
                 );
                 create_random_array(&field, size, 0.2, 1.).unwrap()
             }
+            Column::RequiredFixedSizeBinary16 => Arc::new(create_fsb_array(size, 0., 16)),
+            Column::OptionalStringViewSamePrefix => {
+                Arc::new(create_longer_string_view_array_with_same_prefix(size, 0.2))
+            }
         }
     }
 }
@@ -198,6 +207,8 @@ fn add_benchmark(c: &mut Criterion) {
         &[Column::RequiredI32, Column::Required4CharStringList],
         &[Column::Required4CharStringList, Column::RequiredI32],
         &[Column::RequiredI32, Column::Optional4CharStringList],
+        &[Column::RequiredFixedSizeBinary16, Column::RequiredI32],
+        &[Column::OptionalStringViewSamePrefix, Column::RequiredI32],
         &[Column::Optional4CharStringList, Column::RequiredI32],
         &[
             Column::RequiredI32,
@@ -23,8 +23,8 @@ use std::sync::Arc;
 
 extern crate arrow;
 
-use arrow::compute::{SortColumn, lexsort, sort, sort_to_indices};
-use arrow::datatypes::{Int16Type, Int32Type};
+use arrow::compute::{SortColumn, lexsort, sort, sort_to_indices, take};
+use arrow::datatypes::{Decimal128Type, Int8Type, Int16Type, Int32Type, UInt8Type, UInt16Type};
 use arrow::util::bench_util::*;
 use arrow::{array::*, datatypes::Float32Type};
 use arrow_ord::rank::rank;
@@ -91,6 +91,66 @@ fn add_benchmark(c: &mut Criterion) {
         b.iter(|| bench_sort_to_indices(&arr, None))
     });
 
+    let arr = create_primitive_array::<Int8Type>(2usize.pow(12), 0.0);
+    c.bench_function("sort i8 to indices 2^12", |b| {
+        b.iter(|| bench_sort_to_indices(&arr, None))
+    });
+
+    let arr = create_primitive_array::<Int8Type>(2usize.pow(16), 0.0);
+    c.bench_function("sort i8 to indices 2^16", |b| {
+        b.iter(|| bench_sort_to_indices(&arr, None))
+    });
+
+    let arr = create_primitive_array::<Int8Type>(2usize.pow(12), 0.5);
+    c.bench_function("sort i8 nulls to indices 2^12", |b| {
+        b.iter(|| bench_sort_to_indices(&arr, None))
+    });
+
+    let arr = create_primitive_array::<UInt8Type>(2usize.pow(12), 0.0);
+    c.bench_function("sort u8 to indices 2^12", |b| {
+        b.iter(|| bench_sort_to_indices(&arr, None))
+    });
+
+    let arr = create_primitive_array::<UInt8Type>(2usize.pow(16), 0.0);
+    c.bench_function("sort u8 to indices 2^16", |b| {
+        b.iter(|| bench_sort_to_indices(&arr, None))
+    });
+
+    let arr = create_primitive_array::<UInt8Type>(2usize.pow(12), 0.5);
+    c.bench_function("sort u8 nulls to indices 2^12", |b| {
+        b.iter(|| bench_sort_to_indices(&arr, None))
+    });
+
+    let arr = create_primitive_array::<Int16Type>(2usize.pow(12), 0.0);
+    c.bench_function("sort i16 to indices 2^12", |b| {
+        b.iter(|| bench_sort_to_indices(&arr, None))
+    });
+
+    let arr = create_primitive_array::<Int16Type>(2usize.pow(16), 0.0);
+    c.bench_function("sort i16 to indices 2^16", |b| {
+        b.iter(|| bench_sort_to_indices(&arr, None))
+    });
+
+    let arr = create_primitive_array::<Int16Type>(2usize.pow(12), 0.5);
+    c.bench_function("sort i16 nulls to indices 2^12", |b| {
+        b.iter(|| bench_sort_to_indices(&arr, None))
+    });
+
+    let arr = create_primitive_array::<UInt16Type>(2usize.pow(12), 0.0);
+    c.bench_function("sort u16 to indices 2^12", |b| {
+        b.iter(|| bench_sort_to_indices(&arr, None))
+    });
+
+    let arr = create_primitive_array::<UInt16Type>(2usize.pow(16), 0.0);
+    c.bench_function("sort u16 to indices 2^16", |b| {
+        b.iter(|| bench_sort_to_indices(&arr, None))
+    });
+
+    let arr = create_primitive_array::<UInt16Type>(2usize.pow(12), 0.5);
+    c.bench_function("sort u16 nulls to indices 2^12", |b| {
+        b.iter(|| bench_sort_to_indices(&arr, None))
+    });
+
     let arr = create_f32_array(2_usize.pow(12), false);
     c.bench_function("sort f32 2^12", |b| b.iter(|| bench_sort(&arr)));
     c.bench_function("sort f32 to indices 2^12", |b| {
@@ -200,6 +260,29 @@ fn add_benchmark(c: &mut Criterion) {
         |b| b.iter(|| bench_sort_to_indices(&arr, None)),
     );
 
+    // This will generate string view arrays with 2^12 elements, each sharing a 7 byte prefix,
+    // so comparisons cannot be decided by the 4 byte prefix stored in the view
+    let arr = create_longer_string_view_array_with_same_prefix(2usize.pow(12), 0.0);
+    c.bench_function("sort string_view same prefix to indices 2^12", |b| {
+        b.iter(|| bench_sort_to_indices(&arr, None))
+    });
+
+    // Random 16 byte values, such as UUIDs
+    let arr = create_fsb_array(2usize.pow(12), 0.0, 16);
+    c.bench_function("sort fixed_size_binary[16] to indices 2^12", |b| {
+        b.iter(|| bench_sort_to_indices(&arr, None))
+    });
+
+    let arr = create_fsb_array(2usize.pow(12), 0.5, 16);
+    c.bench_function("sort fixed_size_binary[16] nulls to indices 2^12", |b| {
+        b.iter(|| bench_sort_to_indices(&arr, None))
+    });
+
+    let arr = create_fsb_array(2usize.pow(20), 0.0, 16);
+    c.bench_function("sort fixed_size_binary[16] to indices 2^20", |b| {
+        b.iter(|| bench_sort_to_indices(&arr, None))
+    });
+
     let arr = create_string_dict_array::<Int32Type>(2usize.pow(12), 0.0, 10);
     c.bench_function("sort string[10] dict to indices 2^12", |b| {
         b.iter(|| bench_sort_to_indices(&arr, None))
@@ -210,6 +293,23 @@ fn add_benchmark(c: &mut Criterion) {
         b.iter(|| bench_sort_to_indices(&arr, None))
     });
 
+    // Low-cardinality decimal dictionary, comparing sorting through the dictionary
+    // values against unpacking the dictionary and sorting the result
+    let values = create_primitive_array::<Decimal128Type>(100, 0.0);
+    let arr = create_dict_from_values::<Int32Type>(10_000_000, 0.1, &values);
+    let mut group = c.benchmark_group("sort decimal128[100] dict 10M");
+    group.sample_size(10);
+    group.bench_function("dict to indices", |b| {
+        b.iter(|| bench_sort_to_indices(&arr, None))
+    });
+    group.bench_function("unpack then sort to indices", |b| {
+        b.iter(|| {
+            let unpacked = take(arr.values().as_ref(), arr.keys(), None).unwrap();
+            bench_sort_to_indices(&unpacked, None)
+        })
+    });
+    group.finish();
+
     let run_encoded_array =
         create_primitive_run_array::<Int16Type, Int32Type>(2usize.pow(12), 2usize.pow(10));
 
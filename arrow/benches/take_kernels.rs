@@ -76,6 +76,15 @@ fn add_benchmark(c: &mut Criterion) {
         b.iter(|| bench_take(&values, &indices))
     });
 
+    let values = create_primitive_array::<Int8Type>(1024, 0.0);
+    let indices = create_random_index(1024, 0.0);
+    c.bench_function("take i8 1024", |b| b.iter(|| bench_take(&values, &indices)));
+
+    let values = create_primitive_array::<Int16Type>(1024, 0.0);
+    c.bench_function("take i16 1024", |b| {
+        b.iter(|| bench_take(&values, &indices))
+    });
+
     let values = create_primitive_array::<Int32Type>(512, 0.0);
     let indices = create_random_index(512, 0.0);
     c.bench_function("take check bounds i32 512", |b| {
@@ -17,7 +17,7 @@
 
 //! Defines `ArrowError` for representing failures in various Arrow operations.
 
-pub use arrow_schema::ArrowError;
+pub use arrow_schema::{ArrowError, ErrorKind};
 
 /// A specialized `Result` type for Arrow operations.
 pub type Result<T> = std::result::Result<T, ArrowError>;
@@ -16,12 +16,13 @@
 // under the License.
 
 use arrow_arith::numeric::{add, sub};
-use arrow_arith::temporal::{DatePart, date_part};
+use arrow_arith::temporal::{DatePart, DateTruncGranularity, date_part, date_trunc};
 use arrow_array::cast::AsArray;
 use arrow_array::temporal_conversions::as_datetime_with_timezone;
 use arrow_array::timezone::Tz;
 use arrow_array::types::*;
 use arrow_array::*;
+use arrow_schema::TimeUnit;
 use chrono::{DateTime, TimeZone};
 
 #[test]
@@ -188,3 +189,148 @@ fn test_timestamp_with_timezone() {
         test_timestamp_with_timezone_impl::<TimestampNanosecondType>(timezone);
     }
 }
+
+fn check_date_trunc<T: ArrowTimestampType>(
+    input: &str,
+    granularity: DateTruncGranularity,
+    expected: &str,
+    tz: &str,
+) {
+    let divisor = match T::UNIT {
+        TimeUnit::Second => 1_000_000_000,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    };
+    let nanos = |s: &str| {
+        DateTime::parse_from_rfc3339(s)
+            .unwrap()
+            .timestamp_nanos_opt()
+            .unwrap()
+            / divisor
+    };
+    let array = PrimitiveArray::<T>::from_iter_values([nanos(input)]).with_timezone(tz);
+    let actual = date_trunc(&array, granularity).unwrap();
+    let expected = PrimitiveArray::<T>::from_iter_values([nanos(expected)]).with_timezone(tz);
+    assert_eq!(
+        actual.as_primitive::<T>(),
+        &expected,
+        "{granularity} of {input} in {tz}"
+    );
+}
+
+fn check_date_trunc_all_units(
+    input: &str,
+    granularity: DateTruncGranularity,
+    expected: &str,
+    tz: &str,
+) {
+    check_date_trunc::<TimestampSecondType>(input, granularity, expected, tz);
+    check_date_trunc::<TimestampMillisecondType>(input, granularity, expected, tz);
+    check_date_trunc::<TimestampMicrosecondType>(input, granularity, expected, tz);
+    check_date_trunc::<TimestampNanosecondType>(input, granularity, expected, tz);
+}
+
+#[test]
+fn test_date_trunc_dst_start() {
+    use DateTruncGranularity::*;
+    // Clocks in New York moved forward from 02:00 to 03:00 on 2021-03-14
+    let tz = "America/New_York";
+    let input = "2021-03-14T12:00:00-04:00";
+    check_date_trunc_all_units(input, Day, "2021-03-14T00:00:00-05:00", tz);
+    check_date_trunc_all_units(input, Week, "2021-03-08T00:00:00-05:00", tz);
+    check_date_trunc_all_units(input, Month, "2021-03-01T00:00:00-05:00", tz);
+    check_date_trunc_all_units(
+        "2021-03-14T03:30:00-04:00",
+        Hour,
+        "2021-03-14T03:00:00-04:00",
+        tz,
+    );
+    check_date_trunc_all_units(
+        "2021-03-14T01:59:59-05:00",
+        Hour,
+        "2021-03-14T01:00:00-05:00",
+        tz,
+    );
+
+    // Clocks in Sao Paulo moved forward from 00:00 to 01:00 on 2018-11-04, so the
+    // day started at 01:00
+    let tz = "America/Sao_Paulo";
+    let input = "2018-11-04T12:00:00-02:00";
+    check_date_trunc_all_units(input, Day, "2018-11-04T01:00:00-02:00", tz);
+    check_date_trunc_all_units(input, Month, "2018-11-01T00:00:00-03:00", tz);
+    check_date_trunc_all_units(input, Hour, input, tz);
+}
+
+#[test]
+fn test_date_trunc_dst_end() {
+    use DateTruncGranularity::*;
+    // Clocks in New York moved back from 02:00 to 01:00 on 2021-11-07, so the hour
+    // from 01:00 occurred twice
+    let tz = "America/New_York";
+    check_date_trunc_all_units(
+        "2021-11-07T12:00:00-05:00",
+        Day,
+        "2021-11-07T00:00:00-04:00",
+        tz,
+    );
+    check_date_trunc_all_units(
+        "2021-11-07T01:30:00-04:00",
+        Hour,
+        "2021-11-07T01:00:00-04:00",
+        tz,
+    );
+    check_date_trunc_all_units(
+        "2021-11-07T01:30:00-05:00",
+        Hour,
+        "2021-11-07T01:00:00-05:00",
+        tz,
+    );
+    check_date_trunc_all_units(
+        "2021-11-07T01:30:45-05:00",
+        Minute,
+        "2021-11-07T01:30:00-05:00",
+        tz,
+    );
+    check_date_trunc_all_units(
+        "2021-11-07T01:30:00-05:00",
+        Day,
+        "2021-11-07T00:00:00-04:00",
+        tz,
+    );
+}
+
+#[test]
+fn test_date_trunc_timezone() {
+    use DateTruncGranularity::*;
+    // Kathmandu is 5 hours and 45 minutes ahead of UTC
+    let tz = "Asia/Kathmandu";
+    check_date_trunc_all_units(
+        "2021-01-01T00:00:00Z",
+        Hour,
+        "2021-01-01T05:00:00+05:45",
+        tz,
+    );
+    check_date_trunc_all_units(
+        "2021-01-01T00:00:00Z",
+        Year,
+        "2021-01-01T00:00:00+05:45",
+        tz,
+    );
+
+    // Before the epoch in local time and in UTC
+    let tz = "America/New_York";
+    check_date_trunc_all_units("1970-01-01T01:00:00Z", Day, "1969-12-31T00:00:00-05:00", tz);
+    check_date_trunc_all_units(
+        "1969-12-31T23:59:59Z",
+        Week,
+        "1969-12-29T00:00:00-05:00",
+        tz,
+    );
+    check_date_trunc_all_units(
+        "1969-12-31T23:59:59Z",
+        Quarter,
+        "1969-10-01T00:00:00-04:00",
+        tz,
+    );
+}
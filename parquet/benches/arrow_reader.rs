@@ -1845,6 +1845,47 @@ fn add_benches(c: &mut Criterion) {
         },
     );
 
+    // delta length byte array with varying lengths
+    let delta_length_string_no_null_data = build_constant_prefix_byte_array_page_iterator(
+        mandatory_string_column_desc.clone(),
+        0.0,
+        Encoding::DELTA_LENGTH_BYTE_ARRAY,
+        false,
+    );
+    group.bench_function(
+        "delta length byte array encoded, mandatory, no NULLs",
+        |b| {
+            b.iter(|| {
+                let array_reader = create_byte_array_reader(
+                    delta_length_string_no_null_data.clone(),
+                    mandatory_string_column_desc.clone(),
+                );
+                count = bench_array_reader(array_reader);
+            });
+            assert_eq!(count, EXPECTED_VALUE_COUNT);
+        },
+    );
+
+    let delta_length_string_half_null_data = build_constant_prefix_byte_array_page_iterator(
+        optional_string_column_desc.clone(),
+        0.5,
+        Encoding::DELTA_LENGTH_BYTE_ARRAY,
+        false,
+    );
+    group.bench_function(
+        "delta length byte array encoded, optional, half NULLs",
+        |b| {
+            b.iter(|| {
+                let array_reader = create_byte_array_reader(
+                    delta_length_string_half_null_data.clone(),
+                    optional_string_column_desc.clone(),
+                );
+                count = bench_array_reader(array_reader);
+            });
+            assert_eq!(count, EXPECTED_VALUE_COUNT);
+        },
+    );
+
     group.finish();
 
     // binary benchmarks
@@ -2173,6 +2214,47 @@ fn add_benches(c: &mut Criterion) {
         assert_eq!(count, EXPECTED_VALUE_COUNT);
     });
 
+    // delta length byte array with varying lengths
+    let delta_length_string_no_null_data = build_constant_prefix_byte_array_page_iterator(
+        mandatory_string_column_desc.clone(),
+        0.0,
+        Encoding::DELTA_LENGTH_BYTE_ARRAY,
+        false,
+    );
+    group.bench_function(
+        "delta length byte array encoded, mandatory, no NULLs",
+        |b| {
+            b.iter(|| {
+                let array_reader = create_string_view_byte_array_reader(
+                    delta_length_string_no_null_data.clone(),
+                    mandatory_string_column_desc.clone(),
+                );
+                count = bench_array_reader(array_reader);
+            });
+            assert_eq!(count, EXPECTED_VALUE_COUNT);
+        },
+    );
+
+    let delta_length_string_half_null_data = build_constant_prefix_byte_array_page_iterator(
+        optional_string_column_desc.clone(),
+        0.5,
+        Encoding::DELTA_LENGTH_BYTE_ARRAY,
+        false,
+    );
+    group.bench_function(
+        "delta length byte array encoded, optional, half NULLs",
+        |b| {
+            b.iter(|| {
+                let array_reader = create_string_view_byte_array_reader(
+                    delta_length_string_half_null_data.clone(),
+                    optional_string_column_desc.clone(),
+                );
+                count = bench_array_reader(array_reader);
+            });
+            assert_eq!(count, EXPECTED_VALUE_COUNT);
+        },
+    );
+
     group.finish();
 
     // list benchmarks
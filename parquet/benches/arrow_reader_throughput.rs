@@ -0,0 +1,425 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! End-to-end throughput benchmarks for [`ParquetRecordBatchReader`] covering
+//! common read patterns.
+//!
+//! Unlike the `arrow_reader` benchmark, which measures individual array readers
+//! over pre-encoded pages, these benchmarks read complete files, including
+//! decoding the metadata, decompression, and assembling [`RecordBatch`]es.
+//!
+//! Each benchmark reports throughput in both rows/second and bytes/second, where
+//! the bytes are the compressed size of the column chunks read.
+//!
+//! The files are generated deterministically, with [`NUM_ROWS`] rows in row groups
+//! of [`ROW_GROUP_SIZE`] rows:
+//!
+//! * `wide_flat`: 100 Int64 columns, the first of which is a sorted row number
+//! * `strings`: Int64, Utf8 and Binary columns of varying lengths
+//! * `nested`: a struct column with Int64 and Utf8 fields, and a list of Int64
+//! * `low_cardinality`: a Utf8 column with [`LOW_CARDINALITY_VALUES`] distinct values,
+//!   read both as Utf8 and, with [`ArrowReaderOptions::with_dictionary_columns`], as
+//!   a dictionary. The memory used by the decoded arrays in each case is printed
+//!
+//! All benchmarks read from an in-memory [`Bytes`] buffer, except for
+//! `wide_flat/file`, which reads the same data from a temporary file for comparison
+//! with `wide_flat/all_columns`.
+//!
+//! [`ParquetRecordBatchReader`]: parquet::arrow::arrow_reader::ParquetRecordBatchReader
+//! [`ArrowReaderOptions::with_dictionary_columns`]: parquet::arrow::arrow_reader::ArrowReaderOptions::with_dictionary_columns
+
+use arrow::array::{Array, ArrayRef, BinaryBuilder, Int64Array, ListBuilder, StringBuilder};
+use arrow::array::{Int64Builder, StructArray};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::record_batch::RecordBatch;
+use bytes::Bytes;
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::ProjectionMask;
+use parquet::arrow::arrow_reader::{
+    ArrowReaderBuilder, ArrowReaderOptions, ParquetRecordBatchReaderBuilder,
+};
+use parquet::file::metadata::ParquetMetaData;
+use parquet::file::properties::WriterProperties;
+use parquet::file::reader::ChunkReader;
+use parquet::file::statistics::Statistics;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::io::Write;
+use std::sync::Arc;
+
+/// The number of rows in each file
+const NUM_ROWS: usize = 1024 * 1024;
+
+/// The maximum number of rows in each row group
+const ROW_GROUP_SIZE: usize = 64 * 1024;
+
+/// The number of rows generated and written at a time
+const WRITE_BATCH_SIZE: usize = 8 * 1024;
+
+/// The number of columns in the `wide_flat` file
+const WIDE_NUM_COLUMNS: usize = 100;
+
+/// The number of distinct values in the `low_cardinality` file
+const LOW_CARDINALITY_VALUES: usize = 16;
+
+/// Writes [`NUM_ROWS`] rows produced by `make_batch` to an in-memory Parquet file
+///
+/// `make_batch` is called with the row number of the first row, and the number of
+/// rows, of each batch
+fn write_parquet(
+    schema: SchemaRef,
+    mut make_batch: impl FnMut(&mut StdRng, usize, usize) -> Vec<ArrayRef>,
+) -> Bytes {
+    let mut rng = StdRng::seed_from_u64(42);
+    let props = WriterProperties::builder()
+        .set_max_row_group_row_count(Some(ROW_GROUP_SIZE))
+        .build();
+
+    let mut buf = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut buf, schema.clone(), Some(props)).unwrap();
+    for start in (0..NUM_ROWS).step_by(WRITE_BATCH_SIZE) {
+        let len = WRITE_BATCH_SIZE.min(NUM_ROWS - start);
+        let columns = make_batch(&mut rng, start, len);
+        let batch = RecordBatch::try_new(schema.clone(), columns).unwrap();
+        writer.write(&batch).unwrap();
+    }
+    writer.close().unwrap();
+    buf.into()
+}
+
+fn wide_flat_file() -> Bytes {
+    let fields: Vec<_> = (0..WIDE_NUM_COLUMNS)
+        .map(|i| Field::new(format!("c{i}"), DataType::Int64, true))
+        .collect();
+
+    write_parquet(Arc::new(Schema::new(fields)), |rng, start, len| {
+        let row_numbers = Int64Array::from_iter_values(start as i64..(start + len) as i64);
+        std::iter::once(Arc::new(row_numbers) as ArrayRef)
+            .chain((1..WIDE_NUM_COLUMNS).map(|_| {
+                let values = (0..len).map(|_| rng.random_range(0..10_000));
+                Arc::new(Int64Array::from_iter_values(values)) as ArrayRef
+            }))
+            .collect()
+    })
+}
+
+/// Returns a random alphanumeric string with a length in `0..max_len`
+fn random_string(rng: &mut StdRng, max_len: usize) -> String {
+    let charset = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+    let len = rng.random_range(0..max_len);
+    (0..len)
+        .map(|_| charset[rng.random_range(0..charset.len())] as char)
+        .collect()
+}
+
+fn strings_file() -> Bytes {
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("utf8", DataType::Utf8, true),
+        Field::new("binary", DataType::Binary, true),
+    ]);
+
+    write_parquet(Arc::new(schema), |rng, start, len| {
+        let ids = Int64Array::from_iter_values(start as i64..(start + len) as i64);
+        let mut strings = StringBuilder::new();
+        let mut binaries = BinaryBuilder::new();
+        for _ in 0..len {
+            match rng.random_bool(0.1) {
+                true => strings.append_null(),
+                false => strings.append_value(random_string(rng, 32)),
+            }
+            match rng.random_bool(0.1) {
+                true => binaries.append_null(),
+                false => {
+                    let value: Vec<u8> =
+                        (0..rng.random_range(0..64)).map(|_| rng.random()).collect();
+                    binaries.append_value(value)
+                }
+            }
+        }
+        vec![
+            Arc::new(ids),
+            Arc::new(strings.finish()),
+            Arc::new(binaries.finish()),
+        ]
+    })
+}
+
+fn nested_file() -> Bytes {
+    let struct_fields = vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let list_field = Field::new_list_field(DataType::Int64, true);
+    let schema = Schema::new(vec![
+        Field::new_struct("struct", struct_fields.clone(), true),
+        Field::new_list("list", list_field, true),
+    ]);
+
+    write_parquet(Arc::new(schema), |rng, start, len| {
+        let a = Int64Array::from_iter_values(start as i64..(start + len) as i64);
+        let mut b = StringBuilder::new();
+        (0..len).for_each(|_| b.append_value(random_string(rng, 16)));
+        let nulls = (0..len).map(|_| !rng.random_bool(0.1)).collect();
+        let structs = StructArray::new(
+            struct_fields.clone().into(),
+            vec![Arc::new(a), Arc::new(b.finish())],
+            Some(nulls),
+        );
+
+        let mut lists = ListBuilder::new(Int64Builder::new());
+        for _ in 0..len {
+            match rng.random_bool(0.1) {
+                true => lists.append_null(),
+                false => {
+                    let values = (0..rng.random_range(0..8)).map(|_| rng.random_range(0..1000));
+                    lists.values().append_slice(&values.collect::<Vec<_>>());
+                    lists.append(true);
+                }
+            }
+        }
+        vec![Arc::new(structs), Arc::new(lists.finish())]
+    })
+}
+
+fn low_cardinality_file() -> Bytes {
+    let schema = Schema::new(vec![Field::new("utf8", DataType::Utf8, true)]);
+
+    let mut rng = StdRng::seed_from_u64(7);
+    let values: Vec<_> = (0..LOW_CARDINALITY_VALUES)
+        .map(|_| format!("{:32}", random_string(&mut rng, 32)))
+        .collect();
+
+    write_parquet(Arc::new(schema), |rng, _, len| {
+        let mut strings = StringBuilder::new();
+        for _ in 0..len {
+            match rng.random_bool(0.1) {
+                true => strings.append_null(),
+                false => strings.append_value(&values[rng.random_range(0..values.len())]),
+            }
+        }
+        vec![Arc::new(strings.finish())]
+    })
+}
+
+/// Returns the indices of the row groups that may contain a value of the first
+/// column greater than or equal to `min`, based on their statistics
+fn prune_row_groups(metadata: &ParquetMetaData, min: i64) -> Vec<usize> {
+    metadata
+        .row_groups()
+        .iter()
+        .enumerate()
+        .filter(|(_, rg)| match rg.column(0).statistics() {
+            Some(Statistics::Int64(s)) => s.max_opt().is_none_or(|max| *max >= min),
+            _ => true,
+        })
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// Returns the [`Throughput`] of reading `row_groups` with `projection`
+fn throughput(
+    metadata: &ParquetMetaData,
+    row_groups: &[usize],
+    projection: &ProjectionMask,
+) -> Throughput {
+    let mut elements = 0;
+    let mut bytes = 0;
+    for rg in row_groups.iter().map(|idx| metadata.row_group(*idx)) {
+        elements += rg.num_rows() as u64;
+        bytes += rg
+            .columns()
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| projection.leaf_included(*idx))
+            .map(|(_, c)| c.compressed_size() as u64)
+            .sum::<u64>();
+    }
+    Throughput::ElementsAndBytes { elements, bytes }
+}
+
+/// A read pattern to benchmark
+struct ReadPattern {
+    /// The row groups to read, or `None` for all
+    row_groups: Option<Vec<usize>>,
+    /// The leaf columns to read, or `None` for all
+    leaves: Option<Vec<usize>>,
+}
+
+impl ReadPattern {
+    const ALL: Self = Self {
+        row_groups: None,
+        leaves: None,
+    };
+
+    /// Configures `builder` to read this pattern, returning it and the [`Throughput`]
+    fn apply<T>(&self, builder: ArrowReaderBuilder<T>) -> (ArrowReaderBuilder<T>, Throughput) {
+        let metadata = builder.metadata().clone();
+        let row_groups = self
+            .row_groups
+            .clone()
+            .unwrap_or_else(|| (0..metadata.num_row_groups()).collect());
+        let projection = match &self.leaves {
+            Some(leaves) => ProjectionMask::leaves(builder.parquet_schema(), leaves.clone()),
+            None => ProjectionMask::all(),
+        };
+
+        let throughput = throughput(&metadata, &row_groups, &projection);
+        let builder = builder
+            .with_row_groups(row_groups)
+            .with_projection(projection);
+        (builder, throughput)
+    }
+}
+
+/// Reads `reader` with `pattern`, returning the number of rows read
+fn read<T: ChunkReader + 'static>(reader: T, pattern: &ReadPattern) -> usize {
+    let builder = ParquetRecordBatchReaderBuilder::try_new(reader).unwrap();
+    let (builder, _) = pattern.apply(builder);
+    builder
+        .build()
+        .unwrap()
+        .map(|b| b.unwrap().num_rows())
+        .sum()
+}
+
+/// Benchmarks reading `data` with `pattern`, which is expected to read `expected_rows`
+fn bench_read(
+    c: &mut Criterion,
+    name: &str,
+    data: &Bytes,
+    pattern: ReadPattern,
+    expected_rows: usize,
+) {
+    let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+    let (_, throughput) = pattern.apply(builder);
+
+    let mut group = c.benchmark_group("arrow_reader_throughput");
+    group.sample_size(10);
+    group.throughput(throughput);
+    group.bench_function(name, |b| {
+        b.iter(|| assert_eq!(read(data.clone(), &pattern), expected_rows))
+    });
+    group.finish();
+}
+
+fn bench_wide_flat(c: &mut Criterion) {
+    let data = wide_flat_file();
+    bench_read(
+        c,
+        "wide_flat/all_columns",
+        &data,
+        ReadPattern::ALL,
+        NUM_ROWS,
+    );
+
+    // Read every tenth column
+    let projection = ReadPattern {
+        row_groups: None,
+        leaves: Some((0..WIDE_NUM_COLUMNS).step_by(10).collect()),
+    };
+    bench_read(
+        c,
+        "wide_flat/projection_10_of_100",
+        &data,
+        projection,
+        NUM_ROWS,
+    );
+
+    // Read the last eighth of the rows, skipping row groups based on statistics
+    let min = (NUM_ROWS - NUM_ROWS / 8) as i64;
+    let metadata = ParquetRecordBatchReaderBuilder::try_new(data.clone())
+        .unwrap()
+        .metadata()
+        .clone();
+    let pruned = ReadPattern {
+        row_groups: Some(prune_row_groups(&metadata, min)),
+        leaves: None,
+    };
+    bench_read(
+        c,
+        "wide_flat/row_group_pruning",
+        &data,
+        pruned,
+        NUM_ROWS / 8,
+    );
+
+    // Read the same data from a file
+    let mut file = tempfile::tempfile().unwrap();
+    file.write_all(&data).unwrap();
+    let builder = ParquetRecordBatchReaderBuilder::try_new(file.try_clone().unwrap()).unwrap();
+    let (_, throughput) = ReadPattern::ALL.apply(builder);
+
+    let mut group = c.benchmark_group("arrow_reader_throughput");
+    group.sample_size(10);
+    group.throughput(throughput);
+    group.bench_function("wide_flat/file", |b| {
+        b.iter(|| {
+            let file = file.try_clone().unwrap();
+            assert_eq!(read(file, &ReadPattern::ALL), NUM_ROWS)
+        })
+    });
+    group.finish();
+}
+
+fn bench_strings(c: &mut Criterion) {
+    let data = strings_file();
+    bench_read(c, "strings/all_columns", &data, ReadPattern::ALL, NUM_ROWS);
+}
+
+fn bench_nested(c: &mut Criterion) {
+    let data = nested_file();
+    bench_read(c, "nested/all_columns", &data, ReadPattern::ALL, NUM_ROWS);
+}
+
+fn bench_low_cardinality(c: &mut Criterion) {
+    let data = low_cardinality_file();
+
+    // Returns the total memory used by the decoded arrays
+    let read = |options: &ArrowReaderOptions| -> usize {
+        ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options.clone())
+            .unwrap()
+            .build()
+            .unwrap()
+            .map(|b| b.unwrap().column(0).get_array_memory_size())
+            .sum()
+    };
+
+    let mut group = c.benchmark_group("arrow_reader_throughput");
+    group.sample_size(10);
+    for (name, options) in [
+        ("low_cardinality/utf8", ArrowReaderOptions::new()),
+        (
+            "low_cardinality/dictionary",
+            ArrowReaderOptions::new().with_dictionary_columns(["utf8"]),
+        ),
+    ] {
+        // Report the memory of the decoded arrays, which differs between them
+        group.throughput(Throughput::Bytes(read(&options) as u64));
+        group.bench_function(name, |b| b.iter(|| read(&options)));
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_wide_flat,
+    bench_strings,
+    bench_nested,
+    bench_low_cardinality
+);
+criterion_main!(benches);
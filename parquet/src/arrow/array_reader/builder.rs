@@ -30,7 +30,7 @@ use crate::arrow::array_reader::row_group_index::RowGroupIndexReader;
 use crate::arrow::array_reader::row_number::RowNumberReader;
 use crate::arrow::array_reader::{
     ArrayReader, FixedSizeListArrayReader, ListArrayReader, ListViewArrayReader, MapArrayReader,
-    NullArrayReader, PrimitiveArrayReader, RowGroups, StructArrayReader,
+    NullArrayReader, PrimitiveArrayReader, RowGroups, RunEndEncodedArrayReader, StructArrayReader,
     make_byte_array_dictionary_reader, make_byte_array_reader,
 };
 use crate::arrow::arrow_reader::metrics::ArrowReaderMetrics;
@@ -376,6 +376,17 @@ impl<'a> ArrayReaderBuilder<'a> {
         field: &ParquetField,
         mask: &ProjectionMask,
     ) -> Result<Option<Box<dyn ArrayReader>>> {
+        if let DataType::RunEndEncoded(_, values) = &field.arrow_type {
+            let values_field = ParquetField {
+                arrow_type: values.data_type().clone(),
+                ..field.clone()
+            };
+            let reader = self.build_primitive_reader(&values_field, mask)?;
+            return Ok(reader.map(|reader| {
+                Box::new(RunEndEncodedArrayReader::new(reader, field.arrow_type.clone())) as _
+            }));
+        }
+
         let (col_idx, primitive_type) = match &field.field_type {
             ParquetFieldType::Primitive {
                 col_idx,
@@ -459,6 +470,12 @@ impl<'a> ArrayReaderBuilder<'a> {
                 _ => make_byte_array_reader(page_iterator, column_desc, arrow_type)?,
             },
             PhysicalType::FIXED_LEN_BYTE_ARRAY => match arrow_type {
+                // The dictionary reader only supports binary values
+                Some(DataType::Dictionary(_, ref value))
+                    if matches!(value.as_ref(), DataType::Interval(_)) =>
+                {
+                    make_fixed_len_byte_array_reader(page_iterator, column_desc, arrow_type)?
+                }
                 Some(DataType::Dictionary(_, _)) => {
                     make_byte_array_dictionary_reader(page_iterator, column_desc, arrow_type)?
                 }
@@ -16,20 +16,21 @@
 // under the License.
 
 use crate::arrow::array_reader::{ArrayReader, read_records, skip_records};
-use crate::arrow::buffer::bit_util::sign_extend_be;
+use crate::arrow::buffer::bit_util::{sign_extend_be, sign_extend_or_truncate_be};
 use crate::arrow::buffer::offset_buffer::OffsetBuffer;
-use crate::arrow::decoder::{DeltaByteArrayDecoder, DictIndexDecoder};
+use crate::arrow::decoder::{
+    DeltaByteArrayDecoder, DeltaLengthByteArrayDecoder, DictIndexDecoder,
+};
 use crate::arrow::record_reader::GenericRecordReader;
 use crate::arrow::schema::parquet_to_arrow_field;
 use crate::basic::{ConvertedType, Encoding};
 use crate::column::page::PageIterator;
 use crate::column::reader::decoder::ColumnValueDecoder;
-use crate::data_type::Int32Type;
-use crate::encodings::decoding::{Decoder, DeltaBitPackDecoder};
 use crate::errors::{ParquetError, Result};
 use crate::schema::types::ColumnDescPtr;
 use arrow_array::{
-    Array, ArrayRef, BinaryArray, Decimal128Array, Decimal256Array, OffsetSizeTrait,
+    Array, ArrayRef, BinaryArray, Decimal32Array, Decimal64Array, Decimal128Array, Decimal256Array,
+    OffsetSizeTrait,
 };
 use arrow_buffer::i256;
 use arrow_schema::DataType as ArrowType;
@@ -54,6 +55,8 @@ pub fn make_byte_array_reader(
     match data_type {
         ArrowType::Binary
         | ArrowType::Utf8
+        | ArrowType::Decimal32(_, _)
+        | ArrowType::Decimal64(_, _)
         | ArrowType::Decimal128(_, _)
         | ArrowType::Decimal256(_, _) => {
             let reader = GenericRecordReader::new(column_desc);
@@ -123,6 +126,30 @@ impl<I: OffsetSizeTrait> ArrayReader for ByteArrayReader<I> {
             // Apply conversion to all elements regardless of null slots as the conversions
             // are infallible. This improves performance by avoiding a branch in the inner
             // loop (see docs for `PrimitiveArray::from_unary`).
+            ArrowType::Decimal32(p, s) => {
+                let array = buffer.into_array(null_buffer, ArrowType::Binary);
+                let binary = array.as_any().downcast_ref::<BinaryArray>().unwrap();
+                // Null slots will have 0 length, so we need to check for that in the lambda
+                // or sign_extend_or_truncate_be will panic.
+                let decimal = Decimal32Array::from_unary(binary, |x| match x.len() {
+                    0 => i32::default(),
+                    _ => i32::from_be_bytes(sign_extend_or_truncate_be(x)),
+                })
+                .with_precision_and_scale(p, s)?;
+                Arc::new(decimal)
+            }
+            ArrowType::Decimal64(p, s) => {
+                let array = buffer.into_array(null_buffer, ArrowType::Binary);
+                let binary = array.as_any().downcast_ref::<BinaryArray>().unwrap();
+                // Null slots will have 0 length, so we need to check for that in the lambda
+                // or sign_extend_or_truncate_be will panic.
+                let decimal = Decimal64Array::from_unary(binary, |x| match x.len() {
+                    0 => i64::default(),
+                    _ => i64::from_be_bytes(sign_extend_or_truncate_be(x)),
+                })
+                .with_precision_and_scale(p, s)?;
+                Arc::new(decimal)
+            }
             ArrowType::Decimal128(p, s) => {
                 let array = buffer.into_array(null_buffer, ArrowType::Binary);
                 let binary = array.as_any().downcast_ref::<BinaryArray>().unwrap();
@@ -277,7 +304,7 @@ impl ByteArrayDecoder {
                 ByteArrayDecoderDictionary::new(data, num_levels, num_values)?,
             ),
             Encoding::DELTA_LENGTH_BYTE_ARRAY => ByteArrayDecoder::DeltaLength(
-                ByteArrayDecoderDeltaLength::new(data, validate_utf8)?,
+                ByteArrayDecoderDeltaLength::new(data, num_levels, validate_utf8)?,
             ),
             Encoding::DELTA_BYTE_ARRAY => {
                 ByteArrayDecoder::DeltaByteArray(ByteArrayDecoderDelta::new(data, validate_utf8)?)
@@ -304,7 +331,7 @@ impl ByteArrayDecoder {
             ByteArrayDecoder::Plain(d) => d.read(out, len),
             ByteArrayDecoder::Dictionary(d) => {
                 let dict =
-                    dict.ok_or_else(|| general_err!("missing dictionary page for column"))?;
+                    dict.ok_or_else(|| corrupt_err!("missing dictionary page for column"))?;
 
                 d.read(out, dict, len)
             }
@@ -323,7 +350,7 @@ impl ByteArrayDecoder {
             ByteArrayDecoder::Plain(d) => d.skip(len),
             ByteArrayDecoder::Dictionary(d) => {
                 let dict =
-                    dict.ok_or_else(|| general_err!("missing dictionary page for column"))?;
+                    dict.ok_or_else(|| corrupt_err!("missing dictionary page for column"))?;
 
                 d.skip(dict, len)
             }
@@ -431,45 +458,13 @@ impl ByteArrayDecoderPlain {
 
 /// Decoder from [`Encoding::DELTA_LENGTH_BYTE_ARRAY`] data to [`OffsetBuffer`]
 pub struct ByteArrayDecoderDeltaLength {
-    lengths: Vec<i32>,
-    data: Bytes,
-    length_offset: usize,
-    data_offset: usize,
-    validate_utf8: bool,
+    decoder: DeltaLengthByteArrayDecoder,
 }
 
 impl ByteArrayDecoderDeltaLength {
-    fn new(data: Bytes, validate_utf8: bool) -> Result<Self> {
-        let mut len_decoder = DeltaBitPackDecoder::<Int32Type>::new();
-        len_decoder.set_data(data.clone(), 0)?;
-        let values = len_decoder.values_left();
-
-        let mut lengths = vec![0; values];
-        len_decoder.get(&mut lengths)?;
-
-        let mut total_bytes = 0;
-
-        for l in lengths.iter() {
-            if *l < 0 {
-                return Err(ParquetError::General(
-                    "negative delta length byte array length".to_string(),
-                ));
-            }
-            total_bytes += *l as usize;
-        }
-
-        if total_bytes + len_decoder.get_offset() > data.len() {
-            return Err(ParquetError::General(
-                "Insufficient delta length byte array bytes".to_string(),
-            ));
-        }
-
+    fn new(data: Bytes, num_levels: usize, validate_utf8: bool) -> Result<Self> {
         Ok(Self {
-            lengths,
-            data,
-            validate_utf8,
-            length_offset: 0,
-            data_offset: len_decoder.get_offset(),
+            decoder: DeltaLengthByteArrayDecoder::new(data, num_levels, validate_utf8)?,
         })
     }
 
@@ -478,45 +473,24 @@ impl ByteArrayDecoderDeltaLength {
         output: &mut OffsetBuffer<I>,
         len: usize,
     ) -> Result<usize> {
-        let initial_values_length = output.values.len();
-
-        let to_read = len.min(self.lengths.len() - self.length_offset);
-        output.offsets.reserve(to_read);
-
-        let src_lengths = &self.lengths[self.length_offset..self.length_offset + to_read];
-
-        let total_bytes: usize = src_lengths.iter().map(|x| *x as usize).sum();
-        output.values.reserve(total_bytes);
-
-        let mut current_offset = self.data_offset;
-        for length in src_lengths {
-            let end_offset = current_offset + *length as usize;
-            output.try_push(
-                &self.data.as_ref()[current_offset..end_offset],
-                self.validate_utf8,
-            )?;
-            current_offset = end_offset;
-        }
-
-        self.data_offset = current_offset;
-        self.length_offset += to_read;
-
-        if self.validate_utf8 {
-            output.check_valid_utf8(initial_values_length)?;
-        }
-        Ok(to_read)
+        self.decoder.read(len, |lengths, data, range| {
+            let mut offset = output.values.len();
+            I::from_usize(offset + range.len())
+                .ok_or_else(|| general_err!("index overflow decoding byte array"))?;
+
+            // The values are contiguous, and so can be copied in one go, with the
+            // offsets computed directly from the lengths
+            output.values.extend_from_slice(&data[range]);
+            output.offsets.extend(lengths.iter().map(|length| {
+                offset += *length as usize;
+                I::usize_as(offset)
+            }));
+            Ok(())
+        })
     }
 
     fn skip(&mut self, to_skip: usize) -> Result<usize> {
-        let remain_values = self.lengths.len() - self.length_offset;
-        let to_skip = remain_values.min(to_skip);
-
-        let src_lengths = &self.lengths[self.length_offset..self.length_offset + to_skip];
-        let total_bytes: usize = src_lengths.iter().map(|x| *x as usize).sum();
-
-        self.data_offset += total_bytes;
-        self.length_offset += to_skip;
-        Ok(to_skip)
+        self.decoder.skip(to_skip)
     }
 }
 
@@ -325,7 +325,7 @@ where
                 let dict = self
                     .dict
                     .as_ref()
-                    .ok_or_else(|| general_err!("missing dictionary page for column"))?;
+                    .ok_or_else(|| corrupt_err!("missing dictionary page for column"))?;
 
                 assert_eq!(dict.data_type(), &self.value_type);
 
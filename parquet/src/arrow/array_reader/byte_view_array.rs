@@ -17,14 +17,14 @@
 
 use crate::arrow::array_reader::{ArrayReader, read_records, skip_records};
 use crate::arrow::buffer::view_buffer::ViewBuffer;
-use crate::arrow::decoder::{DeltaByteArrayDecoder, DictIndexDecoder};
+use crate::arrow::decoder::{
+    DeltaByteArrayDecoder, DeltaLengthByteArrayDecoder, DictIndexDecoder,
+};
 use crate::arrow::record_reader::GenericRecordReader;
 use crate::arrow::schema::parquet_to_arrow_field;
 use crate::basic::{ConvertedType, Encoding};
 use crate::column::page::PageIterator;
 use crate::column::reader::decoder::ColumnValueDecoder;
-use crate::data_type::Int32Type;
-use crate::encodings::decoding::{Decoder, DeltaBitPackDecoder};
 use crate::errors::{ParquetError, Result};
 use crate::schema::types::ColumnDescPtr;
 use crate::util::utf8::check_valid_utf8;
@@ -239,7 +239,7 @@ impl ByteViewArrayDecoder {
                 )?)
             }
             Encoding::DELTA_LENGTH_BYTE_ARRAY => ByteViewArrayDecoder::DeltaLength(
-                ByteViewArrayDecoderDeltaLength::new(data, validate_utf8)?,
+                ByteViewArrayDecoderDeltaLength::new(data, num_levels, validate_utf8)?,
             ),
             Encoding::DELTA_BYTE_ARRAY => ByteViewArrayDecoder::DeltaByteArray(
                 ByteViewArrayDecoderDelta::new(data, validate_utf8)?,
@@ -266,7 +266,7 @@ impl ByteViewArrayDecoder {
             ByteViewArrayDecoder::Plain(d) => d.read(out, len),
             ByteViewArrayDecoder::Dictionary(d) => {
                 let dict = dict
-                    .ok_or_else(|| general_err!("dictionary required for dictionary encoding"))?;
+                    .ok_or_else(|| corrupt_err!("dictionary required for dictionary encoding"))?;
                 d.read(out, dict, len)
             }
             ByteViewArrayDecoder::DeltaLength(d) => d.read(out, len),
@@ -280,7 +280,7 @@ impl ByteViewArrayDecoder {
             ByteViewArrayDecoder::Plain(d) => d.skip(len),
             ByteViewArrayDecoder::Dictionary(d) => {
                 let dict = dict
-                    .ok_or_else(|| general_err!("dictionary required for dictionary encoding"))?;
+                    .ok_or_else(|| corrupt_err!("dictionary required for dictionary encoding"))?;
                 d.skip(dict, len)
             }
             ByteViewArrayDecoder::DeltaLength(d) => d.skip(len),
@@ -513,7 +513,7 @@ impl ByteViewArrayDecoderDictionary {
                         Some(&view) => view,
                         None => {
                             if error.is_none() {
-                                error = Some(general_err!("invalid key={} for dictionary", *k));
+                                error = Some(corrupt_err!("invalid key={} for dictionary", *k));
                             }
                             0
                         }
@@ -535,7 +535,7 @@ impl ByteViewArrayDecoderDictionary {
                         }
                         None => {
                             if error.is_none() {
-                                error = Some(general_err!("invalid key={} for dictionary", *k));
+                                error = Some(corrupt_err!("invalid key={} for dictionary", *k));
                             }
                             0
                         }
@@ -559,95 +559,38 @@ impl ByteViewArrayDecoderDictionary {
 
 /// Decoder from [`Encoding::DELTA_LENGTH_BYTE_ARRAY`] data to [`ViewBuffer`]
 pub struct ByteViewArrayDecoderDeltaLength {
-    lengths: Vec<i32>,
-    data: Bytes,
-    length_offset: usize,
-    data_offset: usize,
-    validate_utf8: bool,
+    decoder: DeltaLengthByteArrayDecoder,
 }
 
 impl ByteViewArrayDecoderDeltaLength {
-    fn new(data: Bytes, validate_utf8: bool) -> Result<Self> {
-        let mut len_decoder = DeltaBitPackDecoder::<Int32Type>::new();
-        len_decoder.set_data(data.clone(), 0)?;
-        let values = len_decoder.values_left();
-
-        let mut lengths = vec![0; values];
-        len_decoder.get(&mut lengths)?;
-
-        let mut total_bytes = 0;
-
-        for l in lengths.iter() {
-            if *l < 0 {
-                return Err(ParquetError::General(
-                    "negative delta length byte array length".to_string(),
-                ));
-            }
-            total_bytes += *l as usize;
-        }
-
-        if total_bytes + len_decoder.get_offset() > data.len() {
-            return Err(ParquetError::General(
-                "Insufficient delta length byte array bytes".to_string(),
-            ));
-        }
-
+    fn new(data: Bytes, num_levels: usize, validate_utf8: bool) -> Result<Self> {
         Ok(Self {
-            lengths,
-            data,
-            validate_utf8,
-            length_offset: 0,
-            data_offset: len_decoder.get_offset(),
+            decoder: DeltaLengthByteArrayDecoder::new(data, num_levels, validate_utf8)?,
         })
     }
 
     fn read(&mut self, output: &mut ViewBuffer, len: usize) -> Result<usize> {
-        let to_read = len.min(self.lengths.len() - self.length_offset);
-        output.views.reserve(to_read);
-
-        let src_lengths = &self.lengths[self.length_offset..self.length_offset + to_read];
-
-        // Zero copy convert `bytes::Bytes` into `arrow_buffer::Buffer`
-        let bytes = Buffer::from(self.data.clone());
-        let block_id = output.append_block(bytes);
-
-        let mut current_offset = self.data_offset;
-        let initial_offset = current_offset;
-
-        output.views.extend(src_lengths.iter().map(|length| {
-            let len = *length as u32;
-            let start_offset = current_offset;
-            current_offset += len as usize;
-            // # Safety
-            // The length and offset are guaranteed valid by the entry check in `new`
-            make_view(
-                &self.data[start_offset..start_offset + len as usize],
-                block_id,
-                start_offset as u32,
-            )
-        }));
-
-        // Delta length encoding has continuous strings, we can validate utf8 in one go
-        if self.validate_utf8 {
-            check_valid_utf8(&self.data[initial_offset..current_offset])?;
-        }
-
-        self.data_offset = current_offset;
-        self.length_offset += to_read;
-
-        Ok(to_read)
+        self.decoder.read(len, |lengths, data, range| {
+            if lengths.is_empty() {
+                return Ok(());
+            }
+            output.views.reserve(lengths.len());
+
+            // Zero copy convert `bytes::Bytes` into `arrow_buffer::Buffer`
+            let block_id = output.append_block(Buffer::from(data.clone()));
+
+            let mut offset = range.start;
+            output.views.extend(lengths.iter().map(|length| {
+                let start_offset = offset;
+                offset += *length as usize;
+                make_view(&data[start_offset..offset], block_id, start_offset as u32)
+            }));
+            Ok(())
+        })
     }
 
     fn skip(&mut self, to_skip: usize) -> Result<usize> {
-        let remain_values = self.lengths.len() - self.length_offset;
-        let to_skip = remain_values.min(to_skip);
-
-        let src_lengths = &self.lengths[self.length_offset..self.length_offset + to_skip];
-        let total_bytes: usize = src_lengths.iter().map(|x| *x as usize).sum();
-
-        self.data_offset += total_bytes;
-        self.length_offset += to_skip;
-        Ok(to_skip)
+        self.decoder.skip(to_skip)
     }
 }
 
@@ -16,7 +16,9 @@
 // under the License.
 
 use crate::arrow::array_reader::{ArrayReader, read_records, skip_records};
-use crate::arrow::buffer::bit_util::{iter_set_bits_rev, sign_extend_be};
+use crate::arrow::buffer::bit_util::{
+    iter_set_bits_rev, sign_extend_be, sign_extend_or_truncate_be,
+};
 use crate::arrow::decoder::{DeltaByteArrayDecoder, DictIndexDecoder};
 use crate::arrow::record_reader::GenericRecordReader;
 use crate::arrow::record_reader::buffer::ValuesBuffer;
@@ -26,11 +28,17 @@ use crate::column::page::PageIterator;
 use crate::column::reader::decoder::ColumnValueDecoder;
 use crate::errors::{ParquetError, Result};
 use crate::schema::types::ColumnDescPtr;
+use arrow_array::builder::PrimitiveDictionaryBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    ArrowDictionaryKeyType, IntervalDayTimeType, IntervalMonthDayNanoType, IntervalYearMonthType,
+};
 use arrow_array::{
-    ArrayRef, Decimal32Array, Decimal64Array, Decimal128Array, Decimal256Array,
-    FixedSizeBinaryArray, Float16Array, IntervalDayTimeArray, IntervalYearMonthArray,
+    Array, ArrayRef, ArrowPrimitiveType, Decimal32Array, Decimal64Array, Decimal128Array,
+    Decimal256Array, FixedSizeBinaryArray, Float16Array, IntervalDayTimeArray,
+    IntervalMonthDayNanoArray, IntervalYearMonthArray, PrimitiveArray, downcast_integer,
 };
-use arrow_buffer::{Buffer, IntervalDayTime, i256};
+use arrow_buffer::{Buffer, IntervalDayTime, IntervalMonthDayNano, i256};
 use arrow_data::ArrayDataBuilder;
 use arrow_schema::{DataType as ArrowType, IntervalUnit};
 use bytes::Bytes;
@@ -62,24 +70,24 @@ pub fn make_fixed_len_byte_array_reader(
             ));
         }
     };
-    match &data_type {
-        ArrowType::FixedSizeBinary(_) => {}
-        ArrowType::Decimal32(_, _) => {
-            if byte_length > 4 {
-                return Err(general_err!(
-                    "decimal 32 type too large, must be less then 4 bytes, got {}",
-                    byte_length
-                ));
-            }
-        }
-        ArrowType::Decimal64(_, _) => {
-            if byte_length > 8 {
+    let value_type = match &data_type {
+        // Intervals are decoded and then packed into a dictionary
+        ArrowType::Dictionary(key, value) if matches!(value.as_ref(), ArrowType::Interval(_)) => {
+            if !key.is_dictionary_key_type() {
                 return Err(general_err!(
-                    "decimal 64 type too large, must be less then 8 bytes, got {}",
-                    byte_length
+                    "invalid dictionary key type for fixed length byte array reader - {}",
+                    data_type
                 ));
             }
+            value.as_ref()
         }
+        data_type => data_type,
+    };
+    match value_type {
+        ArrowType::FixedSizeBinary(_) => {}
+        // The precision of these types bounds the values, so any leading bytes beyond
+        // their width are a sign extension that is discarded when decoding
+        ArrowType::Decimal32(_, _) | ArrowType::Decimal64(_, _) => {}
         ArrowType::Decimal128(_, _) => {
             if byte_length > 16 {
                 return Err(general_err!(
@@ -183,14 +191,18 @@ impl ArrayReader for FixedLenByteArrayReader {
         // Note the conversions below apply to all elements regardless of null slots as the
         // conversion lambdas are all infallible. This improves performance by avoiding a branch in
         // the inner loop (see docs for `PrimitiveArray::from_unary`).
-        let array: ArrayRef = match &self.data_type {
+        let value_type = match &self.data_type {
+            ArrowType::Dictionary(_, value) => value.as_ref(),
+            data_type => data_type,
+        };
+        let array: ArrayRef = match value_type {
             ArrowType::Decimal32(p, s) => {
-                let f = |b: &[u8]| i32::from_be_bytes(sign_extend_be(b));
+                let f = |b: &[u8]| i32::from_be_bytes(sign_extend_or_truncate_be(b));
                 Arc::new(Decimal32Array::from_unary(&binary, f).with_precision_and_scale(*p, *s)?)
                     as ArrayRef
             }
             ArrowType::Decimal64(p, s) => {
-                let f = |b: &[u8]| i64::from_be_bytes(sign_extend_be(b));
+                let f = |b: &[u8]| i64::from_be_bytes(sign_extend_or_truncate_be(b));
                 Arc::new(Decimal64Array::from_unary(&binary, f).with_precision_and_scale(*p, *s)?)
                     as ArrayRef
             }
@@ -222,7 +234,15 @@ impl ArrayReader for FixedLenByteArrayReader {
                         Arc::new(IntervalDayTimeArray::from_unary(&binary, f)) as ArrayRef
                     }
                     IntervalUnit::MonthDayNano => {
-                        return Err(nyi_err!("MonthDayNano intervals not supported"));
+                        let f = |b: &[u8]| {
+                            let millis = i32::from_le_bytes(b[8..12].try_into().unwrap());
+                            IntervalMonthDayNano::new(
+                                i32::from_le_bytes(b[0..4].try_into().unwrap()),
+                                i32::from_le_bytes(b[4..8].try_into().unwrap()),
+                                millis as i64 * 1_000_000,
+                            )
+                        };
+                        Arc::new(IntervalMonthDayNanoArray::from_unary(&binary, f)) as ArrayRef
                     }
                 }
             }
@@ -233,6 +253,11 @@ impl ArrayReader for FixedLenByteArrayReader {
             _ => Arc::new(binary) as ArrayRef,
         };
 
+        let array = match &self.data_type {
+            ArrowType::Dictionary(key, _) => pack_dictionary(key, array.as_ref()),
+            _ => array,
+        };
+
         self.def_levels_buffer = self.record_reader.consume_def_levels();
         self.rep_levels_buffer = self.record_reader.consume_rep_levels();
         self.record_reader.reset();
@@ -253,6 +278,39 @@ impl ArrayReader for FixedLenByteArrayReader {
     }
 }
 
+macro_rules! pack_dictionary_helper {
+    ($t:ty, $values:ident) => {
+        match $values.data_type() {
+            ArrowType::Interval(IntervalUnit::YearMonth) => {
+                pack_dictionary_impl::<$t, IntervalYearMonthType>($values.as_primitive())
+            }
+            ArrowType::Interval(IntervalUnit::DayTime) => {
+                pack_dictionary_impl::<$t, IntervalDayTimeType>($values.as_primitive())
+            }
+            ArrowType::Interval(IntervalUnit::MonthDayNano) => {
+                pack_dictionary_impl::<$t, IntervalMonthDayNanoType>($values.as_primitive())
+            }
+            _ => unreachable!("Invalid dictionary value type"),
+        }
+    };
+}
+
+/// Packs the decoded interval `values` into a dictionary with keys of type `key`
+fn pack_dictionary(key: &ArrowType, values: &dyn Array) -> ArrayRef {
+    downcast_integer! {
+        key => (pack_dictionary_helper, values),
+        _ => unreachable!("Invalid key type"),
+    }
+}
+
+fn pack_dictionary_impl<K: ArrowDictionaryKeyType, V: ArrowPrimitiveType>(
+    values: &PrimitiveArray<V>,
+) -> ArrayRef {
+    let mut builder = PrimitiveDictionaryBuilder::<K, V>::with_capacity(1024, values.len());
+    builder.extend(values);
+    Arc::new(builder.finish())
+}
+
 #[derive(Default)]
 struct FixedLenByteArrayBuffer {
     buffer: Vec<u8>,
@@ -357,7 +415,7 @@ impl ColumnValueDecoder for ValueDecoder {
         }
         let expected_len = num_values as usize * self.byte_length;
         if expected_len > buf.len() {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "too few bytes in dictionary page, expected {} got {}",
                 expected_len,
                 buf.len()
@@ -439,7 +497,7 @@ impl ColumnValueDecoder for ValueDecoder {
 
                 decoder.read(to_read, |slice| {
                     if slice.len() != self.byte_length {
-                        return Err(general_err!(
+                        return Err(corrupt_err!(
                             "encountered array with incorrect length, got {} expected {}",
                             slice.len(),
                             self.byte_length
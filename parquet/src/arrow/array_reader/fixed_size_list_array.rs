@@ -91,7 +91,7 @@ impl ArrayReader for FixedSizeListArrayReader {
         if !rep_levels.is_empty() && rep_levels[0] != 0 {
             // This implies either the source data was invalid, or the leaf column
             // reader did not correctly delimit semantic records
-            return Err(general_err!("first repetition level of batch must be 0"));
+            return Err(corrupt_err!("first repetition level of batch must be 0"));
         }
 
         let mut validity = self
@@ -115,7 +115,7 @@ impl ArrayReader for FixedSizeListArrayReader {
                 Ordering::Greater => {
                     // Repetition level greater than current => already handled by inner array
                     if *d < self.def_level {
-                        return Err(general_err!(
+                        return Err(corrupt_err!(
                             "Encountered repetition level too large for definition level"
                         ));
                     }
@@ -134,7 +134,7 @@ impl ArrayReader for FixedSizeListArrayReader {
                     // - zero (null entries, start of array)
                     // Any other length indicates invalid data
                     if start_idx.is_some() && row_len != self.fixed_size {
-                        return Err(general_err!(
+                        return Err(corrupt_err!(
                             "Encountered misaligned row with length {} (expected length {})",
                             row_len,
                             self.fixed_size
@@ -187,7 +187,7 @@ impl ArrayReader for FixedSizeListArrayReader {
 
         // Verify total number of elements is aligned with fixed list size
         if list_len * self.fixed_size != child_data.len() {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "fixed-size list length must be a multiple of {} but array contains {} elements",
                 self.fixed_size,
                 child_data.len()
@@ -107,7 +107,7 @@ impl<OffsetSize: OffsetSizeTrait> ArrayReader for ListArrayReader<OffsetSize> {
         if !rep_levels.is_empty() && rep_levels[0] != 0 {
             // This implies either the source data was invalid, or the leaf column
             // reader did not correctly delimit semantic records
-            return Err(general_err!("first repetition level of batch must be 0"));
+            return Err(corrupt_err!("first repetition level of batch must be 0"));
         }
 
         // A non-nullable list has a single definition level indicating if the list is empty
@@ -151,7 +151,7 @@ impl<OffsetSize: OffsetSizeTrait> ArrayReader for ListArrayReader<OffsetSize> {
                 Ordering::Greater => {
                     // Repetition level greater than current => already handled by inner array
                     if *d < self.def_level {
-                        return Err(general_err!(
+                        return Err(corrupt_err!(
                             "Encountered repetition level too large for definition level"
                         ));
                     }
@@ -209,7 +209,7 @@ impl<OffsetSize: OffsetSizeTrait> ArrayReader for ListArrayReader<OffsetSize> {
         };
 
         if cur_offset != child_data.len() {
-            return Err(general_err!("Failed to reconstruct list from level data"));
+            return Err(corrupt_err!("Failed to reconstruct list from level data"));
         }
 
         let value_offsets = Buffer::from(list_offsets.to_byte_slice());
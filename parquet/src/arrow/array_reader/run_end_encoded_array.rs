@@ -0,0 +1,70 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use crate::arrow::array_reader::ArrayReader;
+use crate::errors::Result;
+use arrow_array::ArrayRef;
+use arrow_cast::cast;
+use arrow_schema::DataType as ArrowType;
+use std::any::Any;
+
+/// Decodes a leaf column with an inner [`ArrayReader`] for its values, and run end
+/// encodes the resulting arrays to produce an Arrow `RunEndEncoded` type
+pub struct RunEndEncodedArrayReader {
+    data_type: ArrowType,
+    values_reader: Box<dyn ArrayReader>,
+}
+
+impl RunEndEncodedArrayReader {
+    /// Construct run end encoded array reader, producing arrays of `data_type`
+    pub fn new(values_reader: Box<dyn ArrayReader>, data_type: ArrowType) -> Self {
+        Self {
+            data_type,
+            values_reader,
+        }
+    }
+}
+
+impl ArrayReader for RunEndEncodedArrayReader {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_data_type(&self) -> &ArrowType {
+        &self.data_type
+    }
+
+    fn read_records(&mut self, batch_size: usize) -> Result<usize> {
+        self.values_reader.read_records(batch_size)
+    }
+
+    fn consume_batch(&mut self) -> Result<ArrayRef> {
+        let values = self.values_reader.consume_batch()?;
+        Ok(cast(&values, &self.data_type)?)
+    }
+
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        self.values_reader.skip_records(num_records)
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        self.values_reader.get_def_levels()
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        self.values_reader.get_rep_levels()
+    }
+}
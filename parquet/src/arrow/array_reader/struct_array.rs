@@ -69,7 +69,7 @@ impl ArrayReader for StructArrayReader {
             match read {
                 Some(expected) => {
                     if expected != child_read {
-                        return Err(general_err!(
+                        return Err(corrupt_err!(
                             "StructArrayReader out of sync in read_records, expected {} read, got {}",
                             expected,
                             child_read
@@ -121,7 +121,7 @@ impl ArrayReader for StructArrayReader {
             .iter()
             .all(|arr| arr.len() == children_array_len);
         if !all_children_len_eq {
-            return Err(general_err!("Not all children array length are the same!"));
+            return Err(corrupt_err!("Not all children array length are the same!"));
         }
 
         let DataType::Struct(fields) = &self.data_type else {
@@ -170,7 +170,7 @@ impl ArrayReader for StructArrayReader {
             }
 
             if bitmap_builder.len() != children_array_len {
-                return Err(general_err!("Failed to decode level data for struct array"));
+                return Err(corrupt_err!("Failed to decode level data for struct array"));
             }
             nulls = Some(NullBuffer::from(bitmap_builder));
         }
@@ -194,7 +194,7 @@ impl ArrayReader for StructArrayReader {
             match skipped {
                 Some(expected) => {
                     if expected != child_skipped {
-                        return Err(general_err!(
+                        return Err(corrupt_err!(
                             "StructArrayReader out of sync, expected {} skipped, got {}",
                             expected,
                             child_skipped
@@ -0,0 +1,66 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Row group pruning using bloom filters
+
+use crate::bloom_filter::Sbbf;
+use crate::data_type::AsBytes;
+
+/// An equality predicate on a leaf column, used to prune row groups with
+/// bloom filters, see [`ParquetRecordBatchReaderBuilder::with_bloom_filter_predicates`]
+///
+/// The predicate is satisfied by rows where the column is equal to any of `values`.
+///
+/// Bloom filters are computed on the physical representation of values, so `values`
+/// must be provided in the physical type of the column, e.g. `i32` for an `INT32`
+/// column (including those read as [`Int8`], [`Int16`] or [`Date32`]), `i64` for an
+/// `INT64` column, and `str` or `[u8]` for a `BYTE_ARRAY` column.
+///
+/// [`ParquetRecordBatchReaderBuilder::with_bloom_filter_predicates`]: super::ParquetRecordBatchReaderBuilder::with_bloom_filter_predicates
+/// [`Int8`]: arrow_schema::DataType::Int8
+/// [`Int16`]: arrow_schema::DataType::Int16
+/// [`Date32`]: arrow_schema::DataType::Date32
+#[derive(Debug, Clone)]
+pub struct BloomFilterPredicate {
+    column_idx: usize,
+    values: Vec<Vec<u8>>,
+}
+
+impl BloomFilterPredicate {
+    /// Create a new [`BloomFilterPredicate`] on the leaf column at `column_idx` in
+    /// the parquet schema, satisfied by any of `values`
+    pub fn new<'a, T, I>(column_idx: usize, values: I) -> Self
+    where
+        T: AsBytes + ?Sized + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        Self {
+            column_idx,
+            values: values.into_iter().map(|v| v.as_bytes().to_vec()).collect(),
+        }
+    }
+
+    /// Returns the index of the leaf column in the parquet schema
+    pub fn column_idx(&self) -> usize {
+        self.column_idx
+    }
+
+    /// Returns `false` if `bloom_filter` guarantees that none of the values are present
+    pub(crate) fn check(&self, bloom_filter: &Sbbf) -> bool {
+        self.values.iter().any(|v| bloom_filter.check(v.as_slice()))
+    }
+}
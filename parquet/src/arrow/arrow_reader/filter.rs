@@ -172,11 +172,17 @@ where
 /// to filter the data after the RecordBatch has been fully decoded, if the eliminated rows are
 /// not contiguous.
 ///
+/// Choosing a good order for the predicates can be hard, as it depends on the data. See
+/// [`RowFilter::with_adaptive_ordering`] to instead let the reader order the predicates
+/// based on their observed selectivity and cost.
+///
 /// [`RowSelection`]: crate::arrow::arrow_reader::RowSelection
 /// [`ArrowReaderBuilder::with_row_filter`]: crate::arrow::arrow_reader::ArrowReaderBuilder::with_row_filter
 pub struct RowFilter {
     /// A list of [`ArrowPredicate`]
     pub(crate) predicates: Vec<Box<dyn ArrowPredicate>>,
+    /// If true, the predicates may be evaluated in a different order
+    pub(crate) adaptive_ordering: bool,
 }
 
 impl Debug for RowFilter {
@@ -188,8 +194,38 @@ impl Debug for RowFilter {
 impl RowFilter {
     /// Create a new [`RowFilter`] from an array of [`ArrowPredicate`]
     pub fn new(predicates: Vec<Box<dyn ArrowPredicate>>) -> Self {
-        Self { predicates }
+        Self {
+            predicates,
+            adaptive_ordering: false,
+        }
+    }
+
+    /// Reorder the predicates based on their observed selectivity and cost (defaults to `false`)
+    ///
+    /// By default, predicates are evaluated in the order they were provided. If set, the
+    /// predicates are instead evaluated one row group at a time, and after each row group
+    /// are reordered so that the predicates eliminating the most rows relative to their
+    /// cost are evaluated first. The cost of a predicate is estimated from the uncompressed
+    /// size of the columns in its [`ProjectionMask`].
+    ///
+    /// As all predicates must evaluate to `true` for a row to be returned, this does not
+    /// change the rows returned, but a predicate may be passed rows that would otherwise
+    /// have been eliminated by a predicate provided before it.
+    ///
+    /// This is currently only supported by [`ParquetRecordBatchReaderBuilder`], other
+    /// readers evaluate the predicates in order.
+    ///
+    /// [`ParquetRecordBatchReaderBuilder`]: crate::arrow::arrow_reader::ParquetRecordBatchReaderBuilder
+    pub fn with_adaptive_ordering(mut self, adaptive_ordering: bool) -> Self {
+        self.adaptive_ordering = adaptive_ordering;
+        self
+    }
+
+    /// Returns true if the predicates may be reordered, see [`Self::with_adaptive_ordering`]
+    pub fn adaptive_ordering(&self) -> bool {
+        self.adaptive_ordering
     }
+
     /// Returns the inner predicates
     pub fn predicates(&self) -> &Vec<Box<dyn ArrowPredicate>> {
         &self.predicates
@@ -199,3 +235,100 @@ impl RowFilter {
         self.predicates
     }
 }
+
+/// Orders the predicates of a [`RowFilter`] based on their observed selectivity and cost
+///
+/// See [`RowFilter::with_adaptive_ordering`]
+#[derive(Debug)]
+pub(crate) struct PredicateOrdering {
+    /// The indices of the predicates in the order they should be evaluated
+    order: Vec<usize>,
+    /// The observations for each predicate
+    observed: Vec<PredicateObservations>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct PredicateObservations {
+    /// The number of rows the predicate was evaluated on
+    rows_in: usize,
+    /// The number of rows selected by the predicate
+    rows_out: usize,
+    /// The estimated total cost of evaluating the predicate
+    cost: f64,
+}
+
+impl PredicateOrdering {
+    /// Create a new [`PredicateOrdering`] for `num_predicates` predicates in their original order
+    pub(crate) fn new(num_predicates: usize) -> Self {
+        Self {
+            order: (0..num_predicates).collect(),
+            observed: vec![PredicateObservations::default(); num_predicates],
+        }
+    }
+
+    /// Returns the indices of the predicates in the order they should be evaluated
+    pub(crate) fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Record that the predicate at `idx` selected `rows_out` of `rows_in` rows, at an
+    /// estimated cost of `cost_per_row` for each row evaluated
+    pub(crate) fn record(
+        &mut self,
+        idx: usize,
+        rows_in: usize,
+        rows_out: usize,
+        cost_per_row: f64,
+    ) {
+        let observed = &mut self.observed[idx];
+        observed.rows_in += rows_in;
+        observed.rows_out += rows_out;
+        observed.cost += cost_per_row * rows_in as f64;
+    }
+
+    /// Reorder the predicates by increasing cost per eliminated row
+    ///
+    /// Predicates that have not been evaluated on any rows are evaluated first, and
+    /// the order of predicates with equal rank is preserved.
+    pub(crate) fn reorder(&mut self) {
+        let rank = |idx: usize| {
+            let observed = &self.observed[idx];
+            if observed.rows_in == 0 {
+                return 0.;
+            }
+            let rows_in = observed.rows_in as f64;
+            let eliminated = 1. - observed.rows_out as f64 / rows_in;
+            // Infinite for a predicate that eliminates no rows
+            (observed.cost / rows_in) / eliminated
+        };
+        self.order.sort_by(|a, b| rank(*a).total_cmp(&rank(*b)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_predicate_ordering() {
+        let mut ordering = PredicateOrdering::new(4);
+        assert_eq!(ordering.order(), &[0, 1, 2, 3]);
+
+        // Expensive predicate that eliminates no rows
+        ordering.record(0, 100, 100, 10.);
+        // Cheap predicate that eliminates half of the rows
+        ordering.record(1, 100, 50, 1.);
+        // Expensive predicate that eliminates almost all rows
+        ordering.record(2, 50, 1, 4.);
+        // Predicate 3 is not evaluated
+        ordering.reorder();
+        assert_eq!(ordering.order(), &[3, 1, 2, 0]);
+
+        // Observations accumulate
+        ordering.record(3, 100, 100, 1.);
+        ordering.record(1, 100, 100, 1.);
+        ordering.record(1, 100, 100, 1.);
+        ordering.reorder();
+        assert_eq!(ordering.order(), &[2, 1, 3, 0]);
+    }
+}
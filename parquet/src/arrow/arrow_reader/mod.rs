@@ -19,9 +19,15 @@
 
 use arrow_array::cast::AsArray;
 use arrow_array::{Array, RecordBatch, RecordBatchReader};
-use arrow_schema::{ArrowError, DataType as ArrowType, FieldRef, Schema, SchemaRef};
+use arrow_schema::{
+    ArrowError, DECIMAL32_MAX_PRECISION, DECIMAL64_MAX_PRECISION, DataType as ArrowType, FieldRef,
+    Fields, Schema, SchemaRef,
+};
 use arrow_select::filter::filter_record_batch;
+pub use bloom_filter::BloomFilterPredicate;
+use filter::PredicateOrdering;
 pub use filter::{ArrowPredicate, ArrowPredicateFn, RowFilter};
+pub use pruning::{StatisticsPredicate, StatisticsPruner};
 pub use selection::{RowSelection, RowSelectionCursor, RowSelectionPolicy, RowSelector};
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
@@ -32,7 +38,7 @@ use crate::arrow::schema::{
     ParquetField, parquet_to_arrow_schema_and_fields, virtual_type::is_virtual_column,
 };
 use crate::arrow::{FieldLevels, ProjectionMask, parquet_to_arrow_field_levels_with_virtual};
-use crate::basic::{BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash};
+use crate::basic::{BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, LogicalType};
 use crate::bloom_filter::{
     SBBF_HEADER_SIZE_ESTIMATE, Sbbf, chunk_read_bloom_filter_header_and_offset,
 };
@@ -45,14 +51,21 @@ use crate::file::metadata::{
     ParquetStatisticsPolicy, RowGroupMetaData,
 };
 use crate::file::reader::{ChunkReader, SerializedPageReader};
-use crate::schema::types::SchemaDescriptor;
+use crate::schema::types::{SchemaDescriptor, Type};
 
 use crate::arrow::arrow_reader::metrics::ArrowReaderMetrics;
 // Exposed so integration tests and benchmarks can temporarily override the threshold.
 pub use read_plan::{ReadPlan, ReadPlanBuilder};
 
+#[cfg(feature = "async")]
+pub(crate) use output_schema::OutputSchema;
+
+mod bloom_filter;
 mod filter;
 pub mod metrics;
+#[cfg(feature = "async")]
+mod output_schema;
+mod pruning;
 mod read_plan;
 pub(crate) mod selection;
 pub mod statistics;
@@ -139,6 +152,10 @@ pub struct ArrowReaderBuilder<T> {
     pub(crate) metrics: ArrowReaderMetrics,
 
     pub(crate) max_predicate_cache_size: usize,
+
+    pub(crate) strict_logical_types: bool,
+
+    pub(crate) output_schema: Option<SchemaRef>,
 }
 
 impl<T: Debug> Debug for ArrowReaderBuilder<T> {
@@ -157,6 +174,8 @@ impl<T: Debug> Debug for ArrowReaderBuilder<T> {
             .field("limit", &self.limit)
             .field("offset", &self.offset)
             .field("metrics", &self.metrics)
+            .field("strict_logical_types", &self.strict_logical_types)
+            .field("output_schema", &self.output_schema)
             .finish()
     }
 }
@@ -178,6 +197,8 @@ impl<T> ArrowReaderBuilder<T> {
             offset: None,
             metrics: ArrowReaderMetrics::Disabled,
             max_predicate_cache_size: 100 * 1024 * 1024, // 100MB default cache size
+            strict_logical_types: metadata.strict_logical_types,
+            output_schema: None,
         }
     }
 
@@ -222,6 +243,70 @@ impl<T> ArrowReaderBuilder<T> {
         }
     }
 
+    /// Only read data from the provided nested fields
+    ///
+    /// Each element of `projection` is the name of a top-level column, and the paths of
+    /// the fields to read within it. A path is a `.` separated list of struct or map
+    /// field names, relative to the column, with lists traversed transparently. If no
+    /// paths are provided, the entire column is read.
+    ///
+    /// Only the parquet columns below the selected fields are read, and the output
+    /// schema retains only the selected fields. For example, given a column
+    /// `event: { id, properties: { key, value } }`, the projection
+    /// `[("event", vec!["properties.key"])]` produces a column of type
+    /// `event: { properties: { key } }`.
+    ///
+    /// Names that do not match a field are ignored. See [`Self::with_projection`]
+    /// and [`ProjectionMask::try_columns`] to select columns by parquet column path.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, RecordBatchReader, StructArray};
+    /// # use arrow_schema::{DataType, Field, Fields};
+    /// # use bytes::Bytes;
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    /// let event = StructArray::from(vec![
+    ///     (Arc::new(Field::new("id", DataType::Int32, false)), Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+    ///     (Arc::new(Field::new("kind", DataType::Int32, false)), Arc::new(Int32Array::from(vec![3, 4])) as ArrayRef),
+    /// ]);
+    /// let batch = RecordBatch::try_from_iter([("event", Arc::new(event) as ArrayRef)]).unwrap();
+    /// let mut buffer = Vec::new();
+    /// let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), None).unwrap();
+    /// writer.write(&batch).unwrap();
+    /// writer.close().unwrap();
+    ///
+    /// let reader = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer))
+    ///     .unwrap()
+    ///     .with_nested_projection([("event", vec!["kind"])])
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let fields = Fields::from(vec![Field::new("kind", DataType::Int32, false)]);
+    /// assert_eq!(reader.schema().field(0).data_type(), &DataType::Struct(fields));
+    /// ```
+    pub fn with_nested_projection<'a>(
+        self,
+        projection: impl IntoIterator<Item = (&'a str, Vec<&'a str>)>,
+    ) -> Self {
+        let mut leaves = vec![];
+        if let Some(root) = &self.fields {
+            for (column, paths) in projection {
+                if paths.is_empty() {
+                    root.nested_leaves(&[column], &mut leaves);
+                }
+                for path in paths {
+                    let path: Vec<_> = std::iter::once(column).chain(path.split('.')).collect();
+                    root.nested_leaves(&path, &mut leaves);
+                }
+            }
+        }
+        let mask = ProjectionMask::leaves(self.parquet_schema(), leaves);
+        self.with_projection(mask)
+    }
+
     /// Configure how row selections should be materialised during execution
     ///
     /// See [`RowSelectionPolicy`] for more details
@@ -298,6 +383,68 @@ impl<T> ArrowReaderBuilder<T> {
         }
     }
 
+    /// Only read pages that may contain rows satisfying all of `predicates`, according
+    /// to the min/max statistics in the page index
+    ///
+    /// This builds a [`RowSelection`] with [`StatisticsPruner::prune_pages`] for the row
+    /// groups selected by [`Self::with_row_groups`], or all row groups if not set, and
+    /// intersects it with any selection provided by [`Self::with_row_selection`]. As a
+    /// [`RowSelection`] applies to the rows of the selected row groups, the row groups
+    /// should not be changed afterwards.
+    ///
+    /// No pages are skipped if the page index has not been loaded, see
+    /// [`ArrowReaderOptions::with_page_index_policy`]. As only whole pages are skipped,
+    /// the rows read may not satisfy `predicates`, and must still be filtered if required.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    /// # use bytes::Bytes;
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::arrow::arrow_reader::{
+    /// #     ArrowReaderOptions, ParquetRecordBatchReaderBuilder, StatisticsPredicate,
+    /// # };
+    /// # use parquet::file::metadata::PageIndexPolicy;
+    /// # use parquet::file::properties::WriterProperties;
+    /// # let batch = RecordBatch::try_from_iter([
+    /// #     ("a", Arc::new(Int32Array::from_iter_values(0..100)) as _),
+    /// # ]).unwrap();
+    /// # let props = WriterProperties::builder()
+    /// #     .set_data_page_row_count_limit(10)
+    /// #     .set_write_batch_size(10)
+    /// #     .build();
+    /// # let mut file = Vec::new();
+    /// # let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), Some(props)).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// # let file = Bytes::from(file);
+    /// let options = ArrowReaderOptions::new().with_page_index_policy(PageIndexPolicy::Required);
+    /// // Only read the pages that may contain `a = 42`
+    /// let value = Arc::new(Int32Array::from(vec![42])) as ArrayRef;
+    /// let reader = ParquetRecordBatchReaderBuilder::try_new_with_options(file, options)
+    ///     .unwrap()
+    ///     .with_page_index_predicates(&[StatisticsPredicate::eq("a", value)])
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    /// let num_rows: usize = reader.map(|b| b.unwrap().num_rows()).sum();
+    /// assert_eq!(num_rows, 10);
+    /// ```
+    pub fn with_page_index_predicates(self, predicates: &[StatisticsPredicate]) -> Result<Self> {
+        let row_groups = match &self.row_groups {
+            Some(row_groups) => row_groups.clone(),
+            None => (0..self.metadata.num_row_groups()).collect(),
+        };
+        let parquet_schema = self.metadata.file_metadata().schema_descr();
+        let pruner = StatisticsPruner::new(&self.schema, parquet_schema, &self.metadata);
+        let pruned = pruner.prune_pages(predicates, &row_groups)?;
+        let selection = match &self.selection {
+            Some(selection) => selection.intersection(&pruned),
+            None => pruned,
+        };
+        Ok(self.with_row_selection(selection))
+    }
+
     /// Provide a [`RowFilter`] to skip decoding rows
     ///
     /// Row filters are applied after row group selection and row selection
@@ -432,6 +579,57 @@ impl<T> ArrowReaderBuilder<T> {
     }
 }
 
+/// Returns an error if `projection`, or the projection of any predicate in `filter`,
+/// includes a column annotated with a [`LogicalType`] that is not known to this crate
+///
+/// See [`ArrowReaderOptions::with_strict_logical_types`]
+pub(crate) fn check_logical_types(
+    metadata: &ParquetMetaData,
+    projection: &ProjectionMask,
+    filter: Option<&RowFilter>,
+) -> Result<()> {
+    /// Visits the leaves of `field`, where `unknown` is the name and field id of the
+    /// nearest ancestor annotated with an unknown logical type, if any
+    fn visit(
+        field: &Type,
+        included: &dyn Fn(usize) -> bool,
+        unknown: Option<(&str, i16)>,
+        schema: &SchemaDescriptor,
+        leaf_idx: &mut usize,
+    ) -> Result<()> {
+        let unknown = match field.get_basic_info().logical_type_ref() {
+            Some(LogicalType::_Unknown { field_id }) => Some((field.name(), *field_id)),
+            _ => unknown,
+        };
+        if !field.is_primitive() {
+            return field
+                .get_fields()
+                .iter()
+                .try_for_each(|f| visit(f, included, unknown, schema, leaf_idx));
+        }
+
+        let idx = *leaf_idx;
+        *leaf_idx += 1;
+        match unknown {
+            Some((name, field_id)) if included(idx) => Err(arrow_err!(
+                "Cannot read column \"{}\": field \"{}\" is annotated with an unknown logical type with field id {}",
+                schema.column(idx).path().string(),
+                name,
+                field_id
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    let predicates = filter.map(|f| f.predicates.as_slice()).unwrap_or_default();
+    let included = |idx| {
+        projection.leaf_included(idx)
+            || predicates.iter().any(|p| p.projection().leaf_included(idx))
+    };
+    let schema = metadata.file_metadata().schema_descr();
+    visit(schema.root_schema(), &included, None, schema, &mut 0)
+}
+
 /// Options that control how [`ParquetMetaData`] is read when constructing
 /// an Arrow reader.
 ///
@@ -466,6 +664,19 @@ pub struct ArrowReaderOptions {
     pub(crate) file_decryption_properties: Option<Arc<FileDecryptionProperties>>,
 
     virtual_columns: Vec<FieldRef>,
+
+    /// If true, reading a column with an unknown logical type is an error
+    strict_logical_types: bool,
+
+    /// If true, read `BYTE_ARRAY` columns as [`ArrowType::Utf8View`] and [`ArrowType::BinaryView`]
+    schema_view_types: bool,
+
+    /// If true, read `DECIMAL` columns with a small precision as [`ArrowType::Decimal32`]
+    /// and [`ArrowType::Decimal64`]
+    small_decimal_types: bool,
+
+    /// Columns to read as [`ArrowType::Dictionary`]
+    dictionary_columns: Vec<String>,
 }
 
 impl ArrowReaderOptions {
@@ -788,6 +999,170 @@ impl ArrowReaderOptions {
         })
     }
 
+    /// Return an error when reading a column annotated with a [`LogicalType`] that is not
+    /// known to this crate (defaults to `false`)
+    ///
+    /// Files written by newer writers may contain logical types added to the format after
+    /// this crate was released. By default, such columns are read as the Arrow type of their
+    /// converted or physical type, with the [`PARQUET_UNKNOWN_LOGICAL_TYPE_META_KEY`]
+    /// metadata key set on the corresponding [`Field`].
+    ///
+    /// If set, building a reader that projects such a column instead returns an error.
+    /// Columns that are not projected can always be skipped.
+    ///
+    /// [`LogicalType`]: crate::basic::LogicalType
+    /// [`PARQUET_UNKNOWN_LOGICAL_TYPE_META_KEY`]: crate::arrow::PARQUET_UNKNOWN_LOGICAL_TYPE_META_KEY
+    /// [`Field`]: arrow_schema::Field
+    pub fn with_strict_logical_types(self, strict_logical_types: bool) -> Self {
+        Self {
+            strict_logical_types,
+            ..self
+        }
+    }
+
+    /// Read string and binary columns as [`ArrowType::Utf8View`] and [`ArrowType::BinaryView`]
+    /// (defaults to `false`)
+    ///
+    /// If set, columns that would otherwise be read as `Utf8` or `LargeUtf8` are read as
+    /// `Utf8View`, and those read as `Binary` or `LargeBinary` as `BinaryView`, including
+    /// within nested types. Values are decoded into views of the decompressed page data,
+    /// avoiding copying them into a separate buffer, which benefits downstream kernels
+    /// operating on strings, such as comparisons and filters.
+    ///
+    /// This is equivalent to supplying a [schema hint](Self::with_schema) with these types
+    /// changed, and is applied on top of any schema hint or embedded arrow schema. Columns
+    /// read as dictionaries, see [`Self::with_dictionary_columns`], are not affected.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use bytes::Bytes;
+    /// # use arrow_array::{ArrayRef, RecordBatch, StringArray};
+    /// # use arrow_schema::DataType;
+    /// # use parquet::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    /// # use parquet::arrow::ArrowWriter;
+    /// let cities = StringArray::from(vec!["Berlin", "Paris", "Tokyo"]);
+    /// let batch = RecordBatch::try_from_iter([("city", Arc::new(cities) as ArrayRef)]).unwrap();
+    /// let mut file = Vec::new();
+    /// let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), None).unwrap();
+    /// writer.write(&batch).unwrap();
+    /// writer.close().unwrap();
+    ///
+    /// let options = ArrowReaderOptions::new().with_schema_view_types(true);
+    /// let mut reader = ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(file), options)
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    /// let batch = reader.next().unwrap().unwrap();
+    /// assert_eq!(batch.column(0).data_type(), &DataType::Utf8View);
+    /// ```
+    pub fn with_schema_view_types(self, schema_view_types: bool) -> Self {
+        Self {
+            schema_view_types,
+            ..self
+        }
+    }
+
+    /// Read decimal columns with a small precision as [`ArrowType::Decimal32`] and
+    /// [`ArrowType::Decimal64`] (defaults to `false`)
+    ///
+    /// If set, columns that would otherwise be read as `Decimal128` with a precision of
+    /// at most 9 are read as `Decimal32`, and those with a precision of at most 18 as
+    /// `Decimal64`, including within nested types. This reduces the memory used by
+    /// typical decimal columns, such as monetary amounts, by 2-4x.
+    ///
+    /// This is equivalent to supplying a [schema hint](Self::with_schema) with these types
+    /// changed, and is applied on top of any schema hint or embedded arrow schema.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use bytes::Bytes;
+    /// # use arrow_array::{ArrayRef, Decimal128Array, RecordBatch};
+    /// # use arrow_schema::DataType;
+    /// # use parquet::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    /// # use parquet::arrow::ArrowWriter;
+    /// let prices = Decimal128Array::from(vec![1999, 2450, 999])
+    ///     .with_precision_and_scale(9, 2)
+    ///     .unwrap();
+    /// let batch = RecordBatch::try_from_iter([("price", Arc::new(prices) as ArrayRef)]).unwrap();
+    /// let mut file = Vec::new();
+    /// let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), None).unwrap();
+    /// writer.write(&batch).unwrap();
+    /// writer.close().unwrap();
+    ///
+    /// let options = ArrowReaderOptions::new().with_small_decimal_types(true);
+    /// let mut reader = ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(file), options)
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    /// let batch = reader.next().unwrap().unwrap();
+    /// assert_eq!(batch.column(0).data_type(), &DataType::Decimal32(9, 2));
+    /// ```
+    pub fn with_small_decimal_types(self, small_decimal_types: bool) -> Self {
+        Self {
+            small_decimal_types,
+            ..self
+        }
+    }
+
+    /// Read the given columns as [`ArrowType::Dictionary`] with [`ArrowType::Int32`] keys
+    ///
+    /// Columns are identified by their name, with the names of any enclosing struct
+    /// columns separated by `.`, e.g. `"a.b"`. Only columns of `Utf8`, `LargeUtf8`,
+    /// `Binary`, `LargeBinary` and `FixedSizeBinary` type can be read as dictionaries,
+    /// `Utf8View` and `BinaryView` columns are read as dictionaries of `Utf8` and `Binary`
+    /// respectively. Building the reader returns an error if a column does not exist or
+    /// has another type.
+    ///
+    /// This is equivalent to supplying a [schema hint](Self::with_schema) with
+    /// these columns changed to dictionaries, and is applied on top of any schema
+    /// hint or embedded arrow schema.
+    ///
+    /// Where a column chunk is dictionary encoded, the dictionary page is used directly
+    /// as the values of the returned arrays, avoiding materializing repeated values.
+    /// If a batch contains values from pages that are not dictionary encoded, such as
+    /// when the writer fell back to `PLAIN` encoding, or from multiple column chunks, a
+    /// new dictionary is computed for the batch instead. An error is returned if the
+    /// number of distinct values in a batch exceeds the range of the key type.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use bytes::Bytes;
+    /// # use arrow_array::{ArrayRef, RecordBatch, StringArray};
+    /// # use arrow_schema::DataType;
+    /// # use parquet::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    /// # use parquet::arrow::ArrowWriter;
+    /// let cities = StringArray::from(vec!["Berlin", "Berlin", "Paris", "Berlin", "Paris"]);
+    /// let batch = RecordBatch::try_from_iter([("city", Arc::new(cities) as ArrayRef)]).unwrap();
+    /// let mut file = Vec::new();
+    /// let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), None).unwrap();
+    /// writer.write(&batch).unwrap();
+    /// writer.close().unwrap();
+    ///
+    /// let options = ArrowReaderOptions::new().with_dictionary_columns(["city"]);
+    /// let mut reader = ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(file), options)
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    /// let batch = reader.next().unwrap().unwrap();
+    /// assert_eq!(
+    ///     batch.column(0).data_type(),
+    ///     &DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+    /// );
+    /// ```
+    pub fn with_dictionary_columns<I, S>(self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            dictionary_columns: columns.into_iter().map(Into::into).collect(),
+            ..self
+        }
+    }
+
     #[deprecated(
         since = "57.2.0",
         note = "Use `column_index_policy` or `offset_index_policy` instead"
@@ -833,6 +1208,134 @@ impl ArrowReaderOptions {
     }
 }
 
+/// Returns the schema hint to use for `fields` if [`ArrowReaderOptions::with_schema_view_types`],
+/// [`ArrowReaderOptions::with_small_decimal_types`] or
+/// [`ArrowReaderOptions::with_dictionary_columns`] are set
+fn type_option_fields(fields: &Fields, options: &ArrowReaderOptions) -> Result<Option<Fields>> {
+    if !options.schema_view_types
+        && !options.small_decimal_types
+        && options.dictionary_columns.is_empty()
+    {
+        return Ok(None);
+    }
+    let mut fields = fields.clone();
+    if options.schema_view_types {
+        fields = fields
+            .iter()
+            .map(|f| map_leaf_field(f, &view_type))
+            .collect();
+    }
+    if options.small_decimal_types {
+        fields = fields
+            .iter()
+            .map(|f| map_leaf_field(f, &small_decimal_type))
+            .collect();
+    }
+    if !options.dictionary_columns.is_empty() {
+        fields = dictionary_fields(&fields, &options.dictionary_columns)?;
+    }
+    Ok(Some(fields))
+}
+
+/// Returns `field` with the types of any leaf fields replaced by `f`, if it returns `Some`
+fn map_leaf_field(field: &FieldRef, f: &impl Fn(&ArrowType) -> Option<ArrowType>) -> FieldRef {
+    let map = |field| map_leaf_field(field, f);
+    let data_type = match field.data_type() {
+        ArrowType::List(c) => ArrowType::List(map(c)),
+        ArrowType::LargeList(c) => ArrowType::LargeList(map(c)),
+        ArrowType::ListView(c) => ArrowType::ListView(map(c)),
+        ArrowType::LargeListView(c) => ArrowType::LargeListView(map(c)),
+        ArrowType::FixedSizeList(c, size) => ArrowType::FixedSizeList(map(c), *size),
+        ArrowType::Map(c, sorted) => ArrowType::Map(map(c), *sorted),
+        ArrowType::Struct(children) => ArrowType::Struct(children.iter().map(map).collect()),
+        data_type => match f(data_type) {
+            Some(data_type) => data_type,
+            None => return Arc::clone(field),
+        },
+    };
+    Arc::new(field.as_ref().clone().with_data_type(data_type))
+}
+
+/// Returns the view equivalent of string and binary types
+///
+/// See [`ArrowReaderOptions::with_schema_view_types`]
+fn view_type(data_type: &ArrowType) -> Option<ArrowType> {
+    match data_type {
+        ArrowType::Utf8 | ArrowType::LargeUtf8 => Some(ArrowType::Utf8View),
+        ArrowType::Binary | ArrowType::LargeBinary => Some(ArrowType::BinaryView),
+        _ => None,
+    }
+}
+
+/// Returns the smallest decimal type for `Decimal128` types with a small precision
+///
+/// See [`ArrowReaderOptions::with_small_decimal_types`]
+fn small_decimal_type(data_type: &ArrowType) -> Option<ArrowType> {
+    match data_type {
+        ArrowType::Decimal128(p, s) if *p <= DECIMAL32_MAX_PRECISION => {
+            Some(ArrowType::Decimal32(*p, *s))
+        }
+        ArrowType::Decimal128(p, s) if *p <= DECIMAL64_MAX_PRECISION => {
+            Some(ArrowType::Decimal64(*p, *s))
+        }
+        _ => None,
+    }
+}
+
+/// Returns `fields` with the columns at `paths` read as dictionaries
+///
+/// See [`ArrowReaderOptions::with_dictionary_columns`]
+fn dictionary_fields(fields: &Fields, paths: &[String]) -> Result<Fields> {
+    let mut fields = fields.to_vec();
+    for path in paths {
+        let parts: Vec<_> = path.split('.').collect();
+        set_dictionary_field(&mut fields, &parts, path)?;
+    }
+    Ok(fields.into())
+}
+
+fn set_dictionary_field(fields: &mut [FieldRef], parts: &[&str], path: &str) -> Result<()> {
+    let not_found = || general_err!("Cannot read column \"{}\" as dictionary: not found", path);
+    let (name, rest) = parts.split_first().ok_or_else(not_found)?;
+    let field = fields
+        .iter_mut()
+        .find(|f| f.name() == name)
+        .ok_or_else(not_found)?;
+
+    let data_type = match (field.data_type(), rest) {
+        (ArrowType::Struct(children), [_, ..]) => {
+            let mut children = children.to_vec();
+            set_dictionary_field(&mut children, rest, path)?;
+            ArrowType::Struct(children.into())
+        }
+        (_, [_, ..]) => return Err(not_found()),
+        (ArrowType::Dictionary(_, _), []) => return Ok(()),
+        (
+            ArrowType::Utf8
+            | ArrowType::LargeUtf8
+            | ArrowType::Binary
+            | ArrowType::LargeBinary
+            | ArrowType::FixedSizeBinary(_),
+            [],
+        ) => dictionary_type(field.data_type().clone()),
+        (ArrowType::Utf8View, []) => dictionary_type(ArrowType::Utf8),
+        (ArrowType::BinaryView, []) => dictionary_type(ArrowType::Binary),
+        (data_type, []) => {
+            return Err(general_err!(
+                "Cannot read column \"{}\" of type {} as dictionary",
+                path,
+                data_type
+            ));
+        }
+    };
+    *field = Arc::new(field.as_ref().clone().with_data_type(data_type));
+    Ok(())
+}
+
+fn dictionary_type(value_type: ArrowType) -> ArrowType {
+    ArrowType::Dictionary(Box::new(ArrowType::Int32), Box::new(value_type))
+}
+
 /// The metadata necessary to construct a [`ArrowReaderBuilder`]
 ///
 /// Note this structure is cheaply clone-able as it consists of several arcs.
@@ -855,6 +1358,8 @@ pub struct ArrowReaderMetadata {
     pub(crate) schema: SchemaRef,
     /// The Parquet schema (root field)
     pub(crate) fields: Option<Arc<ParquetField>>,
+    /// See [`ArrowReaderOptions::with_strict_logical_types`]
+    pub(crate) strict_logical_types: bool,
 }
 
 impl ArrowReaderMetadata {
@@ -891,28 +1396,58 @@ impl ArrowReaderMetadata {
     /// of the settings in `options`. See [`Self::load`] to load metadata including the page index if needed.
     pub fn try_new(metadata: Arc<ParquetMetaData>, options: ArrowReaderOptions) -> Result<Self> {
         match options.supplied_schema {
-            Some(supplied_schema) => Self::with_supplied_schema(
-                metadata,
-                supplied_schema.clone(),
-                &options.virtual_columns,
-            ),
+            Some(ref supplied_schema) => {
+                let supplied_schema = match type_option_fields(supplied_schema.fields(), &options)?
+                {
+                    None => Arc::clone(supplied_schema),
+                    Some(fields) => Arc::new(Schema::new_with_metadata(
+                        fields,
+                        supplied_schema.metadata().clone(),
+                    )),
+                };
+                Self::with_supplied_schema(
+                    metadata,
+                    supplied_schema,
+                    &options.virtual_columns,
+                    options.strict_logical_types,
+                )
+            }
             None => {
                 let kv_metadata = match options.skip_arrow_metadata {
                     true => None,
                     false => metadata.file_metadata().key_value_metadata(),
                 };
 
-                let (schema, fields) = parquet_to_arrow_schema_and_fields(
+                let (mut schema, mut fields) = parquet_to_arrow_schema_and_fields(
                     metadata.file_metadata().schema_descr(),
                     ProjectionMask::all(),
                     kv_metadata,
                     &options.virtual_columns,
                 )?;
 
+                // Use the inferred schema, with any type options applied, as the hint
+                let inferred: Fields = schema
+                    .fields()
+                    .iter()
+                    .filter(|f| !is_virtual_column(f))
+                    .cloned()
+                    .collect();
+                if let Some(hint) = type_option_fields(&inferred, &options)? {
+                    let levels = parquet_to_arrow_field_levels_with_virtual(
+                        metadata.file_metadata().schema_descr(),
+                        ProjectionMask::all(),
+                        Some(&hint),
+                        &options.virtual_columns,
+                    )?;
+                    schema = Schema::new_with_metadata(levels.fields, schema.metadata().clone());
+                    fields = levels.levels;
+                }
+
                 Ok(Self {
                     metadata,
                     schema: Arc::new(schema),
                     fields: fields.map(Arc::new),
+                    strict_logical_types: options.strict_logical_types,
                 })
             }
         }
@@ -922,6 +1457,7 @@ impl ArrowReaderMetadata {
         metadata: Arc<ParquetMetaData>,
         supplied_schema: SchemaRef,
         virtual_columns: &[FieldRef],
+        strict_logical_types: bool,
     ) -> Result<Self> {
         let parquet_schema = metadata.file_metadata().schema_descr();
         let field_levels = parquet_to_arrow_field_levels_with_virtual(
@@ -985,6 +1521,7 @@ impl ArrowReaderMetadata {
             metadata,
             schema: supplied_schema,
             fields: field_levels.levels.map(Arc::new),
+            strict_logical_types,
         })
     }
 
@@ -1124,7 +1661,7 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
         let offset: u64 = if let Some(offset) = column_metadata.bloom_filter_offset() {
             offset
                 .try_into()
-                .map_err(|_| ParquetError::General("Bloom filter offset is invalid".to_string()))?
+                .map_err(|_| ParquetError::Corrupt("Bloom filter offset is invalid".to_string()))?
         } else {
             return Ok(None);
         };
@@ -1160,7 +1697,7 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
             ),
             None => {
                 let bitset_length: usize = header.num_bytes.try_into().map_err(|_| {
-                    ParquetError::General("Bloom filter length is invalid".to_string())
+                    ParquetError::Corrupt("Bloom filter length is invalid".to_string())
                 })?;
                 self.input.0.get_bytes(bitset_offset, bitset_length)?
             }
@@ -1168,10 +1705,195 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
         Ok(Some(Sbbf::new(&bitset)))
     }
 
+    /// Only read row groups that may contain rows satisfying all of `predicates`,
+    /// according to their bloom filters
+    ///
+    /// A row group is skipped if, for any of the [`BloomFilterPredicate`], the bloom
+    /// filter of its column guarantees that none of the values are present. Row groups
+    /// without a bloom filter for a column are not pruned by predicates on that column.
+    ///
+    /// If [`Self::with_row_groups`] has been called, only those row groups are considered.
+    /// As a [`RowSelection`] applies to the rows of the selected row groups, this returns
+    /// an error if [`Self::with_row_selection`] has already been called.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{Int32Array, RecordBatch};
+    /// # use bytes::Bytes;
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::arrow::arrow_reader::{BloomFilterPredicate, ParquetRecordBatchReaderBuilder};
+    /// # use parquet::file::properties::WriterProperties;
+    /// # let batch = RecordBatch::try_from_iter([
+    /// #     ("a", Arc::new(Int32Array::from_iter_values(0..100)) as _),
+    /// # ]).unwrap();
+    /// # let props = WriterProperties::builder()
+    /// #     .set_bloom_filter_enabled(true)
+    /// #     .set_max_row_group_row_count(Some(10))
+    /// #     .build();
+    /// # let mut file = Vec::new();
+    /// # let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), Some(props)).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// # let file = Bytes::from(file);
+    /// // Only read the row groups that may contain `a = 42`
+    /// let predicate = BloomFilterPredicate::new(0, &[42_i32]);
+    /// let builder = ParquetRecordBatchReaderBuilder::try_new(file)
+    ///     .unwrap()
+    ///     .with_bloom_filter_predicates(&[predicate])
+    ///     .unwrap();
+    /// let num_rows: usize = builder.build().unwrap().map(|b| b.unwrap().num_rows()).sum();
+    /// assert!(num_rows < 100);
+    /// ```
+    pub fn with_bloom_filter_predicates(self, predicates: &[BloomFilterPredicate]) -> Result<Self> {
+        if self.selection.is_some() {
+            return Err(general_err!(
+                "Bloom filter predicates must be applied before a row selection"
+            ));
+        }
+        let num_columns = self.metadata.file_metadata().schema_descr().num_columns();
+        if let Some(p) = predicates.iter().find(|p| p.column_idx() >= num_columns) {
+            return Err(general_err!(
+                "Bloom filter predicate column index {} out of bounds for {} columns",
+                p.column_idx(),
+                num_columns
+            ));
+        }
+
+        let row_groups = match &self.row_groups {
+            Some(row_groups) => row_groups.clone(),
+            None => (0..self.metadata.num_row_groups()).collect(),
+        };
+        let mut selected = Vec::with_capacity(row_groups.len());
+        for row_group_idx in row_groups {
+            let mut keep = true;
+            for predicate in predicates {
+                let bloom_filter =
+                    self.get_row_group_column_bloom_filter(row_group_idx, predicate.column_idx())?;
+                if bloom_filter.is_some_and(|sbbf| !predicate.check(&sbbf)) {
+                    keep = false;
+                    break;
+                }
+            }
+            if keep {
+                selected.push(row_group_idx);
+            }
+        }
+        Ok(self.with_row_groups(selected))
+    }
+
     /// Build a [`ParquetRecordBatchReader`]
     ///
     /// Note: this will eagerly evaluate any `RowFilter` before returning
     pub fn build(self) -> Result<ParquetRecordBatchReader> {
+        let PlannedRead {
+            reader,
+            fields,
+            projection,
+            metrics,
+            batch_size: _,
+            plan_builder,
+        } = self.plan()?;
+
+        let array_reader = ArrayReaderBuilder::new(&reader, &metrics)
+            .with_parquet_metadata(&reader.metadata)
+            .build_array_reader(fields.as_deref(), &projection)?;
+
+        Ok(ParquetRecordBatchReader::new(
+            array_reader,
+            plan_builder.build(),
+        ))
+    }
+
+    /// Build a separate [`ParquetRecordBatchReader`] for each row group
+    ///
+    /// The readers share the underlying [`ChunkReader`] and [`ParquetMetaData`],
+    /// including any page index, and can be moved to other threads to decode the
+    /// row groups in parallel, without reopening the file or re-reading its footer.
+    /// Reading the returned readers in order yields the same rows as [`Self::build`].
+    ///
+    /// A reader is returned for each row group selected with [`Self::with_row_groups`],
+    /// or each row group in the file, even if no rows of it are selected. Any
+    /// [`RowSelection`], offset and limit apply to the row groups as a whole, and are
+    /// split between the readers.
+    ///
+    /// Note: as for [`Self::build`], this will eagerly evaluate any `RowFilter` before
+    /// returning
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{Int32Array, RecordBatch};
+    /// # use bytes::Bytes;
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    /// # use parquet::file::properties::WriterProperties;
+    /// # let batch = RecordBatch::try_from_iter([
+    /// #     ("a", Arc::new(Int32Array::from_iter_values(0..100)) as _),
+    /// # ]).unwrap();
+    /// # let props = WriterProperties::builder()
+    /// #     .set_max_row_group_row_count(Some(25))
+    /// #     .build();
+    /// # let mut file = Vec::new();
+    /// # let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), Some(props)).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// # let file = Bytes::from(file);
+    /// let readers = ParquetRecordBatchReaderBuilder::try_new(file)
+    ///     .unwrap()
+    ///     .build_row_group_readers()
+    ///     .unwrap();
+    /// assert_eq!(readers.len(), 4);
+    ///
+    /// // Decode each row group on a separate thread
+    /// let num_rows: usize = std::thread::scope(|s| {
+    ///     let handles: Vec<_> = readers
+    ///         .into_iter()
+    ///         .map(|reader| s.spawn(|| reader.map(|b| b.unwrap().num_rows()).sum::<usize>()))
+    ///         .collect();
+    ///     handles.into_iter().map(|h| h.join().unwrap()).sum()
+    /// });
+    /// assert_eq!(num_rows, 100);
+    /// ```
+    pub fn build_row_group_readers(self) -> Result<Vec<ParquetRecordBatchReader>> {
+        let PlannedRead {
+            reader,
+            fields,
+            projection,
+            metrics,
+            batch_size,
+            plan_builder,
+        } = self.plan()?;
+
+        let policy = *plan_builder.row_selection_policy();
+        let mut remaining = plan_builder.selection().cloned();
+
+        reader
+            .row_groups
+            .iter()
+            .map(|&row_group_idx| {
+                let num_rows = reader.metadata.row_group(row_group_idx).num_rows() as usize;
+                let row_group_reader = ReaderRowGroups {
+                    reader: Arc::clone(&reader.reader),
+                    metadata: Arc::clone(&reader.metadata),
+                    row_groups: vec![row_group_idx],
+                };
+
+                let array_reader = ArrayReaderBuilder::new(&row_group_reader, &metrics)
+                    .with_parquet_metadata(&row_group_reader.metadata)
+                    .build_array_reader(fields.as_deref(), &projection)?;
+
+                let selection = remaining.as_mut().map(|s| s.split_off(num_rows));
+                let read_plan = ReadPlanBuilder::new(batch_size)
+                    .with_selection(selection)
+                    .with_row_selection_policy(policy)
+                    .build();
+
+                Ok(ParquetRecordBatchReader::new(array_reader, read_plan))
+            })
+            .collect()
+    }
+
+    /// Evaluates any `RowFilter`, offset and limit, returning the resulting selection
+    fn plan(self) -> Result<PlannedRead<T>> {
         let Self {
             input,
             metadata,
@@ -1188,8 +1910,15 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
             metrics,
             // Not used for the sync reader, see https://github.com/apache/arrow-rs/issues/8000
             max_predicate_cache_size: _,
+            strict_logical_types,
+            // Only set by ParquetRecordBatchStreamBuilder
+            output_schema: _,
         } = self;
 
+        if strict_logical_types {
+            check_logical_types(&metadata, &projection, filter.as_ref())?;
+        }
+
         // Try to avoid allocate large buffer
         let batch_size = batch_size.min(metadata.file_metadata().num_rows() as usize);
 
@@ -1206,7 +1935,15 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
             .with_row_selection_policy(row_selection_policy);
 
         // Update selection based on any filters
-        if let Some(filter) = filter.as_mut() {
+        if let Some(filter) = filter.as_mut().filter(|f| f.adaptive_ordering) {
+            plan_builder = reader.evaluate_adaptive(
+                plan_builder,
+                filter,
+                fields.as_deref(),
+                &metrics,
+                batch_size,
+            )?;
+        } else if let Some(filter) = filter.as_mut() {
             for predicate in filter.predicates.iter_mut() {
                 // break early if we have ruled out all rows
                 if !plan_builder.selects_any() {
@@ -1221,21 +1958,34 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
             }
         }
 
-        let array_reader = ArrayReaderBuilder::new(&reader, &metrics)
-            .with_parquet_metadata(&reader.metadata)
-            .build_array_reader(fields.as_deref(), &projection)?;
-
-        let read_plan = plan_builder
+        let plan_builder = plan_builder
             .limited(reader.num_rows())
             .with_offset(offset)
             .with_limit(limit)
-            .build_limited()
-            .build();
+            .build_limited();
 
-        Ok(ParquetRecordBatchReader::new(array_reader, read_plan))
+        Ok(PlannedRead {
+            reader,
+            fields,
+            projection,
+            metrics,
+            batch_size,
+            plan_builder,
+        })
     }
 }
 
+/// The state of a [`ParquetRecordBatchReaderBuilder`] once any `RowFilter`, offset and
+/// limit have been applied to the selection of [`Self::plan_builder`]
+struct PlannedRead<T: ChunkReader> {
+    reader: ReaderRowGroups<T>,
+    fields: Option<Arc<ParquetField>>,
+    projection: ProjectionMask,
+    metrics: ArrowReaderMetrics,
+    batch_size: usize,
+    plan_builder: ReadPlanBuilder,
+}
+
 struct ReaderRowGroups<T: ChunkReader> {
     reader: Arc<T>,
 
@@ -1244,6 +1994,84 @@ struct ReaderRowGroups<T: ChunkReader> {
     row_groups: Vec<usize>,
 }
 
+impl<T: ChunkReader + 'static> ReaderRowGroups<T> {
+    /// Update the selection of `plan_builder` based on `filter`, evaluating the predicates
+    /// one row group at a time and reordering them between row groups
+    ///
+    /// See [`RowFilter::with_adaptive_ordering`]
+    fn evaluate_adaptive(
+        &self,
+        plan_builder: ReadPlanBuilder,
+        filter: &mut RowFilter,
+        fields: Option<&ParquetField>,
+        metrics: &ArrowReaderMetrics,
+        batch_size: usize,
+    ) -> Result<ReadPlanBuilder> {
+        let mut remaining = plan_builder.selection().cloned();
+        let policy = *plan_builder.row_selection_policy();
+        let mut ordering = PredicateOrdering::new(filter.predicates.len());
+        let mut selectors = vec![];
+        let mut selects_all = remaining.is_none();
+
+        for &row_group_idx in &self.row_groups {
+            let row_group = self.metadata.row_group(row_group_idx);
+            let num_rows = row_group.num_rows() as usize;
+            let reader = ReaderRowGroups {
+                reader: Arc::clone(&self.reader),
+                metadata: Arc::clone(&self.metadata),
+                row_groups: vec![row_group_idx],
+            };
+
+            let selection = remaining.as_mut().map(|s| s.split_off(num_rows));
+            let mut row_group_plan = ReadPlanBuilder::new(batch_size)
+                .with_selection(selection)
+                .with_row_selection_policy(policy);
+
+            for idx in ordering.order().to_vec() {
+                // break early if we have ruled out all rows
+                if !row_group_plan.selects_any() {
+                    break;
+                }
+
+                let predicate = &mut filter.predicates[idx];
+                let projection = predicate.projection();
+                let projected_bytes: i64 = row_group
+                    .columns()
+                    .iter()
+                    .enumerate()
+                    .filter(|(leaf_idx, _)| projection.leaf_included(*leaf_idx))
+                    .map(|(_, column)| column.uncompressed_size())
+                    .sum();
+
+                let array_reader = ArrayReaderBuilder::new(&reader, metrics)
+                    .with_parquet_metadata(&reader.metadata)
+                    .build_array_reader(fields, projection)?;
+
+                let rows_in = row_group_plan.num_rows_selected().unwrap_or(num_rows);
+                row_group_plan = row_group_plan.with_predicate(array_reader, predicate.as_mut())?;
+                let rows_out = row_group_plan.num_rows_selected().unwrap_or(num_rows);
+
+                let cost_per_row = projected_bytes as f64 / num_rows.max(1) as f64;
+                ordering.record(idx, rows_in, rows_out, cost_per_row);
+            }
+            ordering.reorder();
+
+            match row_group_plan.selection() {
+                Some(selection) => {
+                    selects_all = false;
+                    selectors.extend(selection.iter().copied());
+                }
+                None => selectors.push(RowSelector::select(num_rows)),
+            }
+        }
+
+        Ok(match selects_all {
+            true => plan_builder,
+            false => plan_builder.with_selection(Some(selectors.into())),
+        })
+    }
+}
+
 impl<T: ChunkReader + 'static> RowGroups for ReaderRowGroups<T> {
     fn num_rows(&self) -> usize {
         let meta = self.metadata.row_groups();
@@ -1297,6 +2125,7 @@ impl<T: ChunkReader + 'static> ReaderPageIterator<T> {
         let reader = self.reader.clone();
 
         SerializedPageReader::new(reader, column_chunk_metadata, total_rows, page_locations)?
+            .with_row_group(rg_idx)
             .add_crypto_context(
                 rg_idx,
                 self.column_idx,
@@ -1334,6 +2163,9 @@ pub struct ParquetRecordBatchReader {
     array_reader: Box<dyn ArrayReader>,
     schema: SchemaRef,
     read_plan: ReadPlan,
+    /// Converts each batch to the schema requested by the async reader, if any
+    #[cfg(feature = "async")]
+    output_schema: Option<Arc<OutputSchema>>,
 }
 
 impl Debug for ParquetRecordBatchReader {
@@ -1350,13 +2182,41 @@ impl Iterator for ParquetRecordBatchReader {
     type Item = Result<RecordBatch, ArrowError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.next_inner()
+        let batch = self
+            .next_inner()
             .map_err(|arrow_err| arrow_err.into())
-            .transpose()
+            .transpose();
+
+        #[cfg(feature = "async")]
+        if let Some(output_schema) = &self.output_schema {
+            return batch.map(|b| b.and_then(|b| output_schema.apply(b)));
+        }
+        batch
     }
 }
 
 impl ParquetRecordBatchReader {
+    /// Returns the next [`RecordBatch`] from the reader, or `None` if the reader
+    /// has reached the end of the file
+    ///
+    /// Unlike [`Iterator::next`], which converts errors to [`ArrowError`], this
+    /// returns a [`ParquetError`], whose [`ParquetError::kind`] and
+    /// [`ParquetError::context`] describe the error.
+    ///
+    /// [`ParquetError`]: crate::errors::ParquetError
+    /// [`ParquetError::kind`]: crate::errors::ParquetError::kind
+    /// [`ParquetError::context`]: crate::errors::ParquetError::context
+    pub fn try_next(&mut self) -> Result<Option<RecordBatch>> {
+        let Some(batch) = self.next_inner()? else {
+            return Ok(None);
+        };
+        #[cfg(feature = "async")]
+        if let Some(output_schema) = &self.output_schema {
+            return Ok(Some(output_schema.apply(batch)?));
+        }
+        Ok(Some(batch))
+    }
+
     /// Returns the next `RecordBatch` from the reader, or `None` if the reader
     /// has reached the end of the file.
     ///
@@ -1380,7 +2240,7 @@ impl ParquetRecordBatchReader {
                     if mask_chunk.initial_skip > 0 {
                         let skipped = self.array_reader.skip_records(mask_chunk.initial_skip)?;
                         if skipped != mask_chunk.initial_skip {
-                            return Err(general_err!(
+                            return Err(corrupt_err!(
                                 "failed to skip rows, expected {}, got {}",
                                 mask_chunk.initial_skip,
                                 skipped
@@ -1399,13 +2259,13 @@ impl ParquetRecordBatchReader {
 
                     let read = self.array_reader.read_records(mask_chunk.chunk_rows)?;
                     if read == 0 {
-                        return Err(general_err!(
+                        return Err(corrupt_err!(
                             "reached end of column while expecting {} rows",
                             mask_chunk.chunk_rows
                         ));
                     }
                     if read != mask_chunk.chunk_rows {
-                        return Err(general_err!(
+                        return Err(corrupt_err!(
                             "insufficient rows read from array reader - expected {}, got {}",
                             mask_chunk.chunk_rows,
                             read
@@ -1425,7 +2285,7 @@ impl ParquetRecordBatchReader {
                         filter_record_batch(&RecordBatch::from(struct_array), &mask)?;
 
                     if filtered_batch.num_rows() != mask_chunk.selected_rows {
-                        return Err(general_err!(
+                        return Err(corrupt_err!(
                             "filtered rows mismatch selection - expected {}, got {}",
                             mask_chunk.selected_rows,
                             filtered_batch.num_rows()
@@ -1446,7 +2306,7 @@ impl ParquetRecordBatchReader {
                         let skipped = self.array_reader.skip_records(front.row_count)?;
 
                         if skipped != front.row_count {
-                            return Err(general_err!(
+                            return Err(corrupt_err!(
                                 "failed to skip rows, expected {}, got {}",
                                 front.row_count,
                                 skipped
@@ -1502,6 +2362,10 @@ impl RecordBatchReader for ParquetRecordBatchReader {
     /// Note that the schema metadata will be stripped here. See
     /// [`ParquetRecordBatchReaderBuilder::schema`] if the metadata is desired.
     fn schema(&self) -> SchemaRef {
+        #[cfg(feature = "async")]
+        if let Some(output_schema) = &self.output_schema {
+            return output_schema.schema().clone();
+        }
         self.schema.clone()
     }
 }
@@ -1540,6 +2404,8 @@ impl ParquetRecordBatchReader {
             array_reader,
             schema: Arc::new(Schema::new(levels.fields.clone())),
             read_plan,
+            #[cfg(feature = "async")]
+            output_schema: None,
         })
     }
 
@@ -1556,9 +2422,18 @@ impl ParquetRecordBatchReader {
             array_reader,
             schema: Arc::new(schema),
             read_plan,
+            #[cfg(feature = "async")]
+            output_schema: None,
         }
     }
 
+    /// Convert the batches returned by this reader to `output_schema`
+    #[cfg(feature = "async")]
+    pub(crate) fn with_output_schema(mut self, output_schema: Arc<OutputSchema>) -> Self {
+        self.output_schema = Some(output_schema);
+        self
+    }
+
     #[inline(always)]
     pub(crate) fn batch_size(&self) -> usize {
         self.read_plan.batch_size()
@@ -1574,6 +2449,7 @@ pub(crate) mod tests {
     use std::io::Seek;
     use std::path::PathBuf;
     use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     use rand::rngs::StdRng;
     use rand::{Rng, RngCore, SeedableRng, random, rng};
@@ -1605,13 +2481,18 @@ pub(crate) mod tests {
     use arrow_array::cast::AsArray;
     use arrow_array::types::{
         Date32Type, Date64Type, Decimal32Type, Decimal64Type, Decimal128Type, Decimal256Type,
-        DecimalType, Float16Type, Float32Type, Float64Type, Time32MillisecondType,
-        Time64MicrosecondType,
+        DecimalType, Float16Type, Float32Type, Float64Type, Int32Type as ArrowInt32Type,
+        Time32MillisecondType, Time64MicrosecondType,
     };
     use arrow_array::*;
-    use arrow_buffer::{ArrowNativeType, Buffer, IntervalDayTime, NullBuffer, i256};
+    use arrow_buffer::{
+        ArrowNativeType, Buffer, IntervalDayTime, IntervalMonthDayNano, NullBuffer, OffsetBuffer,
+        i256,
+    };
     use arrow_data::{ArrayData, ArrayDataBuilder};
-    use arrow_schema::{DataType as ArrowDataType, Field, Fields, Schema, SchemaRef, TimeUnit};
+    use arrow_schema::{
+        DataType as ArrowDataType, Field, Fields, IntervalUnit, Schema, SchemaRef, TimeUnit,
+    };
     use arrow_select::concat::concat_batches;
     use bytes::Bytes;
     use half::f16;
@@ -1774,7 +2655,7 @@ pub(crate) mod tests {
         let builder = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
         let original_schema = Arc::clone(builder.schema());
 
-        let mask = ProjectionMask::columns(builder.parquet_schema(), ["blog_id"]);
+        let mask = ProjectionMask::try_columns(builder.parquet_schema(), ["blog_id"]).unwrap();
         let reader = builder.with_projection(mask).build().unwrap();
 
         // Verify that the schema was correctly parsed
@@ -2211,7 +3092,7 @@ pub(crate) mod tests {
         run_single_column_reader_tests::<FixedLenByteArrayType, _, RandFixedLenGen>(
             12,
             ConvertedType::INTERVAL,
-            None,
+            Some(ArrowDataType::Interval(IntervalUnit::DayTime)),
             |vals| {
                 Arc::new(
                     vals.iter()
@@ -2230,6 +3111,33 @@ pub(crate) mod tests {
         );
     }
 
+    #[test]
+    fn test_interval_month_day_nano_column_reader() {
+        run_single_column_reader_tests::<FixedLenByteArrayType, _, RandFixedLenGen>(
+            12,
+            ConvertedType::INTERVAL,
+            None,
+            |vals| {
+                Arc::new(
+                    vals.iter()
+                        .map(|x| {
+                            x.as_ref().map(|b| {
+                                let b = b.as_ref();
+                                let millis = i32::from_le_bytes(b[8..12].try_into().unwrap());
+                                IntervalMonthDayNano::new(
+                                    i32::from_le_bytes(b[0..4].try_into().unwrap()),
+                                    i32::from_le_bytes(b[4..8].try_into().unwrap()),
+                                    millis as i64 * 1_000_000,
+                                )
+                            })
+                        })
+                        .collect::<IntervalMonthDayNanoArray>(),
+                )
+            },
+            &[Encoding::PLAIN, Encoding::RLE_DICTIONARY],
+        );
+    }
+
     #[test]
     fn test_int96_single_column_reader_test() {
         let encodings = &[Encoding::PLAIN, Encoding::RLE_DICTIONARY];
@@ -3627,10 +4535,11 @@ pub(crate) mod tests {
         let file = File::open(&path).unwrap();
         let builder = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
 
-        let mask = ProjectionMask::columns(
+        let mask = ProjectionMask::try_columns(
             builder.parquet_schema(),
             ["roll_num.count", "PC_CUR.mean", "PC_CUR.sum"],
-        );
+        )
+        .unwrap();
         let projected_reader = builder
             .with_projection(mask)
             .with_batch_size(60)
@@ -3665,6 +4574,90 @@ pub(crate) mod tests {
         }
     }
 
+    #[test]
+    fn test_read_nested_projection() {
+        let struct_array = |fields: Vec<(&str, ArrayRef)>| {
+            let (fields, arrays): (Vec<_>, Vec<_>) = fields
+                .into_iter()
+                .map(|(name, a)| (Field::new(name, a.data_type().clone(), false), a))
+                .unzip();
+            StructArray::new(fields.into(), arrays, None)
+        };
+        let list_array = |values: StructArray| {
+            let field = Arc::new(Field::new_list_field(values.data_type().clone(), false));
+            let offsets = arrow_buffer::OffsetBuffer::from_lengths([2, 1]);
+            ListArray::new(field, offsets, Arc::new(values), None)
+        };
+
+        let properties = struct_array(vec![
+            ("key", Arc::new(StringArray::from(vec!["a", "b"]))),
+            ("value", Arc::new(StringArray::from(vec!["x", "y"]))),
+        ]);
+        let event = struct_array(vec![
+            ("id", Arc::new(Int32Array::from(vec![1, 2]))),
+            ("properties", Arc::new(properties)),
+        ]);
+        let tags = list_array(struct_array(vec![
+            ("name", Arc::new(StringArray::from(vec!["t1", "t2", "t3"]))),
+            ("score", Arc::new(Int32Array::from(vec![10, 20, 30]))),
+        ]));
+        let batch = RecordBatch::try_from_iter([
+            ("event", Arc::new(event) as ArrayRef),
+            ("tags", Arc::new(tags) as ArrayRef),
+            ("other", Arc::new(Int32Array::from(vec![5, 6])) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buffer = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let buffer = Bytes::from(buffer);
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(buffer.clone())
+            .unwrap()
+            .with_nested_projection([
+                ("tags", vec!["score"]),
+                ("event", vec!["properties.key", "missing"]),
+                ("unknown", vec![]),
+            ])
+            .build()
+            .unwrap();
+        let out: Vec<_> = reader.map(|b| b.unwrap()).collect();
+        assert_eq!(out.len(), 1);
+
+        let expected = RecordBatch::try_from_iter([
+            (
+                "event",
+                Arc::new(struct_array(vec![(
+                    "properties",
+                    Arc::new(struct_array(vec![(
+                        "key",
+                        Arc::new(StringArray::from(vec!["a", "b"])),
+                    )])),
+                )])) as ArrayRef,
+            ),
+            (
+                "tags",
+                Arc::new(list_array(struct_array(vec![(
+                    "score",
+                    Arc::new(Int32Array::from(vec![10, 20, 30])),
+                )]))) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        assert_eq!(out[0], expected);
+
+        // An empty list of paths selects the entire column
+        let reader = ParquetRecordBatchReaderBuilder::try_new(buffer)
+            .unwrap()
+            .with_nested_projection([("event", vec![]), ("other", vec![])])
+            .build()
+            .unwrap();
+        let out = reader.map(|b| b.unwrap()).next().unwrap();
+        assert_eq!(out, batch.project(&[0, 2]).unwrap());
+    }
+
     #[test]
     fn test_read_maps() {
         let testdata = arrow::util::test_util::parquet_test_data();
@@ -3744,80 +4737,472 @@ pub(crate) mod tests {
                 .unwrap(),
         );
 
-        let dict_type = ArrowDataType::Dictionary(
-            Box::new(ArrowDataType::Int32),
-            Box::new(ArrowDataType::Utf8),
+        let dict_type = ArrowDataType::Dictionary(
+            Box::new(ArrowDataType::Int32),
+            Box::new(ArrowDataType::Utf8),
+        );
+
+        let arrow_field = Field::new("leaf", dict_type, true);
+
+        let mut file = tempfile::tempfile().unwrap();
+
+        let values = vec![
+            vec![
+                ByteArray::from("hello"),
+                ByteArray::from("a"),
+                ByteArray::from("b"),
+                ByteArray::from("d"),
+            ],
+            vec![
+                ByteArray::from("c"),
+                ByteArray::from("a"),
+                ByteArray::from("b"),
+            ],
+        ];
+
+        let def_levels = vec![
+            vec![1, 0, 0, 1, 0, 0, 1, 1],
+            vec![0, 0, 1, 1, 0, 0, 1, 0, 0],
+        ];
+
+        let opts = TestOptions {
+            encoding: Encoding::RLE_DICTIONARY,
+            ..Default::default()
+        };
+
+        generate_single_column_file_with_data::<ByteArrayType>(
+            &values,
+            Some(&def_levels),
+            file.try_clone().unwrap(), // Cannot use &mut File (#1163)
+            schema,
+            Some(arrow_field),
+            &opts,
+        )
+        .unwrap();
+
+        file.rewind().unwrap();
+
+        let record_reader = ParquetRecordBatchReader::try_new(file, 3).unwrap();
+
+        let batches = record_reader
+            .collect::<Result<Vec<RecordBatch>, _>>()
+            .unwrap();
+
+        assert_eq!(batches.len(), 6);
+        assert!(batches.iter().all(|x| x.num_columns() == 1));
+
+        let row_counts = batches
+            .iter()
+            .map(|x| (x.num_rows(), x.column(0).null_count()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            row_counts,
+            vec![(3, 2), (3, 2), (3, 1), (3, 1), (3, 2), (2, 2)]
+        );
+
+        let get_dict = |batch: &RecordBatch| batch.column(0).to_data().child_data()[0].clone();
+
+        // First and second batch in same row group -> same dictionary
+        assert_eq!(get_dict(&batches[0]), get_dict(&batches[1]));
+        // Third batch spans row group -> computed dictionary
+        assert_ne!(get_dict(&batches[1]), get_dict(&batches[2]));
+        assert_ne!(get_dict(&batches[2]), get_dict(&batches[3]));
+        // Fourth, fifth and sixth from same row group -> same dictionary
+        assert_eq!(get_dict(&batches[3]), get_dict(&batches[4]));
+        assert_eq!(get_dict(&batches[4]), get_dict(&batches[5]));
+    }
+
+    fn dictionary_columns_file() -> (RecordBatch, Bytes) {
+        // Low cardinality, will be dictionary encoded
+        let s =
+            StringArray::from_iter((0..1000).map(|i| (i % 7 != 0).then(|| format!("v{}", i % 10))));
+        // High cardinality, will fall back to PLAIN encoding
+        let a = StringArray::from_iter_values((0..1000).map(|i| format!("u{i}")));
+        let b = Int32Array::from_iter_values(0..1000);
+        let t = StructArray::from(vec![
+            (
+                Arc::new(Field::new("a", ArrowDataType::Utf8, false)),
+                Arc::new(a) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("b", ArrowDataType::Int32, false)),
+                Arc::new(b) as ArrayRef,
+            ),
+        ]);
+        let batch = RecordBatch::try_from_iter([
+            ("s", Arc::new(s) as ArrayRef),
+            ("t", Arc::new(t) as ArrayRef),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_dictionary_page_size_limit(256)
+            .set_data_page_row_count_limit(100)
+            .set_write_batch_size(100)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        (batch, Bytes::from(buf))
+    }
+
+    #[test]
+    fn test_dictionary_columns() {
+        let (batch, data) = dictionary_columns_file();
+        let dict_type = |v: ArrowDataType| {
+            ArrowDataType::Dictionary(Box::new(ArrowDataType::Int32), Box::new(v))
+        };
+
+        let options = ArrowReaderOptions::new().with_dictionary_columns(["s", "t.a"]);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options).unwrap();
+        let schema = builder.schema().clone();
+        assert_eq!(schema.field(0).data_type(), &dict_type(ArrowDataType::Utf8));
+        let ArrowDataType::Struct(children) = schema.field(1).data_type() else {
+            panic!("expected struct")
+        };
+        assert_eq!(children[0].data_type(), &dict_type(ArrowDataType::Utf8));
+        assert_eq!(children[1].data_type(), &ArrowDataType::Int32);
+
+        let read = builder
+            .with_batch_size(1000)
+            .build()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(read.schema(), schema);
+
+        // The dictionary page is used directly as the values
+        let s = read.column(0).as_dictionary::<ArrowInt32Type>();
+        assert_eq!(s.values().len(), 10);
+        assert_eq!(
+            &arrow_cast::cast(s, &ArrowDataType::Utf8).unwrap(),
+            batch.column(0)
+        );
+
+        // PLAIN fallback pages are read into a computed dictionary
+        let a = read.column(1).as_struct().column(0);
+        let expected = batch.column(1).as_struct().column(0);
+        assert_eq!(a.as_dictionary::<ArrowInt32Type>().values().len(), 1000);
+        assert_eq!(
+            &arrow_cast::cast(a, &ArrowDataType::Utf8).unwrap(),
+            expected
+        );
+        assert_eq!(
+            read.column(1).as_struct().column(1),
+            batch.column(1).as_struct().column(1)
+        );
+
+        // Combines with a schema hint
+        let hint = Arc::new(Schema::new(vec![
+            Field::new("s", ArrowDataType::LargeUtf8, true),
+            batch.schema().field(1).clone(),
+        ]));
+        let options = ArrowReaderOptions::new()
+            .with_schema(hint)
+            .with_dictionary_columns(["s"]);
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(data, options).unwrap();
+        assert_eq!(
+            builder.schema().field(0).data_type(),
+            &dict_type(ArrowDataType::LargeUtf8)
+        );
+        assert_eq!(builder.schema().field(1), batch.schema().field(1));
+    }
+
+    #[test]
+    fn test_schema_view_types() {
+        let strings = StringArray::from(vec![Some("a"), None, Some("a string longer than 12")]);
+        let binary = BinaryArray::from_opt_vec(vec![Some(b"x"), Some(b"yz"), None]);
+        let large = LargeStringArray::from(vec!["one", "two", "three"]);
+        let nested = StructArray::from(vec![(
+            Arc::new(Field::new("c", ArrowDataType::Utf8, false)),
+            Arc::new(StringArray::from(vec!["p", "q", "r"])) as ArrayRef,
+        )]);
+        let list = ListArray::from_iter_primitive::<ArrowInt32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            None,
+            Some(vec![Some(2), Some(3)]),
+        ]);
+        let batch = RecordBatch::try_from_iter([
+            ("s", Arc::new(strings) as ArrayRef),
+            ("b", Arc::new(binary) as ArrayRef),
+            ("l", Arc::new(large) as ArrayRef),
+            ("t", Arc::new(nested) as ArrayRef),
+            ("i", Arc::new(list) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let options = ArrowReaderOptions::new().with_schema_view_types(true);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options).unwrap();
+        let schema = builder.schema().clone();
+        assert_eq!(schema.field(0).data_type(), &ArrowDataType::Utf8View);
+        assert_eq!(schema.field(1).data_type(), &ArrowDataType::BinaryView);
+        assert_eq!(schema.field(2).data_type(), &ArrowDataType::Utf8View);
+        let ArrowDataType::Struct(children) = schema.field(3).data_type() else {
+            panic!("expected struct")
+        };
+        assert_eq!(children[0].data_type(), &ArrowDataType::Utf8View);
+        assert_eq!(schema.field(4), batch.schema().field(4));
+
+        let read = builder.build().unwrap().next().unwrap().unwrap();
+        assert_eq!(read.schema(), schema);
+        for (read, expected) in read.columns().iter().zip(batch.columns()) {
+            let cast = arrow_cast::cast(read, expected.data_type()).unwrap();
+            assert_eq!(&cast, expected);
+        }
+
+        // Dictionary columns are not affected
+        let options = ArrowReaderOptions::new()
+            .with_schema_view_types(true)
+            .with_dictionary_columns(["s"]);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options).unwrap();
+        assert_eq!(
+            builder.schema().field(0).data_type(),
+            &ArrowDataType::Dictionary(
+                Box::new(ArrowDataType::Int32),
+                Box::new(ArrowDataType::Utf8)
+            )
+        );
+        assert_eq!(
+            builder.schema().field(1).data_type(),
+            &ArrowDataType::BinaryView
+        );
+        let read = builder.build().unwrap().next().unwrap().unwrap();
+        assert_eq!(
+            &arrow_cast::cast(read.column(0), &ArrowDataType::Utf8).unwrap(),
+            batch.column(0)
+        );
+
+        // Applied on top of a supplied schema
+        let hint = Arc::new(Schema::new(
+            batch
+                .schema()
+                .fields()
+                .iter()
+                .map(|f| match f.name().as_str() {
+                    "s" => Arc::new(Field::new("s", ArrowDataType::LargeUtf8, true)),
+                    _ => Arc::clone(f),
+                })
+                .collect::<Vec<_>>(),
+        ));
+        let options = ArrowReaderOptions::new()
+            .with_schema(hint)
+            .with_schema_view_types(true);
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(data, options).unwrap();
+        assert_eq!(builder.schema(), &schema);
+    }
+
+    #[test]
+    fn test_small_decimal_types() {
+        let decimal = |values: Vec<Option<i128>>, precision, scale| {
+            Decimal128Array::from(values)
+                .with_precision_and_scale(precision, scale)
+                .unwrap()
+        };
+        let list_field = Arc::new(Field::new("item", ArrowDataType::Decimal128(9, 2), true));
+        let list = ListArray::new(
+            list_field,
+            OffsetBuffer::from_lengths([2, 0, 1]),
+            Arc::new(decimal(vec![Some(1), None, Some(-3)], 9, 2)),
+            None,
+        );
+        let batch = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(decimal(vec![Some(12345), None, Some(-1)], 5, 2)) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(decimal(vec![Some(i64::MAX as i128), Some(0), None], 18, 4)) as _,
+            ),
+            (
+                "c",
+                Arc::new(decimal(
+                    vec![Some(i128::MAX / 1000), Some(1), Some(2)],
+                    36,
+                    2,
+                )) as _,
+            ),
+            ("d", Arc::new(list) as _),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let options = ArrowReaderOptions::new().with_small_decimal_types(true);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options).unwrap();
+        let schema = builder.schema().clone();
+        assert_eq!(schema.field(0).data_type(), &ArrowDataType::Decimal32(5, 2));
+        assert_eq!(
+            schema.field(1).data_type(),
+            &ArrowDataType::Decimal64(18, 4)
+        );
+        assert_eq!(
+            schema.field(2).data_type(),
+            &ArrowDataType::Decimal128(36, 2)
         );
+        let ArrowDataType::List(item) = schema.field(3).data_type() else {
+            panic!("expected list")
+        };
+        assert_eq!(item.data_type(), &ArrowDataType::Decimal32(9, 2));
 
-        let arrow_field = Field::new("leaf", dict_type, true);
+        let read = builder.build().unwrap().next().unwrap().unwrap();
+        assert_eq!(read.schema(), schema);
+        for (read, expected) in read.columns().iter().zip(batch.columns()) {
+            let cast = arrow_cast::cast(read, expected.data_type()).unwrap();
+            assert_eq!(&cast, expected);
+        }
 
-        let mut file = tempfile::tempfile().unwrap();
+        // Write the small decimal types, including a precision of 1 stored as INT64
+        let batch = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(
+                    Decimal32Array::from(vec![Some(1), None, Some(-9)])
+                        .with_precision_and_scale(1, 0)
+                        .unwrap(),
+                ) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(
+                    Decimal64Array::from(vec![Some(999_999_999), Some(0), None])
+                        .with_precision_and_scale(9, 3)
+                        .unwrap(),
+                ) as _,
+            ),
+        ])
+        .unwrap();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
 
-        let values = vec![
-            vec![
-                ByteArray::from("hello"),
-                ByteArray::from("a"),
-                ByteArray::from("b"),
-                ByteArray::from("d"),
-            ],
-            vec![
-                ByteArray::from("c"),
-                ByteArray::from("a"),
-                ByteArray::from("b"),
-            ],
-        ];
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf)).unwrap();
+        let column_types: Vec<_> = builder
+            .parquet_schema()
+            .columns()
+            .iter()
+            .map(|c| c.physical_type())
+            .collect();
+        assert_eq!(column_types, [PhysicalType::INT64, PhysicalType::INT32]);
+        let read = builder.build().unwrap().next().unwrap().unwrap();
+        assert_eq!(read, batch);
+    }
 
-        let def_levels = vec![
-            vec![1, 0, 0, 1, 0, 0, 1, 1],
-            vec![0, 0, 1, 1, 0, 0, 1, 0, 0],
-        ];
+    #[test]
+    fn test_small_decimal_types_physical_types() {
+        const MESSAGE_TYPE: &str = "
+            message test {
+              REQUIRED FIXED_LEN_BYTE_ARRAY (16) a (DECIMAL(9,2));
+              OPTIONAL BYTE_ARRAY b (DECIMAL(18,4));
+              REQUIRED INT64 c (DECIMAL(9,2));
+            }
+        ";
+        let schema = Arc::new(parse_message_type(MESSAGE_TYPE).unwrap());
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = SerializedFileWriter::new(&mut buf, schema, Default::default()).unwrap();
+        let mut row_group_writer = writer.next_row_group().unwrap();
 
-        let opts = TestOptions {
-            encoding: Encoding::RLE_DICTIONARY,
-            ..Default::default()
-        };
+        let a: Vec<_> = [-999_999_999_i128, 0, 12345, 1]
+            .iter()
+            .map(|v| FixedLenByteArray::from(v.to_be_bytes().to_vec()))
+            .collect();
+        let mut col_writer = row_group_writer.next_column().unwrap().unwrap();
+        col_writer
+            .typed::<FixedLenByteArrayType>()
+            .write_batch(&a, None, None)
+            .unwrap();
+        col_writer.close().unwrap();
 
-        generate_single_column_file_with_data::<ByteArrayType>(
-            &values,
-            Some(&def_levels),
-            file.try_clone().unwrap(), // Cannot use &mut File (#1163)
-            schema,
-            Some(arrow_field),
-            &opts,
-        )
-        .unwrap();
+        // Values of varying lengths, including a redundant sign extension
+        let b = [
+            ByteArray::from(vec![0xFF]),
+            ByteArray::from(i64::MIN.to_be_bytes().to_vec()),
+            ByteArray::from((-42_i128).to_be_bytes().to_vec()),
+        ];
+        let mut col_writer = row_group_writer.next_column().unwrap().unwrap();
+        col_writer
+            .typed::<ByteArrayType>()
+            .write_batch(&b, Some(&[1, 1, 0, 1]), None)
+            .unwrap();
+        col_writer.close().unwrap();
 
-        file.rewind().unwrap();
+        let mut col_writer = row_group_writer.next_column().unwrap().unwrap();
+        col_writer
+            .typed::<Int64Type>()
+            .write_batch(&[999_999_999, -1, 7, 0], None, None)
+            .unwrap();
+        col_writer.close().unwrap();
 
-        let record_reader = ParquetRecordBatchReader::try_new(file, 3).unwrap();
+        row_group_writer.close().unwrap();
+        writer.close().unwrap();
 
-        let batches = record_reader
-            .collect::<Result<Vec<RecordBatch>, _>>()
-            .unwrap();
+        let options = ArrowReaderOptions::new().with_small_decimal_types(true);
+        let mut reader =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(buf), options)
+                .unwrap()
+                .build()
+                .unwrap();
+        let read = reader.next().unwrap().unwrap();
+        assert_eq!(read.num_rows(), 4);
 
-        assert_eq!(batches.len(), 6);
-        assert!(batches.iter().all(|x| x.num_columns() == 1));
+        let expected = Decimal32Array::from(vec![-999_999_999, 0, 12345, 1])
+            .with_precision_and_scale(9, 2)
+            .unwrap();
+        assert_eq!(read.column(0).as_primitive::<Decimal32Type>(), &expected);
+        let expected = Decimal64Array::from(vec![Some(-1), Some(i64::MIN), None, Some(-42)])
+            .with_precision_and_scale(18, 4)
+            .unwrap();
+        assert_eq!(read.column(1).as_primitive::<Decimal64Type>(), &expected);
+        let expected = Decimal32Array::from(vec![999_999_999, -1, 7, 0])
+            .with_precision_and_scale(9, 2)
+            .unwrap();
+        assert_eq!(read.column(2).as_primitive::<Decimal32Type>(), &expected);
+    }
 
-        let row_counts = batches
-            .iter()
-            .map(|x| (x.num_rows(), x.column(0).null_count()))
-            .collect::<Vec<_>>();
+    #[test]
+    fn test_dictionary_columns_errors() {
+        let (_, data) = dictionary_columns_file();
+        let err = |column: &str| {
+            let options = ArrowReaderOptions::new().with_dictionary_columns([column]);
+            ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options)
+                .unwrap_err()
+                .to_string()
+        };
 
         assert_eq!(
-            row_counts,
-            vec![(3, 2), (3, 2), (3, 1), (3, 1), (3, 2), (2, 2)]
+            err("missing"),
+            "Parquet error: Cannot read column \"missing\" as dictionary: not found"
+        );
+        assert_eq!(
+            err("s.a"),
+            "Parquet error: Cannot read column \"s.a\" as dictionary: not found"
+        );
+        assert_eq!(
+            err("t"),
+            "Parquet error: Cannot read column \"t\" of type Struct(\"a\": non-null Utf8, \"b\": non-null Int32) as dictionary"
+        );
+        assert_eq!(
+            err("t.b"),
+            "Parquet error: Cannot read column \"t.b\" of type Int32 as dictionary"
         );
-
-        let get_dict = |batch: &RecordBatch| batch.column(0).to_data().child_data()[0].clone();
-
-        // First and second batch in same row group -> same dictionary
-        assert_eq!(get_dict(&batches[0]), get_dict(&batches[1]));
-        // Third batch spans row group -> computed dictionary
-        assert_ne!(get_dict(&batches[1]), get_dict(&batches[2]));
-        assert_ne!(get_dict(&batches[2]), get_dict(&batches[3]));
-        // Fourth, fifth and sixth from same row group -> same dictionary
-        assert_eq!(get_dict(&batches[3]), get_dict(&batches[4]));
-        assert_eq!(get_dict(&batches[4]), get_dict(&batches[5]));
     }
 
     #[test]
@@ -5294,6 +6679,365 @@ pub(crate) mod tests {
         assert_eq!(out.num_columns(), 2);
     }
 
+    /// Writes a file with annotated columns `a`, `b` and `v`, and then replaces their
+    /// logical types in the footer with union members this crate does not know
+    fn write_unknown_logical_types() -> Bytes {
+        let id = Type::primitive_type_builder("id", PhysicalType::INT32)
+            .with_repetition(Repetition::REQUIRED)
+            .build()
+            .unwrap();
+        let a = Type::primitive_type_builder("a", PhysicalType::INT32)
+            .with_repetition(Repetition::OPTIONAL)
+            .with_logical_type(Some(LogicalType::Unknown))
+            .build()
+            .unwrap();
+        let b = Type::primitive_type_builder("b", PhysicalType::FIXED_LEN_BYTE_ARRAY)
+            .with_repetition(Repetition::OPTIONAL)
+            .with_length(16)
+            .with_logical_type(Some(LogicalType::Uuid))
+            .build()
+            .unwrap();
+        let v_fields = ["metadata", "value"].map(|name| {
+            let field = Type::primitive_type_builder(name, PhysicalType::BYTE_ARRAY)
+                .with_repetition(Repetition::REQUIRED)
+                .build();
+            Arc::new(field.unwrap())
+        });
+        let v = Type::group_type_builder("v")
+            .with_repetition(Repetition::OPTIONAL)
+            .with_logical_type(Some(LogicalType::Variant {
+                specification_version: None,
+            }))
+            .with_fields(v_fields.to_vec())
+            .build()
+            .unwrap();
+        let schema = Type::group_type_builder("schema")
+            .with_fields([id, a, b, v].map(Arc::new).to_vec())
+            .build()
+            .unwrap();
+
+        let mut buf = Vec::new();
+        let mut writer =
+            SerializedFileWriter::new(&mut buf, Arc::new(schema), Default::default()).unwrap();
+        let mut row_group = writer.next_row_group().unwrap();
+        let mut col = row_group.next_column().unwrap().unwrap();
+        col.typed::<Int32Type>()
+            .write_batch(&[1, 2, 3], None, None)
+            .unwrap();
+        col.close().unwrap();
+        let mut col = row_group.next_column().unwrap().unwrap();
+        col.typed::<Int32Type>()
+            .write_batch(&[4, 6], Some(&[1, 0, 1]), None)
+            .unwrap();
+        col.close().unwrap();
+        let mut col = row_group.next_column().unwrap().unwrap();
+        col.typed::<FixedLenByteArrayType>()
+            .write_batch(
+                &[FixedLenByteArray::from(vec![7; 16])],
+                Some(&[0, 1, 0]),
+                None,
+            )
+            .unwrap();
+        col.close().unwrap();
+        for value in ["m", "v"] {
+            let mut col = row_group.next_column().unwrap().unwrap();
+            let values = [ByteArray::from(value), ByteArray::from(value)];
+            col.typed::<ByteArrayType>()
+                .write_batch(&values, Some(&[1, 0, 1]), None)
+                .unwrap();
+            col.close().unwrap();
+        }
+        row_group.close().unwrap();
+        writer.close().unwrap();
+
+        // Replace the union member following each field name in the footer
+        let replacements: [(&[u8], &[u8], &[u8]); 3] = [
+            (
+                b"\x01a",
+                &[0xbc, 0x00], // UNKNOWN
+                &[
+                    0x0c, 0x3c, // field 30: struct
+                    0x15, 0x54, // field 1: i32 42
+                    0x18, 0x03, b'a', b'b', b'c', // field 2: binary "abc"
+                    0x19, 0x25, 0x02, 0x04, // field 3: list<i32> [1, 2]
+                    0x1c, 0x00, // field 4: empty struct
+                    0x00,
+                ],
+            ),
+            (b"\x01b", &[0xec, 0x00], &[0x0c, 0x3e, 0x00]), // UUID -> field 31
+            (
+                b"\x01v",
+                &[0x0c, 0x20, 0x00],
+                &[0x0c, 0x40, 0x15, 0x02, 0x00],
+            ), // VARIANT -> 32
+        ];
+        let footer_len = u32::from_le_bytes(buf[buf.len() - 8..buf.len() - 4].try_into().unwrap());
+        let footer_start = buf.len() - 8 - footer_len as usize;
+        let mut footer = buf[footer_start..buf.len() - 8].to_vec();
+        for (name, from, to) in replacements {
+            let find = |haystack: &[u8], needle: &[u8]| {
+                haystack
+                    .windows(needle.len())
+                    .position(|w| w == needle)
+                    .unwrap()
+            };
+            let start = find(&footer, name);
+            let start = start + find(&footer[start..], from);
+            footer.splice(start..start + from.len(), to.iter().copied());
+        }
+
+        buf.truncate(footer_start);
+        buf.extend_from_slice(&footer);
+        buf.extend_from_slice(&(footer.len() as u32).to_le_bytes());
+        buf.extend_from_slice(b"PAR1");
+        Bytes::from(buf)
+    }
+
+    #[test]
+    fn test_read_injected_unknown_logical_types() {
+        let data = write_unknown_logical_types();
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+
+        let schema = builder.parquet_schema();
+        let field_ids = [1, 2].map(|i| schema.column(i).logical_type_ref().cloned());
+        assert_eq!(
+            field_ids,
+            [30, 31].map(|field_id| Some(LogicalType::_Unknown { field_id }))
+        );
+        let v = &schema.root_schema().get_fields()[3];
+        assert_eq!(
+            v.get_basic_info().logical_type_ref(),
+            Some(&LogicalType::_Unknown { field_id: 32 })
+        );
+
+        // Columns fall back to their physical type, recording the unknown field id
+        let meta = |field_id: &str| {
+            HashMap::from([(
+                crate::arrow::PARQUET_UNKNOWN_LOGICAL_TYPE_META_KEY.to_string(),
+                field_id.to_string(),
+            )])
+        };
+        let v_fields = Fields::from(vec![
+            Field::new("metadata", ArrowDataType::Binary, false),
+            Field::new("value", ArrowDataType::Binary, false),
+        ]);
+        let expected = Schema::new(vec![
+            Field::new("id", ArrowDataType::Int32, false),
+            Field::new("a", ArrowDataType::Int32, true).with_metadata(meta("30")),
+            Field::new("b", ArrowDataType::FixedSizeBinary(16), true).with_metadata(meta("31")),
+            Field::new_struct("v", v_fields, true).with_metadata(meta("32")),
+        ]);
+        assert_eq!(builder.schema().as_ref(), &expected);
+
+        let batch = builder.build().unwrap().next().unwrap().unwrap();
+        assert_eq!(
+            batch.column(1).as_primitive::<types::Int32Type>(),
+            &Int32Array::from(vec![Some(4), None, Some(6)])
+        );
+        assert_eq!(batch.column(2).as_fixed_size_binary().null_count(), 2);
+        assert_eq!(batch.column(2).as_fixed_size_binary().value(1), &[7; 16]);
+        let v = batch.column(3).as_struct();
+        assert_eq!(v.null_count(), 1);
+        assert_eq!(v.column(1).as_binary::<i32>().value(2), b"v");
+
+        // Strict mode only errors if an unknown column is projected
+        let options = ArrowReaderOptions::new().with_strict_logical_types(true);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options.clone())
+                .unwrap();
+        let mask = ProjectionMask::leaves(builder.parquet_schema(), [0]);
+        let batch = builder
+            .with_projection(mask)
+            .build()
+            .unwrap()
+            .next()
+            .unwrap();
+        assert_eq!(batch.unwrap().num_columns(), 1);
+
+        for (leaf, expected) in [
+            (
+                1,
+                r#"Cannot read column "a": field "a" is annotated with an unknown logical type with field id 30"#,
+            ),
+            (
+                4,
+                r#"Cannot read column "v.value": field "v" is annotated with an unknown logical type with field id 32"#,
+            ),
+        ] {
+            let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(
+                data.clone(),
+                options.clone(),
+            )
+            .unwrap();
+            let mask = ProjectionMask::leaves(builder.parquet_schema(), [0, leaf]);
+            let err = builder.with_projection(mask).build().unwrap_err();
+            assert_eq!(err.to_string(), format!("Arrow: {expected}"));
+        }
+
+        // As are unknown columns read by a row filter
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(data, options).unwrap();
+        let mask = ProjectionMask::leaves(builder.parquet_schema(), [1]);
+        let filter = ArrowPredicateFn::new(mask, |batch| {
+            Ok(BooleanArray::from(vec![true; batch.num_rows()]))
+        });
+        let mask = ProjectionMask::leaves(builder.parquet_schema(), [0]);
+        let err = builder
+            .with_projection(mask)
+            .with_row_filter(RowFilter::new(vec![Box::new(filter)]))
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains("field id 30"), "{err}");
+    }
+
+    #[test]
+    fn test_build_row_group_readers() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(StringArray::from_iter_values(
+                    (0..100).map(|i| i.to_string()),
+                )) as _,
+            ),
+        ])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(25))
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        // Concatenates the batches of each reader, checking the readers are independent
+        fn read_all(readers: Vec<ParquetRecordBatchReader>) -> Vec<RecordBatch> {
+            std::thread::scope(|s| {
+                let handles: Vec<_> = readers
+                    .into_iter()
+                    .map(|reader| s.spawn(|| reader.map(|b| b.unwrap()).collect::<Vec<_>>()))
+                    .collect();
+                handles
+                    .into_iter()
+                    .flat_map(|h| h.join().unwrap())
+                    .collect()
+            })
+        }
+
+        let builder = || ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        let readers = builder().build_row_group_readers().unwrap();
+        assert_eq!(readers.len(), 4);
+        let read = concat_batches(&batch.schema(), &read_all(readers)).unwrap();
+        assert_eq!(read, batch);
+
+        // Row groups, selections, offsets, limits and filters apply as for `build`
+        let configure = |builder: ParquetRecordBatchReaderBuilder<Bytes>| {
+            let mask = ProjectionMask::leaves(builder.parquet_schema(), [0]);
+            let filter = ArrowPredicateFn::new(mask, |batch| {
+                let a = batch.column(0).as_primitive::<ArrowInt32Type>();
+                Ok(BooleanArray::from_unary(a, |v| v % 3 != 0))
+            });
+            builder
+                .with_batch_size(7)
+                .with_row_groups(vec![0, 2, 3])
+                .with_row_selection(RowSelection::from(vec![
+                    RowSelector::skip(10),
+                    RowSelector::select(50),
+                ]))
+                .with_row_filter(RowFilter::new(vec![Box::new(filter)]))
+                .with_offset(3)
+                .with_limit(20)
+        };
+        let expected: Vec<_> = configure(builder())
+            .build()
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let expected = concat_batches(&batch.schema(), &expected).unwrap();
+        assert_eq!(expected.num_rows(), 20);
+
+        let readers = configure(builder()).build_row_group_readers().unwrap();
+        assert_eq!(readers.len(), 3);
+        let read = concat_batches(&batch.schema(), &read_all(readers)).unwrap();
+        assert_eq!(read, expected);
+    }
+
+    #[test]
+    fn test_row_filter_adaptive_ordering() {
+        let strings = StringArray::from_iter_values((0..400).map(|i| format!("{i:0>100}")));
+        let ints = Int32Array::from_iter_values(0..400);
+        let batch = RecordBatch::try_from_iter([
+            ("s", Arc::new(strings) as ArrayRef),
+            ("i", Arc::new(ints) as ArrayRef),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(100))
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        // Returns the batches read, and the number of rows each predicate was evaluated on
+        let read = |adaptive_ordering: bool| {
+            let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+            assert_eq!(builder.metadata().num_row_groups(), 4);
+            let schema = builder.parquet_schema();
+
+            // An expensive predicate that eliminates no rows
+            let expensive_rows = Arc::new(AtomicUsize::new(0));
+            let counter = Arc::clone(&expensive_rows);
+            let expensive = ArrowPredicateFn::new(ProjectionMask::leaves(schema, [0]), move |b| {
+                counter.fetch_add(b.num_rows(), Ordering::Relaxed);
+                Ok(BooleanArray::from(vec![true; b.num_rows()]))
+            });
+
+            // A cheap predicate that eliminates most rows
+            let cheap_rows = Arc::new(AtomicUsize::new(0));
+            let counter = Arc::clone(&cheap_rows);
+            let cheap = ArrowPredicateFn::new(ProjectionMask::leaves(schema, [1]), move |b| {
+                counter.fetch_add(b.num_rows(), Ordering::Relaxed);
+                let i = b.column(0).as_primitive::<ArrowInt32Type>();
+                Ok(BooleanArray::from_unary(i, |v| v % 10 == 0))
+            });
+
+            let filter = RowFilter::new(vec![Box::new(expensive), Box::new(cheap)])
+                .with_adaptive_ordering(adaptive_ordering);
+            let selection =
+                RowSelection::from(vec![RowSelector::skip(50), RowSelector::select(350)]);
+            let batches = builder
+                .with_row_selection(selection)
+                .with_row_filter(filter)
+                .build()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+
+            let expensive_rows = expensive_rows.load(Ordering::Relaxed);
+            let cheap_rows = cheap_rows.load(Ordering::Relaxed);
+            (
+                concat_batches(&batch.schema(), &batches).unwrap(),
+                expensive_rows,
+                cheap_rows,
+            )
+        };
+
+        let (expected, expensive_rows, cheap_rows) = read(false);
+        assert_eq!(expected.num_rows(), 35);
+        assert_eq!((expensive_rows, cheap_rows), (350, 350));
+
+        // After the first row group the cheap predicate is evaluated first
+        let (actual, expensive_rows, cheap_rows) = read(true);
+        assert_eq!(actual, expected);
+        assert_eq!((expensive_rows, cheap_rows), (50 + 30, 350));
+    }
+
     #[test]
     fn test_read_row_numbers() {
         let file = write_parquet_from_iter(vec![(
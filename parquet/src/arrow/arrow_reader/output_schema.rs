@@ -0,0 +1,104 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`OutputSchema`] for casting decoded [`RecordBatch`]es to a requested schema
+
+use crate::errors::{ParquetError, Result};
+use arrow_array::{RecordBatch, RecordBatchOptions, new_null_array};
+use arrow_cast::{CastOptions, can_cast_types, cast_with_options};
+use arrow_schema::{ArrowError, Schema, SchemaRef};
+use std::sync::Arc;
+
+/// Converts [`RecordBatch`]es decoded from a parquet file to a requested schema
+///
+/// Each field of the requested schema is read from the decoded column with the same
+/// name, casting it if the types differ. Fields with no corresponding column are
+/// filled with nulls, and decoded columns not in the requested schema are dropped.
+#[derive(Debug)]
+pub(crate) struct OutputSchema {
+    schema: SchemaRef,
+    /// The index of the decoded column for each field of `schema`, if any
+    columns: Vec<Option<usize>>,
+}
+
+impl OutputSchema {
+    /// Create a new [`OutputSchema`] converting batches of `input` to `schema`
+    ///
+    /// Returns an error if a column cannot be cast to the requested type, or if a
+    /// non-nullable field has no corresponding column
+    pub(crate) fn try_new(input: &Schema, schema: SchemaRef) -> Result<Self> {
+        let columns = schema
+            .fields()
+            .iter()
+            .map(|field| match input.index_of(field.name()) {
+                Ok(idx) => {
+                    let from = input.field(idx).data_type();
+                    let to = field.data_type();
+                    if from != to && !can_cast_types(from, to) {
+                        return Err(schema_err!(
+                            "Cannot cast column \"{}\" from {} to {}",
+                            field.name(),
+                            from,
+                            to
+                        ));
+                    }
+                    Ok(Some(idx))
+                }
+                Err(_) if field.is_nullable() => Ok(None),
+                Err(_) => Err(schema_err!(
+                    "Column \"{}\" not found and is not nullable",
+                    field.name()
+                )),
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { schema, columns })
+    }
+
+    /// Returns the requested schema
+    pub(crate) fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Convert `batch` to the requested schema
+    pub(crate) fn apply(&self, batch: RecordBatch) -> Result<RecordBatch, ArrowError> {
+        // Values that cannot be represented in the requested type are an error,
+        // rather than silently being replaced with null
+        let cast_options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let columns = self
+            .schema
+            .fields()
+            .iter()
+            .zip(&self.columns)
+            .map(|(field, idx)| match idx {
+                Some(idx) if batch.column(*idx).data_type() == field.data_type() => {
+                    Ok(Arc::clone(batch.column(*idx)))
+                }
+                Some(idx) => {
+                    cast_with_options(batch.column(*idx), field.data_type(), &cast_options)
+                }
+                None => Ok(new_null_array(field.data_type(), batch.num_rows())),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+        RecordBatch::try_new_with_options(Arc::clone(&self.schema), columns, &options)
+    }
+}
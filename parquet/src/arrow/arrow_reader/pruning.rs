@@ -0,0 +1,794 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Row group and page pruning using min/max statistics
+
+use crate::arrow::arrow_reader::statistics::StatisticsConverter;
+use crate::arrow::arrow_reader::{RowSelection, RowSelector};
+use crate::basic::BoundaryOrder;
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::{ParquetMetaData, RowGroupMetaData};
+use crate::file::page_index::column_index::ColumnIndexMetaData;
+use crate::schema::types::SchemaDescriptor;
+use arrow_array::{Array, ArrayRef, BooleanArray, Scalar};
+use arrow_cast::cast;
+use arrow_ord::cmp;
+use arrow_ord::ord::make_comparator;
+use arrow_schema::{Schema, SortOptions};
+use std::sync::Arc;
+
+/// A predicate on a column, used to prune row groups and pages with their
+/// min/max statistics, see [`StatisticsPruner`]
+///
+/// Values are provided as arrow arrays, and are cast to the data type of the
+/// column in the arrow schema. The single value of [`Self::eq`], [`Self::lt`],
+/// [`Self::gt`] and [`Self::between`] must be provided as an array of length 1.
+///
+/// Like SQL comparisons, predicates are never satisfied by null values.
+#[derive(Debug, Clone)]
+pub struct StatisticsPredicate {
+    column: String,
+    op: PredicateOp,
+}
+
+#[derive(Debug, Clone)]
+enum PredicateOp {
+    Eq(ArrayRef),
+    Lt(ArrayRef),
+    Gt(ArrayRef),
+    Between(ArrayRef, ArrayRef),
+    In(ArrayRef),
+}
+
+impl StatisticsPredicate {
+    /// Create a predicate satisfied by rows where `column = value`
+    pub fn eq(column: impl Into<String>, value: ArrayRef) -> Self {
+        Self::new(column, PredicateOp::Eq(value))
+    }
+
+    /// Create a predicate satisfied by rows where `column < value`
+    pub fn lt(column: impl Into<String>, value: ArrayRef) -> Self {
+        Self::new(column, PredicateOp::Lt(value))
+    }
+
+    /// Create a predicate satisfied by rows where `column > value`
+    pub fn gt(column: impl Into<String>, value: ArrayRef) -> Self {
+        Self::new(column, PredicateOp::Gt(value))
+    }
+
+    /// Create a predicate satisfied by rows where `low <= column <= high`
+    pub fn between(column: impl Into<String>, low: ArrayRef, high: ArrayRef) -> Self {
+        Self::new(column, PredicateOp::Between(low, high))
+    }
+
+    /// Create a predicate satisfied by rows where `column` is equal to any of `values`
+    ///
+    /// Null elements of `values` are ignored
+    pub fn in_list(column: impl Into<String>, values: ArrayRef) -> Self {
+        Self::new(column, PredicateOp::In(values))
+    }
+
+    fn new(column: impl Into<String>, op: PredicateOp) -> Self {
+        Self {
+            column: column.into(),
+            op,
+        }
+    }
+
+    /// Returns the name of the column in the arrow schema
+    pub fn column(&self) -> &str {
+        &self.column
+    }
+
+    /// Returns a `Vec` with an element for each of `mins` and `maxes`, which is `false`
+    /// if the statistics guarantee no value in the corresponding container satisfies
+    /// this predicate
+    fn evaluate(&self, mins: &ArrayRef, maxes: &ArrayRef) -> Result<Vec<bool>> {
+        let mut result = vec![false; mins.len()];
+        for (lower, upper) in self.ranges(mins)? {
+            let mut matched = vec![true; mins.len()];
+            if let Some(lower) = lower {
+                matched = and(matched, lower.above(maxes)?);
+            }
+            if let Some(upper) = upper {
+                matched = and(matched, upper.below(mins)?);
+            }
+            result.iter_mut().zip(matched).for_each(|(r, m)| *r |= m);
+        }
+        Ok(result)
+    }
+
+    /// Returns the ranges of values satisfying this predicate, as pairs of lower and
+    /// upper bounds with the data type of `statistics`
+    ///
+    /// A container may contain values in a range if its max is above the lower bound,
+    /// and its min is below the upper bound.
+    fn ranges(&self, statistics: &ArrayRef) -> Result<Vec<(Option<Bound>, Option<Bound>)>> {
+        let bound = |value: &ArrayRef, inclusive: bool| -> Result<Bound> {
+            let value = self.scalar(value, statistics)?;
+            Ok(Bound { value, inclusive })
+        };
+        Ok(match &self.op {
+            PredicateOp::Eq(v) => vec![(Some(bound(v, true)?), Some(bound(v, true)?))],
+            PredicateOp::Lt(v) => vec![(None, Some(bound(v, false)?))],
+            PredicateOp::Gt(v) => vec![(Some(bound(v, false)?), None)],
+            PredicateOp::Between(low, high) => {
+                vec![(Some(bound(low, true)?), Some(bound(high, true)?))]
+            }
+            PredicateOp::In(values) => {
+                let values = cast(values, statistics.data_type())?;
+                (0..values.len())
+                    .filter(|idx| values.is_valid(*idx))
+                    .map(|idx| {
+                        let bound = || Bound {
+                            value: values.slice(idx, 1),
+                            inclusive: true,
+                        };
+                        (Some(bound()), Some(bound()))
+                    })
+                    .collect()
+            }
+        })
+    }
+
+    /// Returns a `Vec` with an element for each of `mins` and `maxes`, as for
+    /// [`Self::evaluate`], for pages sorted in `order` with no missing statistics
+    ///
+    /// As both the mins and maxes of sorted pages are sorted, the pages that may
+    /// contain a range of values are found with a binary search.
+    fn search(&self, mins: &ArrayRef, maxes: &ArrayRef, order: PageOrder) -> Result<Vec<bool>> {
+        let len = mins.len();
+        // Maps a position in ascending order to the index of the page
+        let page = |pos: usize| match order {
+            PageOrder::Descending => len - 1 - pos,
+            _ => pos,
+        };
+
+        let mut result = vec![false; len];
+        for (lower, upper) in self.ranges(mins)? {
+            // Pages before `start` have a max below the lower bound
+            let start = match lower {
+                Some(lower) => {
+                    let cmp = make_comparator(maxes, &lower.value, SortOptions::default())?;
+                    partition_point(len, |pos| {
+                        let ord = cmp(page(pos), 0);
+                        ord.is_lt() || (ord.is_eq() && !lower.inclusive)
+                    })
+                }
+                None => 0,
+            };
+            // Pages from `end` onwards have a min above the upper bound
+            let end = match upper {
+                Some(upper) => {
+                    let cmp = make_comparator(mins, &upper.value, SortOptions::default())?;
+                    partition_point(len, |pos| {
+                        let ord = cmp(page(pos), 0);
+                        ord.is_lt() || (ord.is_eq() && upper.inclusive)
+                    })
+                }
+                None => len,
+            };
+            (start..end).for_each(|pos| result[page(pos)] = true);
+        }
+        Ok(result)
+    }
+
+    /// Casts `value` to the data type of `statistics`
+    fn scalar(&self, value: &ArrayRef, statistics: &ArrayRef) -> Result<ArrayRef> {
+        if value.len() != 1 {
+            return Err(general_err!(
+                "Statistics predicate on column '{}' expected a single value, got {}",
+                self.column,
+                value.len()
+            ));
+        }
+        Ok(cast(value, statistics.data_type())?)
+    }
+}
+
+/// A bound of a range of values, see [`StatisticsPredicate::ranges`]
+struct Bound {
+    /// The bound, as an array of length 1
+    value: ArrayRef,
+    inclusive: bool,
+}
+
+impl Bound {
+    /// Returns whether containers with `maxes` may contain values above this lower bound
+    fn above(&self, maxes: &ArrayRef) -> Result<Vec<bool>> {
+        let value = Scalar::new(Arc::clone(&self.value));
+        let result = match self.inclusive {
+            true => cmp::gt_eq(maxes, &value)?,
+            false => cmp::gt(maxes, &value)?,
+        };
+        Ok(may_match(&result))
+    }
+
+    /// Returns whether containers with `mins` may contain values below this upper bound
+    fn below(&self, mins: &ArrayRef) -> Result<Vec<bool>> {
+        let value = Scalar::new(Arc::clone(&self.value));
+        let result = match self.inclusive {
+            true => cmp::lt_eq(mins, &value)?,
+            false => cmp::lt(mins, &value)?,
+        };
+        Ok(may_match(&result))
+    }
+}
+
+/// Converts the result of a comparison to a `Vec<bool>`, treating unknown (null)
+/// results as a possible match
+fn may_match(result: &BooleanArray) -> Vec<bool> {
+    (0..result.len())
+        .map(|idx| result.is_null(idx) || result.value(idx))
+        .collect()
+}
+
+fn and(left: Vec<bool>, right: Vec<bool>) -> Vec<bool> {
+    left.into_iter().zip(right).map(|(l, r)| l && r).collect()
+}
+
+/// Returns the first position in `0..len` for which `pred` is `false`, where `pred`
+/// is `true` for all positions before it
+fn partition_point(len: usize, pred: impl Fn(usize) -> bool) -> usize {
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match pred(mid) {
+            true => low = mid + 1,
+            false => high = mid,
+        }
+    }
+    low
+}
+
+/// The order of the pages of a column chunk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PageOrder {
+    /// No order is declared
+    Unordered,
+    Ascending,
+    Descending,
+    /// The declared orders contradict each other
+    Conflicting,
+}
+
+impl PageOrder {
+    /// Returns the order of the pages of a column chunk declared by the
+    /// [`BoundaryOrder`] of its column index, and the [`SortingColumn`]s of its row group
+    ///
+    /// Only the first sorting column determines the order of the pages, as subsequent
+    /// columns are only sorted within runs of equal values of the preceding columns.
+    ///
+    /// [`SortingColumn`]: crate::file::metadata::SortingColumn
+    fn declared(
+        row_group: &RowGroupMetaData,
+        column: usize,
+        column_index: &ColumnIndexMetaData,
+    ) -> Self {
+        let boundary_order = match column_index.get_boundary_order() {
+            Some(BoundaryOrder::ASCENDING) => Self::Ascending,
+            Some(BoundaryOrder::DESCENDING) => Self::Descending,
+            _ => Self::Unordered,
+        };
+        let sorting_order = match row_group.sorting_columns().and_then(|s| s.first()) {
+            Some(sorting) if sorting.column_idx as usize == column => match sorting.descending {
+                true => Self::Descending,
+                false => Self::Ascending,
+            },
+            _ => Self::Unordered,
+        };
+        match (boundary_order, sorting_order) {
+            (Self::Unordered, order) | (order, Self::Unordered) => order,
+            (a, b) if a == b => a,
+            _ => Self::Conflicting,
+        }
+    }
+
+    /// Returns whether the non-null values of `statistics` are sorted in this order
+    fn is_sorted(self, statistics: &ArrayRef) -> Result<bool> {
+        let cmp = make_comparator(statistics, statistics, SortOptions::default())?;
+        let mut valid = (0..statistics.len()).filter(|idx| statistics.is_valid(*idx));
+        let Some(mut prev) = valid.next() else {
+            return Ok(true);
+        };
+        for idx in valid {
+            let sorted = match self {
+                Self::Descending => cmp(prev, idx).is_ge(),
+                _ => cmp(prev, idx).is_le(),
+            };
+            if !sorted {
+                return Ok(false);
+            }
+            prev = idx;
+        }
+        Ok(true)
+    }
+}
+
+/// Prunes row groups and pages that cannot contain rows satisfying a set of
+/// [`StatisticsPredicate`], using the min/max statistics in [`ParquetMetaData`]
+///
+/// Row groups and pages are only pruned if their statistics guarantee that no row
+/// satisfies all of the predicates, so the rows read must still be filtered, e.g.
+/// with a [`RowFilter`]. Predicates on columns without statistics, or not present
+/// in the parquet file, do not prune anything.
+///
+/// Note: as parquet statistics exclude `NaN` values, floating point columns are
+/// pruned as if they contain no `NaN`.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use bytes::Bytes;
+/// # use parquet::arrow::ArrowWriter;
+/// # use parquet::arrow::arrow_reader::{
+/// #     ArrowReaderOptions, ParquetRecordBatchReaderBuilder, StatisticsPredicate, StatisticsPruner,
+/// # };
+/// # use parquet::file::metadata::PageIndexPolicy;
+/// # use parquet::file::properties::WriterProperties;
+/// # let batch = RecordBatch::try_from_iter([
+/// #     ("a", Arc::new(Int32Array::from_iter_values(0..100)) as _),
+/// # ]).unwrap();
+/// # let props = WriterProperties::builder()
+/// #     .set_max_row_group_row_count(Some(20))
+/// #     .set_data_page_row_count_limit(5)
+/// #     .set_write_batch_size(5)
+/// #     .build();
+/// # let mut file = Vec::new();
+/// # let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), Some(props)).unwrap();
+/// # writer.write(&batch).unwrap();
+/// # writer.close().unwrap();
+/// # let file = Bytes::from(file);
+/// let options = ArrowReaderOptions::new().with_page_index_policy(PageIndexPolicy::Required);
+/// let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(file, options).unwrap();
+///
+/// // Only read the row groups and pages that may contain `a > 42`
+/// let value = Arc::new(Int32Array::from(vec![42])) as ArrayRef;
+/// let predicates = [StatisticsPredicate::gt("a", value)];
+/// let pruner = StatisticsPruner::new(builder.schema(), builder.parquet_schema(), builder.metadata());
+/// let row_groups = pruner.prune_row_groups(&predicates).unwrap();
+/// let selection = pruner.prune_pages(&predicates, &row_groups).unwrap();
+/// assert_eq!(row_groups, vec![2, 3, 4]);
+/// assert_eq!(selection.row_count(), 60);
+///
+/// let reader = builder
+///     .with_row_groups(row_groups)
+///     .with_row_selection(selection)
+///     .build()
+///     .unwrap();
+/// let num_rows: usize = reader.map(|b| b.unwrap().num_rows()).sum();
+/// assert_eq!(num_rows, 60);
+/// ```
+///
+/// [`RowFilter`]: crate::arrow::arrow_reader::RowFilter
+#[derive(Debug, Clone, Copy)]
+pub struct StatisticsPruner<'a> {
+    arrow_schema: &'a Schema,
+    parquet_schema: &'a SchemaDescriptor,
+    metadata: &'a ParquetMetaData,
+}
+
+impl<'a> StatisticsPruner<'a> {
+    /// Create a new [`StatisticsPruner`] for a parquet file with `metadata`, with
+    /// columns identified by name in `arrow_schema`
+    pub fn new(
+        arrow_schema: &'a Schema,
+        parquet_schema: &'a SchemaDescriptor,
+        metadata: &'a ParquetMetaData,
+    ) -> Self {
+        Self {
+            arrow_schema,
+            parquet_schema,
+            metadata,
+        }
+    }
+
+    /// Returns the indexes of the row groups that may contain rows satisfying all
+    /// of `predicates`, according to their row group statistics
+    pub fn prune_row_groups(&self, predicates: &[StatisticsPredicate]) -> Result<Vec<usize>> {
+        let row_groups = self.metadata.row_groups();
+        let mut keep = vec![true; row_groups.len()];
+        for predicate in predicates {
+            let converter = self.converter(predicate)?;
+            let mins = converter.row_group_mins(row_groups)?;
+            let maxes = converter.row_group_maxes(row_groups)?;
+            let matched = predicate.evaluate(&mins, &maxes)?;
+            keep.iter_mut().zip(matched).for_each(|(k, m)| *k &= m);
+        }
+        Ok((0..row_groups.len()).filter(|idx| keep[*idx]).collect())
+    }
+
+    /// Returns a [`RowSelection`] of the rows in `row_groups` that are in pages that
+    /// may contain rows satisfying all of `predicates`, according to the page index
+    ///
+    /// As for [`ParquetRecordBatchReaderBuilder::with_row_selection`], the selection
+    /// applies to the rows of `row_groups` in order. If the page index has not been
+    /// loaded, e.g. with [`ArrowReaderOptions::with_page_index_policy`], all rows are
+    /// selected.
+    ///
+    /// If the pages of a column chunk are sorted, as declared by the [`BoundaryOrder`]
+    /// of its column index or the first [`SortingColumn`] of its row group, the pages
+    /// to read are found with a binary search. If the declared orders disagree with
+    /// each other or with the page statistics, all pages of the column chunk are
+    /// conservatively selected.
+    ///
+    /// [`SortingColumn`]: crate::file::metadata::SortingColumn
+    /// [`ParquetRecordBatchReaderBuilder::with_row_selection`]: crate::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::with_row_selection
+    /// [`ArrowReaderOptions::with_page_index_policy`]: crate::arrow::arrow_reader::ArrowReaderOptions::with_page_index_policy
+    pub fn prune_pages(
+        &self,
+        predicates: &[StatisticsPredicate],
+        row_groups: &[usize],
+    ) -> Result<RowSelection> {
+        let num_rows = row_groups
+            .iter()
+            .map(|idx| self.metadata.row_group(*idx).num_rows() as usize)
+            .sum();
+        let mut selection = RowSelection::from(vec![RowSelector::select(num_rows)]);
+
+        let (Some(column_index), Some(offset_index)) =
+            (self.metadata.column_index(), self.metadata.offset_index())
+        else {
+            return Ok(selection);
+        };
+
+        for predicate in predicates {
+            let converter = self.converter(predicate)?;
+            let Some(column) = converter.parquet_column_index() else {
+                continue;
+            };
+
+            let Some(row_counts) = converter.data_page_row_counts(
+                offset_index,
+                self.metadata.row_groups(),
+                row_groups,
+            )?
+            else {
+                continue;
+            };
+            let mut row_counts = row_counts.values().iter();
+
+            let mut selectors = Vec::new();
+            for row_group in row_groups {
+                let row_group = std::slice::from_ref(row_group);
+                let mins = converter.data_page_mins(column_index, offset_index, row_group)?;
+                let maxes = converter.data_page_maxes(column_index, offset_index, row_group)?;
+
+                let order = PageOrder::declared(
+                    self.metadata.row_group(row_group[0]),
+                    column,
+                    &column_index[row_group[0]][column],
+                );
+                let matched = match order {
+                    PageOrder::Unordered => predicate.evaluate(&mins, &maxes)?,
+                    PageOrder::Conflicting => vec![true; mins.len()],
+                    _ if !order.is_sorted(&mins)? || !order.is_sorted(&maxes)? => {
+                        vec![true; mins.len()]
+                    }
+                    _ if mins.null_count() != 0 || maxes.null_count() != 0 => {
+                        predicate.evaluate(&mins, &maxes)?
+                    }
+                    _ => predicate.search(&mins, &maxes, order)?,
+                };
+
+                selectors.extend(
+                    row_counts
+                        .by_ref()
+                        .zip(matched)
+                        .map(|(row_count, matched)| match matched {
+                            true => RowSelector::select(*row_count as usize),
+                            false => RowSelector::skip(*row_count as usize),
+                        }),
+                );
+            }
+            selection = selection.intersection(&RowSelection::from(selectors));
+        }
+        Ok(selection)
+    }
+
+    fn converter(&self, predicate: &StatisticsPredicate) -> Result<StatisticsConverter<'a>> {
+        StatisticsConverter::try_new(&predicate.column, self.arrow_schema, self.parquet_schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::ArrowWriter;
+    use crate::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    use crate::file::metadata::{PageIndexPolicy, SortingColumn};
+    use crate::file::properties::WriterProperties;
+    use arrow_array::cast::AsArray;
+    use arrow_array::{Int32Array, RecordBatch, StringArray, TimestampMillisecondArray};
+    use arrow_select::concat::concat_batches;
+    use bytes::Bytes;
+    use std::sync::Arc;
+
+    /// Writes 5 row groups of 20 rows, with pages of 5 rows, where column `a`
+    /// contains `0..100` and column `b` the string representation of `a`
+    fn test_file() -> ParquetRecordBatchReaderBuilder<Bytes> {
+        let a = Int32Array::from_iter_values(0..100);
+        let b = StringArray::from_iter_values(a.values().iter().map(|v| format!("{v:02}")));
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+        ])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(20))
+            .set_data_page_row_count_limit(5)
+            .set_write_batch_size(5)
+            .build();
+        let mut file = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let options = ArrowReaderOptions::new().with_page_index_policy(PageIndexPolicy::Required);
+        ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(file), options).unwrap()
+    }
+
+    fn int(v: i32) -> ArrayRef {
+        Arc::new(Int32Array::from(vec![v]))
+    }
+
+    fn prune(predicates: &[StatisticsPredicate]) -> (Vec<usize>, Vec<RowSelector>) {
+        let builder = test_file();
+        let pruner = StatisticsPruner::new(
+            builder.schema(),
+            builder.parquet_schema(),
+            builder.metadata(),
+        );
+        let row_groups = pruner.prune_row_groups(predicates).unwrap();
+        let selection = pruner.prune_pages(predicates, &row_groups).unwrap();
+        (row_groups, selection.into())
+    }
+
+    #[test]
+    fn test_prune_comparisons() {
+        let (row_groups, selectors) = prune(&[StatisticsPredicate::eq("a", int(42))]);
+        assert_eq!(row_groups, vec![2]);
+        assert_eq!(
+            selectors,
+            vec![RowSelector::select(5), RowSelector::skip(15)]
+        );
+
+        let (row_groups, selectors) = prune(&[StatisticsPredicate::lt("a", int(7))]);
+        assert_eq!(row_groups, vec![0]);
+        assert_eq!(
+            selectors,
+            vec![RowSelector::select(10), RowSelector::skip(10)]
+        );
+
+        let (row_groups, selectors) = prune(&[StatisticsPredicate::gt("a", int(94))]);
+        assert_eq!(row_groups, vec![4]);
+        assert_eq!(
+            selectors,
+            vec![RowSelector::skip(15), RowSelector::select(5)]
+        );
+
+        let (row_groups, selectors) = prune(&[StatisticsPredicate::between("a", int(18), int(21))]);
+        assert_eq!(row_groups, vec![0, 1]);
+        assert_eq!(
+            selectors,
+            vec![
+                RowSelector::skip(15),
+                RowSelector::select(10),
+                RowSelector::skip(15)
+            ]
+        );
+
+        let values = Arc::new(Int32Array::from(vec![Some(3), None, Some(98)])) as ArrayRef;
+        let (row_groups, selectors) = prune(&[StatisticsPredicate::in_list("a", values)]);
+        assert_eq!(row_groups, vec![0, 4]);
+        assert_eq!(
+            selectors,
+            vec![
+                RowSelector::select(5),
+                RowSelector::skip(30),
+                RowSelector::select(5)
+            ]
+        );
+
+        let (row_groups, selectors) = prune(&[StatisticsPredicate::gt("a", int(100))]);
+        assert!(row_groups.is_empty());
+        assert!(selectors.is_empty());
+    }
+
+    #[test]
+    fn test_prune_multiple_predicates() {
+        // Values are cast to the column type
+        let value = Arc::new(StringArray::from(vec!["50"])) as ArrayRef;
+        let (row_groups, selectors) = prune(&[
+            StatisticsPredicate::gt("a", int(37)),
+            StatisticsPredicate::lt("b", value),
+        ]);
+        assert_eq!(row_groups, vec![1, 2]);
+        assert_eq!(
+            selectors,
+            vec![
+                RowSelector::skip(15),
+                RowSelector::select(15),
+                RowSelector::skip(10)
+            ]
+        );
+    }
+
+    /// Writes a single row group of 100 rows, with pages of 10 rows, where column `ts`
+    /// contains `values` and the row group declares `sorting`
+    fn sorted_file(
+        values: impl IntoIterator<Item = i64>,
+        sorting: Vec<SortingColumn>,
+    ) -> ParquetRecordBatchReaderBuilder<Bytes> {
+        let ts = TimestampMillisecondArray::from_iter_values(values);
+        let batch = RecordBatch::try_from_iter([("ts", Arc::new(ts) as ArrayRef)]).unwrap();
+        let props = WriterProperties::builder()
+            .set_data_page_row_count_limit(10)
+            .set_write_batch_size(10)
+            .set_sorting_columns(Some(sorting))
+            .build();
+        let mut file = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let options = ArrowReaderOptions::new().with_page_index_policy(PageIndexPolicy::Required);
+        ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(file), options).unwrap()
+    }
+
+    fn sorting(descending: bool) -> Vec<SortingColumn> {
+        vec![SortingColumn {
+            column_idx: 0,
+            descending,
+            nulls_first: false,
+        }]
+    }
+
+    fn ts(v: i64) -> ArrayRef {
+        Arc::new(TimestampMillisecondArray::from(vec![v]))
+    }
+
+    fn prune_pages(
+        builder: &ParquetRecordBatchReaderBuilder<Bytes>,
+        predicates: &[StatisticsPredicate],
+    ) -> Vec<RowSelector> {
+        let pruner = StatisticsPruner::new(
+            builder.schema(),
+            builder.parquet_schema(),
+            builder.metadata(),
+        );
+        pruner.prune_pages(predicates, &[0]).unwrap().into()
+    }
+
+    #[test]
+    fn test_prune_descending_pages() {
+        let builder = sorted_file((0..100).rev(), sorting(true));
+        let column_index = &builder.metadata().column_index().unwrap()[0][0];
+        assert_eq!(
+            column_index.get_boundary_order(),
+            Some(BoundaryOrder::DESCENDING)
+        );
+
+        let selectors = prune_pages(&builder, &[StatisticsPredicate::lt("ts", ts(15))]);
+        assert_eq!(
+            selectors,
+            vec![RowSelector::skip(80), RowSelector::select(20)]
+        );
+
+        let selectors = prune_pages(
+            &builder,
+            &[StatisticsPredicate::between("ts", ts(25), ts(54))],
+        );
+        assert_eq!(
+            selectors,
+            vec![
+                RowSelector::skip(40),
+                RowSelector::select(40),
+                RowSelector::skip(20)
+            ]
+        );
+
+        let selectors = prune_pages(&builder, &[StatisticsPredicate::gt("ts", ts(99))]);
+        assert_eq!(selectors, vec![RowSelector::skip(100)]);
+
+        // Only the tail pages are read
+        let reader = builder
+            .with_page_index_predicates(&[StatisticsPredicate::lt("ts", ts(15))])
+            .unwrap()
+            .build()
+            .unwrap();
+        let batches = reader.collect::<std::result::Result<Vec<_>, _>>().unwrap();
+        let batch = concat_batches(&batches[0].schema(), &batches).unwrap();
+        let expected = TimestampMillisecondArray::from_iter_values((0..20).rev());
+        assert_eq!(batch.column(0).as_primitive(), &expected);
+    }
+
+    #[test]
+    fn test_prune_conflicting_order() {
+        // Sorting columns declare ascending, but the boundary order is descending
+        let builder = sorted_file((0..100).rev(), sorting(false));
+        let column_index = &builder.metadata().column_index().unwrap()[0][0];
+        assert_eq!(
+            column_index.get_boundary_order(),
+            Some(BoundaryOrder::DESCENDING)
+        );
+
+        let selectors = prune_pages(&builder, &[StatisticsPredicate::lt("ts", ts(15))]);
+        assert_eq!(selectors, vec![RowSelector::select(100)]);
+    }
+
+    #[test]
+    fn test_prune_contradicted_order() {
+        // Sorting columns declare descending, but the pages are not sorted
+        let pages = [3, 7, 1, 9, 0, 5, 2, 8, 4, 6];
+        let values = (0..100).map(|v| pages[v as usize / 10] * 10 + v % 10);
+        let builder = sorted_file(values, sorting(true));
+        let column_index = &builder.metadata().column_index().unwrap()[0][0];
+        assert_eq!(
+            column_index.get_boundary_order(),
+            Some(BoundaryOrder::UNORDERED)
+        );
+
+        let selectors = prune_pages(&builder, &[StatisticsPredicate::lt("ts", ts(15))]);
+        assert_eq!(selectors, vec![RowSelector::select(100)]);
+    }
+
+    #[test]
+    fn test_prune_without_page_index() {
+        let builder = test_file();
+        let metadata = builder.metadata().as_ref().clone();
+        let metadata = metadata
+            .into_builder()
+            .set_column_index(None)
+            .set_offset_index(None)
+            .build();
+        let pruner = StatisticsPruner::new(builder.schema(), builder.parquet_schema(), &metadata);
+
+        let predicates = [StatisticsPredicate::eq("a", int(42))];
+        let row_groups = pruner.prune_row_groups(&predicates).unwrap();
+        assert_eq!(row_groups, vec![2]);
+        let selectors: Vec<_> = pruner.prune_pages(&predicates, &row_groups).unwrap().into();
+        assert_eq!(selectors, vec![RowSelector::select(20)]);
+    }
+
+    #[test]
+    fn test_prune_errors() {
+        let builder = test_file();
+        let pruner = StatisticsPruner::new(
+            builder.schema(),
+            builder.parquet_schema(),
+            builder.metadata(),
+        );
+
+        let err = pruner
+            .prune_row_groups(&[StatisticsPredicate::eq("c", int(1))])
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Column 'c' not found in schema for statistics conversion"
+        );
+
+        let values = Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef;
+        let err = pruner
+            .prune_row_groups(&[StatisticsPredicate::eq("a", values)])
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Statistics predicate on column 'a' expected a single value, got 2"
+        );
+    }
+}
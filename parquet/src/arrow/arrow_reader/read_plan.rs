@@ -156,7 +156,7 @@ impl ReadPlanBuilder {
             let filter = predicate.evaluate(maybe_batch)?;
             // Since user supplied predicate, check error here to catch bugs quickly
             if filter.len() != input_rows {
-                return Err(arrow_err!(
+                return Err(general_err!(
                     "ArrowPredicate predicate returned {} rows, expected {input_rows}",
                     filter.len()
                 ));
@@ -19,7 +19,7 @@
 
 /// Notice that all the corresponding tests are in
 /// `arrow-rs/parquet/tests/arrow_reader/statistics.rs`.
-use crate::arrow::buffer::bit_util::sign_extend_be;
+use crate::arrow::buffer::bit_util::{sign_extend_be, sign_extend_or_truncate_be};
 use crate::arrow::parquet_column;
 use crate::basic::Type as PhysicalType;
 use crate::errors::{ParquetError, Result};
@@ -56,13 +56,13 @@ pub(crate) fn from_bytes_to_i32(b: &[u8]) -> i32 {
     // The bytes array are from parquet file and must be the big-endian.
     // The endian is defined by parquet format, and the reference document
     // https://github.com/apache/parquet-format/blob/54e53e5d7794d383529dd30746378f19a12afd58/src/main/thrift/parquet.thrift#L66
-    i32::from_be_bytes(sign_extend_be::<4>(b))
+    i32::from_be_bytes(sign_extend_or_truncate_be::<4>(b))
 }
 
 // Convert the bytes array to i64.
 // The endian of the input bytes array must be big-endian.
 pub(crate) fn from_bytes_to_i64(b: &[u8]) -> i64 {
-    i64::from_be_bytes(sign_extend_be::<8>(b))
+    i64::from_be_bytes(sign_extend_or_truncate_be::<8>(b))
 }
 
 // Convert the bytes array to i128.
@@ -403,7 +403,7 @@ macro_rules! get_statistics {
                 [<$stat_type_prefix Int64StatsIterator>]::new($iterator).map(|x| x.map(|x| *x as u64)),
             ))),
             DataType::Float16 => Ok(Arc::new(Float16Array::from_iter(
-                [<$stat_type_prefix FixedLenByteArrayStatsIterator>]::new($iterator).map(|x| x.and_then(|x| {
+                [<$stat_type_prefix FixedLenByteArrayStatsIterator>]::new(skip_legacy_byte_stats($iterator)).map(|x| x.and_then(|x| {
                     from_bytes_to_f16(x)
                 })),
             ))),
@@ -523,25 +523,25 @@ macro_rules! get_statistics {
             },
             DataType::Decimal32(precision, scale) => {
                 let arr = Decimal32Array::from_iter(
-                    [<$stat_type_prefix Decimal32StatsIterator>]::new($iterator)
+                    [<$stat_type_prefix Decimal32StatsIterator>]::new(skip_legacy_byte_stats($iterator))
                 ).with_precision_and_scale(*precision, *scale)?;
                 Ok(Arc::new(arr))
             },
             DataType::Decimal64(precision, scale) => {
                 let arr = Decimal64Array::from_iter(
-                    [<$stat_type_prefix Decimal64StatsIterator>]::new($iterator)
+                    [<$stat_type_prefix Decimal64StatsIterator>]::new(skip_legacy_byte_stats($iterator))
                 ).with_precision_and_scale(*precision, *scale)?;
                 Ok(Arc::new(arr))
             },
             DataType::Decimal128(precision, scale) => {
                 let arr = Decimal128Array::from_iter(
-                    [<$stat_type_prefix Decimal128StatsIterator>]::new($iterator)
+                    [<$stat_type_prefix Decimal128StatsIterator>]::new(skip_legacy_byte_stats($iterator))
                 ).with_precision_and_scale(*precision, *scale)?;
                 Ok(Arc::new(arr))
             },
             DataType::Decimal256(precision, scale) => {
                 let arr = Decimal256Array::from_iter(
-                    [<$stat_type_prefix Decimal256StatsIterator>]::new($iterator)
+                    [<$stat_type_prefix Decimal256StatsIterator>]::new(skip_legacy_byte_stats($iterator))
                 ).with_precision_and_scale(*precision, *scale)?;
                 Ok(Arc::new(arr))
             },
@@ -1257,6 +1257,25 @@ macro_rules! get_data_page_statistics {
         }
     }
 }
+/// Replaces byte array statistics stored in the deprecated `min` and `max` fields with `None`
+///
+/// Older writers computed these using unsigned lexicographic comparison of the bytes, which
+/// does not match the sort order of decimal or float16 values (e.g. it places negative
+/// decimals above positive ones), and so they cannot be used to prune data
+fn skip_legacy_byte_stats<'a, I>(iter: I) -> impl Iterator<Item = Option<&'a ParquetStatistics>>
+where
+    I: Iterator<Item = Option<&'a ParquetStatistics>>,
+{
+    iter.map(|stats| {
+        stats.filter(|s| match s {
+            ParquetStatistics::ByteArray(_) | ParquetStatistics::FixedLenByteArray(_) => {
+                !s.is_min_max_deprecated()
+            }
+            _ => true,
+        })
+    })
+}
+
 /// Extracts the min statistics from an iterator of [`ParquetStatistics`] to an
 /// [`ArrayRef`]
 ///
@@ -1439,9 +1458,11 @@ impl<'a> StatisticsConverter<'a> {
         for metadata in metadatas.into_iter() {
             let row_count = metadata.num_rows();
             let row_count: u64 = row_count.try_into().map_err(|e| {
-                arrow_err!(format!(
-                    "Parquet row count {row_count} too large to convert to u64: {e}"
-                ))
+                general_err!(
+                    "Parquet row count {} too large to convert to u64: {}",
+                    row_count,
+                    e
+                )
             })?;
             builder.append_value(row_count);
         }
@@ -28,10 +28,13 @@ use crate::geospatial::statistics::GeospatialStatistics;
 use crate::schema::types::ColumnDescPtr;
 use crate::util::bit_util::num_required_bits;
 use crate::util::interner::{Interner, Storage};
+use arrow_array::types::ArrowDictionaryKeyType;
 use arrow_array::{
     Array, ArrayAccessor, BinaryArray, BinaryViewArray, DictionaryArray, FixedSizeBinaryArray,
-    LargeBinaryArray, LargeStringArray, StringArray, StringViewArray,
+    LargeBinaryArray, LargeStringArray, StringArray, StringViewArray, TypedDictionaryArray,
 };
+use arrow_buffer::ArrowNativeType;
+use arrow_data::ArrayData;
 use arrow_schema::DataType;
 
 macro_rules! downcast_dict_impl {
@@ -295,7 +298,7 @@ impl FallbackEncoder {
 }
 
 /// [`Storage`] for the [`Interner`] used by [`DictEncoder`]
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 struct ByteArrayStorage {
     /// Encoded dictionary data
     page: Vec<u8>,
@@ -331,10 +334,30 @@ impl Storage for ByteArrayStorage {
     }
 }
 
+/// The interned key of each value of an arrow dictionary, see [`DictEncoder::encode_dictionary`]
+#[derive(Debug, Clone)]
+struct DictionaryKeyMapping {
+    /// The values of the arrow dictionary
+    values: ArrayData,
+    /// The interned key of each value, or [`Self::NOT_INTERNED`]
+    keys: Vec<u64>,
+}
+
+impl DictionaryKeyMapping {
+    const NOT_INTERNED: u64 = u64::MAX;
+
+    fn new(values: ArrayData) -> Self {
+        let keys = vec![Self::NOT_INTERNED; values.len()];
+        Self { values, keys }
+    }
+}
+
 /// A dictionary encoder for byte array data
 #[derive(Debug, Default)]
 struct DictEncoder {
     interner: Interner<ByteArrayStorage>,
+    /// The mapping for the most recently encoded arrow dictionary, if any
+    dictionary_keys: Option<DictionaryKeyMapping>,
     indices: Vec<u64>,
     variable_length_bytes: i64,
 }
@@ -356,13 +379,69 @@ impl DictEncoder {
         }
     }
 
+    /// Encode the values of an arrow dictionary `array` to the in-progress page
+    ///
+    /// Rather than interning the value of each row, each value of the arrow dictionary
+    /// is interned at most once, and the resulting mapping from arrow dictionary keys
+    /// to interned keys is reused for subsequent arrays with the same dictionary.
+    fn encode_dictionary<'a, K, V>(
+        &mut self,
+        array: TypedDictionaryArray<'a, K, V>,
+        indices: &[usize],
+    ) where
+        K: ArrowDictionaryKeyType,
+        V: Sync + Send,
+        &'a V: ArrayAccessor + Array,
+        <&'a V as ArrayAccessor>::Item: AsRef<[u8]>,
+    {
+        let values = array.values();
+        // Dictionaries are compared by pointer, so this is cheap
+        let dictionary = values.to_data();
+        let mapping = match &mut self.dictionary_keys {
+            Some(mapping) if mapping.values.ptr_eq(&dictionary) => mapping,
+            mapping => mapping.insert(DictionaryKeyMapping::new(dictionary)),
+        };
+
+        let keys = array.keys().values();
+        self.indices.reserve(indices.len());
+
+        for idx in indices {
+            let key = keys[*idx].as_usize();
+            let value = values.value(key);
+            let interned = &mut mapping.keys[key];
+            if *interned == DictionaryKeyMapping::NOT_INTERNED {
+                *interned = self.interner.intern(value.as_ref());
+            }
+            self.indices.push(*interned);
+            self.variable_length_bytes += value.as_ref().len() as i64;
+        }
+    }
+
+    /// Returns a new encoder with the same dictionary and no encoded values
+    fn clone_dictionary(&self) -> Self {
+        Self {
+            interner: self.interner.clone(),
+            dictionary_keys: self.dictionary_keys.clone(),
+            indices: Vec::new(),
+            variable_length_bytes: 0,
+        }
+    }
+
     fn bit_width(&self) -> u8 {
         let length = self.interner.storage().values.len();
         num_required_bits(length.saturating_sub(1) as u64)
     }
 
     fn estimated_memory_size(&self) -> usize {
-        self.interner.estimated_memory_size() + self.indices.capacity() * std::mem::size_of::<u64>()
+        let dictionary_keys_size = self
+            .dictionary_keys
+            .as_ref()
+            .map(|m| m.keys.capacity() * std::mem::size_of::<u64>())
+            .unwrap_or_default();
+
+        self.interner.estimated_memory_size()
+            + dictionary_keys_size
+            + self.indices.capacity() * std::mem::size_of::<u64>()
     }
 
     fn estimated_data_page_size(&self) -> usize {
@@ -386,6 +465,7 @@ impl DictEncoder {
 
     fn flush_data_page(
         &mut self,
+        encoding: Encoding,
         min_value: Option<ByteArray>,
         max_value: Option<ByteArray>,
     ) -> DataPageValues<ByteArray> {
@@ -408,7 +488,7 @@ impl DictEncoder {
         DataPageValues {
             buf: encoder.consume().into(),
             num_values,
-            encoding: Encoding::RLE_DICTIONARY,
+            encoding,
             min_value,
             max_value,
             variable_length_bytes,
@@ -416,9 +496,15 @@ impl DictEncoder {
     }
 }
 
+/// The dictionary of a [`ByteArrayEncoder`], which can be used to start the
+/// dictionary of the next column chunk, see [`WriterProperties::dictionary_reuse_enabled`]
+#[derive(Debug)]
+pub(crate) struct ByteArrayDictionary(DictEncoder);
+
 pub struct ByteArrayEncoder {
     fallback: FallbackEncoder,
     dict_encoder: Option<DictEncoder>,
+    dict_data_page_encoding: Encoding,
     statistics_enabled: EnabledStatistics,
     min_value: Option<ByteArray>,
     max_value: Option<ByteArray>,
@@ -426,6 +512,27 @@ pub struct ByteArrayEncoder {
     geo_stats_accumulator: Option<Box<dyn GeoStatsAccumulator>>,
 }
 
+impl ByteArrayEncoder {
+    /// Returns a copy of the dictionary of this encoder, if dictionary encoding is
+    /// enabled and has not fallen back to another encoding
+    pub(crate) fn dictionary(&self) -> Option<ByteArrayDictionary> {
+        let encoder = self.dict_encoder.as_ref()?;
+        Some(ByteArrayDictionary(encoder.clone_dictionary()))
+    }
+
+    /// Starts the dictionary of this encoder with `dictionary`
+    ///
+    /// Does nothing if dictionary encoding is disabled, or if values have already been
+    /// written.
+    pub(crate) fn set_dictionary(&mut self, dictionary: ByteArrayDictionary) {
+        if let Some(encoder) = &mut self.dict_encoder {
+            if encoder.interner.storage().values.is_empty() {
+                *encoder = dictionary.0;
+            }
+        }
+    }
+}
+
 impl ColumnValueEncoder for ByteArrayEncoder {
     type T = ByteArray;
     type Values = dyn Array;
@@ -457,6 +564,7 @@ impl ColumnValueEncoder for ByteArrayEncoder {
             statistics_enabled,
             bloom_filter,
             dict_encoder: dictionary,
+            dict_data_page_encoding: props.dictionary_data_page_encoding(),
             min_value: None,
             max_value: None,
             geo_stats_accumulator,
@@ -538,7 +646,9 @@ impl ColumnValueEncoder for ByteArrayEncoder {
         let max_value = self.max_value.take();
 
         match &mut self.dict_encoder {
-            Some(encoder) => Ok(encoder.flush_data_page(min_value, max_value)),
+            Some(encoder) => {
+                Ok(encoder.flush_data_page(self.dict_data_page_encoding, min_value, max_value))
+            }
             _ => self.fallback.flush_data_page(min_value, max_value),
         }
     }
@@ -553,7 +663,7 @@ impl ColumnValueEncoder for ByteArrayEncoder {
 /// This is a free function so it can be used with `downcast_op!`
 fn encode<T>(values: T, indices: &[usize], encoder: &mut ByteArrayEncoder)
 where
-    T: ArrayAccessor + Copy,
+    T: DictEncodable,
     T::Item: Copy + Ord + AsRef<[u8]>,
 {
     if encoder.statistics_enabled != EnabledStatistics::None {
@@ -579,11 +689,51 @@ where
     }
 
     match &mut encoder.dict_encoder {
-        Some(dict_encoder) => dict_encoder.encode(values, indices),
+        Some(dict_encoder) => values.dict_encode(dict_encoder, indices),
         None => encoder.fallback.encode(values, indices),
     }
 }
 
+/// Arrays that can be encoded by a [`DictEncoder`]
+///
+/// This allows arrow dictionaries to be encoded with [`DictEncoder::encode_dictionary`]
+trait DictEncodable: ArrayAccessor + Copy {
+    fn dict_encode(self, encoder: &mut DictEncoder, indices: &[usize]);
+}
+
+macro_rules! dict_encodable {
+    ($($array:ty),*) => {
+        $(
+            impl DictEncodable for &$array {
+                fn dict_encode(self, encoder: &mut DictEncoder, indices: &[usize]) {
+                    encoder.encode(self, indices)
+                }
+            }
+        )*
+    };
+}
+
+dict_encodable!(
+    StringArray,
+    LargeStringArray,
+    StringViewArray,
+    BinaryArray,
+    LargeBinaryArray,
+    BinaryViewArray
+);
+
+impl<'a, K, V> DictEncodable for TypedDictionaryArray<'a, K, V>
+where
+    K: ArrowDictionaryKeyType,
+    V: Sync + Send,
+    &'a V: ArrayAccessor + Array,
+    <&'a V as ArrayAccessor>::Item: Default + AsRef<[u8]>,
+{
+    fn dict_encode(self, encoder: &mut DictEncoder, indices: &[usize]) {
+        encoder.encode_dictionary(self, indices)
+    }
+}
+
 /// Computes the min and max for the provided array and indices
 ///
 /// This is a free function so it can be used with `downcast_op!`
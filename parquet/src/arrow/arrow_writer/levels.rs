@@ -42,10 +42,14 @@
 
 use crate::column::chunker::CdcChunk;
 use crate::errors::{ParquetError, Result};
+use arrow_array::builder::PrimitiveBuilder;
 use arrow_array::cast::AsArray;
-use arrow_array::{Array, ArrayRef, OffsetSizeTrait};
+use arrow_array::types::{ArrowDictionaryKeyType, RunEndIndexType};
+use arrow_array::{
+    Array, ArrayRef, DictionaryArray, OffsetSizeTrait, RunArray, downcast_run_array,
+};
 use arrow_buffer::bit_iterator::BitIndexIterator;
-use arrow_buffer::{NullBuffer, OffsetBuffer, ScalarBuffer};
+use arrow_buffer::{ArrowNativeType, NullBuffer, OffsetBuffer, ScalarBuffer};
 use arrow_schema::{DataType, Field};
 use std::ops::Range;
 use std::sync::Arc;
@@ -97,6 +101,38 @@ fn is_leaf(data_type: &DataType) -> bool {
     )
 }
 
+/// Converts a [`RunArray`] to a [`DictionaryArray`] with the same values, keyed by the
+/// physical index of each run, so that it can be written in the same way as a dictionary
+/// without materializing its values
+fn run_end_encoded_to_dictionary(array: &ArrayRef) -> Result<ArrayRef> {
+    downcast_run_array!(
+        array => Ok(Arc::new(run_array_to_dictionary(array)?)),
+        t => unreachable!("expected run end encoded array, got {t}")
+    )
+}
+
+fn run_array_to_dictionary<R: RunEndIndexType + ArrowDictionaryKeyType>(
+    array: &RunArray<R>,
+) -> Result<DictionaryArray<R>> {
+    let values = array.values();
+    let value_nulls = values.logical_nulls();
+    let mut keys = PrimitiveBuilder::<R>::with_capacity(array.len());
+    if !array.is_empty() {
+        let run_ends = array.run_ends();
+        let mut start = 0;
+        for physical in run_ends.get_start_physical_index()..=run_ends.get_end_physical_index() {
+            let end = run_ends.values()[physical].as_usize() - run_ends.offset();
+            let end = end.min(array.len());
+            match value_nulls.as_ref().is_some_and(|n| n.is_null(physical)) {
+                true => keys.append_nulls(end - start),
+                false => keys.append_value_n(R::Native::usize_as(physical), end - start),
+            }
+            start = end;
+        }
+    }
+    Ok(DictionaryArray::try_new(keys.finish(), values.clone())?)
+}
+
 /// The definition and repetition level of an array within a potentially nested hierarchy
 #[derive(Debug, Default, Clone, Copy)]
 struct LevelContext {
@@ -175,6 +211,11 @@ impl LevelInfoBuilder {
                 let levels = ArrayLevels::new(parent_ctx, is_nullable, array.clone());
                 Ok(Self::Primitive(levels))
             }
+            DataType::RunEndEncoded(_, v) if is_leaf(v.data_type()) => {
+                let array = run_end_encoded_to_dictionary(array)?;
+                let levels = ArrayLevels::new(parent_ctx, is_nullable, array);
+                Ok(Self::Primitive(levels))
+            }
             DataType::Struct(children) => {
                 let array = array.as_struct();
                 let def_level = match is_nullable {
@@ -30,18 +30,20 @@ use arrow_array::cast::AsArray;
 use arrow_array::types::*;
 use arrow_array::{ArrayRef, Int32Array, RecordBatch, RecordBatchWriter};
 use arrow_schema::{
-    ArrowError, DataType as ArrowDataType, Field, IntervalUnit, SchemaRef, TimeUnit,
+    ArrowError, DataType as ArrowDataType, Field, IntervalUnit, Schema, SchemaRef, TimeUnit,
 };
 
-use super::schema::{add_encoded_arrow_schema_to_metadata, decimal_length_from_precision};
+use super::schema::{
+    add_encoded_arrow_schema_to_metadata, decimal_length_from_precision, rename_duplicate_fields,
+};
 
 use crate::arrow::ArrowSchemaConverter;
-use crate::arrow::arrow_writer::byte_array::ByteArrayEncoder;
+use crate::arrow::arrow_writer::byte_array::{ByteArrayDictionary, ByteArrayEncoder};
 use crate::column::page::{CompressedPage, PageWriteSpec, PageWriter};
 use crate::column::page_encryption::PageEncryptor;
 use crate::column::writer::encoder::ColumnValueEncoder;
 use crate::column::writer::{
-    ColumnCloseResult, ColumnWriter, GenericColumnWriter, get_column_writer,
+    ColumnCloseResult, ColumnWriter, GenericColumnWriter, check_column_encoding, get_column_writer,
 };
 use crate::data_type::{ByteArray, FixedLenByteArray};
 #[cfg(feature = "encryption")]
@@ -132,6 +134,20 @@ mod levels;
 /// [`IntervalMonthDayNanoArray`]: https://docs.rs/arrow/latest/arrow/array/type.IntervalMonthDayNanoArray.html
 /// [support nanosecond intervals]: https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#interval
 ///
+/// ## Field Metadata and Extension Types
+///
+/// Unless [`ArrowWriterOptions::with_skip_arrow_metadata`] is set, the Arrow schema is
+/// embedded in the file metadata under the [`ARROW_SCHEMA_META_KEY`] key. This preserves
+/// the metadata of all fields, including nested fields, and therefore Arrow extension types
+/// identified by the `ARROW:extension:name` and `ARROW:extension:metadata` keys. These are
+/// restored by the reader when it reads the embedded schema.
+///
+/// Without the embedded schema only extension types with a corresponding Parquet logical
+/// type, such as `arrow.uuid` and `arrow.json`, can be restored, and only when the
+/// `arrow_canonical_extension_types` feature is enabled.
+///
+/// [`ARROW_SCHEMA_META_KEY`]: crate::arrow::ARROW_SCHEMA_META_KEY
+///
 /// ## Type Compatibility
 /// The writer can write Arrow [`RecordBatch`]s that are logically equivalent. This means that for
 /// a  given column, the writer can accept multiple Arrow [`DataType`]s that contain the same
@@ -186,6 +202,10 @@ pub struct ArrowWriter<W: Write> {
     /// The schema is used to verify that each record batch written has the correct schema
     arrow_schema: SchemaRef,
 
+    /// The indices of the columns of each record batch to write, if any are excluded by
+    /// [`WriterProperties::excluded_columns`]
+    projection: Option<Vec<usize>>,
+
     /// Creates new [`ArrowRowGroupWriter`] instances as required
     row_group_writer_factory: ArrowRowGroupWriterFactory,
 
@@ -219,6 +239,10 @@ impl<W: Write + Send> ArrowWriter<W> {
     /// The writer will fail if:
     ///  * a `SerializedFileWriter` cannot be created from the ParquetWriter
     ///  * the Arrow schema contains unsupported datatypes such as Unions
+    ///  * sibling fields of the Arrow schema have the same name, unless
+    ///    [`WriterPropertiesBuilder::set_rename_duplicate_columns`] is enabled
+    ///
+    /// [`WriterPropertiesBuilder::set_rename_duplicate_columns`]: crate::file::properties::WriterPropertiesBuilder::set_rename_duplicate_columns
     pub fn try_new(
         writer: W,
         arrow_schema: SchemaRef,
@@ -233,17 +257,37 @@ impl<W: Write + Send> ArrowWriter<W> {
     /// The writer will fail if:
     ///  * a `SerializedFileWriter` cannot be created from the ParquetWriter
     ///  * the Arrow schema contains unsupported datatypes such as Unions
+    ///  * sibling fields of the Arrow schema have the same name, unless
+    ///    [`WriterPropertiesBuilder::set_rename_duplicate_columns`] is enabled
+    ///
+    /// [`WriterPropertiesBuilder::set_rename_duplicate_columns`]: crate::file::properties::WriterPropertiesBuilder::set_rename_duplicate_columns
     pub fn try_new_with_options(
         writer: W,
         arrow_schema: SchemaRef,
         options: ArrowWriterOptions,
     ) -> Result<Self> {
         let mut props = options.properties;
+        let rename_duplicates = props.rename_duplicate_columns() && options.schema_descr.is_none();
+
+        let excluded = props.excluded_columns();
+        let projection: Vec<_> = (0..arrow_schema.fields().len())
+            .filter(|idx| !excluded.contains(arrow_schema.field(*idx).name()))
+            .collect();
+        let (arrow_schema, projection) = match projection.len() == arrow_schema.fields().len() {
+            true => (arrow_schema, None),
+            false => (
+                Arc::new(arrow_schema.project(&projection)?),
+                Some(projection),
+            ),
+        };
 
         let schema = if let Some(parquet_schema) = options.schema_descr {
             parquet_schema.clone()
         } else {
-            let mut converter = ArrowSchemaConverter::new().with_coerce_types(props.coerce_types());
+            let mut converter = ArrowSchemaConverter::new()
+                .with_coerce_types(props.coerce_types())
+                .with_canonicalize_nested_names(props.canonicalize_nested_names())
+                .with_rename_duplicate_columns(props.rename_duplicate_columns());
             if let Some(schema_root) = &options.schema_root {
                 converter = converter.schema_root(schema_root);
             }
@@ -252,8 +296,15 @@ impl<W: Write + Send> ArrowWriter<W> {
         };
 
         if !options.skip_arrow_metadata {
-            // add serialized arrow schema
-            add_encoded_arrow_schema_to_metadata(&arrow_schema, &mut props);
+            // add serialized arrow schema, recording the Parquet names of renamed fields
+            if rename_duplicates {
+                let (_, annotated) = rename_duplicate_fields(arrow_schema.fields(), true)?;
+                let annotated =
+                    Schema::new_with_metadata(annotated, arrow_schema.metadata().clone());
+                add_encoded_arrow_schema_to_metadata(&annotated, &mut props);
+            } else {
+                add_encoded_arrow_schema_to_metadata(&arrow_schema, &mut props);
+            }
         }
 
         let max_row_group_row_count = props.max_row_group_row_count();
@@ -282,6 +333,7 @@ impl<W: Write + Send> ArrowWriter<W> {
             writer: file_writer,
             in_progress: None,
             arrow_schema,
+            projection,
             row_group_writer_factory,
             max_row_group_row_count,
             max_row_group_bytes,
@@ -346,7 +398,20 @@ impl<W: Write + Send> ArrowWriter<W> {
     /// If both limits are set, the lower bound (whichever triggers first) is respected.
     ///
     /// This will fail if the `batch`'s schema does not match the writer's schema.
+    ///
+    /// Columns excluded by [`WriterProperties::excluded_columns`] are not written.
     pub fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        match &self.projection {
+            Some(projection) => {
+                let batch = batch.project(projection)?;
+                self.write_projected(&batch)
+            }
+            None => self.write_projected(batch),
+        }
+    }
+
+    /// Encodes the provided [`RecordBatch`], from which any excluded columns have been removed
+    fn write_projected(&mut self, batch: &RecordBatch) -> Result<()> {
         if batch.num_rows() == 0 {
             return Ok(());
         }
@@ -364,8 +429,8 @@ impl<W: Write + Send> ArrowWriter<W> {
                 let to_write = max_rows - in_progress.buffered_rows;
                 let a = batch.slice(0, to_write);
                 let b = batch.slice(to_write, batch.num_rows() - to_write);
-                self.write(&a)?;
-                return self.write(&b);
+                self.write_projected(&a)?;
+                return self.write_projected(&b);
             }
         }
 
@@ -377,7 +442,7 @@ impl<W: Write + Send> ArrowWriter<W> {
 
                 if current_bytes >= max_bytes {
                     self.flush()?;
-                    return self.write(batch);
+                    return self.write_projected(batch);
                 }
 
                 let avg_row_bytes = current_bytes / in_progress.buffered_rows;
@@ -390,11 +455,11 @@ impl<W: Write + Send> ArrowWriter<W> {
                         if rows_that_fit > 0 {
                             let a = batch.slice(0, rows_that_fit);
                             let b = batch.slice(rows_that_fit, batch.num_rows() - rows_that_fit);
-                            self.write(&a)?;
-                            return self.write(&b);
+                            self.write_projected(&a)?;
+                            return self.write_projected(&b);
                         } else {
                             self.flush()?;
-                            return self.write(batch);
+                            return self.write_projected(batch);
                         }
                     }
                 }
@@ -442,14 +507,41 @@ impl<W: Write + Send> ArrowWriter<W> {
             None => return Ok(()),
         };
 
+        let chunks = in_progress.close()?;
+        if let Some(block_size) = self.writer.properties().hdfs_block_size() {
+            let row_group_size = chunks.iter().map(|c| c.data.length as u64).sum();
+            self.pad_to_block_boundary(block_size, row_group_size)?;
+        }
+
         let mut row_group_writer = self.writer.next_row_group()?;
-        for chunk in in_progress.close()? {
+        for chunk in chunks {
             chunk.append_to_row_group(&mut row_group_writer)?;
         }
         row_group_writer.close()?;
         Ok(())
     }
 
+    /// Writes zero bytes so that a row group of `row_group_size` bytes, which would
+    /// otherwise span a boundary between blocks of `block_size` bytes, starts at the
+    /// next boundary
+    ///
+    /// See [`WriterPropertiesBuilder::set_hdfs_block_size`](crate::file::properties::WriterPropertiesBuilder::set_hdfs_block_size)
+    fn pad_to_block_boundary(&mut self, block_size: u64, row_group_size: u64) -> Result<()> {
+        const ZEROS: [u8; 8192] = [0; 8192];
+
+        let remaining = block_size - self.writer.bytes_written() as u64 % block_size;
+        if row_group_size <= remaining || row_group_size > block_size {
+            return Ok(());
+        }
+        let mut padding = remaining;
+        while padding > 0 {
+            let len = padding.min(ZEROS.len() as u64) as usize;
+            self.writer.write_all(&ZEROS[..len])?;
+            padding -= len as u64;
+        }
+        Ok(())
+    }
+
     /// Additional [`KeyValue`] metadata to be written in addition to those from [`WriterProperties`]
     ///
     /// This method provide a way to append kv_metadata after write RecordBatch
@@ -874,8 +966,14 @@ impl ArrowColumnChunk {
 pub struct ArrowColumnWriter {
     writer: ArrowColumnWriterImpl,
     chunk: SharedColumnChunk,
+    /// Where to store the dictionary for the next row group, if it is reused,
+    /// see [`WriterProperties::dictionary_reuse_enabled`]
+    dictionary: Option<SharedDictionary>,
 }
 
+/// The dictionary of a byte array column, shared between the writers of each row group
+type SharedDictionary = Arc<Mutex<Option<ByteArrayDictionary>>>;
+
 impl std::fmt::Debug for ArrowColumnWriter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ArrowColumnWriter").finish_non_exhaustive()
@@ -942,7 +1040,12 @@ impl ArrowColumnWriter {
     /// Close this column returning the written [`ArrowColumnChunk`]
     pub fn close(self) -> Result<ArrowColumnChunk> {
         let close = match self.writer {
-            ArrowColumnWriterImpl::ByteArray(c) => c.close()?,
+            ArrowColumnWriterImpl::ByteArray(c) => {
+                if let Some(dictionary) = &self.dictionary {
+                    *dictionary.lock().unwrap() = c.encoder().dictionary();
+                }
+                c.close()?
+            }
             ArrowColumnWriterImpl::Column(c) => c.close()?,
         };
         let chunk = Arc::try_unwrap(self.chunk).ok().unwrap();
@@ -1059,6 +1162,8 @@ pub struct ArrowRowGroupWriterFactory {
     schema: SchemaDescPtr,
     arrow_schema: SchemaRef,
     props: WriterPropertiesPtr,
+    /// The dictionary of each leaf column, see [`WriterProperties::dictionary_reuse_enabled`]
+    dictionaries: Vec<SharedDictionary>,
     #[cfg(feature = "encryption")]
     file_encryptor: Option<Arc<FileEncryptor>>,
 }
@@ -1071,10 +1176,14 @@ impl ArrowRowGroupWriterFactory {
     ) -> Self {
         let schema = Arc::clone(file_writer.schema_descr_ptr());
         let props = Arc::clone(file_writer.properties());
+        let dictionaries = (0..schema.num_columns())
+            .map(|_| Default::default())
+            .collect();
         Self {
             schema,
             arrow_schema,
             props,
+            dictionaries,
             #[cfg(feature = "encryption")]
             file_encryptor: file_writer.file_encryptor(),
         }
@@ -1104,12 +1213,13 @@ impl ArrowRowGroupWriterFactory {
     #[cfg(feature = "encryption")]
     fn column_writer_factory(&self, row_group_idx: usize) -> ArrowColumnWriterFactory {
         ArrowColumnWriterFactory::new()
+            .with_dictionaries(self.dictionaries.clone())
             .with_file_encryptor(row_group_idx, self.file_encryptor.clone())
     }
 
     #[cfg(not(feature = "encryption"))]
     fn column_writer_factory(&self, _row_group_idx: usize) -> ArrowColumnWriterFactory {
-        ArrowColumnWriterFactory::new()
+        ArrowColumnWriterFactory::new().with_dictionaries(self.dictionaries.clone())
     }
 }
 
@@ -1140,6 +1250,8 @@ struct ArrowColumnWriterFactory {
     row_group_index: usize,
     #[cfg(feature = "encryption")]
     file_encryptor: Option<Arc<FileEncryptor>>,
+    /// The dictionary of each leaf column, see [`WriterProperties::dictionary_reuse_enabled`]
+    dictionaries: Vec<SharedDictionary>,
 }
 
 impl ArrowColumnWriterFactory {
@@ -1149,9 +1261,17 @@ impl ArrowColumnWriterFactory {
             row_group_index: 0,
             #[cfg(feature = "encryption")]
             file_encryptor: None,
+            dictionaries: vec![],
         }
     }
 
+    /// Reuse the dictionaries of byte array columns stored in `dictionaries`, for
+    /// columns with [`WriterProperties::dictionary_reuse_enabled`]
+    pub fn with_dictionaries(mut self, dictionaries: Vec<SharedDictionary>) -> Self {
+        self.dictionaries = dictionaries;
+        self
+    }
+
     #[cfg(feature = "encryption")]
     pub fn with_file_encryptor(
         mut self,
@@ -1201,23 +1321,39 @@ impl ArrowColumnWriterFactory {
     ) -> Result<()> {
         // Instantiate writers for normal columns
         let col = |desc: &ColumnDescPtr| -> Result<ArrowColumnWriter> {
+            check_column_encoding(desc, props)?;
             let page_writer = self.create_page_writer(desc, out.len())?;
             let chunk = page_writer.buffer.clone();
             let writer = get_column_writer(desc.clone(), props.clone(), page_writer);
             Ok(ArrowColumnWriter {
                 chunk,
                 writer: ArrowColumnWriterImpl::Column(writer),
+                dictionary: None,
             })
         };
 
         // Instantiate writers for byte arrays (e.g. Utf8,  Binary, etc)
         let bytes = |desc: &ColumnDescPtr| -> Result<ArrowColumnWriter> {
+            check_column_encoding(desc, props)?;
             let page_writer = self.create_page_writer(desc, out.len())?;
             let chunk = page_writer.buffer.clone();
-            let writer = GenericColumnWriter::new(desc.clone(), props.clone(), page_writer);
+            let mut writer = GenericColumnWriter::<ByteArrayEncoder>::new(
+                desc.clone(),
+                props.clone(),
+                page_writer,
+            );
+            let dictionary = match props.dictionary_reuse_enabled(desc.path()) {
+                true => self.dictionaries.get(out.len()).cloned(),
+                false => None,
+            };
+            // Start from the dictionary of the previous row group, if any
+            if let Some(previous) = dictionary.as_ref().and_then(|d| d.lock().unwrap().take()) {
+                writer.encoder_mut().set_dictionary(previous);
+            }
             Ok(ArrowColumnWriter {
                 chunk,
                 writer: ArrowColumnWriterImpl::ByteArray(writer),
+                dictionary,
             })
         };
 
@@ -1262,6 +1398,17 @@ impl ArrowColumnWriterFactory {
                 ArrowDataType::FixedSizeBinary(_) => out.push(bytes(leaves.next().unwrap())?),
                 _ => out.push(col(leaves.next().unwrap())?),
             },
+            // Run end encoded arrays are written as dictionaries, see `LevelInfoBuilder`
+            ArrowDataType::RunEndEncoded(_, values) => match values.data_type() {
+                ArrowDataType::Utf8
+                | ArrowDataType::LargeUtf8
+                | ArrowDataType::Binary
+                | ArrowDataType::LargeBinary
+                | ArrowDataType::Utf8View
+                | ArrowDataType::BinaryView
+                | ArrowDataType::FixedSizeBinary(_) => out.push(bytes(leaves.next().unwrap())?),
+                _ => out.push(col(leaves.next().unwrap())?),
+            },
             _ => {
                 return Err(ParquetError::NYI(format!(
                     "Attempting to write an Arrow type {data_type} to parquet that is not yet implemented"
@@ -1434,6 +1581,13 @@ fn write_leaf(
                         write_primitive(typed, array.values(), levels)
                     }
                 },
+                ArrowDataType::Decimal32(_, _) => {
+                    // a decimal with a precision of 1 is stored as an int64
+                    let array = column
+                        .as_primitive::<Decimal32Type>()
+                        .unary::<_, Int64Type>(|v| v as i64);
+                    write_primitive(typed, array.values(), levels)
+                }
                 ArrowDataType::Decimal64(_, _) => {
                     let array = column
                         .as_primitive::<Decimal64Type>()
@@ -1482,10 +1636,9 @@ fn write_leaf(
                         let array = column.as_primitive::<IntervalDayTimeType>();
                         get_interval_dt_array_slice(array, indices)
                     }
-                    _ => {
-                        return Err(ParquetError::NYI(format!(
-                            "Attempting to write an Arrow interval type {interval_unit:?} to parquet that is not yet implemented"
-                        )));
+                    IntervalUnit::MonthDayNano => {
+                        let array = column.as_primitive::<IntervalMonthDayNanoType>();
+                        get_interval_mdn_array_slice(array, indices)?
                     }
                 },
                 ArrowDataType::FixedSizeBinary(_) => {
@@ -1580,6 +1733,35 @@ fn get_interval_dt_array_slice(
     values
 }
 
+/// Returns 12-byte values representing 3 values of months, days and milliseconds (4-bytes each).
+/// Returns an error if the nanoseconds of an Arrow MonthDayNano interval are not a whole number
+/// of milliseconds representable in 4 bytes.
+fn get_interval_mdn_array_slice(
+    array: &arrow_array::IntervalMonthDayNanoArray,
+    indices: &[usize],
+) -> Result<Vec<FixedLenByteArray>> {
+    let mut values = Vec::with_capacity(indices.len());
+    for i in indices {
+        let mut out = [0; 12];
+        let value = array.value(*i);
+        let millis = (value.nanoseconds % 1_000_000 == 0)
+            .then(|| i32::try_from(value.nanoseconds / 1_000_000).ok())
+            .flatten()
+            .ok_or_else(|| {
+                general_err!(
+                    "Cannot write interval with {} nanoseconds to parquet, \
+                     which only supports a 32-bit number of milliseconds",
+                    value.nanoseconds
+                )
+            })?;
+        out[0..4].copy_from_slice(&value.months.to_le_bytes());
+        out[4..8].copy_from_slice(&value.days.to_le_bytes());
+        out[8..12].copy_from_slice(&millis.to_le_bytes());
+        values.push(FixedLenByteArray::from(ByteArray::from(out.to_vec())));
+    }
+    Ok(values)
+}
+
 fn get_decimal_32_array_slice(
     array: &arrow_array::Decimal32Array,
     indices: &[usize],
@@ -1667,8 +1849,10 @@ mod tests {
 
     use std::fs::File;
 
-    use crate::arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder};
-    use crate::arrow::{ARROW_SCHEMA_META_KEY, PARQUET_FIELD_ID_META_KEY};
+    use crate::arrow::arrow_reader::{
+        ArrowReaderOptions, ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder,
+    };
+    use crate::arrow::{ARROW_SCHEMA_META_KEY, PARQUET_FIELD_ID_META_KEY, ProjectionMask};
     use crate::column::page::{Page, PageReader};
     use crate::file::metadata::thrift::PageHeader;
     use crate::file::page_index::column_index::ColumnIndexMetaData;
@@ -1687,7 +1871,7 @@ mod tests {
     use num_traits::{FromPrimitive, ToPrimitive};
     use tempfile::tempfile;
 
-    use crate::basic::Encoding;
+    use crate::basic::{Encoding, PageType};
     use crate::data_type::AsBytes;
     use crate::file::metadata::{ColumnChunkMetaData, ParquetMetaData, ParquetMetaDataReader};
     use crate::file::properties::{
@@ -2034,7 +2218,7 @@ mod tests {
     fn arrow_writer_binary_view() {
         let string_field = Field::new("a", DataType::Utf8View, false);
         let binary_field = Field::new("b", DataType::BinaryView, false);
-        let nullable_string_field = Field::new("a", DataType::Utf8View, true);
+        let nullable_string_field = Field::new("c", DataType::Utf8View, true);
         let schema = Schema::new(vec![string_field, binary_field, nullable_string_field]);
 
         let raw_string_values = vec!["foo", "bar", "large payload over 12 bytes", "lulu"];
@@ -2100,6 +2284,136 @@ mod tests {
         }
     }
 
+    #[test]
+    fn arrow_writer_version_pages_and_encodings() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("dict", DataType::Utf8, false),
+            Field::new("int", DataType::Int64, false),
+            Field::new("float", DataType::Float64, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(StringArray::from_iter_values(
+                    (0..100).map(|i| format!("{}", i % 3)),
+                )),
+                Arc::new(Int64Array::from_iter_values(0..100)),
+                Arc::new(Float64Array::from_iter_values((0..100).map(|i| i as f64))),
+            ],
+        )
+        .unwrap();
+
+        let check = |version: WriterVersion,
+                     strict: bool,
+                     data_page: PageType,
+                     dict_page_encoding: Encoding,
+                     dict_data_encoding: Encoding,
+                     fallback: &[Encoding]| {
+            let props = WriterProperties::builder()
+                .set_writer_version(version)
+                .set_strict_writer_version(strict)
+                .set_column_dictionary_enabled("int".into(), false)
+                .set_column_dictionary_enabled("float".into(), false)
+                .build();
+            let mut buf = Vec::new();
+            let mut writer = ArrowWriter::try_new(&mut buf, schema.clone(), Some(props)).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+
+            let buf = Bytes::from(buf);
+            let reader = SerializedFileReader::new(buf.clone()).unwrap();
+            let row_group = reader.get_row_group(0).unwrap();
+            let pages = |column: usize| -> Vec<(PageType, Encoding)> {
+                row_group
+                    .get_column_page_reader(column)
+                    .unwrap()
+                    .map(|page| {
+                        let page = page.unwrap();
+                        (page.page_type(), page.encoding())
+                    })
+                    .collect()
+            };
+
+            assert_eq!(
+                pages(0),
+                vec![
+                    (PageType::DICTIONARY_PAGE, dict_page_encoding),
+                    (data_page, dict_data_encoding)
+                ]
+            );
+            assert_eq!(pages(1), vec![(data_page, fallback[0])]);
+            assert_eq!(pages(2), vec![(data_page, fallback[1])]);
+
+            let read = ParquetRecordBatchReader::try_new(buf, 1024)
+                .unwrap()
+                .next()
+                .unwrap()
+                .unwrap();
+            assert_eq!(read, batch);
+        };
+
+        check(
+            WriterVersion::PARQUET_1_0,
+            false,
+            PageType::DATA_PAGE,
+            Encoding::PLAIN,
+            Encoding::RLE_DICTIONARY,
+            &[Encoding::PLAIN, Encoding::PLAIN],
+        );
+        // Parquet 1.0 readers only understand v1 data pages and encodings
+        check(
+            WriterVersion::PARQUET_1_0,
+            true,
+            PageType::DATA_PAGE,
+            Encoding::PLAIN_DICTIONARY,
+            Encoding::PLAIN_DICTIONARY,
+            &[Encoding::PLAIN, Encoding::PLAIN],
+        );
+        check(
+            WriterVersion::PARQUET_2_0,
+            true,
+            PageType::DATA_PAGE_V2,
+            Encoding::PLAIN,
+            Encoding::RLE_DICTIONARY,
+            &[Encoding::DELTA_BINARY_PACKED, Encoding::PLAIN],
+        );
+    }
+
+    #[test]
+    fn arrow_writer_strict_parquet_1_0_encodings() {
+        let schema = Arc::new(Schema::new(vec![Field::new("int", DataType::Int64, false)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int64Array::from_iter_values(0..100))],
+        )
+        .unwrap();
+
+        for encoding in [Encoding::DELTA_BINARY_PACKED, Encoding::BYTE_STREAM_SPLIT] {
+            let builder = || {
+                WriterProperties::builder()
+                    .set_writer_version(WriterVersion::PARQUET_1_0)
+                    .set_dictionary_enabled(false)
+                    .set_encoding(encoding)
+            };
+
+            // Explicitly configured encodings are used by default
+            let props = builder().build();
+            let mut writer = ArrowWriter::try_new(Vec::new(), schema.clone(), Some(props)).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+
+            let props = builder().set_strict_writer_version(true).build();
+            let mut writer = ArrowWriter::try_new(Vec::new(), schema.clone(), Some(props)).unwrap();
+            let err = writer.write(&batch).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!(
+                    "Parquet error: Encoding {encoding} is not supported by writer version PARQUET_1_0 for column \"int\""
+                )
+            );
+        }
+    }
+
     fn get_decimal_batch(precision: u8, scale: i8) -> RecordBatch {
         let decimal_field = Field::new("a", DataType::Decimal128(precision, scale), false);
         let schema = Schema::new(vec![decimal_field]);
@@ -2735,7 +3049,14 @@ mod tests {
                 Encoding::DELTA_BINARY_PACKED,
                 Encoding::BYTE_STREAM_SPLIT,
             ],
-            DataType::Float32 | DataType::Float64 => {
+            DataType::Float16
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::FixedSizeBinary(_)
+            | DataType::Decimal32(_, _)
+            | DataType::Decimal64(_, _)
+            | DataType::Decimal128(_, _)
+            | DataType::Decimal256(_, _) => {
                 vec![Encoding::PLAIN, Encoding::BYTE_STREAM_SPLIT]
             }
             _ => vec![Encoding::PLAIN],
@@ -3079,18 +3400,118 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "Attempting to write an Arrow interval type MonthDayNano to parquet that is not yet implemented"
-    )]
     fn interval_month_day_nano_single_column() {
         required_and_optional::<IntervalMonthDayNanoArray, _>(vec![
-            IntervalMonthDayNano::new(0, 1, 5),
-            IntervalMonthDayNano::new(0, 3, 2),
-            IntervalMonthDayNano::new(3, -2, -5),
-            IntervalMonthDayNano::new(-200, 4, -1),
+            IntervalMonthDayNano::new(0, 1, 5_000_000),
+            IntervalMonthDayNano::new(0, 3, 2_000_000),
+            IntervalMonthDayNano::new(3, -2, -5_000_000),
+            IntervalMonthDayNano::new(-200, 4, -1_000_000),
+            IntervalMonthDayNano::new(i32::MAX, i32::MIN, i32::MAX as i64 * 1_000_000),
         ]);
     }
 
+    #[test]
+    fn interval_month_day_nano_without_arrow_schema() {
+        // Without the Arrow schema, all parquet intervals are read as MonthDayNano
+        let values = IntervalMonthDayNanoArray::from(vec![
+            Some(IntervalMonthDayNano::new(1, 2, 3_000_000)),
+            None,
+            Some(IntervalMonthDayNano::new(-1, 0, -86_400_000_000_000)),
+        ]);
+        let year_month = IntervalYearMonthArray::from(vec![Some(14), None, Some(-3)]);
+        let day_time = IntervalDayTimeArray::from(vec![
+            Some(IntervalDayTime::new(5, 6)),
+            None,
+            Some(IntervalDayTime::new(-7, 8)),
+        ]);
+        let batch = RecordBatch::try_from_iter([
+            ("mdn", Arc::new(values.clone()) as ArrayRef),
+            ("ym", Arc::new(year_month) as ArrayRef),
+            ("dt", Arc::new(day_time) as ArrayRef),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder().build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let options = ArrowReaderOptions::new().with_skip_arrow_metadata(true);
+        let reader =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(buf), options)
+                .unwrap()
+                .build()
+                .unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let expected = [
+            values,
+            IntervalMonthDayNanoArray::from(vec![
+                Some(IntervalMonthDayNano::new(14, 0, 0)),
+                None,
+                Some(IntervalMonthDayNano::new(-3, 0, 0)),
+            ]),
+            IntervalMonthDayNanoArray::from(vec![
+                Some(IntervalMonthDayNano::new(0, 5, 6_000_000)),
+                None,
+                Some(IntervalMonthDayNano::new(0, -7, 8_000_000)),
+            ]),
+        ];
+        for (column, expected) in read[0].columns().iter().zip(expected) {
+            assert_eq!(column.as_primitive::<IntervalMonthDayNanoType>(), &expected);
+        }
+    }
+
+    #[test]
+    fn interval_nested_and_dictionary() {
+        let year_month = IntervalYearMonthArray::from(vec![Some(14), None, Some(-3), Some(2)]);
+        let list = ListArray::new(
+            Arc::new(Field::new_list_field(year_month.data_type().clone(), true)),
+            OffsetBuffer::from_lengths([2, 0, 2]),
+            Arc::new(year_month),
+            None,
+        );
+        let day_time = IntervalDayTimeArray::from(vec![
+            Some(IntervalDayTime::new(5, 6)),
+            None,
+            Some(IntervalDayTime::new(-7, 8)),
+        ]);
+        let strukt = StructArray::from(vec![(
+            Arc::new(Field::new("dt", day_time.data_type().clone(), true)),
+            Arc::new(day_time) as ArrayRef,
+        )]);
+        let mdn = IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNano::new(1, 2, 3_000_000),
+            IntervalMonthDayNano::new(-1, 0, 0),
+        ]);
+        let dict = DictionaryArray::new(Int32Array::from(vec![0, 1, 0]), Arc::new(mdn));
+        let batch = RecordBatch::try_from_iter([
+            ("l", Arc::new(list) as ArrayRef),
+            ("s", Arc::new(strukt) as ArrayRef),
+            ("d", Arc::new(dict) as ArrayRef),
+        ])
+        .unwrap();
+        roundtrip(batch, None);
+    }
+
+    #[test]
+    fn interval_month_day_nano_sub_millisecond() {
+        for nanoseconds in [1, -999_999, (i32::MAX as i64 + 1) * 1_000_000] {
+            let array =
+                IntervalMonthDayNanoArray::from(vec![IntervalMonthDayNano::new(1, 2, nanoseconds)]);
+            let batch = RecordBatch::try_from_iter([("i", Arc::new(array) as ArrayRef)]).unwrap();
+            let mut writer = ArrowWriter::try_new(vec![], batch.schema(), None).unwrap();
+            let err = writer.write(&batch).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!(
+                    "Parquet error: Cannot write interval with {nanoseconds} nanoseconds to parquet, \
+                     which only supports a 32-bit number of milliseconds"
+                )
+            );
+        }
+    }
+
     #[test]
     fn binary_single_column() {
         let one_vec: Vec<u8> = (0..SMALL_SIZE as u8).collect();
@@ -3161,6 +3582,131 @@ mod tests {
         );
     }
 
+    #[test]
+    fn fixed_len_decimal_column_bloom_filter() {
+        // Values spanning the sign boundary, whose two's complement encodings are
+        // incorrectly ordered by an unsigned byte comparison
+        let values = vec![
+            1,
+            -1,
+            127,
+            -128,
+            128,
+            -129,
+            0,
+            i64::MAX as i128,
+            i64::MIN as i128,
+        ];
+        let array = Decimal128Array::from(values.clone())
+            .with_precision_and_scale(38, 0)
+            .unwrap();
+        let mut options = RoundTripOptions::new(Arc::new(array), false);
+        options.bloom_filter = true;
+
+        let files = one_column_roundtrip_with_options(options);
+
+        let reader = SerializedFileReader::new(files[0].clone()).unwrap();
+        let column = reader.metadata().row_group(0).column(0);
+        assert_eq!(
+            column.column_type(),
+            crate::basic::Type::FIXED_LEN_BYTE_ARRAY
+        );
+        let stats = column.statistics().unwrap();
+        let min = (i64::MIN as i128).to_be_bytes();
+        let max = (i64::MAX as i128).to_be_bytes();
+        assert_eq!(stats.min_bytes_opt(), Some(min.as_slice()));
+        assert_eq!(stats.max_bytes_opt(), Some(max.as_slice()));
+
+        check_bloom_filter(
+            files,
+            "col".to_string(),
+            values.iter().map(|v| v.to_be_bytes().to_vec()).collect(),
+            vec![2_i128.to_be_bytes().to_vec()],
+        );
+    }
+
+    #[test]
+    fn float16_column_bloom_filter() {
+        let values: Vec<_> = [1.0, f32::NAN, -2.0, 0.5, -0.0]
+            .into_iter()
+            .map(f16::from_f32)
+            .collect();
+        let array = Float16Array::from(values.clone());
+        let mut options = RoundTripOptions::new(Arc::new(array), false);
+        options.bloom_filter = true;
+
+        let files = one_column_roundtrip_with_options(options);
+
+        // NaN is excluded from the statistics
+        let reader = SerializedFileReader::new(files[0].clone()).unwrap();
+        let stats = reader
+            .metadata()
+            .row_group(0)
+            .column(0)
+            .statistics()
+            .unwrap();
+        let min = f16::from_f32(-2.0).to_le_bytes();
+        let max = f16::from_f32(1.0).to_le_bytes();
+        assert_eq!(stats.min_bytes_opt(), Some(min.as_slice()));
+        assert_eq!(stats.max_bytes_opt(), Some(max.as_slice()));
+
+        // NaN is included in the bloom filter
+        check_bloom_filter(
+            files,
+            "col".to_string(),
+            values.iter().map(|v| v.to_le_bytes().to_vec()).collect(),
+            vec![f16::from_f32(3.0).to_le_bytes().to_vec()],
+        );
+    }
+
+    #[test]
+    fn float_column_bloom_filter() {
+        let values = vec![1.0, f32::NAN, -2.0, 0.5, -0.0, f32::INFINITY];
+        let array = Float32Array::from(values.clone());
+        let mut options = RoundTripOptions::new(Arc::new(array), false);
+        options.bloom_filter = true;
+
+        let files = one_column_roundtrip_with_options(options);
+        check_bloom_filter(files, "col".to_string(), values, vec![3.0, 0.25]);
+    }
+
+    #[test]
+    fn double_column_bloom_filter() {
+        let values = vec![1.0, f64::NAN, -2.0, 0.5, -0.0, f64::NEG_INFINITY];
+        let array = Float64Array::from(values.clone());
+        let mut options = RoundTripOptions::new(Arc::new(array), false);
+        options.bloom_filter = true;
+
+        let files = one_column_roundtrip_with_options(options);
+        check_bloom_filter(files, "col".to_string(), values, vec![3.0, 0.25]);
+    }
+
+    #[test]
+    fn fixed_size_binary_column_bloom_filter() {
+        let values: Vec<Vec<u8>> = (0..SMALL_SIZE as u32)
+            .map(|i| i.to_be_bytes().repeat(4))
+            .collect();
+        let array = FixedSizeBinaryArray::try_from_iter(values.iter()).unwrap();
+        let mut options = RoundTripOptions::new(Arc::new(array), false);
+        options.bloom_filter = true;
+
+        let files = one_column_roundtrip_with_options(options);
+
+        let reader = SerializedFileReader::new(files[0].clone()).unwrap();
+        let column = reader.metadata().row_group(0).column(0);
+        assert_eq!(
+            column.column_type(),
+            crate::basic::Type::FIXED_LEN_BYTE_ARRAY
+        );
+
+        check_bloom_filter(
+            files,
+            "col".to_string(),
+            values,
+            vec![u32::MAX.to_be_bytes().repeat(4)],
+        );
+    }
+
     #[test]
     fn empty_string_null_column_bloom_filter() {
         let raw_values: Vec<_> = (0..SMALL_SIZE).map(|i| i.to_string()).collect();
@@ -3393,6 +3939,188 @@ mod tests {
         assert_eq!(map_field.get_fields()[1].name(), "value");
     }
 
+    #[test]
+    fn list_and_map_canonicalized_names() {
+        let list_field =
+            Field::new_list("my_list", Field::new("item", DataType::Int32, true), false);
+        let map_field = Field::new_map(
+            "my_map",
+            "entries",
+            Field::new("keys", DataType::Utf8, false),
+            Field::new("values", DataType::Int32, true),
+            false,
+            true,
+        );
+        let date_field = Field::new("date", DataType::Date64, false);
+
+        let arrow_schema = Arc::new(Schema::new(vec![list_field, map_field, date_field]));
+        let columns = arrow_schema
+            .fields()
+            .iter()
+            .map(|f| create_random_array(f, 100, 0.2, 0.0).unwrap())
+            .collect();
+        let batch = RecordBatch::try_new(arrow_schema.clone(), columns).unwrap();
+
+        let props = WriterProperties::builder()
+            .set_canonicalize_nested_names(true)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, arrow_schema.clone(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let buf = Bytes::from(buf);
+
+        // The Thrift schema uses the names prescribed by the specification
+        let options = ArrowReaderOptions::new().with_skip_arrow_metadata(true);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(buf.clone(), options).unwrap();
+        let fields = builder.parquet_schema().root_schema().get_fields();
+        let list_field = &fields[0].get_fields()[0];
+        assert_eq!(list_field.name(), "list");
+        assert_eq!(list_field.get_fields()[0].name(), "element");
+        let map_field = &fields[1].get_fields()[0];
+        assert_eq!(map_field.name(), "key_value");
+        assert_eq!(map_field.get_fields()[0].name(), "key");
+        assert_eq!(map_field.get_fields()[1].name(), "value");
+        // Unlike with `coerce_types`, the types are unchanged
+        assert_eq!(fields[2].get_physical_type(), crate::basic::Type::INT64);
+
+        // Without the Arrow schema, the Parquet names are used
+        let DataType::List(element) = builder.schema().field(0).data_type() else {
+            panic!("expected list")
+        };
+        assert_eq!(element.name(), "element");
+
+        // With the Arrow schema, the original names are recovered
+        let builder = ParquetRecordBatchReaderBuilder::try_new(buf).unwrap();
+        assert_eq!(builder.schema(), &arrow_schema);
+        let batches = builder
+            .build()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(batches, vec![batch]);
+    }
+
+    #[test]
+    fn arrow_writer_duplicate_field_names() {
+        let nested = Fields::from(vec![
+            Field::new("value", DataType::Int32, true),
+            Field::new("value", DataType::Utf8, true),
+        ]);
+        let arrow_schema = Arc::new(Schema::new(vec![
+            Field::new("value", DataType::Int64, false),
+            Field::new_struct("s", nested, true),
+            Field::new("value", DataType::Float64, true),
+        ]));
+        let columns = arrow_schema
+            .fields()
+            .iter()
+            .map(|f| create_random_array(f, 100, 0.2, 0.0).unwrap())
+            .collect();
+        let batch = RecordBatch::try_new(arrow_schema.clone(), columns).unwrap();
+
+        // Duplicate names are rejected by default
+        let top_level = Arc::new(arrow_schema.project(&[0, 2]).unwrap());
+        let err = ArrowWriter::try_new(vec![], top_level, None).unwrap_err();
+        assert!(err.to_string().contains("Duplicate field name \"value\""));
+
+        let err = ArrowWriter::try_new(vec![], arrow_schema.clone(), None).unwrap_err();
+        assert!(err.to_string().contains("Duplicate field name \"s.value\""));
+
+        let props = WriterProperties::builder()
+            .set_rename_duplicate_columns(true)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, arrow_schema.clone(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let buf = Bytes::from(buf);
+
+        // The Parquet schema uses the new names
+        let options = ArrowReaderOptions::new().with_skip_arrow_metadata(true);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(buf.clone(), options).unwrap();
+        let paths: Vec<_> = builder
+            .parquet_schema()
+            .columns()
+            .iter()
+            .map(|c| c.path().string())
+            .collect();
+        assert_eq!(paths, ["value", "s.value", "s.value_1", "value_1"]);
+
+        // The original names are restored from the Arrow schema
+        let builder = ParquetRecordBatchReaderBuilder::try_new(buf.clone()).unwrap();
+        assert_eq!(builder.schema(), &arrow_schema);
+        let batches = builder
+            .build()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(batches, vec![batch.clone()]);
+
+        // Projecting by the new names is unambiguous
+        let builder = ParquetRecordBatchReaderBuilder::try_new(buf).unwrap();
+        let mask = ProjectionMask::try_columns(builder.parquet_schema(), ["s.value_1", "value_1"])
+            .unwrap();
+        let reader = builder.with_projection(mask).build().unwrap();
+        let expected = Schema::new(vec![
+            Field::new_struct("s", vec![Field::new("value", DataType::Utf8, true)], true),
+            Field::new("value", DataType::Float64, true),
+        ]);
+        assert_eq!(reader.schema().as_ref(), &expected);
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches[0].column(1), batch.column(2));
+    }
+
+    #[test]
+    fn arrow_writer_excluded_columns() {
+        let arrow_schema = Arc::new(Schema::new(vec![
+            Field::new("row_id", DataType::UInt64, false),
+            Field::new("a", DataType::Int32, true),
+            Field::new("debug", DataType::Utf8, true),
+            Field::new("b", DataType::Float64, true),
+        ]));
+        let columns = arrow_schema
+            .fields()
+            .iter()
+            .map(|f| create_random_array(f, 100, 0.2, 0.0).unwrap())
+            .collect();
+        let batch = RecordBatch::try_new(arrow_schema.clone(), columns).unwrap();
+
+        // Names not in the schema are ignored
+        let excluded = ["row_id", "debug", "missing"].map(String::from).to_vec();
+        let props = WriterProperties::builder()
+            .set_excluded_columns(excluded)
+            .set_max_row_group_row_count(Some(30))
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, arrow_schema.clone(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf)).unwrap();
+        let expected = batch.project(&[1, 3]).unwrap();
+        assert_eq!(builder.schema(), expected.schema_ref());
+        assert_eq!(builder.parquet_schema().num_columns(), 2);
+        assert_eq!(builder.metadata().num_row_groups(), 4);
+
+        let batches = builder
+            .build()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let actual = arrow::compute::concat_batches(expected.schema_ref(), &batches).unwrap();
+        assert_eq!(actual, expected);
+
+        // Batches must still match the schema the writer was created with
+        let props = WriterProperties::builder()
+            .set_excluded_columns(vec!["debug".to_string()])
+            .build();
+        let mut writer = ArrowWriter::try_new(vec![], arrow_schema, Some(props)).unwrap();
+        writer.write(&expected).unwrap_err();
+    }
+
     #[test]
     fn fallback_flush_data_page() {
         //tests if the Fallback::flush_data_page clears all buffers correctly
@@ -3688,6 +4416,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn arrow_writer_extension_type_metadata() {
+        use arrow_schema::extension::{EXTENSION_TYPE_METADATA_KEY, EXTENSION_TYPE_NAME_KEY};
+
+        let extension = |name: &str, metadata: &str| {
+            HashMap::from([
+                (EXTENSION_TYPE_NAME_KEY.to_string(), name.to_string()),
+                (
+                    EXTENSION_TYPE_METADATA_KEY.to_string(),
+                    metadata.to_string(),
+                ),
+            ])
+        };
+
+        // Extension types without a corresponding Parquet logical type, at the top level
+        // and nested within lists and structs, are restored from the embedded arrow schema
+        let geometry = Field::new("geometry", DataType::Binary, true)
+            .with_metadata(extension("geoarrow.wkb", r#"{"crs":"OGC:CRS84"}"#));
+        let element = Field::new_list_field(DataType::Utf8, true)
+            .with_metadata(extension("example.tag", "v1"));
+        let id = Field::new("id", DataType::FixedSizeBinary(4), false)
+            .with_metadata(extension("example.id", ""));
+
+        let mut tags = ListBuilder::new(StringBuilder::new()).with_field(element.clone());
+        tags.append_value([Some("a"), None]);
+        tags.append_null();
+        let tags = tags.finish();
+
+        let ids = FixedSizeBinaryArray::try_from_iter([b"abcd", b"efgh"].into_iter()).unwrap();
+        let info = StructArray::new(
+            Fields::from(vec![id]),
+            vec![Arc::new(ids) as ArrayRef],
+            None,
+        );
+
+        let schema = Arc::new(Schema::new(vec![
+            geometry,
+            Field::new("tags", tags.data_type().clone(), true),
+            Field::new("info", info.data_type().clone(), false),
+        ]));
+        let geometries = BinaryArray::from_opt_vec(vec![Some(b"\x01\x01"), None]);
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(geometries), Arc::new(tags), Arc::new(info)],
+        )
+        .unwrap();
+
+        // `roundtrip` asserts the schema, including all field metadata, is preserved
+        roundtrip(batch, None);
+    }
+
     #[test]
     fn arrow_writer_primitive_dictionary() {
         // define schema
@@ -3820,6 +4599,127 @@ mod tests {
         one_column_roundtrip_with_schema(Arc::new(d), schema);
     }
 
+    #[test]
+    fn arrow_writer_run_end_encoded() {
+        let values = StringArray::from(vec![Some("alpha"), None, Some("beta"), Some("alpha")]);
+        let run_ends = Int32Array::from(vec![2, 3, 6, 7]);
+        let array = RunArray::try_new(&run_ends, &values).unwrap();
+        one_column_roundtrip(Arc::new(array), true);
+
+        let values = Int64Array::from(vec![Some(1), None, Some(2), Some(1)]);
+        let run_ends = Int16Array::from(vec![1, 4, 5, 9]);
+        let array = RunArray::try_new(&run_ends, &values).unwrap();
+        one_column_roundtrip(Arc::new(array), true);
+
+        let values = Int32Array::from(vec![3, 1, 2]);
+        let run_ends = Int64Array::from(vec![10, 11, 20]);
+        let array = RunArray::try_new(&run_ends, &values).unwrap();
+        one_column_roundtrip(Arc::new(array), false);
+    }
+
+    #[test]
+    fn arrow_writer_run_end_encoded_sliced() {
+        let values = StringArray::from(vec![Some("alpha"), None, Some("beta"), Some("gamma")]);
+        let run_ends = Int32Array::from(vec![3, 5, 8, 10]);
+        let array = RunArray::try_new(&run_ends, &values).unwrap();
+        let array = Arc::new(array) as ArrayRef;
+        let sliced = array.slice(1, 6);
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "col",
+            sliced.data_type().clone(),
+            true,
+        )]));
+        let batch = RecordBatch::try_new(schema, vec![sliced.clone()]).unwrap();
+        let mut file = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let mut reader = ParquetRecordBatchReader::try_new(Bytes::from(file), 1024).unwrap();
+        let read = reader.next().unwrap().unwrap();
+        assert_eq!(read.column(0).data_type(), sliced.data_type());
+        let expected = StringArray::from(vec![
+            Some("alpha"),
+            Some("alpha"),
+            None,
+            None,
+            Some("beta"),
+            Some("beta"),
+        ]);
+        let actual = arrow_cast::cast(read.column(0), &DataType::Utf8).unwrap();
+        assert_eq!(actual.as_string::<i32>(), &expected);
+    }
+
+    #[test]
+    fn arrow_writer_run_end_encoded_encoding() {
+        let values = StringArray::from(vec!["alpha", "beta"]);
+        let run_ends = Int32Array::from(vec![1000, 2000]);
+        let array = RunArray::try_new(&run_ends, &values).unwrap();
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "col",
+            array.data_type().clone(),
+            false,
+        )]));
+        let batch = RecordBatch::try_new(schema, vec![Arc::new(array)]).unwrap();
+        let mut file = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let file = Bytes::from(file);
+
+        // Written with dictionary encoding
+        let builder = ParquetRecordBatchReaderBuilder::try_new(file.clone()).unwrap();
+        let column = builder.metadata().row_group(0).column(0);
+        assert!(column.dictionary_page_offset().is_some());
+        assert!(column.encodings().any(|e| e == Encoding::RLE_DICTIONARY));
+
+        // Without the arrow schema, read as the values type
+        let options = ArrowReaderOptions::new().with_skip_arrow_metadata(true);
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(file, options).unwrap();
+        assert_eq!(builder.schema().field(0).data_type(), &DataType::Utf8);
+        let mut reader = builder.with_batch_size(2000).build().unwrap();
+        let read = reader.next().unwrap().unwrap();
+        let expected = arrow_cast::cast(batch.column(0), &DataType::Utf8).unwrap();
+        assert_eq!(read.column(0), &expected);
+    }
+
+    #[test]
+    fn arrow_reader_run_end_encoded_hint() {
+        let values = Arc::new(StringArray::from(vec![
+            Some("alpha"),
+            Some("alpha"),
+            None,
+            Some("beta"),
+        ])) as ArrayRef;
+        let batch = RecordBatch::try_from_iter([("col", values)]).unwrap();
+        let mut file = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        // Request run end encoding with the supplied schema
+        let data_type = DataType::RunEndEncoded(
+            Arc::new(Field::new("run_ends", DataType::Int32, false)),
+            Arc::new(Field::new("values", DataType::Utf8, true)),
+        );
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "col",
+            data_type.clone(),
+            true,
+        )]));
+        let options = ArrowReaderOptions::new().with_schema(schema);
+        let mut reader =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(file), options)
+                .unwrap()
+                .build()
+                .unwrap();
+        let read = reader.next().unwrap().unwrap();
+        let expected = arrow_cast::cast(batch.column(0), &data_type).unwrap();
+        assert_eq!(read.column(0), &expected);
+        assert_eq!(read.column(0).as_run::<Int32Type>().values().len(), 3);
+    }
+
     #[test]
     fn u32_min_max() {
         // check values roundtrip through parquet
@@ -4786,6 +5686,111 @@ mod tests {
         assert_eq!(get_dict_page_size(col1_meta), 1024 * 1024 * 4);
     }
 
+    #[test]
+    fn test_dictionary_array_batches() {
+        let schema = Arc::new(Schema::new(vec![Field::new_dictionary(
+            "col",
+            DataType::Int32,
+            DataType::Utf8,
+            true,
+        )]));
+        let values: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "unused", "c"]));
+        let other_values: ArrayRef = Arc::new(StringArray::from(vec!["c", "d", "a"]));
+
+        let dictionary = |keys: Vec<Option<i32>>, values: &ArrayRef| {
+            let array = DictionaryArray::new(Int32Array::from(keys), Arc::clone(values));
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap()
+        };
+        let batches = [
+            dictionary(vec![Some(0), Some(1), None, Some(0)], &values),
+            // Same dictionary as the previous batch
+            dictionary(vec![Some(3), None, Some(1), Some(3)], &values),
+            // Different dictionary with overlapping values
+            dictionary(vec![Some(1), Some(0), Some(2), None], &other_values),
+            dictionary(vec![Some(0), Some(3), Some(1)], &values),
+        ];
+
+        let mut writer = ArrowWriter::try_new(Vec::new(), schema.clone(), None).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        let data = Bytes::from(writer.into_inner().unwrap());
+
+        let reader = ParquetRecordBatchReader::try_new(data.clone(), 1024).unwrap();
+        let read: Vec<_> = reader.map(|b| b.unwrap()).collect();
+        let read = arrow::compute::concat_batches(&schema, &read).unwrap();
+        let expected = arrow::compute::concat_batches(&schema, &batches).unwrap();
+        let as_strings =
+            |batch: &RecordBatch| arrow::compute::cast(batch.column(0), &DataType::Utf8).unwrap();
+        assert_eq!(&as_strings(&read), &as_strings(&expected));
+
+        // Only the values that are used are written to the dictionary page
+        let metadata = ParquetMetaDataReader::new()
+            .parse_and_finish(&data)
+            .unwrap();
+        let column = metadata.row_group(0).column(0);
+        let mut reader = SerializedPageReader::new(Arc::new(data), column, 0, None).unwrap();
+        match reader.get_next_page().unwrap().unwrap() {
+            Page::DictionaryPage { num_values, .. } => assert_eq!(num_values, 4),
+            _ => panic!("expected DictionaryPage"),
+        }
+    }
+
+    #[test]
+    fn test_dictionary_reuse() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("reused", DataType::Utf8, false),
+            Field::new("not_reused", DataType::Utf8, false),
+        ]));
+        let batch = |values: Vec<&str>| {
+            let array: ArrayRef = Arc::new(StringArray::from(values));
+            RecordBatch::try_new(schema.clone(), vec![array.clone(), array]).unwrap()
+        };
+        let batches = [
+            batch(vec!["a", "b", "a", "b"]),
+            batch(vec!["c", "a", "c", "a"]),
+            batch(vec!["d", "d", "d", "d"]),
+        ];
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(4))
+            .set_dictionary_reuse_enabled(true)
+            .set_column_dictionary_reuse_enabled(ColumnPath::from("not_reused"), false)
+            .build();
+        let mut writer = ArrowWriter::try_new(Vec::new(), schema.clone(), Some(props)).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        let data = Bytes::from(writer.into_inner().unwrap());
+
+        let reader = ParquetRecordBatchReader::try_new(data.clone(), 4).unwrap();
+        let read: Vec<_> = reader.map(|b| b.unwrap()).collect();
+        assert_eq!(read, batches);
+
+        let metadata = ParquetMetaDataReader::new()
+            .parse_and_finish(&data)
+            .unwrap();
+        assert_eq!(metadata.num_row_groups(), 3);
+        let dictionary_sizes = |col: usize| -> Vec<u32> {
+            metadata
+                .row_groups()
+                .iter()
+                .map(|row_group| {
+                    let column = row_group.column(col);
+                    let mut reader =
+                        SerializedPageReader::new(Arc::new(data.clone()), column, 0, None).unwrap();
+                    match reader.get_next_page().unwrap().unwrap() {
+                        Page::DictionaryPage { num_values, .. } => num_values,
+                        _ => panic!("expected DictionaryPage"),
+                    }
+                })
+                .collect()
+        };
+        // The dictionary of each row group contains the values of the previous row groups
+        assert_eq!(dictionary_sizes(0), vec![2, 3, 4]);
+        assert_eq!(dictionary_sizes(1), vec![2, 2, 1]);
+    }
+
     struct WriteBatchesShape {
         num_batches: usize,
         rows_per_batch: usize,
@@ -5025,4 +6030,53 @@ mod tests {
         let total_rows: i64 = sizes.iter().sum();
         assert_eq!(total_rows, 100, "Total rows should be preserved");
     }
+
+    #[test]
+    fn test_hdfs_block_size_aligns_row_groups() {
+        const BLOCK_SIZE: u64 = 8192;
+
+        // Returns the byte range of each row group
+        fn row_group_ranges(metadata: &ParquetMetaData) -> Vec<(u64, u64)> {
+            metadata
+                .row_groups()
+                .iter()
+                .map(|rg| {
+                    let (start, len) = rg.column(0).byte_range();
+                    (start, start + len)
+                })
+                .collect()
+        }
+        let spans_boundary =
+            |(start, end): &(u64, u64)| start / BLOCK_SIZE != (end - 1) / BLOCK_SIZE;
+
+        let shape = || WriteBatchesShape {
+            num_batches: 20,
+            rows_per_batch: 10,
+            row_size: 100,
+        };
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(None)
+            .set_max_row_group_bytes(Some(3500));
+
+        let unaligned = write_batches(shape(), props.clone().build());
+        let unaligned_ranges = row_group_ranges(unaligned.metadata());
+        assert!(unaligned_ranges.iter().any(spans_boundary));
+
+        let aligned = write_batches(shape(), props.set_hdfs_block_size(Some(BLOCK_SIZE)).build());
+        let ranges = row_group_ranges(aligned.metadata());
+        assert!(!ranges.iter().any(spans_boundary), "{ranges:?}");
+        assert!(ranges.iter().any(|(start, _)| start % BLOCK_SIZE == 0));
+        assert_eq!(
+            row_group_sizes(aligned.metadata()),
+            row_group_sizes(unaligned.metadata())
+        );
+
+        let batches = aligned
+            .build()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(rows, 200);
+    }
 }
@@ -0,0 +1,291 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use futures::FutureExt;
+use futures::future::BoxFuture;
+
+use crate::arrow::arrow_reader::ArrowReaderOptions;
+use crate::arrow::async_reader::AsyncFileReader;
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::ParquetMetaData;
+
+/// The default maximum gap between two ranges that are merged, see
+/// [`CoalescingReader::with_max_gap`]
+pub const DEFAULT_COALESCE_MAX_GAP: u64 = 1024 * 1024;
+
+/// The default maximum size of a merged request, see
+/// [`CoalescingReader::with_max_request_size`]
+pub const DEFAULT_COALESCE_MAX_REQUEST_SIZE: u64 = 16 * 1024 * 1024;
+
+/// An [`AsyncFileReader`] that merges nearby byte ranges into fewer, larger requests
+///
+/// Reading many small pages or column chunks can result in a large number of
+/// small requests, which is slow and expensive for object stores such as S3.
+/// This wraps another [`AsyncFileReader`] and coalesces the ranges passed to
+/// [`AsyncFileReader::get_byte_ranges`] that are separated by at most
+/// [`Self::with_max_gap`] bytes, as long as the merged request does not exceed
+/// [`Self::with_max_request_size`] bytes. The bytes in the gaps are read and
+/// discarded.
+///
+/// Note some readers, such as [`ParquetObjectReader`], already coalesce ranges.
+///
+/// # Example
+/// ```
+/// # #[tokio::main(flavor="current_thread")]
+/// # async fn main() {
+/// # use std::io::Cursor;
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// # use futures::TryStreamExt;
+/// # use parquet::arrow::async_reader::CoalescingReader;
+/// # use parquet::arrow::{ArrowWriter, ParquetRecordBatchStreamBuilder};
+/// # let batch = RecordBatch::try_from_iter([
+/// #     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+/// # ]).unwrap();
+/// # let mut buffer = Vec::new();
+/// # let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), None).unwrap();
+/// # writer.write(&batch).unwrap();
+/// # writer.close().unwrap();
+/// // Merge ranges less than 64KiB apart into requests of at most 8MiB
+/// let reader = CoalescingReader::new(Cursor::new(buffer))
+///     .with_max_gap(64 * 1024)
+///     .with_max_request_size(8 * 1024 * 1024);
+/// let stream = ParquetRecordBatchStreamBuilder::new(reader)
+///     .await
+///     .unwrap()
+///     .build()
+///     .unwrap();
+/// let batches: Vec<_> = stream.try_collect().await.unwrap();
+/// assert_eq!(batches, vec![batch]);
+/// # }
+/// ```
+///
+/// [`ParquetObjectReader`]: crate::arrow::async_reader::ParquetObjectReader
+#[derive(Clone, Debug)]
+pub struct CoalescingReader<T> {
+    inner: T,
+    max_gap: u64,
+    max_request_size: u64,
+}
+
+impl<T> CoalescingReader<T> {
+    /// Creates a new [`CoalescingReader`] wrapping `inner`
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            max_gap: DEFAULT_COALESCE_MAX_GAP,
+            max_request_size: DEFAULT_COALESCE_MAX_REQUEST_SIZE,
+        }
+    }
+
+    /// Sets the maximum number of bytes between two ranges for them to be
+    /// fetched with a single request (defaults to [`DEFAULT_COALESCE_MAX_GAP`])
+    ///
+    /// A value of `0` only merges adjacent or overlapping ranges.
+    pub fn with_max_gap(self, max_gap: u64) -> Self {
+        Self { max_gap, ..self }
+    }
+
+    /// Sets the maximum size of a merged request, in bytes (defaults to
+    /// [`DEFAULT_COALESCE_MAX_REQUEST_SIZE`])
+    ///
+    /// Ranges that are larger than this are still fetched, but never merged
+    /// with other ranges.
+    pub fn with_max_request_size(self, max_request_size: u64) -> Self {
+        Self {
+            max_request_size,
+            ..self
+        }
+    }
+
+    /// Returns a reference to the wrapped reader
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Consumes this reader, returning the wrapped reader
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: AsyncFileReader> AsyncFileReader for CoalescingReader<T> {
+    fn get_bytes(&mut self, range: Range<u64>) -> BoxFuture<'_, Result<Bytes>> {
+        self.inner.get_bytes(range)
+    }
+
+    fn get_byte_ranges(&mut self, ranges: Vec<Range<u64>>) -> BoxFuture<'_, Result<Vec<Bytes>>> {
+        let coalesced = coalesce_ranges(&ranges, self.max_gap, self.max_request_size);
+        if coalesced.len() == ranges.len() {
+            // Nothing to merge, avoid slicing the results
+            return self.inner.get_byte_ranges(ranges);
+        }
+
+        async move {
+            let fetched = self.inner.get_byte_ranges(coalesced.clone()).await?;
+            if fetched.len() != coalesced.len() {
+                return Err(ParquetError::General(format!(
+                    "expected {} byte ranges, got {}",
+                    coalesced.len(),
+                    fetched.len()
+                )));
+            }
+
+            ranges
+                .iter()
+                .map(|range| {
+                    // The coalesced ranges are sorted and disjoint, so find the
+                    // last one starting at or before this range
+                    let idx = coalesced.partition_point(|c| c.start <= range.start) - 1;
+                    let (coalesced, data) = (&coalesced[idx], &fetched[idx]);
+                    let start = (range.start - coalesced.start) as usize;
+                    let end = (range.end - coalesced.start) as usize;
+                    if end > data.len() {
+                        return Err(ParquetError::EOF(format!(
+                            "Expected {} bytes for range {range:?}, got {}",
+                            range.end - range.start,
+                            data.len().saturating_sub(start)
+                        )));
+                    }
+                    Ok(data.slice(start..end))
+                })
+                .collect()
+        }
+        .boxed()
+    }
+
+    fn get_metadata<'a>(
+        &'a mut self,
+        options: Option<&'a ArrowReaderOptions>,
+    ) -> BoxFuture<'a, Result<Arc<ParquetMetaData>>> {
+        self.inner.get_metadata(options)
+    }
+}
+
+/// Returns the sorted, disjoint ranges covering `ranges`, merging ranges that are
+/// at most `max_gap` bytes apart unless the result would exceed `max_request_size`
+fn coalesce_ranges(ranges: &[Range<u64>], max_gap: u64, max_request_size: u64) -> Vec<Range<u64>> {
+    let mut sorted: Vec<_> = ranges.to_vec();
+    sorted.sort_unstable_by_key(|r| r.start);
+
+    let mut coalesced: Vec<Range<u64>> = Vec::with_capacity(sorted.len());
+    for range in sorted {
+        match coalesced.last_mut() {
+            // Overlapping ranges are always merged, so that the result is disjoint
+            Some(last)
+                if range.start < last.end
+                    || (range.start - last.end <= max_gap
+                        && range.end - last.start <= max_request_size) =>
+            {
+                last.end = last.end.max(range.end);
+            }
+            _ => coalesced.push(range),
+        }
+    }
+    coalesced
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_coalesce_ranges() {
+        // Adjacent and nearby ranges are merged
+        assert_eq!(
+            coalesce_ranges(&[0..10, 10..20, 25..30], 5, 100),
+            vec![0..30]
+        );
+        // Gaps larger than max_gap are not merged
+        assert_eq!(
+            coalesce_ranges(&[0..10, 16..20, 30..40], 5, 100),
+            vec![0..10, 16..20, 30..40]
+        );
+        // Unsorted and overlapping ranges
+        assert_eq!(
+            coalesce_ranges(&[50..60, 0..10, 5..8, 8..15], 0, 100),
+            vec![0..15, 50..60]
+        );
+        // Merged requests do not exceed max_request_size
+        assert_eq!(
+            coalesce_ranges(&[0..10, 10..20, 20..30, 30..40], 0, 20),
+            vec![0..20, 20..40]
+        );
+        // Ranges larger than max_request_size are kept as is
+        assert_eq!(
+            coalesce_ranges(&[0..50, 50..60], 0, 20),
+            vec![0..50, 50..60]
+        );
+        assert_eq!(coalesce_ranges(&[], 0, 20), vec![]);
+    }
+
+    struct TestReader {
+        data: Bytes,
+        requests: Arc<Mutex<Vec<Range<u64>>>>,
+    }
+
+    impl AsyncFileReader for TestReader {
+        fn get_bytes(&mut self, range: Range<u64>) -> BoxFuture<'_, Result<Bytes>> {
+            self.requests.lock().unwrap().push(range.clone());
+            let data = self.data.slice(range.start as usize..range.end as usize);
+            futures::future::ready(Ok(data)).boxed()
+        }
+
+        fn get_metadata<'a>(
+            &'a mut self,
+            _options: Option<&'a ArrowReaderOptions>,
+        ) -> BoxFuture<'a, Result<Arc<ParquetMetaData>>> {
+            unimplemented!()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_coalescing_reader() {
+        let data = Bytes::from_iter(0..=255u8);
+        let requests = Arc::new(Mutex::new(vec![]));
+        let mut reader = CoalescingReader::new(TestReader {
+            data: data.clone(),
+            requests: Arc::clone(&requests),
+        })
+        .with_max_gap(8)
+        .with_max_request_size(64);
+
+        let ranges = vec![100..110, 0..4, 4..10, 112..120, 2..6, 200..255, 20..30];
+        let result = reader.get_byte_ranges(ranges.clone()).await.unwrap();
+        let expected: Vec<_> = ranges
+            .iter()
+            .map(|r| data.slice(r.start as usize..r.end as usize))
+            .collect();
+        assert_eq!(result, expected);
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![0..10, 20..30, 100..120, 200..255]
+        );
+
+        // Ranges that cannot be merged are passed through unchanged
+        requests.lock().unwrap().clear();
+        let ranges = vec![50..60, 0..10];
+        let result = reader.get_byte_ranges(ranges.clone()).await.unwrap();
+        assert_eq!(result, vec![data.slice(50..60), data.slice(0..10)]);
+        assert_eq!(*requests.lock().unwrap(), ranges);
+    }
+}
@@ -37,7 +37,8 @@ use arrow_array::RecordBatch;
 use arrow_schema::{Schema, SchemaRef};
 
 use crate::arrow::arrow_reader::{
-    ArrowReaderBuilder, ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReader,
+    ArrowReaderBuilder, ArrowReaderMetadata, ArrowReaderOptions, OutputSchema,
+    ParquetRecordBatchReader,
 };
 
 use crate::basic::{BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash};
@@ -47,6 +48,9 @@ use crate::bloom_filter::{
 use crate::errors::{ParquetError, Result};
 use crate::file::metadata::{ParquetMetaData, ParquetMetaDataReader};
 
+mod coalesce;
+pub use coalesce::*;
+
 mod metadata;
 pub use metadata::*;
 
@@ -204,7 +208,16 @@ impl ArrowReaderMetadata {
 ///
 /// Allows sharing the same builder for different readers while keeping the same
 /// ParquetRecordBatchStreamBuilder API
-pub struct AsyncReader<T>(T);
+pub struct AsyncReader<T>(T, Option<FetchConcurrency<T>>);
+
+/// Configuration for fetching byte ranges concurrently, see
+/// [`ParquetRecordBatchStreamBuilder::with_fetch_concurrency`]
+struct FetchConcurrency<T> {
+    /// The maximum number of concurrent requests
+    concurrency: usize,
+    /// Creates an additional reader for each concurrent request
+    clone_input: fn(&T) -> T,
+}
 
 /// A builder for reading parquet files from an `async` source as  [`ParquetRecordBatchStream`]
 ///
@@ -408,7 +421,67 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
     /// # }
     /// ```
     pub fn new_with_metadata(input: T, metadata: ArrowReaderMetadata) -> Self {
-        Self::new_builder(AsyncReader(input), metadata)
+        Self::new_builder(AsyncReader(input, None), metadata)
+    }
+
+    /// Cast the [`RecordBatch`]es read from the file to `schema`
+    ///
+    /// Each field of `schema` is read from the projected column with the same name,
+    /// casting it with [`arrow_cast::cast`] if the types differ. This allows, for
+    /// example, reading an `Int32` column as `Int64`, or a `Utf8` column as
+    /// `LargeUtf8`, without a separate cast after reading. Values that cannot be
+    /// represented in the requested type result in an error when read.
+    ///
+    /// Fields of `schema` without a corresponding projected column are read as null,
+    /// and projected columns that are not in `schema` are discarded. Use
+    /// [`Self::with_projection`] to avoid reading columns that are not needed.
+    ///
+    /// [`Self::build`] returns an error if a column cannot be cast to the requested
+    /// type, or if a non-nullable field of `schema` has no corresponding column.
+    ///
+    /// # Example
+    /// ```
+    /// # #[tokio::main(flavor="current_thread")]
+    /// # async fn main() {
+    /// # use std::io::Cursor;
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::Int64Type;
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// # use futures::TryStreamExt;
+    /// # use parquet::arrow::{ArrowWriter, ParquetRecordBatchStreamBuilder};
+    /// # let batch = RecordBatch::try_from_iter([
+    /// #     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+    /// # ]).unwrap();
+    /// # let mut buffer = Vec::new();
+    /// # let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), None).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// // Read the Int32 column "a" as Int64, and add a column "b" of nulls
+    /// let schema = Arc::new(Schema::new(vec![
+    ///     Field::new("a", DataType::Int64, true),
+    ///     Field::new("b", DataType::Utf8, true),
+    /// ]));
+    /// let stream = ParquetRecordBatchStreamBuilder::new(Cursor::new(buffer))
+    ///     .await
+    ///     .unwrap()
+    ///     .with_output_schema(schema.clone())
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(stream.schema(), &schema);
+    ///
+    /// let batches: Vec<_> = stream.try_collect().await.unwrap();
+    /// let a = batches[0].column(0).as_primitive::<Int64Type>();
+    /// assert_eq!(a.values(), &[1, 2, 3]);
+    /// assert_eq!(batches[0].column(1).null_count(), 3);
+    /// # }
+    /// ```
+    pub fn with_output_schema(self, schema: SchemaRef) -> Self {
+        Self {
+            output_schema: Some(schema),
+            ..self
+        }
     }
 
     /// Read bloom filter for a column in a row group
@@ -427,7 +500,7 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
         let offset: u64 = if let Some(offset) = column_metadata.bloom_filter_offset() {
             offset
                 .try_into()
-                .map_err(|_| ParquetError::General("Bloom filter offset is invalid".to_string()))?
+                .map_err(|_| ParquetError::Corrupt("Bloom filter offset is invalid".to_string()))?
         } else {
             return Ok(None);
         };
@@ -467,7 +540,7 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
             ),
             None => {
                 let bitset_length: u64 = header.num_bytes.try_into().map_err(|_| {
-                    ParquetError::General("Bloom filter length is invalid".to_string())
+                    ParquetError::Corrupt("Bloom filter length is invalid".to_string())
                 })?;
                 self.input
                     .0
@@ -497,6 +570,8 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
             offset,
             metrics,
             max_predicate_cache_size,
+            strict_logical_types,
+            output_schema,
         } = self;
 
         // Ensure schema of ParquetRecordBatchStream respects projection, and does
@@ -507,6 +582,14 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
             .filter_leaves(|idx, _| idx < projection_len && projection.leaf_included(idx));
         let projected_schema = Arc::new(Schema::new(projected_fields));
 
+        let output_schema = output_schema
+            .map(|s| OutputSchema::try_new(&projected_schema, s).map(Arc::new))
+            .transpose()?;
+        let stream_schema = match &output_schema {
+            Some(output_schema) => Arc::clone(output_schema.schema()),
+            None => projected_schema,
+        };
+
         let decoder = ParquetPushDecoderBuilder {
             input: NoInput,
             metadata,
@@ -522,19 +605,72 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
             offset,
             metrics,
             max_predicate_cache_size,
+            strict_logical_types,
+            output_schema: None,
         }
         .build()?;
 
         let request_state = RequestState::None { input: input.0 };
 
         Ok(ParquetRecordBatchStream {
-            schema: projected_schema,
+            schema: stream_schema,
             decoder,
             request_state,
+            fetch_concurrency: input.1,
+            output_schema,
         })
     }
 }
 
+impl<T: AsyncFileReader + Clone + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
+    /// Fetch the byte ranges needed to decode each row group using up to
+    /// `concurrency` concurrent requests (defaults to `1`)
+    ///
+    /// By default, the byte ranges of all projected column chunks are fetched with a
+    /// single call to [`AsyncFileReader::get_byte_ranges`]. When `concurrency` is
+    /// greater than `1`, the ranges are instead split into up to `concurrency`
+    /// groups of consecutive ranges, each fetched concurrently with its own clone of
+    /// the input. This can reduce latency for object stores with high round trip
+    /// times, at the cost of issuing more requests.
+    ///
+    /// # Example
+    /// ```
+    /// # #[tokio::main(flavor="current_thread")]
+    /// # async fn main() {
+    /// # use std::io::Cursor;
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    /// # use futures::TryStreamExt;
+    /// # use parquet::arrow::{ArrowWriter, ParquetRecordBatchStreamBuilder};
+    /// # let batch = RecordBatch::try_from_iter([
+    /// #     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+    /// #     ("b", Arc::new(Int32Array::from(vec![4, 5, 6])) as ArrayRef),
+    /// # ]).unwrap();
+    /// # let mut buffer = Vec::new();
+    /// # let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), None).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// // Fetch the column chunks of each row group with up to 8 concurrent requests.
+    /// // This can be any cloneable `AsyncFileReader`, such as a `ParquetObjectReader`
+    /// let stream = ParquetRecordBatchStreamBuilder::new(Cursor::new(buffer))
+    ///     .await
+    ///     .unwrap()
+    ///     .with_fetch_concurrency(8)
+    ///     .build()
+    ///     .unwrap();
+    /// let batches: Vec<_> = stream.try_collect().await.unwrap();
+    /// assert_eq!(batches, vec![batch]);
+    /// # }
+    /// ```
+    pub fn with_fetch_concurrency(mut self, concurrency: usize) -> Self {
+        self.input.1 = (concurrency > 1).then_some(FetchConcurrency {
+            concurrency,
+            clone_input: T::clone,
+        });
+        self
+    }
+}
+
 /// State machine that tracks outstanding requests to fetch data
 ///
 /// The parameter `T` is the input, typically an `AsyncFileReader`
@@ -561,9 +697,36 @@ where
     T: AsyncFileReader + Unpin + Send + 'static,
 {
     /// Issue a request to fetch `ranges`, returning the Outstanding state
-    fn begin_request(mut input: T, ranges: Vec<Range<u64>>) -> Self {
+    fn begin_request(
+        mut input: T,
+        ranges: Vec<Range<u64>>,
+        fetch_concurrency: Option<&FetchConcurrency<T>>,
+    ) -> Self {
         let ranges_captured = ranges.clone();
 
+        if let Some(fetch) = fetch_concurrency.filter(|_| ranges.len() > 1) {
+            // Split the ranges into groups of consecutive ranges, fetching
+            // each group with its own reader so that the requests can proceed
+            // concurrently. The original input is returned upon completion.
+            let chunk_size = ranges.len().div_ceil(fetch.concurrency);
+            let readers: Vec<_> = ranges
+                .chunks(chunk_size)
+                .map(|_| (fetch.clone_input)(&input))
+                .collect();
+            let future = async move {
+                let fetches = readers
+                    .into_iter()
+                    .zip(ranges_captured.chunks(chunk_size))
+                    .map(|(mut reader, ranges)| async move {
+                        reader.get_byte_ranges(ranges.to_vec()).await
+                    });
+                let data = futures::future::try_join_all(fetches).await?;
+                Ok((input, data.into_iter().flatten().collect()))
+            }
+            .boxed();
+            return RequestState::Outstanding { ranges, future };
+        }
+
         // Note this must move the input *into* the future
         // because the get_byte_ranges future has a lifetime
         // (aka can have references internally) and thus must
@@ -621,6 +784,11 @@ pub struct ParquetRecordBatchStream<T> {
     request_state: RequestState<T>,
     /// Decoding state machine (no IO)
     decoder: ParquetPushDecoder,
+    /// Set by [`ParquetRecordBatchStreamBuilder::with_fetch_concurrency`], if any
+    fetch_concurrency: Option<FetchConcurrency<T>>,
+    /// Converts each batch to the schema set by
+    /// [`ParquetRecordBatchStreamBuilder::with_output_schema`], if any
+    output_schema: Option<Arc<OutputSchema>>,
 }
 
 impl<T> std::fmt::Debug for ParquetRecordBatchStream<T> {
@@ -668,11 +836,21 @@ where
                 RequestState::None { input } => {
                     match self.decoder.try_next_reader()? {
                         DecodeResult::NeedsData(ranges) => {
-                            self.request_state = RequestState::begin_request(input, ranges);
+                            self.request_state = RequestState::begin_request(
+                                input,
+                                ranges,
+                                self.fetch_concurrency.as_ref(),
+                            );
                             continue; // poll again (as the input might be ready immediately)
                         }
                         DecodeResult::Data(reader) => {
                             self.request_state = RequestState::None { input };
+                            let reader = match &self.output_schema {
+                                Some(output_schema) => {
+                                    reader.with_output_schema(Arc::clone(output_schema))
+                                }
+                                None => reader,
+                            };
                             return Ok(Some(reader));
                         }
                         DecodeResult::Finished => return Ok(None),
@@ -730,11 +908,19 @@ where
                     // No outstanding requests, proceed to decode the next batch
                     match self.decoder.try_decode()? {
                         DecodeResult::NeedsData(ranges) => {
-                            self.request_state = RequestState::begin_request(input, ranges);
+                            self.request_state = RequestState::begin_request(
+                                input,
+                                ranges,
+                                self.fetch_concurrency.as_ref(),
+                            );
                             continue; // poll again (as the input might be ready immediately)
                         }
                         DecodeResult::Data(batch) => {
                             self.request_state = RequestState::None { input };
+                            let batch = match &self.output_schema {
+                                Some(output_schema) => output_schema.apply(batch)?,
+                                None => batch,
+                            };
                             return Ok(Poll::Ready(Some(batch)));
                         }
                         DecodeResult::Finished => {
@@ -780,16 +966,17 @@ mod tests {
     use crate::file::metadata::PageIndexPolicy;
     use crate::file::metadata::ParquetMetaDataReader;
     use crate::file::properties::WriterProperties;
+    use arrow::compute::concat_batches;
     use arrow::compute::kernels::cmp::eq;
     use arrow::error::Result as ArrowResult;
     use arrow_array::builder::{Float32Builder, ListBuilder, StringBuilder};
     use arrow_array::cast::AsArray;
-    use arrow_array::types::Int32Type;
+    use arrow_array::types::{Int32Type, Int64Type};
     use arrow_array::{
         Array, ArrayRef, BooleanArray, Int32Array, RecordBatchReader, Scalar, StringArray,
         StructArray, UInt64Array,
     };
-    use arrow_schema::{DataType, Field, Schema};
+    use arrow_schema::{DataType, Field, Fields, Schema};
     use futures::{StreamExt, TryStreamExt};
     use rand::{Rng, rng};
     use std::collections::HashMap;
@@ -1320,6 +1507,195 @@ mod tests {
         assert_eq!(col2.values(), &[4, 5]);
     }
 
+    /// An [`AsyncFileReader`] that records the maximum number of concurrent requests
+    #[derive(Clone)]
+    struct ConcurrencyTrackingReader {
+        inner: TestReader,
+        in_flight: Arc<Mutex<(usize, usize)>>,
+    }
+
+    impl AsyncFileReader for ConcurrencyTrackingReader {
+        fn get_bytes(&mut self, range: Range<u64>) -> BoxFuture<'_, Result<Bytes>> {
+            self.inner.get_bytes(range)
+        }
+
+        fn get_byte_ranges(
+            &mut self,
+            ranges: Vec<Range<u64>>,
+        ) -> BoxFuture<'_, Result<Vec<Bytes>>> {
+            async move {
+                {
+                    let mut in_flight = self.in_flight.lock().unwrap();
+                    in_flight.0 += 1;
+                    in_flight.1 = in_flight.1.max(in_flight.0);
+                }
+                // Yield so that the other concurrent requests can start
+                tokio::task::yield_now().await;
+                let result = self.inner.get_byte_ranges(ranges).await;
+                self.in_flight.lock().unwrap().0 -= 1;
+                result
+            }
+            .boxed()
+        }
+
+        fn get_metadata<'a>(
+            &'a mut self,
+            options: Option<&'a ArrowReaderOptions>,
+        ) -> BoxFuture<'a, Result<Arc<ParquetMetaData>>> {
+            self.inner.get_metadata(options)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_concurrency() {
+        let columns: Vec<_> = (0..5)
+            .map(|i| {
+                let values = Int32Array::from_iter_values((0..100).map(|v| v * i));
+                (format!("c{i}"), Arc::new(values) as ArrayRef)
+            })
+            .collect();
+        let data = RecordBatch::try_from_iter(columns).unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(50))
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, data.schema(), Some(props)).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+        let buf = Bytes::from(buf);
+
+        for (concurrency, expected_max_in_flight) in [(1, 1), (2, 2), (3, 3), (10, 5)] {
+            let reader = ConcurrencyTrackingReader {
+                inner: TestReader::new(buf.clone()),
+                in_flight: Default::default(),
+            };
+            let requests = reader.inner.requests.clone();
+            let in_flight = reader.in_flight.clone();
+
+            let stream = ParquetRecordBatchStreamBuilder::new(reader)
+                .await
+                .unwrap()
+                .with_fetch_concurrency(concurrency)
+                .build()
+                .unwrap();
+            let batches: Vec<_> = stream.try_collect().await.unwrap();
+            assert_eq!(concat_batches(&data.schema(), &batches).unwrap(), data);
+
+            // Every column chunk of both row groups is fetched exactly once
+            assert_eq!(requests.lock().unwrap().len(), 10);
+            assert_eq!(*in_flight.lock().unwrap(), (0, expected_max_in_flight));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_output_schema() {
+        let a = StringArray::from_iter_values(["a", "b", "c", "d"]);
+        let b = StringArray::from_iter_values(["1", "2", "3", "4"]);
+        let c = Int32Array::from_iter(0..4);
+        let data = RecordBatch::try_from_iter([
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+            ("c", Arc::new(c) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(2))
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, data.schema(), Some(props)).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+        let test = TestReader::new(buf.into());
+
+        let output_schema = Arc::new(Schema::new(vec![
+            Field::new("c", DataType::Int64, false),
+            Field::new("missing", DataType::Float64, true),
+            Field::new("a", DataType::LargeUtf8, false),
+        ]));
+        let stream = ParquetRecordBatchStreamBuilder::new(test.clone())
+            .await
+            .unwrap()
+            .with_output_schema(Arc::clone(&output_schema))
+            .build()
+            .unwrap();
+        assert_eq!(stream.schema(), &output_schema);
+
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        assert_eq!(batches.len(), 2);
+        let batch = &batches[1];
+        assert_eq!(batch.schema(), output_schema);
+        let c = batch.column(0).as_primitive::<Int64Type>();
+        assert_eq!(c.values(), &[2, 3]);
+        assert_eq!(batch.column(1).null_count(), 2);
+        let a = batch.column(2).as_string::<i64>();
+        assert_eq!(a.value(0), "c");
+        assert_eq!(a.value(1), "d");
+
+        // Readers returned by next_row_group also convert their batches
+        let mut stream = ParquetRecordBatchStreamBuilder::new(test.clone())
+            .await
+            .unwrap()
+            .with_output_schema(Arc::clone(&output_schema))
+            .build()
+            .unwrap();
+        let reader = stream.next_row_group().await.unwrap().unwrap();
+        assert_eq!(reader.schema(), output_schema);
+        let batches = reader.collect::<ArrowResult<Vec<_>>>().unwrap();
+        let c = batches[0].column(0).as_primitive::<Int64Type>();
+        assert_eq!(c.values(), &[0, 1]);
+
+        // Values that cannot be cast are an error when read
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Int32, true),
+        ]));
+        let stream = ParquetRecordBatchStreamBuilder::new(test.clone())
+            .await
+            .unwrap()
+            .with_output_schema(schema)
+            .build()
+            .unwrap();
+        let err = stream.try_collect::<Vec<_>>().await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "External: Cast error: Cannot cast string 'a' to value of Int32 type"
+        );
+
+        // Types that cannot be cast are an error when building the stream
+        let fields = Fields::from(vec![Field::new("x", DataType::Int32, true)]);
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "c",
+            DataType::Struct(fields),
+            true,
+        )]));
+        let err = ParquetRecordBatchStreamBuilder::new(test.clone())
+            .await
+            .unwrap()
+            .with_output_schema(schema)
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Cannot cast column \"c\" from Int32 to Struct(\"x\": Int32)"
+        );
+
+        // As are non-nullable fields that are not read
+        let schema = Arc::new(Schema::new(vec![Field::new("c", DataType::Int64, false)]));
+        let builder = ParquetRecordBatchStreamBuilder::new(test).await.unwrap();
+        let mask = ProjectionMask::try_columns(builder.parquet_schema(), ["a"]).unwrap();
+        let err = builder
+            .with_projection(mask)
+            .with_output_schema(schema)
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Column \"c\" not found and is not nullable"
+        );
+    }
+
     #[tokio::test]
     async fn test_batch_size_overallocate() {
         let testdata = arrow::util::test_util::parquet_test_data();
@@ -136,6 +136,12 @@ impl<T: AsyncWrite + Unpin + Send> AsyncFileWriter for T {
 /// although this will likely increase overall file size and reduce query performance.
 /// See [ArrowWriter] for more information.
 ///
+/// Alternatively, [`Self::with_max_buffered_bytes`] configures a threshold above which
+/// [`Self::write`] closes the in progress row group early, and does not resolve until the
+/// encoded bytes have been accepted by the [`AsyncFileWriter`]. This provides natural
+/// back-pressure: a producer awaiting [`Self::write`] cannot outpace a slow sink by more
+/// than the configured number of bytes.
+///
 /// ```no_run
 /// # use tokio::fs::File;
 /// # use arrow_array::RecordBatch;
@@ -156,6 +162,9 @@ pub struct AsyncArrowWriter<W> {
 
     /// Async writer provided by caller
     async_writer: W,
+
+    /// Buffered bytes above which the in progress row group is flushed on write
+    max_buffered_bytes: Option<usize>,
 }
 
 impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
@@ -180,9 +189,34 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
         Ok(Self {
             sync_writer,
             async_writer: writer,
+            max_buffered_bytes: None,
         })
     }
 
+    /// Sets the maximum number of bytes buffered in memory before [`Self::write`]
+    /// flushes the in progress row group to the underlying [`AsyncFileWriter`]
+    ///
+    /// After each call to [`Self::write`], if [`Self::memory_size`] exceeds `max_bytes`,
+    /// the in progress row group is closed early and written to the [`AsyncFileWriter`]
+    /// before the returned future resolves.
+    ///
+    /// Note that parquet requires the pages of a column chunk to be contiguous, and so
+    /// buffered data can only be handed to the sink a whole row group at a time. A low
+    /// threshold will therefore produce many small row groups.
+    ///
+    /// Defaults to `None`, in which case row groups are only closed once they reach the
+    /// limits configured in [`WriterProperties`]
+    pub fn with_max_buffered_bytes(mut self, max_bytes: Option<usize>) -> Self {
+        self.max_buffered_bytes = max_bytes;
+        self
+    }
+
+    /// Returns the maximum number of bytes buffered before [`Self::write`] flushes,
+    /// if any. See [`Self::with_max_buffered_bytes`]
+    pub fn max_buffered_bytes(&self) -> Option<usize> {
+        self.max_buffered_bytes
+    }
+
     /// Returns metadata for any flushed row groups
     pub fn flushed_row_groups(&self) -> &[RowGroupMetaData] {
         self.sync_writer.flushed_row_groups()
@@ -214,11 +248,19 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
 
     /// Enqueues the provided `RecordBatch` to be written
     ///
-    /// After every sync write by the inner [ArrowWriter], the inner buffer will be
-    /// checked and flush if at least half full
+    /// After every sync write by the inner [ArrowWriter], any completed row groups are
+    /// written to the [`AsyncFileWriter`]. If [`Self::with_max_buffered_bytes`] is set and
+    /// the buffered data exceeds it, the in progress row group is flushed as well
     pub async fn write(&mut self, batch: &RecordBatch) -> Result<()> {
         let before = self.sync_writer.flushed_row_groups().len();
         self.sync_writer.write(batch)?;
+
+        if let Some(max) = self.max_buffered_bytes {
+            if self.sync_writer.memory_size() > max {
+                self.sync_writer.flush()?;
+            }
+        }
+
         if before != self.sync_writer.flushed_row_groups().len() {
             self.do_write().await?;
         }
@@ -299,6 +341,7 @@ mod tests {
     use arrow_array::{ArrayRef, BinaryArray, Int32Array, Int64Array, RecordBatchReader};
     use bytes::Bytes;
     use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     use super::*;
 
@@ -467,4 +510,95 @@ mod tests {
 
         writer.close().await.unwrap();
     }
+
+    /// An [`AsyncFileWriter`] that yields to the runtime before accepting each write,
+    /// simulating a slow sink
+    struct ThrottledWriter {
+        buffer: Vec<u8>,
+        writes: Arc<AtomicUsize>,
+    }
+
+    impl AsyncFileWriter for ThrottledWriter {
+        fn write(&mut self, bs: Bytes) -> BoxFuture<'_, Result<()>> {
+            async move {
+                for _ in 0..10 {
+                    tokio::task::yield_now().await;
+                }
+                self.writes.fetch_add(1, Ordering::Relaxed);
+                self.buffer.extend_from_slice(&bs);
+                Ok(())
+            }
+            .boxed()
+        }
+
+        fn complete(&mut self) -> BoxFuture<'_, Result<()>> {
+            async { Ok(()) }.boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_writer_max_buffered_bytes() {
+        let col = Arc::new(Int64Array::from_iter_values(0..1024)) as ArrayRef;
+        let batch = RecordBatch::try_from_iter([("col", col)]).unwrap();
+        let batch_size = batch.get_array_memory_size();
+        let max_bytes = 4 * batch_size;
+
+        let writes = Arc::new(AtomicUsize::new(0));
+        let sink = ThrottledWriter {
+            buffer: Vec::new(),
+            writes: writes.clone(),
+        };
+        let mut writer = AsyncArrowWriter::try_new(sink, batch.schema(), None)
+            .unwrap()
+            .with_max_buffered_bytes(Some(max_bytes));
+        assert_eq!(writer.max_buffered_bytes(), Some(max_bytes));
+
+        let mut peak = 0;
+        for _ in 0..100 {
+            writer.write(&batch).await.unwrap();
+            // Once write resolves, buffered data is bounded by the threshold
+            peak = peak.max(writer.memory_size());
+            assert!(writer.memory_size() <= max_bytes);
+            assert!(writer.in_progress_size() <= max_bytes);
+        }
+        assert!(peak > 0);
+
+        // Row groups were closed early and handed to the sink as they completed
+        let row_groups = writer.flushed_row_groups().len();
+        assert!(row_groups > 1, "{row_groups}");
+        assert_eq!(writes.load(Ordering::Relaxed), row_groups);
+
+        writer.finish().await.unwrap();
+        let buffer = Bytes::from(writer.into_inner().buffer);
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(buffer)
+            .unwrap()
+            .build()
+            .unwrap();
+        let num_rows: usize = reader.map(|b| b.unwrap().num_rows()).sum();
+        assert_eq!(num_rows, 100 * batch.num_rows());
+    }
+
+    #[tokio::test]
+    async fn test_async_writer_max_buffered_bytes_unset() {
+        let col = Arc::new(Int64Array::from_iter_values(0..1024)) as ArrayRef;
+        let batch = RecordBatch::try_from_iter([("col", col)]).unwrap();
+
+        let writes = Arc::new(AtomicUsize::new(0));
+        let sink = ThrottledWriter {
+            buffer: Vec::new(),
+            writes: writes.clone(),
+        };
+        let mut writer = AsyncArrowWriter::try_new(sink, batch.schema(), None).unwrap();
+        assert_eq!(writer.max_buffered_bytes(), None);
+
+        for _ in 0..100 {
+            writer.write(&batch).await.unwrap();
+        }
+        // Without a threshold everything remains buffered in a single row group
+        assert!(writer.flushed_row_groups().is_empty());
+        assert_eq!(writes.load(Ordering::Relaxed), 0);
+        assert_eq!(writer.in_progress_rows(), 100 * batch.num_rows());
+        writer.close().await.unwrap();
+    }
 }
@@ -28,6 +28,18 @@ use tokio::io::AsyncWriteExt;
 
 /// [`ParquetObjectWriter`] for writing to parquet to [`ObjectStore`]
 ///
+/// Data is written using a [`BufWriter`], which buffers up to `capacity` bytes (10MB by
+/// default) in memory. Smaller files are written with a single [`ObjectStore::put_opts`]
+/// call, larger files are instead streamed using [`ObjectStore::put_multipart_opts`],
+/// uploading parts of `capacity` bytes as row groups are flushed by
+/// [`AsyncArrowWriter`]. As a result, memory usage is bounded by the in progress row
+/// group and the parts in flight, rather than the size of the file.
+///
+/// See [`Self::with_capacity`] and [`Self::with_max_concurrency`] to configure this,
+/// and [`Self::abort`] to clean up a partially written file.
+///
+/// [`AsyncArrowWriter`]: crate::arrow::AsyncArrowWriter
+///
 /// ```
 /// # use arrow_array::{ArrayRef, Int64Array, RecordBatch};
 /// # use object_store::memory::InMemory;
@@ -81,6 +93,20 @@ impl ParquetObjectWriter {
         Self::from_buf_writer(BufWriter::new(store, path))
     }
 
+    /// Create a new [`ParquetObjectWriter`] that writes to the specified path in the given
+    /// store, buffering up to `capacity` bytes in memory before switching to a multipart
+    /// upload with parts of `capacity` bytes.
+    ///
+    /// Note: object stores typically impose a minimum part size, e.g. 5MB for S3
+    pub fn with_capacity(store: Arc<dyn ObjectStore>, path: Path, capacity: usize) -> Self {
+        Self::from_buf_writer(BufWriter::with_capacity(store, path, capacity))
+    }
+
+    /// Override the maximum number of in-flight part uploads (defaults to 8)
+    pub fn with_max_concurrency(self, max_concurrency: usize) -> Self {
+        Self::from_buf_writer(self.w.with_max_concurrency(max_concurrency))
+    }
+
     /// Construct a new ParquetObjectWriter via a existing BufWriter.
     pub fn from_buf_writer(w: BufWriter) -> Self {
         Self { w }
@@ -90,6 +116,19 @@ impl ParquetObjectWriter {
     pub fn into_inner(self) -> BufWriter {
         self.w
     }
+
+    /// Abort writing, cleaning up any in progress multipart upload.
+    ///
+    /// This can be used to avoid leaving incomplete uploads behind if an error occurs
+    /// part way through writing a file, e.g. with [`AsyncArrowWriter::into_inner`].
+    ///
+    /// [`AsyncArrowWriter::into_inner`]: crate::arrow::AsyncArrowWriter::into_inner
+    pub async fn abort(&mut self) -> Result<()> {
+        self.w
+            .abort()
+            .await
+            .map_err(|err| ParquetError::External(Box::new(err)))
+    }
 }
 
 impl AsyncFileWriter for ParquetObjectWriter {
@@ -155,4 +194,55 @@ mod tests {
 
         assert_eq!(to_write, read);
     }
+
+    #[tokio::test]
+    async fn test_async_writer_multipart() {
+        let store = Arc::new(InMemory::new());
+        let path = Path::from("test");
+
+        let col = Arc::new(Int64Array::from_iter_values(0..1024)) as ArrayRef;
+        let to_write = RecordBatch::try_from_iter([("col", col)]).unwrap();
+
+        // Buffer less than a single row group, so each is uploaded as one or more parts
+        let object_store_writer =
+            ParquetObjectWriter::with_capacity(store.clone(), path.clone(), 1024)
+                .with_max_concurrency(2);
+        let mut writer =
+            AsyncArrowWriter::try_new(object_store_writer, to_write.schema(), None).unwrap();
+        for _ in 0..8 {
+            writer.write(&to_write).await.unwrap();
+            writer.flush().await.unwrap();
+        }
+        writer.close().await.unwrap();
+
+        let buffer = store.get(&path).await.unwrap().bytes().await.unwrap();
+        let reader = ParquetRecordBatchReaderBuilder::try_new(buffer).unwrap();
+        assert_eq!(reader.metadata().num_row_groups(), 8);
+        let read = reader.build().unwrap();
+        for batch in read {
+            assert_eq!(batch.unwrap(), to_write);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_writer_abort() {
+        let store = Arc::new(InMemory::new());
+        let path = Path::from("test");
+
+        let col = Arc::new(Int64Array::from_iter_values(0..1024)) as ArrayRef;
+        let to_write = RecordBatch::try_from_iter([("col", col)]).unwrap();
+
+        let object_store_writer =
+            ParquetObjectWriter::with_capacity(store.clone(), path.clone(), 1024);
+        let mut writer =
+            AsyncArrowWriter::try_new(object_store_writer, to_write.schema(), None).unwrap();
+        writer.write(&to_write).await.unwrap();
+        writer.flush().await.unwrap();
+
+        let mut object_store_writer = writer.into_inner();
+        object_store_writer.abort().await.unwrap();
+
+        let err = store.head(&path).await.unwrap_err();
+        assert!(matches!(err, object_store::Error::NotFound { .. }), "{err}");
+    }
 }
@@ -62,6 +62,14 @@ pub fn sign_extend_be<const N: usize>(b: &[u8]) -> [u8; N] {
     result
 }
 
+/// Performs big endian sign extension, discarding any leading bytes beyond `N`
+///
+/// This is only correct if the discarded bytes are themselves a sign extension, as is
+/// the case for a decimal whose precision fits in `N` bytes stored in a wider type
+pub fn sign_extend_or_truncate_be<const N: usize>(b: &[u8]) -> [u8; N] {
+    sign_extend_be(&b[b.len().saturating_sub(N)..])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -154,7 +154,7 @@ impl<K: ArrowNativeType + Ord, V: OffsetSizeTrait> DictionaryBuffer<K, V> {
                         .copied()
                         .fold(true, |a, x| a && x >= min && x < max)
                     {
-                        return Err(general_err!(
+                        return Err(corrupt_err!(
                             "dictionary key beyond bounds of dictionary: 0..{}",
                             values.len()
                         ));
@@ -249,7 +249,11 @@ fn pack_values_impl<K: ArrowDictionaryKeyType, T: ByteArrayType>(
     let mut builder = GenericByteDictionaryBuilder::<K, T>::with_capacity(array.len(), 1024, 1024);
     for x in array {
         match x {
-            Some(x) => builder.append_value(x),
+            Some(x) => {
+                builder
+                    .append(x)
+                    .map_err(|_| general_err!("dictionary too large for index type"))?;
+            }
             None => builder.append_null(),
         }
     }
@@ -267,7 +271,11 @@ fn pack_fixed_values_impl<K: ArrowDictionaryKeyType>(
     );
     for x in array {
         match x {
-            Some(x) => builder.append_value(x),
+            Some(x) => {
+                builder
+                    .append(x)
+                    .map_err(|_| general_err!("dictionary too large for index type"))?;
+            }
             None => builder.append_null(),
         }
     }
@@ -395,4 +403,24 @@ mod tests {
             err
         );
     }
+    #[test]
+    fn test_spilled_key_overflow() {
+        let dict_type = ArrowType::Dictionary(Box::new(ArrowType::Int8), Box::new(ArrowType::Utf8));
+
+        let mut buffer = DictionaryBuffer::<i8, i32>::default();
+        let values = buffer.spill_values().unwrap();
+        for i in 0..128 {
+            values.try_push(i.to_string().as_bytes(), false).unwrap();
+        }
+        std::mem::take(&mut buffer)
+            .into_array(None, &dict_type)
+            .unwrap();
+
+        let values = buffer.spill_values().unwrap();
+        for i in 0..129 {
+            values.try_push(i.to_string().as_bytes(), false).unwrap();
+        }
+        let err = buffer.into_array(None, &dict_type).unwrap_err().to_string();
+        assert!(err.contains("dictionary too large for index type"), "{err}");
+    }
 }
@@ -65,7 +65,7 @@ impl<I: OffsetSizeTrait> OffsetBuffer<I> {
                 // A valid code-point iff it does not start with 0b10xxxxxx
                 // Bit-magic taken from `std::str::is_char_boundary`
                 if (b as i8) < -0x40 {
-                    return Err(ParquetError::General(
+                    return Err(ParquetError::Corrupt(
                         "encountered non UTF-8 data".to_string(),
                     ));
                 }
@@ -96,7 +96,7 @@ impl<I: OffsetSizeTrait> OffsetBuffer<I> {
         for key in keys {
             let index = key.as_usize();
             if index + 1 >= dict_offsets.len() {
-                return Err(general_err!(
+                return Err(corrupt_err!(
                     "dictionary key beyond bounds of dictionary: 0..{}",
                     dict_offsets.len().saturating_sub(1)
                 ));
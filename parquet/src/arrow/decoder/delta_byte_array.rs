@@ -49,7 +49,7 @@ impl DeltaByteArrayDecoder {
         assert_eq!(suffix.get(&mut suffix_lengths)?, num_suffix);
 
         if num_prefix != num_suffix {
-            return Err(general_err!(format!(
+            return Err(corrupt_err!(format!(
                 "inconsistent DELTA_BYTE_ARRAY lengths, prefixes: {num_prefix}, suffixes: {num_suffix}"
             )));
         }
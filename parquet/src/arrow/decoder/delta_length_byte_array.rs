@@ -0,0 +1,294 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ops::Range;
+
+use bytes::Bytes;
+
+use crate::data_type::Int32Type;
+use crate::encodings::decoding::{Decoder, DeltaBitPackDecoder};
+use crate::errors::{ParquetError, Result};
+use crate::util::utf8::check_valid_utf8;
+
+/// Decoder for `Encoding::DELTA_LENGTH_BYTE_ARRAY`
+///
+/// The values of a `DELTA_LENGTH_BYTE_ARRAY` page are stored contiguously after the
+/// encoded lengths, and so rather than yielding each value individually, this yields
+/// the lengths of a batch of values along with the range of the page containing them.
+/// This allows offsets to be computed directly from the lengths, and the values to be
+/// copied in a single operation, or referenced in place by views.
+pub struct DeltaLengthByteArrayDecoder {
+    lengths: Vec<i32>,
+    data: Bytes,
+    length_offset: usize,
+    data_offset: usize,
+    validate_utf8: bool,
+}
+
+impl DeltaLengthByteArrayDecoder {
+    /// Create a new [`DeltaLengthByteArrayDecoder`] with the provided data page
+    ///
+    /// `max_values` is an upper bound on the number of values in the page, such as
+    /// the number of levels, and is used to reject corrupt lengths before allocating
+    ///
+    /// If `validate_utf8` is true, each value is validated to be UTF-8 as it is read
+    pub fn new(data: Bytes, max_values: usize, validate_utf8: bool) -> Result<Self> {
+        let mut len_decoder = DeltaBitPackDecoder::<Int32Type>::new();
+        len_decoder.set_data(data.clone(), 0)?;
+
+        let values = len_decoder.values_left();
+        if values > max_values {
+            return Err(corrupt_err!(
+                "delta length byte array contains {} values, expected at most {}",
+                values,
+                max_values
+            ));
+        }
+
+        let mut lengths = vec![0; values];
+        len_decoder.get(&mut lengths)?;
+
+        let data_offset = len_decoder.get_offset();
+        let mut total_bytes = data_offset;
+        for l in lengths.iter() {
+            let l = usize::try_from(*l)
+                .map_err(|_| corrupt_err!("negative delta length byte array length"))?;
+            total_bytes = total_bytes
+                .checked_add(l)
+                .ok_or_else(|| corrupt_err!("Insufficient delta length byte array bytes"))?;
+        }
+
+        if total_bytes > data.len() {
+            return Err(corrupt_err!("Insufficient delta length byte array bytes"));
+        }
+
+        Ok(Self {
+            lengths,
+            data,
+            length_offset: 0,
+            data_offset,
+            validate_utf8,
+        })
+    }
+
+    /// Returns the number of values remaining
+    pub fn remaining(&self) -> usize {
+        self.lengths.len() - self.length_offset
+    }
+
+    /// Read up to `len` values, returning the number of values read
+    ///
+    /// Calls `f` once with the lengths of the values read, the data page, and the
+    /// range of the data page containing the values, which are stored contiguously
+    pub fn read<F>(&mut self, len: usize, f: F) -> Result<usize>
+    where
+        F: FnOnce(&[i32], &Bytes, Range<usize>) -> Result<()>,
+    {
+        let to_read = len.min(self.remaining());
+
+        let lengths = &self.lengths[self.length_offset..self.length_offset + to_read];
+        let total_bytes: usize = lengths.iter().map(|x| *x as usize).sum();
+        let range = self.data_offset..self.data_offset + total_bytes;
+
+        if self.validate_utf8 {
+            check_valid_utf8_values(lengths, &self.data[range.clone()])?;
+        }
+
+        f(lengths, &self.data, range.clone())?;
+
+        self.data_offset = range.end;
+        self.length_offset += to_read;
+        Ok(to_read)
+    }
+
+    /// Skip up to `to_skip` values, returning the number of values skipped
+    pub fn skip(&mut self, to_skip: usize) -> Result<usize> {
+        let to_skip = to_skip.min(self.remaining());
+
+        let lengths = &self.lengths[self.length_offset..self.length_offset + to_skip];
+        let total_bytes: usize = lengths.iter().map(|x| *x as usize).sum();
+
+        self.data_offset += total_bytes;
+        self.length_offset += to_skip;
+        Ok(to_skip)
+    }
+}
+
+/// Validates that `data`, consisting of contiguous values with `lengths`, is UTF-8
+/// and that each value starts on a char boundary, i.e. each value is itself UTF-8
+fn check_valid_utf8_values(lengths: &[i32], data: &[u8]) -> Result<()> {
+    check_valid_utf8(data)?;
+    if data.is_ascii() {
+        return Ok(());
+    }
+
+    let mut offset = 0;
+    for length in lengths {
+        // A valid code-point iff it does not start with 0b10xxxxxx
+        // Bit-magic taken from `std::str::is_char_boundary`
+        if let Some(&b) = data.get(offset) {
+            if *length != 0 && (b as i8) < -0x40 {
+                return Err(ParquetError::Corrupt(
+                    "encountered non UTF-8 data".to_string(),
+                ));
+            }
+        }
+        offset += *length as usize;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_type::{ByteArray, ByteArrayType};
+    use crate::encodings::encoding::{DeltaLengthByteArrayEncoder, Encoder};
+    use crate::errors::ErrorKind;
+    use rand::prelude::*;
+
+    fn encode<T: Into<ByteArray> + Copy>(values: &[T]) -> Bytes {
+        let values: Vec<ByteArray> = values.iter().map(|v| (*v).into()).collect();
+        let mut encoder = DeltaLengthByteArrayEncoder::<ByteArrayType>::new();
+        encoder.put(&values).unwrap();
+        encoder.flush_buffer().unwrap()
+    }
+
+    /// Reads all values from `decoder` in batches of `batch_size`
+    fn read_all(decoder: &mut DeltaLengthByteArrayDecoder, batch_size: usize) -> Vec<Vec<u8>> {
+        let mut values = vec![];
+        loop {
+            let read = decoder
+                .read(batch_size, |lengths, data, range| {
+                    let mut offset = range.start;
+                    for length in lengths {
+                        let end = offset + *length as usize;
+                        values.push(data[offset..end].to_vec());
+                        offset = end;
+                    }
+                    assert_eq!(offset, range.end);
+                    Ok(())
+                })
+                .unwrap();
+            if read == 0 {
+                return values;
+            }
+        }
+    }
+
+    #[test]
+    fn test_delta_length_byte_array_decoder() {
+        let data = encode(&["hello", "", "world", "a", "bc"]);
+        let mut decoder = DeltaLengthByteArrayDecoder::new(data.clone(), 5, true).unwrap();
+        assert_eq!(decoder.remaining(), 5);
+
+        let values = read_all(&mut decoder, 2);
+        assert_eq!(values, vec![&b"hello"[..], b"", b"world", b"a", b"bc"]);
+        assert_eq!(decoder.remaining(), 0);
+
+        let mut decoder = DeltaLengthByteArrayDecoder::new(data, 5, true).unwrap();
+        assert_eq!(decoder.skip(3).unwrap(), 3);
+        assert_eq!(read_all(&mut decoder, 1), vec![&b"a"[..], b"bc"]);
+        assert_eq!(decoder.skip(3).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_delta_length_byte_array_too_many_values() {
+        let data = encode(&["hello", "world", "a"]);
+        let err = DeltaLengthByteArrayDecoder::new(data, 2, false)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: delta length byte array contains 3 values, expected at most 2"
+        );
+    }
+
+    #[test]
+    fn test_delta_length_byte_array_truncated() {
+        let data = encode(&["hello", "world"]);
+        let data = data.slice(..data.len() - 1);
+        let err = DeltaLengthByteArrayDecoder::new(data, 2, false)
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Corrupt);
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Insufficient delta length byte array bytes"
+        );
+    }
+
+    #[test]
+    fn test_delta_length_byte_array_utf8_boundary() {
+        // "é" is two bytes, splitting it between values yields valid UTF-8 overall,
+        // but neither value is valid UTF-8 on its own
+        let data = encode(&[&b"a\xC3"[..], b"\xA9b"]);
+
+        let mut decoder = DeltaLengthByteArrayDecoder::new(data.clone(), 2, false).unwrap();
+        assert_eq!(read_all(&mut decoder, 2).len(), 2);
+
+        let mut decoder = DeltaLengthByteArrayDecoder::new(data, 2, true).unwrap();
+        let err = decoder.read(2, |_, _, _| Ok(())).unwrap_err();
+        assert_eq!(err.to_string(), "Parquet error: encountered non UTF-8 data");
+    }
+
+    #[test]
+    fn test_delta_length_byte_array_corrupt() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let values: Vec<String> = (0..300)
+            .map(|i| "x".repeat(rng.random_range(0..20)) + &i.to_string())
+            .collect();
+        let values: Vec<&str> = values.iter().map(|v| v.as_str()).collect();
+        let data = encode(&values);
+
+        // Corrupting the page must result in an error or values that are within the
+        // page, and never panic or read out of bounds
+        for _ in 0..1000 {
+            let mut corrupt = data.to_vec();
+            match rng.random_range(0..3) {
+                0 => corrupt.truncate(rng.random_range(0..data.len())),
+                _ => {
+                    for _ in 0..rng.random_range(1..4) {
+                        let idx = rng.random_range(0..corrupt.len());
+                        corrupt[idx] = rng.random();
+                    }
+                }
+            }
+
+            let corrupt = Bytes::from(corrupt);
+            let Ok(mut decoder) = DeltaLengthByteArrayDecoder::new(corrupt.clone(), 300, true)
+            else {
+                continue;
+            };
+            while decoder.remaining() > 0 {
+                let batch_size = rng.random_range(1..100);
+                let result = match rng.random_bool(0.2) {
+                    true => decoder.skip(batch_size),
+                    false => decoder.read(batch_size, |lengths, data, range| {
+                        assert!(range.end <= corrupt.len());
+                        let total: usize = lengths.iter().map(|l| *l as usize).sum();
+                        assert_eq!(range.len(), total);
+                        assert_eq!(data.len(), corrupt.len());
+                        Ok(())
+                    }),
+                };
+                if result.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -18,7 +18,9 @@
 //! Specialized decoders optimised for decoding to arrow format
 
 mod delta_byte_array;
+mod delta_length_byte_array;
 mod dictionary_index;
 
 pub use delta_byte_array::DeltaByteArrayDecoder;
+pub use delta_length_byte_array::DeltaLengthByteArrayDecoder;
 pub use dictionary_index::DictIndexDecoder;
@@ -210,7 +210,8 @@ impl RowGroups for InMemoryRowGroup<'_> {
                     column_chunk_metadata,
                     self.row_count,
                     page_locations,
-                )?;
+                )?
+                .with_row_group(self.row_group_idx);
                 let page_reader = page_reader.add_crypto_context(
                     self.row_group_idx,
                     i,
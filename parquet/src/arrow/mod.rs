@@ -204,7 +204,8 @@ pub use self::arrow_writer::ArrowWriter;
 pub use self::async_reader::ParquetRecordBatchStreamBuilder;
 #[cfg(feature = "async")]
 pub use self::async_writer::AsyncArrowWriter;
-use crate::schema::types::SchemaDescriptor;
+use crate::errors::{ParquetError, Result};
+use crate::schema::types::{SchemaDescriptor, Type};
 use arrow_schema::{FieldRef, Schema};
 
 pub use self::schema::{
@@ -222,10 +223,35 @@ pub const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
 /// The value of this metadata key, if present on [`Field::metadata`], will be used
 /// to populate [`BasicTypeInfo::id`]
 ///
+/// The value must be a 32-bit integer, unique within the schema, or writing will fail.
+/// When converting a Parquet schema to Arrow, this key is populated from the field id
+/// of each field, including nested fields such as list elements and map keys and values.
+///
 /// [`Field::metadata`]: arrow_schema::Field::metadata
 /// [`BasicTypeInfo::id`]: crate::schema::types::BasicTypeInfo::id
 pub const PARQUET_FIELD_ID_META_KEY: &str = "PARQUET:field_id";
 
+/// Metadata key populated on [`Field::metadata`] when converting a Parquet schema to Arrow,
+/// for fields annotated with a [`LogicalType`] not known to this crate
+///
+/// Such fields are read using the Arrow type of their converted or physical type, with the
+/// value of this key set to the Thrift field id of the unknown [`LogicalType`] union member.
+/// This key is ignored when writing.
+///
+/// [`Field::metadata`]: arrow_schema::Field::metadata
+/// [`LogicalType`]: crate::basic::LogicalType
+pub const PARQUET_UNKNOWN_LOGICAL_TYPE_META_KEY: &str = "PARQUET:unknown_logical_type";
+
+/// Metadata key recording the Parquet name of a field renamed because it has the same
+/// name as a sibling field
+///
+/// When [`WriterProperties::rename_duplicate_columns`] is enabled, this key is set on the
+/// renamed fields of the Arrow schema embedded in the file, allowing the Arrow reader to
+/// restore the original names. This key is ignored when writing, and removed when reading.
+///
+/// [`WriterProperties::rename_duplicate_columns`]: crate::file::properties::WriterProperties::rename_duplicate_columns
+pub const PARQUET_COLUMN_NAME_META_KEY: &str = "PARQUET:column_name";
+
 /// A [`ProjectionMask`] identifies a set of columns within a potentially nested schema to project
 ///
 /// In particular, a [`ProjectionMask`] can be constructed from a list of leaf column indices
@@ -353,6 +379,14 @@ impl ProjectionMask {
     ///
     /// Also, this will not produce the desired results if a column contains a '.' in its name.
     /// Use [`Self::leaves`] or [`Self::roots`] in that case.
+    ///
+    /// Names matching several columns, as can occur if sibling columns have the same name,
+    /// select all of them, and names matching no columns are ignored. Use
+    /// [`Self::try_columns`] to instead return an error in these cases.
+    #[deprecated(
+        since = "58.1.0",
+        note = "Use `try_columns` instead, which returns an error for ambiguous or unknown names"
+    )]
     pub fn columns<'a>(
         schema: &SchemaDescriptor,
         names: impl IntoIterator<Item = &'a str>,
@@ -376,6 +410,57 @@ impl ProjectionMask {
         Self { mask: Some(mask) }
     }
 
+    /// Create a [`ProjectionMask`] which selects only the named columns, returning an error
+    /// if a name is ambiguous or not found
+    ///
+    /// This is like [`Self::columns`], except that it returns an error if any component of
+    /// a name matches more than one sibling column, as can occur in files written by
+    /// other implementations, or matches no columns. For example, given the schema
+    /// ```ignore
+    /// message schema {
+    ///   REQUIRED group a {
+    ///     OPTIONAL INT32 b;    // leaf index 0
+    ///     OPTIONAL INT64 b;    // leaf index 1
+    ///   }
+    ///   REQUIRED INT32 c;      // leaf index 2
+    /// }
+    /// ```
+    /// `["a", "c"]` would return leaf columns 0, 1 and 2, whereas `["a.b"]`, `["a.d"]` and
+    /// `["d"]` would return an error.
+    pub fn try_columns<'a>(
+        schema: &SchemaDescriptor,
+        names: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Self> {
+        let mut mask = vec![false; schema.num_columns()];
+        for name in names {
+            let mut tp = schema.root_schema();
+            // The index of the first leaf column below `tp`
+            let mut leaf_idx = 0;
+            for part in name.split('.') {
+                let fields = match tp.is_group() {
+                    true => tp.get_fields(),
+                    false => &[],
+                };
+                let mut matches = fields.iter().enumerate().filter(|(_, f)| f.name() == part);
+                let Some((idx, field)) = matches.next() else {
+                    return Err(general_err!("Column \"{}\" not found in schema", name));
+                };
+                if matches.next().is_some() {
+                    return Err(general_err!(
+                        "Column \"{}\" is ambiguous, \"{}\" matches more than one field",
+                        name,
+                        part
+                    ));
+                }
+                leaf_idx += fields[..idx].iter().map(|f| num_leaves(f)).sum::<usize>();
+                tp = field;
+            }
+            mask[leaf_idx..leaf_idx + num_leaves(tp)].fill(true);
+        }
+
+        Ok(Self { mask: Some(mask) })
+    }
+
     /// Returns true if the leaf column `leaf_idx` is included by the mask
     pub fn leaf_included(&self, leaf_idx: usize) -> bool {
         self.mask.as_ref().map(|m| m[leaf_idx]).unwrap_or(true)
@@ -466,6 +551,14 @@ impl ProjectionMask {
     }
 }
 
+/// Returns the number of leaf columns in `tp`
+fn num_leaves(tp: &Type) -> usize {
+    match tp.is_group() {
+        true => tp.get_fields().iter().map(|f| num_leaves(f)).sum(),
+        false => 1,
+    }
+}
+
 /// Lookups up the parquet column by name
 ///
 /// Returns the parquet column index and the corresponding arrow field
@@ -495,7 +588,8 @@ pub fn parquet_column<'a>(
 mod test {
     use crate::arrow::ArrowWriter;
     use crate::file::metadata::{
-        ParquetMetaData, ParquetMetaDataOptions, ParquetMetaDataReader, ParquetMetaDataWriter,
+        PageIndexPolicy, ParquetMetaData, ParquetMetaDataOptions, ParquetMetaDataReader,
+        ParquetMetaDataWriter,
     };
     use crate::file::properties::{EnabledStatistics, WriterProperties};
     use crate::schema::parser::parse_message_type;
@@ -595,6 +689,108 @@ mod test {
         assert_eq!(original_metadata, roundtrip_metadata);
     }
 
+    #[test]
+    fn test_metadata_to_from_bytes() {
+        let parquet_bytes = create_parquet_file();
+        let original_metadata = ParquetMetaDataReader::new()
+            .with_page_index_policy(PageIndexPolicy::Required)
+            .parse_and_finish(&parquet_bytes)
+            .unwrap();
+        assert!(original_metadata.column_index().is_some());
+        assert!(original_metadata.offset_index().is_some());
+
+        let metadata_bytes = original_metadata.to_bytes().unwrap();
+        let roundtrip_metadata = ParquetMetaData::from_bytes(&metadata_bytes).unwrap();
+
+        // The page indexes are loaded from the serialized bytes
+        assert_eq!(
+            original_metadata.column_index(),
+            roundtrip_metadata.column_index()
+        );
+        assert_eq!(
+            original_metadata.offset_index(),
+            roundtrip_metadata.offset_index()
+        );
+
+        // Encoding statistics decoded as a mask are not serialized
+        let column = roundtrip_metadata.row_group(0).column(0);
+        assert!(column.page_encoding_stats_mask().is_none());
+        assert_eq!(
+            clear_encoding_stats(original_metadata.clone()),
+            roundtrip_metadata
+        );
+
+        // The page index locations still refer to the original file
+        for (original, roundtrip) in original_metadata
+            .row_groups()
+            .iter()
+            .zip(roundtrip_metadata.row_groups())
+        {
+            for (original, roundtrip) in original.columns().iter().zip(roundtrip.columns()) {
+                assert!(roundtrip.column_index_range().is_some());
+                assert_eq!(
+                    original.column_index_range(),
+                    roundtrip.column_index_range()
+                );
+                assert_eq!(
+                    original.offset_index_range(),
+                    roundtrip.offset_index_range()
+                );
+            }
+        }
+        let without_indexes = roundtrip_metadata
+            .into_builder()
+            .set_column_index(None)
+            .set_offset_index(None)
+            .build();
+        let reloaded_metadata = ParquetMetaDataReader::new_with_metadata(without_indexes)
+            .with_page_index_policy(PageIndexPolicy::Required)
+            .parse_and_finish(&parquet_bytes)
+            .unwrap();
+        assert_eq!(
+            original_metadata.column_index(),
+            reloaded_metadata.column_index()
+        );
+        assert_eq!(
+            original_metadata.offset_index(),
+            reloaded_metadata.offset_index()
+        );
+
+        // Metadata without page indexes
+        let without_indexes = original_metadata
+            .into_builder()
+            .set_column_index(None)
+            .set_offset_index(None)
+            .build();
+        let roundtrip_metadata =
+            ParquetMetaData::from_bytes(&without_indexes.to_bytes().unwrap()).unwrap();
+        assert_eq!(clear_encoding_stats(without_indexes), roundtrip_metadata);
+
+        let truncated = metadata_bytes.slice(..metadata_bytes.len() - 1);
+        let mut trailing = metadata_bytes.to_vec();
+        trailing.push(0);
+        for bytes in [
+            parquet_bytes.slice(..4),
+            metadata_to_bytes(&reloaded_metadata),
+            truncated,
+            trailing.into(),
+        ] {
+            let err = ParquetMetaData::from_bytes(&bytes).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Parquet error: Invalid serialized ParquetMetaData"
+            );
+        }
+
+        let mut unsupported = metadata_bytes.to_vec();
+        unsupported[4] = 2;
+        let err = ParquetMetaData::from_bytes(&unsupported.into()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "NYI: Serialized ParquetMetaData version 2, expected 1"
+        );
+    }
+
     /// Sets the page index offset locations in the metadata to `None`
     ///
     /// This is because the offsets are used to find the relative location of the index
@@ -619,6 +815,20 @@ mod test {
         metadata_builder.build()
     }
 
+    /// Clears the page encoding statistics of each column chunk in the metadata
+    fn clear_encoding_stats(metadata: ParquetMetaData) -> ParquetMetaData {
+        let mut metadata_builder = metadata.into_builder();
+        for rg in metadata_builder.take_row_groups() {
+            let mut rg_builder = rg.into_builder();
+            for col in rg_builder.take_columns() {
+                let col = col.into_builder().clear_page_encoding_stats().build();
+                rg_builder = rg_builder.add_column_metadata(col.unwrap());
+            }
+            metadata_builder = metadata_builder.add_row_group(rg_builder.build().unwrap());
+        }
+        metadata_builder.build()
+    }
+
     /// Write a parquet filed into an in memory buffer
     fn create_parquet_file() -> Bytes {
         let mut buf = vec![];
@@ -648,6 +858,7 @@ mod test {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_mask_from_column_names() {
         let schema = parse_schema(
             "
@@ -764,6 +975,69 @@ mod test {
         assert_eq!(mask.mask.unwrap(), [true, false]);
     }
 
+    #[test]
+    fn test_projection_mask_try_columns() {
+        let schema = parse_schema(
+            "
+            message test_schema {
+                OPTIONAL group a (MAP) {
+                    REPEATED group key_value {
+                        REQUIRED BYTE_ARRAY key (UTF8);
+                        OPTIONAL INT32 value;
+                    }
+                }
+                REQUIRED group b {
+                    OPTIONAL INT32 c;
+                    OPTIONAL INT64 c;
+                    OPTIONAL INT32 d;
+                }
+                REQUIRED INT32 e;
+                REQUIRED INT32 e;
+            }
+            ",
+        );
+
+        let mask = ProjectionMask::try_columns(&schema, []).unwrap();
+        assert_eq!(mask.mask.unwrap(), [false; 7]);
+
+        let mask = ProjectionMask::try_columns(&schema, ["a", "b.d"]).unwrap();
+        assert_eq!(
+            mask.mask.unwrap(),
+            [true, true, false, false, true, false, false]
+        );
+
+        let mask = ProjectionMask::try_columns(&schema, ["a.key_value.value", "b"]).unwrap();
+        assert_eq!(
+            mask.mask.unwrap(),
+            [false, true, true, true, true, false, false]
+        );
+
+        let err = ProjectionMask::try_columns(&schema, ["a", "b.c"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Column \"b.c\" is ambiguous, \"c\" matches more than one field"
+        );
+
+        let err = ProjectionMask::try_columns(&schema, ["e"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Column \"e\" is ambiguous, \"e\" matches more than one field"
+        );
+
+        let err = ProjectionMask::try_columns(&schema, ["b.f"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Column \"b.f\" not found in schema"
+        );
+
+        // Cannot select below a leaf column
+        let err = ProjectionMask::try_columns(&schema, ["b.d.f"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Column \"b.d.f\" not found in schema"
+        );
+    }
+
     #[test]
     fn test_projection_mask_union() {
         let mut mask1 = ProjectionMask {
@@ -24,6 +24,7 @@ mod remaining;
 use crate::DecodeResult;
 use crate::arrow::arrow_reader::{
     ArrowReaderBuilder, ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReader,
+    check_logical_types,
 };
 use crate::errors::ParquetError;
 use crate::file::metadata::ParquetMetaData;
@@ -176,8 +177,15 @@ impl ParquetPushDecoderBuilder {
             metrics,
             row_selection_policy,
             max_predicate_cache_size,
+            strict_logical_types,
+            // Only set by ParquetRecordBatchStreamBuilder
+            output_schema: _,
         } = self;
 
+        if strict_logical_types {
+            check_logical_types(&parquet_metadata, &projection, filter.as_ref())?;
+        }
+
         // If no row groups were specified, read all of them
         let row_groups =
             row_groups.unwrap_or_else(|| (0..parquet_metadata.num_row_groups()).collect());
@@ -716,7 +724,7 @@ mod test {
 
         let mut decoder = builder
             .with_projection(
-                ProjectionMask::columns(&schema_descr, ["a", "b"]), // read "a", "b"
+                ProjectionMask::try_columns(&schema_descr, ["a", "b"]).unwrap(), // read "a", "b"
             )
             .build()
             .unwrap();
@@ -754,7 +762,7 @@ mod test {
         // a > 250
         let row_filter_a = ArrowPredicateFn::new(
             // claim to use both a and b so we get two ranges requests for the filter pages
-            ProjectionMask::columns(&schema_descr, ["a", "b"]),
+            ProjectionMask::try_columns(&schema_descr, ["a", "b"]).unwrap(),
             |batch: RecordBatch| {
                 let scalar_250 = Int64Array::new_scalar(250);
                 let column = batch.column(0).as_primitive::<Int64Type>();
@@ -765,7 +773,7 @@ mod test {
         let mut decoder = builder
             .with_projection(
                 // read only column "a" to test that filter pages are reused
-                ProjectionMask::columns(&schema_descr, ["a"]), // read "a"
+                ProjectionMask::try_columns(&schema_descr, ["a"]).unwrap(), // read "a"
             )
             .with_row_filter(RowFilter::new(vec![Box::new(row_filter_a)]))
             .build()
@@ -811,7 +819,7 @@ mod test {
 
         // a > 250
         let row_filter_a = ArrowPredicateFn::new(
-            ProjectionMask::columns(&schema_descr, ["a"]),
+            ProjectionMask::try_columns(&schema_descr, ["a"]).unwrap(),
             |batch: RecordBatch| {
                 let scalar_250 = Int64Array::new_scalar(250);
                 let column = batch.column(0).as_primitive::<Int64Type>();
@@ -822,7 +830,7 @@ mod test {
         let mut decoder = builder
             .with_projection(
                 // read only column "a" to test that filter pages are reused
-                ProjectionMask::columns(&schema_descr, ["b"]), // read "b"
+                ProjectionMask::try_columns(&schema_descr, ["b"]).unwrap(), // read "b"
             )
             .with_row_filter(RowFilter::new(vec![Box::new(row_filter_a)]))
             .with_row_selection(RowSelection::from(vec![
@@ -865,7 +873,7 @@ mod test {
 
         // a > 175
         let row_filter_a = ArrowPredicateFn::new(
-            ProjectionMask::columns(&schema_descr, ["a"]),
+            ProjectionMask::try_columns(&schema_descr, ["a"]).unwrap(),
             |batch: RecordBatch| {
                 let scalar_175 = Int64Array::new_scalar(175);
                 let column = batch.column(0).as_primitive::<Int64Type>();
@@ -875,7 +883,7 @@ mod test {
 
         // b < 625
         let row_filter_b = ArrowPredicateFn::new(
-            ProjectionMask::columns(&schema_descr, ["b"]),
+            ProjectionMask::try_columns(&schema_descr, ["b"]).unwrap(),
             |batch: RecordBatch| {
                 let scalar_625 = Int64Array::new_scalar(625);
                 let column = batch.column(0).as_primitive::<Int64Type>();
@@ -885,7 +893,7 @@ mod test {
 
         let mut decoder = builder
             .with_projection(
-                ProjectionMask::columns(&schema_descr, ["c"]), // read "c"
+                ProjectionMask::try_columns(&schema_descr, ["c"]).unwrap(), // read "c"
             )
             .with_row_filter(RowFilter::new(vec![
                 Box::new(row_filter_a),
@@ -945,7 +953,7 @@ mod test {
 
         // a > 250
         let row_filter_a = ArrowPredicateFn::new(
-            ProjectionMask::columns(&schema_descr, ["a"]),
+            ProjectionMask::try_columns(&schema_descr, ["a"]).unwrap(),
             |batch: RecordBatch| {
                 let scalar_250 = Int64Array::new_scalar(250);
                 let column = batch.column(0).as_primitive::<Int64Type>();
@@ -956,7 +964,7 @@ mod test {
         let mut decoder = builder
             .with_projection(
                 // read only column "a" to test that filter pages are reused
-                ProjectionMask::columns(&schema_descr, ["a"]), // read "a"
+                ProjectionMask::try_columns(&schema_descr, ["a"]).unwrap(), // read "a"
             )
             .with_row_filter(RowFilter::new(vec![Box::new(row_filter_a)]))
             .build()
@@ -991,7 +999,7 @@ mod test {
         // only read column "c", but with empty filters
         let mut decoder = builder
             .with_projection(
-                ProjectionMask::columns(&schema_descr, ["c"]), // read "c"
+                ProjectionMask::try_columns(&schema_descr, ["c"]).unwrap(), // read "c"
             )
             .with_row_filter(RowFilter::new(vec![
                 // empty filters should be ignored
@@ -275,7 +275,7 @@ impl PackedDecoder {
                 return Ok(v);
             }
         }
-        Err(general_err!("too many bytes for VLQ"))
+        Err(corrupt_err!("too many bytes for VLQ"))
     }
 }
 
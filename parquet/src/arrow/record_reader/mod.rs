@@ -30,7 +30,7 @@ use crate::column::{
     },
 };
 use crate::data_type::DataType;
-use crate::errors::{ParquetError, Result};
+use crate::errors::{ErrorContext, ParquetError, Result};
 use crate::schema::types::ColumnDescPtr;
 
 pub(crate) mod buffer;
@@ -54,6 +54,8 @@ pub struct GenericRecordReader<V, CV> {
     def_levels: Option<DefinitionLevelBuffer>,
     rep_levels: Option<Vec<i16>>,
     column_reader: Option<ColumnReader<CV>>,
+    /// The location of the current column chunk, attached to any errors
+    error_context: ErrorContext,
     /// Number of buffered levels / null-padded values
     num_values: usize,
     /// Number of buffered records
@@ -77,6 +79,7 @@ where
             def_levels,
             rep_levels,
             column_reader: None,
+            error_context: ErrorContext::new(),
             column_desc: desc,
             num_values: 0,
             num_records: 0,
@@ -95,6 +98,7 @@ where
         let rep_level_decoder = (descr.max_rep_level() != 0)
             .then(|| RepetitionLevelDecoderImpl::new(descr.max_rep_level()));
 
+        self.error_context = page_reader.error_context();
         self.column_reader = Some(GenericColumnReader::new_with_decoders(
             self.column_desc.clone(),
             page_reader,
@@ -111,6 +115,11 @@ where
     ///
     /// Number of actual records read.
     pub fn read_records(&mut self, num_records: usize) -> Result<usize> {
+        self.read_records_impl(num_records)
+            .map_err(|e| e.with_context(self.error_context.clone()))
+    }
+
+    fn read_records_impl(&mut self, num_records: usize) -> Result<usize> {
         if self.column_reader.is_none() {
             return Ok(0);
         }
@@ -134,7 +143,9 @@ where
     /// Number of records skipped
     pub fn skip_records(&mut self, num_records: usize) -> Result<usize> {
         match self.column_reader.as_mut() {
-            Some(reader) => reader.skip_records(num_records),
+            Some(reader) => reader
+                .skip_records(num_records)
+                .map_err(|e| e.with_context(self.error_context.clone())),
             None => Ok(0),
         }
     }
@@ -218,7 +229,7 @@ where
 
         if values_read < levels_read {
             let def_levels = self.def_levels.as_ref().ok_or_else(|| {
-                general_err!("Definition levels should exist when data is less than levels!")
+                corrupt_err!("Definition levels should exist when data is less than levels!")
             })?;
 
             self.values.pad_nulls(
@@ -21,8 +21,11 @@ use std::sync::Arc;
 use crate::arrow::schema::extension::try_add_extension_type;
 use crate::arrow::schema::primitive::convert_primitive;
 use crate::arrow::schema::virtual_type::{RowGroupIndex, RowNumber};
-use crate::arrow::{PARQUET_FIELD_ID_META_KEY, ProjectionMask};
-use crate::basic::{ConvertedType, Repetition};
+use crate::arrow::{
+    PARQUET_COLUMN_NAME_META_KEY, PARQUET_FIELD_ID_META_KEY, PARQUET_UNKNOWN_LOGICAL_TYPE_META_KEY,
+    ProjectionMask,
+};
+use crate::basic::{ConvertedType, LogicalType, Repetition};
 use crate::errors::ParquetError;
 use crate::errors::Result;
 use crate::schema::types::{SchemaDescriptor, Type, TypePtr};
@@ -81,6 +84,39 @@ impl ParquetField {
             ParquetFieldType::Virtual(_) => None,
         }
     }
+
+    /// Appends to `leaves` the indices of the leaf columns below the nested field
+    /// identified by `path`
+    ///
+    /// Each element of `path` is the name of a struct or map field, with lists
+    /// traversed transparently. An empty `path` identifies `self`
+    pub(crate) fn nested_leaves(&self, path: &[&str], leaves: &mut Vec<usize>) {
+        match &self.field_type {
+            ParquetFieldType::Primitive { col_idx, .. } if path.is_empty() => leaves.push(*col_idx),
+            ParquetFieldType::Primitive { .. } | ParquetFieldType::Virtual(_) => {}
+            ParquetFieldType::Group { children } => {
+                let Some((name, rest)) = path.split_first() else {
+                    children.iter().for_each(|c| c.nested_leaves(path, leaves));
+                    return;
+                };
+                let fields = match &self.arrow_type {
+                    DataType::Struct(fields) => fields,
+                    DataType::Map(entries, _) => match entries.data_type() {
+                        DataType::Struct(fields) => fields,
+                        _ => return,
+                    },
+                    // Lists have a single child, the list element
+                    _ => {
+                        children.iter().for_each(|c| c.nested_leaves(path, leaves));
+                        return;
+                    }
+                };
+                if let Some(idx) = fields.iter().position(|f| f.name() == name) {
+                    children[idx].nested_leaves(rest, leaves);
+                }
+            }
+        }
+    }
 }
 
 /// Types of virtual columns that can be computed at read time
@@ -216,7 +252,11 @@ impl Visitor {
             let data_type = match arrow_fields {
                 Some(fields) => {
                     let field = &fields[idx];
-                    if field.name() != parquet_field.name() {
+                    // Fields renamed when writing record their Parquet name in metadata
+                    let parquet_name = field.metadata().get(PARQUET_COLUMN_NAME_META_KEY);
+                    if field.name() != parquet_field.name()
+                        && parquet_name.map(String::as_str) != Some(parquet_field.name())
+                    {
                         return Err(arrow_err!(
                             "incompatible arrow schema, expected field named {} got {}",
                             parquet_field.name(),
@@ -380,7 +420,7 @@ impl Visitor {
                 };
 
                 let map_field = Field::new_struct(
-                    map_key_value.name(),
+                    arrow_map.map_or(map_key_value.name(), |f| f.name()),
                     [key_field, value_field],
                     false, // The inner map field is always non-nullable (#1697)
                 )
@@ -611,14 +651,17 @@ pub(super) fn convert_virtual_field(
 
 /// Computes the Arrow [`Field`] for a child column
 ///
-/// The resulting Arrow [`Field`] will have the type dictated by the Parquet `field`, a name
-/// dictated by the `parquet_type`, and any metadata from `arrow_hint`
+/// The resulting Arrow [`Field`] will have the type dictated by the Parquet `field`, and the
+/// name and any metadata from `arrow_hint`, falling back to the name of the `parquet_type`
+///
+/// The hint's name takes precedence as writers may rename list elements and map children
+/// to the names prescribed by the Parquet specification, e.g. `item` to `element`
 fn convert_field(
     parquet_type: &Type,
     field: &mut ParquetField,
     arrow_hint: Option<&Field>,
 ) -> Result<Field, ParquetError> {
-    let name = parquet_type.name();
+    let name = arrow_hint.map_or(parquet_type.name(), |hint| hint.name());
     let data_type = field.arrow_type.clone();
     let nullable = field.nullable;
 
@@ -635,17 +678,27 @@ fn convert_field(
                 _ => Field::new(name, data_type, nullable),
             };
 
-            Ok(field.with_metadata(hint.metadata().clone()))
+            let mut metadata = hint.metadata().clone();
+            metadata.remove(PARQUET_COLUMN_NAME_META_KEY);
+            Ok(field.with_metadata(metadata))
         }
         None => {
             let mut ret = Field::new(name, data_type, nullable);
             let basic_info = parquet_type.get_basic_info();
+            let mut meta = HashMap::new();
             if basic_info.has_id() {
-                let mut meta = HashMap::with_capacity(1);
                 meta.insert(
                     PARQUET_FIELD_ID_META_KEY.to_string(),
                     basic_info.id().to_string(),
                 );
+            }
+            if let Some(LogicalType::_Unknown { field_id }) = basic_info.logical_type_ref() {
+                meta.insert(
+                    PARQUET_UNKNOWN_LOGICAL_TYPE_META_KEY.to_string(),
+                    field_id.to_string(),
+                );
+            }
+            if !meta.is_empty() {
                 ret.set_metadata(meta);
             }
             try_add_extension_type(ret, parquet_type)
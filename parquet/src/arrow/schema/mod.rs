@@ -19,7 +19,7 @@
 
 use base64::Engine;
 use base64::prelude::BASE64_STANDARD;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use arrow_ipc::writer;
@@ -37,7 +37,7 @@ mod extension;
 mod primitive;
 pub mod virtual_type;
 
-use super::PARQUET_FIELD_ID_META_KEY;
+use super::{PARQUET_COLUMN_NAME_META_KEY, PARQUET_FIELD_ID_META_KEY};
 use crate::arrow::ProjectionMask;
 use crate::arrow::schema::extension::{
     has_extension_type, logical_type_for_binary, logical_type_for_binary_view,
@@ -397,6 +397,14 @@ pub struct ArrowSchemaConverter<'a> {
     ///
     /// See docs on [Self::with_coerce_types]`
     coerce_types: bool,
+    /// Should list and map children use the names prescribed by the Parquet specification?
+    ///
+    /// See docs on [Self::with_canonicalize_nested_names]
+    canonicalize_nested_names: bool,
+    /// Should fields with the same name as a sibling be renamed?
+    ///
+    /// See docs on [Self::with_rename_duplicate_columns]
+    rename_duplicate_columns: bool,
 }
 
 impl Default for ArrowSchemaConverter<'_> {
@@ -411,6 +419,8 @@ impl<'a> ArrowSchemaConverter<'a> {
         Self {
             schema_root: "arrow_schema",
             coerce_types: false,
+            canonicalize_nested_names: false,
+            rename_duplicate_columns: false,
         }
     }
 
@@ -449,6 +459,48 @@ impl<'a> ArrowSchemaConverter<'a> {
         self
     }
 
+    /// Should the children of [`List`] and [`Map`] types be given the names prescribed
+    /// by the Parquet specification (default `false`).
+    ///
+    /// Setting this option to `true` names list elements `element`, and the
+    /// repeated group of a map `key_value` with children `key` and `value`,
+    /// regardless of the names in the Arrow schema. Some readers, such as older
+    /// versions of Hive, misread files using other names. Unlike
+    /// [`Self::with_coerce_types`], the Arrow types are otherwise unchanged.
+    ///
+    /// The Arrow names are preserved in the embedded Arrow schema written by
+    /// [`ArrowWriter`], which the Arrow reader uses in preference to the Parquet
+    /// names, so files written by [`ArrowWriter`] still round-trip losslessly.
+    ///
+    /// [`List`]: https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#lists
+    /// [`Map`]: https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#maps
+    /// [`ArrowWriter`]: crate::arrow::arrow_writer::ArrowWriter
+    pub fn with_canonicalize_nested_names(mut self, canonicalize_nested_names: bool) -> Self {
+        self.canonicalize_nested_names = canonicalize_nested_names;
+        self
+    }
+
+    /// Should fields with the same name as a sibling field be renamed (default `false`).
+    ///
+    /// Arrow permits several fields of a schema or struct to share a name, but the
+    /// columns of the resulting Parquet file could then not be distinguished by name. By
+    /// default, [`Self::convert`] returns an error for such schemas.
+    ///
+    /// Setting this option to `true` instead renames the second and subsequent fields
+    /// with a given name by appending a suffix `_1`, `_2`, etc, skipping any names
+    /// already in use. For example fields `[value, value, value_1]` are named
+    /// `[value, value_2, value_1]`.
+    ///
+    /// [`ArrowWriter`] records the new names in the [`PARQUET_COLUMN_NAME_META_KEY`]
+    /// metadata of the embedded Arrow schema, which the Arrow reader uses to restore the
+    /// original names.
+    ///
+    /// [`ArrowWriter`]: crate::arrow::arrow_writer::ArrowWriter
+    pub fn with_rename_duplicate_columns(mut self, rename_duplicate_columns: bool) -> Self {
+        self.rename_duplicate_columns = rename_duplicate_columns;
+        self
+    }
+
     /// Set the root schema element name (defaults to `"arrow_schema"`).
     pub fn schema_root(mut self, schema_root: &'a str) -> Self {
         self.schema_root = schema_root;
@@ -457,13 +509,29 @@ impl<'a> ArrowSchemaConverter<'a> {
 
     /// Convert the specified Arrow [`Schema`] to the desired Parquet [`SchemaDescriptor`]
     ///
+    /// Field ids are taken from the [`PARQUET_FIELD_ID_META_KEY`] metadata of each
+    /// field, including nested fields. Returns an error if a field id is not a valid
+    /// 32-bit integer, or if the same field id is used more than once.
+    ///
+    /// Returns an error if sibling fields have the same name, unless
+    /// [`Self::with_rename_duplicate_columns`] is enabled.
+    ///
     /// See example in [`ArrowSchemaConverter`]
     pub fn convert(&self, schema: &Schema) -> Result<SchemaDescriptor> {
-        let fields = schema
-            .fields()
+        let (renamed, _) = rename_duplicate_fields(schema.fields(), self.rename_duplicate_columns)?;
+        let fields = renamed
             .iter()
-            .map(|field| arrow_to_parquet_type(field, self.coerce_types).map(Arc::new))
-            .collect::<Result<_>>()?;
+            .map(|field| {
+                arrow_to_parquet_type(field, self.coerce_types, self.canonicalize_nested_names)
+                    .map(Arc::new)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut seen = HashSet::new();
+        for field in &fields {
+            check_unique_field_ids(field, &mut seen)?;
+        }
+
         let group = Type::group_type_builder(self.schema_root)
             .with_fields(fields)
             .build()?;
@@ -471,6 +539,123 @@ impl<'a> ArrowSchemaConverter<'a> {
     }
 }
 
+/// Makes the names of sibling fields within `fields`, including nested fields, unique
+///
+/// Returns an error if there are duplicate names and `rename` is false, otherwise returns
+/// `fields` with duplicates renamed as described in
+/// [`ArrowSchemaConverter::with_rename_duplicate_columns`], along with `fields` annotated
+/// with the new names in the [`PARQUET_COLUMN_NAME_META_KEY`] metadata of renamed fields
+pub(crate) fn rename_duplicate_fields(fields: &Fields, rename: bool) -> Result<(Fields, Fields)> {
+    rename_duplicate_children(fields, None, rename)
+}
+
+fn rename_duplicate_children(
+    fields: &Fields,
+    path: Option<&str>,
+    rename: bool,
+) -> Result<(Fields, Fields)> {
+    let mut names: HashSet<String> = fields.iter().map(|f| f.name().clone()).collect();
+    let mut seen = HashSet::with_capacity(fields.len());
+    let mut renamed = Vec::with_capacity(fields.len());
+    let mut annotated = Vec::with_capacity(fields.len());
+    for field in fields {
+        let name = field.name();
+        let path = match path {
+            Some(path) => format!("{path}.{name}"),
+            None => name.clone(),
+        };
+        let duplicate = !seen.insert(name.as_str());
+        if duplicate && !rename {
+            return Err(arrow_err!(
+                "Duplicate field name \"{}\" in Arrow schema, Parquet columns must have unique names. \
+                 Duplicate fields can be renamed with WriterProperties::set_rename_duplicate_columns",
+                path
+            ));
+        }
+
+        let (field_renamed, field_annotated) = rename_duplicate_nested(field, &path, rename)?;
+        if !duplicate {
+            renamed.push(field_renamed);
+            annotated.push(field_annotated);
+            continue;
+        }
+
+        let new_name = (1..)
+            .map(|i| format!("{name}_{i}"))
+            .find(|n| !names.contains(n))
+            .unwrap();
+        names.insert(new_name.clone());
+
+        let mut metadata = field_annotated.metadata().clone();
+        metadata.insert(PARQUET_COLUMN_NAME_META_KEY.to_string(), new_name.clone());
+        annotated.push(Arc::new(field_annotated.as_ref().clone().with_metadata(metadata)));
+        renamed.push(Arc::new(field_renamed.as_ref().clone().with_name(new_name)));
+    }
+    Ok((renamed.into(), annotated.into()))
+}
+
+/// Applies [`rename_duplicate_children`] to any fields nested within `field`
+fn rename_duplicate_nested(
+    field: &FieldRef,
+    path: &str,
+    rename: bool,
+) -> Result<(FieldRef, FieldRef)> {
+    let child = |child: &FieldRef| {
+        rename_duplicate_nested(child, &format!("{path}.{}", child.name()), rename)
+    };
+    let (renamed, annotated) = match field.data_type() {
+        DataType::Struct(fields) => {
+            let (r, a) = rename_duplicate_children(fields, Some(path), rename)?;
+            (DataType::Struct(r), DataType::Struct(a))
+        }
+        DataType::List(f) => {
+            let (r, a) = child(f)?;
+            (DataType::List(r), DataType::List(a))
+        }
+        DataType::LargeList(f) => {
+            let (r, a) = child(f)?;
+            (DataType::LargeList(r), DataType::LargeList(a))
+        }
+        DataType::ListView(f) => {
+            let (r, a) = child(f)?;
+            (DataType::ListView(r), DataType::ListView(a))
+        }
+        DataType::LargeListView(f) => {
+            let (r, a) = child(f)?;
+            (DataType::LargeListView(r), DataType::LargeListView(a))
+        }
+        DataType::FixedSizeList(f, size) => {
+            let (r, a) = child(f)?;
+            (DataType::FixedSizeList(r, *size), DataType::FixedSizeList(a, *size))
+        }
+        DataType::Map(f, sorted) => {
+            let (r, a) = child(f)?;
+            (DataType::Map(r, *sorted), DataType::Map(a, *sorted))
+        }
+        _ => return Ok((Arc::clone(field), Arc::clone(field))),
+    };
+    let with_type = |data_type| Arc::new(field.as_ref().clone().with_data_type(data_type));
+    Ok((with_type(renamed), with_type(annotated)))
+}
+
+/// Returns an error if `tp` or any of its children has a field id already in `seen`
+fn check_unique_field_ids(tp: &Type, seen: &mut HashSet<i32>) -> Result<()> {
+    let info = tp.get_basic_info();
+    if info.has_id() && !seen.insert(info.id()) {
+        return Err(arrow_err!(
+            "Duplicate field id {} found for field \"{}\"",
+            info.id(),
+            info.name()
+        ));
+    }
+    if tp.is_group() {
+        for child in tp.get_fields() {
+            check_unique_field_ids(child, seen)?;
+        }
+    }
+    Ok(())
+}
+
 fn parse_key_value_metadata(
     key_value_metadata: Option<&Vec<KeyValue>>,
 ) -> Option<HashMap<String, String>> {
@@ -531,7 +716,10 @@ pub fn decimal_length_from_precision(precision: u8) -> usize {
 }
 
 /// Convert an arrow field to a parquet `Type`
-fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
+///
+/// If `canonical_names` or `coerce_types` is set, the children of lists and maps are
+/// given the names prescribed by the Parquet specification
+fn arrow_to_parquet_type(field: &Field, coerce_types: bool, canonical_names: bool) -> Result<Type> {
     const PARQUET_LIST_ELEMENT_NAME: &str = "element";
     const PARQUET_MAP_STRUCT_NAME: &str = "key_value";
     const PARQUET_KEY_FIELD_NAME: &str = "key";
@@ -543,7 +731,7 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
     } else {
         Repetition::REQUIRED
     };
-    let id = field_id(field);
+    let id = field_id(field)?;
     // create type from field
     match field.data_type() {
         DataType::Null => Type::primitive_type_builder(name, PhysicalType::INT32)
@@ -774,12 +962,14 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
         | DataType::LargeList(f)
         | DataType::ListView(f)
         | DataType::LargeListView(f) => {
-            let field_ref = if coerce_types && f.name() != PARQUET_LIST_ELEMENT_NAME {
+            let field_ref = if (coerce_types || canonical_names)
+                && f.name() != PARQUET_LIST_ELEMENT_NAME
+            {
                 // Ensure proper naming per the Parquet specification
                 let ff = f.as_ref().clone().with_name(PARQUET_LIST_ELEMENT_NAME);
-                Arc::new(arrow_to_parquet_type(&ff, coerce_types)?)
+                Arc::new(arrow_to_parquet_type(&ff, coerce_types, canonical_names)?)
             } else {
-                Arc::new(arrow_to_parquet_type(f, coerce_types)?)
+                Arc::new(arrow_to_parquet_type(f, coerce_types, canonical_names)?)
             };
 
             Type::group_type_builder(name)
@@ -801,7 +991,7 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
             // recursively convert children to types/nodes
             let fields = fields
                 .iter()
-                .map(|f| arrow_to_parquet_type(f, coerce_types).map(Arc::new))
+                .map(|f| arrow_to_parquet_type(f, coerce_types, canonical_names).map(Arc::new))
                 .collect::<Result<_>>()?;
             Type::group_type_builder(name)
                 .with_fields(fields)
@@ -812,20 +1002,21 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
         }
         DataType::Map(field, _) => {
             if let DataType::Struct(struct_fields) = field.data_type() {
-                // If coercing then set inner struct name to "key_value"
-                let map_struct_name = if coerce_types {
+                // If canonicalizing then set inner struct name to "key_value"
+                let canonical_names = coerce_types || canonical_names;
+                let map_struct_name = if canonical_names {
                     PARQUET_MAP_STRUCT_NAME
                 } else {
                     field.name()
                 };
 
-                // If coercing then ensure struct fields are named "key" and "value"
+                // If canonicalizing then ensure struct fields are named "key" and "value"
                 let fix_map_field = |name: &str, fld: &Arc<Field>| -> Result<Arc<Type>> {
-                    if coerce_types && fld.name() != name {
+                    if canonical_names && fld.name() != name {
                         let f = fld.as_ref().clone().with_name(name);
-                        Ok(Arc::new(arrow_to_parquet_type(&f, coerce_types)?))
+                        Ok(Arc::new(arrow_to_parquet_type(&f, coerce_types, canonical_names)?))
                     } else {
-                        Ok(Arc::new(arrow_to_parquet_type(fld, coerce_types)?))
+                        Ok(Arc::new(arrow_to_parquet_type(fld, coerce_types, canonical_names)?))
                     }
                 };
                 let key_field = fix_map_field(PARQUET_KEY_FIELD_NAME, &struct_fields[0])?;
@@ -852,17 +1043,28 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
         DataType::Dictionary(_, value) => {
             // Dictionary encoding not handled at the schema level
             let dict_field = field.clone().with_data_type(value.as_ref().clone());
-            arrow_to_parquet_type(&dict_field, coerce_types)
+            arrow_to_parquet_type(&dict_field, coerce_types, canonical_names)
+        }
+        DataType::RunEndEncoded(_, values) => {
+            // Run end encoding not handled at the schema level
+            let values_field = field.clone().with_data_type(values.data_type().clone());
+            arrow_to_parquet_type(&values_field, coerce_types, canonical_names)
         }
-        DataType::RunEndEncoded(_, _) => Err(arrow_err!(
-            "Converting RunEndEncodedType to parquet not supported",
-        )),
     }
 }
 
-fn field_id(field: &Field) -> Option<i32> {
-    let value = field.metadata().get(super::PARQUET_FIELD_ID_META_KEY)?;
-    value.parse().ok() // Fail quietly if not a valid integer
+fn field_id(field: &Field) -> Result<Option<i32>> {
+    let Some(value) = field.metadata().get(super::PARQUET_FIELD_ID_META_KEY) else {
+        return Ok(None);
+    };
+    value.parse().map(Some).map_err(|_| {
+        arrow_err!(
+            "Invalid {} \"{}\" for field \"{}\", expected a 32-bit integer",
+            PARQUET_FIELD_ID_META_KEY,
+            value,
+            field.name()
+        )
+    })
 }
 
 #[cfg(test)]
@@ -875,7 +1077,10 @@ mod tests {
     use crate::file::metadata::KeyValue;
     use crate::file::reader::FileReader;
     use crate::{
-        arrow::{ArrowWriter, arrow_reader::ParquetRecordBatchReaderBuilder},
+        arrow::{
+            ArrowWriter, arrow_reader::ParquetRecordBatchReaderBuilder,
+            arrow_writer::ArrowWriterOptions,
+        },
         schema::{parser::parse_message_type, types::SchemaDescriptor},
     };
     use arrow::datatypes::{DataType, Field, IntervalUnit, TimeUnit};
@@ -989,6 +1194,41 @@ mod tests {
         assert_eq!(&arrow_fields, converted_arrow_schema.fields());
     }
 
+    #[test]
+    fn test_interval_fields() {
+        let message_type = "
+        message test_schema {
+            REQUIRED FIXED_LEN_BYTE_ARRAY (12) interval (INTERVAL);
+        }
+        ";
+
+        let parquet_group_type = parse_message_type(message_type).unwrap();
+
+        let parquet_schema = SchemaDescriptor::new(Arc::new(parquet_group_type));
+        let converted_arrow_schema = parquet_to_arrow_schema(&parquet_schema, None).unwrap();
+
+        let arrow_fields = Fields::from(vec![Field::new(
+            "interval",
+            DataType::Interval(IntervalUnit::MonthDayNano),
+            false,
+        )]);
+        assert_eq!(&arrow_fields, converted_arrow_schema.fields());
+
+        // The reverse conversion is the same for all units
+        for unit in [
+            IntervalUnit::YearMonth,
+            IntervalUnit::DayTime,
+            IntervalUnit::MonthDayNano,
+        ] {
+            let schema = Schema::new(vec![Field::new("interval", DataType::Interval(unit), false)]);
+            let converted = ArrowSchemaConverter::new()
+                .schema_root("test_schema")
+                .convert(&schema)
+                .unwrap();
+            assert_eq!(converted.root_schema(), parquet_schema.root_schema());
+        }
+    }
+
     #[test]
     fn test_duplicate_fields() {
         let message_type = "
@@ -1552,7 +1792,7 @@ mod tests {
         }
 
         let mask =
-            ProjectionMask::columns(&parquet_schema, ["group2.leaf4", "group1.leaf1", "leaf5"]);
+            ProjectionMask::try_columns(&parquet_schema, ["group2.leaf4", "group1.leaf1", "leaf5"]).unwrap();
         let converted_arrow_schema =
             parquet_to_arrow_schema_by_columns(&parquet_schema, mask, None).unwrap();
         let converted_fields = converted_arrow_schema.fields();
@@ -2261,6 +2501,275 @@ mod tests {
         Ok(())
     }
 
+    /// Returns the field ids of `tp` and all its descendants in depth-first order
+    fn collect_field_ids(tp: &Type, out: &mut Vec<(String, i32)>) {
+        let info = tp.get_basic_info();
+        if info.has_id() {
+            out.push((info.name().to_string(), info.id()));
+        }
+        if tp.is_group() {
+            tp.get_fields()
+                .iter()
+                .for_each(|f| collect_field_ids(f, out));
+        }
+    }
+
+    fn arrow_field_id(field: &Field) -> Option<&str> {
+        field
+            .metadata()
+            .get(PARQUET_FIELD_ID_META_KEY)
+            .map(|s| s.as_str())
+    }
+
+    #[test]
+    fn test_parquet_field_ids_nested_roundtrip() -> Result<()> {
+        let with_id = |f: Field, id: &str| {
+            f.with_metadata(HashMap::from([(
+                PARQUET_FIELD_ID_META_KEY.to_string(),
+                id.to_string(),
+            )]))
+        };
+
+        let element = with_id(Field::new("element", DataType::Int32, true), "3");
+        let key = with_id(Field::new("key", DataType::Utf8, false), "5");
+        let value = with_id(Field::new("value", DataType::Float64, true), "6");
+        let entries = Field::new(
+            "key_value",
+            DataType::Struct(vec![key, value].into()),
+            false,
+        );
+        let child = with_id(Field::new("child", DataType::Int64, true), "8");
+
+        let schema = Arc::new(Schema::new(vec![
+            with_id(Field::new("id", DataType::Int64, false), "1"),
+            with_id(
+                Field::new("list", DataType::List(Arc::new(element)), true),
+                "2",
+            ),
+            with_id(
+                Field::new("map", DataType::Map(Arc::new(entries), false), true),
+                "4",
+            ),
+            with_id(
+                Field::new("struct", DataType::Struct(vec![child].into()), true),
+                "7",
+            ),
+        ]));
+
+        // Skip the embedded arrow schema so field ids must be read from the parquet schema
+        let options = ArrowWriterOptions::new().with_skip_arrow_metadata(true);
+        let writer = ArrowWriter::try_new_with_options(vec![], schema.clone(), options)?;
+        let parquet_bytes = bytes::Bytes::from(writer.into_inner()?);
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(parquet_bytes)?;
+        let mut ids = vec![];
+        collect_field_ids(builder.parquet_schema().root_schema(), &mut ids);
+        let ids: Vec<_> = ids.iter().map(|(n, id)| (n.as_str(), *id)).collect();
+        assert_eq!(
+            ids,
+            vec![
+                ("id", 1),
+                ("list", 2),
+                ("element", 3),
+                ("map", 4),
+                ("key", 5),
+                ("value", 6),
+                ("struct", 7),
+                ("child", 8),
+            ]
+        );
+
+        let read = builder.schema();
+        assert_eq!(arrow_field_id(read.field(0)), Some("1"));
+        let list = read.field(1);
+        assert_eq!(arrow_field_id(list), Some("2"));
+        let DataType::List(element) = list.data_type() else {
+            panic!("expected list, got {list}")
+        };
+        assert_eq!(arrow_field_id(element), Some("3"));
+
+        let map = read.field(2);
+        assert_eq!(arrow_field_id(map), Some("4"));
+        let DataType::Map(entries, _) = map.data_type() else {
+            panic!("expected map, got {map}")
+        };
+        let DataType::Struct(kv) = entries.data_type() else {
+            panic!("expected struct, got {entries}")
+        };
+        assert_eq!(arrow_field_id(&kv[0]), Some("5"));
+        assert_eq!(arrow_field_id(&kv[1]), Some("6"));
+
+        let strct = read.field(3);
+        assert_eq!(arrow_field_id(strct), Some("7"));
+        let DataType::Struct(children) = strct.data_type() else {
+            panic!("expected struct, got {strct}")
+        };
+        assert_eq!(arrow_field_id(&children[0]), Some("8"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parquet_field_ids_interop() -> Result<()> {
+        // Schema layout as written by PyArrow for an Iceberg table with nested ids
+        let message_type = "
+        message schema {
+            REQUIRED INT64 id = 1;
+            OPTIONAL group tags (LIST) = 2 {
+                REPEATED group list {
+                    OPTIONAL BYTE_ARRAY element (STRING) = 3;
+                }
+            }
+            OPTIONAL group props (MAP) = 4 {
+                REPEATED group key_value {
+                    REQUIRED BYTE_ARRAY key (STRING) = 5;
+                    OPTIONAL group value = 6 {
+                        OPTIONAL INT32 x = 7;
+                    }
+                }
+            }
+        }
+        ";
+        let parquet_schema = SchemaDescriptor::new(Arc::new(parse_message_type(message_type)?));
+        let arrow_schema = parquet_to_arrow_schema(&parquet_schema, None)?;
+
+        assert_eq!(arrow_field_id(arrow_schema.field(0)), Some("1"));
+        let DataType::List(element) = arrow_schema.field(1).data_type() else {
+            panic!("expected list")
+        };
+        assert_eq!(arrow_field_id(arrow_schema.field(1)), Some("2"));
+        assert_eq!(arrow_field_id(element), Some("3"));
+        let DataType::Map(entries, _) = arrow_schema.field(2).data_type() else {
+            panic!("expected map")
+        };
+        assert_eq!(arrow_field_id(arrow_schema.field(2)), Some("4"));
+        let DataType::Struct(kv) = entries.data_type() else {
+            panic!("expected struct")
+        };
+        assert_eq!(arrow_field_id(&kv[0]), Some("5"));
+        assert_eq!(arrow_field_id(&kv[1]), Some("6"));
+        let DataType::Struct(value) = kv[1].data_type() else {
+            panic!("expected struct")
+        };
+        assert_eq!(arrow_field_id(&value[0]), Some("7"));
+
+        // Converting back produces the same ids
+        let converted = ArrowSchemaConverter::new()
+            .with_coerce_types(true)
+            .convert(&arrow_schema)?;
+        let mut expected = vec![];
+        collect_field_ids(parquet_schema.root_schema(), &mut expected);
+        let mut actual = vec![];
+        collect_field_ids(converted.root_schema(), &mut actual);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parquet_field_ids_invalid() {
+        let field = |name: &str, id: &str| {
+            Field::new(name, DataType::Int32, true).with_metadata(HashMap::from([(
+                PARQUET_FIELD_ID_META_KEY.to_string(),
+                id.to_string(),
+            )]))
+        };
+
+        let schema = Schema::new(vec![field("a", "1"), field("b", "one")]);
+        let err = ArrowSchemaConverter::new().convert(&schema).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Invalid PARQUET:field_id \"one\" for field \"b\", expected a 32-bit integer"
+        );
+
+        let schema = Schema::new(vec![field("a", "1"), field("b", "4294967296")]);
+        let err = ArrowSchemaConverter::new().convert(&schema).unwrap_err();
+        assert!(err.to_string().contains("expected a 32-bit integer"), "{err}");
+
+        let nested = Field::new_struct("s", vec![field("b", "1")], true);
+        let schema = Schema::new(vec![field("a", "1"), nested]);
+        let err = ArrowSchemaConverter::new().convert(&schema).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Duplicate field id 1 found for field \"b\""
+        );
+
+        // Duplicate ids are also rejected by the writer
+        let schema = Arc::new(Schema::new(vec![field("a", "2"), field("b", "2")]));
+        let err = ArrowWriter::try_new(vec![], schema, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Duplicate field id 2 found for field \"b\""
+        );
+    }
+
+    #[test]
+    fn test_duplicate_field_names() {
+        let value = |data_type| Field::new("value", data_type, true);
+        let schema = Schema::new(vec![value(DataType::Int32), value(DataType::Utf8)]);
+        let err = ArrowSchemaConverter::new().convert(&schema).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Duplicate field name \"value\" in Arrow schema, Parquet columns must have unique names. \
+             Duplicate fields can be renamed with WriterProperties::set_rename_duplicate_columns"
+        );
+
+        let nested = Fields::from(vec![
+            value(DataType::Int32),
+            value(DataType::Int64),
+            Field::new("value_1", DataType::Int32, true),
+            value(DataType::Utf8),
+        ]);
+        let schema = Schema::new(vec![
+            Field::new_struct("a", nested.clone(), true),
+            Field::new_list("b", Field::new_struct("item", nested, true), true),
+        ]);
+        let err = ArrowSchemaConverter::new().convert(&schema).unwrap_err();
+        assert!(err.to_string().contains("Duplicate field name \"a.value\""));
+
+        let converted = ArrowSchemaConverter::new()
+            .with_rename_duplicate_columns(true)
+            .convert(&schema)
+            .unwrap();
+        let paths: Vec<_> = converted
+            .columns()
+            .iter()
+            .map(|c| c.path().string())
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "a.value",
+                "a.value_2",
+                "a.value_1",
+                "a.value_3",
+                "b.list.item.value",
+                "b.list.item.value_2",
+                "b.list.item.value_1",
+                "b.list.item.value_3",
+            ]
+        );
+
+        // The original fields are annotated with their new names
+        let (_, annotated) = rename_duplicate_fields(schema.fields(), true).unwrap();
+        let DataType::Struct(fields) = annotated[0].data_type() else {
+            unreachable!()
+        };
+        let names: Vec<_> = fields
+            .iter()
+            .map(|f| (f.name().as_str(), f.metadata().get(PARQUET_COLUMN_NAME_META_KEY)))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("value", None),
+                ("value", Some(&"value_2".to_string())),
+                ("value_1", None),
+                ("value", Some(&"value_3".to_string())),
+            ]
+        );
+    }
+
     #[test]
     fn test_arrow_schema_roundtrip_lists() -> Result<()> {
         let metadata: HashMap<String, String> = [("Key".to_string(), "Value".to_string())]
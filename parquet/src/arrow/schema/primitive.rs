@@ -102,6 +102,19 @@ fn apply_hint(parquet: DataType, hint: DataType) -> DataType {
                 false => hinted,
             }
         }
+
+        // Potentially preserve run end encoding
+        (_, DataType::RunEndEncoded(_, values)) => {
+            // Apply hint to values type
+            let hinted = apply_hint(parquet, values.data_type().clone());
+
+            // If matches run end encoded values - preserve run end encoding
+            // otherwise use hinted values type
+            match &hinted == values.data_type() {
+                true => hint,
+                false => hinted,
+            }
+        }
         _ => parquet,
     }
 }
@@ -163,8 +176,17 @@ fn decimal_256_type(scale: i32, precision: i32) -> Result<DataType> {
     Ok(DataType::Decimal256(precision, scale))
 }
 
+/// Returns the [`LogicalType`] of `info`, or `None` if it is not known to this crate
+///
+/// Logical types written by newer versions of the format fall back to the mapping
+/// of the converted type, or failing that, the physical type
+fn known_logical_type(info: &BasicTypeInfo) -> Option<&LogicalType> {
+    info.logical_type_ref()
+        .filter(|t| !matches!(t, LogicalType::_Unknown { .. }))
+}
+
 fn from_int32(info: &BasicTypeInfo, scale: i32, precision: i32) -> Result<DataType> {
-    match (info.logical_type_ref(), info.converted_type()) {
+    match (known_logical_type(info), info.converted_type()) {
         (None, ConvertedType::NONE) => Ok(DataType::Int32),
         (
             Some(
@@ -214,7 +236,7 @@ fn from_int32(info: &BasicTypeInfo, scale: i32, precision: i32) -> Result<DataTy
 }
 
 fn from_int64(info: &BasicTypeInfo, scale: i32, precision: i32) -> Result<DataType> {
-    match (info.logical_type_ref(), info.converted_type()) {
+    match (known_logical_type(info), info.converted_type()) {
         (None, ConvertedType::NONE) => Ok(DataType::Int64),
         (
             Some(LogicalType::Integer {
@@ -275,14 +297,13 @@ fn from_int64(info: &BasicTypeInfo, scale: i32, precision: i32) -> Result<DataTy
 }
 
 fn from_byte_array(info: &BasicTypeInfo, precision: i32, scale: i32) -> Result<DataType> {
-    match (info.logical_type_ref(), info.converted_type()) {
+    match (known_logical_type(info), info.converted_type()) {
         (Some(LogicalType::String), _) => Ok(DataType::Utf8),
         (Some(LogicalType::Json), _) => Ok(DataType::Utf8),
         (Some(LogicalType::Bson), _) => Ok(DataType::Binary),
         (Some(LogicalType::Enum), _) => Ok(DataType::Binary),
         (Some(LogicalType::Geometry { .. }), _) => Ok(DataType::Binary),
         (Some(LogicalType::Geography { .. }), _) => Ok(DataType::Binary),
-        (Some(LogicalType::_Unknown { .. }), _) => Ok(DataType::Binary),
         (None, ConvertedType::NONE) => Ok(DataType::Binary),
         (None, ConvertedType::JSON) => Ok(DataType::Utf8),
         (None, ConvertedType::BSON) => Ok(DataType::Binary),
@@ -311,7 +332,7 @@ fn from_fixed_len_byte_array(
     type_length: i32,
 ) -> Result<DataType> {
     // TODO: This should check the type length for the decimal and interval types
-    match (info.logical_type_ref(), info.converted_type()) {
+    match (known_logical_type(info), info.converted_type()) {
         (Some(LogicalType::Decimal { scale, precision }), _) => {
             if type_length <= 16 {
                 decimal_128_type(*scale, *precision)
@@ -327,10 +348,10 @@ fn from_fixed_len_byte_array(
             }
         }
         (None, ConvertedType::INTERVAL) => {
-            // There is currently no reliable way of determining which IntervalUnit
-            // to return. Thus without the original Arrow schema, the results
-            // would be incorrect if all 12 bytes of the interval are populated
-            Ok(DataType::Interval(IntervalUnit::DayTime))
+            // MonthDayNano is the only IntervalUnit able to represent all three
+            // components of the interval, the original Arrow schema, if any, is
+            // used to determine the IntervalUnit written
+            Ok(DataType::Interval(IntervalUnit::MonthDayNano))
         }
         (Some(LogicalType::Float16), _) => {
             if type_length == 2 {
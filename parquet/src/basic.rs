@@ -318,7 +318,7 @@ impl<'a, R: ThriftCompactInputProtocol<'a>> ReadThrift<'a, R> for LogicalType {
     fn read_thrift(prot: &mut R) -> Result<Self> {
         let field_ident = prot.read_field_begin(0)?;
         if field_ident.field_type == FieldType::Stop {
-            return Err(general_err!("received empty union from remote LogicalType"));
+            return Err(corrupt_err!("received empty union from remote LogicalType"));
         }
         let ret = match field_ident.id {
             1 => {
@@ -422,7 +422,7 @@ impl<'a, R: ThriftCompactInputProtocol<'a>> ReadThrift<'a, R> for LogicalType {
         };
         let field_ident = prot.read_field_begin(field_ident.id)?;
         if field_ident.field_type != FieldType::Stop {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "Received multiple fields for union from remote LogicalType"
             ));
         }
@@ -585,7 +585,8 @@ enum Encoding {
   ///
   /// The values in the dictionary are encoded using PLAIN encoding.
   /// Since it is deprecated, RLE_DICTIONARY encoding is used for a data page, and
-  /// PLAIN encoding is used for dictionary page.
+  /// PLAIN encoding is used for dictionary page, unless only Parquet 1.0 encodings
+  /// are allowed with `WriterPropertiesBuilder::set_strict_writer_version`.
   PLAIN_DICTIONARY = 2;
   /// Group packed run length encoding.
   ///
@@ -708,7 +709,7 @@ impl EncodingMask {
     /// This will return an error if a bit outside the allowable range is set.
     pub fn try_new(val: i32) -> Result<Self> {
         if val as u32 & Self::ALLOWED_MASK != 0 {
-            return Err(general_err!("Attempt to create invalid mask: 0x{:x}", val));
+            return Err(corrupt_err!("Attempt to create invalid mask: 0x{:x}", val));
         }
         Ok(Self(val))
     }
@@ -846,7 +847,7 @@ impl<'a, R: ThriftCompactInputProtocol<'a>> ReadThrift<'a, R> for Compression {
             5 => Self::LZ4,
             6 => Self::ZSTD(Default::default()),
             7 => Self::LZ4_RAW,
-            _ => return Err(general_err!("Unexpected CompressionCodec {}", val)),
+            _ => return Err(corrupt_err!("Unexpected CompressionCodec {}", val)),
         })
     }
 }
@@ -1307,7 +1308,7 @@ impl<'a, R: ThriftCompactInputProtocol<'a>> ReadThrift<'a, R> for ColumnOrder {
     fn read_thrift(prot: &mut R) -> Result<Self> {
         let field_ident = prot.read_field_begin(0)?;
         if field_ident.field_type == FieldType::Stop {
-            return Err(general_err!("Received empty union from remote ColumnOrder"));
+            return Err(corrupt_err!("Received empty union from remote ColumnOrder"));
         }
         let ret = match field_ident.id {
             1 => {
@@ -1322,7 +1323,7 @@ impl<'a, R: ThriftCompactInputProtocol<'a>> ReadThrift<'a, R> for ColumnOrder {
         };
         let field_ident = prot.read_field_begin(field_ident.id)?;
         if field_ident.field_type != FieldType::Stop {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "Received multiple fields for union from remote ColumnOrder"
             ));
         }
@@ -1438,7 +1439,7 @@ impl str::FromStr for Repetition {
             "REQUIRED" => Ok(Repetition::REQUIRED),
             "OPTIONAL" => Ok(Repetition::OPTIONAL),
             "REPEATED" => Ok(Repetition::REPEATED),
-            other => Err(general_err!("Invalid parquet repetition {}", other)),
+            other => Err(corrupt_err!("Invalid parquet repetition {}", other)),
         }
     }
 }
@@ -1456,7 +1457,7 @@ impl str::FromStr for Type {
             "DOUBLE" => Ok(Type::DOUBLE),
             "BYTE_ARRAY" | "BINARY" => Ok(Type::BYTE_ARRAY),
             "FIXED_LEN_BYTE_ARRAY" => Ok(Type::FIXED_LEN_BYTE_ARRAY),
-            other => Err(general_err!("Invalid parquet type {}", other)),
+            other => Err(corrupt_err!("Invalid parquet type {}", other)),
         }
     }
 }
@@ -1489,7 +1490,7 @@ impl str::FromStr for ConvertedType {
             "JSON" => Ok(ConvertedType::JSON),
             "BSON" => Ok(ConvertedType::BSON),
             "INTERVAL" => Ok(ConvertedType::INTERVAL),
-            other => Err(general_err!("Invalid parquet converted type {}", other)),
+            other => Err(corrupt_err!("Invalid parquet converted type {}", other)),
         }
     }
 }
@@ -1534,7 +1535,7 @@ impl str::FromStr for LogicalType {
                 crs: None,
                 algorithm: Some(EdgeInterpolationAlgorithm::SPHERICAL),
             }),
-            other => Err(general_err!("Invalid parquet logical type {}", other)),
+            other => Err(corrupt_err!("Invalid parquet logical type {}", other)),
         }
     }
 }
@@ -2089,6 +2090,34 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_read_unknown_logical_type() {
+        // An unknown union member with fields of several types, followed by the union stop
+        let bytes = [
+            0x0c, 0x3c, // field 30: struct
+            0x15, 0x54, // field 1: i32 42
+            0x18, 0x03, b'a', b'b', b'c', // field 2: binary "abc"
+            0x19, 0x25, 0x02, 0x04, // field 3: list<i32> [1, 2]
+            0x1c, 0x00, // field 4: empty struct
+            0x00, // end of field 30
+            0x00, // end of union
+            0xff, // trailing data
+        ];
+        let mut prot = ThriftSliceInputProtocol::new(&bytes);
+        let logical_type = LogicalType::read_thrift(&mut prot).unwrap();
+        assert_eq!(logical_type, LogicalType::_Unknown { field_id: 30 });
+        assert_eq!(prot.as_slice(), &[0xff]);
+
+        // Multiple union members are an error, even if unknown
+        let bytes = [0x0c, 0x3c, 0x00, 0x1c, 0x00, 0x00];
+        let mut prot = ThriftSliceInputProtocol::new(&bytes);
+        let err = LogicalType::read_thrift(&mut prot).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Received multiple fields for union from remote LogicalType"
+        );
+    }
+
     #[test]
     fn test_display_repetition() {
         assert_eq!(Repetition::REQUIRED.to_string(), "REQUIRED");
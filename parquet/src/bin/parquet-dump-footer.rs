@@ -0,0 +1,1269 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Binary that prints the raw Thrift metadata in the footer of a parquet file as JSON
+//!
+//! Unlike `parquet-schema` and `parquet-layout`, this does not interpret the metadata,
+//! instead printing every field of the Thrift structures exactly as encoded, including
+//! duplicated entries and fields unknown to this crate. Each field is keyed by its
+//! Thrift field id, name and type, for example `"3:num_rows:i64"`, and fields are
+//! printed in the order they are encoded, so the output of two files can be compared
+//! with `diff`.
+//!
+//! Binary fields are printed as strings if they are valid UTF-8, and otherwise as
+//! `{"hex": "..."}`.
+//!
+//! # Install
+//!
+//! `parquet-dump-footer` can be installed using `cargo`:
+//! ```
+//! cargo install parquet --features=cli
+//! ```
+//! After this `parquet-dump-footer` should be available:
+//! ```
+//! parquet-dump-footer XYZ.parquet
+//! ```
+//!
+//! The binary can also be built from the source code and run as follows:
+//! ```
+//! cargo run --features=cli --bin parquet-dump-footer XYZ.parquet
+//! ```
+//!
+//! # Options
+//!
+//! - `--redact-statistics` replaces the min and max values of statistics, column
+//!   indexes and bounding boxes with `"<redacted>"`, to allow sharing the metadata
+//!   of files containing sensitive data.
+//! - `--page-indexes` also prints the column index and offset index of each column chunk.
+
+use std::fs::File;
+
+use clap::Parser;
+use parquet::errors::{ParquetError, Result};
+use parquet::file::reader::ChunkReader;
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Serialize, Serializer};
+
+/// The length of the trailing metadata length and magic bytes
+const FOOTER_SIZE: u64 = 8;
+const PARQUET_MAGIC: &[u8; 4] = b"PAR1";
+const PARQUET_MAGIC_ENCR_FOOTER: &[u8; 4] = b"PARE";
+
+/// The names of the fields of a Thrift struct in `parquet.thrift`
+struct StructDef {
+    fields: &'static [FieldDef],
+}
+
+impl StructDef {
+    fn field(&self, id: i16) -> Option<&FieldDef> {
+        self.fields.iter().find(|f| f.id == id)
+    }
+}
+
+struct FieldDef {
+    id: i16,
+    name: &'static str,
+    /// The definition of this field if it is a struct, or a list of structs
+    child: Option<&'static StructDef>,
+    /// Whether this field contains min or max values, see `--redact-statistics`
+    redact: bool,
+}
+
+const fn field(id: i16, name: &'static str) -> FieldDef {
+    FieldDef {
+        id,
+        name,
+        child: None,
+        redact: false,
+    }
+}
+
+const fn child(id: i16, name: &'static str, child: &'static StructDef) -> FieldDef {
+    FieldDef {
+        id,
+        name,
+        child: Some(child),
+        redact: false,
+    }
+}
+
+const fn redacted(id: i16, name: &'static str) -> FieldDef {
+    FieldDef {
+        id,
+        name,
+        child: None,
+        redact: true,
+    }
+}
+
+static SIZE_STATISTICS: StructDef = StructDef {
+    fields: &[
+        field(1, "unencoded_byte_array_data_bytes"),
+        field(2, "repetition_level_histogram"),
+        field(3, "definition_level_histogram"),
+    ],
+};
+
+static BOUNDING_BOX: StructDef = StructDef {
+    fields: &[
+        redacted(1, "xmin"),
+        redacted(2, "xmax"),
+        redacted(3, "ymin"),
+        redacted(4, "ymax"),
+        redacted(5, "zmin"),
+        redacted(6, "zmax"),
+        redacted(7, "mmin"),
+        redacted(8, "mmax"),
+    ],
+};
+
+static GEOSPATIAL_STATISTICS: StructDef = StructDef {
+    fields: &[
+        child(1, "bbox", &BOUNDING_BOX),
+        field(2, "geospatial_types"),
+    ],
+};
+
+static STATISTICS: StructDef = StructDef {
+    fields: &[
+        redacted(1, "max"),
+        redacted(2, "min"),
+        field(3, "null_count"),
+        field(4, "distinct_count"),
+        redacted(5, "max_value"),
+        redacted(6, "min_value"),
+        field(7, "is_max_value_exact"),
+        field(8, "is_min_value_exact"),
+    ],
+};
+
+static TIME_UNIT: StructDef = StructDef {
+    fields: &[field(1, "MILLIS"), field(2, "MICROS"), field(3, "NANOS")],
+};
+
+static DECIMAL_TYPE: StructDef = StructDef {
+    fields: &[field(1, "scale"), field(2, "precision")],
+};
+
+static TIME_TYPE: StructDef = StructDef {
+    fields: &[field(1, "isAdjustedToUTC"), child(2, "unit", &TIME_UNIT)],
+};
+
+static INT_TYPE: StructDef = StructDef {
+    fields: &[field(1, "bitWidth"), field(2, "isSigned")],
+};
+
+static VARIANT_TYPE: StructDef = StructDef {
+    fields: &[field(1, "specification_version")],
+};
+
+static GEOMETRY_TYPE: StructDef = StructDef {
+    fields: &[field(1, "crs")],
+};
+
+static GEOGRAPHY_TYPE: StructDef = StructDef {
+    fields: &[field(1, "crs"), field(2, "algorithm")],
+};
+
+static LOGICAL_TYPE: StructDef = StructDef {
+    fields: &[
+        field(1, "STRING"),
+        field(2, "MAP"),
+        field(3, "LIST"),
+        field(4, "ENUM"),
+        child(5, "DECIMAL", &DECIMAL_TYPE),
+        field(6, "DATE"),
+        child(7, "TIME", &TIME_TYPE),
+        child(8, "TIMESTAMP", &TIME_TYPE),
+        child(10, "INTEGER", &INT_TYPE),
+        field(11, "UNKNOWN"),
+        field(12, "JSON"),
+        field(13, "BSON"),
+        field(14, "UUID"),
+        field(15, "FLOAT16"),
+        child(16, "VARIANT", &VARIANT_TYPE),
+        child(17, "GEOMETRY", &GEOMETRY_TYPE),
+        child(18, "GEOGRAPHY", &GEOGRAPHY_TYPE),
+    ],
+};
+
+static SCHEMA_ELEMENT: StructDef = StructDef {
+    fields: &[
+        field(1, "type"),
+        field(2, "type_length"),
+        field(3, "repetition_type"),
+        field(4, "name"),
+        field(5, "num_children"),
+        field(6, "converted_type"),
+        field(7, "scale"),
+        field(8, "precision"),
+        field(9, "field_id"),
+        child(10, "logicalType", &LOGICAL_TYPE),
+    ],
+};
+
+static KEY_VALUE: StructDef = StructDef {
+    fields: &[field(1, "key"), field(2, "value")],
+};
+
+static SORTING_COLUMN: StructDef = StructDef {
+    fields: &[
+        field(1, "column_idx"),
+        field(2, "descending"),
+        field(3, "nulls_first"),
+    ],
+};
+
+static PAGE_ENCODING_STATS: StructDef = StructDef {
+    fields: &[
+        field(1, "page_type"),
+        field(2, "encoding"),
+        field(3, "count"),
+    ],
+};
+
+static COLUMN_META_DATA: StructDef = StructDef {
+    fields: &[
+        field(1, "type"),
+        field(2, "encodings"),
+        field(3, "path_in_schema"),
+        field(4, "codec"),
+        field(5, "num_values"),
+        field(6, "total_uncompressed_size"),
+        field(7, "total_compressed_size"),
+        child(8, "key_value_metadata", &KEY_VALUE),
+        field(9, "data_page_offset"),
+        field(10, "index_page_offset"),
+        field(11, "dictionary_page_offset"),
+        child(12, "statistics", &STATISTICS),
+        child(13, "encoding_stats", &PAGE_ENCODING_STATS),
+        field(14, "bloom_filter_offset"),
+        field(15, "bloom_filter_length"),
+        child(16, "size_statistics", &SIZE_STATISTICS),
+        child(17, "geospatial_statistics", &GEOSPATIAL_STATISTICS),
+    ],
+};
+
+static ENCRYPTION_WITH_COLUMN_KEY: StructDef = StructDef {
+    fields: &[field(1, "path_in_schema"), field(2, "key_metadata")],
+};
+
+static COLUMN_CRYPTO_META_DATA: StructDef = StructDef {
+    fields: &[
+        field(1, "ENCRYPTION_WITH_FOOTER_KEY"),
+        child(2, "ENCRYPTION_WITH_COLUMN_KEY", &ENCRYPTION_WITH_COLUMN_KEY),
+    ],
+};
+
+static COLUMN_CHUNK: StructDef = StructDef {
+    fields: &[
+        field(1, "file_path"),
+        field(2, "file_offset"),
+        child(3, "meta_data", &COLUMN_META_DATA),
+        field(4, "offset_index_offset"),
+        field(5, "offset_index_length"),
+        field(6, "column_index_offset"),
+        field(7, "column_index_length"),
+        child(8, "crypto_metadata", &COLUMN_CRYPTO_META_DATA),
+        field(9, "encrypted_column_metadata"),
+    ],
+};
+
+static ROW_GROUP: StructDef = StructDef {
+    fields: &[
+        child(1, "columns", &COLUMN_CHUNK),
+        field(2, "total_byte_size"),
+        field(3, "num_rows"),
+        child(4, "sorting_columns", &SORTING_COLUMN),
+        field(5, "file_offset"),
+        field(6, "total_compressed_size"),
+        field(7, "ordinal"),
+    ],
+};
+
+static COLUMN_ORDER: StructDef = StructDef {
+    fields: &[field(1, "TYPE_ORDER")],
+};
+
+static AES_GCM: StructDef = StructDef {
+    fields: &[
+        field(1, "aad_prefix"),
+        field(2, "aad_file_unique"),
+        field(3, "supply_aad_prefix"),
+    ],
+};
+
+static ENCRYPTION_ALGORITHM: StructDef = StructDef {
+    fields: &[
+        child(1, "AES_GCM_V1", &AES_GCM),
+        child(2, "AES_GCM_CTR_V1", &AES_GCM),
+    ],
+};
+
+static FILE_META_DATA: StructDef = StructDef {
+    fields: &[
+        field(1, "version"),
+        child(2, "schema", &SCHEMA_ELEMENT),
+        field(3, "num_rows"),
+        child(4, "row_groups", &ROW_GROUP),
+        child(5, "key_value_metadata", &KEY_VALUE),
+        field(6, "created_by"),
+        child(7, "column_orders", &COLUMN_ORDER),
+        child(8, "encryption_algorithm", &ENCRYPTION_ALGORITHM),
+        field(9, "footer_signing_key_metadata"),
+    ],
+};
+
+static PAGE_LOCATION: StructDef = StructDef {
+    fields: &[
+        field(1, "offset"),
+        field(2, "compressed_page_size"),
+        field(3, "first_row_index"),
+    ],
+};
+
+static OFFSET_INDEX: StructDef = StructDef {
+    fields: &[
+        child(1, "page_locations", &PAGE_LOCATION),
+        field(2, "unencoded_byte_array_data_bytes"),
+    ],
+};
+
+static COLUMN_INDEX: StructDef = StructDef {
+    fields: &[
+        field(1, "null_pages"),
+        redacted(2, "min_values"),
+        redacted(3, "max_values"),
+        field(4, "boundary_order"),
+        field(5, "null_counts"),
+        field(6, "repetition_level_histograms"),
+        field(7, "definition_level_histograms"),
+    ],
+};
+
+/// A Thrift compact protocol type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Type {
+    Bool,
+    I8,
+    I16,
+    I32,
+    I64,
+    Double,
+    Binary,
+    List,
+    Set,
+    Map,
+    Struct,
+}
+
+impl Type {
+    /// Decodes the type of a list element or map entry, where both `1` and `2` denote
+    /// a boolean for compatibility with existing writers
+    fn from_element(v: u8) -> Result<Self> {
+        Ok(match v {
+            1 | 2 => Self::Bool,
+            3 => Self::I8,
+            4 => Self::I16,
+            5 => Self::I32,
+            6 => Self::I64,
+            7 => Self::Double,
+            8 => Self::Binary,
+            9 => Self::List,
+            10 => Self::Set,
+            11 => Self::Map,
+            12 => Self::Struct,
+            _ => {
+                return Err(ParquetError::General(format!(
+                    "invalid thrift element type {v}"
+                )));
+            }
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Bool => "bool",
+            Self::I8 => "i8",
+            Self::I16 => "i16",
+            Self::I32 => "i32",
+            Self::I64 => "i64",
+            Self::Double => "double",
+            Self::Binary => "binary",
+            Self::List => "list",
+            Self::Set => "set",
+            Self::Map => "map",
+            Self::Struct => "struct",
+        }
+    }
+}
+
+/// A decoded Thrift value
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Bool(bool),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    Double(f64),
+    Binary(Vec<u8>),
+    Struct(Struct),
+    List(Type, Vec<Value>),
+    Set(Type, Vec<Value>),
+    /// The key and value types, which are not encoded for an empty map
+    Map(Option<(Type, Type)>, Vec<(Value, Value)>),
+    /// A value removed by `--redact-statistics`
+    Redacted(Type),
+}
+
+impl Value {
+    /// Returns the name of the Thrift type of this value, e.g. `list<i32>`
+    fn type_name(&self) -> String {
+        match self {
+            Self::List(element, _) => format!("list<{}>", element.name()),
+            Self::Set(element, _) => format!("set<{}>", element.name()),
+            Self::Map(Some((key, value)), _) => format!("map<{},{}>", key.name(), value.name()),
+            Self::Map(None, _) => "map".to_string(),
+            Self::Bool(_) => Type::Bool.name().to_string(),
+            Self::I8(_) => Type::I8.name().to_string(),
+            Self::I16(_) => Type::I16.name().to_string(),
+            Self::I32(_) => Type::I32.name().to_string(),
+            Self::I64(_) => Type::I64.name().to_string(),
+            Self::Double(_) => Type::Double.name().to_string(),
+            Self::Binary(_) => Type::Binary.name().to_string(),
+            Self::Struct(_) => Type::Struct.name().to_string(),
+            Self::Redacted(ty) => ty.name().to_string(),
+        }
+    }
+
+    fn is_container(&self) -> bool {
+        matches!(self, Self::List(..) | Self::Set(..) | Self::Map(..))
+    }
+
+    /// Replaces this value, or each element if this is a list, with [`Value::Redacted`]
+    fn redact(self, ty: Type) -> Self {
+        match self {
+            Self::List(element, values) => Self::List(
+                element,
+                values
+                    .into_iter()
+                    .map(|_| Self::Redacted(element))
+                    .collect(),
+            ),
+            _ => Self::Redacted(ty),
+        }
+    }
+}
+
+/// A decoded Thrift struct, with its fields in the order they were encoded
+#[derive(Debug, Clone, PartialEq)]
+struct Struct {
+    fields: Vec<Field>,
+}
+
+impl Struct {
+    fn get(&self, id: i16) -> Option<&Value> {
+        self.fields.iter().find(|f| f.id == id).map(|f| &f.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Field {
+    id: i16,
+    name: Option<&'static str>,
+    value: Value,
+}
+
+impl Field {
+    fn key(&self) -> String {
+        let name = self.name.unwrap_or("unknown");
+        format!("{}:{name}:{}", self.id, self.value.type_name())
+    }
+}
+
+impl Serialize for Struct {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.fields.len()))?;
+        for field in &self.fields {
+            map.serialize_entry(&field.key(), &field.value)?;
+        }
+        map.end()
+    }
+}
+
+/// An element of a list, set or map, which is wrapped in an object keyed by its
+/// type if it is itself a container, as the element type does not describe it fully
+struct Element<'a>(&'a Value);
+
+impl Serialize for Element<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self.0.is_container() {
+            true => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(&self.0.type_name(), self.0)?;
+                map.end()
+            }
+            false => self.0.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Self::Bool(v) => serializer.serialize_bool(*v),
+            Self::I8(v) => serializer.serialize_i8(*v),
+            Self::I16(v) => serializer.serialize_i16(*v),
+            Self::I32(v) => serializer.serialize_i32(*v),
+            Self::I64(v) => serializer.serialize_i64(*v),
+            // JSON has no representation of non-finite numbers
+            Self::Double(v) if !v.is_finite() => serializer.serialize_str(&v.to_string()),
+            Self::Double(v) => serializer.serialize_f64(*v),
+            Self::Binary(v) => match std::str::from_utf8(v) {
+                Ok(s) => serializer.serialize_str(s),
+                Err(_) => {
+                    let hex: String = v.iter().map(|b| format!("{b:02x}")).collect();
+                    let mut map = serializer.serialize_map(Some(1))?;
+                    map.serialize_entry("hex", &hex)?;
+                    map.end()
+                }
+            },
+            Self::Struct(s) => s.serialize(serializer),
+            Self::List(_, values) | Self::Set(_, values) => {
+                let mut seq = serializer.serialize_seq(Some(values.len()))?;
+                for value in values {
+                    seq.serialize_element(&Element(value))?;
+                }
+                seq.end()
+            }
+            Self::Map(_, entries) => {
+                let mut seq = serializer.serialize_seq(Some(entries.len()))?;
+                for (key, value) in entries {
+                    seq.serialize_element(&(Element(key), Element(value)))?;
+                }
+                seq.end()
+            }
+            Self::Redacted(_) => serializer.serialize_str("<redacted>"),
+        }
+    }
+}
+
+/// The maximum nesting of structs and containers, to bound the recursion of [`Decoder`]
+const MAX_DEPTH: usize = 64;
+
+/// Decodes structs encoded with the [Thrift compact protocol], naming their fields
+/// from a [`StructDef`]
+///
+/// This does not use the generated thrift code, as that discards unknown fields and
+/// the order in which fields were encoded.
+///
+/// [Thrift compact protocol]: https://github.com/apache/thrift/blob/master/doc/specs/thrift-compact-protocol.md
+struct Decoder<'a> {
+    buf: &'a [u8],
+    redact: bool,
+    depth: usize,
+}
+
+impl Decoder<'_> {
+    fn read_byte(&mut self) -> Result<u8> {
+        let (first, rest) = self.buf.split_first().ok_or_else(eof_err)?;
+        self.buf = rest;
+        Ok(*first)
+    }
+
+    fn read_slice(&mut self, len: usize) -> Result<&[u8]> {
+        if len > self.buf.len() {
+            return Err(eof_err());
+        }
+        let (slice, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Ok(slice)
+    }
+
+    fn read_vlq(&mut self) -> Result<u64> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.read_byte()?;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(ParquetError::General("invalid thrift varint".to_string()))
+    }
+
+    fn read_zig_zag(&mut self) -> Result<i64> {
+        let v = self.read_vlq()?;
+        Ok((v >> 1) as i64 ^ -((v & 1) as i64))
+    }
+
+    fn read_size(&mut self) -> Result<usize> {
+        let size = self.read_vlq()?;
+        // Every element occupies at least one byte, so this bounds allocations
+        match usize::try_from(size) {
+            Ok(size) if size <= self.buf.len() => Ok(size),
+            _ => Err(eof_err()),
+        }
+    }
+
+    fn read_struct(&mut self, def: Option<&'static StructDef>) -> Result<Struct> {
+        let mut fields = vec![];
+        let mut last_id = 0_i16;
+        loop {
+            let header = self.read_byte()?;
+            let field_type = header & 0x0f;
+            if field_type == 0 {
+                break;
+            }
+            let id = match header >> 4 {
+                0 => i16::try_from(self.read_zig_zag()?)
+                    .map_err(|_| ParquetError::General("invalid thrift field id".to_string()))?,
+                delta => last_id.wrapping_add(delta.into()),
+            };
+            last_id = id;
+
+            let field_def = def.and_then(|d| d.field(id));
+            let child = field_def.and_then(|f| f.child);
+            let (ty, value) = match field_type {
+                1 => (Type::Bool, Value::Bool(true)),
+                2 => (Type::Bool, Value::Bool(false)),
+                v => {
+                    let ty = Type::from_element(v)?;
+                    (ty, self.read_value(ty, child)?)
+                }
+            };
+            let value = match self.redact && field_def.is_some_and(|f| f.redact) {
+                true => value.redact(ty),
+                false => value,
+            };
+            fields.push(Field {
+                id,
+                name: field_def.map(|f| f.name),
+                value,
+            });
+        }
+        Ok(Struct { fields })
+    }
+
+    fn read_value(&mut self, ty: Type, def: Option<&'static StructDef>) -> Result<Value> {
+        Ok(match ty {
+            Type::Bool => match self.read_byte()? {
+                1 => Value::Bool(true),
+                0 | 2 => Value::Bool(false),
+                b => return Err(ParquetError::General(format!("invalid thrift boolean {b}"))),
+            },
+            Type::I8 => Value::I8(self.read_byte()? as i8),
+            Type::I16 => Value::I16(
+                i16::try_from(self.read_zig_zag()?)
+                    .map_err(|_| ParquetError::General("invalid thrift i16".to_string()))?,
+            ),
+            Type::I32 => Value::I32(
+                i32::try_from(self.read_zig_zag()?)
+                    .map_err(|_| ParquetError::General("invalid thrift i32".to_string()))?,
+            ),
+            Type::I64 => Value::I64(self.read_zig_zag()?),
+            Type::Double => {
+                let bytes = self.read_slice(8)?;
+                Value::Double(f64::from_le_bytes(bytes.try_into().unwrap()))
+            }
+            Type::Binary => {
+                let len = self.read_size()?;
+                Value::Binary(self.read_slice(len)?.to_vec())
+            }
+            Type::Struct => Value::Struct(self.nested(|d| d.read_struct(def))?),
+            Type::List | Type::Set => {
+                let header = self.read_byte()?;
+                let element = Type::from_element(header & 0x0f)?;
+                let len = match header >> 4 {
+                    15 => self.read_size()?,
+                    len => len.into(),
+                };
+                let values = self.nested(|d| {
+                    (0..len)
+                        .map(|_| d.read_value(element, def))
+                        .collect::<Result<Vec<_>>>()
+                })?;
+                match ty {
+                    Type::List => Value::List(element, values),
+                    _ => Value::Set(element, values),
+                }
+            }
+            Type::Map => {
+                let len = self.read_size()?;
+                if len == 0 {
+                    return Ok(Value::Map(None, vec![]));
+                }
+                let types = self.read_byte()?;
+                let key = Type::from_element(types >> 4)?;
+                let value = Type::from_element(types & 0x0f)?;
+                let entries = self.nested(|d| {
+                    (0..len)
+                        .map(|_| Ok((d.read_value(key, None)?, d.read_value(value, None)?)))
+                        .collect::<Result<Vec<_>>>()
+                })?;
+                Value::Map(Some((key, value)), entries)
+            }
+        })
+    }
+
+    fn nested<T>(&mut self, f: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        if self.depth == MAX_DEPTH {
+            return Err(ParquetError::General(
+                "thrift structure nested too deeply".to_string(),
+            ));
+        }
+        self.depth += 1;
+        let result = f(self);
+        self.depth -= 1;
+        result
+    }
+}
+
+fn eof_err() -> ParquetError {
+    ParquetError::EOF("unexpected end of thrift data".to_string())
+}
+
+/// Decodes the struct described by `def` from `buf`, returning it along with the
+/// number of bytes following it
+fn decode(buf: &[u8], def: &'static StructDef, redact: bool) -> Result<(Struct, usize)> {
+    let mut decoder = Decoder {
+        buf,
+        redact,
+        depth: 0,
+    };
+    let decoded = decoder.read_struct(Some(def))?;
+    Ok((decoded, decoder.buf.len()))
+}
+
+#[derive(Debug, Serialize)]
+struct Footer {
+    metadata_length: usize,
+    /// The number of bytes of metadata following the `FileMetaData`, such as the
+    /// signature of a signed plaintext footer
+    #[serde(skip_serializing_if = "is_zero")]
+    trailing_bytes: usize,
+    file_metadata: Struct,
+    /// The page indexes of each column chunk of each row group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page_indexes: Option<Vec<Vec<PageIndexes>>>,
+}
+
+fn is_zero(v: &usize) -> bool {
+    *v == 0
+}
+
+#[derive(Debug, Serialize)]
+struct PageIndexes {
+    column_index: Option<Struct>,
+    offset_index: Option<Struct>,
+}
+
+/// Returns the `i64` value of the field `offset_id`, and the `i32` value of `length_id`
+fn index_range(column: &Struct, offset_id: i16, length_id: i16) -> Option<(u64, usize)> {
+    match (column.get(offset_id), column.get(length_id)) {
+        (Some(Value::I64(offset)), Some(Value::I32(length))) => {
+            Some((*offset as u64, *length as usize))
+        }
+        _ => None,
+    }
+}
+
+fn read_index<R: ChunkReader>(
+    reader: &R,
+    range: Option<(u64, usize)>,
+    def: &'static StructDef,
+    redact: bool,
+) -> Result<Option<Struct>> {
+    range
+        .map(|(offset, length)| {
+            let bytes = reader.get_bytes(offset, length)?;
+            Ok(decode(&bytes, def, redact)?.0)
+        })
+        .transpose()
+}
+
+fn dump_footer<R: ChunkReader>(reader: &R, redact: bool, page_indexes: bool) -> Result<Footer> {
+    let file_size = reader.len();
+    if file_size < FOOTER_SIZE {
+        return Err(ParquetError::General(
+            "file size is smaller than the parquet footer".to_string(),
+        ));
+    }
+    let footer = reader.get_bytes(file_size - FOOTER_SIZE, FOOTER_SIZE as usize)?;
+    match &footer[4..] {
+        magic if magic == PARQUET_MAGIC => {}
+        magic if magic == PARQUET_MAGIC_ENCR_FOOTER => {
+            return Err(ParquetError::General(
+                "files with an encrypted footer are not supported".to_string(),
+            ));
+        }
+        _ => {
+            return Err(ParquetError::General(
+                "invalid parquet file, corrupt footer".to_string(),
+            ));
+        }
+    }
+
+    let metadata_length = u32::from_le_bytes(footer[..4].try_into().unwrap()) as u64;
+    if metadata_length + FOOTER_SIZE > file_size {
+        return Err(ParquetError::General(format!(
+            "invalid metadata length {metadata_length} for a file of {file_size} bytes"
+        )));
+    }
+    let metadata = reader.get_bytes(
+        file_size - FOOTER_SIZE - metadata_length,
+        metadata_length as usize,
+    )?;
+    let (file_metadata, trailing_bytes) = decode(&metadata, &FILE_META_DATA, redact)?;
+
+    let page_indexes = page_indexes
+        .then(|| {
+            let row_groups = match file_metadata.get(4) {
+                Some(Value::List(_, row_groups)) => row_groups.as_slice(),
+                _ => &[],
+            };
+            row_groups
+                .iter()
+                .map(|row_group| {
+                    let columns = match row_group {
+                        Value::Struct(row_group) => match row_group.get(1) {
+                            Some(Value::List(_, columns)) => columns.as_slice(),
+                            _ => &[],
+                        },
+                        _ => &[],
+                    };
+                    columns
+                        .iter()
+                        .map(|column| {
+                            let Value::Struct(column) = column else {
+                                return Ok(PageIndexes {
+                                    column_index: None,
+                                    offset_index: None,
+                                });
+                            };
+                            Ok(PageIndexes {
+                                column_index: read_index(
+                                    reader,
+                                    index_range(column, 6, 7),
+                                    &COLUMN_INDEX,
+                                    redact,
+                                )?,
+                                offset_index: read_index(
+                                    reader,
+                                    index_range(column, 4, 5),
+                                    &OFFSET_INDEX,
+                                    redact,
+                                )?,
+                            })
+                        })
+                        .collect::<Result<Vec<_>>>()
+                })
+                .collect::<Result<Vec<_>>>()
+        })
+        .transpose()?;
+
+    Ok(Footer {
+        metadata_length: metadata_length as usize,
+        trailing_bytes,
+        file_metadata,
+        page_indexes,
+    })
+}
+
+#[derive(Debug, Parser)]
+#[clap(author, version, about("Prints the raw Thrift metadata in the footer of a parquet file as JSON"), long_about = None)]
+struct Args {
+    #[clap(help("Path to a parquet file"))]
+    file: String,
+    #[clap(
+        long,
+        help("Replace the min and max values of statistics and page indexes with \"<redacted>\"")
+    )]
+    redact_statistics: bool,
+    #[clap(
+        long,
+        help("Also print the column and offset index of each column chunk")
+    )]
+    page_indexes: bool,
+}
+
+impl Args {
+    fn run(&self) -> Result<()> {
+        let file = File::open(&self.file)?;
+        let footer = dump_footer(&file, self.redact_statistics, self.page_indexes)?;
+
+        let out = std::io::stdout();
+        let writer = out.lock();
+
+        serde_json::to_writer_pretty(writer, &footer).unwrap();
+        println!();
+        Ok(())
+    }
+}
+
+fn main() -> Result<()> {
+    Args::parse().run()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{ArrayRef, Int32Array, StringArray};
+    use arrow::record_batch::RecordBatch;
+    use bytes::Bytes;
+    use parquet::arrow::ArrowWriter;
+    use parquet::file::metadata::{KeyValue, PageIndexPolicy, ParquetMetaDataReader};
+    use parquet::file::properties::{EnabledStatistics, WriterProperties};
+    use serde_json::{Map, Value as Json};
+    use std::sync::Arc;
+
+    /// Returns the compact protocol type of a type name printed by the tool
+    fn type_code(name: &str) -> u8 {
+        match name.split('<').next().unwrap() {
+            "bool" => 2,
+            "i8" => 3,
+            "i16" => 4,
+            "i32" => 5,
+            "i64" => 6,
+            "double" => 7,
+            "binary" => 8,
+            "list" => 9,
+            "set" => 10,
+            "map" => 11,
+            "struct" => 12,
+            _ => panic!("unknown type {name}"),
+        }
+    }
+
+    /// Returns the type names within the angle brackets of a container type name
+    fn type_params(name: &str) -> Vec<&str> {
+        name[name.find('<').unwrap() + 1..name.len() - 1]
+            .split(',')
+            .collect()
+    }
+
+    /// Encodes the JSON printed by the tool back to the Thrift compact protocol, in the
+    /// same way as the writer in this crate
+    #[derive(Default)]
+    struct Encoder(Vec<u8>);
+
+    impl Encoder {
+        fn vlq(&mut self, mut v: u64) {
+            while v >= 0x80 {
+                self.0.push(v as u8 | 0x80);
+                v >>= 7;
+            }
+            self.0.push(v as u8)
+        }
+
+        fn zig_zag(&mut self, v: i64) {
+            self.vlq(((v << 1) ^ (v >> 63)) as u64)
+        }
+
+        fn encode_struct(&mut self, json: &Map<String, Json>) {
+            // `serde_json::Map` sorts its keys, so restore the field order from the ids
+            let mut fields: Vec<_> = json
+                .iter()
+                .map(|(key, value)| {
+                    let mut parts = key.splitn(3, ':');
+                    let id: i16 = parts.next().unwrap().parse().unwrap();
+                    (id, parts.nth(1).unwrap(), value)
+                })
+                .collect();
+            fields.sort_by_key(|(id, _, _)| *id);
+
+            let mut last_id = 0;
+            for (id, name, value) in fields {
+                let code = match value {
+                    Json::Bool(v) if name == "bool" => 2 - *v as u8,
+                    _ => type_code(name),
+                };
+                match id - last_id {
+                    delta @ 1..=15 => self.0.push((delta as u8) << 4 | code),
+                    _ => {
+                        self.0.push(code);
+                        self.zig_zag(id.into());
+                    }
+                }
+                if name != "bool" {
+                    self.encode_value(name, value);
+                }
+                last_id = id;
+            }
+            self.0.push(0);
+        }
+
+        fn encode_element(&mut self, name: &str, json: &Json) {
+            match type_code(name) {
+                9..=11 => {
+                    let (name, value) = json.as_object().unwrap().iter().next().unwrap();
+                    self.encode_value(name, value)
+                }
+                _ => self.encode_value(name, json),
+            }
+        }
+
+        fn encode_value(&mut self, name: &str, json: &Json) {
+            match type_code(name) {
+                2 => self.0.push(2 - json.as_bool().unwrap() as u8),
+                3 => self.0.push(json.as_i64().unwrap() as u8),
+                4..=6 => self.zig_zag(json.as_i64().unwrap()),
+                7 => self
+                    .0
+                    .extend_from_slice(&json.as_f64().unwrap().to_le_bytes()),
+                8 => {
+                    let bytes = match json {
+                        Json::String(s) => s.as_bytes().to_vec(),
+                        _ => {
+                            let hex = json["hex"].as_str().unwrap();
+                            (0..hex.len())
+                                .step_by(2)
+                                .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+                                .collect()
+                        }
+                    };
+                    self.vlq(bytes.len() as u64);
+                    self.0.extend_from_slice(&bytes);
+                }
+                9 | 10 => {
+                    let element = type_params(name)[0];
+                    let values = json.as_array().unwrap();
+                    match values.len() {
+                        len @ 0..15 => self.0.push((len as u8) << 4 | type_code(element)),
+                        len => {
+                            self.0.push(0xf0 | type_code(element));
+                            self.vlq(len as u64);
+                        }
+                    }
+                    for value in values {
+                        self.encode_element(element, value);
+                    }
+                }
+                11 => {
+                    let entries = json.as_array().unwrap();
+                    self.vlq(entries.len() as u64);
+                    if !entries.is_empty() {
+                        let params = type_params(name);
+                        self.0
+                            .push(type_code(params[0]) << 4 | type_code(params[1]));
+                        for entry in entries {
+                            self.encode_element(params[0], &entry[0]);
+                            self.encode_element(params[1], &entry[1]);
+                        }
+                    }
+                }
+                _ => self.encode_struct(json.as_object().unwrap()),
+            }
+        }
+    }
+
+    fn encode(json: &Json) -> Vec<u8> {
+        let mut encoder = Encoder::default();
+        encoder.encode_struct(json.as_object().unwrap());
+        encoder.0
+    }
+
+    /// Writes a file with statistics, page indexes and duplicated key value metadata
+    fn write_fixture() -> Bytes {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "id",
+                Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef,
+            ),
+            (
+                "name",
+                Arc::new(StringArray::from_iter(
+                    (0..100).map(|i| (i % 7 != 0).then(|| format!("secret_{i}"))),
+                )) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_statistics_enabled(EnabledStatistics::Page)
+            .set_data_page_row_count_limit(30)
+            .set_write_batch_size(10)
+            .set_max_row_group_row_count(Some(60))
+            .set_key_value_metadata(Some(vec![
+                KeyValue::new("dup".to_string(), "a".to_string()),
+                KeyValue::new("dup".to_string(), "b".to_string()),
+            ]))
+            .build();
+        let mut out = vec![];
+        let mut writer = ArrowWriter::try_new(&mut out, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        out.into()
+    }
+
+    #[test]
+    fn test_round_trip_footer() {
+        let file = write_fixture();
+        let footer = dump_footer(&file, false, true).unwrap();
+        let json = serde_json::to_value(&footer).unwrap();
+
+        // Re-encoding the output reproduces the footer exactly
+        let len = footer.metadata_length;
+        let metadata = &file[file.len() - 8 - len..file.len() - 8];
+        assert_eq!(encode(&json["file_metadata"]), metadata);
+
+        // As well as each page index
+        let page_indexes = json["page_indexes"].as_array().unwrap();
+        assert_eq!(page_indexes.len(), 2);
+        let metadata = ParquetMetaDataReader::new()
+            .with_page_index_policy(PageIndexPolicy::Required)
+            .parse_and_finish(&file)
+            .unwrap();
+        for (row_group, indexes) in metadata.row_groups().iter().zip(page_indexes) {
+            for (column, indexes) in row_group.columns().iter().zip(indexes.as_array().unwrap()) {
+                let range = column.column_index_range().unwrap();
+                let expected = &file[range.start as usize..range.end as usize];
+                assert_eq!(encode(&indexes["column_index"]), expected);
+
+                let range = column.offset_index_range().unwrap();
+                let expected = &file[range.start as usize..range.end as usize];
+                assert_eq!(encode(&indexes["offset_index"]), expected);
+            }
+        }
+
+        // Duplicated key value metadata is preserved
+        let kv = json["file_metadata"]["5:key_value_metadata:list<struct>"]
+            .as_array()
+            .unwrap();
+        let dups: Vec<_> = kv
+            .iter()
+            .filter(|kv| kv["1:key:binary"] == "dup")
+            .map(|kv| kv["2:value:binary"].as_str().unwrap())
+            .collect();
+        assert_eq!(dups, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_stable_output() {
+        let file = write_fixture();
+        let a = serde_json::to_string_pretty(&dump_footer(&file, false, true).unwrap()).unwrap();
+        let b = serde_json::to_string_pretty(&dump_footer(&file, false, true).unwrap()).unwrap();
+        assert_eq!(a, b);
+
+        // Fields are printed in the order they are encoded, not sorted as strings
+        let version = a.find("\"1:version:i32\"").unwrap();
+        let schema = a.find("\"2:schema:list<struct>\"").unwrap();
+        let row_groups = a.find("\"4:row_groups:list<struct>\"").unwrap();
+        let created_by = a.find("\"6:created_by:binary\"").unwrap();
+        assert!(version < schema && schema < row_groups && row_groups < created_by);
+    }
+
+    #[test]
+    fn test_redact_statistics() {
+        let file = write_fixture();
+        let plain = serde_json::to_string(&dump_footer(&file, false, true).unwrap()).unwrap();
+        assert!(plain.contains("secret_1"));
+
+        let footer = dump_footer(&file, true, true).unwrap();
+        let redacted = serde_json::to_string(&footer).unwrap();
+        assert!(!redacted.contains("secret_"), "{redacted}");
+        assert!(redacted.contains("\"<redacted>\""));
+
+        let json = serde_json::to_value(&footer).unwrap();
+        let column = &json["file_metadata"]["4:row_groups:list<struct>"][0]["1:columns:list<struct>"]
+            [1]["3:meta_data:struct"];
+        let statistics = &column["12:statistics:struct"];
+        assert_eq!(statistics["5:max_value:binary"], "<redacted>");
+        assert_eq!(statistics["6:min_value:binary"], "<redacted>");
+        // Other statistics are kept
+        assert_eq!(statistics["3:null_count:i64"], 9);
+
+        // Each page's values in the column index are redacted
+        let column_index = &json["page_indexes"][0][1]["column_index"];
+        let min_values = column_index["2:min_values:list<binary>"]
+            .as_array()
+            .unwrap();
+        assert_eq!(min_values.len(), 2);
+        assert!(min_values.iter().all(|v| v == "<redacted>"));
+        assert_eq!(
+            column_index["1:null_pages:list<bool>"]
+                .as_array()
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_skip_page_indexes() {
+        let file = write_fixture();
+        let json = serde_json::to_value(dump_footer(&file, false, false).unwrap()).unwrap();
+        assert!(json.get("page_indexes").is_none());
+        assert!(json.get("trailing_bytes").is_none());
+    }
+
+    #[test]
+    fn test_unknown_fields() {
+        let buf = [
+            // field 1, i32 2
+            0x15, 0x04, //
+            // field 10, bool true
+            0x91, //
+            // field 100, a list containing a list of the i64s 5 and -1
+            0x09, 0xc8, 0x01, 0x19, 0x26, 0x0a, 0x01, //
+            // stop
+            0x00, //
+        ];
+        let (decoded, trailing) = decode(&buf, &FILE_META_DATA, false).unwrap();
+        assert_eq!(trailing, 0);
+        let json = serde_json::to_string(&decoded).unwrap();
+        assert_eq!(
+            json,
+            r#"{"1:version:i32":2,"10:unknown:bool":true,"100:unknown:list<list>":[{"list<i64>":[5,-1]}]}"#
+        );
+        assert_eq!(encode(&serde_json::from_str(&json).unwrap()), buf);
+
+        let err = decode(&buf[..5], &FILE_META_DATA, false).unwrap_err();
+        assert_eq!(err.to_string(), "EOF: unexpected end of thrift data");
+    }
+
+    #[test]
+    fn test_invalid_footer() {
+        let err = dump_footer(&Bytes::from_static(b"PAR1"), false, false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: file size is smaller than the parquet footer"
+        );
+
+        let err = dump_footer(
+            &Bytes::from_static(b"PAR1\x00\x00\x00\x00PARE"),
+            false,
+            false,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: files with an encrypted footer are not supported"
+        );
+
+        let err = dump_footer(
+            &Bytes::from_static(b"PAR1\xff\x00\x00\x00PAR1"),
+            false,
+            false,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: invalid metadata length 255 for a file of 12 bytes"
+        );
+    }
+}
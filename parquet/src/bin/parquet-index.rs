@@ -19,7 +19,7 @@
 //!
 //! # Install
 //!
-//! `parquet-layout` can be installed using `cargo`:
+//! `parquet-index` can be installed using `cargo`:
 //! ```
 //! cargo install parquet --features=cli
 //! ```
@@ -28,21 +28,26 @@
 //! parquet-index XYZ.parquet COLUMN_NAME
 //! ```
 //!
+//! The column can be given by name or dot separated path, and if omitted the page index
+//! of all columns is printed. Use `--output json` to print the page index as JSON.
+//!
 //! The binary can also be built from the source code and run as follows:
 //! ```
 //! cargo run --features=cli --bin parquet-index XYZ.parquet COLUMN_NAME
+//! ```
 //!
 //! [page index]: https://github.com/apache/parquet-format/blob/master/PageIndex.md
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use parquet::data_type::ByteArray;
 use parquet::errors::{ParquetError, Result};
 use parquet::file::page_index::column_index::{
     ByteArrayColumnIndex, ColumnIndexMetaData, PrimitiveColumnIndex,
 };
 use parquet::file::page_index::offset_index::{OffsetIndexMetaData, PageLocation};
-use parquet::file::reader::{FileReader, SerializedFileReader};
+use parquet::file::reader::{ChunkReader, FileReader, SerializedFileReader};
 use parquet::file::serialized_reader::ReadOptionsBuilder;
+use serde::Serialize;
 use std::fs::File;
 
 #[derive(Debug, Parser)]
@@ -51,72 +56,108 @@ struct Args {
     #[clap(help("Path to a parquet file"))]
     file: String,
 
-    #[clap(help("Column name to print"))]
+    #[clap(help("Column name or dot separated path to print, prints all columns if omitted"))]
+    column: Option<String>,
+
+    #[clap(value_enum, short, long, default_value_t = Output::Text, help("Output format"))]
+    output: Output,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq)]
+enum Output {
+    Text,
+    Json,
+}
+
+/// The page index of a column chunk
+#[derive(Debug, Serialize)]
+struct ColumnChunkIndex {
+    row_group: usize,
     column: String,
+    /// Whether the column chunk has a column index, if not the pages have no statistics
+    has_column_index: bool,
+    pages: Vec<PageIndex>,
+}
+
+/// The page index entries of a page
+#[derive(Debug, Serialize)]
+struct PageIndex {
+    offset: i64,
+    compressed_page_size: i32,
+    first_row_index: i64,
+    row_count: i64,
+    null_count: Option<i64>,
+    min: Option<String>,
+    max: Option<String>,
 }
 
 impl Args {
     fn run(&self) -> Result<()> {
         let file = File::open(&self.file)?;
+        let chunks = self.read_index(file)?;
+        match self.output {
+            Output::Text => print_text(&chunks),
+            Output::Json => {
+                serde_json::to_writer_pretty(std::io::stdout().lock(), &chunks)
+                    .map_err(|e| ParquetError::External(Box::new(e)))?;
+                println!();
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the page index of the selected columns of `file`
+    fn read_index<R: ChunkReader + 'static>(&self, file: R) -> Result<Vec<ColumnChunkIndex>> {
         let options = ReadOptionsBuilder::new().with_page_index().build();
         let reader = SerializedFileReader::new_with_options(file, options)?;
+        let metadata = reader.metadata();
 
-        let schema = reader.metadata().file_metadata().schema_descr();
-        let column_idx = schema
-            .columns()
-            .iter()
-            .position(|x| x.name() == self.column.as_str())
-            .ok_or_else(|| {
-                ParquetError::General(format!("Failed to find column {}", self.column))
-            })?;
-
-        // Column index data for all row groups and columns
-        let column_index = reader
-            .metadata()
-            .column_index()
-            .ok_or_else(|| ParquetError::General("Column index not found".to_string()))?;
+        let schema = metadata.file_metadata().schema_descr();
+        let columns: Vec<usize> = match &self.column {
+            Some(column) => {
+                let column_idx = schema
+                    .columns()
+                    .iter()
+                    .position(|x| x.name() == column.as_str() || x.path().string() == *column)
+                    .ok_or_else(|| {
+                        ParquetError::General(format!("Failed to find column {column}"))
+                    })?;
+                vec![column_idx]
+            }
+            None => (0..schema.num_columns()).collect(),
+        };
 
         // Offset index data for all row groups and columns
-        let offset_index = reader
-            .metadata()
+        let offset_index = metadata
             .offset_index()
             .ok_or_else(|| ParquetError::General("Offset index not found".to_string()))?;
 
-        // Iterate through each row group
-        for (row_group_idx, ((column_indices, offset_indices), row_group)) in column_index
-            .iter()
-            .zip(offset_index)
-            .zip(reader.metadata().row_groups())
-            .enumerate()
+        let mut chunks = vec![];
+        for (row_group_idx, (offset_indices, row_group)) in
+            offset_index.iter().zip(metadata.row_groups()).enumerate()
         {
-            println!("Row Group: {row_group_idx}");
-            let offset_index = offset_indices.get(column_idx).ok_or_else(|| {
-                ParquetError::General(format!(
-                    "No offset index for row group {row_group_idx} column chunk {column_idx}"
-                ))
-            })?;
-
-            let row_counts =
-                compute_row_counts(offset_index.page_locations.as_slice(), row_group.num_rows());
-            match &column_indices[column_idx] {
-                ColumnIndexMetaData::NONE => println!("NO INDEX"),
-                ColumnIndexMetaData::BOOLEAN(v) => {
-                    print_index::<bool>(v, offset_index, &row_counts)?
-                }
-                ColumnIndexMetaData::INT32(v) => print_index(v, offset_index, &row_counts)?,
-                ColumnIndexMetaData::INT64(v) => print_index(v, offset_index, &row_counts)?,
-                ColumnIndexMetaData::INT96(v) => print_index(v, offset_index, &row_counts)?,
-                ColumnIndexMetaData::FLOAT(v) => print_index(v, offset_index, &row_counts)?,
-                ColumnIndexMetaData::DOUBLE(v) => print_index(v, offset_index, &row_counts)?,
-                ColumnIndexMetaData::BYTE_ARRAY(v) => {
-                    print_bytes_index(v, offset_index, &row_counts)?
-                }
-                ColumnIndexMetaData::FIXED_LEN_BYTE_ARRAY(v) => {
-                    print_bytes_index(v, offset_index, &row_counts)?
-                }
+            for &column_idx in &columns {
+                let offset_index = offset_indices.get(column_idx).ok_or_else(|| {
+                    ParquetError::General(format!(
+                        "No offset index for row group {row_group_idx} column chunk {column_idx}"
+                    ))
+                })?;
+                // Column index data, if present
+                let column_index = metadata
+                    .column_index()
+                    .and_then(|c| c.get(row_group_idx))
+                    .and_then(|c| c.get(column_idx))
+                    .unwrap_or(&ColumnIndexMetaData::NONE);
+
+                chunks.push(ColumnChunkIndex {
+                    row_group: row_group_idx,
+                    column: schema.column(column_idx).path().string(),
+                    has_column_index: !matches!(column_index, ColumnIndexMetaData::NONE),
+                    pages: page_indexes(column_index, offset_index, row_group.num_rows())?,
+                });
             }
         }
-        Ok(())
+        Ok(chunks)
     }
 }
 
@@ -136,91 +177,200 @@ fn compute_row_counts(offset_index: &[PageLocation], rows: i64) -> Vec<i64> {
     out
 }
 
-/// Prints index information for a single column chunk
-fn print_index<T: std::fmt::Display>(
-    column_index: &PrimitiveColumnIndex<T>,
+/// Returns the page index entries for each page of a column chunk
+fn page_indexes(
+    column_index: &ColumnIndexMetaData,
     offset_index: &OffsetIndexMetaData,
-    row_counts: &[i64],
-) -> Result<()> {
-    if column_index.num_pages() as usize != offset_index.page_locations.len() {
+    rows: i64,
+) -> Result<Vec<PageIndex>> {
+    let locations = offset_index.page_locations();
+    let (min, max) = match column_index {
+        ColumnIndexMetaData::NONE => (vec![None; locations.len()], vec![None; locations.len()]),
+        ColumnIndexMetaData::BOOLEAN(v) => primitive_min_max(v),
+        ColumnIndexMetaData::INT32(v) => primitive_min_max(v),
+        ColumnIndexMetaData::INT64(v) => primitive_min_max(v),
+        ColumnIndexMetaData::INT96(v) => primitive_min_max(v),
+        ColumnIndexMetaData::FLOAT(v) => primitive_min_max(v),
+        ColumnIndexMetaData::DOUBLE(v) => primitive_min_max(v),
+        ColumnIndexMetaData::BYTE_ARRAY(v) => bytes_min_max(v),
+        ColumnIndexMetaData::FIXED_LEN_BYTE_ARRAY(v) => bytes_min_max(v),
+    };
+
+    if min.len() != locations.len() {
         return Err(ParquetError::General(format!(
             "Index length mismatch, got {} and {}",
-            column_index.num_pages(),
-            offset_index.page_locations.len()
+            min.len(),
+            locations.len()
         )));
     }
 
-    for (idx, (((min, max), o), row_count)) in column_index
-        .min_values_iter()
-        .zip(column_index.max_values_iter())
-        .zip(offset_index.page_locations())
+    let row_counts = compute_row_counts(locations, rows);
+    Ok(locations
+        .iter()
         .zip(row_counts)
+        .zip(min.into_iter().zip(max))
         .enumerate()
-    {
-        print!(
-            "Page {:>5} at offset {:#010x} with length {:>10} and row count {:>10}",
-            idx, o.offset, o.compressed_page_size, row_count
-        );
-        match min {
-            Some(m) => print!(", min {m:>10}"),
-            None => print!(", min {:>10}", "NONE"),
-        }
+        .map(|(idx, ((location, row_count), (min, max)))| PageIndex {
+            offset: location.offset,
+            compressed_page_size: location.compressed_page_size,
+            first_row_index: location.first_row_index,
+            row_count,
+            null_count: column_index.null_count(idx),
+            min,
+            max,
+        })
+        .collect())
+}
 
-        match max {
-            Some(m) => print!(", max {m:>10}"),
-            None => print!(", max {:>10}", "NONE"),
-        }
-        println!()
-    }
+type MinMax = (Vec<Option<String>>, Vec<Option<String>>);
 
-    Ok(())
+fn primitive_min_max<T: std::fmt::Display>(column_index: &PrimitiveColumnIndex<T>) -> MinMax {
+    let to_string = |v: Option<&T>| v.map(|v| v.to_string());
+    (
+        column_index.min_values_iter().map(to_string).collect(),
+        column_index.max_values_iter().map(to_string).collect(),
+    )
 }
 
-fn print_bytes_index(
-    column_index: &ByteArrayColumnIndex,
-    offset_index: &OffsetIndexMetaData,
-    row_counts: &[i64],
-) -> Result<()> {
-    if column_index.num_pages() as usize != offset_index.page_locations.len() {
-        return Err(ParquetError::General(format!(
-            "Index length mismatch, got {} and {}",
-            column_index.num_pages(),
-            offset_index.page_locations.len()
-        )));
-    }
+fn bytes_min_max(column_index: &ByteArrayColumnIndex) -> MinMax {
+    let to_string = |v: Option<&[u8]>| {
+        v.map(|v| match std::str::from_utf8(v) {
+            Ok(s) => s.to_string(),
+            Err(_) => ByteArray::from(v).to_string(),
+        })
+    };
+    (
+        column_index.min_values_iter().map(to_string).collect(),
+        column_index.max_values_iter().map(to_string).collect(),
+    )
+}
 
-    for (idx, (((min, max), o), row_count)) in column_index
-        .min_values_iter()
-        .zip(column_index.max_values_iter())
-        .zip(offset_index.page_locations())
-        .zip(row_counts)
-        .enumerate()
-    {
-        print!(
-            "Page {:>5} at offset {:#010x} with length {:>10} and row count {:>10}",
-            idx, o.offset, o.compressed_page_size, row_count
-        );
-        match min {
-            Some(m) => match String::from_utf8(m.to_vec()) {
-                Ok(s) => print!(", min {s:>10}"),
-                Err(_) => print!(", min {:>10}", ByteArray::from(m)),
-            },
-            None => print!(", min {:>10}", "NONE"),
+fn print_text(chunks: &[ColumnChunkIndex]) {
+    let mut row_group = None;
+    for chunk in chunks {
+        if row_group != Some(chunk.row_group) {
+            println!("Row Group: {}", chunk.row_group);
+            row_group = Some(chunk.row_group);
+        }
+        match chunk.has_column_index {
+            true => println!("Column: {}", chunk.column),
+            false => println!("Column: {} (NO COLUMN INDEX)", chunk.column),
         }
 
-        match max {
-            Some(m) => match String::from_utf8(m.to_vec()) {
-                Ok(s) => print!(", max {s:>10}"),
-                Err(_) => print!(", min {:>10}", ByteArray::from(m)),
-            },
-            None => print!(", max {:>10}", "NONE"),
+        for (idx, page) in chunk.pages.iter().enumerate() {
+            print!(
+                "Page {:>5} at offset {:#010x} with length {:>10} and row count {:>10}",
+                idx, page.offset, page.compressed_page_size, page.row_count
+            );
+            if chunk.has_column_index {
+                let none = "NONE".to_string();
+                match page.null_count {
+                    Some(n) => print!(", null count {n:>10}"),
+                    None => print!(", null count {none:>10}"),
+                }
+                print!(", min {:>10}", page.min.as_ref().unwrap_or(&none));
+                print!(", max {:>10}", page.max.as_ref().unwrap_or(&none));
+            }
+            println!()
         }
-        println!()
     }
-
-    Ok(())
 }
 
 fn main() -> Result<()> {
     Args::parse().run()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+    use bytes::Bytes;
+    use parquet::arrow::ArrowWriter;
+    use parquet::file::properties::WriterProperties;
+    use std::sync::Arc;
+
+    fn test_file() -> Bytes {
+        let ints = Int32Array::from_iter((0..25).map(|i| (i % 4 != 0).then_some(i)));
+        let strings = StringArray::from_iter_values((0..25).map(|i| format!("s{i:02}")));
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(ints) as ArrayRef),
+            ("b", Arc::new(strings) as ArrayRef),
+        ])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_data_page_row_count_limit(10)
+            .set_write_batch_size(10)
+            .set_max_row_group_row_count(Some(20))
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        Bytes::from(buf)
+    }
+
+    fn args(args: &[&str]) -> Args {
+        Args::try_parse_from([&["parquet-index", "file"], args].concat()).unwrap()
+    }
+
+    #[test]
+    fn test_read_index() {
+        let chunks = args(&["b"]).read_index(test_file()).unwrap();
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks.iter().all(|c| c.column == "b" && c.has_column_index));
+
+        let pages = &chunks[0].pages;
+        let summary: Vec<_> = pages
+            .iter()
+            .map(|p| {
+                (
+                    p.first_row_index,
+                    p.row_count,
+                    p.min.as_deref(),
+                    p.max.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (0, 10, Some("s00"), Some("s09")),
+                (10, 10, Some("s10"), Some("s19"))
+            ]
+        );
+        assert_eq!(chunks[1].pages.len(), 1);
+        assert_eq!(chunks[1].pages[0].row_count, 5);
+
+        let chunks = args(&[]).read_index(test_file()).unwrap();
+        let columns: Vec<_> = chunks
+            .iter()
+            .map(|c| (c.row_group, c.column.as_str()))
+            .collect();
+        assert_eq!(columns, vec![(0, "a"), (0, "b"), (1, "a"), (1, "b")]);
+
+        let null_counts: Vec<_> = chunks[0].pages.iter().map(|p| p.null_count).collect();
+        assert_eq!(null_counts, vec![Some(3), Some(2)]);
+        assert_eq!(chunks[0].pages[0].min.as_deref(), Some("1"));
+        assert_eq!(chunks[0].pages[0].max.as_deref(), Some("9"));
+
+        let err = args(&["c"]).read_index(test_file()).unwrap_err();
+        assert_eq!(err.to_string(), "Parquet error: Failed to find column c");
+    }
+
+    #[test]
+    fn test_read_index_json() {
+        let chunks = args(&["a", "--output", "json"])
+            .read_index(test_file())
+            .unwrap();
+        let json = serde_json::to_value(&chunks[1]).unwrap();
+        assert_eq!(json["row_group"], 1);
+        assert_eq!(json["column"], "a");
+        assert_eq!(json["has_column_index"], true);
+        let page = &json["pages"][0];
+        assert_eq!(page["first_row_index"], 0);
+        assert_eq!(page["row_count"], 5);
+        assert_eq!(page["null_count"], 2);
+        assert_eq!(page["min"], "21");
+        assert_eq!(page["max"], "23");
+    }
+}
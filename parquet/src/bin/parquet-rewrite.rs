@@ -32,19 +32,36 @@
 //! ```
 //! cargo run --features=cli --bin parquet-rewrite -- -i XYZ.parquet -o XYZ2.parquet
 //! ```
+//!
+//! Options can be set for individual columns, identified by their dot separated path:
+//! ```
+//! parquet-rewrite -i XYZ.parquet -o XYZ2.parquet --column-dictionary-enabled a.b=false
+//! ```
+//!
+//! If no option requires the data to be encoded again, for example when only dropping
+//! bloom filters, the column chunks are copied to the output without being decoded.
 
 use std::fs::File;
+use std::sync::Arc;
 
 use arrow_array::RecordBatchReader;
 use clap::{Parser, ValueEnum, builder::PossibleValue};
 use parquet::{
     arrow::{ArrowWriter, arrow_reader::ParquetRecordBatchReaderBuilder},
     basic::{BrotliLevel, Compression, Encoding, GzipLevel, ZstdLevel},
+    bloom_filter::Sbbf,
+    column::writer::ColumnCloseResult,
     file::{
-        properties::{BloomFilterPosition, EnabledStatistics, WriterProperties, WriterVersion},
+        metadata::{PageIndexPolicy, ParquetMetaDataReader},
+        properties::{
+            BloomFilterPosition, EnabledStatistics, WriterProperties, WriterPropertiesBuilder,
+            WriterVersion,
+        },
         reader::FileReader,
         serialized_reader::SerializedFileReader,
+        writer::SerializedFileWriter,
     },
+    schema::types::ColumnPath,
 };
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
@@ -217,6 +234,18 @@ impl From<BloomFilterPositionArgs> for BloomFilterPosition {
     }
 }
 
+/// Parses a `<COLUMN>=<BOOL>` column option, where `<COLUMN>` is a dot separated path
+fn parse_column_flag(value: &str) -> Result<(ColumnPath, bool), String> {
+    let (column, flag) = value
+        .rsplit_once('=')
+        .ok_or_else(|| format!("expected <COLUMN>=<BOOL>, got {value}"))?;
+    let flag = flag
+        .parse()
+        .map_err(|_| format!("expected true or false, got {flag}"))?;
+    let path = column.split('.').map(String::from).collect();
+    Ok((ColumnPath::new(path), flag))
+}
+
 #[derive(Debug, Parser)]
 #[clap(author, version, about("Read and write parquet file with potentially different settings"), long_about = None)]
 struct Args {
@@ -244,6 +273,12 @@ struct Args {
     #[clap(long)]
     dictionary_enabled: Option<bool>,
 
+    /// Sets flag to enable/disable dictionary encoding for a column, as `<COLUMN>=<BOOL>`.
+    ///
+    /// Takes precedence over `--dictionary-enabled`, and may be repeated.
+    #[clap(long, value_parser = parse_column_flag)]
+    column_dictionary_enabled: Vec<(ColumnPath, bool)>,
+
     /// Sets best effort maximum dictionary page size, in bytes.
     #[clap(long)]
     dictionary_page_size_limit: Option<usize>,
@@ -280,14 +315,22 @@ struct Args {
     write_page_header_statistics: Option<bool>,
 
     /// Sets whether bloom filter is enabled for all columns.
+    ///
+    /// If false, any bloom filters in the input are dropped.
     #[clap(long)]
     bloom_filter_enabled: Option<bool>,
 
-    /// Sets bloom filter false positive probability (fpp) for all columns.
+    /// Sets whether bloom filter is enabled for a column, as `<COLUMN>=<BOOL>`.
+    ///
+    /// Takes precedence over `--bloom-filter-enabled`, and may be repeated.
+    #[clap(long, value_parser = parse_column_flag)]
+    column_bloom_filter_enabled: Vec<(ColumnPath, bool)>,
+
+    /// Sets bloom filter false positive probability (fpp) for all columns with bloom filters enabled.
     #[clap(long)]
     bloom_filter_fpp: Option<f64>,
 
-    /// Sets number of distinct values (ndv) for bloom filter for all columns.
+    /// Sets number of distinct values (ndv) for bloom filter for all columns with bloom filters enabled.
     #[clap(long)]
     bloom_filter_ndv: Option<u64>,
 
@@ -312,9 +355,248 @@ struct Args {
     coerce_types: Option<bool>,
 }
 
-fn main() {
-    let args = Args::parse();
+impl Args {
+    /// Returns true if the column chunks of the input can be copied to the output
+    /// without being decoded, as no option requires them to be encoded again
+    fn can_copy_column_chunks(&self) -> bool {
+        let Self {
+            input: _,
+            output: _,
+            compression,
+            compression_level,
+            encoding,
+            dictionary_enabled,
+            column_dictionary_enabled,
+            dictionary_page_size_limit,
+            max_row_group_size,
+            data_page_row_count_limit,
+            data_page_size_limit,
+            statistics_truncate_length,
+            column_index_truncate_length,
+            write_page_header_statistics,
+            bloom_filter_enabled,
+            column_bloom_filter_enabled,
+            // Only used when computing new bloom filters
+            bloom_filter_fpp: _,
+            bloom_filter_ndv: _,
+            bloom_filter_position: _,
+            statistics_enabled,
+            writer_version,
+            write_batch_size,
+            coerce_types,
+        } = self;
+
+        compression.is_none()
+            && compression_level.is_none()
+            && encoding.is_none()
+            && dictionary_enabled.is_none()
+            && column_dictionary_enabled.is_empty()
+            && dictionary_page_size_limit.is_none()
+            && max_row_group_size.is_none()
+            && data_page_row_count_limit.is_none()
+            && data_page_size_limit.is_none()
+            && statistics_truncate_length.is_none()
+            && column_index_truncate_length.is_none()
+            && write_page_header_statistics.is_none()
+            // Bloom filters can be dropped, but adding them requires the data
+            && *bloom_filter_enabled != Some(true)
+            && column_bloom_filter_enabled.iter().all(|(_, enabled)| !enabled)
+            && statistics_enabled.is_none()
+            && writer_version.is_none()
+            && write_batch_size.is_none()
+            && coerce_types.is_none()
+    }
+
+    /// Returns true if the bloom filter of `column` in the input should be kept
+    /// when copying its column chunks
+    fn keep_bloom_filter(&self, column: &ColumnPath) -> bool {
+        let enabled = self
+            .column_bloom_filter_enabled
+            .iter()
+            .rev()
+            .find(|(path, _)| path == column)
+            .map(|(_, enabled)| *enabled);
+        enabled.or(self.bloom_filter_enabled).unwrap_or(true)
+    }
+
+    /// Returns the [`WriterPropertiesBuilder`] configured from these arguments
+    fn writer_properties(&self) -> WriterPropertiesBuilder {
+        let mut writer_properties_builder = WriterProperties::builder();
+
+        if let Some(value) = self.compression {
+            let compression = compression_from_args(value, self.compression_level);
+            writer_properties_builder = writer_properties_builder.set_compression(compression);
+        }
+
+        // setup encoding
+        if let Some(value) = self.encoding {
+            writer_properties_builder = writer_properties_builder.set_encoding(value.into());
+        }
+        if let Some(value) = self.dictionary_enabled {
+            writer_properties_builder = writer_properties_builder.set_dictionary_enabled(value);
+        }
+        for (column, value) in &self.column_dictionary_enabled {
+            writer_properties_builder =
+                writer_properties_builder.set_column_dictionary_enabled(column.clone(), *value);
+        }
+        if let Some(value) = self.dictionary_page_size_limit {
+            writer_properties_builder =
+                writer_properties_builder.set_dictionary_page_size_limit(value);
+        }
+
+        if let Some(value) = self.max_row_group_size {
+            writer_properties_builder =
+                writer_properties_builder.set_max_row_group_row_count(Some(value));
+        }
+        if let Some(value) = self.data_page_row_count_limit {
+            writer_properties_builder =
+                writer_properties_builder.set_data_page_row_count_limit(value);
+        }
+        if let Some(value) = self.data_page_size_limit {
+            writer_properties_builder = writer_properties_builder.set_data_page_size_limit(value);
+        }
+        if let Some(value) = self.dictionary_page_size_limit {
+            writer_properties_builder =
+                writer_properties_builder.set_dictionary_page_size_limit(value);
+        }
+        if let Some(value) = self.statistics_truncate_length {
+            writer_properties_builder =
+                writer_properties_builder.set_statistics_truncate_length(Some(value));
+        }
+        if let Some(value) = self.column_index_truncate_length {
+            writer_properties_builder =
+                writer_properties_builder.set_column_index_truncate_length(Some(value));
+        }
+        if let Some(value) = self.bloom_filter_enabled {
+            writer_properties_builder = writer_properties_builder.set_bloom_filter_enabled(value);
+
+            if value {
+                if let Some(value) = self.bloom_filter_fpp {
+                    writer_properties_builder =
+                        writer_properties_builder.set_bloom_filter_fpp(value);
+                }
+                if let Some(value) = self.bloom_filter_ndv {
+                    writer_properties_builder =
+                        writer_properties_builder.set_bloom_filter_ndv(value);
+                }
+            }
+        }
+        for (column, value) in &self.column_bloom_filter_enabled {
+            writer_properties_builder =
+                writer_properties_builder.set_column_bloom_filter_enabled(column.clone(), *value);
+
+            if *value {
+                if let Some(value) = self.bloom_filter_fpp {
+                    writer_properties_builder = writer_properties_builder
+                        .set_column_bloom_filter_fpp(column.clone(), value);
+                }
+                if let Some(value) = self.bloom_filter_ndv {
+                    writer_properties_builder = writer_properties_builder
+                        .set_column_bloom_filter_ndv(column.clone(), value);
+                }
+            }
+        }
+        if let Some(value) = self.statistics_enabled {
+            writer_properties_builder =
+                writer_properties_builder.set_statistics_enabled(value.into());
+        }
+        // set this after statistics_enabled
+        if let Some(value) = self.write_page_header_statistics {
+            writer_properties_builder =
+                writer_properties_builder.set_write_page_header_statistics(value);
+            if value {
+                writer_properties_builder =
+                    writer_properties_builder.set_statistics_enabled(EnabledStatistics::Page);
+            }
+        }
+        if let Some(value) = self.writer_version {
+            writer_properties_builder = writer_properties_builder.set_writer_version(value.into());
+        }
+        if let Some(value) = self.coerce_types {
+            writer_properties_builder = writer_properties_builder.set_coerce_types(value);
+        }
+        if let Some(value) = self.write_batch_size {
+            writer_properties_builder = writer_properties_builder.set_write_batch_size(value);
+        }
+        if let Some(value) = self.bloom_filter_position {
+            writer_properties_builder =
+                writer_properties_builder.set_bloom_filter_position(value.into());
+        }
+        writer_properties_builder
+    }
+}
+
+/// Rewrite the input by decoding it and encoding it again with the new properties
+fn rewrite(args: &Args, writer_properties: WriterProperties) {
+    let parquet_reader = ParquetRecordBatchReaderBuilder::try_new(
+        File::open(&args.input).expect("Unable to open input file"),
+    )
+    .expect("parquet open")
+    .build()
+    .expect("parquet open");
 
+    let mut parquet_writer = ArrowWriter::try_new(
+        File::create(&args.output).expect("Unable to open output file"),
+        parquet_reader.schema(),
+        Some(writer_properties),
+    )
+    .expect("create arrow writer");
+
+    for maybe_batch in parquet_reader {
+        let batch = maybe_batch.expect("reading batch");
+        parquet_writer.write(&batch).expect("writing data");
+    }
+
+    parquet_writer.close().expect("finalizing file");
+}
+
+/// Rewrite the input by copying its column chunks, without decoding them
+fn copy_column_chunks(args: &Args, writer_properties: WriterProperties) {
+    let input = File::open(&args.input).expect("Unable to open input file");
+    // Enable reading page indexes if present
+    let metadata = ParquetMetaDataReader::new()
+        .with_page_index_policy(PageIndexPolicy::Optional)
+        .parse_and_finish(&input)
+        .expect("read metadata");
+
+    let schema = metadata.file_metadata().schema_descr().root_schema_ptr();
+    let mut writer = SerializedFileWriter::new(
+        File::create(&args.output).expect("Unable to open output file"),
+        schema,
+        Arc::new(writer_properties),
+    )
+    .expect("create file writer");
+
+    let column_indexes = metadata.column_index();
+    let offset_indexes = metadata.offset_index();
+    for (rg_idx, rg) in metadata.row_groups().iter().enumerate() {
+        let rg_column_indexes = column_indexes.and_then(|ci| ci.get(rg_idx));
+        let rg_offset_indexes = offset_indexes.and_then(|oi| oi.get(rg_idx));
+        let mut rg_out = writer.next_row_group().expect("create row group");
+        for (col_idx, column) in rg.columns().iter().enumerate() {
+            let bloom_filter = match args.keep_bloom_filter(column.column_path()) {
+                true => Sbbf::read_from_column_chunk(column, &input).expect("read bloom filter"),
+                false => None,
+            };
+            let result = ColumnCloseResult {
+                bytes_written: column.compressed_size() as _,
+                rows_written: rg.num_rows() as _,
+                metadata: column.clone(),
+                bloom_filter,
+                column_index: rg_column_indexes.and_then(|row| row.get(col_idx)).cloned(),
+                offset_index: rg_offset_indexes.and_then(|row| row.get(col_idx)).cloned(),
+            };
+            rg_out
+                .append_column(&input, result)
+                .expect("copy column chunk");
+        }
+        rg_out.close().expect("close row group");
+    }
+
+    writer.close().expect("finalizing file");
+}
+
+fn run(args: &Args) {
     // read key-value metadata
     let parquet_reader =
         SerializedFileReader::new(File::open(&args.input).expect("Unable to open input file"))
@@ -325,102 +607,201 @@ fn main() {
         .key_value_metadata()
         .cloned();
 
-    // create actual parquet reader
-    let parquet_reader = ParquetRecordBatchReaderBuilder::try_new(
-        File::open(args.input).expect("Unable to open input file"),
-    )
-    .expect("parquet open")
-    .build()
-    .expect("parquet open");
+    let writer_properties = args
+        .writer_properties()
+        .set_key_value_metadata(kv_md)
+        .build();
+    match args.can_copy_column_chunks() {
+        true => copy_column_chunks(args, writer_properties),
+        false => rewrite(args, writer_properties),
+    }
+}
 
-    let mut writer_properties_builder = WriterProperties::builder().set_key_value_metadata(kv_md);
+fn main() {
+    run(&Args::parse())
+}
 
-    if let Some(value) = args.compression {
-        let compression = compression_from_args(value, args.compression_level);
-        writer_properties_builder = writer_properties_builder.set_compression(compression);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+    use parquet::basic::Encoding;
+    use parquet::file::metadata::ParquetMetaData;
+    use parquet::file::reader::ChunkReader;
+
+    fn args(args: &[&str]) -> Args {
+        Args::try_parse_from([&["parquet-rewrite", "-i", "in", "-o", "out"], args].concat())
+            .unwrap()
     }
 
-    // setup encoding
-    if let Some(value) = args.encoding {
-        writer_properties_builder = writer_properties_builder.set_encoding(value.into());
-    }
-    if let Some(value) = args.dictionary_enabled {
-        writer_properties_builder = writer_properties_builder.set_dictionary_enabled(value);
-    }
-    if let Some(value) = args.dictionary_page_size_limit {
-        writer_properties_builder = writer_properties_builder.set_dictionary_page_size_limit(value);
+    #[test]
+    fn test_parse_column_flag() {
+        let (path, enabled) = parse_column_flag("a.b=false").unwrap();
+        assert_eq!(
+            path,
+            ColumnPath::new(vec!["a".to_string(), "b".to_string()])
+        );
+        assert!(!enabled);
+
+        let err = parse_column_flag("a").unwrap_err();
+        assert_eq!(err, "expected <COLUMN>=<BOOL>, got a");
+        let err = parse_column_flag("a=yes").unwrap_err();
+        assert_eq!(err, "expected true or false, got yes");
     }
 
-    if let Some(value) = args.max_row_group_size {
-        writer_properties_builder =
-            writer_properties_builder.set_max_row_group_row_count(Some(value));
-    }
-    if let Some(value) = args.data_page_row_count_limit {
-        writer_properties_builder = writer_properties_builder.set_data_page_row_count_limit(value);
-    }
-    if let Some(value) = args.data_page_size_limit {
-        writer_properties_builder = writer_properties_builder.set_data_page_size_limit(value);
+    #[test]
+    fn test_can_copy_column_chunks() {
+        assert!(args(&[]).can_copy_column_chunks());
+        assert!(args(&["--bloom-filter-enabled", "false"]).can_copy_column_chunks());
+        assert!(args(&["--column-bloom-filter-enabled", "a=false"]).can_copy_column_chunks());
+        assert!(args(&["--bloom-filter-position", "end"]).can_copy_column_chunks());
+
+        assert!(!args(&["--bloom-filter-enabled", "true"]).can_copy_column_chunks());
+        assert!(!args(&["--column-bloom-filter-enabled", "a=true"]).can_copy_column_chunks());
+        assert!(!args(&["--column-dictionary-enabled", "a=false"]).can_copy_column_chunks());
+        assert!(!args(&["--compression", "zstd"]).can_copy_column_chunks());
+        assert!(!args(&["--max-row-group-size", "10"]).can_copy_column_chunks());
+
+        let args = args(&[
+            "--bloom-filter-enabled",
+            "false",
+            "--column-bloom-filter-enabled",
+            "b=true",
+            "--column-bloom-filter-enabled",
+            "c.d=false",
+        ]);
+        assert!(!args.keep_bloom_filter(&ColumnPath::from("a")));
+        assert!(args.keep_bloom_filter(&ColumnPath::from("b")));
+        assert!(!args.keep_bloom_filter(&ColumnPath::new(vec!["c".into(), "d".into()])));
     }
-    if let Some(value) = args.dictionary_page_size_limit {
-        writer_properties_builder = writer_properties_builder.set_dictionary_page_size_limit(value);
+
+    /// Writes a file with columns `a` and `b` and bloom filters to `path`
+    fn write_input(path: &std::path::Path) -> RecordBatch {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(StringArray::from_iter_values(
+                    (0..100).map(|i| format!("{}", i % 5)),
+                )),
+            ),
+        ])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_bloom_filter_enabled(true)
+            .set_max_row_group_row_count(Some(50))
+            .build();
+        let mut writer =
+            ArrowWriter::try_new(File::create(path).unwrap(), batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        batch
     }
-    if let Some(value) = args.statistics_truncate_length {
-        writer_properties_builder =
-            writer_properties_builder.set_statistics_truncate_length(Some(value));
+
+    /// Returns the data, and the metadata of the file at `path`
+    fn read_output(path: &std::path::Path) -> (RecordBatch, Arc<ParquetMetaData>) {
+        let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(path).unwrap()).unwrap();
+        let metadata = Arc::clone(builder.metadata());
+        let batch = builder.build().unwrap().next().unwrap().unwrap();
+        (batch, metadata)
     }
-    if let Some(value) = args.column_index_truncate_length {
-        writer_properties_builder =
-            writer_properties_builder.set_column_index_truncate_length(Some(value));
+
+    fn has_bloom_filter<R: ChunkReader>(
+        metadata: &ParquetMetaData,
+        column: usize,
+        reader: &R,
+    ) -> bool {
+        metadata.row_groups().iter().all(|rg| {
+            Sbbf::read_from_column_chunk(rg.column(column), reader)
+                .unwrap()
+                .is_some()
+        })
     }
-    if let Some(value) = args.bloom_filter_enabled {
-        writer_properties_builder = writer_properties_builder.set_bloom_filter_enabled(value);
 
-        if value {
-            if let Some(value) = args.bloom_filter_fpp {
-                writer_properties_builder = writer_properties_builder.set_bloom_filter_fpp(value);
-            }
-            if let Some(value) = args.bloom_filter_ndv {
-                writer_properties_builder = writer_properties_builder.set_bloom_filter_ndv(value);
-            }
-            if let Some(value) = args.bloom_filter_position {
-                writer_properties_builder =
-                    writer_properties_builder.set_bloom_filter_position(value.into());
+    #[test]
+    fn test_copy_column_chunks() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("input.parquet");
+        let output = dir.path().join("output.parquet");
+        let batch = write_input(&input);
+
+        let args = Args::try_parse_from([
+            "parquet-rewrite",
+            "-i",
+            input.to_str().unwrap(),
+            "-o",
+            output.to_str().unwrap(),
+            "--column-bloom-filter-enabled",
+            "a=false",
+        ])
+        .unwrap();
+        assert!(args.can_copy_column_chunks());
+        run(&args);
+
+        let (read, metadata) = read_output(&output);
+        assert_eq!(read, batch);
+        assert_eq!(metadata.num_row_groups(), 2);
+        // The column chunks are copied as is
+        let input_metadata = read_output(&input).1;
+        for (rg, input_rg) in metadata
+            .row_groups()
+            .iter()
+            .zip(input_metadata.row_groups())
+        {
+            for (column, input_column) in rg.columns().iter().zip(input_rg.columns()) {
+                assert_eq!(column.compressed_size(), input_column.compressed_size());
             }
         }
-    }
-    if let Some(value) = args.statistics_enabled {
-        writer_properties_builder = writer_properties_builder.set_statistics_enabled(value.into());
-    }
-    // set this after statistics_enabled
-    if let Some(value) = args.write_page_header_statistics {
-        writer_properties_builder =
-            writer_properties_builder.set_write_page_header_statistics(value);
-        if value {
-            writer_properties_builder =
-                writer_properties_builder.set_statistics_enabled(EnabledStatistics::Page);
-        }
-    }
-    if let Some(value) = args.writer_version {
-        writer_properties_builder = writer_properties_builder.set_writer_version(value.into());
-    }
-    if let Some(value) = args.coerce_types {
-        writer_properties_builder = writer_properties_builder.set_coerce_types(value);
-    }
-    if let Some(value) = args.write_batch_size {
-        writer_properties_builder = writer_properties_builder.set_write_batch_size(value);
-    }
-    let writer_properties = writer_properties_builder.build();
-    let mut parquet_writer = ArrowWriter::try_new(
-        File::create(&args.output).expect("Unable to open output file"),
-        parquet_reader.schema(),
-        Some(writer_properties),
-    )
-    .expect("create arrow writer");
 
-    for maybe_batch in parquet_reader {
-        let batch = maybe_batch.expect("reading batch");
-        parquet_writer.write(&batch).expect("writing data");
+        let file = File::open(&output).unwrap();
+        assert!(!has_bloom_filter(&metadata, 0, &file));
+        assert!(has_bloom_filter(&metadata, 1, &file));
     }
 
-    parquet_writer.close().expect("finalizing file");
+    #[test]
+    fn test_rewrite() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("input.parquet");
+        let output = dir.path().join("output.parquet");
+        let batch = write_input(&input);
+
+        let args = Args::try_parse_from([
+            "parquet-rewrite",
+            "-i",
+            input.to_str().unwrap(),
+            "-o",
+            output.to_str().unwrap(),
+            "--max-row-group-size",
+            "100",
+            "--column-dictionary-enabled",
+            "b=false",
+            "--bloom-filter-enabled",
+            "false",
+        ])
+        .unwrap();
+        assert!(!args.can_copy_column_chunks());
+        run(&args);
+
+        let (read, metadata) = read_output(&output);
+        assert_eq!(read, batch);
+        assert_eq!(metadata.num_row_groups(), 1);
+
+        let column = metadata.row_group(0).column(1);
+        assert!(column.dictionary_page_offset().is_none());
+        assert!(!column.encodings().any(|e| e == Encoding::RLE_DICTIONARY));
+        assert!(
+            metadata
+                .row_group(0)
+                .column(0)
+                .dictionary_page_offset()
+                .is_some()
+        );
+
+        let file = File::open(&output).unwrap();
+        assert!(!has_bloom_filter(&metadata, 0, &file));
+        assert!(!has_bloom_filter(&metadata, 1, &file));
+    }
 }
@@ -25,15 +25,21 @@
 //! ```
 //! After this `parquet-show-bloom-filter` should be available:
 //! ```
-//! parquet-show-bloom-filter XYZ.parquet id a
+//! parquet-show-bloom-filter XYZ.parquet --column id a
 //! ```
 //!
 //! The binary can also be built from the source code and run as follows:
 //! ```
-//! cargo run --features=cli --bin parquet-show-bloom-filter -- --file-name XYZ.parquet --column id --values a
+//! cargo run --features=cli --bin parquet-show-bloom-filter -- XYZ.parquet --column id a
+//! ```
+//!
+//! Multiple columns can be checked by repeating `--column`, and `--output json`
+//! prints the results as JSON for use in scripts:
+//! ```
+//! parquet-show-bloom-filter XYZ.parquet --column id --column name --output json a b
 //! ```
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use parquet::basic::Type;
 use parquet::bloom_filter::Sbbf;
 use parquet::file::metadata::ColumnChunkMetaData;
@@ -42,6 +48,7 @@ use parquet::file::{
     reader::{FileReader, SerializedFileReader},
     serialized_reader::ReadOptionsBuilder,
 };
+use serde::Serialize;
 use std::{fs::File, path::Path};
 
 #[derive(Debug, Parser)]
@@ -49,23 +56,50 @@ use std::{fs::File, path::Path};
 struct Args {
     #[clap(help("Path to the parquet file"))]
     file_name: String,
-    #[clap(help(
-        "Check the bloom filter indexes for the given column. Only string typed columns or columns with an Int32 or Int64 physical type are supported"
-    ))]
-    column: String,
+    #[clap(
+        short,
+        long = "column",
+        help(
+            "Check the bloom filter indexes for the given column, can be repeated. Only string typed columns or columns with an Int32, Int64, Float, Double or FixedLenByteArray physical type are supported"
+        ),
+        required = true
+    )]
+    columns: Vec<String>,
     #[clap(
         help(
-            "Check if the given values match bloom filter, the values will be parsed to the physical type of the column"
+            "Check if the given values match bloom filter, the values will be parsed to the physical type of the column. FixedLenByteArray values are given as hex, e.g. 00ff or a UUID"
         ),
         required = true
     )]
     values: Vec<String>,
+    #[clap(value_enum, short, long, default_value_t = Output::Text, help("Output format"))]
+    output: Output,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq)]
+enum Output {
+    Text,
+    Json,
+}
+
+/// The result of checking a value against the bloom filter of a column in a row group
+#[derive(Debug, Serialize)]
+struct ValueCheck<'a> {
+    row_group: usize,
+    column: &'a str,
+    value: &'a str,
+    /// `None` if the value could not be checked, see `error`
+    present: Option<bool>,
+    error: Option<String>,
+    /// The size of the bloom filter bitset in bytes
+    bloom_filter_size: Option<usize>,
+    num_hash_functions: Option<usize>,
 }
 
 fn main() {
     let args = Args::parse();
-    let file_name = args.file_name;
-    let path = Path::new(&file_name);
+    let file_name = &args.file_name;
+    let path = Path::new(file_name);
     let file = File::open(path).expect("Unable to open file");
 
     let file_reader = SerializedFileReader::new_with_options(
@@ -80,49 +114,92 @@ fn main() {
     )
     .expect("Unable to open file as Parquet");
     let metadata = file_reader.metadata();
+    let mut checks = vec![];
     for (ri, row_group) in metadata.row_groups().iter().enumerate() {
-        println!("Row group #{ri}");
-        println!("{}", "=".repeat(80));
-        if let Some((column_index, column)) = row_group
-            .columns()
-            .iter()
-            .enumerate()
-            .find(|(_, column)| column.column_path().string() == args.column)
-        {
-            let row_group_reader = file_reader
-                .get_row_group(ri)
-                .expect("Unable to read row group");
-            if let Some(sbbf) = row_group_reader.get_column_bloom_filter(column_index) {
-                args.values.iter().for_each(|value| {
-                    match check_filter(sbbf, value, column) {
-                        Ok(present) => {
-                            println!(
-                                "Value {} is {} in bloom filter",
-                                value,
-                                if present { "present" } else { "absent" }
-                            )
-                        }
-                        Err(err) => {
-                            println!("{err}");
-                        }
+        if args.output == Output::Text {
+            println!("Row group #{ri}");
+            println!("{}", "=".repeat(80));
+        }
+        let row_group_reader = file_reader
+            .get_row_group(ri)
+            .expect("Unable to read row group");
+        for column_name in &args.columns {
+            let filter = match row_group
+                .columns()
+                .iter()
+                .enumerate()
+                .find(|(_, column)| column.column_path().string() == *column_name)
+            {
+                Some((column_index, column)) => {
+                    match row_group_reader.get_column_bloom_filter(column_index) {
+                        Some(sbbf) => Ok((sbbf, column)),
+                        None => Err(format!("No bloom filter found for column {column_name}")),
+                    }
+                }
+                None => Err(format!(
+                    "No column named {} found, candidate columns are: {}",
+                    column_name,
+                    row_group
+                        .columns()
+                        .iter()
+                        .map(|c| c.column_path().string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+            };
+
+            match args.output {
+                Output::Text => {
+                    if args.columns.len() > 1 {
+                        println!("Column {column_name}");
+                        println!("{}", "-".repeat(80));
+                    }
+                    match &filter {
+                        Ok((sbbf, column)) => args.values.iter().for_each(|value| {
+                            match check_filter(sbbf, value, column) {
+                                Ok(present) => {
+                                    println!(
+                                        "Value {} is {} in bloom filter",
+                                        value,
+                                        if present { "present" } else { "absent" }
+                                    )
+                                }
+                                Err(err) => {
+                                    println!("{err}");
+                                }
+                            };
+                        }),
+                        Err(err) => println!("{err}"),
+                    }
+                }
+                Output::Json => checks.extend(args.values.iter().map(|value| {
+                    let (present, error) = match &filter {
+                        Ok((sbbf, column)) => match check_filter(sbbf, value, column) {
+                            Ok(present) => (Some(present), None),
+                            Err(err) => (None, Some(err)),
+                        },
+                        Err(err) => (None, Some(err.clone())),
                     };
-                });
-            } else {
-                println!("No bloom filter found for column {}", args.column);
+                    let sbbf = filter.as_ref().ok().map(|(sbbf, _)| sbbf);
+                    ValueCheck {
+                        row_group: ri,
+                        column: column_name,
+                        value,
+                        present,
+                        error,
+                        bloom_filter_size: sbbf.map(|sbbf| sbbf.num_bytes()),
+                        num_hash_functions: sbbf.map(|sbbf| sbbf.num_hash_functions()),
+                    }
+                })),
             }
-        } else {
-            println!(
-                "No column named {} found, candidate columns are: {}",
-                args.column,
-                row_group
-                    .columns()
-                    .iter()
-                    .map(|c| c.column_path().string())
-                    .collect::<Vec<_>>()
-                    .join(", ")
-            );
         }
     }
+
+    if args.output == Output::Json {
+        serde_json::to_writer_pretty(std::io::stdout().lock(), &checks)
+            .expect("Unable to write JSON");
+        println!();
+    }
 }
 
 fn check_filter(sbbf: &Sbbf, value: &String, column: &ColumnChunkMetaData) -> Result<bool, String> {
@@ -139,10 +216,47 @@ fn check_filter(sbbf: &Sbbf, value: &String, column: &ColumnChunkMetaData) -> Re
                 .map_err(|e| format!("Unable to parse value '{value}' to i64: {e}"))?;
             Ok(sbbf.check(&value))
         }
+        Type::FLOAT => {
+            let value: f32 = value
+                .parse()
+                .map_err(|e| format!("Unable to parse value '{value}' to f32: {e}"))?;
+            Ok(sbbf.check(&value))
+        }
+        Type::DOUBLE => {
+            let value: f64 = value
+                .parse()
+                .map_err(|e| format!("Unable to parse value '{value}' to f64: {e}"))?;
+            Ok(sbbf.check(&value))
+        }
         Type::BYTE_ARRAY => Ok(sbbf.check(&value.as_str())),
+        Type::FIXED_LEN_BYTE_ARRAY => {
+            let bytes = parse_hex(value)
+                .ok_or_else(|| format!("Unable to parse value '{value}' as hex"))?;
+            let type_length = column.column_descr().type_length();
+            if bytes.len() != type_length as usize {
+                return Err(format!(
+                    "Value '{value}' has {} bytes, expected {type_length}",
+                    bytes.len()
+                ));
+            }
+            Ok(sbbf.check(bytes.as_slice()))
+        }
         _ => Err(format!(
             "Unsupported column type for checking bloom filter: {}",
             column.column_type()
         )),
     }
 }
+
+/// Parses a hex string, ignoring any `-` separators as found in UUIDs
+fn parse_hex(value: &str) -> Option<Vec<u8>> {
+    let digits = value
+        .chars()
+        .filter(|c| *c != '-')
+        .map(|c| c.to_digit(16).map(|d| d as u8))
+        .collect::<Option<Vec<_>>>()?;
+    if digits.len() % 2 != 0 {
+        return None;
+    }
+    Some(digits.chunks(2).map(|c| (c[0] << 4) | c[1]).collect())
+}
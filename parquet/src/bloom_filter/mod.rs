@@ -353,7 +353,7 @@ impl Sbbf {
         let offset: u64 = if let Some(offset) = column_metadata.bloom_filter_offset() {
             offset
                 .try_into()
-                .map_err(|_| ParquetError::General("Bloom filter offset is invalid".to_string()))?
+                .map_err(|_| ParquetError::Corrupt("Bloom filter offset is invalid".to_string()))?
         } else {
             return Ok(None);
         };
@@ -386,7 +386,7 @@ impl Sbbf {
             Some(_) => buffer.slice((bitset_offset - offset) as usize..),
             None => {
                 let bitset_length: usize = header.num_bytes.try_into().map_err(|_| {
-                    ParquetError::General("Bloom filter length is invalid".to_string())
+                    ParquetError::Corrupt("Bloom filter length is invalid".to_string())
                 })?;
                 reader.get_bytes(bitset_offset, bitset_length)?
             }
@@ -426,6 +426,16 @@ impl Sbbf {
         self.0[block_index].check(hash as u32)
     }
 
+    /// Returns the size of the bitset of this bloom filter in bytes
+    pub fn num_bytes(&self) -> usize {
+        self.0.len() * std::mem::size_of::<Block>()
+    }
+
+    /// Returns the number of hash functions used to set and check the bits of each value
+    pub fn num_hash_functions(&self) -> usize {
+        SALT.len()
+    }
+
     /// Return the total in memory size of this bloom filter in bytes
     pub(crate) fn estimated_memory_size(&self) -> usize {
         self.0.capacity() * std::mem::size_of::<Block>()
@@ -459,11 +469,11 @@ impl Sbbf {
         let bitset_length: u64 = header
             .num_bytes
             .try_into()
-            .map_err(|_| ParquetError::General("Bloom filter length is invalid".to_string()))?;
+            .map_err(|_| ParquetError::Corrupt("Bloom filter length is invalid".to_string()))?;
 
         // Validate that bitset consumes all remaining bytes
         if header_len + bitset_length != bytes.len() as u64 {
-            return Err(ParquetError::General(format!(
+            return Err(ParquetError::Corrupt(format!(
                 "Bloom filter data contains extra bytes: expected {} total bytes, got {}",
                 header_len + bitset_length,
                 bytes.len()
@@ -474,7 +484,7 @@ impl Sbbf {
         let end = (header_len + bitset_length) as usize;
         let bitset = bytes
             .get(start..end)
-            .ok_or_else(|| ParquetError::General("Bloom filter bitset is invalid".to_string()))?;
+            .ok_or_else(|| ParquetError::Corrupt("Bloom filter bitset is invalid".to_string()))?;
 
         Ok(Self::new(bitset))
     }
@@ -531,6 +541,8 @@ mod tests {
             99, 65, 2, 0, 224, 44, 64, 78, 96, 4,
         ];
         let sbbf = Sbbf::new(bitset);
+        assert_eq!(sbbf.num_bytes(), 32);
+        assert_eq!(sbbf.num_hash_functions(), 8);
         for a in 0..10i64 {
             let value = format!("a{a}");
             assert!(sbbf.check(&value.as_str()));
@@ -20,9 +20,10 @@
 use bytes::Bytes;
 
 use crate::basic::{Encoding, PageType};
-use crate::errors::{ParquetError, Result};
-use crate::file::metadata::thrift::{
-    DataPageHeader, DataPageHeaderV2, DictionaryPageHeader, PageHeader,
+use crate::errors::{ErrorContext, ParquetError, Result};
+use crate::file::metadata::thrift::PageHeader;
+pub use crate::file::metadata::thrift::{
+    DataPageHeader, DataPageHeaderV2, DictionaryPageHeader, PageStatistics,
 };
 use crate::file::statistics::{Statistics, page_stats_to_thrift};
 
@@ -202,13 +203,13 @@ impl CompressedPage {
         let uncompressed_size = self.uncompressed_size();
         let compressed_size = self.compressed_size();
         if uncompressed_size > i32::MAX as usize {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "Page uncompressed size overflow: {}",
                 uncompressed_size
             ));
         }
         if compressed_size > i32::MAX as usize {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "Page compressed size overflow: {}",
                 compressed_size
             ));
@@ -380,6 +381,93 @@ impl TryFrom<&crate::file::metadata::thrift::PageHeader> for PageMetadata {
     }
 }
 
+/// The header of a [`RawPage`], specific to its page type
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RawPageHeader {
+    /// Header of a data page, Parquet format v1
+    DataPage(DataPageHeader),
+    /// Header of a data page, Parquet format v2
+    DataPageV2(DataPageHeaderV2),
+    /// Header of a dictionary page
+    DictionaryPage(DictionaryPageHeader),
+}
+
+/// A page of a column chunk as stored in the file, without decompression or decoding
+///
+/// This is intended for handing pages to an external decoder, see
+/// [`read_column_chunk_pages`](crate::file::serialized_reader::read_column_chunk_pages).
+///
+/// For [`PageType::DATA_PAGE_V2`] the repetition and definition levels at the start of
+/// the page are never compressed, see [`DataPageHeaderV2`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RawPage {
+    /// The type of this page
+    pub page_type: PageType,
+    /// The bytes of the page, compressed with the codec of the column chunk
+    pub compressed_bytes: Bytes,
+    /// The size of the page once decompressed, in bytes
+    pub uncompressed_size: u32,
+    /// The encoding of the values in this page
+    pub encoding: Encoding,
+    /// The number of values in this page, including nulls
+    pub num_values: i32,
+    /// The optional CRC32 checksum of `compressed_bytes`
+    pub crc: Option<i32>,
+    /// The page type specific header
+    pub header: RawPageHeader,
+}
+
+impl TryFrom<(PageHeader, Bytes)> for RawPage {
+    type Error = ParquetError;
+
+    fn try_from((value, compressed_bytes): (PageHeader, Bytes)) -> Result<Self> {
+        let uncompressed_size = u32::try_from(value.uncompressed_page_size)
+            .map_err(|_| corrupt_err!("Invalid page header"))?;
+        let missing = || corrupt_err!("Missing {:?} page header", value.r#type);
+        let (encoding, num_values, header) = match value.r#type {
+            PageType::DATA_PAGE => {
+                let header = value.data_page_header.ok_or_else(missing)?;
+                (
+                    header.encoding,
+                    header.num_values,
+                    RawPageHeader::DataPage(header),
+                )
+            }
+            PageType::DATA_PAGE_V2 => {
+                let header = value.data_page_header_v2.ok_or_else(missing)?;
+                (
+                    header.encoding,
+                    header.num_values,
+                    RawPageHeader::DataPageV2(header),
+                )
+            }
+            PageType::DICTIONARY_PAGE => {
+                let header = value.dictionary_page_header.ok_or_else(missing)?;
+                (
+                    header.encoding,
+                    header.num_values,
+                    RawPageHeader::DictionaryPage(header),
+                )
+            }
+            other => {
+                return Err(general_err!(
+                    "page type {:?} cannot be converted to RawPage",
+                    other
+                ));
+            }
+        };
+        Ok(Self {
+            page_type: value.r#type,
+            compressed_bytes,
+            uncompressed_size,
+            encoding,
+            num_values,
+            crc: value.crc,
+            header,
+        })
+    }
+}
+
 /// API for reading pages from a column chunk.
 /// This offers a iterator like API to get the next page.
 pub trait PageReader: Iterator<Item = Result<Page>> + Send {
@@ -415,6 +503,13 @@ pub trait PageReader: Iterator<Item = Result<Page>> + Send {
             Some(metadata) => Ok(metadata.num_rows.is_some()),
         }
     }
+    /// Returns the location in the file of the column chunk read by this reader
+    ///
+    /// This is attached to errors encountered while decoding the returned pages,
+    /// see [`ParquetError::with_context`]
+    fn error_context(&self) -> ErrorContext {
+        ErrorContext::new()
+    }
 }
 
 /// API for writing pages in a column chunk.
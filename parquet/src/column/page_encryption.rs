@@ -31,7 +31,10 @@ use std::sync::Arc;
 /// Encrypts page headers and page data for columns
 pub(crate) struct PageEncryptor {
     file_encryptor: Arc<FileEncryptor>,
+    /// Encrypts page headers
     block_encryptor: Box<dyn BlockEncryptor>,
+    /// Encrypts page data
+    data_encryptor: Box<dyn BlockEncryptor>,
     row_group_index: usize,
     column_index: usize,
     page_index: usize,
@@ -48,9 +51,11 @@ impl PageEncryptor {
         match file_encryptor {
             Some(file_encryptor) if file_encryptor.is_column_encrypted(column_path) => {
                 let block_encryptor = file_encryptor.get_column_encryptor(column_path)?;
+                let data_encryptor = file_encryptor.get_column_data_encryptor(column_path)?;
                 Ok(Some(Self {
                     file_encryptor: file_encryptor.clone(),
                     block_encryptor,
+                    data_encryptor,
                     row_group_index,
                     column_index,
                     page_index: 0,
@@ -78,7 +83,7 @@ impl PageEncryptor {
             self.column_index,
             Some(self.page_index),
         )?;
-        let encrypted_buffer = self.block_encryptor.encrypt(page.data(), &aad)?;
+        let encrypted_buffer = self.data_encryptor.encrypt(page.data(), &aad)?;
 
         Ok(encrypted_buffer)
     }
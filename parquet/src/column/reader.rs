@@ -225,7 +225,7 @@ where
 
                     if records_read == 0 && levels_read == 0 {
                         // The fact that we're still looping implies there must be some levels to read.
-                        return Err(general_err!(
+                        return Err(corrupt_err!(
                             "Insufficient repetition levels read from column"
                         ));
                     }
@@ -252,7 +252,7 @@ where
                     let (values_read, levels_read) = reader.read_def_levels(out, levels_to_read)?;
 
                     if levels_read != levels_to_read {
-                        return Err(general_err!(
+                        return Err(corrupt_err!(
                             "insufficient definition levels read from column - expected {levels_to_read}, got {levels_read}"
                         ));
                     }
@@ -265,7 +265,7 @@ where
             let values_read = self.values_decoder.read(values, values_to_read)?;
 
             if values_read != values_to_read {
-                return Err(general_err!(
+                return Err(corrupt_err!(
                     "insufficient values read from column - expected: {values_to_read}, got: {values_read}",
                 ));
             }
@@ -362,7 +362,7 @@ where
             };
 
             if rep_levels_read != def_levels_read {
-                return Err(general_err!(
+                return Err(corrupt_err!(
                     "levels mismatch, read {} repetition levels and {} definition levels",
                     rep_levels_read,
                     def_levels_read
@@ -371,7 +371,7 @@ where
 
             let values = self.values_decoder.skip_values(values_read)?;
             if values != values_read {
-                return Err(general_err!(
+                return Err(corrupt_err!(
                     "skipped {} values, expected {}",
                     values,
                     values_read
@@ -393,7 +393,7 @@ where
             }) => self
                 .values_decoder
                 .set_dict(buf, num_values, encoding, is_sorted),
-            _ => Err(ParquetError::General(
+            _ => Err(ParquetError::Corrupt(
                 "Invalid page. Expecting dictionary page".to_string(),
             )),
         }
@@ -490,7 +490,7 @@ where
                             statistics: _,
                         } => {
                             if num_nulls > num_values {
-                                return Err(general_err!(
+                                return Err(corrupt_err!(
                                     "more nulls than values in page, contained {} values and {} nulls",
                                     num_values,
                                     num_nulls
@@ -573,12 +573,12 @@ fn parse_v1_level(
                 let data_size = read_num_bytes::<i32>(i32_size, buf.as_ref()) as usize;
                 let end = i32_size
                     .checked_add(data_size)
-                    .ok_or(general_err!("invalid level length"))?;
+                    .ok_or(corrupt_err!("invalid level length"))?;
                 if end <= buf.len() {
                     return Ok((end, buf.slice(i32_size..end)));
                 }
             }
-            Err(general_err!("not enough data to read levels"))
+            Err(corrupt_err!("not enough data to read levels"))
         }
         #[allow(deprecated)]
         Encoding::BIT_PACKED => {
@@ -586,7 +586,7 @@ fn parse_v1_level(
             let num_bytes = ceil(num_buffered_values as usize * bit_width as usize, 8);
             Ok((num_bytes, buf.slice(..num_bytes)))
         }
-        _ => Err(general_err!("invalid level encoding: {}", encoding)),
+        _ => Err(corrupt_err!("invalid level encoding: {}", encoding)),
     }
 }
 
@@ -176,7 +176,7 @@ impl<T: DataType> ColumnValueDecoder for ColumnValueDecoderImpl<T> {
         }
 
         if self.decoder_mask.is_set(encoding) {
-            return Err(general_err!("Column cannot have more than one dictionary"));
+            return Err(corrupt_err!("Column cannot have more than one dictionary"));
         }
 
         if encoding == Encoding::RLE_DICTIONARY {
@@ -132,6 +132,7 @@ pub trait ColumnValueEncoder {
 pub struct ColumnValueEncoderImpl<T: DataType> {
     encoder: Box<dyn Encoder<T>>,
     dict_encoder: Option<DictEncoder<T>>,
+    dict_data_page_encoding: Encoding,
     descr: ColumnDescPtr,
     num_values: usize,
     statistics_enabled: EnabledStatistics,
@@ -215,6 +216,7 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
         Ok(Self {
             encoder,
             dict_encoder,
+            dict_data_page_encoding: props.dictionary_data_page_encoding(),
             descr: descr.clone(),
             num_values: 0,
             statistics_enabled,
@@ -306,7 +308,7 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
 
     fn flush_data_page(&mut self) -> Result<DataPageValues<T::T>> {
         let (buf, encoding) = match &mut self.dict_encoder {
-            Some(encoder) => (encoder.write_indices()?, Encoding::RLE_DICTIONARY),
+            Some(encoder) => (encoder.write_indices()?, self.dict_data_page_encoding),
             _ => (self.encoder.flush_buffer()?, self.encoder.encoding()),
         };
 
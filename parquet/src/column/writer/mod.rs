@@ -34,6 +34,7 @@ use crate::column::writer::encoder::{ColumnValueEncoder, ColumnValueEncoderImpl,
 use crate::compression::{Codec, CodecOptionsBuilder, create_codec};
 use crate::data_type::private::ParquetValueType;
 use crate::data_type::*;
+use crate::encodings::encoding::check_encoding;
 use crate::encodings::levels::LevelEncoder;
 #[cfg(feature = "encryption")]
 use crate::encryption::encrypt::get_column_crypto_metadata;
@@ -115,6 +116,38 @@ impl ColumnWriter<'_> {
     }
 }
 
+/// Returns an error if the encoding set for `descr` in `props` cannot be used for
+/// its physical type, or is not allowed by [`WriterProperties::strict_writer_version`].
+///
+/// This should be checked before creating a column writer, as [`GenericColumnWriter::new`]
+/// panics if the encoder cannot be created.
+pub(crate) fn check_column_encoding(
+    descr: &ColumnDescriptor,
+    props: &WriterProperties,
+) -> Result<()> {
+    let Some(encoding) = props.encoding(descr.path()) else {
+        return Ok(());
+    };
+    let parquet_2_0_encoding = matches!(
+        encoding,
+        Encoding::DELTA_BINARY_PACKED
+            | Encoding::DELTA_LENGTH_BYTE_ARRAY
+            | Encoding::DELTA_BYTE_ARRAY
+            | Encoding::BYTE_STREAM_SPLIT
+    );
+    if parquet_2_0_encoding
+        && props.strict_writer_version()
+        && props.writer_version() == WriterVersion::PARQUET_1_0
+    {
+        return Err(general_err!(
+            "Encoding {} is not supported by writer version PARQUET_1_0 for column {}",
+            encoding,
+            descr.path()
+        ));
+    }
+    check_encoding(encoding, descr)
+}
+
 /// Create a specific column writer corresponding to column descriptor `descr`.
 pub fn get_column_writer<'a>(
     descr: ColumnDescPtr,
@@ -337,6 +370,7 @@ pub struct GenericColumnWriter<'a, E: ColumnValueEncoder> {
     descr: ColumnDescPtr,
     props: WriterPropertiesPtr,
     statistics_enabled: EnabledStatistics,
+    column_index_truncate_length: Option<usize>,
 
     page_writer: Box<dyn PageWriter + 'a>,
     codec: Compression,
@@ -380,6 +414,7 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         let encoder = E::try_new(&descr, props.as_ref()).unwrap();
 
         let statistics_enabled = props.statistics_enabled(descr.path());
+        let column_index_truncate_length = props.column_column_index_truncate_length(descr.path());
 
         let mut encodings = BTreeSet::new();
         // Used for level information
@@ -405,7 +440,7 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         }
 
         // Disable offset_index_builder if requested by user.
-        let offset_index_builder = match props.offset_index_disabled() {
+        let offset_index_builder = match props.column_offset_index_disabled(descr.path()) {
             false => Some(OffsetIndexBuilder::new()),
             _ => None,
         };
@@ -414,6 +449,7 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
             descr,
             props,
             statistics_enabled,
+            column_index_truncate_length,
             page_writer,
             codec,
             compressor,
@@ -569,6 +605,18 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         self.column_metrics.total_bytes_written as usize + self.encoder.estimated_memory_size()
     }
 
+    /// Returns a reference to the value encoder
+    #[cfg(feature = "arrow")]
+    pub(crate) fn encoder(&self) -> &E {
+        &self.encoder
+    }
+
+    /// Returns a mutable reference to the value encoder
+    #[cfg(feature = "arrow")]
+    pub(crate) fn encoder_mut(&mut self) -> &mut E {
+        &mut self.encoder
+    }
+
     /// Returns total number of bytes written by this column writer so far.
     /// This value is also returned when column writer is closed.
     ///
@@ -834,12 +882,12 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
                         self.column_index_builder.append(
                             null_page,
                             self.truncate_min_value(
-                                self.props.column_index_truncate_length(),
+                                self.column_index_truncate_length,
                                 stat.min_bytes_opt().unwrap(),
                             )
                             .0,
                             self.truncate_max_value(
-                                self.props.column_index_truncate_length(),
+                                self.column_index_truncate_length,
                                 stat.max_bytes_opt().unwrap(),
                             )
                             .0,
@@ -1241,7 +1289,13 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         builder = self.set_column_chunk_encryption_properties(builder);
 
         let metadata = builder.build()?;
-        Ok(metadata)
+        match self.props.column_chunk_key_value_metadata(&metadata) {
+            Some(key_value_metadata) => metadata
+                .into_builder()
+                .set_key_value_metadata(Some(key_value_metadata))
+                .build(),
+            None => Ok(metadata),
+        }
     }
 
     /// Encodes definition or repetition levels for Data Page v1.
@@ -79,6 +79,8 @@ pub trait Codec: Send {
 pub struct CodecOptions {
     /// Whether or not to fallback to other LZ4 older implementations on error in LZ4_HADOOP.
     backward_compatible_lz4: bool,
+    /// Whether or not to fallback to other LZ4 implementations on error in LZ4_RAW.
+    lz4_raw_fallback: bool,
 }
 
 impl Default for CodecOptions {
@@ -90,12 +92,15 @@ impl Default for CodecOptions {
 pub struct CodecOptionsBuilder {
     /// Whether or not to fallback to other LZ4 older implementations on error in LZ4_HADOOP.
     backward_compatible_lz4: bool,
+    /// Whether or not to fallback to other LZ4 implementations on error in LZ4_RAW.
+    lz4_raw_fallback: bool,
 }
 
 impl Default for CodecOptionsBuilder {
     fn default() -> Self {
         Self {
             backward_compatible_lz4: true,
+            lz4_raw_fallback: false,
         }
     }
 }
@@ -114,9 +119,23 @@ impl CodecOptionsBuilder {
         self
     }
 
+    /// Enable/disable the LZ4_RAW fallback.
+    ///
+    /// If the LZ4_RAW fallback is enabled, on LZ4_RAW error it will fallback to LZ4_HADOOP
+    /// and then LZ4_FRAME, for compatibility with writers that label these formats as
+    /// LZ4_RAW. Note that this may decode a corrupt LZ4_RAW page successfully.
+    ///
+    /// By default the LZ4_RAW fallback is disabled, and on LZ4_RAW error it will return
+    /// the error.
+    pub fn set_lz4_raw_fallback(mut self, value: bool) -> CodecOptionsBuilder {
+        self.lz4_raw_fallback = value;
+        self
+    }
+
     pub fn build(self) -> CodecOptions {
         CodecOptions {
             backward_compatible_lz4: self.backward_compatible_lz4,
+            lz4_raw_fallback: self.lz4_raw_fallback,
         }
     }
 }
@@ -150,21 +169,21 @@ pub fn create_codec(codec: CodecType, _options: &CodecOptions) -> Result<Option<
         CodecType::BROTLI(level) => {
             #[cfg(any(feature = "brotli", test))]
             return Ok(Some(Box::new(BrotliCodec::new(level))));
-            Err(ParquetError::General(
+            Err(ParquetError::NYI(
                 "Disabled feature at compile time: brotli".into(),
             ))
         }
         CodecType::GZIP(level) => {
             #[cfg(any(feature = "flate2", test))]
             return Ok(Some(Box::new(GZipCodec::new(level))));
-            Err(ParquetError::General(
+            Err(ParquetError::NYI(
                 "Disabled feature at compile time: flate2".into(),
             ))
         }
         CodecType::SNAPPY => {
             #[cfg(any(feature = "snap", test))]
             return Ok(Some(Box::new(SnappyCodec::new())));
-            Err(ParquetError::General(
+            Err(ParquetError::NYI(
                 "Disabled feature at compile time: snap".into(),
             ))
         }
@@ -173,21 +192,21 @@ pub fn create_codec(codec: CodecType, _options: &CodecOptions) -> Result<Option<
             return Ok(Some(Box::new(LZ4HadoopCodec::new(
                 _options.backward_compatible_lz4,
             ))));
-            Err(ParquetError::General(
+            Err(ParquetError::NYI(
                 "Disabled feature at compile time: lz4".into(),
             ))
         }
         CodecType::ZSTD(level) => {
             #[cfg(any(feature = "zstd", test))]
             return Ok(Some(Box::new(ZSTDCodec::new(level))));
-            Err(ParquetError::General(
+            Err(ParquetError::NYI(
                 "Disabled feature at compile time: zstd".into(),
             ))
         }
         CodecType::LZ4_RAW => {
             #[cfg(any(feature = "lz4", test))]
-            return Ok(Some(Box::new(LZ4RawCodec::new())));
-            Err(ParquetError::General(
+            return Ok(Some(Box::new(LZ4RawCodec::new(_options.lz4_raw_fallback))));
+            Err(ParquetError::NYI(
                 "Disabled feature at compile time: lz4".into(),
             ))
         }
@@ -591,16 +610,32 @@ impl Default for ZstdLevel {
 #[cfg(any(feature = "lz4", test))]
 mod lz4_raw_codec {
     use crate::compression::Codec;
+    use crate::compression::lz4_codec::LZ4Codec;
+    use crate::compression::lz4_hadoop_codec::LZ4HadoopCodec;
     use crate::errors::ParquetError;
     use crate::errors::Result;
 
     /// Codec for LZ4 Raw compression algorithm.
-    pub struct LZ4RawCodec {}
+    pub struct LZ4RawCodec {
+        /// Whether or not to fallback to other LZ4 implementations on error.
+        fallback: bool,
+    }
 
     impl LZ4RawCodec {
         /// Creates new LZ4 Raw compression codec.
-        pub(crate) fn new() -> Self {
-            Self {}
+        pub(crate) fn new(fallback: bool) -> Self {
+            Self { fallback }
+        }
+    }
+
+    /// Try to decompress the buffer as a single LZ4 block of `output.len()` bytes.
+    fn try_decompress_raw(input: &[u8], output: &mut [u8]) -> Result<usize> {
+        match lz4_flex::block::decompress_into(input, output) {
+            Ok(n) if n == output.len() => Ok(n),
+            Ok(_) => Err(ParquetError::General(
+                "LZ4RawCodec uncompress_size is not the expected one".into(),
+            )),
+            Err(e) => Err(ParquetError::External(Box::new(e))),
         }
     }
 
@@ -621,16 +656,31 @@ mod lz4_raw_codec {
                 }
             };
             output_buf.resize(offset + required_len, 0);
-            match lz4_flex::block::decompress_into(input_buf, &mut output_buf[offset..]) {
-                Ok(n) => {
-                    if n != required_len {
-                        return Err(ParquetError::General(
-                            "LZ4RawCodec uncompress_size is not the expected one".into(),
-                        ));
+            match try_decompress_raw(input_buf, &mut output_buf[offset..]) {
+                Ok(n) => Ok(n),
+                Err(e) if !self.fallback => {
+                    output_buf.truncate(offset);
+                    Err(e)
+                }
+                // Fallback done to be compatible with writers that label data
+                // compressed with the Hadoop Lz4Codec or the LZ4 frame format as LZ4_RAW.
+                Err(e) => {
+                    // Truncate any inserted element before trying next algorithm.
+                    output_buf.truncate(offset);
+                    let mut hadoop = LZ4HadoopCodec::new(false);
+                    if let Ok(n) = hadoop.decompress(input_buf, output_buf, uncompress_size) {
+                        return Ok(n);
+                    }
+                    output_buf.truncate(offset);
+                    match LZ4Codec::new().decompress(input_buf, output_buf, uncompress_size) {
+                        Ok(n) if n == required_len => Ok(n),
+                        // Report the original error if no fallback succeeds
+                        _ => {
+                            output_buf.truncate(offset);
+                            Err(e)
+                        }
                     }
-                    Ok(n)
                 }
-                Err(e) => Err(ParquetError::External(Box::new(e))),
             }
         }
 
@@ -780,7 +830,7 @@ mod lz4_hadoop_codec {
                         Err(_) => {
                             // Truncate any inserted element before tryingg next algorithm.
                             output_buf.truncate(output_len);
-                            LZ4RawCodec::new().decompress(input_buf, output_buf, uncompress_size)
+                            LZ4RawCodec::new(false).decompress(input_buf, output_buf, uncompress_size)
                         }
                     }
                 }
@@ -793,7 +843,7 @@ mod lz4_hadoop_codec {
             output_buf.resize(offset + PREFIX_LEN, 0);
 
             // Append LZ4_RAW compressed bytes after prefix.
-            LZ4RawCodec::new().compress(input_buf, output_buf)?;
+            LZ4RawCodec::new(false).compress(input_buf, output_buf)?;
 
             // Prepend decompressed size and compressed size in big endian to be compatible
             // with LZ4_HADOOP.
@@ -931,4 +981,68 @@ mod tests {
     fn test_codec_lz4_raw() {
         test_codec_with_size(CodecType::LZ4_RAW);
     }
+
+    #[test]
+    fn test_codec_lz4_raw_fallback() {
+        let data = random_bytes(10000);
+        let hadoop = {
+            let mut compressed = vec![];
+            LZ4HadoopCodec::new(false)
+                .compress(&data, &mut compressed)
+                .unwrap();
+            compressed
+        };
+        let frame = {
+            let mut compressed = vec![];
+            lz4_codec::LZ4Codec::new()
+                .compress(&data, &mut compressed)
+                .unwrap();
+            compressed
+        };
+
+        let lenient = CodecOptionsBuilder::default()
+            .set_lz4_raw_fallback(true)
+            .build();
+        let strict = CodecOptionsBuilder::default().build();
+        for compressed in [hadoop, frame] {
+            let mut codec = create_codec(CodecType::LZ4_RAW, &lenient)
+                .unwrap()
+                .unwrap();
+            let mut decompressed = vec![];
+            let size = codec
+                .decompress(&compressed, &mut decompressed, Some(data.len()))
+                .unwrap();
+            assert_eq!(size, data.len());
+            assert_eq!(decompressed, data);
+
+            let mut codec = create_codec(CodecType::LZ4_RAW, &strict)
+                .unwrap()
+                .unwrap();
+            let mut decompressed = vec![];
+            codec
+                .decompress(&compressed, &mut decompressed, Some(data.len()))
+                .unwrap_err();
+        }
+
+        // A corrupt page fails with the fallback and by default
+        let mut corrupt = vec![];
+        create_codec(CodecType::LZ4_RAW, &strict)
+            .unwrap()
+            .unwrap()
+            .compress(&data, &mut corrupt)
+            .unwrap();
+        corrupt.truncate(corrupt.len() / 2);
+        for options in [lenient, strict] {
+            let mut codec = create_codec(CodecType::LZ4_RAW, &options)
+                .unwrap()
+                .unwrap();
+            for compressed in [&corrupt[..], &[0xFF; 16]] {
+                let mut decompressed = vec![1, 2, 3];
+                codec
+                    .decompress(compressed, &mut decompressed, Some(data.len()))
+                    .unwrap_err();
+                assert_eq!(decompressed, vec![1, 2, 3]);
+            }
+        }
+    }
 }
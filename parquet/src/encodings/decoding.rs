@@ -211,7 +211,7 @@ pub trait Decoder<T: DataType>: Send {
         let values_to_read = num_values - null_count;
         let values_read = self.get(buffer)?;
         if values_read != values_to_read {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "Number of values read: {}, doesn't match expected: {}",
                 values_read,
                 values_to_read
@@ -387,7 +387,7 @@ impl<T: DataType> Decoder<T> for DictDecoder<T> {
 
         let bit_width = data.as_ref()[0];
         if bit_width > 32 {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "Invalid or corrupted RLE bit width {}. Max allowed is 32",
                 bit_width
             ));
@@ -599,7 +599,7 @@ where
             .ok_or_else(|| eof_err!("Not enough data to decode 'min_delta'"))?;
 
         self.min_delta =
-            T::T::from_i64(min_delta).ok_or_else(|| general_err!("'min_delta' too large"))?;
+            T::T::from_i64(min_delta).ok_or_else(|| corrupt_err!("'min_delta' too large"))?;
 
         self.mini_block_bit_widths.clear();
         self.bit_reader
@@ -646,7 +646,7 @@ where
     #[inline]
     fn check_bit_width(&self, bit_width: usize) -> Result<()> {
         if bit_width > std::mem::size_of::<T::T>() * 8 {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "Invalid delta bit width {} which is larger than expected {} ",
                 bit_width,
                 std::mem::size_of::<T::T>() * 8
@@ -672,17 +672,17 @@ where
             .get_vlq_int()
             .ok_or_else(|| eof_err!("Not enough data to decode 'block_size'"))?
             .try_into()
-            .map_err(|_| general_err!("invalid 'block_size'"))?;
+            .map_err(|_| corrupt_err!("invalid 'block_size'"))?;
 
         self.mini_blocks_per_block = self
             .bit_reader
             .get_vlq_int()
             .ok_or_else(|| eof_err!("Not enough data to decode 'mini_blocks_per_block'"))?
             .try_into()
-            .map_err(|_| general_err!("invalid 'mini_blocks_per_block'"))?;
+            .map_err(|_| corrupt_err!("invalid 'mini_blocks_per_block'"))?;
 
         if self.mini_blocks_per_block == 0 {
-            return Err(general_err!("cannot have zero miniblocks per block"));
+            return Err(corrupt_err!("cannot have zero miniblocks per block"));
         }
 
         self.values_left = self
@@ -690,7 +690,7 @@ where
             .get_vlq_int()
             .ok_or_else(|| eof_err!("Not enough data to decode 'values_left'"))?
             .try_into()
-            .map_err(|_| general_err!("invalid 'values_left'"))?;
+            .map_err(|_| corrupt_err!("invalid 'values_left'"))?;
 
         let first_value = self
             .bit_reader
@@ -698,17 +698,17 @@ where
             .ok_or_else(|| eof_err!("Not enough data to decode 'first_value'"))?;
 
         self.first_value =
-            Some(T::T::from_i64(first_value).ok_or_else(|| general_err!("first value too large"))?);
+            Some(T::T::from_i64(first_value).ok_or_else(|| corrupt_err!("first value too large"))?);
 
         if self.block_size % 128 != 0 {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "'block_size' must be a multiple of 128, got {}",
                 self.block_size
             ));
         }
 
         if self.block_size % self.mini_blocks_per_block != 0 {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "'block_size' must be a multiple of 'mini_blocks_per_block' got {} and {}",
                 self.block_size,
                 self.mini_blocks_per_block
@@ -722,7 +722,7 @@ where
         self.mini_block_bit_widths.clear();
 
         if self.values_per_mini_block % 32 != 0 {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "'values_per_mini_block' must be a multiple of 32 got {}",
                 self.values_per_mini_block
             ));
@@ -761,7 +761,7 @@ where
                 .get_batch(&mut buffer[read..read + batch_to_read], bit_width);
 
             if batch_read != batch_to_read {
-                return Err(general_err!(
+                return Err(corrupt_err!(
                     "Expected to read {} values from miniblock got {}",
                     batch_to_read,
                     batch_read
@@ -866,7 +866,7 @@ where
                 .get_batch(&mut skip_buffer[0..mini_block_to_skip], bit_width);
 
             if skip_count != mini_block_to_skip {
-                return Err(general_err!(
+                return Err(corrupt_err!(
                     "Expected to skip {} values from mini block got {}.",
                     mini_block_batch_size,
                     skip_count
@@ -161,7 +161,7 @@ impl<T: DataType> Decoder<T> for VariableWidthByteStreamSplitDecoder<T> {
     fn set_data(&mut self, data: Bytes, num_values: usize) -> Result<()> {
         // Rough check that all data elements are the same length
         if data.len() % self.type_width != 0 {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "Input data length is not a multiple of type width {}",
                 self.type_width
             ));
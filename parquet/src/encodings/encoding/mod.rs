@@ -24,7 +24,7 @@ use crate::data_type::private::ParquetValueType;
 use crate::data_type::*;
 use crate::encodings::rle::RleEncoder;
 use crate::errors::{ParquetError, Result};
-use crate::schema::types::ColumnDescPtr;
+use crate::schema::types::{ColumnDescPtr, ColumnDescriptor};
 use crate::util::bit_util::{BitWriter, num_required_bits};
 
 use byte_stream_split_encoder::{ByteStreamSplitEncoder, VariableWidthByteStreamSplitEncoder};
@@ -80,10 +80,13 @@ pub trait Encoder<T: DataType>: Send {
 
 /// Gets a encoder for the particular data type `T` and encoding `encoding`. Memory usage
 /// for the encoder instance is tracked by `mem_tracker`.
+///
+/// Returns an error if `encoding` cannot be used for the physical type of `T`.
 pub fn get_encoder<T: DataType>(
     encoding: Encoding,
     descr: &ColumnDescPtr,
 ) -> Result<Box<dyn Encoder<T>>> {
+    check_encoding(encoding, descr)?;
     let encoder: Box<dyn Encoder<T>> = match encoding {
         Encoding::PLAIN => Box::new(PlainEncoder::new()),
         Encoding::RLE_DICTIONARY | Encoding::PLAIN_DICTIONARY => {
@@ -106,6 +109,33 @@ pub fn get_encoder<T: DataType>(
     Ok(encoder)
 }
 
+/// Returns an error if `encoding` cannot be used for the physical type of `descr`
+pub(crate) fn check_encoding(encoding: Encoding, descr: &ColumnDescriptor) -> Result<()> {
+    let physical_type = descr.physical_type();
+    let supported = match encoding {
+        Encoding::RLE => physical_type == Type::BOOLEAN,
+        Encoding::DELTA_BINARY_PACKED => matches!(physical_type, Type::INT32 | Type::INT64),
+        Encoding::DELTA_LENGTH_BYTE_ARRAY => physical_type == Type::BYTE_ARRAY,
+        Encoding::DELTA_BYTE_ARRAY => {
+            matches!(physical_type, Type::BYTE_ARRAY | Type::FIXED_LEN_BYTE_ARRAY)
+        }
+        Encoding::BYTE_STREAM_SPLIT => matches!(
+            physical_type,
+            Type::INT32 | Type::INT64 | Type::FLOAT | Type::DOUBLE | Type::FIXED_LEN_BYTE_ARRAY
+        ),
+        _ => true,
+    };
+    if !supported {
+        return Err(general_err!(
+            "Encoding {} is not supported for column {} of type {}",
+            encoding,
+            descr.path(),
+            physical_type
+        ));
+    }
+    Ok(())
+}
+
 // ----------------------------------------------------------------------
 // Plain encoding
 
@@ -778,11 +808,49 @@ mod tests {
         // supported encodings
         create_and_check_encoder::<Int32Type>(0, Encoding::PLAIN, None);
         create_and_check_encoder::<Int32Type>(0, Encoding::DELTA_BINARY_PACKED, None);
-        create_and_check_encoder::<Int32Type>(0, Encoding::DELTA_LENGTH_BYTE_ARRAY, None);
-        create_and_check_encoder::<Int32Type>(0, Encoding::DELTA_BYTE_ARRAY, None);
+        create_and_check_encoder::<Int64Type>(0, Encoding::BYTE_STREAM_SPLIT, None);
+        create_and_check_encoder::<ByteArrayType>(0, Encoding::DELTA_LENGTH_BYTE_ARRAY, None);
+        create_and_check_encoder::<ByteArrayType>(0, Encoding::DELTA_BYTE_ARRAY, None);
+        create_and_check_encoder::<FixedLenByteArrayType>(2, Encoding::DELTA_BYTE_ARRAY, None);
+        create_and_check_encoder::<FixedLenByteArrayType>(2, Encoding::BYTE_STREAM_SPLIT, None);
         create_and_check_encoder::<BoolType>(0, Encoding::RLE, None);
 
         // error when initializing
+        create_and_check_encoder::<Int32Type>(
+            0,
+            Encoding::DELTA_LENGTH_BYTE_ARRAY,
+            Some(general_err!(
+                "Encoding DELTA_LENGTH_BYTE_ARRAY is not supported for column \"\" of type INT32"
+            )),
+        );
+        create_and_check_encoder::<Int32Type>(
+            0,
+            Encoding::DELTA_BYTE_ARRAY,
+            Some(general_err!(
+                "Encoding DELTA_BYTE_ARRAY is not supported for column \"\" of type INT32"
+            )),
+        );
+        create_and_check_encoder::<DoubleType>(
+            0,
+            Encoding::DELTA_BINARY_PACKED,
+            Some(general_err!(
+                "Encoding DELTA_BINARY_PACKED is not supported for column \"\" of type DOUBLE"
+            )),
+        );
+        create_and_check_encoder::<ByteArrayType>(
+            0,
+            Encoding::BYTE_STREAM_SPLIT,
+            Some(general_err!(
+                "Encoding BYTE_STREAM_SPLIT is not supported for column \"\" of type BYTE_ARRAY"
+            )),
+        );
+        create_and_check_encoder::<Int32Type>(
+            0,
+            Encoding::RLE,
+            Some(general_err!(
+                "Encoding RLE is not supported for column \"\" of type INT32"
+            )),
+        );
         create_and_check_encoder::<Int32Type>(
             0,
             Encoding::RLE_DICTIONARY,
@@ -533,7 +533,7 @@ impl RleDecoder {
                 let value_width = bit_util::ceil(self.bit_width as usize, 8);
                 self.current_value = bit_reader.get_aligned::<u64>(value_width);
                 self.current_value.ok_or_else(|| {
-                    general_err!("parquet_data_error: not enough data for RLE decoding")
+                    corrupt_err!("parquet_data_error: not enough data for RLE decoding")
                 })?;
             }
             Ok(true)
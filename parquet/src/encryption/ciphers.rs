@@ -19,9 +19,12 @@ use crate::errors::ParquetError;
 use crate::errors::ParquetError::General;
 use crate::errors::Result;
 use crate::file::metadata::HeapSize;
+use aes::{Aes128, Aes192, Aes256};
+use ctr::Ctr32BE;
+use ctr::cipher::{KeyIvInit, StreamCipher};
 use ring::aead::{AES_128_GCM, Aad, LessSafeKey, NonceSequence, UnboundKey};
 use ring::rand::{SecureRandom, SystemRandom};
-use std::fmt::Debug;
+use std::fmt::{Debug, Formatter};
 
 const RIGHT_TWELVE: u128 = 0x0000_0000_ffff_ffff_ffff_ffff_ffff_ffff;
 pub(crate) const NONCE_LEN: usize = 12;
@@ -88,6 +91,83 @@ impl BlockDecryptor for RingGcmBlockDecryptor {
     }
 }
 
+/// An AES key used in CTR mode, as used to encrypt page data with the `AES_GCM_CTR_V1` algorithm
+#[derive(Clone)]
+struct AesCtrKey(Vec<u8>);
+
+impl AesCtrKey {
+    fn new(key_bytes: &[u8]) -> Result<Self> {
+        match key_bytes.len() {
+            16 | 24 | 32 => Ok(Self(key_bytes.to_vec())),
+            _ => Err(General("Failed to create AES key".to_string())),
+        }
+    }
+
+    /// Encrypts or decrypts `buf` in place
+    fn apply_keystream(&self, nonce: &[u8], buf: &mut [u8]) {
+        // The IV is the nonce followed by a 4 byte big endian counter starting at 1
+        let mut iv = [0u8; NONCE_LEN + 4];
+        iv[..NONCE_LEN].copy_from_slice(nonce);
+        iv[NONCE_LEN + 3] = 1;
+        // The key length is validated on construction
+        match self.0.len() {
+            16 => Ctr32BE::<Aes128>::new_from_slices(&self.0, &iv)
+                .unwrap()
+                .apply_keystream(buf),
+            24 => Ctr32BE::<Aes192>::new_from_slices(&self.0, &iv)
+                .unwrap()
+                .apply_keystream(buf),
+            _ => Ctr32BE::<Aes256>::new_from_slices(&self.0, &iv)
+                .unwrap()
+                .apply_keystream(buf),
+        }
+    }
+}
+
+impl Debug for AesCtrKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("AesCtrKey").finish_non_exhaustive()
+    }
+}
+
+/// Decrypts page data encrypted with AES in CTR mode, which does not authenticate the data
+#[derive(Debug, Clone)]
+pub(crate) struct CtrBlockDecryptor {
+    key: AesCtrKey,
+}
+
+impl CtrBlockDecryptor {
+    pub(crate) fn new(key_bytes: &[u8]) -> Result<Self> {
+        Ok(Self {
+            key: AesCtrKey::new(key_bytes)?,
+        })
+    }
+}
+
+impl HeapSize for CtrBlockDecryptor {
+    fn heap_size(&self) -> usize {
+        self.key.0.heap_size()
+    }
+}
+
+impl BlockDecryptor for CtrBlockDecryptor {
+    fn decrypt(&self, length_and_ciphertext: &[u8], _aad: &[u8]) -> Result<Vec<u8>> {
+        if length_and_ciphertext.len() < SIZE_LEN + NONCE_LEN {
+            return Err(General("Ciphertext is too short".to_string()));
+        }
+        let nonce = &length_and_ciphertext[SIZE_LEN..SIZE_LEN + NONCE_LEN];
+        let mut result = length_and_ciphertext[SIZE_LEN + NONCE_LEN..].to_vec();
+        self.key.apply_keystream(nonce, &mut result);
+        Ok(result)
+    }
+
+    fn compute_plaintext_tag(&self, _aad: &[u8], _plaintext: &[u8]) -> Result<Vec<u8>> {
+        Err(General(
+            "AES CTR mode cannot compute a plaintext tag".to_string(),
+        ))
+    }
+}
+
 pub(crate) trait BlockEncryptor: Debug + Send + Sync {
     fn encrypt(&mut self, plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>>;
 }
@@ -186,6 +266,45 @@ impl BlockEncryptor for RingGcmBlockEncryptor {
     }
 }
 
+/// Encrypts page data with AES in CTR mode
+#[derive(Debug, Clone)]
+pub(crate) struct CtrBlockEncryptor {
+    key: AesCtrKey,
+    nonce_sequence: CounterNonce,
+}
+
+impl CtrBlockEncryptor {
+    /// Create a new `CtrBlockEncryptor` with a given key and random nonce.
+    /// The nonce will advance with each block encryption and
+    /// return an error if it wraps around.
+    pub(crate) fn new(key_bytes: &[u8]) -> Result<Self> {
+        let rng = SystemRandom::new();
+        Ok(Self {
+            key: AesCtrKey::new(key_bytes)?,
+            nonce_sequence: CounterNonce::new(&rng)?,
+        })
+    }
+}
+
+impl BlockEncryptor for CtrBlockEncryptor {
+    fn encrypt(&mut self, plaintext: &[u8], _aad: &[u8]) -> Result<Vec<u8>> {
+        // Format is: [ciphertext size, nonce, ciphertext]
+        let ciphertext_length: u32 = (NONCE_LEN + plaintext.len())
+            .try_into()
+            .map_err(|err| General(format!("Plaintext data too long. {err:?}")))?;
+        let mut ciphertext = Vec::with_capacity(SIZE_LEN + ciphertext_length as usize);
+        ciphertext.extend(ciphertext_length.to_le_bytes());
+
+        let nonce = self.nonce_sequence.advance()?;
+        ciphertext.extend(nonce.as_ref());
+        ciphertext.extend(plaintext);
+        self.key
+            .apply_keystream(nonce.as_ref(), &mut ciphertext[SIZE_LEN + NONCE_LEN..]);
+
+        Ok(ciphertext)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -204,4 +323,48 @@ mod tests {
 
         assert_eq!(plaintext, decrypted.as_slice());
     }
+
+    #[test]
+    fn test_ctr_round_trip() {
+        for key_len in [16, 24, 32] {
+            let key = vec![1u8; key_len];
+            let mut encryptor = CtrBlockEncryptor::new(&key).unwrap();
+            let decryptor = CtrBlockDecryptor::new(&key).unwrap();
+
+            let plaintext = b"hello, world!";
+            let ciphertext = encryptor.encrypt(plaintext, b"").unwrap();
+            assert_eq!(ciphertext.len(), SIZE_LEN + NONCE_LEN + plaintext.len());
+            assert_ne!(&ciphertext[SIZE_LEN + NONCE_LEN..], plaintext);
+
+            let decrypted = decryptor.decrypt(&ciphertext, b"").unwrap();
+            assert_eq!(plaintext, decrypted.as_slice());
+        }
+
+        let err = CtrBlockDecryptor::new(&[0u8; 15]).unwrap_err();
+        assert_eq!(err.to_string(), "Parquet error: Failed to create AES key");
+    }
+
+    #[test]
+    fn test_ctr_iv() {
+        use aes::cipher::{BlockEncrypt, KeyInit};
+
+        // The keystream is the encryption of the nonce followed by a
+        // 4 byte big endian counter starting at 1
+        let key = [7u8; 16];
+        let nonce = [3u8; NONCE_LEN];
+        let mut keystream = [0u8; 32];
+        AesCtrKey::new(&key)
+            .unwrap()
+            .apply_keystream(&nonce, &mut keystream);
+
+        let cipher = Aes128::new_from_slice(&key).unwrap();
+        for (counter, expected) in keystream.chunks(16).enumerate() {
+            let mut block = [0u8; 16];
+            block[..NONCE_LEN].copy_from_slice(&nonce);
+            block[NONCE_LEN..].copy_from_slice(&(counter as u32 + 1).to_be_bytes());
+            let mut block = block.into();
+            cipher.encrypt_block(&mut block);
+            assert_eq!(block.as_slice(), expected);
+        }
+    }
 }
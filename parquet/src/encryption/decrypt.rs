@@ -17,7 +17,10 @@
 
 //! Configuration and utilities for decryption of files using Parquet Modular Encryption
 
-use crate::encryption::ciphers::{BlockDecryptor, RingGcmBlockDecryptor, TAG_LEN};
+use crate::encryption::ciphers::{
+    BlockDecryptor, CtrBlockDecryptor, RingGcmBlockDecryptor, TAG_LEN,
+};
+use crate::encryption::encrypt::ParquetCipher;
 use crate::encryption::modules::{ModuleType, create_footer_aad, create_module_aad};
 use crate::errors::{ParquetError, Result};
 use crate::file::column_crypto_metadata::ColumnCryptoMetaData;
@@ -144,8 +147,7 @@ impl CryptoContext {
     ) -> Result<Self> {
         let (data_decryptor, metadata_decryptor) = match column_crypto_metadata {
             ColumnCryptoMetaData::ENCRYPTION_WITH_FOOTER_KEY => {
-                // TODO: In GCM-CTR mode will this need to be a non-GCM decryptor?
-                let data_decryptor = file_decryptor.get_footer_decryptor()?;
+                let data_decryptor = file_decryptor.get_footer_data_decryptor()?;
                 let metadata_decryptor = file_decryptor.get_footer_decryptor()?;
                 (data_decryptor, metadata_decryptor)
             }
@@ -562,13 +564,18 @@ impl DecryptionPropertiesBuilderWithRetriever {
 #[derive(Clone, Debug)]
 pub(crate) struct FileDecryptor {
     decryption_properties: Arc<FileDecryptionProperties>,
+    cipher: ParquetCipher,
     footer_decryptor: Arc<dyn BlockDecryptor>,
+    /// Decrypts the page data of columns encrypted with the footer key
+    footer_data_decryptor: Arc<dyn BlockDecryptor>,
     file_aad: Vec<u8>,
 }
 
 impl PartialEq for FileDecryptor {
     fn eq(&self, other: &Self) -> bool {
-        self.decryption_properties == other.decryption_properties && self.file_aad == other.file_aad
+        self.decryption_properties == other.decryption_properties
+            && self.cipher == other.cipher
+            && self.file_aad == other.file_aad
     }
 }
 
@@ -583,6 +590,12 @@ impl HeapSize for FileDecryptor {
     fn heap_size(&self) -> usize {
         self.decryption_properties.heap_size()
             + (Arc::clone(&self.footer_decryptor) as Arc<dyn HeapSize>).heap_size()
+            + match self.cipher {
+                ParquetCipher::AesGcmV1 => 0,
+                ParquetCipher::AesGcmCtrV1 => {
+                    (Arc::clone(&self.footer_data_decryptor) as Arc<dyn HeapSize>).heap_size()
+                }
+            }
             + self.file_aad.heap_size()
     }
 }
@@ -590,21 +603,32 @@ impl HeapSize for FileDecryptor {
 impl FileDecryptor {
     pub(crate) fn new(
         decryption_properties: &Arc<FileDecryptionProperties>,
+        cipher: ParquetCipher,
         footer_key_metadata: Option<&[u8]>,
         aad_file_unique: Vec<u8>,
         aad_prefix: Vec<u8>,
     ) -> Result<Self> {
         let file_aad = [aad_prefix.as_slice(), aad_file_unique.as_slice()].concat();
         let footer_key = decryption_properties.footer_key(footer_key_metadata)?;
-        let footer_decryptor = RingGcmBlockDecryptor::new(&footer_key).map_err(|e| {
+        let invalid_key = |e: ParquetError| {
             general_err!(
                 "Invalid footer key. {}",
                 e.to_string().replace("Parquet error: ", "")
             )
-        })?;
+        };
+        let footer_decryptor: Arc<dyn BlockDecryptor> =
+            Arc::new(RingGcmBlockDecryptor::new(&footer_key).map_err(invalid_key)?);
+        let footer_data_decryptor: Arc<dyn BlockDecryptor> = match cipher {
+            ParquetCipher::AesGcmV1 => Arc::clone(&footer_decryptor),
+            ParquetCipher::AesGcmCtrV1 => {
+                Arc::new(CtrBlockDecryptor::new(&footer_key).map_err(invalid_key)?)
+            }
+        };
 
         Ok(Self {
-            footer_decryptor: Arc::new(footer_decryptor),
+            footer_decryptor,
+            footer_data_decryptor,
+            cipher,
             decryption_properties: Arc::clone(decryption_properties),
             file_aad,
         })
@@ -614,6 +638,11 @@ impl FileDecryptor {
         Ok(self.footer_decryptor.clone())
     }
 
+    /// Get the decryptor for the page data of columns encrypted with the footer key
+    pub(crate) fn get_footer_data_decryptor(&self) -> Result<Arc<dyn BlockDecryptor>> {
+        Ok(self.footer_data_decryptor.clone())
+    }
+
     /// Verify the signature of the footer
     pub(crate) fn verify_plaintext_footer_signature(&self, plaintext_footer: &[u8]) -> Result<()> {
         // Plaintext footer format is: [plaintext metadata, nonce, authentication tag]
@@ -641,7 +670,10 @@ impl FileDecryptor {
         let column_key = self
             .decryption_properties
             .column_key(column_name, key_metadata)?;
-        Ok(Arc::new(RingGcmBlockDecryptor::new(&column_key)?))
+        Ok(match self.cipher {
+            ParquetCipher::AesGcmV1 => Arc::new(RingGcmBlockDecryptor::new(&column_key)?),
+            ParquetCipher::AesGcmCtrV1 => Arc::new(CtrBlockDecryptor::new(&column_key)?),
+        })
     }
 
     pub(crate) fn get_column_metadata_decryptor(
@@ -649,8 +681,10 @@ impl FileDecryptor {
         column_name: &str,
         key_metadata: Option<&[u8]>,
     ) -> Result<Arc<dyn BlockDecryptor>> {
-        // Once GCM CTR mode is implemented, data and metadata decryptors may be different
-        self.get_column_data_decryptor(column_name, key_metadata)
+        let column_key = self
+            .decryption_properties
+            .column_key(column_name, key_metadata)?;
+        Ok(Arc::new(RingGcmBlockDecryptor::new(&column_key)?))
     }
 
     pub(crate) fn file_aad(&self) -> &Vec<u8> {
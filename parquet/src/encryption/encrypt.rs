@@ -18,7 +18,7 @@
 //! Configuration and utilities for Parquet Modular Encryption
 
 use crate::encryption::ciphers::{
-    BlockEncryptor, NONCE_LEN, RingGcmBlockEncryptor, SIZE_LEN, TAG_LEN,
+    BlockEncryptor, CtrBlockEncryptor, NONCE_LEN, RingGcmBlockEncryptor, SIZE_LEN, TAG_LEN,
 };
 use crate::errors::{ParquetError, Result};
 use crate::file::column_crypto_metadata::{ColumnCryptoMetaData, EncryptionWithColumnKey};
@@ -29,6 +29,20 @@ use std::collections::{HashMap, HashSet};
 use std::io::Write;
 use std::sync::Arc;
 
+/// The algorithm used to encrypt a Parquet file, see the
+/// [specification](https://github.com/apache/parquet-format/blob/master/Encryption.md#41-aes-gcm)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParquetCipher {
+    /// All modules are encrypted with AES-GCM (`AES_GCM_V1`)
+    #[default]
+    AesGcmV1,
+    /// Page data is encrypted with AES-CTR, and all other modules with AES-GCM
+    /// (`AES_GCM_CTR_V1`). This is faster than [`Self::AesGcmV1`], but the page
+    /// data is not protected against tampering.
+    AesGcmCtrV1,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 struct EncryptionKey {
     key: Vec<u8>,
@@ -90,7 +104,17 @@ impl EncryptionKey {
 ///     .build()?;
 /// # Ok::<(), parquet::errors::ParquetError>(())
 /// ```
+///
+/// Encrypt page data with AES-CTR rather than AES-GCM:
+/// ```
+/// # use parquet::encryption::encrypt::{FileEncryptionProperties, ParquetCipher};
+/// let file_encryption_properties = FileEncryptionProperties::builder(b"0123456789012345".into())
+///     .with_cipher(ParquetCipher::AesGcmCtrV1)
+///     .build()?;
+/// # Ok::<(), parquet::errors::ParquetError>(())
+/// ```
 pub struct FileEncryptionProperties {
+    cipher: ParquetCipher,
     encrypt_footer: bool,
     footer_key: EncryptionKey,
     column_keys: HashMap<String, EncryptionKey>,
@@ -104,6 +128,11 @@ impl FileEncryptionProperties {
         EncryptionPropertiesBuilder::new(footer_key)
     }
 
+    /// The algorithm used to encrypt the file
+    pub fn cipher(&self) -> ParquetCipher {
+        self.cipher
+    }
+
     /// Should the footer be encrypted
     pub fn encrypt_footer(&self) -> bool {
         self.encrypt_footer
@@ -181,6 +210,7 @@ impl FileEncryptionProperties {
 ///
 /// See [`FileEncryptionProperties`] for example usage.
 pub struct EncryptionPropertiesBuilder {
+    cipher: ParquetCipher,
     encrypt_footer: bool,
     footer_key: EncryptionKey,
     column_keys: HashMap<String, EncryptionKey>,
@@ -192,6 +222,7 @@ impl EncryptionPropertiesBuilder {
     /// Create a new [`EncryptionPropertiesBuilder`] with the given footer encryption key
     pub fn new(footer_key: Vec<u8>) -> EncryptionPropertiesBuilder {
         Self {
+            cipher: ParquetCipher::default(),
             footer_key: EncryptionKey::new(footer_key),
             column_keys: HashMap::default(),
             aad_prefix: None,
@@ -200,6 +231,12 @@ impl EncryptionPropertiesBuilder {
         }
     }
 
+    /// Set the algorithm used to encrypt the file. Defaults to [`ParquetCipher::AesGcmV1`].
+    pub fn with_cipher(mut self, cipher: ParquetCipher) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
     /// Set if the footer should be stored in plaintext (not encrypted). Defaults to false.
     pub fn with_plaintext_footer(mut self, plaintext_footer: bool) -> Self {
         self.encrypt_footer = !plaintext_footer;
@@ -277,6 +314,7 @@ impl EncryptionPropertiesBuilder {
     /// Build the encryption properties
     pub fn build(self) -> Result<Arc<FileEncryptionProperties>> {
         Ok(Arc::new(FileEncryptionProperties {
+            cipher: self.cipher,
             encrypt_footer: self.encrypt_footer,
             footer_key: self.footer_key,
             column_keys: self.column_keys,
@@ -348,18 +386,37 @@ impl FileEncryptor {
         )?))
     }
 
-    /// Get the encryptor for a column.
+    /// Get the encryptor for the metadata of a column, including its page headers.
     /// Will return an error if the column is not an encrypted column.
     pub(crate) fn get_column_encryptor(
         &self,
         column_path: &str,
     ) -> Result<Box<dyn BlockEncryptor>> {
+        Ok(Box::new(RingGcmBlockEncryptor::new(
+            self.column_key(column_path)?,
+        )?))
+    }
+
+    /// Get the encryptor for the page data of a column.
+    /// Will return an error if the column is not an encrypted column.
+    pub(crate) fn get_column_data_encryptor(
+        &self,
+        column_path: &str,
+    ) -> Result<Box<dyn BlockEncryptor>> {
+        let key = self.column_key(column_path)?;
+        Ok(match self.properties.cipher {
+            ParquetCipher::AesGcmV1 => Box::new(RingGcmBlockEncryptor::new(key)?),
+            ParquetCipher::AesGcmCtrV1 => Box::new(CtrBlockEncryptor::new(key)?),
+        })
+    }
+
+    fn column_key(&self, column_path: &str) -> Result<&[u8]> {
         if self.properties.column_keys.is_empty() {
-            return self.get_footer_encryptor();
+            return Ok(&self.properties.footer_key.key);
         }
         match self.properties.column_keys.get(column_path) {
             None => Err(general_err!("Column '{}' is not encrypted", column_path)),
-            Some(column_key) => Ok(Box::new(RingGcmBlockEncryptor::new(column_key.key())?)),
+            Some(column_key) => Ok(column_key.key()),
         }
     }
 }
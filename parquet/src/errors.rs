@@ -22,8 +22,10 @@ use std::error::Error;
 use std::string::FromUtf8Error;
 use std::{cell, io, result, str};
 
-#[cfg(feature = "arrow")]
 use arrow_schema::ArrowError;
+pub use arrow_schema::ErrorKind;
+
+use crate::schema::types::ColumnPath;
 
 /// Parquet error enumeration
 // Note: we don't implement PartialEq as the semantics for the
@@ -43,7 +45,8 @@ pub enum ParquetError {
     EOF(String),
     #[cfg(feature = "arrow")]
     /// Arrow error.
-    /// Returned when reading into arrow or writing from arrow.
+    /// Returned when an arrow schema or array is incompatible with the Parquet data
+    /// being read or written.
     ArrowError(String),
     /// Error when the requested column index is more than the
     /// number of columns in the row group
@@ -56,6 +59,135 @@ pub enum ParquetError {
     /// Returned when a function needs more data to complete properly.
     /// The `Range<u64>` indicates the range of bytes that are needed.
     NeedMoreDataRange(std::ops::Range<u64>),
+    /// Returned when the data being read is corrupt or violates the Parquet specification.
+    Corrupt(String),
+    /// Returned when a schema is invalid, or is incompatible with the data.
+    Schema(String),
+    /// An error annotated with the location in the file at which it occurred.
+    ///
+    /// Displays as the underlying error, see [`ParquetError::context`]
+    Context(Box<ParquetError>, Box<ErrorContext>),
+}
+
+/// The location in a Parquet file at which an error occurred, see [`ParquetError::context`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ErrorContext {
+    /// The path of the column being read
+    pub column: Option<ColumnPath>,
+    /// The index of the row group being read
+    pub row_group: Option<usize>,
+    /// The byte offset in the file of the page being read
+    pub offset: Option<u64>,
+}
+
+impl ErrorContext {
+    /// Creates an empty [`ErrorContext`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the path of the column being read
+    pub fn with_column(mut self, column: ColumnPath) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    /// Sets the index of the row group being read
+    pub fn with_row_group(mut self, row_group: usize) -> Self {
+        self.row_group = Some(row_group);
+        self
+    }
+
+    /// Sets the byte offset in the file of the page being read
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Fills any fields not set in `self` from `other`
+    fn merge(&mut self, other: ErrorContext) {
+        self.column = self.column.take().or(other.column);
+        self.row_group = self.row_group.or(other.row_group);
+        self.offset = self.offset.or(other.offset);
+    }
+}
+
+impl std::fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut sep = "";
+        if let Some(column) = &self.column {
+            write!(f, "column {column}")?;
+            sep = ", ";
+        }
+        if let Some(row_group) = self.row_group {
+            write!(f, "{sep}row group {row_group}")?;
+            sep = ", ";
+        }
+        if let Some(offset) = self.offset {
+            write!(f, "{sep}offset {offset}")?;
+        }
+        Ok(())
+    }
+}
+
+impl ParquetError {
+    /// Returns the [`ErrorKind`] of this error
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            ParquetError::NYI(_) => ErrorKind::Unsupported,
+            ParquetError::EOF(_) | ParquetError::Corrupt(_) => ErrorKind::Corrupt,
+            ParquetError::Schema(_) => ErrorKind::Schema,
+            #[cfg(feature = "arrow")]
+            ParquetError::ArrowError(_) => ErrorKind::Schema,
+            ParquetError::External(e) => external_kind(e.as_ref()),
+            ParquetError::Context(e, _) => e.kind(),
+            ParquetError::General(_)
+            | ParquetError::IndexOutOfBound(_, _)
+            | ParquetError::NeedMoreData(_)
+            | ParquetError::NeedMoreDataRange(_) => ErrorKind::Other,
+        }
+    }
+
+    /// Returns the location in the file at which this error occurred, if known
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            ParquetError::Context(_, context) => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Annotates this error with the location in the file at which it occurred
+    ///
+    /// If this error already has a context, any fields it does not set are filled from
+    /// `context`. [`ParquetError::NeedMoreData`] and [`ParquetError::NeedMoreDataRange`]
+    /// are returned unchanged, as callers are expected to match on them.
+    pub fn with_context(self, context: ErrorContext) -> Self {
+        match self {
+            ParquetError::Context(e, mut existing) => {
+                existing.merge(context);
+                ParquetError::Context(e, existing)
+            }
+            e @ (ParquetError::NeedMoreData(_) | ParquetError::NeedMoreDataRange(_)) => e,
+            e => ParquetError::Context(Box::new(e), Box::new(context)),
+        }
+    }
+}
+
+fn external_kind(e: &(dyn Error + Send + Sync + 'static)) -> ErrorKind {
+    if e.is::<io::Error>() {
+        return ErrorKind::Io;
+    }
+    if let Some(e) = e.downcast_ref::<ParquetError>() {
+        return e.kind();
+    }
+    if let Some(e) = e.downcast_ref::<ArrowError>() {
+        return match e {
+            ArrowError::ExternalError(e) => external_kind(e.as_ref()),
+            e => e.kind(),
+        };
+    }
+    ErrorKind::External
 }
 
 impl std::fmt::Display for ParquetError {
@@ -76,6 +208,12 @@ impl std::fmt::Display for ParquetError {
             ParquetError::NeedMoreDataRange(range) => {
                 write!(fmt, "NeedMoreDataRange: {}..{}", range.start, range.end)
             }
+            ParquetError::Corrupt(message) | ParquetError::Schema(message) => {
+                write!(fmt, "Parquet error: {message}")
+            }
+            // The context is available from `ParquetError::context`, and is not
+            // included in the message so that it is unchanged by annotation
+            ParquetError::Context(e, _) => write!(fmt, "{e}"),
         }
     }
 }
@@ -84,6 +222,7 @@ impl Error for ParquetError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             ParquetError::External(e) => Some(e.as_ref()),
+            ParquetError::Context(e, _) => e.source(),
             _ => None,
         }
     }
@@ -181,6 +320,16 @@ macro_rules! nyi_err {
     ($fmt:expr, $($args:expr),*) => (ParquetError::NYI(format!($fmt, $($args),*)));
 }
 
+macro_rules! corrupt_err {
+    ($fmt:expr) => (ParquetError::Corrupt($fmt.to_owned()));
+    ($fmt:expr, $($args:expr),*) => (ParquetError::Corrupt(format!($fmt, $($args),*)));
+}
+
+macro_rules! schema_err {
+    ($fmt:expr) => (ParquetError::Schema($fmt.to_owned()));
+    ($fmt:expr, $($args:expr),*) => (ParquetError::Schema(format!($fmt, $($args),*)));
+}
+
 macro_rules! eof_err {
     ($fmt:expr) => (ParquetError::EOF($fmt.to_owned()));
     ($fmt:expr, $($args:expr),*) => (ParquetError::EOF(format!($fmt, $($args),*)));
@@ -204,3 +353,79 @@ impl From<ParquetError> for ArrowError {
         Self::ParquetError(format!("{p}"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind() {
+        assert_eq!(nyi_err!("x").kind(), ErrorKind::Unsupported);
+        assert_eq!(eof_err!("x").kind(), ErrorKind::Corrupt);
+        assert_eq!(corrupt_err!("x").kind(), ErrorKind::Corrupt);
+        assert_eq!(schema_err!("x").kind(), ErrorKind::Schema);
+        assert_eq!(general_err!("x").kind(), ErrorKind::Other);
+
+        let io = io::Error::new(io::ErrorKind::UnexpectedEof, "eof");
+        assert_eq!(ParquetError::from(io).kind(), ErrorKind::Io);
+        let utf8 = String::from_utf8(vec![0xff]).unwrap_err();
+        assert_eq!(ParquetError::from(utf8).kind(), ErrorKind::External);
+        let nested = ParquetError::External(Box::new(corrupt_err!("x")));
+        assert_eq!(nested.kind(), ErrorKind::Corrupt);
+    }
+
+    #[test]
+    #[cfg(feature = "arrow")]
+    fn test_arrow_kind() {
+        let kind = |e: ArrowError| ParquetError::from(e).kind();
+        assert_eq!(kind(ArrowError::SchemaError("x".into())), ErrorKind::Schema);
+        assert_eq!(kind(ArrowError::CastError("x".into())), ErrorKind::Other);
+        assert_eq!(kind(ArrowError::ComputeError("x".into())), ErrorKind::Other);
+        assert_eq!(kind(ArrowError::IpcError("x".into())), ErrorKind::Corrupt);
+        assert_eq!(
+            kind(ArrowError::NotYetImplemented("x".into())),
+            ErrorKind::Unsupported
+        );
+        let io = io::Error::new(io::ErrorKind::UnexpectedEof, "eof");
+        assert_eq!(kind(ArrowError::IoError("x".into(), io)), ErrorKind::Io);
+        assert_eq!(
+            kind(ArrowError::CDataInterface("x".into())),
+            ErrorKind::External
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow")]
+    fn test_arrow_conversion() {
+        let column = ColumnPath::from("a");
+        let err = corrupt_err!("bad page")
+            .with_context(ErrorContext::new().with_column(column).with_row_group(2));
+
+        let arrow = ArrowError::from(err);
+        assert!(matches!(arrow, ArrowError::ParquetError(_)));
+        assert_eq!(
+            arrow.to_string(),
+            "Parquet argument error: Parquet error: bad page"
+        );
+    }
+
+    #[test]
+    fn test_context() {
+        let column = ColumnPath::from(vec!["a".to_string(), "b".to_string()]);
+        let err = corrupt_err!("bad page")
+            .with_context(ErrorContext::new().with_offset(4))
+            .with_context(ErrorContext::new().with_column(column).with_offset(8));
+
+        assert_eq!(err.kind(), ErrorKind::Corrupt);
+        let context = err.context().unwrap();
+        assert_eq!(context.column.as_ref().unwrap().string(), "a.b");
+        assert_eq!(context.row_group, None);
+        assert_eq!(context.offset, Some(4));
+        assert_eq!(context.to_string(), "column \"a.b\", offset 4");
+        assert_eq!(err.to_string(), "Parquet error: bad page");
+
+        let err = ParquetError::NeedMoreData(4).with_context(ErrorContext::new().with_offset(4));
+        assert!(matches!(err, ParquetError::NeedMoreData(4)));
+        assert!(err.context().is_none());
+    }
+}
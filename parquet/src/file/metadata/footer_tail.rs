@@ -64,7 +64,7 @@ impl FooterTail {
         } else if magic == PARQUET_MAGIC {
             false
         } else {
-            return Err(general_err!("Invalid Parquet file. Corrupt footer"));
+            return Err(corrupt_err!("Invalid Parquet file. Corrupt footer"));
         };
         // get the metadata length from the footer
         let metadata_len = u32::from_le_bytes(slice[..4].try_into().unwrap());
@@ -100,7 +100,7 @@ impl TryFrom<&[u8]> for FooterTail {
 
     fn try_from(value: &[u8]) -> Result<Self> {
         if value.len() != FOOTER_SIZE {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "Invalid footer length {}, expected {FOOTER_SIZE}",
                 value.len()
             ));
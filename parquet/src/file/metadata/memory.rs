@@ -175,6 +175,7 @@ impl HeapSize for ColumnChunkMetaData {
             + self.unencoded_byte_array_data_bytes.heap_size()
             + self.repetition_level_histogram.heap_size()
             + self.definition_level_histogram.heap_size()
+            + self.key_value_metadata.heap_size()
             + self.geo_statistics.heap_size()
             + encryption_heap_size
     }
@@ -307,6 +308,11 @@ impl HeapSize for i64 {
         0 // no heap allocations
     }
 }
+impl HeapSize for u64 {
+    fn heap_size(&self) -> usize {
+        0 // no heap allocations
+    }
+}
 
 impl HeapSize for f32 {
     fn heap_size(&self) -> usize {
@@ -41,6 +41,8 @@
 //! * [`ParquetMetaDataReader`] for reading metadata from an I/O source (sync and async)
 //! * [`ParquetMetaDataPushDecoder`] for decoding from bytes without I/O
 //! * [`ParquetMetaDataWriter`] for writing.
+//! * [`ParquetMetaData::to_bytes`] and [`ParquetMetaData::from_bytes`] for
+//!   caching metadata outside of the file.
 //!
 //! # Examples
 //!
@@ -127,13 +129,14 @@ use crate::{
     data_type::private::ParquetValueType, file::page_index::offset_index::OffsetIndexMetaData,
 };
 
+use bytes::Bytes;
 pub use footer_tail::FooterTail;
 pub use options::{ParquetMetaDataOptions, ParquetStatisticsPolicy};
 pub use push_decoder::ParquetMetaDataPushDecoder;
 pub use reader::{PageIndexPolicy, ParquetMetaDataReader};
 use std::io::Write;
 use std::ops::Range;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 pub use writer::ParquetMetaDataWriter;
 pub(crate) use writer::ThriftMetadataWriter;
 
@@ -198,8 +201,32 @@ pub struct ParquetMetaData {
     /// Optional file decryptor
     #[cfg(feature = "encryption")]
     file_decryptor: Option<Box<FileDecryptor>>,
+    /// Lazily computed index within the file of the first row of each row group
+    row_group_offsets: RowGroupOffsets,
 }
 
+/// The index within the file of the first row of each row group, followed by the
+/// total number of rows, computed on first use
+///
+/// As this is derived from the row groups, it is ignored when comparing [`ParquetMetaData`]
+#[derive(Debug, Clone, Default)]
+struct RowGroupOffsets(OnceLock<Vec<u64>>);
+
+impl PartialEq for RowGroupOffsets {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+/// Identifies the bytes of [`ParquetMetaData::to_bytes`]
+const SERIALIZED_MAGIC: &[u8; 4] = b"PQMD";
+
+/// The version of the format of [`ParquetMetaData::to_bytes`]
+const SERIALIZED_VERSION: u32 = 1;
+
+/// The length of the header of [`ParquetMetaData::to_bytes`]: magic, version and length
+const SERIALIZED_HEADER_LEN: usize = 16;
+
 impl ParquetMetaData {
     /// Creates Parquet metadata from file metadata and a list of row
     /// group metadata
@@ -211,6 +238,7 @@ impl ParquetMetaData {
             offset_index: None,
             #[cfg(feature = "encryption")]
             file_decryptor: None,
+            row_group_offsets: RowGroupOffsets::default(),
         }
     }
 
@@ -253,6 +281,69 @@ impl ParquetMetaData {
         &self.row_groups
     }
 
+    /// Returns the index of the row group containing the row at `row_index` within
+    /// the file, and the offset of that row within the row group.
+    ///
+    /// Returns `None` if `row_index` is not less than the total number of rows in
+    /// the row groups.
+    ///
+    /// The first call computes the first row of each row group, in `O(n)` for `n`
+    /// row groups, after which lookups perform a binary search in `O(log n)`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use parquet::file::metadata::ParquetMetaData;
+    /// # fn load_metadata() -> ParquetMetaData { unimplemented!(); }
+    /// let metadata = load_metadata();
+    /// // Find the row group containing the 1000th row of the file
+    /// if let Some((row_group, offset)) = metadata.row_group_for_row(999) {
+    ///     let range = metadata.row_group_row_range(row_group);
+    ///     assert_eq!(range.start + offset, 999);
+    /// }
+    /// ```
+    pub fn row_group_for_row(&self, row_index: u64) -> Option<(usize, u64)> {
+        let offsets = self.row_group_offsets();
+        if row_index >= *offsets.last()? {
+            return None;
+        }
+        // Find the last row group starting at or before `row_index`, which skips
+        // any empty row groups starting at the same row
+        let idx = offsets.partition_point(|&offset| offset <= row_index) - 1;
+        Some((idx, row_index - offsets[idx]))
+    }
+
+    /// Returns the range of rows within the file contained in the `i`th row group.
+    ///
+    /// See [`Self::row_group_for_row`] for the inverse lookup.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than [`Self::num_row_groups`].
+    pub fn row_group_row_range(&self, i: usize) -> Range<u64> {
+        assert!(
+            i < self.row_groups.len(),
+            "row group index {i} out of bounds for {} row groups",
+            self.row_groups.len()
+        );
+        let offsets = self.row_group_offsets();
+        offsets[i]..offsets[i + 1]
+    }
+
+    fn row_group_offsets(&self) -> &[u64] {
+        self.row_group_offsets.0.get_or_init(|| {
+            let mut offsets = Vec::with_capacity(self.row_groups.len() + 1);
+            offsets.push(0);
+            let mut total = 0;
+            for row_group in &self.row_groups {
+                // Negative row counts are invalid, and treated as empty
+                total += row_group.num_rows().max(0) as u64;
+                offsets.push(total);
+            }
+            offsets
+        })
+    }
+
     /// Returns the column index for this file if loaded
     ///
     /// Returns `None` if the parquet file does not have a `ColumnIndex` or
@@ -273,6 +364,31 @@ impl ParquetMetaData {
         self.offset_index.as_ref()
     }
 
+    /// Returns the total size in bytes of the `ColumnIndex` structures written
+    /// to this file, as recorded in the column chunk metadata
+    ///
+    /// Returns `0` if no column chunk has a `ColumnIndex`.
+    pub fn column_index_size(&self) -> u64 {
+        self.index_size(ColumnChunkMetaData::column_index_length)
+    }
+
+    /// Returns the total size in bytes of the `OffsetIndex` structures written
+    /// to this file, as recorded in the column chunk metadata
+    ///
+    /// Returns `0` if no column chunk has an `OffsetIndex`.
+    pub fn offset_index_size(&self) -> u64 {
+        self.index_size(ColumnChunkMetaData::offset_index_length)
+    }
+
+    fn index_size(&self, length: impl Fn(&ColumnChunkMetaData) -> Option<i32>) -> u64 {
+        self.row_groups
+            .iter()
+            .flat_map(|rg| rg.columns())
+            .filter_map(&length)
+            .map(|len| len as u64)
+            .sum()
+    }
+
     /// Estimate of the bytes allocated to store `ParquetMetadata`
     ///
     /// # Notes:
@@ -298,9 +414,148 @@ impl ParquetMetaData {
             + self.row_groups.heap_size()
             + self.column_index.heap_size()
             + self.offset_index.heap_size()
+            + self.row_group_offsets.0.get().map_or(0, |o| o.heap_size())
             + encryption_size
     }
 
+    /// Serializes this metadata, including any loaded page indexes, so that it can
+    /// be cached externally and decoded with [`Self::from_bytes`]
+    ///
+    /// Caching the serialized metadata avoids fetching the footer and page indexes
+    /// from the file each time it is opened, which is especially beneficial for
+    /// files stored in object stores.
+    ///
+    /// The serialized bytes are laid out as follows:
+    ///
+    /// 1. The magic bytes `PQMD` and the format version (4 bytes, little endian)
+    /// 2. Length of (3) (8 bytes, little endian)
+    /// 3. The page indexes and footer, as written by [`ParquetMetaDataWriter`], see
+    ///    [`ParquetMetaDataWriter#output-format`]
+    /// 4. For each [`ColumnChunkMetaData`], the locations of its `ColumnIndex` and
+    ///    `OffsetIndex` in the original file: a byte whose bits 0 to 3 mark which of
+    ///    the column index offset, column index length, offset index offset and offset
+    ///    index length are present, followed by those present (8 byte offsets and 4 byte
+    ///    lengths, little endian)
+    ///
+    /// As the page indexes in (3) are located relative to its start, (4) records their
+    /// locations in the original file so that [`ColumnChunkMetaData::column_index_range`]
+    /// and [`ColumnChunkMetaData::offset_index_range`] remain valid after decoding.
+    ///
+    /// Note that page encoding statistics decoded as a mask, see
+    /// [`ParquetMetaDataOptions::with_encoding_stats_as_mask`], are not serialized.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use parquet::file::metadata::ParquetMetaData;
+    /// # fn load_metadata() -> ParquetMetaData { unimplemented!(); }
+    /// let metadata = load_metadata();
+    /// let bytes = metadata.to_bytes().unwrap();
+    /// // store `bytes` in a cache, and later decode them
+    /// let decoded = ParquetMetaData::from_bytes(&bytes).unwrap();
+    /// assert_eq!(decoded.row_groups().len(), metadata.row_groups().len());
+    /// ```
+    pub fn to_bytes(&self) -> Result<Bytes> {
+        let mut buf = Vec::with_capacity(SERIALIZED_HEADER_LEN);
+        buf.extend_from_slice(SERIALIZED_MAGIC);
+        buf.extend_from_slice(&SERIALIZED_VERSION.to_le_bytes());
+        buf.extend_from_slice(&[0; 8]);
+
+        // Only page indexes written to `buf` may be located by the serialized footer
+        ParquetMetaDataWriter::new(&mut buf, self)
+            .with_page_index_locations(false)
+            .finish()?;
+        let metadata_len = (buf.len() - SERIALIZED_HEADER_LEN) as u64;
+        buf[8..SERIALIZED_HEADER_LEN].copy_from_slice(&metadata_len.to_le_bytes());
+
+        for column in self.row_groups.iter().flat_map(|rg| rg.columns()) {
+            let present = column.column_index_offset.is_some() as u8
+                | (column.column_index_length.is_some() as u8) << 1
+                | (column.offset_index_offset.is_some() as u8) << 2
+                | (column.offset_index_length.is_some() as u8) << 3;
+            buf.push(present);
+            if let Some(offset) = column.column_index_offset {
+                buf.extend_from_slice(&offset.to_le_bytes());
+            }
+            if let Some(length) = column.column_index_length {
+                buf.extend_from_slice(&length.to_le_bytes());
+            }
+            if let Some(offset) = column.offset_index_offset {
+                buf.extend_from_slice(&offset.to_le_bytes());
+            }
+            if let Some(length) = column.offset_index_length {
+                buf.extend_from_slice(&length.to_le_bytes());
+            }
+        }
+        Ok(buf.into())
+    }
+
+    /// Decodes metadata serialized with [`Self::to_bytes`], including any page
+    /// indexes
+    pub fn from_bytes(bytes: &Bytes) -> Result<Self> {
+        let invalid = || corrupt_err!("Invalid serialized ParquetMetaData");
+
+        if bytes.len() < SERIALIZED_HEADER_LEN || &bytes[..4] != SERIALIZED_MAGIC {
+            return Err(invalid());
+        }
+        let version = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        if version != SERIALIZED_VERSION {
+            return Err(nyi_err!(
+                "Serialized ParquetMetaData version {}, expected {}",
+                version,
+                SERIALIZED_VERSION
+            ));
+        }
+        let metadata_len = u64::from_le_bytes(bytes[8..SERIALIZED_HEADER_LEN].try_into().unwrap());
+        let metadata_end = usize::try_from(metadata_len)
+            .ok()
+            .and_then(|len| len.checked_add(SERIALIZED_HEADER_LEN))
+            .filter(|end| *end <= bytes.len())
+            .ok_or_else(invalid)?;
+
+        let mut metadata = ParquetMetaDataReader::new()
+            .with_page_index_policy(PageIndexPolicy::Optional)
+            .parse_and_finish(&bytes.slice(SERIALIZED_HEADER_LEN..metadata_end))?;
+
+        // Restore the locations of the page indexes in the original file, the
+        // bits of each column chunk's leading byte mark which of them are present
+        fn take<const N: usize>(locations: &mut &[u8], present: bool) -> Option<Option<[u8; N]>> {
+            if !present {
+                return Some(None);
+            }
+            let (value, rest) = locations.split_first_chunk::<N>()?;
+            *locations = rest;
+            Some(Some(*value))
+        }
+        let mut locations = &bytes[metadata_end..];
+        for column in metadata
+            .row_groups
+            .iter_mut()
+            .flat_map(|rg| &mut rg.columns)
+        {
+            let [present] = take(&mut locations, true).flatten().ok_or_else(invalid)?;
+            if present >> 4 != 0 {
+                return Err(invalid());
+            }
+            let locations = &mut locations;
+            column.column_index_offset = take(locations, present & 1 != 0)
+                .ok_or_else(invalid)?
+                .map(i64::from_le_bytes);
+            column.column_index_length = take(locations, present & 2 != 0)
+                .ok_or_else(invalid)?
+                .map(i32::from_le_bytes);
+            column.offset_index_offset = take(locations, present & 4 != 0)
+                .ok_or_else(invalid)?
+                .map(i64::from_le_bytes);
+            column.offset_index_length = take(locations, present & 8 != 0)
+                .ok_or_else(invalid)?
+                .map(i32::from_le_bytes);
+        }
+        if !locations.is_empty() {
+            return Err(invalid());
+        }
+        Ok(metadata)
+    }
+
     /// Override the column index
     pub(crate) fn set_column_index(&mut self, index: Option<ParquetColumnIndex>) {
         self.column_index = index;
@@ -429,7 +684,9 @@ impl ParquetMetaDataBuilder {
 
     /// Creates a new ParquetMetaData from the builder
     pub fn build(self) -> ParquetMetaData {
-        let Self(metadata) = self;
+        let Self(mut metadata) = self;
+        // The row groups may have been modified
+        metadata.row_group_offsets = RowGroupOffsets::default();
         metadata
     }
 }
@@ -832,6 +1089,7 @@ pub struct ColumnChunkMetaData {
     unencoded_byte_array_data_bytes: Option<i64>,
     repetition_level_histogram: Option<LevelHistogram>,
     definition_level_histogram: Option<LevelHistogram>,
+    key_value_metadata: Option<Vec<KeyValue>>,
     #[cfg(feature = "encryption")]
     column_crypto_metadata: Option<Box<ColumnCryptoMetaData>>,
     #[cfg(feature = "encryption")]
@@ -1134,11 +1392,14 @@ impl ColumnChunkMetaData {
         self.column_index_length
     }
 
-    /// Returns the range for the offset index if any
-    pub(crate) fn column_index_range(&self) -> Option<Range<u64>> {
+    /// Returns the byte range of the column index within the file, if any.
+    ///
+    /// Returns `None` if the column index offset or length is not set, or is negative.
+    /// See [`ParquetMetaDataReader`] to read and decode the page indexes of a file.
+    pub fn column_index_range(&self) -> Option<Range<u64>> {
         let offset = u64::try_from(self.column_index_offset?).ok()?;
         let length = u64::try_from(self.column_index_length?).ok()?;
-        Some(offset..(offset + length))
+        Some(offset..offset.checked_add(length)?)
     }
 
     /// Returns the offset for the offset index.
@@ -1151,11 +1412,13 @@ impl ColumnChunkMetaData {
         self.offset_index_length
     }
 
-    /// Returns the range for the offset index if any
-    pub(crate) fn offset_index_range(&self) -> Option<Range<u64>> {
+    /// Returns the byte range of the offset index within the file, if any.
+    ///
+    /// Returns `None` if the offset index offset or length is not set, or is negative.
+    pub fn offset_index_range(&self) -> Option<Range<u64>> {
         let offset = u64::try_from(self.offset_index_offset?).ok()?;
         let length = u64::try_from(self.offset_index_length?).ok()?;
-        Some(offset..(offset + length))
+        Some(offset..offset.checked_add(length)?)
     }
 
     /// Returns the number of bytes of variable length data after decoding.
@@ -1184,6 +1447,15 @@ impl ColumnChunkMetaData {
         self.definition_level_histogram.as_ref()
     }
 
+    /// Returns the custom key-value metadata of this column chunk.
+    ///
+    /// See [`WriterPropertiesBuilder::set_column_key_value_metadata`] for writing it.
+    ///
+    /// [`WriterPropertiesBuilder::set_column_key_value_metadata`]: crate::file::properties::WriterPropertiesBuilder::set_column_key_value_metadata
+    pub fn key_value_metadata(&self) -> Option<&Vec<KeyValue>> {
+        self.key_value_metadata.as_ref()
+    }
+
     /// Returns the encryption metadata for this column chunk.
     #[cfg(feature = "encryption")]
     pub fn crypto_metadata(&self) -> Option<&ColumnCryptoMetaData> {
@@ -1245,6 +1517,7 @@ impl ColumnChunkMetaDataBuilder {
             unencoded_byte_array_data_bytes: None,
             repetition_level_histogram: None,
             definition_level_histogram: None,
+            key_value_metadata: None,
             #[cfg(feature = "encryption")]
             column_crypto_metadata: None,
             #[cfg(feature = "encryption")]
@@ -1408,6 +1681,12 @@ impl ColumnChunkMetaDataBuilder {
         self
     }
 
+    /// Sets optional custom key-value metadata.
+    pub fn set_key_value_metadata(mut self, value: Option<Vec<KeyValue>>) -> Self {
+        self.0.key_value_metadata = value;
+        self
+    }
+
     #[cfg(feature = "encryption")]
     /// Set the encryption metadata for an encrypted column
     pub fn set_column_crypto_metadata(mut self, value: Option<ColumnCryptoMetaData>) -> Self {
@@ -1923,6 +2202,31 @@ mod tests {
         assert_eq!(col_chunk_res, col_metadata);
     }
 
+    #[test]
+    fn test_page_index_ranges() {
+        let column_descr = get_test_schema_descr().column(0);
+
+        let col_metadata = ColumnChunkMetaData::builder(column_descr.clone())
+            .set_offset_index_offset(Some(7000))
+            .set_offset_index_length(Some(25))
+            .set_column_index_offset(Some(8000))
+            .set_column_index_length(Some(25))
+            .build()
+            .unwrap();
+        assert_eq!(col_metadata.column_index_range(), Some(8000..8025));
+        assert_eq!(col_metadata.offset_index_range(), Some(7000..7025));
+
+        // Missing or negative offsets and lengths have no range
+        let col_metadata = ColumnChunkMetaData::builder(column_descr.clone())
+            .set_offset_index_offset(Some(7000))
+            .set_column_index_offset(Some(-1))
+            .set_column_index_length(Some(25))
+            .build()
+            .unwrap();
+        assert_eq!(col_metadata.column_index_range(), None);
+        assert_eq!(col_metadata.offset_index_range(), None);
+    }
+
     #[test]
     fn test_compressed_size() {
         let schema_descr = get_test_schema_descr();
@@ -1948,6 +2252,61 @@ mod tests {
         assert_eq!(compressed_size_res, compressed_size_exp);
     }
 
+    #[test]
+    fn test_row_group_for_row() {
+        let schema_descr = get_test_schema_descr();
+        let file_metadata = FileMetaData::new(2, 15, None, None, schema_descr.clone(), None);
+        let row_groups = [5, 0, 3, 7, 0].map(|num_rows| test_row_group(&schema_descr, num_rows));
+        let metadata = ParquetMetaDataBuilder::new(file_metadata.clone())
+            .set_row_groups(row_groups.to_vec())
+            .build();
+
+        let ranges: Vec<_> = (0..5).map(|i| metadata.row_group_row_range(i)).collect();
+        assert_eq!(ranges, vec![0..5, 5..5, 5..8, 8..15, 15..15]);
+
+        assert_eq!(metadata.row_group_for_row(0), Some((0, 0)));
+        assert_eq!(metadata.row_group_for_row(4), Some((0, 4)));
+        // Empty row groups are skipped
+        assert_eq!(metadata.row_group_for_row(5), Some((2, 0)));
+        assert_eq!(metadata.row_group_for_row(7), Some((2, 2)));
+        assert_eq!(metadata.row_group_for_row(8), Some((3, 0)));
+        assert_eq!(metadata.row_group_for_row(14), Some((3, 6)));
+        assert_eq!(metadata.row_group_for_row(15), None);
+        assert_eq!(metadata.row_group_for_row(u64::MAX), None);
+
+        // Every row maps back to itself
+        for row in 0..15 {
+            let (idx, offset) = metadata.row_group_for_row(row).unwrap();
+            assert_eq!(metadata.row_group_row_range(idx).start + offset, row);
+        }
+
+        // The cached offsets are ignored by comparisons, and recomputed if the row groups change
+        let unused = ParquetMetaDataBuilder::new(file_metadata.clone())
+            .set_row_groups(row_groups.to_vec())
+            .build();
+        assert_eq!(metadata, unused);
+
+        let mut builder = metadata.into_builder();
+        builder.take_row_groups();
+        let metadata = builder.add_row_group(row_groups[3].clone()).build();
+        assert_eq!(metadata.row_group_for_row(6), Some((0, 6)));
+        assert_eq!(metadata.row_group_for_row(7), None);
+        assert_eq!(metadata.row_group_row_range(0), 0..7);
+
+        let empty = ParquetMetaDataBuilder::new(file_metadata).build();
+        assert_eq!(empty.row_group_for_row(0), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "row group index 1 out of bounds for 1 row groups")]
+    fn test_row_group_row_range_out_of_bounds() {
+        let schema_descr = get_test_schema_descr();
+        let file_metadata = FileMetaData::new(2, 1, None, None, schema_descr.clone(), None);
+        let row_group = test_row_group(&schema_descr, 1);
+        let metadata = ParquetMetaData::new(file_metadata, vec![row_group]);
+        metadata.row_group_row_range(1);
+    }
+
     #[test]
     fn test_memory_size() {
         let schema_descr = get_test_schema_descr();
@@ -2019,9 +2378,9 @@ mod tests {
             .build();
 
         #[cfg(not(feature = "encryption"))]
-        let base_expected_size = 2766;
+        let base_expected_size = 2894;
         #[cfg(feature = "encryption")]
-        let base_expected_size = 2934;
+        let base_expected_size = 3062;
 
         assert_eq!(parquet_meta.memory_size(), base_expected_size);
 
@@ -2050,9 +2409,9 @@ mod tests {
             .build();
 
         #[cfg(not(feature = "encryption"))]
-        let bigger_expected_size = 3192;
+        let bigger_expected_size = 3320;
         #[cfg(feature = "encryption")]
-        let bigger_expected_size = 3360;
+        let bigger_expected_size = 3488;
 
         // more set fields means more memory usage
         assert!(bigger_expected_size > base_expected_size);
@@ -2063,6 +2422,7 @@ mod tests {
     #[cfg(feature = "encryption")]
     fn test_memory_size_with_decryptor() {
         use crate::encryption::decrypt::FileDecryptionProperties;
+        use crate::encryption::encrypt::ParquetCipher;
         use crate::file::metadata::thrift::encryption::AesGcmV1;
 
         let schema_descr = get_test_schema_descr();
@@ -2099,7 +2459,7 @@ mod tests {
             .set_row_groups(row_group_meta.clone())
             .build();
 
-        let base_expected_size = 2058;
+        let base_expected_size = 2138;
         assert_eq!(parquet_meta_data.memory_size(), base_expected_size);
 
         let footer_key = "0123456789012345".as_bytes();
@@ -2114,6 +2474,7 @@ mod tests {
         let decryption_properties = decryption_properties_builder.build().unwrap();
         let decryptor = FileDecryptor::new(
             &decryption_properties,
+            ParquetCipher::AesGcmV1,
             footer_key_metadata.as_deref(),
             aad_file_unique,
             aad_prefix,
@@ -2125,7 +2486,7 @@ mod tests {
             .set_file_decryptor(Some(decryptor))
             .build();
 
-        let expected_size_with_decryptor = 3072;
+        let expected_size_with_decryptor = 3176;
         assert!(expected_size_with_decryptor > base_expected_size);
 
         assert_eq!(
@@ -2134,6 +2495,21 @@ mod tests {
         );
     }
 
+    /// Returns a row group with `num_rows` rows and empty column chunks
+    fn test_row_group(schema_descr: &SchemaDescPtr, num_rows: i64) -> RowGroupMetaData {
+        let columns = schema_descr
+            .columns()
+            .iter()
+            .map(|column_descr| ColumnChunkMetaData::builder(column_descr.clone()).build())
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        RowGroupMetaData::builder(schema_descr.clone())
+            .set_num_rows(num_rows)
+            .set_column_metadata(columns)
+            .build()
+            .unwrap()
+    }
+
     /// Returns sample schema descriptor so we can create column metadata.
     fn get_test_schema_descr() -> SchemaDescPtr {
         let schema = SchemaType::group_type_builder("schema")
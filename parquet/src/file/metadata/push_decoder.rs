@@ -241,7 +241,7 @@ impl ParquetMetaDataPushDecoder {
     /// See examples on [`ParquetMetaDataPushDecoder`].
     pub fn try_new(file_len: u64) -> Result<Self> {
         if file_len < 8 {
-            return Err(ParquetError::General(format!(
+            return Err(ParquetError::Corrupt(format!(
                 "Parquet files are at least 8 bytes long, but file length is {file_len}"
             )));
         };
@@ -18,7 +18,10 @@
 //! Encryption support for Thrift serialization
 
 use crate::{
-    encryption::decrypt::{FileDecryptionProperties, FileDecryptor},
+    encryption::{
+        decrypt::{FileDecryptionProperties, FileDecryptor},
+        encrypt::ParquetCipher,
+    },
     errors::{ParquetError, Result},
     file::{
         column_crypto_metadata::ColumnCryptoMetaData,
@@ -118,7 +121,7 @@ fn row_group_from_encrypted_thrift(
     let schema_descr = rg.schema_descr;
 
     if schema_descr.num_columns() != rg.columns.len() {
-        return Err(general_err!(
+        return Err(corrupt_err!(
             "Column count mismatch. Schema has {} columns while Row Group has {}",
             schema_descr.num_columns(),
             rg.columns.len()
@@ -235,10 +238,10 @@ pub(crate) fn parquet_metadata_with_encryption(
         if let Some(file_decryption_properties) = file_decryption_properties {
             let t_file_crypto_metadata: FileCryptoMetaData =
                 FileCryptoMetaData::read_thrift(&mut prot)
-                    .map_err(|e| general_err!("Could not parse crypto metadata: {}", e))?;
+                    .map_err(|e| corrupt_err!("Could not parse crypto metadata: {}", e))?;
             let supply_aad_prefix = match &t_file_crypto_metadata.encryption_algorithm {
                 EncryptionAlgorithm::AES_GCM_V1(algo) => algo.supply_aad_prefix,
-                _ => Some(false),
+                EncryptionAlgorithm::AES_GCM_CTR_V1(algo) => algo.supply_aad_prefix,
             }
             .unwrap_or(false);
             if supply_aad_prefix && file_decryption_properties.aad_prefix().is_none() {
@@ -273,7 +276,7 @@ pub(crate) fn parquet_metadata_with_encryption(
     }
 
     let parquet_meta = parquet_metadata_from_bytes(buf, options)
-        .map_err(|e| general_err!("Could not parse metadata: {}", e))?;
+        .map_err(|e| corrupt_err!("Could not parse metadata: {}", e))?;
 
     let ParquetMetaData {
         mut file_metadata,
@@ -281,6 +284,7 @@ pub(crate) fn parquet_metadata_with_encryption(
         column_index: _,
         offset_index: _,
         file_decryptor: _,
+        row_group_offsets: _,
     } = parquet_meta;
 
     // Take the encryption algorithm and footer signing key metadata as they are no longer
@@ -322,27 +326,32 @@ fn get_file_decryptor(
     footer_key_metadata: Option<&[u8]>,
     file_decryption_properties: &Arc<FileDecryptionProperties>,
 ) -> Result<FileDecryptor> {
-    match encryption_algorithm {
-        EncryptionAlgorithm::AES_GCM_V1(algo) => {
-            let aad_file_unique = algo
-                .aad_file_unique
-                .ok_or_else(|| general_err!("AAD unique file identifier is not set"))?;
-            let aad_prefix = if let Some(aad_prefix) = file_decryption_properties.aad_prefix() {
-                aad_prefix.clone()
-            } else {
-                algo.aad_prefix.map(|v| v.to_vec()).unwrap_or_default()
-            };
-            let aad_file_unique = aad_file_unique.to_vec();
-
-            FileDecryptor::new(
-                file_decryption_properties,
-                footer_key_metadata,
-                aad_file_unique,
-                aad_prefix,
-            )
-        }
-        EncryptionAlgorithm::AES_GCM_CTR_V1(_) => Err(nyi_err!(
-            "The AES_GCM_CTR_V1 encryption algorithm is not yet supported"
-        )),
-    }
+    let (cipher, aad_prefix, aad_file_unique) = match encryption_algorithm {
+        EncryptionAlgorithm::AES_GCM_V1(algo) => (
+            ParquetCipher::AesGcmV1,
+            algo.aad_prefix,
+            algo.aad_file_unique,
+        ),
+        EncryptionAlgorithm::AES_GCM_CTR_V1(algo) => (
+            ParquetCipher::AesGcmCtrV1,
+            algo.aad_prefix,
+            algo.aad_file_unique,
+        ),
+    };
+    let aad_file_unique =
+        aad_file_unique.ok_or_else(|| general_err!("AAD unique file identifier is not set"))?;
+    let aad_prefix = if let Some(aad_prefix) = file_decryption_properties.aad_prefix() {
+        aad_prefix.clone()
+    } else {
+        aad_prefix.map(|v| v.to_vec()).unwrap_or_default()
+    };
+    let aad_file_unique = aad_file_unique.to_vec();
+
+    FileDecryptor::new(
+        file_decryption_properties,
+        cipher,
+        footer_key_metadata,
+        aad_file_unique,
+        aad_prefix,
+    )
 }
@@ -197,7 +197,7 @@ fn convert_stats(
                 .null_count
                 .map(|null_count| {
                     if null_count < 0 {
-                        return Err(general_err!(
+                        return Err(corrupt_err!(
                             "Statistics null count is negative {}",
                             null_count
                         ));
@@ -225,12 +225,12 @@ fn convert_stats(
             fn check_len(min: &Option<&[u8]>, max: &Option<&[u8]>, len: usize) -> Result<()> {
                 if let Some(min) = min {
                     if min.len() < len {
-                        return Err(general_err!("Insufficient bytes to parse min statistic",));
+                        return Err(corrupt_err!("Insufficient bytes to parse min statistic",));
                     }
                 }
                 if let Some(max) = max {
                     if max.len() < len {
-                        return Err(general_err!("Insufficient bytes to parse max statistic",));
+                        return Err(corrupt_err!("Insufficient bytes to parse max statistic",));
                     }
                 }
                 Ok(())
@@ -354,27 +354,27 @@ const COL_META_ALL_REQUIRED: u16 = COL_META_TYPE
 fn validate_column_metadata(mask: u16) -> Result<()> {
     if mask != COL_META_ALL_REQUIRED {
         if mask & COL_META_ENCODINGS == 0 {
-            return Err(general_err!("Required field encodings is missing"));
+            return Err(corrupt_err!("Required field encodings is missing"));
         }
 
         if mask & COL_META_CODEC == 0 {
-            return Err(general_err!("Required field codec is missing"));
+            return Err(corrupt_err!("Required field codec is missing"));
         }
         if mask & COL_META_NUM_VALUES == 0 {
-            return Err(general_err!("Required field num_values is missing"));
+            return Err(corrupt_err!("Required field num_values is missing"));
         }
         if mask & COL_META_TOTAL_UNCOMP_SZ == 0 {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "Required field total_uncompressed_size is missing"
             ));
         }
         if mask & COL_META_TOTAL_COMP_SZ == 0 {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "Required field total_compressed_size is missing"
             ));
         }
         if mask & COL_META_DATA_PAGE_OFFSET == 0 {
-            return Err(general_err!("Required field data_page_offset is missing"));
+            return Err(corrupt_err!("Required field data_page_offset is missing"));
         }
     }
 
@@ -475,7 +475,10 @@ fn read_column_metadata<'a>(
                 column.total_compressed_size = i64::read_thrift(&mut *prot)?;
                 seen_mask |= COL_META_TOTAL_COMP_SZ;
             }
-            // 8: we don't expose this key value
+            8 => {
+                let val = read_thrift_vec::<KeyValue, ThriftSliceInputProtocol>(&mut *prot)?;
+                column.key_value_metadata = Some(val);
+            }
             9 => {
                 column.data_page_offset = i64::read_thrift(&mut *prot)?;
                 seen_mask |= COL_META_DATA_PAGE_OFFSET;
@@ -603,7 +606,7 @@ fn read_column_chunk<'a>(
 
     // the only required field from ColumnChunk
     if !has_file_offset {
-        return Err(general_err!("Required field file_offset is missing"));
+        return Err(corrupt_err!("Required field file_offset is missing"));
     };
 
     // if encrypted just return. we'll decrypt after finishing the footer and populate the rest.
@@ -653,7 +656,7 @@ fn read_row_group(
             1 => {
                 let list_ident = prot.read_list_begin()?;
                 if schema_descr.num_columns() != list_ident.size as usize {
-                    return Err(general_err!(
+                    return Err(corrupt_err!(
                         "Column count mismatch. Schema has {} columns while Row Group has {}",
                         schema_descr.num_columns(),
                         list_ident.size
@@ -693,13 +696,13 @@ fn read_row_group(
 
     if mask != RG_ALL_REQUIRED {
         if mask & RG_COLUMNS == 0 {
-            return Err(general_err!("Required field columns is missing"));
+            return Err(corrupt_err!("Required field columns is missing"));
         }
         if mask & RG_TOT_BYTE_SIZE == 0 {
-            return Err(general_err!("Required field total_byte_size is missing"));
+            return Err(corrupt_err!("Required field total_byte_size is missing"));
         }
         if mask & RG_NUM_ROWS == 0 {
-            return Err(general_err!("Required field num_rows is missing"));
+            return Err(corrupt_err!("Required field num_rows is missing"));
         }
     }
 
@@ -728,7 +731,7 @@ pub(crate) fn parquet_schema_from_bytes(buf: &[u8]) -> Result<SchemaDescriptor>
         }
         last_field_id = field_ident.id;
     }
-    Err(general_err!("Input does not contain a schema"))
+    Err(corrupt_err!("Input does not contain a schema"))
 }
 
 /// Create [`ParquetMetaData`] from thrift input. Note that this only decodes the file metadata in
@@ -797,7 +800,7 @@ pub(crate) fn parquet_metadata_from_bytes(
             }
             4 => {
                 if schema_descr.is_none() {
-                    return Err(general_err!("Required field schema is missing"));
+                    return Err(corrupt_err!("Required field schema is missing"));
                 }
                 let schema_descr = schema_descr.as_ref().unwrap();
                 let list_ident = prot.read_list_begin()?;
@@ -807,7 +810,7 @@ pub(crate) fn parquet_metadata_from_bytes(
                 let mut assigner = OrdinalAssigner::new();
                 for ordinal in 0..list_ident.size {
                     let ordinal: i16 = ordinal.try_into().map_err(|_| {
-                        ParquetError::General(format!(
+                        ParquetError::Corrupt(format!(
                             "Row group ordinal {ordinal} exceeds i16 max value",
                         ))
                     })?;
@@ -843,13 +846,13 @@ pub(crate) fn parquet_metadata_from_bytes(
         last_field_id = field_ident.id;
     }
     let Some(version) = version else {
-        return Err(general_err!("Required field version is missing"));
+        return Err(corrupt_err!("Required field version is missing"));
     };
     let Some(num_rows) = num_rows else {
-        return Err(general_err!("Required field num_rows is missing"));
+        return Err(corrupt_err!("Required field num_rows is missing"));
     };
     let Some(row_groups) = row_groups else {
-        return Err(general_err!("Required field row_groups is missing"));
+        return Err(corrupt_err!("Required field row_groups is missing"));
     };
 
     let created_by = created_by.map(|c| c.to_owned());
@@ -862,7 +865,7 @@ pub(crate) fn parquet_metadata_from_bytes(
         .as_ref()
         .is_some_and(|cos| cos.len() != schema_descr.num_columns())
     {
-        return Err(general_err!("Column order length mismatch"));
+        return Err(corrupt_err!("Column order length mismatch"));
     }
     // replace default type defined column orders with ones having the correct sort order
     // TODO(ets): this could instead be done above when decoding
@@ -944,7 +947,7 @@ impl OrdinalAssigner {
         if !first_has_ordinal && !rg_has_ordinal {
             rg.ordinal = Some(actual_ordinal);
         } else if first_has_ordinal != rg_has_ordinal {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "Inconsistent ordinal assignment: first_has_ordinal is set to \
                 {} but row-group with actual ordinal {} has rg_has_ordinal set to {}",
                 first_has_ordinal,
@@ -961,7 +964,8 @@ thrift_struct!(
 );
 
 thrift_struct!(
-pub(crate) struct DictionaryPageHeader {
+/// Header of a dictionary page, see [`RawPage`](crate::column::page::RawPage)
+pub struct DictionaryPageHeader {
   /// Number of values in the dictionary
   1: required i32 num_values;
 
@@ -976,29 +980,43 @@ pub(crate) struct DictionaryPageHeader {
 thrift_struct!(
 /// Statistics for the page header.
 ///
-/// This is a duplicate of the [`Statistics`] struct above. Because the page reader uses
+/// This is a duplicate of the thrift `Statistics` struct. Because the page reader uses
 /// the [`Read`] API, we cannot read the min/max values as slices. This should not be
 /// a huge problem since this crate no longer reads the page header statistics by default.
 ///
-/// [`Read`]: crate::parquet_thrift::ThriftReadInputProtocol
-pub(crate) struct PageStatistics {
+/// [`Read`]: std::io::Read
+pub struct PageStatistics {
+   /// Deprecated max value in signed comparison order
    1: optional binary max;
+   /// Deprecated min value in signed comparison order
    2: optional binary min;
+   /// Count of null values in the page
    3: optional i64 null_count;
+   /// Count of distinct values in the page
    4: optional i64 distinct_count;
+   /// Max value of the page, encoded in PLAIN encoding
    5: optional binary max_value;
+   /// Min value of the page, encoded in PLAIN encoding
    6: optional binary min_value;
+   /// If true, `max_value` is the actual maximum value of the page
    7: optional bool is_max_value_exact;
+   /// If true, `min_value` is the actual minimum value of the page
    8: optional bool is_min_value_exact;
 }
 );
 
 thrift_struct!(
-pub(crate) struct DataPageHeader {
+/// Header of a v1 data page, see [`RawPage`](crate::column::page::RawPage)
+pub struct DataPageHeader {
+  /// Number of values, including nulls, in this data page
   1: required i32 num_values
+  /// Encoding used for this data page
   2: required Encoding encoding
+  /// Encoding used for definition levels
   3: required Encoding definition_level_encoding;
+  /// Encoding used for repetition levels
   4: required Encoding repetition_level_encoding;
+  /// Optional statistics for the data in this page
   5: optional PageStatistics statistics;
 }
 );
@@ -1044,18 +1062,18 @@ impl DataPageHeader {
             last_field_id = field_ident.id;
         }
         let Some(num_values) = num_values else {
-            return Err(general_err!("Required field num_values is missing"));
+            return Err(corrupt_err!("Required field num_values is missing"));
         };
         let Some(encoding) = encoding else {
-            return Err(general_err!("Required field encoding is missing"));
+            return Err(corrupt_err!("Required field encoding is missing"));
         };
         let Some(definition_level_encoding) = definition_level_encoding else {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "Required field definition_level_encoding is missing"
             ));
         };
         let Some(repetition_level_encoding) = repetition_level_encoding else {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "Required field repetition_level_encoding is missing"
             ));
         };
@@ -1070,14 +1088,23 @@ impl DataPageHeader {
 }
 
 thrift_struct!(
-pub(crate) struct DataPageHeaderV2 {
+/// Header of a v2 data page, see [`RawPage`](crate::column::page::RawPage)
+pub struct DataPageHeaderV2 {
+  /// Number of values, including nulls, in this data page
   1: required i32 num_values
+  /// Number of null values in this data page
   2: required i32 num_nulls
+  /// Number of rows in this data page
   3: required i32 num_rows
+  /// Encoding used for the data in this page
   4: required Encoding encoding
+  /// Length of the uncompressed definition levels at the start of the page
   5: required i32 definition_levels_byte_length;
+  /// Length of the uncompressed repetition levels at the start of the page
   6: required i32 repetition_levels_byte_length;
+  /// Whether the values are compressed, `None` meaning `true`
   7: optional bool is_compressed = true;
+  /// Optional statistics for the data in this page
   8: optional PageStatistics statistics;
 }
 );
@@ -1138,24 +1165,24 @@ impl DataPageHeaderV2 {
             last_field_id = field_ident.id;
         }
         let Some(num_values) = num_values else {
-            return Err(general_err!("Required field num_values is missing"));
+            return Err(corrupt_err!("Required field num_values is missing"));
         };
         let Some(num_nulls) = num_nulls else {
-            return Err(general_err!("Required field num_nulls is missing"));
+            return Err(corrupt_err!("Required field num_nulls is missing"));
         };
         let Some(num_rows) = num_rows else {
-            return Err(general_err!("Required field num_rows is missing"));
+            return Err(corrupt_err!("Required field num_rows is missing"));
         };
         let Some(encoding) = encoding else {
-            return Err(general_err!("Required field encoding is missing"));
+            return Err(corrupt_err!("Required field encoding is missing"));
         };
         let Some(definition_levels_byte_length) = definition_levels_byte_length else {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "Required field definition_levels_byte_length is missing"
             ));
         };
         let Some(repetition_levels_byte_length) = repetition_levels_byte_length else {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "Required field repetition_levels_byte_length is missing"
             ));
         };
@@ -1256,15 +1283,15 @@ impl PageHeader {
             last_field_id = field_ident.id;
         }
         let Some(type_) = type_ else {
-            return Err(general_err!("Required field type_ is missing"));
+            return Err(corrupt_err!("Required field type_ is missing"));
         };
         let Some(uncompressed_page_size) = uncompressed_page_size else {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "Required field uncompressed_page_size is missing"
             ));
         };
         let Some(compressed_page_size) = compressed_page_size else {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "Required field compressed_page_size is missing"
             ));
         };
@@ -1339,8 +1366,13 @@ pub(super) fn serialize_column_meta_data<W: Write>(
     column_chunk
         .total_compressed_size
         .write_thrift_field(w, 7, 6)?;
-    // no key_value_metadata here
-    let mut last_field_id = column_chunk.data_page_offset.write_thrift_field(w, 9, 7)?;
+    let mut last_field_id = 7;
+    if let Some(key_value_metadata) = column_chunk.key_value_metadata() {
+        last_field_id = key_value_metadata.write_thrift_field(w, 8, last_field_id)?;
+    }
+    last_field_id = column_chunk
+        .data_page_offset
+        .write_thrift_field(w, 9, last_field_id)?;
     if let Some(index_page_offset) = column_chunk.index_page_offset {
         last_field_id = index_page_offset.write_thrift_field(w, 10, last_field_id)?;
     }
@@ -1468,7 +1500,7 @@ fn write_schema<W: Write>(
     writer: &mut ThriftCompactOutputProtocol<W>,
 ) -> Result<()> {
     if !schema.is_group() {
-        return Err(general_err!("Root schema must be Group type"));
+        return Err(corrupt_err!("Root schema must be Group type"));
     }
     write_schema_helper(schema, writer)
 }
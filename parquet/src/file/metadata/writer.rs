@@ -27,11 +27,16 @@ use crate::{
 #[cfg(feature = "encryption")]
 use crate::{
     encryption::{
-        encrypt::{FileEncryptor, encrypt_thrift_object, write_signed_plaintext_thrift_object},
+        encrypt::{
+            FileEncryptor, ParquetCipher, encrypt_thrift_object,
+            write_signed_plaintext_thrift_object,
+        },
         modules::{ModuleType, create_footer_aad, create_module_aad},
     },
     file::column_crypto_metadata::ColumnCryptoMetaData,
-    file::metadata::thrift::encryption::{AesGcmV1, EncryptionAlgorithm, FileCryptoMetaData},
+    file::metadata::thrift::encryption::{
+        AesGcmCtrV1, AesGcmV1, EncryptionAlgorithm, FileCryptoMetaData,
+    },
 };
 use crate::{errors::Result, file::page_index::column_index::ColumnIndexMetaData};
 
@@ -176,21 +181,14 @@ impl<'a, W: Write> ThriftMetadataWriter<'a, W> {
             self.write_offset_indexes(offset_indexes)?;
         }
 
-        // check to see if the index is `None` for every row group and column chunk
-        let all_none = offset_indexes
-            .as_ref()
-            .is_some_and(|oi| oi.iter().all(|oii| oii.iter().all(|idx| idx.is_none())));
-
-        let offset_indexes: Option<ParquetOffsetIndex> = if all_none {
-            None
-        } else {
-            // FIXME(ets): this will panic if there's a missing index.
-            offset_indexes.map(|ovvi| {
-                ovvi.into_iter()
-                    .map(|vi| vi.into_iter().map(|oi| oi.unwrap()).collect())
-                    .collect()
-            })
-        };
+        // `ParquetOffsetIndex` requires an index for every column chunk, so
+        // return `None` if any is missing, e.g. due to
+        // `WriterPropertiesBuilder::set_column_offset_index_disabled`
+        let offset_indexes: Option<ParquetOffsetIndex> = offset_indexes.and_then(|ovvi| {
+            ovvi.into_iter()
+                .map(|vi| vi.into_iter().collect::<Option<Vec<_>>>())
+                .collect()
+        });
 
         Ok(offset_indexes)
     }
@@ -415,6 +413,7 @@ impl<'a, W: Write> ThriftMetadataWriter<'a, W> {
 pub struct ParquetMetaDataWriter<'a, W: Write> {
     buf: TrackedWrite<W>,
     metadata: &'a ParquetMetaData,
+    page_index_locations: bool,
 }
 
 impl<'a, W: Write> ParquetMetaDataWriter<'a, W> {
@@ -436,7 +435,18 @@ impl<'a, W: Write> ParquetMetaDataWriter<'a, W> {
     ///
     /// See example on the struct level documentation
     pub fn new_with_tracked(buf: TrackedWrite<W>, metadata: &'a ParquetMetaData) -> Self {
-        Self { buf, metadata }
+        Self {
+            buf,
+            metadata,
+            page_index_locations: true,
+        }
+    }
+
+    /// Sets whether to keep the page index locations of column chunks whose page
+    /// indexes are not written, defaults to `true`
+    pub(crate) fn with_page_index_locations(mut self, page_index_locations: bool) -> Self {
+        self.page_index_locations = page_index_locations;
+        self
     }
 
     /// Write the metadata to the buffer
@@ -447,7 +457,15 @@ impl<'a, W: Write> ParquetMetaDataWriter<'a, W> {
         let schema_descr = Arc::new(SchemaDescriptor::new(schema.clone()));
         let created_by = file_metadata.created_by().map(str::to_string);
 
-        let row_groups = self.metadata.row_groups.clone();
+        let mut row_groups = self.metadata.row_groups.clone();
+        if !self.page_index_locations {
+            for column in row_groups.iter_mut().flat_map(|rg| &mut rg.columns) {
+                column.column_index_offset = None;
+                column.column_index_length = None;
+                column.offset_index_offset = None;
+                column.offset_index_length = None;
+            }
+        }
 
         let key_value_metadata = file_metadata.key_value_metadata().cloned();
 
@@ -772,11 +790,20 @@ impl MetadataObjectWriter {
         } else {
             None
         };
-        EncryptionAlgorithm::AES_GCM_V1(AesGcmV1 {
-            aad_prefix: aad_prefix.cloned(),
-            aad_file_unique: Some(file_encryptor.aad_file_unique().clone()),
-            supply_aad_prefix,
-        })
+        let aad_prefix = aad_prefix.cloned();
+        let aad_file_unique = Some(file_encryptor.aad_file_unique().clone());
+        match file_encryptor.properties().cipher() {
+            ParquetCipher::AesGcmV1 => EncryptionAlgorithm::AES_GCM_V1(AesGcmV1 {
+                aad_prefix,
+                aad_file_unique,
+                supply_aad_prefix,
+            }),
+            ParquetCipher::AesGcmCtrV1 => EncryptionAlgorithm::AES_GCM_CTR_V1(AesGcmCtrV1 {
+                aad_prefix,
+                aad_file_unique,
+                supply_aad_prefix,
+            }),
+        }
     }
 
     fn file_crypto_metadata(file_encryptor: &'_ FileEncryptor) -> Result<FileCryptoMetaData<'_>> {
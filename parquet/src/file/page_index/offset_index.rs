@@ -85,7 +85,7 @@ impl OffsetIndexMetaData {
         // read field 1 header, then list header, then vec of PageLocations
         let (field_type, delta) = prot.read_field_header()?;
         if delta != 1 || field_type != FieldType::List as u8 {
-            return Err(general_err!("error reading OffsetIndex::page_locations"));
+            return Err(corrupt_err!("error reading OffsetIndex::page_locations"));
         }
 
         // we have to do this manually because we want to use the fast PageLocation decoder
@@ -101,7 +101,7 @@ impl OffsetIndexMetaData {
         let (mut field_type, delta) = prot.read_field_header()?;
         if field_type == FieldType::List as u8 {
             if delta != 1 {
-                return Err(general_err!(
+                return Err(corrupt_err!(
                     "encountered unknown field while reading OffsetIndex"
                 ));
             }
@@ -113,7 +113,7 @@ impl OffsetIndexMetaData {
         }
 
         if field_type != FieldType::Stop as u8 {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "encountered unknown field while reading OffsetIndex"
             ));
         }
@@ -133,13 +133,13 @@ fn read_page_location<'a, R: ThriftCompactInputProtocol<'a>>(prot: &mut R) -> Re
     // there are 3 fields, all mandatory, so all field deltas should be 1
     let (field_type, delta) = prot.read_field_header()?;
     if delta != 1 || field_type != FieldType::I64 as u8 {
-        return Err(general_err!("error reading PageLocation::offset"));
+        return Err(corrupt_err!("error reading PageLocation::offset"));
     }
     let offset = prot.read_i64()?;
 
     let (field_type, delta) = prot.read_field_header()?;
     if delta != 1 || field_type != FieldType::I32 as u8 {
-        return Err(general_err!(
+        return Err(corrupt_err!(
             "error reading PageLocation::compressed_page_size"
         ));
     }
@@ -147,14 +147,14 @@ fn read_page_location<'a, R: ThriftCompactInputProtocol<'a>>(prot: &mut R) -> Re
 
     let (field_type, delta) = prot.read_field_header()?;
     if delta != 1 || field_type != FieldType::I64 as u8 {
-        return Err(general_err!("error reading PageLocation::first_row_index"));
+        return Err(corrupt_err!("error reading PageLocation::first_row_index"));
     }
     let first_row_index = prot.read_i64()?;
 
     // read end of struct...return error if there are unknown fields present
     let (field_type, _) = prot.read_field_header()?;
     if field_type != FieldType::Stop as u8 {
-        return Err(general_err!("unexpected field in PageLocation"));
+        return Err(corrupt_err!("unexpected field in PageLocation"));
     }
 
     Ok(PageLocation {
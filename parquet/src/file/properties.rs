@@ -20,7 +20,7 @@ use crate::basic::{Compression, Encoding};
 use crate::compression::{CodecOptions, CodecOptionsBuilder};
 #[cfg(feature = "encryption")]
 use crate::encryption::encrypt::FileEncryptionProperties;
-use crate::file::metadata::{KeyValue, SortingColumn};
+use crate::file::metadata::{ColumnChunkMetaData, KeyValue, SortingColumn};
 use crate::schema::types::ColumnPath;
 use std::str::FromStr;
 use std::{collections::HashMap, sync::Arc};
@@ -35,6 +35,8 @@ pub const DEFAULT_WRITER_VERSION: WriterVersion = WriterVersion::PARQUET_1_0;
 pub const DEFAULT_COMPRESSION: Compression = Compression::UNCOMPRESSED;
 /// Default value for [`WriterProperties::dictionary_enabled`]
 pub const DEFAULT_DICTIONARY_ENABLED: bool = true;
+/// Default value for [`WriterProperties::dictionary_reuse_enabled`]
+pub const DEFAULT_DICTIONARY_REUSE_ENABLED: bool = false;
 /// Default value for [`WriterProperties::dictionary_page_size_limit`]
 pub const DEFAULT_DICTIONARY_PAGE_SIZE_LIMIT: usize = DEFAULT_PAGE_SIZE;
 /// Default value for [`WriterProperties::data_page_row_count_limit`]
@@ -61,6 +63,12 @@ pub const DEFAULT_STATISTICS_TRUNCATE_LENGTH: Option<usize> = Some(64);
 pub const DEFAULT_OFFSET_INDEX_DISABLED: bool = false;
 /// Default values for [`WriterProperties::coerce_types`]
 pub const DEFAULT_COERCE_TYPES: bool = false;
+/// Default value for [`WriterProperties::strict_writer_version`]
+pub const DEFAULT_STRICT_WRITER_VERSION: bool = false;
+/// Default value for [`WriterProperties::canonicalize_nested_names`]
+pub const DEFAULT_CANONICALIZE_NESTED_NAMES: bool = false;
+/// Default value for [`WriterProperties::rename_duplicate_columns`]
+pub const DEFAULT_RENAME_DUPLICATE_COLUMNS: bool = false;
 /// Default minimum chunk size for content-defined chunking: 256 KiB.
 pub const DEFAULT_CDC_MIN_CHUNK_SIZE: usize = 256 * 1024;
 /// Default maximum chunk size for content-defined chunking: 1024 KiB.
@@ -215,17 +223,23 @@ pub struct WriterProperties {
     write_batch_size: usize,
     max_row_group_row_count: Option<usize>,
     max_row_group_bytes: Option<usize>,
+    hdfs_block_size: Option<u64>,
     bloom_filter_position: BloomFilterPosition,
     writer_version: WriterVersion,
+    strict_writer_version: bool,
     created_by: String,
     offset_index_disabled: bool,
     pub(crate) key_value_metadata: Option<Vec<KeyValue>>,
+    column_key_value_metadata_fn: Option<ColumnKeyValueMetadataFn>,
     default_column_properties: ColumnProperties,
     column_properties: HashMap<ColumnPath, ColumnProperties>,
     sorting_columns: Option<Vec<SortingColumn>>,
     column_index_truncate_length: Option<usize>,
     statistics_truncate_length: Option<usize>,
     coerce_types: bool,
+    canonicalize_nested_names: bool,
+    rename_duplicate_columns: bool,
+    excluded_columns: Vec<String>,
     content_defined_chunking: Option<CdcOptions>,
     #[cfg(feature = "encryption")]
     pub(crate) file_encryption_properties: Option<Arc<FileEncryptionProperties>>,
@@ -343,6 +357,13 @@ impl WriterProperties {
         self.max_row_group_bytes
     }
 
+    /// Returns the HDFS block size row groups are aligned to, or `None` if not aligned.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_hdfs_block_size`]
+    pub fn hdfs_block_size(&self) -> Option<u64> {
+        self.hdfs_block_size
+    }
+
     /// Returns bloom filter position.
     ///
     /// For more details see [`WriterPropertiesBuilder::set_bloom_filter_position`]
@@ -382,6 +403,30 @@ impl WriterProperties {
         self.offset_index_disabled
     }
 
+    /// Returns `true` if offset index writing is disabled for a specific column.
+    ///
+    /// Takes precedence over [`Self::offset_index_disabled`], but is always `false` for a
+    /// column with [`EnabledStatistics::Page`] explicitly set, as its column index requires
+    /// the offset index.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_column_offset_index_disabled`]
+    pub fn column_offset_index_disabled(&self, col: &ColumnPath) -> bool {
+        // As for `offset_index_disabled`, only statistics that were explicitly set are considered
+        let page_stats_enabled = self
+            .column_properties
+            .get(col)
+            .and_then(|c| c.statistics_enabled())
+            .or_else(|| self.default_column_properties.statistics_enabled())
+            == Some(EnabledStatistics::Page);
+        if page_stats_enabled {
+            return false;
+        }
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.offset_index_disabled())
+            .unwrap_or_else(|| self.offset_index_disabled())
+    }
+
     /// Returns `key_value_metadata` KeyValue pairs.
     ///
     /// For more details see [`WriterPropertiesBuilder::set_key_value_metadata`]
@@ -389,6 +434,32 @@ impl WriterProperties {
         self.key_value_metadata.as_ref()
     }
 
+    /// Returns the custom key-value metadata set for a specific column.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_column_key_value_metadata`]
+    pub fn column_key_value_metadata(&self, col: &ColumnPath) -> Option<&Vec<KeyValue>> {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.key_value_metadata())
+    }
+
+    /// Returns the custom key-value metadata to write for a column chunk, which combines
+    /// [`Self::column_key_value_metadata`] with the pairs returned by the callback set with
+    /// [`WriterPropertiesBuilder::set_column_key_value_metadata_fn`], if any.
+    pub(crate) fn column_chunk_key_value_metadata(
+        &self,
+        metadata: &ColumnChunkMetaData,
+    ) -> Option<Vec<KeyValue>> {
+        let mut key_value_metadata = self
+            .column_key_value_metadata(metadata.column_path())
+            .cloned()
+            .unwrap_or_default();
+        if let Some(f) = &self.column_key_value_metadata_fn {
+            key_value_metadata.extend((f.0)(metadata));
+        }
+        (!key_value_metadata.is_empty()).then_some(key_value_metadata)
+    }
+
     /// Returns sorting columns.
     ///
     /// For more details see [`WriterPropertiesBuilder::set_sorting_columns`]
@@ -405,6 +476,19 @@ impl WriterProperties {
         self.column_index_truncate_length
     }
 
+    /// Returns the maximum length of truncated min/max values in the column index for a
+    /// specific column.
+    ///
+    /// Takes precedence over [`Self::column_index_truncate_length`].
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_column_column_index_truncate_length`]
+    pub fn column_column_index_truncate_length(&self, col: &ColumnPath) -> Option<usize> {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.column_index_truncate_length())
+            .unwrap_or(self.column_index_truncate_length)
+    }
+
     /// Returns the maximum length of truncated min/max values in [`Statistics`].
     ///
     /// `None` if truncation is disabled, must be greater than 0 otherwise.
@@ -423,6 +507,27 @@ impl WriterProperties {
         self.coerce_types
     }
 
+    /// Returns `true` if the children of lists and maps are given their canonical names.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_canonicalize_nested_names`]
+    pub fn canonicalize_nested_names(&self) -> bool {
+        self.canonicalize_nested_names
+    }
+
+    /// Returns `true` if fields with duplicate names are renamed when writing.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_rename_duplicate_columns`]
+    pub fn rename_duplicate_columns(&self) -> bool {
+        self.rename_duplicate_columns
+    }
+
+    /// Returns the names of the top-level columns that are not written.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_excluded_columns`]
+    pub fn excluded_columns(&self) -> &[String] {
+        &self.excluded_columns
+    }
+
     /// EXPERIMENTAL: Returns content-defined chunking options, or `None` if CDC is disabled.
     ///
     /// For more details see [`WriterPropertiesBuilder::set_content_defined_chunking`]
@@ -430,24 +535,43 @@ impl WriterProperties {
         self.content_defined_chunking.as_ref()
     }
 
+    /// Returns `true` if only the encodings defined by the writer version may be used.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_strict_writer_version`]
+    pub fn strict_writer_version(&self) -> bool {
+        self.strict_writer_version
+    }
+
+    /// Returns `true` if only Parquet 1.0 encodings may be used, that is if
+    /// [`Self::strict_writer_version`] is set for [`WriterVersion::PARQUET_1_0`].
+    fn parquet_1_0_encodings_only(&self) -> bool {
+        self.strict_writer_version && self.writer_version == WriterVersion::PARQUET_1_0
+    }
+
     /// Returns encoding for a data page, when dictionary encoding is enabled.
     ///
-    /// This is not configurable.
+    /// This is [`Encoding::RLE_DICTIONARY`], unless [`Self::strict_writer_version`] is set
+    /// for [`WriterVersion::PARQUET_1_0`], in which case it is [`Encoding::PLAIN_DICTIONARY`].
     #[inline]
     pub fn dictionary_data_page_encoding(&self) -> Encoding {
-        // PLAIN_DICTIONARY encoding is deprecated in writer version 1.
-        // Dictionary values are encoded using RLE_DICTIONARY encoding.
-        Encoding::RLE_DICTIONARY
+        if self.parquet_1_0_encodings_only() {
+            Encoding::PLAIN_DICTIONARY
+        } else {
+            Encoding::RLE_DICTIONARY
+        }
     }
 
     /// Returns encoding for dictionary page, when dictionary encoding is enabled.
     ///
-    /// This is not configurable.
+    /// This is [`Encoding::PLAIN`], unless [`Self::strict_writer_version`] is set for
+    /// [`WriterVersion::PARQUET_1_0`], in which case it is [`Encoding::PLAIN_DICTIONARY`].
     #[inline]
     pub fn dictionary_page_encoding(&self) -> Encoding {
-        // PLAIN_DICTIONARY is deprecated in writer version 1.
-        // Dictionary is encoded using plain encoding.
-        Encoding::PLAIN
+        if self.parquet_1_0_encodings_only() {
+            Encoding::PLAIN_DICTIONARY
+        } else {
+            Encoding::PLAIN
+        }
     }
 
     /// Returns encoding for a column, if set.
@@ -485,6 +609,17 @@ impl WriterProperties {
             .unwrap_or(DEFAULT_DICTIONARY_ENABLED)
     }
 
+    /// Returns `true` if the dictionary of a column is reused across row groups.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_dictionary_reuse_enabled`]
+    pub fn dictionary_reuse_enabled(&self, col: &ColumnPath) -> bool {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.dictionary_reuse_enabled())
+            .or_else(|| self.default_column_properties.dictionary_reuse_enabled())
+            .unwrap_or(DEFAULT_DICTIONARY_REUSE_ENABLED)
+    }
+
     /// Returns which statistics are written for a column.
     ///
     /// For more details see [`WriterPropertiesBuilder::set_statistics_enabled`]
@@ -542,17 +677,23 @@ pub struct WriterPropertiesBuilder {
     write_batch_size: usize,
     max_row_group_row_count: Option<usize>,
     max_row_group_bytes: Option<usize>,
+    hdfs_block_size: Option<u64>,
     bloom_filter_position: BloomFilterPosition,
     writer_version: WriterVersion,
+    strict_writer_version: bool,
     created_by: String,
     offset_index_disabled: bool,
     key_value_metadata: Option<Vec<KeyValue>>,
+    column_key_value_metadata_fn: Option<ColumnKeyValueMetadataFn>,
     default_column_properties: ColumnProperties,
     column_properties: HashMap<ColumnPath, ColumnProperties>,
     sorting_columns: Option<Vec<SortingColumn>>,
     column_index_truncate_length: Option<usize>,
     statistics_truncate_length: Option<usize>,
     coerce_types: bool,
+    canonicalize_nested_names: bool,
+    rename_duplicate_columns: bool,
+    excluded_columns: Vec<String>,
     content_defined_chunking: Option<CdcOptions>,
     #[cfg(feature = "encryption")]
     file_encryption_properties: Option<Arc<FileEncryptionProperties>>,
@@ -566,17 +707,23 @@ impl Default for WriterPropertiesBuilder {
             write_batch_size: DEFAULT_WRITE_BATCH_SIZE,
             max_row_group_row_count: Some(DEFAULT_MAX_ROW_GROUP_ROW_COUNT),
             max_row_group_bytes: None,
+            hdfs_block_size: None,
             bloom_filter_position: DEFAULT_BLOOM_FILTER_POSITION,
             writer_version: DEFAULT_WRITER_VERSION,
+            strict_writer_version: DEFAULT_STRICT_WRITER_VERSION,
             created_by: DEFAULT_CREATED_BY.to_string(),
             offset_index_disabled: DEFAULT_OFFSET_INDEX_DISABLED,
             key_value_metadata: None,
+            column_key_value_metadata_fn: None,
             default_column_properties: Default::default(),
             column_properties: HashMap::new(),
             sorting_columns: None,
             column_index_truncate_length: DEFAULT_COLUMN_INDEX_TRUNCATE_LENGTH,
             statistics_truncate_length: DEFAULT_STATISTICS_TRUNCATE_LENGTH,
             coerce_types: DEFAULT_COERCE_TYPES,
+            canonicalize_nested_names: DEFAULT_CANONICALIZE_NESTED_NAMES,
+            rename_duplicate_columns: DEFAULT_RENAME_DUPLICATE_COLUMNS,
+            excluded_columns: Vec::new(),
             content_defined_chunking: None,
             #[cfg(feature = "encryption")]
             file_encryption_properties: None,
@@ -592,17 +739,23 @@ impl WriterPropertiesBuilder {
             write_batch_size: self.write_batch_size,
             max_row_group_row_count: self.max_row_group_row_count,
             max_row_group_bytes: self.max_row_group_bytes,
+            hdfs_block_size: self.hdfs_block_size,
             bloom_filter_position: self.bloom_filter_position,
             writer_version: self.writer_version,
+            strict_writer_version: self.strict_writer_version,
             created_by: self.created_by,
             offset_index_disabled: self.offset_index_disabled,
             key_value_metadata: self.key_value_metadata,
+            column_key_value_metadata_fn: self.column_key_value_metadata_fn,
             default_column_properties: self.default_column_properties,
             column_properties: self.column_properties,
             sorting_columns: self.sorting_columns,
             column_index_truncate_length: self.column_index_truncate_length,
             statistics_truncate_length: self.statistics_truncate_length,
             coerce_types: self.coerce_types,
+            canonicalize_nested_names: self.canonicalize_nested_names,
+            rename_duplicate_columns: self.rename_duplicate_columns,
+            excluded_columns: self.excluded_columns,
             content_defined_chunking: self.content_defined_chunking,
             #[cfg(feature = "encryption")]
             file_encryption_properties: self.file_encryption_properties,
@@ -617,12 +770,43 @@ impl WriterPropertiesBuilder {
     ///
     /// This value can determine what features some readers will support.
     ///
+    /// With [`PARQUET_1_0`], data is written using v1 data pages and [`Encoding::PLAIN`]
+    /// as fallback encoding. With [`PARQUET_2_0`], data is written using v2 data pages,
+    /// and delta encodings as fallback where applicable. Dictionary encoded columns use
+    /// [`Encoding::RLE_DICTIONARY`] for either version, and encodings explicitly configured
+    /// with [`Self::set_encoding`] or [`Self::set_column_encoding`] are used regardless of
+    /// the writer version, unless [`Self::set_strict_writer_version`] is enabled.
+    ///
     /// [`PARQUET_1_0`]: [WriterVersion::PARQUET_1_0]
+    /// [`PARQUET_2_0`]: [WriterVersion::PARQUET_2_0]
     pub fn set_writer_version(mut self, value: WriterVersion) -> Self {
         self.writer_version = value;
         self
     }
 
+    /// Sets whether to only use encodings defined by the writer version (defaults to
+    /// `false` via [`DEFAULT_STRICT_WRITER_VERSION`]).
+    ///
+    /// Some readers, such as older versions of Hive, only support Parquet 1.0 encodings.
+    /// When enabled with [`PARQUET_1_0`]:
+    ///
+    /// * dictionary encoded columns are written with the deprecated
+    ///   [`Encoding::PLAIN_DICTIONARY`] encoding, for both dictionary and data pages,
+    ///   instead of [`Encoding::PLAIN`] and [`Encoding::RLE_DICTIONARY`]
+    /// * configuring an encoding introduced by Parquet 2.0, that is
+    ///   [`Encoding::DELTA_BINARY_PACKED`], [`Encoding::DELTA_LENGTH_BYTE_ARRAY`],
+    ///   [`Encoding::DELTA_BYTE_ARRAY`] or [`Encoding::BYTE_STREAM_SPLIT`], results in an
+    ///   error when creating the writer for the column
+    ///
+    /// This has no effect with [`PARQUET_2_0`].
+    ///
+    /// [`PARQUET_1_0`]: [WriterVersion::PARQUET_1_0]
+    /// [`PARQUET_2_0`]: [WriterVersion::PARQUET_2_0]
+    pub fn set_strict_writer_version(mut self, value: bool) -> Self {
+        self.strict_writer_version = value;
+        self
+    }
+
     /// Sets best effort maximum number of rows in a data page (defaults to `20_000`
     /// via [`DEFAULT_DATA_PAGE_ROW_COUNT_LIMIT`]).
     ///
@@ -693,6 +877,28 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets the HDFS block size in bytes that row groups are aligned to, or `None`
+    /// (the default) to write row groups contiguously.
+    ///
+    /// When set, [`ArrowWriter`] writes zero bytes before a row group that would
+    /// otherwise span a block boundary, so that it starts at the next boundary and
+    /// can be read from a single block. Row groups larger than a block are not
+    /// padded, as they span multiple blocks regardless. Readers locate row groups
+    /// using the offsets in the file metadata, and so skip the padding.
+    ///
+    /// This is typically combined with [`Self::set_max_row_group_bytes`] set to a
+    /// value somewhat below the block size (e.g. 128MB), to limit the padding required.
+    ///
+    /// # Panics
+    /// If the value is `Some(0)`.
+    ///
+    /// [`ArrowWriter`]: crate::arrow::arrow_writer::ArrowWriter
+    pub fn set_hdfs_block_size(mut self, value: Option<u64>) -> Self {
+        assert_ne!(value, Some(0), "Cannot have a 0 HDFS block size");
+        self.hdfs_block_size = value;
+        self
+    }
+
     /// Sets where in the final file Bloom Filters are written (defaults to  [`AfterRowGroup`]
     /// via [`DEFAULT_BLOOM_FILTER_POSITION`])
     ///
@@ -733,6 +939,35 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets a callback computing custom key-value metadata for each column chunk
+    /// (defaults to `None`).
+    ///
+    /// The callback is invoked with the [`ColumnChunkMetaData`] of each column chunk once
+    /// all its pages have been written, and so can record values derived from its statistics,
+    /// sizes or encodings. The returned pairs are written after those set with
+    /// [`Self::set_column_key_value_metadata`], and are available via
+    /// [`ColumnChunkMetaData::key_value_metadata`] when reading the file.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use parquet::file::metadata::KeyValue;
+    /// # use parquet::file::properties::WriterProperties;
+    /// let props = WriterProperties::builder()
+    ///     .set_column_key_value_metadata_fn(|metadata| {
+    ///         let size = metadata.uncompressed_size().to_string();
+    ///         vec![KeyValue::new("etl.uncompressed_size".to_string(), size)]
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn set_column_key_value_metadata_fn<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&ColumnChunkMetaData) -> Vec<KeyValue> + Send + Sync + 'static,
+    {
+        self.column_key_value_metadata_fn = Some(ColumnKeyValueMetadataFn(Arc::new(f)));
+        self
+    }
+
     /// Sets sorting order of rows in the row group if any (defaults to `None`).
     pub fn set_sorting_columns(mut self, value: Option<Vec<SortingColumn>>) -> Self {
         self.sorting_columns = value;
@@ -819,6 +1054,53 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Should the internal field names of `List` and `Map` types be renamed to match
+    /// the Parquet specification (defaults to `false` via [`DEFAULT_CANONICALIZE_NESTED_NAMES`]).
+    ///
+    /// Some readers expect list elements to be named `element`, and the children of
+    /// maps to be named `key_value`, `key` and `value`, but Arrow schemas frequently
+    /// use other names such as `item` or `entries`. Unlike [`Self::set_coerce_types`],
+    /// this changes only these names, and the Arrow reader recovers the original names
+    /// from the embedded Arrow schema.
+    ///
+    /// See [`ArrowSchemaConverter::with_canonicalize_nested_names`] for more details
+    ///
+    /// [`ArrowSchemaConverter::with_canonicalize_nested_names`]: crate::arrow::ArrowSchemaConverter::with_canonicalize_nested_names
+    pub fn set_canonicalize_nested_names(mut self, canonicalize_nested_names: bool) -> Self {
+        self.canonicalize_nested_names = canonicalize_nested_names;
+        self
+    }
+
+    /// Should fields with the same name as a sibling field be renamed (defaults to `false`
+    /// via [`DEFAULT_RENAME_DUPLICATE_COLUMNS`]).
+    ///
+    /// Arrow schemas may contain several fields with the same name, but the columns of such
+    /// a Parquet file cannot be distinguished by name. By default, writing such a schema
+    /// returns an error. Setting this option to `true` instead renames duplicates by
+    /// appending a suffix `_1`, `_2`, etc, and the Arrow reader restores the original
+    /// names from the embedded Arrow schema.
+    ///
+    /// See [`ArrowSchemaConverter::with_rename_duplicate_columns`] for more details
+    ///
+    /// [`ArrowSchemaConverter::with_rename_duplicate_columns`]: crate::arrow::ArrowSchemaConverter::with_rename_duplicate_columns
+    pub fn set_rename_duplicate_columns(mut self, rename_duplicate_columns: bool) -> Self {
+        self.rename_duplicate_columns = rename_duplicate_columns;
+        self
+    }
+
+    /// Sets the names of top-level columns that should not be written (defaults to none).
+    ///
+    /// The [`ArrowWriter`] drops fields with these names from the schema of the file,
+    /// and the corresponding columns from each `RecordBatch` written, so that callers
+    /// need not project their batches. Names that do not match a field of the schema are
+    /// ignored.
+    ///
+    /// [`ArrowWriter`]: crate::arrow::arrow_writer::ArrowWriter
+    pub fn set_excluded_columns(mut self, columns: Vec<String>) -> Self {
+        self.excluded_columns = columns;
+        self
+    }
+
     /// EXPERIMENTAL: Sets content-defined chunking options, or disables CDC with `None`.
     ///
     /// When enabled, data page boundaries are determined by a rolling hash of the
@@ -897,6 +1179,28 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets default flag to enable/disable reusing the dictionary of each column across
+    /// row groups (defaults to `false` via [`DEFAULT_DICTIONARY_REUSE_ENABLED`]).
+    ///
+    /// By default, a new dictionary is built for each column chunk. When enabled, the
+    /// dictionary of a column chunk is used to start the dictionary of the same column
+    /// in the next row group, avoiding re-hashing the values of low-cardinality columns.
+    /// Each dictionary page then also contains the values of the previous row groups,
+    /// so this is best suited to columns with a small, stable set of values.
+    ///
+    /// The dictionary is not reused if the column fell back to another encoding
+    /// because the dictionary exceeded [`Self::set_dictionary_page_size_limit`].
+    ///
+    /// Note: this currently only applies to byte array columns, such as `Utf8` and
+    /// `Binary`, including their dictionary types, written with an [`ArrowWriter`].
+    ///
+    /// [`ArrowWriter`]: https://docs.rs/parquet/latest/parquet/arrow/arrow_writer/struct.ArrowWriter.html
+    pub fn set_dictionary_reuse_enabled(mut self, value: bool) -> Self {
+        self.default_column_properties
+            .set_dictionary_reuse_enabled(value);
+        self
+    }
+
     /// Sets best effort maximum dictionary page size, in bytes (defaults to `1024 * 1024`
     /// via [`DEFAULT_DICTIONARY_PAGE_SIZE_LIMIT`]).
     ///
@@ -1026,6 +1330,18 @@ impl WriterPropertiesBuilder {
     /// global defaults or explicitly, this value is considered to be a fallback
     /// encoding for this column.
     ///
+    /// Not all encodings support all physical types:
+    ///
+    /// * [`Encoding::DELTA_BINARY_PACKED`] supports `INT32` and `INT64`
+    /// * [`Encoding::DELTA_LENGTH_BYTE_ARRAY`] supports `BYTE_ARRAY`
+    /// * [`Encoding::DELTA_BYTE_ARRAY`] supports `BYTE_ARRAY` and `FIXED_LEN_BYTE_ARRAY`
+    /// * [`Encoding::BYTE_STREAM_SPLIT`] supports `INT32`, `INT64`, `FLOAT`, `DOUBLE` and
+    ///   `FIXED_LEN_BYTE_ARRAY`
+    /// * [`Encoding::RLE`] supports `BOOLEAN`
+    ///
+    /// As the schema is not known here, an unsupported combination results in an
+    /// error when creating the writer for the column.
+    ///
     /// # Panics
     /// If user tries to set dictionary encoding here, regardless of dictionary
     /// encoding flag being set.
@@ -1050,6 +1366,15 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets flag to enable/disable reusing the dictionary across row groups for a
+    /// specific column.
+    ///
+    /// Takes precedence over [`Self::set_dictionary_reuse_enabled`].
+    pub fn set_column_dictionary_reuse_enabled(mut self, col: ColumnPath, value: bool) -> Self {
+        self.get_mut_props(col).set_dictionary_reuse_enabled(value);
+        self
+    }
+
     /// Sets dictionary page size limit for a specific column.
     ///
     /// Takes precedence over [`Self::set_dictionary_page_size_limit`].
@@ -1090,6 +1415,48 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets whether the writing of the offset index is disabled for a specific column.
+    ///
+    /// Takes precedence over [`Self::set_offset_index_disabled`]. As for that setting, this
+    /// is overridden with `false` if the statistics level of the column is [`Page`].
+    ///
+    /// Note: the column index of a column is only written if its statistics level is [`Page`],
+    /// which can be set with [`Self::set_column_statistics_enabled`].
+    ///
+    /// Note: readers may ignore the page index of a file in which only some columns have an
+    /// offset index, and reading the page index with [`PageIndexPolicy::Required`] will fail.
+    ///
+    /// [`Page`]: EnabledStatistics::Page
+    /// [`PageIndexPolicy::Required`]: crate::file::metadata::PageIndexPolicy::Required
+    pub fn set_column_offset_index_disabled(mut self, col: ColumnPath, value: bool) -> Self {
+        self.get_mut_props(col).set_offset_index_disabled(value);
+        self
+    }
+
+    /// Sets the max length of min/max value fields when writing the column index for a
+    /// specific column.
+    ///
+    /// Takes precedence over [`Self::set_column_index_truncate_length`].
+    ///
+    /// * If `Some`, must be greater than 0, otherwise will panic
+    /// * If `None`, there's no effective limit.
+    pub fn set_column_column_index_truncate_length(
+        mut self,
+        col: ColumnPath,
+        max_length: Option<usize>,
+    ) -> Self {
+        if let Some(value) = max_length {
+            assert!(
+                value > 0,
+                "Cannot have a 0 column index truncate length. If you wish to disable min/max value truncation, set it to `None`."
+            );
+        }
+
+        self.get_mut_props(col)
+            .set_column_index_truncate_length(max_length);
+        self
+    }
+
     /// Sets whether a bloom filter should be written for a specific column.
     ///
     /// Takes precedence over [`Self::set_bloom_filter_enabled`].
@@ -1113,6 +1480,17 @@ impl WriterPropertiesBuilder {
         self.get_mut_props(col).set_bloom_filter_ndv(value);
         self
     }
+
+    /// Sets custom key-value metadata to write in the metadata of every chunk of a
+    /// specific column (defaults to `None`).
+    ///
+    /// Unlike [`Self::set_key_value_metadata`], which applies to the whole file, this is
+    /// available via [`ColumnChunkMetaData::key_value_metadata`] when reading the file.
+    /// See [`Self::set_column_key_value_metadata_fn`] to compute the metadata per column chunk.
+    pub fn set_column_key_value_metadata(mut self, col: ColumnPath, value: Vec<KeyValue>) -> Self {
+        self.get_mut_props(col).set_key_value_metadata(value);
+        self
+    }
 }
 
 impl From<WriterProperties> for WriterPropertiesBuilder {
@@ -1122,17 +1500,23 @@ impl From<WriterProperties> for WriterPropertiesBuilder {
             write_batch_size: props.write_batch_size,
             max_row_group_row_count: props.max_row_group_row_count,
             max_row_group_bytes: props.max_row_group_bytes,
+            hdfs_block_size: props.hdfs_block_size,
             bloom_filter_position: props.bloom_filter_position,
             writer_version: props.writer_version,
+            strict_writer_version: props.strict_writer_version,
             created_by: props.created_by,
             offset_index_disabled: props.offset_index_disabled,
             key_value_metadata: props.key_value_metadata,
+            column_key_value_metadata_fn: props.column_key_value_metadata_fn,
             default_column_properties: props.default_column_properties,
             column_properties: props.column_properties,
             sorting_columns: props.sorting_columns,
             column_index_truncate_length: props.column_index_truncate_length,
             statistics_truncate_length: props.statistics_truncate_length,
             coerce_types: props.coerce_types,
+            canonicalize_nested_names: props.canonicalize_nested_names,
+            rename_duplicate_columns: props.rename_duplicate_columns,
+            excluded_columns: props.excluded_columns,
             content_defined_chunking: props.content_defined_chunking,
             #[cfg(feature = "encryption")]
             file_encryption_properties: props.file_encryption_properties,
@@ -1238,10 +1622,16 @@ struct ColumnProperties {
     data_page_size_limit: Option<usize>,
     dictionary_page_size_limit: Option<usize>,
     dictionary_enabled: Option<bool>,
+    dictionary_reuse_enabled: Option<bool>,
     statistics_enabled: Option<EnabledStatistics>,
     write_page_header_statistics: Option<bool>,
+    offset_index_disabled: Option<bool>,
+    /// `Some(None)` if column index truncation is disabled
+    column_index_truncate_length: Option<Option<usize>>,
     /// bloom filter related properties
     bloom_filter_properties: Option<BloomFilterProperties>,
+    /// custom key-value metadata of the column chunks
+    key_value_metadata: Option<Vec<KeyValue>>,
 }
 
 impl ColumnProperties {
@@ -1276,6 +1666,11 @@ impl ColumnProperties {
         self.dictionary_enabled = Some(enabled);
     }
 
+    /// Sets whether the dictionary is reused across row groups for this column.
+    fn set_dictionary_reuse_enabled(&mut self, enabled: bool) {
+        self.dictionary_reuse_enabled = Some(enabled);
+    }
+
     /// Sets dictionary page size limit for this column.
     fn set_dictionary_page_size_limit(&mut self, value: usize) {
         self.dictionary_page_size_limit = Some(value);
@@ -1291,6 +1686,16 @@ impl ColumnProperties {
         self.write_page_header_statistics = Some(enabled);
     }
 
+    /// Sets whether the offset index is disabled for this column.
+    fn set_offset_index_disabled(&mut self, value: bool) {
+        self.offset_index_disabled = Some(value);
+    }
+
+    /// Sets the column index truncate length for this column.
+    fn set_column_index_truncate_length(&mut self, max_length: Option<usize>) {
+        self.column_index_truncate_length = Some(max_length);
+    }
+
     /// If `value` is `true`, sets bloom filter properties to default values if not previously set,
     /// otherwise it is a no-op.
     /// If `value` is `false`, resets bloom filter properties to `None`.
@@ -1327,6 +1732,11 @@ impl ColumnProperties {
             .ndv = value;
     }
 
+    /// Sets the custom key-value metadata for this column.
+    fn set_key_value_metadata(&mut self, value: Vec<KeyValue>) {
+        self.key_value_metadata = Some(value);
+    }
+
     /// Returns optional encoding for this column.
     fn encoding(&self) -> Option<Encoding> {
         self.encoding
@@ -1344,6 +1754,12 @@ impl ColumnProperties {
         self.dictionary_enabled
     }
 
+    /// Returns optional flag for reusing the dictionary across row groups for this
+    /// column. If result is `None`, then no setting has been provided.
+    fn dictionary_reuse_enabled(&self) -> Option<bool> {
+        self.dictionary_reuse_enabled
+    }
+
     /// Returns optional dictionary page size limit for this column.
     fn dictionary_page_size_limit(&self) -> Option<usize> {
         self.dictionary_page_size_limit
@@ -1368,10 +1784,40 @@ impl ColumnProperties {
         self.write_page_header_statistics
     }
 
+    /// Returns `Some(true)` if the offset index is disabled for this column. If result is
+    /// `None`, then no setting has been provided.
+    fn offset_index_disabled(&self) -> Option<bool> {
+        self.offset_index_disabled
+    }
+
+    /// Returns the column index truncate length for this column. If result is `None`,
+    /// then no setting has been provided.
+    fn column_index_truncate_length(&self) -> Option<Option<usize>> {
+        self.column_index_truncate_length
+    }
+
     /// Returns the bloom filter properties, or `None` if not enabled
     fn bloom_filter_properties(&self) -> Option<&BloomFilterProperties> {
         self.bloom_filter_properties.as_ref()
     }
+
+    /// Returns optional custom key-value metadata for this column.
+    fn key_value_metadata(&self) -> Option<&Vec<KeyValue>> {
+        self.key_value_metadata.as_ref()
+    }
+}
+
+type KeyValueMetadataFn = dyn Fn(&ColumnChunkMetaData) -> Vec<KeyValue> + Send + Sync;
+
+/// Callback computing custom key-value metadata of a column chunk, see
+/// [`WriterPropertiesBuilder::set_column_key_value_metadata_fn`]
+#[derive(Clone)]
+struct ColumnKeyValueMetadataFn(Arc<KeyValueMetadataFn>);
+
+impl std::fmt::Debug for ColumnKeyValueMetadataFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ColumnKeyValueMetadataFn")
+    }
 }
 
 /// Reference counted reader properties.
@@ -1469,6 +1915,18 @@ impl ReaderPropertiesBuilder {
         self
     }
 
+    /// Enable/disable the LZ4_RAW fallback.
+    ///
+    /// If the LZ4_RAW fallback is enabled, on LZ4_RAW error it will fallback to LZ4_HADOOP
+    /// and then LZ4_FRAME, for compatibility with writers that label these formats as
+    /// LZ4_RAW.
+    ///
+    /// By default the LZ4_RAW fallback is disabled.
+    pub fn set_lz4_raw_fallback(mut self, value: bool) -> Self {
+        self.codec_options_builder = self.codec_options_builder.set_lz4_raw_fallback(value);
+        self
+    }
+
     /// Enable/disable reading bloom filter
     ///
     /// If reading bloom filter is enabled, bloom filter will be read from the file.
@@ -1519,6 +1977,16 @@ mod tests {
             Some(DEFAULT_MAX_ROW_GROUP_ROW_COUNT)
         );
         assert_eq!(props.max_row_group_bytes(), None);
+        assert_eq!(props.hdfs_block_size(), None);
+        assert_eq!(
+            props.canonicalize_nested_names(),
+            DEFAULT_CANONICALIZE_NESTED_NAMES
+        );
+        assert_eq!(
+            props.rename_duplicate_columns(),
+            DEFAULT_RENAME_DUPLICATE_COLUMNS
+        );
+        assert!(props.excluded_columns().is_empty());
         assert_eq!(props.bloom_filter_position(), DEFAULT_BLOOM_FILTER_POSITION);
         assert_eq!(props.writer_version(), DEFAULT_WRITER_VERSION);
         assert_eq!(props.created_by(), DEFAULT_CREATED_BY);
@@ -1557,6 +2025,26 @@ mod tests {
                 Encoding::RLE_DICTIONARY
             );
         }
+
+        // unless only Parquet 1.0 encodings are allowed
+        let props = WriterProperties::builder()
+            .set_writer_version(WriterVersion::PARQUET_1_0)
+            .set_strict_writer_version(true)
+            .build();
+        assert_eq!(props.dictionary_page_encoding(), Encoding::PLAIN_DICTIONARY);
+        assert_eq!(
+            props.dictionary_data_page_encoding(),
+            Encoding::PLAIN_DICTIONARY
+        );
+        let props = WriterProperties::builder()
+            .set_writer_version(WriterVersion::PARQUET_2_0)
+            .set_strict_writer_version(true)
+            .build();
+        assert_eq!(props.dictionary_page_encoding(), Encoding::PLAIN);
+        assert_eq!(
+            props.dictionary_data_page_encoding(),
+            Encoding::RLE_DICTIONARY
+        );
     }
 
     #[test]
@@ -1613,11 +2101,13 @@ mod tests {
             .set_encoding(Encoding::DELTA_BINARY_PACKED)
             .set_compression(Compression::GZIP(Default::default()))
             .set_dictionary_enabled(false)
+            .set_dictionary_reuse_enabled(true)
             .set_statistics_enabled(EnabledStatistics::None)
             // specific column settings
             .set_column_encoding(ColumnPath::from("col"), Encoding::RLE)
             .set_column_compression(ColumnPath::from("col"), Compression::SNAPPY)
             .set_column_dictionary_enabled(ColumnPath::from("col"), true)
+            .set_column_dictionary_reuse_enabled(ColumnPath::from("col"), false)
             .set_column_statistics_enabled(ColumnPath::from("col"), EnabledStatistics::Chunk)
             .set_column_bloom_filter_enabled(ColumnPath::from("col"), true)
             .set_column_bloom_filter_ndv(ColumnPath::from("col"), 100_u64)
@@ -1647,6 +2137,7 @@ mod tests {
                 Compression::GZIP(Default::default())
             );
             assert!(!props.dictionary_enabled(&ColumnPath::from("a")));
+            assert!(props.dictionary_reuse_enabled(&ColumnPath::from("a")));
             assert_eq!(
                 props.statistics_enabled(&ColumnPath::from("a")),
                 EnabledStatistics::None
@@ -1661,6 +2152,7 @@ mod tests {
                 Compression::SNAPPY
             );
             assert!(props.dictionary_enabled(&ColumnPath::from("col")));
+            assert!(!props.dictionary_reuse_enabled(&ColumnPath::from("col")));
             assert_eq!(
                 props.statistics_enabled(&ColumnPath::from("col")),
                 EnabledStatistics::Chunk
@@ -1732,6 +2224,12 @@ mod tests {
         let _ = WriterProperties::builder().set_max_row_group_bytes(Some(0));
     }
 
+    #[test]
+    #[should_panic(expected = "Cannot have a 0 HDFS block size")]
+    fn test_writer_properties_panic_on_zero_hdfs_block_size() {
+        let _ = WriterProperties::builder().set_hdfs_block_size(Some(0));
+    }
+
     #[test]
     fn test_writer_properties_bloom_filter_ndv_fpp_set() {
         assert_eq!(
@@ -1798,12 +2296,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_writer_properties_column_page_index() {
+        let props = WriterProperties::builder()
+            .set_statistics_enabled(EnabledStatistics::Chunk)
+            .set_offset_index_disabled(true)
+            .set_column_offset_index_disabled(ColumnPath::from("col"), false)
+            .set_column_column_index_truncate_length(ColumnPath::from("col"), Some(10))
+            .set_column_column_index_truncate_length(ColumnPath::from("page"), None)
+            .build();
+
+        assert!(!props.column_offset_index_disabled(&ColumnPath::from("col")));
+        assert!(props.column_offset_index_disabled(&ColumnPath::from("other")));
+
+        assert_eq!(
+            props.column_column_index_truncate_length(&ColumnPath::from("col")),
+            Some(10)
+        );
+        assert_eq!(
+            props.column_column_index_truncate_length(&ColumnPath::from("page")),
+            None
+        );
+        assert_eq!(
+            props.column_column_index_truncate_length(&ColumnPath::from("other")),
+            DEFAULT_COLUMN_INDEX_TRUNCATE_LENGTH
+        );
+
+        // Page statistics require an offset index
+        let props = WriterProperties::builder()
+            .set_column_offset_index_disabled(ColumnPath::from("page"), true)
+            .set_column_statistics_enabled(ColumnPath::from("page"), EnabledStatistics::Page)
+            .build();
+        assert!(!props.column_offset_index_disabled(&ColumnPath::from("page")));
+    }
+
     #[test]
     fn test_reader_properties_default_settings() {
         let props = ReaderProperties::builder().build();
 
         let codec_options = CodecOptionsBuilder::default()
             .set_backward_compatible_lz4(true)
+            .set_lz4_raw_fallback(false)
             .build();
 
         assert_eq!(props.codec_options(), &codec_options);
@@ -1814,10 +2347,12 @@ mod tests {
     fn test_reader_properties_builder() {
         let props = ReaderProperties::builder()
             .set_backward_compatible_lz4(false)
+            .set_lz4_raw_fallback(true)
             .build();
 
         let codec_options = CodecOptionsBuilder::default()
             .set_backward_compatible_lz4(false)
+            .set_lz4_raw_fallback(true)
             .build();
 
         assert_eq!(props.codec_options(), &codec_options);
@@ -20,11 +20,11 @@
 
 use crate::basic::{PageType, Type};
 use crate::bloom_filter::Sbbf;
-use crate::column::page::{Page, PageMetadata, PageReader};
+use crate::column::page::{Page, PageMetadata, PageReader, RawPage};
 use crate::compression::{Codec, create_codec};
 #[cfg(feature = "encryption")]
 use crate::encryption::decrypt::{CryptoContext, read_and_decrypt};
-use crate::errors::{ParquetError, Result};
+use crate::errors::{ErrorContext, ParquetError, Result};
 use crate::file::metadata::thrift::PageHeader;
 use crate::file::page_index::offset_index::{OffsetIndexMetaData, PageLocation};
 use crate::file::statistics;
@@ -41,6 +41,7 @@ use crate::record::reader::RowIter;
 use crate::schema::types::{SchemaDescPtr, Type as SchemaType};
 use bytes::Bytes;
 use std::collections::VecDeque;
+use std::num::TryFromIntError;
 use std::{fs::File, io::Read, path::Path, sync::Arc};
 
 impl TryFrom<File> for SerializedFileReader<File> {
@@ -371,13 +372,17 @@ impl<R: 'static + ChunkReader> RowGroupReader for SerializedRowGroupReader<'_, R
         let page_locations = self.offset_index.map(|x| x[i].page_locations.clone());
 
         let props = Arc::clone(&self.props);
-        Ok(Box::new(SerializedPageReader::new_with_properties(
+        let mut page_reader = SerializedPageReader::new_with_properties(
             Arc::clone(&self.chunk_reader),
             col,
             usize::try_from(self.metadata.num_rows())?,
             page_locations,
             props,
-        )?))
+        )?;
+        if let Some(ordinal) = self.metadata.ordinal() {
+            page_reader = page_reader.with_row_group(ordinal as usize);
+        }
+        Ok(Box::new(page_reader))
     }
 
     /// get bloom filter for the `i`th column
@@ -390,6 +395,85 @@ impl<R: 'static + ChunkReader> RowGroupReader for SerializedRowGroupReader<'_, R
     }
 }
 
+/// Reads the pages of the column chunk for `column` in `row_group`, without
+/// decompressing or decoding them
+///
+/// This bypasses the decoding machinery of this crate entirely, e.g. to hand the
+/// encoded pages to a custom decoder. Each [`RawPage`] contains the page bytes as
+/// stored in the file, along with its parsed header. Index pages are skipped.
+///
+/// The page headers are read including their statistics, regardless of
+/// [`ReaderProperties`]. Encrypted columns are not supported.
+///
+/// ```no_run
+/// # use std::fs::File;
+/// # use parquet::basic::PageType;
+/// # use parquet::file::serialized_reader::{read_column_chunk_pages, SerializedFileReader};
+/// let reader = SerializedFileReader::new(File::open("data.parquet").unwrap()).unwrap();
+/// for page in read_column_chunk_pages(&reader, 0, 0).unwrap() {
+///     if page.page_type == PageType::DICTIONARY_PAGE {
+///         println!("dictionary of {} values", page.num_values);
+///     }
+/// }
+/// ```
+pub fn read_column_chunk_pages<R: ChunkReader>(
+    reader: &SerializedFileReader<R>,
+    row_group: usize,
+    column: usize,
+) -> Result<Vec<RawPage>> {
+    let metadata = &reader.metadata;
+    if row_group >= metadata.num_row_groups() {
+        return Err(general_err!(
+            "Row group {} out of bounds for file with {} row groups",
+            row_group,
+            metadata.num_row_groups()
+        ));
+    }
+    let row_group_metadata = metadata.row_group(row_group);
+    if column >= row_group_metadata.num_columns() {
+        return Err(general_err!(
+            "Column {} out of bounds for row group with {} columns",
+            column,
+            row_group_metadata.num_columns()
+        ));
+    }
+    let column_metadata = row_group_metadata.column(column);
+    #[cfg(feature = "encryption")]
+    if column_metadata.crypto_metadata().is_some() {
+        return Err(general_err!(
+            "Reading raw pages of encrypted columns is not supported"
+        ));
+    }
+
+    let (start, len) = column_metadata.byte_range();
+    let chunk = reader
+        .chunk_reader
+        .get_bytes(start, usize::try_from(len)?)?;
+
+    let mut pages = Vec::new();
+    let mut offset = 0;
+    while offset < chunk.len() {
+        let mut input = std::io::Cursor::new(&chunk[offset..]);
+        let header = PageHeader::read_thrift(&mut ThriftReadInputProtocol::new(&mut input))?;
+        offset += input.position() as usize;
+
+        let remaining = (chunk.len() - offset) as u64;
+        verify_page_size(
+            header.compressed_page_size,
+            header.uncompressed_page_size,
+            remaining,
+        )?;
+        let data_len = header.compressed_page_size as usize;
+        let data = chunk.slice(offset..offset + data_len);
+        offset += data_len;
+
+        if header.r#type != PageType::INDEX_PAGE {
+            pages.push(RawPage::try_from((header, data))?);
+        }
+    }
+    Ok(pages)
+}
+
 /// Decodes a [`Page`] from the provided `buffer`
 pub(crate) fn decode_page(
     page_header: PageHeader,
@@ -402,7 +486,7 @@ pub(crate) fn decode_page(
     if let Some(expected_crc) = page_header.crc {
         let crc = crc32fast::hash(&buffer);
         if crc != expected_crc as u32 {
-            return Err(general_err!("Page CRC checksum mismatch"));
+            return Err(corrupt_err!("Page CRC checksum mismatch"));
         }
     }
 
@@ -421,7 +505,7 @@ pub(crate) fn decode_page(
             || header_v2.definition_levels_byte_length + header_v2.repetition_levels_byte_length
                 > page_header.uncompressed_page_size
         {
-            return Err(general_err!(
+            return Err(corrupt_err!(
                 "DataPage v2 header contains implausible values \
                     for definition_levels_byte_length ({}) \
                     and repetition_levels_byte_length ({}) \
@@ -440,9 +524,9 @@ pub(crate) fn decode_page(
 
     let buffer = match decompressor {
         Some(decompressor) if can_decompress => {
-            let uncompressed_page_size = usize::try_from(page_header.uncompressed_page_size)?;
+            let uncompressed_page_size = header_value(page_header.uncompressed_page_size)?;
             if offset > buffer.len() || offset > uncompressed_page_size {
-                return Err(general_err!("Invalid page header"));
+                return Err(corrupt_err!("Invalid page header"));
             }
             let decompressed_size = uncompressed_page_size - offset;
             let mut decompressed = Vec::with_capacity(uncompressed_page_size);
@@ -455,7 +539,7 @@ pub(crate) fn decode_page(
             }
 
             if decompressed.len() != uncompressed_page_size {
-                return Err(general_err!(
+                return Err(corrupt_err!(
                     "Actual decompressed size doesn't match the expected one ({} vs {})",
                     decompressed.len(),
                     uncompressed_page_size
@@ -470,12 +554,12 @@ pub(crate) fn decode_page(
     let result = match page_header.r#type {
         PageType::DICTIONARY_PAGE => {
             let dict_header = page_header.dictionary_page_header.as_ref().ok_or_else(|| {
-                ParquetError::General("Missing dictionary page header".to_string())
+                ParquetError::Corrupt("Missing dictionary page header".to_string())
             })?;
             let is_sorted = dict_header.is_sorted.unwrap_or(false);
             Page::DictionaryPage {
                 buf: buffer,
-                num_values: dict_header.num_values.try_into()?,
+                num_values: header_value(dict_header.num_values)?,
                 encoding: dict_header.encoding,
                 is_sorted,
             }
@@ -483,10 +567,10 @@ pub(crate) fn decode_page(
         PageType::DATA_PAGE => {
             let header = page_header
                 .data_page_header
-                .ok_or_else(|| ParquetError::General("Missing V1 data page header".to_string()))?;
+                .ok_or_else(|| ParquetError::Corrupt("Missing V1 data page header".to_string()))?;
             Page::DataPage {
                 buf: buffer,
-                num_values: header.num_values.try_into()?,
+                num_values: header_value(header.num_values)?,
                 encoding: header.encoding,
                 def_level_encoding: header.definition_level_encoding,
                 rep_level_encoding: header.repetition_level_encoding,
@@ -496,16 +580,16 @@ pub(crate) fn decode_page(
         PageType::DATA_PAGE_V2 => {
             let header = page_header
                 .data_page_header_v2
-                .ok_or_else(|| ParquetError::General("Missing V2 data page header".to_string()))?;
+                .ok_or_else(|| ParquetError::Corrupt("Missing V2 data page header".to_string()))?;
             let is_compressed = header.is_compressed.unwrap_or(true);
             Page::DataPageV2 {
                 buf: buffer,
-                num_values: header.num_values.try_into()?,
+                num_values: header_value(header.num_values)?,
                 encoding: header.encoding,
-                num_nulls: header.num_nulls.try_into()?,
-                num_rows: header.num_rows.try_into()?,
-                def_levels_byte_len: header.definition_levels_byte_length.try_into()?,
-                rep_levels_byte_len: header.repetition_levels_byte_length.try_into()?,
+                num_nulls: header_value(header.num_nulls)?,
+                num_rows: header_value(header.num_rows)?,
+                def_levels_byte_len: header_value(header.definition_levels_byte_length)?,
+                rep_levels_byte_len: header_value(header.repetition_levels_byte_length)?,
                 is_compressed,
                 statistics: statistics::from_thrift_page_stats(physical_type, header.statistics)?,
             }
@@ -522,6 +606,13 @@ pub(crate) fn decode_page(
     Ok(result)
 }
 
+/// Converts an integer read from a page header, which is corrupt if out of range
+fn header_value<T: TryFrom<i32, Error = TryFromIntError>>(value: i32) -> Result<T> {
+    value
+        .try_into()
+        .map_err(|e| corrupt_err!("Integer overflow: {}", e))
+}
+
 enum SerializedPageReaderState {
     Values {
         /// The current byte offset in the reader
@@ -560,6 +651,8 @@ struct SerializedPageReaderContext {
     /// Crypto context carrying objects required for decryption
     #[cfg(feature = "encryption")]
     crypto_context: Option<Arc<CryptoContext>>,
+    /// Location of this column chunk, attached to any errors
+    error_context: ErrorContext,
 }
 
 /// A serialized implementation for Parquet [`PageReader`].
@@ -629,6 +722,37 @@ impl<R: ChunkReader> SerializedPageReader<R> {
         Ok(self)
     }
 
+    /// Records the ordinal of the row group this column chunk belongs to,
+    /// which is reported in the [`ErrorContext`] of any errors.
+    pub fn with_row_group(mut self, row_group: usize) -> Self {
+        self.context.error_context = self.context.error_context.with_row_group(row_group);
+        self
+    }
+
+    /// Returns the file offset of the next page, if known
+    fn next_page_offset(&self) -> Option<u64> {
+        match &self.state {
+            SerializedPageReaderState::Values { offset, .. } => Some(*offset),
+            SerializedPageReaderState::Pages {
+                page_locations,
+                dictionary_page,
+                ..
+            } => dictionary_page
+                .as_ref()
+                .or_else(|| page_locations.front())
+                .map(|p| p.offset as u64),
+        }
+    }
+
+    /// Attaches the location of the page starting at `offset` to `e`
+    fn page_error_at(&self, e: ParquetError, offset: Option<u64>) -> ParquetError {
+        let mut context = self.context.error_context.clone();
+        if let Some(offset) = offset {
+            context = context.with_offset(offset);
+        }
+        e.with_context(context)
+    }
+
     /// Creates a new serialized page with custom options.
     pub fn new_with_properties(
         reader: Arc<R>,
@@ -672,6 +796,7 @@ impl<R: ChunkReader> SerializedPageReader<R> {
         if props.read_page_stats() {
             context.read_stats = true;
         }
+        context.error_context = ErrorContext::new().with_column(meta.column_path().clone());
         Ok(Self {
             reader,
             decompressor,
@@ -832,10 +957,10 @@ impl SerializedPageReaderContext {
                 }
             }
             Some(page_crypto_context) => {
-                let data_decryptor = page_crypto_context.data_decryptor();
+                let metadata_decryptor = page_crypto_context.metadata_decryptor();
                 let aad = page_crypto_context.create_page_header_aad()?;
 
-                let buf = read_and_decrypt(data_decryptor, input, aad.as_ref()).map_err(|_| {
+                let buf = read_and_decrypt(metadata_decryptor, input, aad.as_ref()).map_err(|_| {
                     ParquetError::General(format!(
                         "Error decrypting page header for column {}, decryption key may be wrong",
                         page_crypto_context.column_ordinal
@@ -913,7 +1038,43 @@ fn verify_page_size(
 }
 
 impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
+    fn error_context(&self) -> ErrorContext {
+        self.context.error_context.clone()
+    }
+
     fn get_next_page(&mut self) -> Result<Option<Page>> {
+        let offset = self.next_page_offset();
+        self.read_next_page()
+            .map_err(|e| self.page_error_at(e, offset))
+    }
+
+    fn peek_next_page(&mut self) -> Result<Option<PageMetadata>> {
+        let offset = self.next_page_offset();
+        self.read_next_page_metadata()
+            .map_err(|e| self.page_error_at(e, offset))
+    }
+
+    fn skip_next_page(&mut self) -> Result<()> {
+        let offset = self.next_page_offset();
+        self.read_skip_next_page()
+            .map_err(|e| self.page_error_at(e, offset))
+    }
+
+    fn at_record_boundary(&mut self) -> Result<bool> {
+        match &mut self.state {
+            SerializedPageReaderState::Values { .. } => match self.peek_next_page()? {
+                None => Ok(true),
+                // V2 data pages must start at record boundaries per the parquet
+                // spec, so the current page ends at one.
+                Some(metadata) => Ok(metadata.num_rows.is_some()),
+            },
+            SerializedPageReaderState::Pages { .. } => Ok(true),
+        }
+    }
+}
+
+impl<R: ChunkReader> SerializedPageReader<R> {
+    fn read_next_page(&mut self) -> Result<Option<Page>> {
         loop {
             let page = match &mut self.state {
                 SerializedPageReaderState::Values {
@@ -1019,7 +1180,7 @@ impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
         }
     }
 
-    fn peek_next_page(&mut self) -> Result<Option<PageMetadata>> {
+    fn read_next_page_metadata(&mut self) -> Result<Option<PageMetadata>> {
         match &mut self.state {
             SerializedPageReaderState::Values {
                 offset,
@@ -1092,7 +1253,7 @@ impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
         }
     }
 
-    fn skip_next_page(&mut self) -> Result<()> {
+    fn read_skip_next_page(&mut self) -> Result<()> {
         match &mut self.state {
             SerializedPageReaderState::Values {
                 offset,
@@ -1155,35 +1316,26 @@ impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
             }
         }
     }
-
-    fn at_record_boundary(&mut self) -> Result<bool> {
-        match &mut self.state {
-            SerializedPageReaderState::Values { .. } => match self.peek_next_page()? {
-                None => Ok(true),
-                // V2 data pages must start at record boundaries per the parquet
-                // spec, so the current page ends at one.
-                Some(metadata) => Ok(metadata.num_rows.is_some()),
-            },
-            SerializedPageReaderState::Pages { .. } => Ok(true),
-        }
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
 
+    use crate::errors::ErrorKind;
+
     use bytes::Buf;
 
     use crate::file::page_index::column_index::{
         ByteArrayColumnIndex, ColumnIndexMetaData, PrimitiveColumnIndex,
     };
-    use crate::file::properties::{EnabledStatistics, WriterProperties};
+    use crate::file::properties::{EnabledStatistics, WriterProperties, WriterVersion};
 
     use crate::basic::{self, BoundaryOrder, ColumnOrder, Encoding, SortOrder};
+    use crate::column::page::RawPageHeader;
     use crate::column::reader::ColumnReader;
     use crate::data_type::private::ParquetValueType;
-    use crate::data_type::{AsBytes, FixedLenByteArrayType, Int32Type};
+    use crate::data_type::{AsBytes, ByteArray, ByteArrayType, FixedLenByteArrayType, Int32Type};
     use crate::file::metadata::thrift::DataPageHeaderV2;
     #[allow(deprecated)]
     use crate::file::page_index::index_reader::{read_columns_indexes, read_offset_indexes};
@@ -2899,4 +3051,159 @@ mod tests {
         }
         assert_eq!(num_rows, reader.metadata().file_metadata().num_rows());
     }
+
+    fn write_raw_pages_file(props: WriterProperties) -> Bytes {
+        let message_type = "
+        message test_schema {
+          REQUIRED INT32 a;
+          OPTIONAL BYTE_ARRAY b;
+        }
+        ";
+        let schema = Arc::new(parse_message_type(message_type).unwrap());
+        let mut out = Vec::new();
+        let mut writer = SerializedFileWriter::new(&mut out, schema, Arc::new(props)).unwrap();
+        for _ in 0..2 {
+            let mut r = writer.next_row_group().unwrap();
+            let mut c = r.next_column().unwrap().unwrap();
+            let values: Vec<i32> = (0..1000).map(|v| v % 7).collect();
+            c.typed::<Int32Type>()
+                .write_batch(&values, None, None)
+                .unwrap();
+            c.close().unwrap();
+            let mut c = r.next_column().unwrap().unwrap();
+            let values: Vec<_> = (0..500)
+                .map(|v| ByteArray::from(format!("v{v}").as_str()))
+                .collect();
+            let def_levels: Vec<i16> = (0..1000).map(|v| (v % 2) as i16).collect();
+            c.typed::<ByteArrayType>()
+                .write_batch(&values, Some(&def_levels), None)
+                .unwrap();
+            c.close().unwrap();
+            r.close().unwrap();
+        }
+        writer.close().unwrap();
+        Bytes::from(out)
+    }
+
+    #[test]
+    fn test_page_error_context() {
+        let data = write_raw_pages_file(WriterProperties::default());
+        let reader = SerializedFileReader::new(data.clone()).unwrap();
+        let column = reader.metadata().row_group(1).column(1);
+        let offset = column.byte_range().0;
+
+        // Truncate the first page header of column b in the second row group
+        let mut corrupt = data.to_vec();
+        corrupt[offset as usize] = 0;
+        let reader = SerializedFileReader::new(Bytes::from(corrupt)).unwrap();
+        let mut pages = reader
+            .get_row_group(1)
+            .unwrap()
+            .get_column_page_reader(1)
+            .unwrap();
+
+        let err = pages.get_next_page().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Corrupt);
+        let context = err.context().unwrap();
+        assert_eq!(context.column.as_ref().unwrap().string(), "b");
+        assert_eq!(context.row_group, Some(1));
+        assert_eq!(context.offset, Some(offset));
+        assert_eq!(
+            context.to_string(),
+            format!("column \"b\", row group 1, offset {offset}")
+        );
+    }
+
+    #[test]
+    fn test_read_column_chunk_pages() {
+        let props = WriterProperties::builder()
+            .set_data_page_row_count_limit(300)
+            .set_write_batch_size(100)
+            .build();
+        let reader = SerializedFileReader::new(write_raw_pages_file(props)).unwrap();
+
+        for column in 0..2 {
+            let pages = read_column_chunk_pages(&reader, 1, column).unwrap();
+            assert_eq!(pages[0].page_type, PageType::DICTIONARY_PAGE);
+            assert!(matches!(pages[0].header, RawPageHeader::DictionaryPage(_)));
+            assert_eq!(pages[0].encoding, Encoding::PLAIN);
+
+            let data_pages = &pages[1..];
+            assert_eq!(data_pages.len(), 4);
+            let num_values: i32 = data_pages.iter().map(|p| p.num_values).sum();
+            assert_eq!(num_values, 1000);
+
+            // Uncompressed pages hold the same bytes as the decoded pages
+            let decoded: Vec<Page> = reader
+                .get_row_group(1)
+                .unwrap()
+                .get_column_page_reader(column)
+                .unwrap()
+                .collect::<Result<_>>()
+                .unwrap();
+            assert_eq!(decoded.len(), pages.len());
+            for (raw, page) in pages.iter().zip(&decoded) {
+                assert_eq!(raw.page_type, page.page_type());
+                assert_eq!(raw.encoding, page.encoding());
+                assert_eq!(raw.num_values as u32, page.num_values());
+                assert_eq!(raw.compressed_bytes, page.buffer());
+                assert_eq!(raw.uncompressed_size as usize, page.buffer().len());
+                if let RawPageHeader::DataPage(header) = &raw.header {
+                    assert_eq!(header.encoding, Encoding::RLE_DICTIONARY);
+                    assert_eq!(header.definition_level_encoding, Encoding::RLE);
+                }
+            }
+        }
+
+        let err = read_column_chunk_pages(&reader, 2, 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Row group 2 out of bounds for file with 2 row groups"
+        );
+        let err = read_column_chunk_pages(&reader, 0, 2).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Column 2 out of bounds for row group with 2 columns"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "snap")]
+    fn test_read_column_chunk_pages_compressed_v2() {
+        let props = WriterProperties::builder()
+            .set_writer_version(WriterVersion::PARQUET_2_0)
+            .set_compression(basic::Compression::SNAPPY)
+            .set_dictionary_enabled(false)
+            .set_statistics_enabled(EnabledStatistics::Page)
+            .set_write_page_header_statistics(true)
+            .build();
+        let reader = SerializedFileReader::new(write_raw_pages_file(props)).unwrap();
+        let pages = read_column_chunk_pages(&reader, 0, 1).unwrap();
+        assert_eq!(pages.len(), 1);
+
+        let page = &pages[0];
+        assert_eq!(page.page_type, PageType::DATA_PAGE_V2);
+        let RawPageHeader::DataPageV2(header) = &page.header else {
+            panic!("expected a v2 data page header");
+        };
+        assert_eq!(header.num_nulls, 500);
+        assert_eq!(header.num_rows, 1000);
+        assert!(header.statistics.is_some());
+
+        // Decompress the values manually, the levels are stored uncompressed
+        let levels_len =
+            (header.definition_levels_byte_length + header.repetition_levels_byte_length) as usize;
+        let mut decompressed = page.compressed_bytes[..levels_len].to_vec();
+        let mut codec = create_codec(basic::Compression::SNAPPY, &Default::default())
+            .unwrap()
+            .unwrap();
+        codec
+            .decompress(
+                &page.compressed_bytes[levels_len..],
+                &mut decompressed,
+                None,
+            )
+            .unwrap();
+        assert_eq!(decompressed.len(), page.uncompressed_size as usize);
+    }
 }
@@ -27,7 +27,9 @@ use std::io::{BufWriter, IoSlice, Read};
 use std::{io::Write, sync::Arc};
 
 use crate::column::page_encryption::PageEncryptor;
-use crate::column::writer::{ColumnCloseResult, ColumnWriterImpl, get_typed_column_writer_mut};
+use crate::column::writer::{
+    ColumnCloseResult, ColumnWriterImpl, check_column_encoding, get_typed_column_writer_mut,
+};
 use crate::column::{
     page::{CompressedPage, PageWriteSpec, PageWriter},
     writer::{ColumnWriter, get_column_writer},
@@ -362,10 +364,9 @@ impl<W: Write + Send> SerializedFileWriter<W> {
             encoder = encoder.with_key_value_metadata(key_value_metadata)
         }
 
-        encoder = encoder.with_column_indexes(column_indexes);
-        if !self.props.offset_index_disabled() {
-            encoder = encoder.with_offset_indexes(offset_indexes);
-        }
+        encoder = encoder
+            .with_column_indexes(column_indexes)
+            .with_offset_indexes(offset_indexes);
         encoder.finish()
     }
 
@@ -593,15 +594,22 @@ impl<'a, W: Write + Send> SerializedRowGroupWriter<'a, W> {
         let column_indexes = &mut self.column_indexes;
         let offset_indexes = &mut self.offset_indexes;
         let bloom_filters = &mut self.bloom_filters;
+        let props = &self.props;
 
         let on_close = |r: ColumnCloseResult| {
             // Update row group writer metrics
             *total_bytes_written += r.bytes_written;
             *total_uncompressed_bytes += r.metadata.uncompressed_size();
+            // Chunks appended with `append_column` may carry an offset index
+            // for a column whose offset index is disabled
+            let offset_index = match props.column_offset_index_disabled(r.metadata.column_path()) {
+                true => None,
+                false => r.offset_index,
+            };
             column_chunks.push(r.metadata);
             bloom_filters.push(r.bloom_filter);
             column_indexes.push(r.column_index);
-            offset_indexes.push(r.offset_index);
+            offset_indexes.push(offset_index);
 
             if let Some(rows) = *total_rows_written {
                 if rows != r.rows_written {
@@ -660,6 +668,7 @@ impl<'a, W: Write + Send> SerializedRowGroupWriter<'a, W> {
     /// closed returns `Err`.
     pub fn next_column(&mut self) -> Result<Option<SerializedColumnWriter<'_>>> {
         self.next_column_with_factory(|descr, props, page_writer, on_close| {
+            check_column_encoding(&descr, &props)?;
             let column_writer = get_column_writer(descr, props, page_writer);
             Ok(SerializedColumnWriter::new(column_writer, Some(on_close)))
         })
@@ -723,7 +732,8 @@ impl<'a, W: Write + Send> SerializedRowGroupWriter<'a, W> {
             .set_num_values(metadata.num_values())
             .set_data_page_offset(map_offset(src_data_offset))
             .set_dictionary_page_offset(src_dictionary_offset.map(map_offset))
-            .set_unencoded_byte_array_data_bytes(metadata.unencoded_byte_array_data_bytes());
+            .set_unencoded_byte_array_data_bytes(metadata.unencoded_byte_array_data_bytes())
+            .set_key_value_metadata(metadata.key_value_metadata().cloned());
 
         if let Some(rep_hist) = metadata.repetition_level_histogram() {
             builder = builder.set_repetition_level_histogram(Some(rep_hist.clone()))
@@ -1054,7 +1064,7 @@ mod tests {
     use crate::column::page::{Page, PageReader};
     use crate::column::reader::get_typed_column_reader;
     use crate::compression::{Codec, CodecOptionsBuilder, create_codec};
-    use crate::data_type::{BoolType, ByteArrayType, Int32Type};
+    use crate::data_type::{BoolType, ByteArray, ByteArrayType, Int32Type};
     use crate::file::page_index::column_index::ColumnIndexMetaData;
     use crate::file::properties::EnabledStatistics;
     use crate::file::serialized_reader::ReadOptionsBuilder;
@@ -1886,6 +1896,59 @@ mod tests {
         test_kv_metadata(None, Some(vec![]));
     }
 
+    #[test]
+    fn test_column_chunk_key_value_metadata() {
+        let schema = Arc::new(
+            parse_message_type("message schema { REQUIRED INT32 a; REQUIRED INT32 b; }").unwrap(),
+        );
+        let source = KeyValue::new("source".to_string(), "etl".to_string());
+        let props = Arc::new(
+            WriterProperties::builder()
+                .set_column_key_value_metadata(ColumnPath::from("a"), vec![source.clone()])
+                .set_column_key_value_metadata_fn(|metadata| {
+                    let num_values = metadata.num_values().to_string();
+                    match metadata.column_path().string().as_str() {
+                        "b" => vec![KeyValue::new("num_values".to_string(), num_values)],
+                        _ => vec![],
+                    }
+                })
+                .build(),
+        );
+
+        let mut out = Vec::with_capacity(1024);
+        let mut writer = SerializedFileWriter::new(&mut out, schema, props).unwrap();
+        for num_values in [3, 5] {
+            let mut row_group_writer = writer.next_row_group().unwrap();
+            while let Some(mut column) = row_group_writer.next_column().unwrap() {
+                let values: Vec<i32> = (0..num_values).collect();
+                column
+                    .typed::<Int32Type>()
+                    .write_batch(&values, None, None)
+                    .unwrap();
+                column.close().unwrap();
+            }
+            row_group_writer.close().unwrap();
+        }
+        writer.close().unwrap();
+
+        let reader = SerializedFileReader::new(Bytes::from(out)).unwrap();
+        let row_groups = reader.metadata().row_groups();
+        assert_eq!(row_groups.len(), 2);
+        for (row_group, num_values) in row_groups.iter().zip(["3", "5"]) {
+            assert_eq!(
+                row_group.column(0).key_value_metadata(),
+                Some(&vec![source.clone()])
+            );
+            assert_eq!(
+                row_group.column(1).key_value_metadata(),
+                Some(&vec![KeyValue::new(
+                    "num_values".to_string(),
+                    num_values.to_string()
+                )])
+            );
+        }
+    }
+
     #[test]
     fn test_backwards_compatible_statistics() {
         let message_type = "
@@ -2085,6 +2148,84 @@ mod tests {
         assert!(matches!(b_idx, ColumnIndexMetaData::NONE), "{b_idx:?}");
     }
 
+    #[test]
+    fn test_column_page_index_properties() {
+        let message_type = "
+            message test_schema {
+                REQUIRED BYTE_ARRAY a;
+                REQUIRED BYTE_ARRAY b;
+                REQUIRED BYTE_ARRAY c;
+            }
+        ";
+        let schema = Arc::new(parse_message_type(message_type).unwrap());
+        let props = WriterProperties::builder()
+            .set_column_index_truncate_length(None)
+            .set_column_offset_index_disabled("b".into(), true)
+            .set_column_statistics_enabled("b".into(), EnabledStatistics::Chunk)
+            .set_column_column_index_truncate_length("c".into(), Some(2))
+            .build();
+        let mut file = Vec::with_capacity(1024);
+        let mut file_writer =
+            SerializedFileWriter::new(&mut file, schema, Arc::new(props)).unwrap();
+
+        let values = [ByteArray::from("a".repeat(100).as_str())];
+        let mut row_group_writer = file_writer.next_row_group().unwrap();
+        while let Some(mut col_writer) = row_group_writer.next_column().unwrap() {
+            col_writer
+                .typed::<ByteArrayType>()
+                .write_batch(&values, None, None)
+                .unwrap();
+            col_writer.close().unwrap();
+        }
+        row_group_writer.close().unwrap();
+        let metadata = file_writer.finish().unwrap();
+
+        let row_group = metadata.row_group(0);
+        // Column "b" has neither offset nor column index
+        assert!(row_group.column(0).offset_index_offset().is_some());
+        assert!(row_group.column(1).offset_index_offset().is_none());
+        assert!(row_group.column(1).column_index_offset().is_none());
+        assert!(row_group.column(2).offset_index_offset().is_some());
+
+        let column_index_size: i32 = row_group
+            .columns()
+            .iter()
+            .filter_map(|c| c.column_index_length())
+            .sum();
+        let offset_index_size: i32 = row_group
+            .columns()
+            .iter()
+            .filter_map(|c| c.offset_index_length())
+            .sum();
+        assert!(column_index_size > 0);
+        assert!(offset_index_size > 0);
+        assert_eq!(metadata.column_index_size(), column_index_size as u64);
+        assert_eq!(metadata.offset_index_size(), offset_index_size as u64);
+
+        // Offset index is incomplete, and so not included in the returned metadata
+        assert!(metadata.offset_index().is_none());
+        let column_index = metadata.column_index().unwrap();
+        // Column "a" is not truncated, column "c" is truncated to 2 bytes
+        let ColumnIndexMetaData::BYTE_ARRAY(a_idx) = &column_index[0][0] else {
+            panic!("expected BYTE_ARRAY column index");
+        };
+        assert_eq!(a_idx.min_value(0).unwrap().len(), 100);
+        assert_eq!(a_idx.max_value(0).unwrap().len(), 100);
+        assert!(matches!(column_index[0][1], ColumnIndexMetaData::NONE));
+        let ColumnIndexMetaData::BYTE_ARRAY(c_idx) = &column_index[0][2] else {
+            panic!("expected BYTE_ARRAY column index");
+        };
+        assert_eq!(c_idx.min_value(0).unwrap(), b"aa");
+        assert_eq!(c_idx.max_value(0).unwrap(), b"ab");
+
+        drop(file_writer);
+
+        let reader = SerializedFileReader::new(Bytes::from(file)).unwrap();
+        let metadata = reader.metadata();
+        assert_eq!(metadata.column_index_size(), column_index_size as u64);
+        assert_eq!(metadata.offset_index_size(), offset_index_size as u64);
+    }
+
     #[test]
     fn test_byte_array_size_statistics() {
         let message_type = "
@@ -2444,6 +2585,119 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rewrite_preserves_bloom_filters_and_page_indexes() {
+        let schema = Arc::new(parse_message_type("message m { REQUIRED INT32 a; }").unwrap());
+        let write_source = |bloom_filter: bool, row_groups: &[std::ops::Range<i32>]| {
+            let props = WriterProperties::builder()
+                .set_bloom_filter_enabled(bloom_filter)
+                .set_data_page_row_count_limit(10)
+                .set_write_batch_size(10)
+                .build();
+            let mut file = Vec::new();
+            let mut writer =
+                SerializedFileWriter::new(&mut file, schema.clone(), Arc::new(props)).unwrap();
+            for range in row_groups {
+                let mut rg = writer.next_row_group().unwrap();
+                let mut col = rg.next_column().unwrap().unwrap();
+                let values: Vec<i32> = range.clone().collect();
+                col.typed::<Int32Type>()
+                    .write_batch(&values, None, None)
+                    .unwrap();
+                col.close().unwrap();
+                rg.close().unwrap();
+            }
+            writer.close().unwrap();
+            Bytes::from(file)
+        };
+        let inputs = [
+            write_source(true, &[0..50, 50..100]),
+            write_source(false, &[100..125, 125..150]),
+        ];
+
+        // Copy every row group, as done by parquet-concat
+        let props = Arc::new(WriterProperties::builder().build());
+        let mut output = Vec::new();
+        let mut writer = SerializedFileWriter::new(&mut output, schema.clone(), props).unwrap();
+        for input in &inputs {
+            let metadata = ParquetMetaDataReader::new()
+                .with_page_index_policy(PageIndexPolicy::Required)
+                .parse_and_finish(input)
+                .unwrap();
+            for (rg_idx, rg) in metadata.row_groups().iter().enumerate() {
+                let mut rg_out = writer.next_row_group().unwrap();
+                for (col_idx, column) in rg.columns().iter().enumerate() {
+                    let result = ColumnCloseResult {
+                        bytes_written: column.compressed_size() as _,
+                        rows_written: rg.num_rows() as _,
+                        metadata: column.clone(),
+                        bloom_filter: Sbbf::read_from_column_chunk(column, input).unwrap(),
+                        column_index: Some(
+                            metadata.column_index().unwrap()[rg_idx][col_idx].clone(),
+                        ),
+                        offset_index: Some(
+                            metadata.offset_index().unwrap()[rg_idx][col_idx].clone(),
+                        ),
+                    };
+                    rg_out.append_column(input, result).unwrap();
+                }
+                rg_out.close().unwrap();
+            }
+        }
+        writer.close().unwrap();
+        let output = Bytes::from(output);
+
+        let options = ReadOptionsBuilder::new()
+            .with_page_index()
+            .with_reader_properties(
+                ReaderProperties::builder()
+                    .set_read_bloom_filter(true)
+                    .build(),
+            )
+            .build();
+        let reader = SerializedFileReader::new_with_options(output, options).unwrap();
+        let metadata = reader.metadata();
+        assert_eq!(metadata.num_row_groups(), 4);
+
+        let ranges = [0..50, 50..100, 100..125, 125..150];
+        for (rg_idx, range) in ranges.into_iter().enumerate() {
+            let rg_reader = reader.get_row_group(rg_idx).unwrap();
+            let column = metadata.row_group(rg_idx).column(0);
+
+            // Bloom filters are only present for row groups copied from a file with them
+            match rg_reader.get_column_bloom_filter(0) {
+                Some(sbbf) => {
+                    assert!(rg_idx < 2);
+                    assert!(range.clone().all(|v| sbbf.check(&v)));
+                }
+                None => assert!(rg_idx >= 2),
+            }
+
+            assert!(matches!(
+                metadata.column_index().unwrap()[rg_idx][0],
+                ColumnIndexMetaData::INT32(_)
+            ));
+
+            // Page locations refer to the pages' new positions in the output
+            let locations = metadata.offset_index().unwrap()[rg_idx][0].page_locations();
+            assert_eq!(locations.len(), range.len().div_ceil(10));
+            assert_eq!(locations[0].offset, column.data_page_offset());
+            let last = locations.last().unwrap();
+            let (start, len) = column.byte_range();
+            assert_eq!(
+                (last.offset + last.compressed_page_size as i64) as u64,
+                start + len
+            );
+
+            let mut out = Vec::new();
+            let col = rg_reader.get_column_reader(0).unwrap();
+            get_typed_column_reader::<Int32Type>(col)
+                .read_records(100, None, None, &mut out)
+                .unwrap();
+            assert_eq!(out, range.collect::<Vec<_>>());
+        }
+    }
+
     #[test]
     fn test_rewrite_no_page_indexes() {
         let file = get_test_file("alltypes_tiny_pages.parquet");
@@ -55,7 +55,7 @@ macro_rules! thrift_enum {
                 let val = prot.read_i32()?;
                 match val {
                     $($field_value => Ok(Self::$field_name),)*
-                    _ => Err(general_err!("Unexpected {} {}", stringify!($identifier), val)),
+                    _ => Err(corrupt_err!("Unexpected {} {}", stringify!($identifier), val)),
                 }
             }
         }
@@ -143,7 +143,7 @@ macro_rules! thrift_union_all_empty {
             fn read_thrift(prot: &mut R) -> Result<Self> {
                 let field_ident = prot.read_field_begin(0)?;
                 if field_ident.field_type == FieldType::Stop {
-                    return Err(general_err!("Received empty union from remote {}", stringify!($identifier)));
+                    return Err(corrupt_err!("Received empty union from remote {}", stringify!($identifier)));
                 }
                 let ret = match field_ident.id {
                     $($field_id => {
@@ -152,12 +152,12 @@ macro_rules! thrift_union_all_empty {
                     }
                     )*
                     _ => {
-                        return Err(general_err!("Unexpected {} {}", stringify!($identifier), field_ident.id));
+                        return Err(corrupt_err!("Unexpected {} {}", stringify!($identifier), field_ident.id));
                     }
                 };
                 let field_ident = prot.read_field_begin(field_ident.id)?;
                 if field_ident.field_type != FieldType::Stop {
-                    return Err(general_err!(
+                    return Err(corrupt_err!(
                         "Received multiple fields for union from remote {}", stringify!($identifier)
                     ));
                 }
@@ -217,7 +217,7 @@ macro_rules! thrift_union {
             fn read_thrift(prot: &mut R) -> Result<Self> {
                 let field_ident = prot.read_field_begin(0)?;
                 if field_ident.field_type == FieldType::Stop {
-                    return Err(general_err!("Received empty union from remote {}", stringify!($identifier)));
+                    return Err(corrupt_err!("Received empty union from remote {}", stringify!($identifier)));
                 }
                 let ret = match field_ident.id {
                     $($field_id => {
@@ -225,12 +225,12 @@ macro_rules! thrift_union {
                         val
                     })*
                     _ => {
-                        return Err(general_err!("Unexpected {} {}", stringify!($identifier), field_ident.id));
+                        return Err(corrupt_err!("Unexpected {} {}", stringify!($identifier), field_ident.id));
                     }
                 };
                 let field_ident = prot.read_field_begin(field_ident.id)?;
                 if field_ident.field_type != FieldType::Stop {
-                    return Err(general_err!(
+                    return Err(corrupt_err!(
                         concat!("Received multiple fields for union from remote {}", stringify!($identifier))
                     ));
                 }
@@ -419,7 +419,7 @@ macro_rules! __thrift_required_or_optional {
 macro_rules! __thrift_result_required_or_optional {
     (required $field_name:ident) => {
         let Some($field_name) = $field_name else {
-            return Err(general_err!(concat!(
+            return Err(corrupt_err!(concat!(
                 "Required field ",
                 stringify!($field_name),
                 " is missing",
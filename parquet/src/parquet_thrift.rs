@@ -58,24 +58,24 @@ impl From<ThriftProtocolError> for ParquetError {
             ThriftProtocolError::Eof => eof_err!("Unexpected EOF"),
             ThriftProtocolError::IO(e) => e.into(),
             ThriftProtocolError::InvalidFieldType(value) => {
-                general_err!("Unexpected struct field type {}", value)
+                corrupt_err!("Unexpected struct field type {}", value)
             }
             ThriftProtocolError::InvalidElementType(value) => {
-                general_err!("Unexpected list/set element type {}", value)
+                corrupt_err!("Unexpected list/set element type {}", value)
             }
             ThriftProtocolError::FieldDeltaOverflow {
                 field_delta,
                 last_field_id,
-            } => general_err!("cannot add {} to {}", field_delta, last_field_id),
+            } => corrupt_err!("cannot add {} to {}", field_delta, last_field_id),
             ThriftProtocolError::InvalidBoolean(value) => {
-                general_err!("cannot convert {} into bool", value)
+                corrupt_err!("cannot convert {} into bool", value)
             }
-            ThriftProtocolError::Utf8Error => general_err!("invalid utf8"),
+            ThriftProtocolError::Utf8Error => corrupt_err!("invalid utf8"),
             ThriftProtocolError::SkipDepth(field_type) => {
-                general_err!("cannot parse past {:?}", field_type)
+                corrupt_err!("cannot parse past {:?}", field_type)
             }
             ThriftProtocolError::SkipUnsupportedType(field_type) => {
-                general_err!("cannot skip field type {:?}", field_type)
+                corrupt_err!("cannot skip field type {:?}", field_type)
             }
         }
     }
@@ -467,7 +467,7 @@ pub(crate) trait ThriftCompactInputProtocol<'a> {
     fn skip_empty_struct(&mut self) -> Result<()> {
         let b = self.read_byte()?;
         if b != 0 {
-            Err(general_err!("Empty struct has fields"))
+            Err(corrupt_err!("Empty struct has fields"))
         } else {
             Ok(())
         }
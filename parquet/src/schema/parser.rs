@@ -137,12 +137,12 @@ struct Parser<'a> {
 fn assert_token(token: Option<&str>, expected: &str) -> Result<()> {
     match token {
         Some(value) if value == expected => Ok(()),
-        Some(other) => Err(general_err!(
+        Some(other) => Err(schema_err!(
             "Expected '{}', found token '{}'",
             expected,
             other
         )),
-        None => Err(general_err!(
+        None => Err(schema_err!(
             "Expected '{}', but no token found (None)",
             expected
         )),
@@ -153,19 +153,19 @@ fn assert_token(token: Option<&str>, expected: &str) -> Result<()> {
 #[inline]
 fn parse_i32(value: Option<&str>, not_found_msg: &str, parse_fail_msg: &str) -> Result<i32> {
     value
-        .ok_or_else(|| general_err!(not_found_msg))
-        .and_then(|v| v.parse::<i32>().map_err(|_| general_err!(parse_fail_msg)))
+        .ok_or_else(|| schema_err!(not_found_msg))
+        .and_then(|v| v.parse::<i32>().map_err(|_| schema_err!(parse_fail_msg)))
 }
 
 // Utility function to parse boolean or return general error.
 #[inline]
 fn parse_bool(value: Option<&str>, not_found_msg: &str, parse_fail_msg: &str) -> Result<bool> {
     value
-        .ok_or_else(|| general_err!(not_found_msg))
+        .ok_or_else(|| schema_err!(not_found_msg))
         .and_then(|v| {
             v.to_lowercase()
                 .parse::<bool>()
-                .map_err(|_| general_err!(parse_fail_msg))
+                .map_err(|_| schema_err!(parse_fail_msg))
         })
 }
 
@@ -176,12 +176,12 @@ fn parse_timeunit(
     parse_fail_msg: &str,
 ) -> Result<TimeUnit> {
     value
-        .ok_or_else(|| general_err!(not_found_msg))
+        .ok_or_else(|| schema_err!(not_found_msg))
         .and_then(|v| match v.to_uppercase().as_str() {
             "MILLIS" => Ok(TimeUnit::MILLIS),
             "MICROS" => Ok(TimeUnit::MICROS),
             "NANOS" => Ok(TimeUnit::NANOS),
-            _ => Err(general_err!(parse_fail_msg)),
+            _ => Err(schema_err!(parse_fail_msg)),
         })
 }
 
@@ -194,12 +194,12 @@ impl Parser<'_> {
                 let name = self
                     .tokenizer
                     .next()
-                    .ok_or_else(|| general_err!("Expected name, found None"))?;
+                    .ok_or_else(|| schema_err!("Expected name, found None"))?;
                 Type::group_type_builder(name)
                     .with_fields(self.parse_child_types()?)
                     .build()
             }
-            _ => Err(general_err!("Message type does not start with 'message'")),
+            _ => Err(schema_err!("Message type does not start with 'message'")),
         }
     }
 
@@ -224,7 +224,7 @@ impl Parser<'_> {
         let repetition = self
             .tokenizer
             .next()
-            .ok_or_else(|| general_err!("Expected repetition, found None"))
+            .ok_or_else(|| schema_err!("Expected repetition, found None"))
             .and_then(|v| v.to_uppercase().parse::<Repetition>())?;
 
         match self.tokenizer.next() {
@@ -233,7 +233,7 @@ impl Parser<'_> {
                 let physical_type = type_string.to_uppercase().parse::<PhysicalType>()?;
                 self.add_primitive_type(repetition, physical_type)
             }
-            None => Err(general_err!("Invalid type, could not extract next token")),
+            None => Err(schema_err!("Invalid type, could not extract next token")),
         }
     }
 
@@ -242,14 +242,14 @@ impl Parser<'_> {
         let name = self
             .tokenizer
             .next()
-            .ok_or_else(|| general_err!("Expected name, found None"))?;
+            .ok_or_else(|| schema_err!("Expected name, found None"))?;
 
         // Parse logical or converted type if exists
         let (logical_type, converted_type) = if let Some("(") = self.tokenizer.next() {
             let tpe = self
                 .tokenizer
                 .next()
-                .ok_or_else(|| general_err!("Expected converted type, found None"))
+                .ok_or_else(|| schema_err!("Expected converted type, found None"))
                 .and_then(|v| {
                     // Try logical type first
                     let upper = v.to_uppercase();
@@ -308,7 +308,7 @@ impl Parser<'_> {
         let name = self
             .tokenizer
             .next()
-            .ok_or_else(|| general_err!("Expected name, found None"))?;
+            .ok_or_else(|| schema_err!("Expected name, found None"))?;
 
         // Parse converted type
         let (logical_type, converted_type, precision, scale) = if let Some("(") =
@@ -317,7 +317,7 @@ impl Parser<'_> {
             let (mut logical, mut converted) = self
                 .tokenizer
                 .next()
-                .ok_or_else(|| general_err!("Expected logical or converted type, found None"))
+                .ok_or_else(|| schema_err!("Expected logical or converted type, found None"))
                 .and_then(|v| {
                     let upper = v.to_uppercase();
                     let logical = upper.parse::<LogicalType>();
@@ -418,7 +418,7 @@ impl Parser<'_> {
                                 PhysicalType::INT32 => match bit_width {
                                     8 | 16 | 32 => {}
                                     _ => {
-                                        return Err(general_err!(
+                                        return Err(schema_err!(
                                             "Incorrect bit width {} for INT32",
                                             bit_width
                                         ));
@@ -426,14 +426,14 @@ impl Parser<'_> {
                                 },
                                 PhysicalType::INT64 => {
                                     if bit_width != 64 {
-                                        return Err(general_err!(
+                                        return Err(schema_err!(
                                             "Incorrect bit width {} for INT64",
                                             bit_width
                                         ));
                                     }
                                 }
                                 _ => {
-                                    return Err(general_err!(
+                                    return Err(schema_err!(
                                         "Logical type Integer cannot be used with physical type {}",
                                         physical_type
                                     ));
@@ -323,7 +323,7 @@ impl<'a> PrimitiveTypeBuilder<'a> {
 
         // Check length before logical type, since it is used for logical type validation.
         if self.physical_type == PhysicalType::FIXED_LEN_BYTE_ARRAY && self.length < 0 {
-            return Err(general_err!(
+            return Err(schema_err!(
                 "Invalid FIXED_LEN_BYTE_ARRAY length: {} for field '{}'",
                 self.length,
                 self.name
@@ -335,7 +335,7 @@ impl<'a> PrimitiveTypeBuilder<'a> {
             // its logical type
             if self.converted_type != ConvertedType::NONE {
                 if ConvertedType::from(self.logical_type.clone()) != self.converted_type {
-                    return Err(general_err!(
+                    return Err(schema_err!(
                         "Logical type {:?} is incompatible with converted type {} for field '{}'",
                         logical_type,
                         self.converted_type,
@@ -349,7 +349,7 @@ impl<'a> PrimitiveTypeBuilder<'a> {
             // Check that logical type and physical type are compatible
             match (logical_type, self.physical_type) {
                 (LogicalType::Map, _) | (LogicalType::List, _) => {
-                    return Err(general_err!(
+                    return Err(schema_err!(
                         "{:?} cannot be applied to a primitive type for field '{}'",
                         logical_type,
                         self.name
@@ -359,7 +359,7 @@ impl<'a> PrimitiveTypeBuilder<'a> {
                 (LogicalType::Decimal { scale, precision }, _) => {
                     // Check that scale and precision are consistent with legacy values
                     if *scale != self.scale {
-                        return Err(general_err!(
+                        return Err(schema_err!(
                             "DECIMAL logical type scale {} must match self.scale {} for field '{}'",
                             scale,
                             self.scale,
@@ -367,7 +367,7 @@ impl<'a> PrimitiveTypeBuilder<'a> {
                         ));
                     }
                     if *precision != self.precision {
-                        return Err(general_err!(
+                        return Err(schema_err!(
                             "DECIMAL logical type precision {} must match self.precision {} for field '{}'",
                             precision,
                             self.precision,
@@ -386,7 +386,7 @@ impl<'a> PrimitiveTypeBuilder<'a> {
                 ) => {}
                 (LogicalType::Time { unit, .. }, PhysicalType::INT64) => {
                     if *unit == TimeUnit::MILLIS {
-                        return Err(general_err!(
+                        return Err(schema_err!(
                             "Cannot use millisecond unit on INT64 type for field '{}'",
                             self.name
                         ));
@@ -406,14 +406,14 @@ impl<'a> PrimitiveTypeBuilder<'a> {
                 (LogicalType::Geography { .. }, PhysicalType::BYTE_ARRAY) => {}
                 (LogicalType::Uuid, PhysicalType::FIXED_LEN_BYTE_ARRAY) if self.length == 16 => {}
                 (LogicalType::Uuid, PhysicalType::FIXED_LEN_BYTE_ARRAY) => {
-                    return Err(general_err!(
+                    return Err(schema_err!(
                         "UUID cannot annotate field '{}' because it is not a FIXED_LEN_BYTE_ARRAY(16) field",
                         self.name
                     ));
                 }
                 (LogicalType::Float16, PhysicalType::FIXED_LEN_BYTE_ARRAY) if self.length == 2 => {}
                 (LogicalType::Float16, PhysicalType::FIXED_LEN_BYTE_ARRAY) => {
-                    return Err(general_err!(
+                    return Err(schema_err!(
                         "FLOAT16 cannot annotate field '{}' because it is not a FIXED_LEN_BYTE_ARRAY(2) field",
                         self.name
                     ));
@@ -421,7 +421,7 @@ impl<'a> PrimitiveTypeBuilder<'a> {
                 // unknown logical type means just use physical type
                 (LogicalType::_Unknown { .. }, _) => {}
                 (a, b) => {
-                    return Err(general_err!(
+                    return Err(schema_err!(
                         "Cannot annotate {:?} from {} for field '{}'",
                         a,
                         b,
@@ -435,7 +435,7 @@ impl<'a> PrimitiveTypeBuilder<'a> {
             ConvertedType::NONE => {}
             ConvertedType::UTF8 | ConvertedType::BSON | ConvertedType::JSON => {
                 if self.physical_type != PhysicalType::BYTE_ARRAY {
-                    return Err(general_err!(
+                    return Err(schema_err!(
                         "{} cannot annotate field '{}' because it is not a BYTE_ARRAY field",
                         self.converted_type,
                         self.name
@@ -454,7 +454,7 @@ impl<'a> PrimitiveTypeBuilder<'a> {
             | ConvertedType::INT_16
             | ConvertedType::INT_32 => {
                 if self.physical_type != PhysicalType::INT32 {
-                    return Err(general_err!(
+                    return Err(schema_err!(
                         "{} cannot annotate field '{}' because it is not a INT32 field",
                         self.converted_type,
                         self.name
@@ -467,7 +467,7 @@ impl<'a> PrimitiveTypeBuilder<'a> {
             | ConvertedType::UINT_64
             | ConvertedType::INT_64 => {
                 if self.physical_type != PhysicalType::INT64 {
-                    return Err(general_err!(
+                    return Err(schema_err!(
                         "{} cannot annotate field '{}' because it is not a INT64 field",
                         self.converted_type,
                         self.name
@@ -476,7 +476,7 @@ impl<'a> PrimitiveTypeBuilder<'a> {
             }
             ConvertedType::INTERVAL => {
                 if self.physical_type != PhysicalType::FIXED_LEN_BYTE_ARRAY || self.length != 12 {
-                    return Err(general_err!(
+                    return Err(schema_err!(
                         "INTERVAL cannot annotate field '{}' because it is not a FIXED_LEN_BYTE_ARRAY(12) field",
                         self.name
                     ));
@@ -484,14 +484,14 @@ impl<'a> PrimitiveTypeBuilder<'a> {
             }
             ConvertedType::ENUM => {
                 if self.physical_type != PhysicalType::BYTE_ARRAY {
-                    return Err(general_err!(
+                    return Err(schema_err!(
                         "ENUM cannot annotate field '{}' because it is not a BYTE_ARRAY field",
                         self.name
                     ));
                 }
             }
             _ => {
-                return Err(general_err!(
+                return Err(schema_err!(
                     "{} cannot be applied to primitive field '{}'",
                     self.converted_type,
                     self.name
@@ -516,7 +516,7 @@ impl<'a> PrimitiveTypeBuilder<'a> {
             | PhysicalType::BYTE_ARRAY
             | PhysicalType::FIXED_LEN_BYTE_ARRAY => (),
             _ => {
-                return Err(general_err!(
+                return Err(schema_err!(
                     "DECIMAL can only annotate INT32, INT64, BYTE_ARRAY and FIXED_LEN_BYTE_ARRAY"
                 ));
             }
@@ -524,19 +524,16 @@ impl<'a> PrimitiveTypeBuilder<'a> {
 
         // Precision is required and must be a non-zero positive integer.
         if self.precision < 1 {
-            return Err(general_err!(
-                "Invalid DECIMAL precision: {}",
-                self.precision
-            ));
+            return Err(schema_err!("Invalid DECIMAL precision: {}", self.precision));
         }
 
         // Scale must be zero or a positive integer less than the precision.
         if self.scale < 0 {
-            return Err(general_err!("Invalid DECIMAL scale: {}", self.scale));
+            return Err(schema_err!("Invalid DECIMAL scale: {}", self.scale));
         }
 
         if self.scale > self.precision {
-            return Err(general_err!(
+            return Err(schema_err!(
                 "Invalid DECIMAL: scale ({}) cannot be greater than precision \
              ({})",
                 self.scale,
@@ -548,7 +545,7 @@ impl<'a> PrimitiveTypeBuilder<'a> {
         match self.physical_type {
             PhysicalType::INT32 => {
                 if self.precision > 9 {
-                    return Err(general_err!(
+                    return Err(schema_err!(
                         "Cannot represent INT32 as DECIMAL with precision {}",
                         self.precision
                     ));
@@ -556,7 +553,7 @@ impl<'a> PrimitiveTypeBuilder<'a> {
             }
             PhysicalType::INT64 => {
                 if self.precision > 18 {
-                    return Err(general_err!(
+                    return Err(schema_err!(
                         "Cannot represent INT64 as DECIMAL with precision {}",
                         self.precision
                     ));
@@ -566,11 +563,11 @@ impl<'a> PrimitiveTypeBuilder<'a> {
                 let length = self
                     .length
                     .checked_mul(8)
-                    .ok_or(general_err!("Invalid length {} for Decimal", self.length))?;
+                    .ok_or(schema_err!("Invalid length {} for Decimal", self.length))?;
                 let max_precision = (2f64.powi(length - 1) - 1f64).log10().floor() as i32;
 
                 if self.precision > max_precision {
-                    return Err(general_err!(
+                    return Err(schema_err!(
                         "Cannot represent FIXED_LEN_BYTE_ARRAY as DECIMAL with length {} and \
                         precision {}. The max precision can only be {}",
                         self.length,
@@ -1179,7 +1176,7 @@ impl SchemaDescriptor {
 // walk tree and count nodes
 pub(crate) fn num_nodes(tp: &TypePtr) -> Result<usize> {
     if !tp.is_group() {
-        return Err(general_err!("Root schema must be Group type"));
+        return Err(schema_err!("Root schema must be Group type"));
     }
     let mut n_nodes = 1usize; // count root
     for f in tp.get_fields().iter() {
@@ -1200,7 +1197,7 @@ pub(crate) fn count_nodes(tp: &TypePtr, n_nodes: &mut usize) {
 // do a quick walk of the tree to get proper sizing for SchemaDescriptor arrays
 fn num_leaves(tp: &TypePtr) -> Result<usize> {
     if !tp.is_group() {
-        return Err(general_err!("Root schema must be Group type"));
+        return Err(schema_err!("Root schema must be Group type"));
     }
     let mut n_leaves = 0usize;
     for f in tp.get_fields().iter() {
@@ -1282,7 +1279,7 @@ fn build_tree<'a>(
 fn check_logical_type(logical_type: &Option<LogicalType>) -> Result<()> {
     if let Some(LogicalType::Integer { bit_width, .. }) = *logical_type {
         if bit_width != 8 && bit_width != 16 && bit_width != 32 && bit_width != 64 {
-            return Err(general_err!(
+            return Err(schema_err!(
                 "Bit width must be 8, 16, 32, or 64 for Integer logical type"
             ));
         }
@@ -1306,14 +1303,14 @@ pub(crate) fn parquet_schema_from_array<'a>(elements: Vec<SchemaElement<'a>>) ->
         schema_nodes.push(t.1);
     }
     if schema_nodes.len() != 1 {
-        return Err(general_err!(
+        return Err(schema_err!(
             "Expected exactly one root node, but found {}",
             schema_nodes.len()
         ));
     }
 
     if !schema_nodes[0].is_group() {
-        return Err(general_err!("Expected root node to be a group type"));
+        return Err(schema_err!("Expected root node to be a group type"));
     }
 
     Ok(schema_nodes.remove(0))
@@ -1330,7 +1327,7 @@ fn schema_from_array_helper<'a>(
     let is_root_node = index == 0;
 
     if index >= num_elements {
-        return Err(general_err!(
+        return Err(schema_err!(
             "Index out of bound, index = {}, len = {}",
             index,
             num_elements
@@ -1361,7 +1358,7 @@ fn schema_from_array_helper<'a>(
         None | Some(0) => {
             // primitive type
             if element.repetition_type.is_none() {
-                return Err(general_err!(
+                return Err(schema_err!(
                     "Repetition level must be defined for a primitive type"
                 ));
             }
@@ -1424,7 +1421,7 @@ fn schema_from_array_helper<'a>(
             //   All other types must have one.
             if !is_root_node {
                 let Some(rep) = repetition else {
-                    return Err(general_err!(
+                    return Err(schema_err!(
                         "Repetition level must be defined for non-root types"
                     ));
                 };
@@ -28,7 +28,7 @@ fn array_from_slice<const N: usize>(bs: &[u8]) -> Result<[u8; N]> {
     // Need to slice as may be called with zero-padded values
     match bs.get(..N) {
         Some(b) => Ok(b.try_into().unwrap()),
-        None => Err(general_err!(
+        None => Err(corrupt_err!(
             "error converting value, expected {} bytes got {}",
             N,
             bs.len()
@@ -38,7 +38,7 @@ pub trait Storage {
 }
 
 /// A generic value interner supporting various different [`Storage`]
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Interner<S: Storage> {
     state: ahash::RandomState,
 
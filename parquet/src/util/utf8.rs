@@ -46,12 +46,12 @@ pub fn check_valid_utf8(val: &[u8]) -> Result<()> {
         Err(_) => {
             // Use simdutf8::compat to return details about the decoding error
             let e = simdutf8::compat::from_utf8(val).unwrap_err();
-            Err(general_err!("encountered non UTF-8 data: {}", e))
+            Err(corrupt_err!("encountered non UTF-8 data: {}", e))
         }
     }
     #[cfg(not(feature = "simdutf8"))]
     match std::str::from_utf8(val) {
         Ok(_) => Ok(()),
-        Err(e) => Err(general_err!("encountered non UTF-8 data: {}", e)),
+        Err(e) => Err(corrupt_err!("encountered non UTF-8 data: {}", e)),
     }
 }
@@ -19,7 +19,7 @@
 
 use arrow::util::test_util::parquet_test_data;
 use parquet::arrow::arrow_reader::ArrowReaderBuilder;
-use parquet::errors::ParquetError;
+use parquet::errors::{ErrorKind, ParquetError};
 use std::collections::HashSet;
 use std::path::PathBuf;
 
@@ -90,6 +90,9 @@ fn test_arrow_gh_41321() {
         err.to_string(),
         "External: Parquet argument error: Parquet error: Invalid or corrupted RLE bit width 254. Max allowed is 32"
     );
+    let err = read_file_error("ARROW-GH-41321.parquet");
+    assert_eq!(err.kind(), ErrorKind::Corrupt);
+    assert!(err.context().unwrap().column.is_some());
 }
 
 #[test]
@@ -99,6 +102,8 @@ fn test_arrow_gh_41317() {
         err.to_string(),
         "External: Parquet argument error: Parquet error: StructArrayReader out of sync in read_records, expected 5 read, got 2"
     );
+    let err = read_file_error("ARROW-GH-41317.parquet");
+    assert_eq!(err.kind(), ErrorKind::Corrupt);
 }
 
 #[test]
@@ -108,6 +113,9 @@ fn test_arrow_rs_gh_6229_dict_header() {
         err.to_string(),
         "External: Parquet argument error: Parquet error: Integer overflow: out of range integral type conversion attempted"
     );
+    let err = read_file_error("ARROW-RS-GH-6229-DICTHEADER.parquet");
+    assert_eq!(err.kind(), ErrorKind::Corrupt);
+    assert!(err.context().unwrap().column.is_some());
 }
 
 #[test]
@@ -118,6 +126,9 @@ fn test_arrow_rs_gh_6229_dict_levels() {
         err.to_string(),
         "External: Parquet argument error: Parquet error: Insufficient repetition levels read from column"
     );
+    let err = read_file_error("ARROW-RS-GH-6229-LEVELS.parquet");
+    assert_eq!(err.kind(), ErrorKind::Corrupt);
+    assert!(err.context().unwrap().column.is_some());
 }
 
 #[test]
@@ -128,6 +139,8 @@ fn test_arrow_rs_gh_45185_dict_levels() {
         err.to_string(),
         "External: Parquet argument error: Parquet error: first repetition level of batch must be 0"
     );
+    let err = read_file_error("ARROW-GH-45185.parquet");
+    assert_eq!(err.kind(), ErrorKind::Corrupt);
 }
 
 /// Reads the file and tries to return the total row count
@@ -147,6 +160,19 @@ fn read_file(name: &str) -> Result<usize, ParquetError> {
     Ok(num_rows)
 }
 
+/// Reads the file with `ParquetRecordBatchReader::try_next`, returning the first error
+fn read_file_error(name: &str) -> ParquetError {
+    let file = std::fs::File::open(bad_data_dir().join(name)).unwrap();
+    let mut reader = ArrowReaderBuilder::try_new(file).unwrap().build().unwrap();
+    loop {
+        match reader.try_next() {
+            Ok(Some(_)) => {}
+            Ok(None) => panic!("expected an error reading {name}"),
+            Err(e) => return e,
+        }
+    }
+}
+
 #[cfg(feature = "async")]
 #[tokio::test]
 #[allow(deprecated)]
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use super::{make_bloom_filter_file, without_bloom_filter_length};
 use crate::io::TestReader;
 use bytes::Bytes;
 use futures::TryStreamExt;
@@ -59,6 +60,27 @@ async fn test_get_row_group_column_bloom_filter_with_length() {
     test_get_row_group_column_bloom_filter(parquet_data.into(), true).await;
 }
 
+#[tokio::test]
+async fn test_get_row_group_column_bloom_filter_inferred_length() {
+    let data = make_bloom_filter_file();
+    let builder = ParquetRecordBatchStreamBuilder::new(TestReader::new(data.clone()))
+        .await
+        .unwrap();
+    let metadata = without_bloom_filter_length(builder.metadata());
+    let column = metadata.metadata().row_group(0).column(0);
+    assert!(column.bloom_filter_length().is_none());
+
+    let mut builder =
+        ParquetRecordBatchStreamBuilder::new_with_metadata(TestReader::new(data), metadata);
+    let sbbf = builder
+        .get_row_group_column_bloom_filter(0, 0)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(sbbf.check(&"Hello"));
+    assert!(!sbbf.check(&"Hello_Not_Exists"));
+}
+
 async fn test_get_row_group_column_bloom_filter(data: Bytes, with_length: bool) {
     let async_reader = TestReader::new(data.clone());
 
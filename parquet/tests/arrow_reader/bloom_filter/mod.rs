@@ -18,3 +18,48 @@
 #[cfg(feature = "async")]
 mod r#async;
 mod sync;
+
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, RecordBatch, StringArray};
+use bytes::Bytes;
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions};
+use parquet::file::metadata::ParquetMetaData;
+use parquet::file::properties::WriterProperties;
+
+/// Writes a parquet file with a bloom filter on its single column
+fn make_bloom_filter_file() -> Bytes {
+    let values: ArrayRef = Arc::new(StringArray::from(vec!["Hello", "World"]));
+    let batch = RecordBatch::try_from_iter([("s", values)]).unwrap();
+    let props = WriterProperties::builder()
+        .set_bloom_filter_enabled(true)
+        .build();
+    let mut data = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut data, batch.schema(), Some(props)).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+    data.into()
+}
+
+/// Returns `metadata` with `bloom_filter_length` removed from each column chunk, as
+/// written by writers predating its addition to the format
+fn without_bloom_filter_length(metadata: &ParquetMetaData) -> ArrowReaderMetadata {
+    let mut builder = metadata.clone().into_builder();
+    let row_groups = builder
+        .take_row_groups()
+        .into_iter()
+        .map(|row_group| {
+            let mut row_group = row_group.into_builder();
+            let columns = row_group
+                .take_columns()
+                .into_iter()
+                .map(|c| c.into_builder().set_bloom_filter_length(None).build())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            row_group.set_column_metadata(columns).build().unwrap()
+        })
+        .collect();
+    let metadata = builder.set_row_groups(row_groups).build();
+    ArrowReaderMetadata::try_new(Arc::new(metadata), ArrowReaderOptions::default()).unwrap()
+}
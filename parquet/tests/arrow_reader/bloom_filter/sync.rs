@@ -16,11 +16,25 @@
 // under the License.
 
 use std::fs::File;
+use std::sync::Arc;
 
+use super::{make_bloom_filter_file, without_bloom_filter_length};
+use arrow::array::{ArrayRef, AsArray, Int32Array, RecordBatch, StringArray};
+use arrow::datatypes::Int32Type;
 use bytes::Bytes;
 use parquet::{
-    arrow::{ArrowWriter, arrow_reader::ParquetRecordBatchReaderBuilder},
-    file::properties::WriterProperties,
+    arrow::{
+        ArrowWriter,
+        arrow_reader::{
+            BloomFilterPredicate, ParquetRecordBatchReaderBuilder, RowSelection, RowSelector,
+        },
+    },
+    bloom_filter::Sbbf,
+    file::{
+        properties::{ReaderProperties, WriterProperties},
+        reader::RowGroupReader,
+        serialized_reader::SerializedRowGroupReader,
+    },
 };
 
 #[test]
@@ -56,6 +70,146 @@ fn test_get_row_group_column_bloom_filter_without_length() {
     test_get_row_group_column_bloom_filter(data, false);
 }
 
+#[test]
+fn test_get_row_group_column_bloom_filter_inferred_length() {
+    let data = make_bloom_filter_file();
+    let metadata = ParquetRecordBatchReaderBuilder::try_new(data.clone())
+        .unwrap()
+        .metadata()
+        .clone();
+    let metadata = without_bloom_filter_length(&metadata);
+    let column = metadata.metadata().row_group(0).column(0);
+    assert!(column.bloom_filter_offset().is_some());
+    assert!(column.bloom_filter_length().is_none());
+
+    // The length is inferred from the bloom filter header
+    let sbbf = Sbbf::read_from_column_chunk(column, &data)
+        .unwrap()
+        .unwrap();
+    assert!(sbbf.check(&"Hello"));
+    assert!(!sbbf.check(&"Hello_Not_Exists"));
+
+    let builder = ParquetRecordBatchReaderBuilder::new_with_metadata(data, metadata);
+    let sbbf = builder
+        .get_row_group_column_bloom_filter(0, 0)
+        .unwrap()
+        .unwrap();
+    assert!(sbbf.check(&"World"));
+    assert!(!sbbf.check(&"Hello_Not_Exists"));
+}
+
+#[test]
+fn test_get_column_bloom_filter_inferred_length() {
+    let data = make_bloom_filter_file();
+    let metadata = ParquetRecordBatchReaderBuilder::try_new(data.clone())
+        .unwrap()
+        .metadata()
+        .clone();
+    let metadata = without_bloom_filter_length(&metadata);
+    let props = ReaderProperties::builder()
+        .set_read_bloom_filter(true)
+        .build();
+    let row_group = SerializedRowGroupReader::new(
+        Arc::new(data),
+        metadata.metadata().row_group(0),
+        None,
+        Arc::new(props),
+    )
+    .unwrap();
+    let sbbf = row_group.get_column_bloom_filter(0).unwrap();
+    assert!(sbbf.check(&"Hello"));
+    assert!(!sbbf.check(&"Hello_Not_Exists"));
+}
+
+#[test]
+fn test_with_bloom_filter_predicates() {
+    // 4 row groups of 10 rows, with a bloom filter on column "a" only
+    let a = Int32Array::from_iter_values(0..40);
+    let b = StringArray::from_iter_values((0..40).map(|i| format!("b{}", i / 10)));
+    let batch = RecordBatch::try_from_iter([
+        ("a", Arc::new(a) as ArrayRef),
+        ("b", Arc::new(b) as ArrayRef),
+    ])
+    .unwrap();
+    let props = WriterProperties::builder()
+        .set_max_row_group_row_count(Some(10))
+        .set_column_bloom_filter_enabled("a".into(), true)
+        .build();
+    let mut data = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut data, batch.schema(), Some(props)).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+    let data = Bytes::from(data);
+
+    let read = |predicates: &[BloomFilterPredicate], row_groups: Option<Vec<usize>>| {
+        let mut builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        if let Some(row_groups) = row_groups {
+            builder = builder.with_row_groups(row_groups);
+        }
+        let reader = builder
+            .with_bloom_filter_predicates(predicates)
+            .unwrap()
+            .build()
+            .unwrap();
+        reader
+            .flat_map(|b| {
+                let b = b.unwrap();
+                b.column(0).as_primitive::<Int32Type>().values().to_vec()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // Row groups whose bloom filter does not contain any of the values are skipped
+    let rows = read(&[BloomFilterPredicate::new(0, &[5_i32, 25])], None);
+    assert_eq!(rows, (0..10).chain(20..30).collect::<Vec<_>>());
+
+    // Values absent from every row group
+    let rows = read(&[BloomFilterPredicate::new(0, &[100_i32])], None);
+    assert!(rows.is_empty());
+
+    // All predicates must be satisfied
+    let predicates = [
+        BloomFilterPredicate::new(0, &[5_i32, 25]),
+        BloomFilterPredicate::new(0, &[25_i32, 35]),
+    ];
+    assert_eq!(read(&predicates, None), (20..30).collect::<Vec<_>>());
+
+    // Only the selected row groups are considered
+    let rows = read(
+        &[BloomFilterPredicate::new(0, &[5_i32, 25])],
+        Some(vec![2, 3]),
+    );
+    assert_eq!(rows, (20..30).collect::<Vec<_>>());
+
+    // Row groups without a bloom filter for the column are not pruned
+    let rows = read(&[BloomFilterPredicate::new(1, ["b9"])], None);
+    assert_eq!(rows, (0..40).collect::<Vec<_>>());
+
+    // Invalid column index
+    let err = ParquetRecordBatchReaderBuilder::try_new(data.clone())
+        .unwrap()
+        .with_bloom_filter_predicates(&[BloomFilterPredicate::new(2, &[1_i32])])
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parquet error: Bloom filter predicate column index 2 out of bounds for 2 columns"
+    );
+
+    // Must be applied before a row selection
+    let err = ParquetRecordBatchReaderBuilder::try_new(data)
+        .unwrap()
+        .with_row_selection(RowSelection::from(vec![
+            RowSelector::select(5),
+            RowSelector::skip(35),
+        ]))
+        .with_bloom_filter_predicates(&[BloomFilterPredicate::new(0, &[1_i32])])
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parquet error: Bloom filter predicates must be applied before a row selection"
+    );
+}
+
 fn test_get_row_group_column_bloom_filter(data: Bytes, with_length: bool) {
     let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
 
@@ -30,6 +30,7 @@ use parquet::{
         arrow_reader::{ArrowReaderBuilder, ParquetRecordBatchReader},
     },
     basic::Encoding,
+    errors::ErrorKind,
     file::properties::WriterProperties,
 };
 
@@ -142,6 +143,31 @@ fn test_invalid_utf8_string_view_array() {
     }
 }
 
+#[test]
+fn test_invalid_utf8_error_context() {
+    let array = invalid_utf8_first_char::<i32>();
+    let array = unsafe {
+        GenericStringArray::<i32>::new_unchecked(
+            array.offsets().clone(),
+            array.values().clone(),
+            array.nulls().cloned(),
+        )
+    };
+    let data = write_to_parquet_with_encoding(Arc::new(array), Some(Encoding::PLAIN));
+    let mut reader = ArrowReaderBuilder::try_new(Bytes::from(data))
+        .unwrap()
+        .build()
+        .unwrap();
+
+    // The kind and location of the error are available from `try_next`
+    let err = reader.try_next().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Corrupt);
+    let context = err.context().unwrap();
+    assert_eq!(context.column.as_ref().unwrap().string(), "c");
+    assert_eq!(context.row_group, Some(0));
+    assert_eq!(err.to_string(), "Parquet error: encountered non UTF-8 data");
+}
+
 /// Encodings suitable for string data
 const STRING_ENCODINGS: &[Option<Encoding>] = &[
     None,
@@ -21,10 +21,13 @@ use crate::io::{
     LogEntry, OperationLog, TestParquetFile, filter_a_175_b_625, filter_b_575_625, filter_b_false,
     test_file, test_options,
 };
+use arrow_array::{ArrayRef, Int64Array};
 use bytes::Bytes;
 use futures::future::BoxFuture;
 use futures::{FutureExt, StreamExt};
-use parquet::arrow::arrow_reader::{ArrowReaderOptions, RowSelection, RowSelector};
+use parquet::arrow::arrow_reader::{
+    ArrowReaderOptions, RowSelection, RowSelector, StatisticsPredicate,
+};
 use parquet::arrow::async_reader::AsyncFileReader;
 use parquet::arrow::{ParquetRecordBatchStreamBuilder, ProjectionMask};
 use parquet::errors::Result;
@@ -86,7 +89,8 @@ async fn test_read_single_column() {
     let test_file = test_file();
     let builder = async_builder(&test_file, test_options()).await;
     let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
-    let builder = builder.with_projection(ProjectionMask::columns(&schema_descr, ["b"]));
+    let builder =
+        builder.with_projection(ProjectionMask::try_columns(&schema_descr, ["b"]).unwrap());
     // Expect to see only IO for column "b". Should see no IO for columns "a" or "c".
     insta::assert_debug_snapshot!(run(
         &test_file,
@@ -111,7 +115,7 @@ async fn test_read_row_selection() {
     let builder = async_builder(&test_file, test_options()).await;
     let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
     let builder = builder
-        .with_projection(ProjectionMask::columns(&schema_descr, ["a", "b"]))
+        .with_projection(ProjectionMask::try_columns(&schema_descr, ["a", "b"]).unwrap())
         .with_row_selection(RowSelection::from(vec![
             RowSelector::skip(175),
             RowSelector::select(50),
@@ -139,6 +143,42 @@ async fn test_read_row_selection() {
     "#);
 }
 
+#[tokio::test]
+async fn test_read_page_index_predicates() {
+    // There are 400 total rows spread across 4 data pages (100 rows each)
+    // "a" is between 150 and 249 only in DataPage(1) of row group 0 and DataPage(0) of row group 1
+    let test_file = test_file();
+    let builder = async_builder(&test_file, test_options()).await;
+    let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+    let low = Arc::new(Int64Array::from(vec![150])) as ArrayRef;
+    let high = Arc::new(Int64Array::from(vec![249])) as ArrayRef;
+    let builder = builder
+        .with_projection(ProjectionMask::try_columns(&schema_descr, ["a", "b"]).unwrap())
+        .with_page_index_predicates(&[StatisticsPredicate::between("a", low, high)])
+        .unwrap();
+
+    // Expect to see only data IO for one page for each column for each row group
+    insta::assert_debug_snapshot!(run(
+        &test_file,
+        builder).await, @r#"
+    [
+        "Get Provided Metadata",
+        "Event: Builder Configured",
+        "Event: Reader Built",
+        "Read Multi:",
+        "  Row Group 0, column 'a': DictionaryPage   (1617 bytes, 1 requests) [data]",
+        "  Row Group 0, column 'a': DataPage(1)      (126 bytes , 1 requests) [data]",
+        "  Row Group 0, column 'b': DictionaryPage   (1617 bytes, 1 requests) [data]",
+        "  Row Group 0, column 'b': DataPage(1)      (126 bytes , 1 requests) [data]",
+        "Read Multi:",
+        "  Row Group 1, column 'a': DictionaryPage   (1617 bytes, 1 requests) [data]",
+        "  Row Group 1, column 'a': DataPage(0)      (113 bytes , 1 requests) [data]",
+        "  Row Group 1, column 'b': DictionaryPage   (1617 bytes, 1 requests) [data]",
+        "  Row Group 1, column 'b': DataPage(0)      (113 bytes , 1 requests) [data]",
+    ]
+    "#);
+}
+
 #[tokio::test]
 async fn test_read_limit() {
     // There are 400 total rows spread across 4 data pages (100 rows each)
@@ -147,7 +187,7 @@ async fn test_read_limit() {
     let builder = async_builder(&test_file, test_options()).await;
     let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
     let builder = builder
-        .with_projection(ProjectionMask::columns(&schema_descr, ["a"]))
+        .with_projection(ProjectionMask::try_columns(&schema_descr, ["a"]).unwrap())
         .with_limit(125);
 
     insta::assert_debug_snapshot!(run(
@@ -175,7 +215,7 @@ async fn test_read_single_row_filter() {
     let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
 
     let builder = builder
-        .with_projection(ProjectionMask::columns(&schema_descr, ["a", "b"]))
+        .with_projection(ProjectionMask::try_columns(&schema_descr, ["a", "b"]).unwrap())
         .with_row_filter(filter_b_575_625(&schema_descr));
 
     // Expect to see I/O for column b in both row groups to evaluate filter,
@@ -212,7 +252,7 @@ async fn test_read_single_row_filter_no_page_index() {
     let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
 
     let builder = builder
-        .with_projection(ProjectionMask::columns(&schema_descr, ["a", "b"]))
+        .with_projection(ProjectionMask::try_columns(&schema_descr, ["a", "b"]).unwrap())
         .with_row_filter(filter_b_575_625(&schema_descr));
 
     // Since we don't have the page index, expect to see:
@@ -250,7 +290,7 @@ async fn test_read_multiple_row_filter() {
     let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
 
     let builder = builder
-        .with_projection(ProjectionMask::columns(&schema_descr, ["c"]))
+        .with_projection(ProjectionMask::try_columns(&schema_descr, ["c"]).unwrap())
         .with_row_filter(filter_a_175_b_625(&schema_descr));
 
     // Expect that we will see
@@ -292,7 +332,7 @@ async fn test_read_single_row_filter_all() {
     let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
 
     let builder = builder
-        .with_projection(ProjectionMask::columns(&schema_descr, ["a", "b"]))
+        .with_projection(ProjectionMask::try_columns(&schema_descr, ["a", "b"]).unwrap())
         .with_row_filter(filter_b_false(&schema_descr));
 
     // Expect to see reads for column "b" to evaluate the filter, but no reads
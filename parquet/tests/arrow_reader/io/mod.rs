@@ -147,7 +147,7 @@ impl AsyncFileReader for TestReader {
 fn filter_b_575_625(schema_descr: &SchemaDescriptor) -> RowFilter {
     // "b" > 575 and "b" < 625
     let predicate = ArrowPredicateFn::new(
-        ProjectionMask::columns(schema_descr, ["b"]),
+        ProjectionMask::try_columns(schema_descr, ["b"]).unwrap(),
         |batch: RecordBatch| {
             let scalar_575 = Int64Array::new_scalar(575);
             let scalar_625 = Int64Array::new_scalar(625);
@@ -164,7 +164,7 @@ fn filter_b_575_625(schema_descr: &SchemaDescriptor) -> RowFilter {
 fn filter_a_175_b_625(schema_descr: &SchemaDescriptor) -> RowFilter {
     // "a" > 175 and "b" < 625
     let predicate_a = ArrowPredicateFn::new(
-        ProjectionMask::columns(schema_descr, ["a"]),
+        ProjectionMask::try_columns(schema_descr, ["a"]).unwrap(),
         |batch: RecordBatch| {
             let scalar_175 = Int64Array::new_scalar(175);
             let column = batch.column(0).as_primitive::<Int64Type>();
@@ -173,7 +173,7 @@ fn filter_a_175_b_625(schema_descr: &SchemaDescriptor) -> RowFilter {
     );
 
     let predicate_b = ArrowPredicateFn::new(
-        ProjectionMask::columns(schema_descr, ["b"]),
+        ProjectionMask::try_columns(schema_descr, ["b"]).unwrap(),
         |batch: RecordBatch| {
             let scalar_625 = Int64Array::new_scalar(625);
             let column = batch.column(0).as_primitive::<Int64Type>();
@@ -190,7 +190,7 @@ fn filter_a_175_b_625(schema_descr: &SchemaDescriptor) -> RowFilter {
 fn filter_b_false(schema_descr: &SchemaDescriptor) -> RowFilter {
     // "false"
     let predicate = ArrowPredicateFn::new(
-        ProjectionMask::columns(schema_descr, ["b"]),
+        ProjectionMask::try_columns(schema_descr, ["b"]).unwrap(),
         |batch: RecordBatch| {
             let result =
                 BooleanArray::from_iter(std::iter::repeat_n(Some(false), batch.num_rows()));
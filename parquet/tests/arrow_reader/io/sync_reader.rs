@@ -22,10 +22,12 @@ use crate::io::{
     test_file, test_options,
 };
 
+use arrow_array::{ArrayRef, Int64Array};
 use bytes::Bytes;
 use parquet::arrow::ProjectionMask;
 use parquet::arrow::arrow_reader::{
     ArrowReaderOptions, ParquetRecordBatchReaderBuilder, RowSelection, RowSelector,
+    StatisticsPredicate,
 };
 use parquet::file::metadata::PageIndexPolicy;
 use parquet::file::reader::{ChunkReader, Length};
@@ -100,7 +102,8 @@ fn test_read_single_column() {
     let test_file = test_file();
     let builder = sync_builder(&test_file, test_options());
     let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
-    let builder = builder.with_projection(ProjectionMask::columns(&schema_descr, ["b"]));
+    let builder =
+        builder.with_projection(ProjectionMask::try_columns(&schema_descr, ["b"]).unwrap());
     // Expect to see only IO for column "b". Should see no IO for columns "a" or "c".
     insta::assert_debug_snapshot!(run(&test_file, builder),
         @r#"
@@ -126,7 +129,8 @@ fn test_read_single_column_no_page_index() {
     let options = test_options().with_page_index_policy(PageIndexPolicy::from(false));
     let builder = sync_builder(&test_file, options);
     let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
-    let builder = builder.with_projection(ProjectionMask::columns(&schema_descr, ["b"]));
+    let builder =
+        builder.with_projection(ProjectionMask::try_columns(&schema_descr, ["b"]).unwrap());
     // Expect to see only IO for column "b", should see no IO for columns "a" or "c".
     //
     // Note that we need to read all data page headers to find the pages for column b
@@ -164,7 +168,7 @@ fn test_read_row_selection() {
     let builder = builder
         .with_projection(
             // read both "a" and "b"
-            ProjectionMask::columns(&schema_descr, ["a", "b"]),
+            ProjectionMask::try_columns(&schema_descr, ["a", "b"]).unwrap(),
         )
         .with_row_selection(RowSelection::from(vec![
             RowSelector::skip(175),
@@ -193,6 +197,41 @@ fn test_read_row_selection() {
     "#);
 }
 
+#[test]
+fn test_read_page_index_predicates() {
+    // There are 400 total rows spread across 4 data pages (100 rows each)
+    // "a" is between 150 and 249 only in DataPage(1) of row group 0 and DataPage(0) of row group 1
+    let test_file = test_file();
+    let builder = sync_builder(&test_file, test_options());
+    let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
+    let low = Arc::new(Int64Array::from(vec![150])) as ArrayRef;
+    let high = Arc::new(Int64Array::from(vec![249])) as ArrayRef;
+    let builder = builder
+        .with_projection(ProjectionMask::try_columns(&schema_descr, ["a", "b"]).unwrap())
+        .with_page_index_predicates(&[StatisticsPredicate::between("a", low, high)])
+        .unwrap();
+
+    // Expect to see only data IO for one page for each column for each row group
+    insta::assert_debug_snapshot!(run(&test_file, builder),
+        @r#"
+    [
+        "Footer: 8 bytes",
+        "Metadata: 1162",
+        "UNKNOWN: 22230..22877 (maybe Page Index)",
+        "Event: Builder Configured",
+        "Event: Reader Built",
+        "Row Group 0, column 'a': DictionaryPage   (1617 bytes, 1 requests) [data]",
+        "Row Group 0, column 'b': DictionaryPage   (1617 bytes, 1 requests) [data]",
+        "Row Group 0, column 'a': DataPage(1)      (126 bytes , 1 requests) [data]",
+        "Row Group 1, column 'a': DictionaryPage   (1617 bytes, 1 requests) [data]",
+        "Row Group 1, column 'a': DataPage(0)      (113 bytes , 1 requests) [data]",
+        "Row Group 0, column 'b': DataPage(1)      (126 bytes , 1 requests) [data]",
+        "Row Group 1, column 'b': DictionaryPage   (1617 bytes, 1 requests) [data]",
+        "Row Group 1, column 'b': DataPage(0)      (113 bytes , 1 requests) [data]",
+    ]
+    "#);
+}
+
 #[test]
 fn test_read_limit() {
     // There are 400 total rows spread across 4 data pages (100 rows each)
@@ -201,7 +240,7 @@ fn test_read_limit() {
     let builder = sync_builder(&test_file, test_options());
     let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
     let builder = builder
-        .with_projection(ProjectionMask::columns(&schema_descr, ["a"]))
+        .with_projection(ProjectionMask::try_columns(&schema_descr, ["a"]).unwrap())
         .with_limit(125);
 
     insta::assert_debug_snapshot!(run(&test_file, builder),
@@ -231,7 +270,7 @@ fn test_read_single_row_filter() {
     let builder = builder
         .with_projection(
             // read both "a" and "b"
-            ProjectionMask::columns(&schema_descr, ["a", "b"]),
+            ProjectionMask::try_columns(&schema_descr, ["a", "b"]).unwrap(),
         )
         // "b" > 575 and "b" < 625
         .with_row_filter(filter_b_575_625(&schema_descr));
@@ -280,7 +319,7 @@ fn test_read_multiple_row_filter() {
 
     let builder = builder
         .with_projection(
-            ProjectionMask::columns(&schema_descr, ["c"]), // read "c"
+            ProjectionMask::try_columns(&schema_descr, ["c"]).unwrap(), // read "c"
         )
         // a > 175 and b < 625
         .with_row_filter(filter_a_175_b_625(&schema_descr));
@@ -329,7 +368,7 @@ fn test_read_single_row_filter_all() {
     let schema_descr = builder.metadata().file_metadata().schema_descr_ptr();
 
     let builder = builder
-        .with_projection(ProjectionMask::columns(&schema_descr, ["a", "b"]))
+        .with_projection(ProjectionMask::try_columns(&schema_descr, ["a", "b"]).unwrap())
         .with_row_filter(filter_b_false(&schema_descr));
 
     // Expect to see the Footer and Metadata, then I/O for column b
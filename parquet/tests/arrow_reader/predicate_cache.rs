@@ -290,7 +290,7 @@ impl<T> ArrowReaderBuilderExt for ArrowReaderBuilder<T> {
 
         // "b" > 575 and "b" < 625
         let row_filter = ArrowPredicateFn::new(
-            ProjectionMask::columns(&schema_descr, ["b"]),
+            ProjectionMask::try_columns(&schema_descr, ["b"]).unwrap(),
             |batch: RecordBatch| {
                 let scalar_575 = Int64Array::new_scalar(575);
                 let scalar_625 = Int64Array::new_scalar(625);
@@ -299,7 +299,7 @@ impl<T> ArrowReaderBuilderExt for ArrowReaderBuilder<T> {
             },
         );
 
-        self.with_projection(ProjectionMask::columns(&schema_descr, ["a", "b"]))
+        self.with_projection(ProjectionMask::try_columns(&schema_descr, ["a", "b"]).unwrap())
             .with_row_filter(RowFilter::new(vec![Box::new(row_filter)]))
     }
 
@@ -2539,6 +2539,106 @@ fn missing_null_counts_as_zero() {
     );
 }
 
+#[test]
+fn legacy_fixed_len_byte_array_stats_ignored() {
+    use parquet::basic::{LogicalType, Type as PhysicalType};
+    use parquet::data_type::{ByteArray, FixedLenByteArray};
+    use parquet::schema::types::Type as ParquetType;
+
+    let arrow_schema = Arc::new(Schema::new(vec![
+        Field::new("d", DataType::Decimal128(38, 0), true),
+        Field::new("f", DataType::Float16, true),
+    ]));
+    let parquet_schema = ParquetType::group_type_builder("schema")
+        .with_fields(vec![
+            Arc::new(
+                ParquetType::primitive_type_builder("d", PhysicalType::FIXED_LEN_BYTE_ARRAY)
+                    .with_length(16)
+                    .with_logical_type(Some(LogicalType::Decimal {
+                        scale: 0,
+                        precision: 38,
+                    }))
+                    .with_precision(38)
+                    .with_scale(0)
+                    .build()
+                    .unwrap(),
+            ),
+            Arc::new(
+                ParquetType::primitive_type_builder("f", PhysicalType::FIXED_LEN_BYTE_ARRAY)
+                    .with_length(2)
+                    .with_logical_type(Some(LogicalType::Float16))
+                    .build()
+                    .unwrap(),
+            ),
+        ])
+        .build()
+        .unwrap();
+    let parquet_schema = Arc::new(SchemaDescriptor::new(Arc::new(parquet_schema)));
+
+    let flba = |bytes: &[u8]| FixedLenByteArray::from(ByteArray::from(bytes.to_vec()));
+    let row_group = |is_min_max_deprecated: bool| {
+        let decimal_stats = Statistics::FixedLenByteArray(ValueStatistics::new(
+            Some(flba(&(-1_i128).to_be_bytes())),
+            Some(flba(&1_i128.to_be_bytes())),
+            None,
+            Some(0),
+            is_min_max_deprecated,
+        ));
+        let float16_stats = Statistics::FixedLenByteArray(ValueStatistics::new(
+            Some(flba(&f16::from_f32(-1.0).to_le_bytes())),
+            Some(flba(&f16::from_f32(1.0).to_le_bytes())),
+            None,
+            Some(0),
+            is_min_max_deprecated,
+        ));
+        let columns = vec![
+            ColumnChunkMetaData::builder(parquet_schema.column(0))
+                .set_statistics(decimal_stats)
+                .build()
+                .unwrap(),
+            ColumnChunkMetaData::builder(parquet_schema.column(1))
+                .set_statistics(float16_stats)
+                .build()
+                .unwrap(),
+        ];
+        RowGroupMetaData::builder(parquet_schema.clone())
+            .set_column_metadata(columns)
+            .build()
+            .unwrap()
+    };
+    // Statistics in the deprecated min / max fields were not ordered correctly for
+    // these types, and so must be ignored
+    let row_groups = [row_group(false), row_group(true)];
+
+    let converter = StatisticsConverter::try_new("d", &arrow_schema, &parquet_schema).unwrap();
+    let expected_min = Decimal128Array::from(vec![Some(-1), None])
+        .with_precision_and_scale(38, 0)
+        .unwrap();
+    let expected_max = Decimal128Array::from(vec![Some(1), None])
+        .with_precision_and_scale(38, 0)
+        .unwrap();
+    assert_eq!(
+        converter.row_group_mins(&row_groups).unwrap().as_ref(),
+        &expected_min as &dyn Array
+    );
+    assert_eq!(
+        converter.row_group_maxes(&row_groups).unwrap().as_ref(),
+        &expected_max as &dyn Array
+    );
+
+    let converter = StatisticsConverter::try_new("f", &arrow_schema, &parquet_schema).unwrap();
+    let expected_min = Float16Array::from(vec![Some(f16::from_f32(-1.0)), None]);
+    let expected_max = Float16Array::from(vec![Some(f16::from_f32(1.0)), None]);
+    assert_eq!(
+        converter.row_group_mins(&row_groups).unwrap().as_ref(),
+        &expected_min as &dyn Array
+    );
+    assert_eq!(
+        converter.row_group_maxes(&row_groups).unwrap().as_ref(),
+        &expected_max as &dyn Array
+    );
+}
+
 /// return an Arrow schema and corresponding Parquet SchemaDescriptor for
 /// a schema with a single boolean column "b"
 fn bool_arrow_and_parquet_schema() -> (SchemaRef, SchemaDescPtr) {
@@ -17,18 +17,18 @@
 
 //! Tests for [`ArrowWriter`]
 
-use arrow::array::Float64Array;
+use arrow::array::{ArrayRef, Float32Array, Float64Array, Int64Array, StringArray};
 use arrow::datatypes::{DataType, Field, Schema};
 use arrow::record_batch::RecordBatch;
+use bytes::Bytes;
 use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
 use parquet::basic::Encoding;
 use parquet::file::properties::WriterProperties;
+use parquet::schema::types::ColumnPath;
 use std::sync::Arc;
 
 #[test]
-#[should_panic(
-    expected = "DeltaBitPackDecoder only supports Int32Type, UInt32Type, Int64Type, and UInt64Type"
-)]
 fn test_delta_bit_pack_type() {
     let props = WriterProperties::builder()
         .set_column_encoding("col".into(), Encoding::DELTA_BINARY_PACKED)
@@ -46,5 +46,74 @@ fn test_delta_bit_pack_type() {
 
     let mut buffer = Vec::new();
     let mut writer = ArrowWriter::try_new(&mut buffer, record_batch.schema(), Some(props)).unwrap();
-    let _ = writer.write(&record_batch);
+    let err = writer.write(&record_batch).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parquet error: Encoding DELTA_BINARY_PACKED is not supported for column \"col\" of type DOUBLE"
+    );
+}
+
+#[test]
+fn test_byte_array_encoding_type() {
+    let props = WriterProperties::builder()
+        .set_column_encoding("col".into(), Encoding::BYTE_STREAM_SPLIT)
+        .build();
+
+    let record_batch =
+        RecordBatch::try_from_iter([("col", Arc::new(StringArray::from(vec!["a"])) as ArrayRef)])
+            .unwrap();
+
+    let mut buffer = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut buffer, record_batch.schema(), Some(props)).unwrap();
+    let err = writer.write(&record_batch).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parquet error: Encoding BYTE_STREAM_SPLIT is not supported for column \"col\" of type BYTE_ARRAY"
+    );
+}
+
+#[test]
+fn test_column_encodings() {
+    let ints = Int64Array::from_iter_values((0..1000).map(|i| i * 3));
+    let floats = Float32Array::from_iter_values((0..1000).map(|i| i as f32 / 7.));
+    let strings = StringArray::from_iter_values((0..1000).map(|i| format!("prefix_{i:04}")));
+    let record_batch = RecordBatch::try_from_iter([
+        ("ints", Arc::new(ints) as ArrayRef),
+        ("floats", Arc::new(floats) as ArrayRef),
+        ("strings", Arc::new(strings) as ArrayRef),
+    ])
+    .unwrap();
+
+    let encodings = [
+        ("ints", Encoding::DELTA_BINARY_PACKED),
+        ("floats", Encoding::BYTE_STREAM_SPLIT),
+        ("strings", Encoding::DELTA_BYTE_ARRAY),
+    ];
+    let props = encodings
+        .iter()
+        .fold(
+            WriterProperties::builder().set_dictionary_enabled(false),
+            |builder, (column, encoding)| {
+                builder.set_column_encoding(ColumnPath::from(*column), *encoding)
+            },
+        )
+        .build();
+
+    let mut buffer = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut buffer, record_batch.schema(), Some(props)).unwrap();
+    writer.write(&record_batch).unwrap();
+    writer.close().unwrap();
+
+    let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer)).unwrap();
+    let row_group = builder.metadata().row_group(0);
+    for (idx, (_, encoding)) in encodings.iter().enumerate() {
+        let column_encodings: Vec<_> = row_group.column(idx).encodings().collect();
+        assert!(
+            column_encodings.contains(encoding),
+            "{column_encodings:?} does not contain {encoding}"
+        );
+    }
+
+    let read = builder.build().unwrap().next().unwrap().unwrap();
+    assert_eq!(read, record_batch);
 }
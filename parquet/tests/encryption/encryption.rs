@@ -32,7 +32,7 @@ use parquet::arrow::arrow_reader::{
 };
 use parquet::data_type::{ByteArray, ByteArrayType};
 use parquet::encryption::decrypt::FileDecryptionProperties;
-use parquet::encryption::encrypt::FileEncryptionProperties;
+use parquet::encryption::encrypt::{FileEncryptionProperties, ParquetCipher};
 use parquet::errors::ParquetError;
 use parquet::file::metadata::{ColumnChunkMetaData, PageIndexPolicy, ParquetMetaData};
 use parquet::file::properties::WriterProperties;
@@ -227,18 +227,7 @@ fn test_aes_ctr_encryption() {
         .build()
         .unwrap();
 
-    let options =
-        ArrowReaderOptions::default().with_file_decryption_properties(decryption_properties);
-    let metadata = ArrowReaderMetadata::load(&file, options);
-
-    match metadata {
-        Err(parquet::errors::ParquetError::NYI(s)) => {
-            assert!(s.contains("AES_GCM_CTR_V1"));
-        }
-        _ => {
-            panic!("Expected ParquetError::NYI");
-        }
-    };
+    verify_encryption_test_file_read(file, decryption_properties);
 }
 
 #[test]
@@ -388,27 +377,38 @@ fn row_group_sizes(metadata: &ParquetMetaData) -> Vec<i64> {
 
 #[test]
 fn test_uniform_encryption_roundtrip() {
-    uniform_encryption_roundtrip(false, false).unwrap();
+    uniform_encryption_roundtrip(false, false, ParquetCipher::AesGcmV1).unwrap();
 }
 
 #[test]
 fn test_uniform_encryption_roundtrip_with_dictionary() {
-    uniform_encryption_roundtrip(false, true).unwrap();
+    uniform_encryption_roundtrip(false, true, ParquetCipher::AesGcmV1).unwrap();
 }
 
 #[test]
 fn test_uniform_encryption_roundtrip_with_page_index() {
-    uniform_encryption_roundtrip(true, false).unwrap();
+    uniform_encryption_roundtrip(true, false, ParquetCipher::AesGcmV1).unwrap();
 }
 
 #[test]
 fn test_uniform_encryption_roundtrip_with_page_index_and_dictionary() {
-    uniform_encryption_roundtrip(true, true).unwrap();
+    uniform_encryption_roundtrip(true, true, ParquetCipher::AesGcmV1).unwrap();
+}
+
+#[test]
+fn test_uniform_encryption_roundtrip_aes_ctr() {
+    uniform_encryption_roundtrip(false, false, ParquetCipher::AesGcmCtrV1).unwrap();
+}
+
+#[test]
+fn test_uniform_encryption_roundtrip_aes_ctr_with_page_index_and_dictionary() {
+    uniform_encryption_roundtrip(true, true, ParquetCipher::AesGcmCtrV1).unwrap();
 }
 
 fn uniform_encryption_roundtrip(
     page_index: bool,
     dictionary_encoding: bool,
+    cipher: ParquetCipher,
 ) -> parquet::errors::Result<()> {
     let x0_arrays = [
         Int32Array::from((0..100).collect::<Vec<_>>()),
@@ -427,8 +427,9 @@ fn uniform_encryption_roundtrip(
     let file = tempfile::tempfile()?;
 
     let footer_key = b"0123456789012345";
-    let file_encryption_properties =
-        FileEncryptionProperties::builder(footer_key.to_vec()).build()?;
+    let file_encryption_properties = FileEncryptionProperties::builder(footer_key.to_vec())
+        .with_cipher(cipher)
+        .build()?;
 
     let props = WriterProperties::builder()
         // Ensure multiple row groups
@@ -641,6 +642,76 @@ fn test_write_non_uniform_encryption() {
     read_and_roundtrip_to_encrypted_file(&file, decryption_properties, file_encryption_properties);
 }
 
+#[test]
+fn test_write_non_uniform_encryption_aes_ctr() {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", ArrowDataType::Int32, false),
+        Field::new("x", ArrowDataType::Utf8, true),
+        Field::new("y", ArrowDataType::Float64, false),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from_iter_values(0..100)),
+            Arc::new(StringArray::from_iter(
+                (0..100).map(|i| (i % 3 != 0).then(|| format!("x{i}"))),
+            )),
+            Arc::new(Float64Array::from_iter_values((0..100).map(|i| i as f64))),
+        ],
+    )
+    .unwrap();
+
+    let footer_key = b"0123456789012345".to_vec();
+    let x_key = b"1234567890123450".to_vec();
+    let y_key = b"1234567890123451".to_vec();
+
+    let write = |cipher: ParquetCipher, plaintext_footer: bool| {
+        let file_encryption_properties = FileEncryptionProperties::builder(footer_key.clone())
+            .with_cipher(cipher)
+            .with_plaintext_footer(plaintext_footer)
+            .with_column_key("x", x_key.clone())
+            .with_column_key("y", y_key.clone())
+            .build()
+            .unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_row_count(Some(40))
+            .set_data_page_row_count_limit(10)
+            .set_write_batch_size(10)
+            .with_file_encryption_properties(file_encryption_properties)
+            .build();
+
+        let mut file = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut file, schema.clone(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        bytes::Bytes::from(file)
+    };
+
+    for plaintext_footer in [false, true] {
+        let file = write(ParquetCipher::AesGcmCtrV1, plaintext_footer);
+
+        let decryption_properties = FileDecryptionProperties::builder(footer_key.clone())
+            .with_column_key("x", x_key.clone())
+            .with_column_key("y", y_key.clone())
+            .build()
+            .unwrap();
+        let options =
+            ArrowReaderOptions::default().with_file_decryption_properties(decryption_properties);
+        let batches = ParquetRecordBatchReaderBuilder::try_new_with_options(file.clone(), options)
+            .unwrap()
+            .build()
+            .unwrap()
+            .collect::<ArrowResult<Vec<_>>>()
+            .unwrap();
+        let read = arrow::compute::concat_batches(&schema, &batches).unwrap();
+        assert_eq!(read, batch);
+
+        // Pages encrypted with AES-CTR have no authentication tag
+        let gcm_file = write(ParquetCipher::AesGcmV1, plaintext_footer);
+        assert!(file.len() < gcm_file.len());
+    }
+}
+
 #[test]
 fn test_write_uniform_encryption_plaintext_footer() {
     let testdata = arrow::util::test_util::parquet_test_data();
@@ -21,7 +21,7 @@ use crate::encryption_util::{
     TestKeyRetriever, read_encrypted_file, verify_column_indexes,
     verify_encryption_double_test_data, verify_encryption_test_data,
 };
-use arrow_array::RecordBatch;
+use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
 use arrow_schema::Schema;
 use futures::TryStreamExt;
 use parquet::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions};
@@ -33,7 +33,7 @@ use parquet::arrow::{
     ArrowSchemaConverter, ArrowWriter, AsyncArrowWriter, ParquetRecordBatchStreamBuilder,
 };
 use parquet::encryption::decrypt::FileDecryptionProperties;
-use parquet::encryption::encrypt::FileEncryptionProperties;
+use parquet::encryption::encrypt::{FileEncryptionProperties, ParquetCipher};
 use parquet::errors::ParquetError;
 use parquet::file::metadata::PageIndexPolicy;
 use parquet::file::metadata::ParquetMetaData;
@@ -226,25 +226,68 @@ async fn test_aes_ctr_encryption() {
 
     let footer_key = "0123456789012345".as_bytes().to_vec();
     let column_1_key = "1234567890123450".as_bytes().to_vec();
-    //let column_2_key = "1234567890123451".as_bytes().to_vec();
+    let column_2_key = "1234567890123451".as_bytes().to_vec();
 
     let decryption_properties = FileDecryptionProperties::builder(footer_key)
-        .with_column_key("double_field", column_1_key.clone())
-        .with_column_key("float_field", column_1_key)
+        .with_column_key("double_field", column_1_key)
+        .with_column_key("float_field", column_2_key)
         .build()
         .unwrap();
 
-    let options = ArrowReaderOptions::new().with_file_decryption_properties(decryption_properties);
-    let metadata = ArrowReaderMetadata::load_async(&mut file, options).await;
+    verify_encryption_test_file_read_async(&mut file, decryption_properties)
+        .await
+        .unwrap();
+}
 
-    match metadata {
-        Err(ParquetError::NYI(s)) => {
-            assert!(s.contains("AES_GCM_CTR_V1"));
-        }
-        _ => {
-            panic!("Expected ParquetError::NYI");
-        }
-    };
+#[tokio::test]
+async fn test_aes_ctr_encryption_roundtrip() {
+    let batch = RecordBatch::try_from_iter([
+        (
+            "x",
+            Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef,
+        ),
+        (
+            "y",
+            Arc::new(StringArray::from_iter_values(
+                (0..100).map(|i| format!("y{i}")),
+            )) as ArrayRef,
+        ),
+    ])
+    .unwrap();
+
+    let footer_key = b"0123456789012345".to_vec();
+    let column_key = b"1234567890123450".to_vec();
+    let file_encryption_properties = FileEncryptionProperties::builder(footer_key.clone())
+        .with_cipher(ParquetCipher::AesGcmCtrV1)
+        .with_column_key("y", column_key.clone())
+        .build()
+        .unwrap();
+    let props = WriterProperties::builder()
+        .set_max_row_group_row_count(Some(30))
+        .set_data_page_row_count_limit(10)
+        .set_write_batch_size(10)
+        .with_file_encryption_properties(file_encryption_properties)
+        .build();
+
+    let mut file = Vec::new();
+    let mut writer = AsyncArrowWriter::try_new(&mut file, batch.schema(), Some(props)).unwrap();
+    writer.write(&batch).await.unwrap();
+    writer.close().await.unwrap();
+
+    let decryption_properties = FileDecryptionProperties::builder(footer_key)
+        .with_column_key("y", column_key)
+        .build()
+        .unwrap();
+    let options = ArrowReaderOptions::new().with_file_decryption_properties(decryption_properties);
+    let stream =
+        ParquetRecordBatchStreamBuilder::new_with_options(std::io::Cursor::new(file), options)
+            .await
+            .unwrap()
+            .build()
+            .unwrap();
+    let batches: Vec<_> = stream.try_collect().await.unwrap();
+    let read = arrow::compute::concat_batches(&batch.schema(), &batches).unwrap();
+    assert_eq!(read, batch);
 }
 
 #[tokio::test]